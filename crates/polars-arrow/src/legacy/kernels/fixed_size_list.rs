@@ -1,9 +1,9 @@
 use polars_error::{polars_bail, PolarsResult};
-use polars_utils::index::NullCount;
 use polars_utils::IdxSize;
 
-use crate::array::{ArrayRef, FixedSizeListArray, PrimitiveArray};
+use crate::array::{Array, ArrayRef, FixedSizeListArray, PrimitiveArray};
 use crate::compute::take::take_unchecked;
+use crate::compute::utils::combine_validities_and;
 use crate::legacy::prelude::*;
 use crate::legacy::utils::CustomIterTools;
 
@@ -50,10 +50,7 @@ pub fn sub_fixed_size_list_get_literal(
         polars_bail!(ComputeError: "get index is out of bounds");
     }
 
-    let values = arr.values();
-    // SAFETY:
-    // the indices we generate are in bounds
-    unsafe { Ok(take_unchecked(&**values, &take_by)) }
+    take_with_outer_validity(arr, &take_by)
 }
 
 pub fn sub_fixed_size_list_get(
@@ -66,8 +63,20 @@ pub fn sub_fixed_size_list_get(
         polars_bail!(ComputeError: "get index is out of bounds");
     }
 
+    take_with_outer_validity(arr, &take_by)
+}
+
+fn take_with_outer_validity(arr: &FixedSizeListArray, take_by: &IdxArr) -> PolarsResult<ArrayRef> {
     let values = arr.values();
     // SAFETY:
     // the indices we generate are in bounds
-    unsafe { Ok(take_unchecked(&**values, &take_by)) }
+    let out = unsafe { take_unchecked(&**values, take_by) };
+
+    // The values underneath a null sub-array are not guaranteed to be null.
+    if arr.null_count() > 0 {
+        let validity = combine_validities_and(out.validity(), arr.validity());
+        Ok(out.with_validity(validity))
+    } else {
+        Ok(out)
+    }
 }
@@ -38,6 +38,19 @@ pub enum NonExistent {
     Null,
     Raise,
 }
+impl FromStr for NonExistent {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "raise" => Ok(NonExistent::Raise),
+            "null" => Ok(NonExistent::Null),
+            s => polars_bail!(InvalidOperation:
+                "Invalid argument {}, expected one of: \"null\", \"raise\"", s
+            ),
+        }
+    }
+}
 
 #[cfg(feature = "timezones")]
 pub fn convert_to_naive_local(
@@ -400,16 +400,22 @@ impl ChunkCast for ListChunked {
             },
             #[cfg(feature = "dtype-array")]
             Array(child_type, width) => {
-                let physical_type = data_type.to_physical();
+                // cast the inner type first, so that the logical inner type (e.g. the
+                // rev-map of a categorical) is resolved.
+                let list = self.cast(&List(child_type.clone()))?;
+                let list = list.list().unwrap();
+                let child_type = list.inner_dtype();
+
+                let physical_type = Array(Box::new(child_type.to_physical()), *width);
                 // cast to the physical type to avoid logical chunks.
-                let chunks = cast_chunks(self.chunks(), &physical_type, true)?;
+                let chunks = cast_chunks(list.chunks(), &physical_type, true)?;
                 // SAFETY: we just casted so the dtype matches.
                 // we must take this path to correct for physical types.
                 unsafe {
                     Ok(Series::from_chunks_and_dtype_unchecked(
                         self.name(),
                         chunks,
-                        &Array(child_type.clone(), *width),
+                        &Array(Box::new(child_type), *width),
                     ))
                 }
             },
@@ -17,6 +17,13 @@ where
     Missing: Fn(&'a CategoricalChunked) -> BooleanChunked,
 {
     let rev_map_l = lhs.get_rev_map();
+    if lhs.is_enum() || rhs.is_enum() {
+        // The string cache doesn't apply to enums, so don't suggest it.
+        polars_ensure!(
+            lhs.dtype() == rhs.dtype(),
+            ComputeError: "can only compare categoricals of the same type with the same categories"
+        );
+    }
     polars_ensure!(rev_map_l.same_src(rhs.get_rev_map()), string_cache_mismatch);
     let rhs = rhs.physical();
 
@@ -363,15 +370,31 @@ where
         }
     } else {
         // Apply comparison on categories map and then do a lookup
-        let bitmap = str_single_compare_function(lhs.get_rev_map().get_categories(), rhs);
-
-        Ok(
-            BooleanChunked::from_iter_trusted_length(lhs.physical().into_iter().map(|opt_idx| {
-                // SAFETY: indexing into bitmap with same length as original array
-                opt_idx.map(|idx| unsafe { bitmap.get_bit_unchecked(idx as usize) })
-            }))
-            .with_name(lhs.name()),
-        )
+        let bitmap = str_single_compare_function(rev_map.get_categories(), rhs);
+
+        let out = match &**rev_map {
+            // The physical values of a global mapping index into the string cache, so map them
+            // to the index of the category first.
+            RevMapping::Global(map, _, _) => {
+                BooleanChunked::from_iter_trusted_length(lhs.physical().into_iter().map(
+                    |opt_idx| {
+                        // SAFETY: the local indices are in bounds of the categories
+                        opt_idx.map(|idx| unsafe {
+                            bitmap.get_bit_unchecked(*map.get(&idx).unwrap() as usize)
+                        })
+                    },
+                ))
+            },
+            RevMapping::Local(_, _) => {
+                BooleanChunked::from_iter_trusted_length(lhs.physical().into_iter().map(
+                    |opt_idx| {
+                        // SAFETY: indexing into bitmap with same length as original array
+                        opt_idx.map(|idx| unsafe { bitmap.get_bit_unchecked(idx as usize) })
+                    },
+                ))
+            },
+        };
+        Ok(out.with_name(lhs.name()))
     }
 }
 
@@ -1,8 +1,5 @@
-use std::borrow::Cow;
-
 use super::*;
 use crate::series::IsSorted;
-use crate::utils::align_chunks_binary;
 
 fn slots_to_mut(slots: &Utf8ViewArray) -> MutablePlString {
     slots.clone().make_mut()
@@ -131,6 +128,23 @@ fn merge_local_rhs_categorical<'a>(
     ))
 }
 
+/// Whether the categoricals have been built under different string caches, or one of them
+/// without a string cache at all. These can only be combined by re-encoding both of them as
+/// local categoricals.
+pub fn categoricals_from_different_caches(
+    cat_left: &CategoricalChunked,
+    cat_right: &CategoricalChunked,
+) -> bool {
+    if cat_left.is_enum() || cat_right.is_enum() {
+        return false;
+    }
+    match (&**cat_left.get_rev_map(), &**cat_right.get_rev_map()) {
+        (RevMapping::Global(_, _, idl), RevMapping::Global(_, _, idr)) => idl != idr,
+        (RevMapping::Local(_, _), RevMapping::Local(_, _)) => false,
+        _ => true,
+    }
+}
+
 pub trait CategoricalMergeOperation {
     fn finish(self, lhs: &UInt32Chunked, rhs: &UInt32Chunked) -> PolarsResult<UInt32Chunked>;
 }
@@ -141,6 +155,13 @@ pub fn call_categorical_merge_operation<I: CategoricalMergeOperation>(
     cat_right: &CategoricalChunked,
     merge_ops: I,
 ) -> PolarsResult<CategoricalChunked> {
+    if categoricals_from_different_caches(cat_left, cat_right) {
+        return call_categorical_merge_operation(
+            &cat_left.to_local(),
+            &cat_right.to_local(),
+            merge_ops,
+        );
+    }
     let rev_map_left = cat_left.get_rev_map();
     let rev_map_right = cat_right.get_rev_map();
     let (new_physical, new_rev_map) = match (&**rev_map_left, &**rev_map_right) {
@@ -195,10 +216,15 @@ impl CategoricalMergeOperation for DoNothing {
 }
 
 // Make the right categorical compatible with the left
+//
+// Categoricals built under different string caches are both re-encoded as local categoricals.
 pub fn make_categoricals_compatible(
     ca_left: &CategoricalChunked,
     ca_right: &CategoricalChunked,
 ) -> PolarsResult<(CategoricalChunked, CategoricalChunked)> {
+    if categoricals_from_different_caches(ca_left, ca_right) {
+        return make_categoricals_compatible(&ca_left.to_local(), &ca_right.to_local());
+    }
     let new_ca_right = call_categorical_merge_operation(ca_left, ca_right, DoNothing)?;
 
     // Alter rev map of left
@@ -222,20 +248,32 @@ pub fn make_list_categoricals_compatible(
 
     let cat_left = list_ca_left.get_inner();
     let cat_right = list_ca_right.get_inner();
-    let (cat_left, cat_right) =
-        make_categoricals_compatible(cat_left.categorical()?, cat_right.categorical()?)?;
+    let (cat_left, cat_right) = (cat_left.categorical()?, cat_right.categorical()?);
+    let left_is_re_encoded = categoricals_from_different_caches(cat_left, cat_right);
+    let (cat_left, cat_right) = make_categoricals_compatible(cat_left, cat_right)?;
 
-    // we only appended categories to the rev_map at the end, so only change the inner dtype
-    list_ca_left.set_inner_dtype(cat_left.dtype().clone());
+    if left_is_re_encoded {
+        list_ca_left = set_list_categoricals(list_ca_left, &cat_left);
+    } else {
+        // we only appended categories to the rev_map at the end, so only change the inner dtype
+        list_ca_left.set_inner_dtype(cat_left.dtype().clone());
+    }
+    let list_ca_right = set_list_categoricals(list_ca_right, &cat_right);
+    Ok((list_ca_left, list_ca_right))
+}
 
+/// Replace the inner values of `list_ca` by the re-encoded categoricals `cat`.
+///
+/// The chunks of `cat` must correspond to the values of the chunks of `list_ca`, as returned
+/// by [`ListChunked::get_inner`].
+fn set_list_categoricals(mut list_ca: ListChunked, cat: &CategoricalChunked) -> ListChunked {
     // We changed the physicals and the rev_map, offsets and validity buffers are still good
-    let (list_ca_right, cat_physical): (Cow<ListChunked>, Cow<UInt32Chunked>) =
-        align_chunks_binary(&list_ca_right, cat_right.physical());
-    let mut list_ca_right = list_ca_right.into_owned();
+    let cat_physical = cat.physical();
+    assert_eq!(list_ca.chunks().len(), cat_physical.chunks().len());
     // SAFETY:
     // Chunks are aligned, length / dtype remains correct
     unsafe {
-        list_ca_right
+        list_ca
             .downcast_iter_mut()
             .zip(cat_physical.chunks())
             .for_each(|(arr, new_phys)| {
@@ -248,7 +286,7 @@ pub fn make_list_categoricals_compatible(
             });
     }
     // reset the sorted flag and add extra categories back in
-    list_ca_right.set_sorted_flag(IsSorted::Not);
-    list_ca_right.set_inner_dtype(cat_right.dtype().clone());
-    Ok((list_ca_left, list_ca_right))
+    list_ca.set_sorted_flag(IsSorted::Not);
+    list_ca.set_inner_dtype(cat.dtype().clone());
+    list_ca
 }
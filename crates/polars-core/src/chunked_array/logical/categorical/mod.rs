@@ -224,11 +224,8 @@ impl CategoricalChunked {
         }
     }
 
-    pub(crate) fn set_ordering(
-        mut self,
-        ordering: CategoricalOrdering,
-        keep_fast_unique: bool,
-    ) -> Self {
+    /// Set the [`CategoricalOrdering`] used when sorting and comparing the values.
+    pub fn set_ordering(mut self, ordering: CategoricalOrdering, keep_fast_unique: bool) -> Self {
         self.physical.2 = match self.dtype() {
             DataType::Enum(_, _) => {
                 Some(DataType::Enum(Some(self.get_rev_map().clone()), ordering))
@@ -239,6 +236,8 @@ impl CategoricalChunked {
             )),
             _ => panic!("implementation error"),
         };
+        // The physical values are not sorted in lexical order.
+        self.set_flags(self.get_flags());
 
         if !keep_fast_unique {
             self.set_fast_unique(false)
@@ -443,7 +442,11 @@ impl<'a> ExactSizeIterator for CatIter<'a> {}
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{disable_string_cache, enable_string_cache, SINGLE_LOCK};
+    use crate::{
+        disable_string_cache, enable_string_cache, string_cache_enabled_globally,
+        string_cache_refcount, using_string_cache, with_string_cache, StringCacheHolder,
+        SINGLE_LOCK,
+    };
 
     #[test]
     fn test_categorical_round_trip() -> PolarsResult<()> {
@@ -539,4 +542,43 @@ mod test {
         assert_eq!(vals, &["a", "b", "c"]);
         Ok(())
     }
+
+    #[test]
+    fn test_nested_string_cache_holders() {
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+        assert!(!using_string_cache());
+
+        let outer = StringCacheHolder::hold();
+        let inner = with_string_cache(|| {
+            let inner = StringCacheHolder::hold();
+            assert_eq!(string_cache_refcount(), 3);
+            inner
+        });
+        assert_eq!(string_cache_refcount(), 2);
+
+        // Disabling the global string cache does not release the holders.
+        enable_string_cache();
+        assert!(string_cache_enabled_globally());
+        disable_string_cache();
+        assert!(!string_cache_enabled_globally());
+        assert!(using_string_cache());
+
+        let s1 = Series::new("a", ["a", "b"])
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+        drop(outer);
+        let s2 = Series::new("b", ["b", "c"])
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+        assert!(s1
+            .categorical()
+            .unwrap()
+            .get_rev_map()
+            .same_src(s2.categorical().unwrap().get_rev_map()));
+
+        drop(inner);
+        assert_eq!(string_cache_refcount(), 0);
+        assert!(!using_string_cache());
+    }
 }
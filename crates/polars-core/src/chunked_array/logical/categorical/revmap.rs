@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::datatypes::PlHashMap;
 use crate::using_string_cache;
 
-#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(
     any(feature = "serde-lazy", feature = "serde"),
     derive(Serialize, Deserialize)
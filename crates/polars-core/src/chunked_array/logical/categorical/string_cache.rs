@@ -30,18 +30,28 @@ static STRING_CACHE_UUID_CTR: AtomicU32 = AtomicU32::new(0);
 ///
 /// The string cache is enabled until `handle` is dropped.
 ///
+/// # Nesting and threads
+///
+/// Holders are reference counted, so they can be nested and multiple threads can hold
+/// the string cache at the same time. The string cache stays enabled as long as at least
+/// one holder is alive or it was enabled with [`enable_string_cache`]. Use
+/// [`with_string_cache`] to enable it for the duration of a closure.
+///
 /// # De-allocation
 ///
-/// Multiple threads can hold the string cache at the same time.
 /// The contents of the cache will only get dropped when no thread holds it.
 ///
 /// [RAII]: https://en.wikipedia.org/wiki/Resource_acquisition_is_initialization
+#[must_use = "the string cache is released as soon as the holder is dropped"]
 pub struct StringCacheHolder {
     // only added so that it will never be constructed directly
     #[allow(dead_code)]
     private_zst: (),
 }
 
+/// Alias of [`StringCacheHolder`].
+pub type StringCacheGuard = StringCacheHolder;
+
 impl Default for StringCacheHolder {
     fn default() -> Self {
         Self::hold()
@@ -51,29 +61,46 @@ impl Default for StringCacheHolder {
 impl StringCacheHolder {
     /// Hold the StringCache
     pub fn hold() -> StringCacheHolder {
-        increment_string_cache_refcount();
+        increment_string_cache_refcount(&mut STRING_CACHE_REFCOUNT.lock().unwrap());
         StringCacheHolder { private_zst: () }
     }
 }
 
 impl Drop for StringCacheHolder {
     fn drop(&mut self) {
-        decrement_string_cache_refcount();
+        decrement_string_cache_refcount(&mut STRING_CACHE_REFCOUNT.lock().unwrap());
     }
 }
 
-fn increment_string_cache_refcount() {
-    let mut refcount = STRING_CACHE_REFCOUNT.lock().unwrap();
+fn increment_string_cache_refcount(refcount: &mut u32) {
     *refcount += 1;
 }
-fn decrement_string_cache_refcount() {
-    let mut refcount = STRING_CACHE_REFCOUNT.lock().unwrap();
+fn decrement_string_cache_refcount(refcount: &mut u32) {
     *refcount -= 1;
     if *refcount == 0 {
         STRING_CACHE.clear()
     }
 }
 
+/// Run `func` with the global string cache enabled.
+///
+/// The string cache is held for the duration of the call, also if `func` panics. This
+/// does not interfere with other holders of the string cache, so it is safe to use from
+/// multiple threads at the same time.
+///
+/// # Examples
+///
+/// ```
+/// use polars_core::{using_string_cache, with_string_cache};
+///
+/// let enabled = with_string_cache(using_string_cache);
+/// assert!(enabled);
+/// ```
+pub fn with_string_cache<F: FnOnce() -> T, T>(func: F) -> T {
+    let _sc = StringCacheHolder::hold();
+    func()
+}
+
 /// Enable the global string cache.
 ///
 /// [`Categorical`] columns created under the same global string cache have the
@@ -86,23 +113,45 @@ fn decrement_string_cache_refcount() {
 ///
 /// [`Categorical`]: crate::datatypes::DataType::Categorical
 pub fn enable_string_cache() {
+    // Toggle the flag under the lock, so that it stays consistent with the refcount
+    // when multiple threads enable and disable the string cache concurrently.
+    let mut refcount = STRING_CACHE_REFCOUNT.lock().unwrap();
     let was_enabled = STRING_CACHE_ENABLED_GLOBALLY.swap(true, Ordering::AcqRel);
     if !was_enabled {
-        increment_string_cache_refcount();
+        increment_string_cache_refcount(&mut refcount);
     }
 }
 
 /// Disable and clear the global string cache.
 ///
+/// This only releases the hold of [`enable_string_cache`]; the string cache stays enabled
+/// as long as any [`StringCacheHolder`] is alive.
+///
 /// Note: Consider using [`StringCacheHolder`] for a more reliable way of
 /// enabling and disabling the string cache.
 pub fn disable_string_cache() {
+    let mut refcount = STRING_CACHE_REFCOUNT.lock().unwrap();
     let was_enabled = STRING_CACHE_ENABLED_GLOBALLY.swap(false, Ordering::AcqRel);
     if was_enabled {
-        decrement_string_cache_refcount();
+        decrement_string_cache_refcount(&mut refcount);
     }
 }
 
+/// Check whether the global string cache is enabled by [`enable_string_cache`].
+///
+/// Unlike [`using_string_cache`], this ignores any [`StringCacheHolder`]s.
+pub fn string_cache_enabled_globally() -> bool {
+    STRING_CACHE_ENABLED_GLOBALLY.load(Ordering::Acquire)
+}
+
+/// The number of active holds on the global string cache.
+///
+/// This counts every alive [`StringCacheHolder`], plus one if the string cache was enabled
+/// with [`enable_string_cache`].
+pub fn string_cache_refcount() -> u32 {
+    *STRING_CACHE_REFCOUNT.lock().unwrap()
+}
+
 /// Check whether the global string cache is enabled.
 pub fn using_string_cache() -> bool {
     let refcount = STRING_CACHE_REFCOUNT.lock().unwrap();
@@ -520,15 +520,28 @@ impl CategoricalChunked {
     }
 }
 
+#[cfg(feature = "dtype-categorical")]
+impl CategoricalChunked {
+    /// The extremum of an enum remains an enum with the same categories, as its categories
+    /// are fixed up front.
+    fn reduce_dtype(&self) -> DataType {
+        if self.is_enum() {
+            self.dtype().clone()
+        } else {
+            DataType::String
+        }
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 impl ChunkAggSeries for CategoricalChunked {
     fn min_reduce(&self) -> Scalar {
         let av: AnyValue = self.min_categorical().into();
-        Scalar::new(DataType::String, av.into_static().unwrap())
+        Scalar::new(self.reduce_dtype(), av.into_static().unwrap())
     }
     fn max_reduce(&self) -> Scalar {
         let av: AnyValue = self.max_categorical().into();
-        Scalar::new(DataType::String, av.into_static().unwrap())
+        Scalar::new(self.reduce_dtype(), av.into_static().unwrap())
     }
 }
 
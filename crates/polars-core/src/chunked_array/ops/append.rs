@@ -148,6 +148,20 @@ where
 #[doc(hidden)]
 impl ListChunked {
     pub fn append(&mut self, other: &Self) -> PolarsResult<()> {
+        // Re-encode categoricals from different sources, so that they share their categories.
+        #[cfg(feature = "dtype-categorical")]
+        if let (
+            DataType::Categorical(Some(rev_map_l), _),
+            DataType::Categorical(Some(rev_map_r), _),
+        ) = (self.inner_dtype(), other.inner_dtype())
+        {
+            if !rev_map_l.same_src(&rev_map_r) {
+                let (lhs, rhs) = make_list_categoricals_compatible(self.clone(), other.clone())?;
+                *self = lhs;
+                return self.append(&rhs);
+            }
+        }
+
         let dtype = merge_dtypes(self.dtype(), other.dtype())?;
         self.field = Arc::new(Field::new(self.name(), dtype));
 
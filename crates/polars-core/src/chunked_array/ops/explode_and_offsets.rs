@@ -176,13 +176,11 @@ impl ChunkExplode for ArrayChunked {
     }
 
     fn explode_and_offsets(&self) -> PolarsResult<(Series, OffsetsBuffer<i64>)> {
-        let ca = self.rechunk();
-        let arr = ca.downcast_iter().next().unwrap();
         // fast-path for non-null array.
-        if arr.null_count() == 0 {
-            let s = Series::try_from((self.name(), arr.values().clone()))
-                .unwrap()
-                .cast(&ca.inner_dtype())?;
+        // The values of a fixed size list are already laid out in exploded form and the offsets
+        // are implicit, so we don't have to rechunk or gather the values.
+        if self.null_count() == 0 {
+            let s = self.get_inner();
             let width = self.width() as i64;
             let offsets = (0..self.len() + 1)
                 .map(|i| {
@@ -195,6 +193,8 @@ impl ChunkExplode for ArrayChunked {
             return Ok((s, offsets));
         }
 
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
         // we have already ensure that validity is not none.
         let validity = arr.validity().unwrap();
         let values = arr.values();
@@ -33,7 +33,14 @@ impl DateChunked {
     /// Convert from Date into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(&self, format: &str) -> StringChunked {
+        self.to_string_localized(format, Locale::default())
+    }
+
+    /// Convert from Date into String with the given format, with the names of months and
+    /// weekdays in the language of `locale`.
+    pub fn to_string_localized(&self, format: &str, locale: Locale) -> StringChunked {
         let mut ca: StringChunked = self.apply_kernel_cast(&|arr| {
+            let mut formatter = TemporalFormatter::new(format, locale);
             let mut buf = String::new();
             let mut mutarr = MutablePlString::with_capacity(arr.len());
 
@@ -42,7 +49,8 @@ impl DateChunked {
                     None => mutarr.push_null(),
                     Some(v) => {
                         buf.clear();
-                        let datefmt = date32_to_date(*v).format(format);
+                        let date = date32_to_date(*v);
+                        let datefmt = date.format_with_items(formatter.items(&date).iter());
                         write!(buf, "{datefmt}").unwrap();
                         mutarr.push_value(&buf)
                     },
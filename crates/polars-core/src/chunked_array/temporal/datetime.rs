@@ -3,7 +3,6 @@ use std::fmt::Write;
 use arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
 };
-use chrono::format::{DelayedFormat, StrftimeItems};
 #[cfg(feature = "timezones")]
 use chrono::TimeZone as TimeZoneTrait;
 
@@ -11,10 +10,10 @@ use super::*;
 use crate::prelude::DataType::Datetime;
 use crate::prelude::*;
 
-fn apply_datefmt_f<'a>(
+fn apply_datefmt_f(
     arr: &PrimitiveArray<i64>,
     conversion_f: fn(i64) -> NaiveDateTime,
-    datefmt_f: impl Fn(NaiveDateTime) -> DelayedFormat<StrftimeItems<'a>>,
+    mut datefmt_f: impl FnMut(NaiveDateTime, &mut String),
 ) -> ArrayRef {
     let mut buf = String::new();
     let mut mutarr = MutableBinaryViewArray::<str>::with_capacity(arr.len());
@@ -24,8 +23,7 @@ fn apply_datefmt_f<'a>(
             Some(v) => {
                 buf.clear();
                 let converted = conversion_f(*v);
-                let datefmt = datefmt_f(converted);
-                write!(buf, "{datefmt}").unwrap();
+                datefmt_f(converted, &mut buf);
                 mutarr.push_value(&buf)
             },
         }
@@ -37,18 +35,25 @@ fn apply_datefmt_f<'a>(
 fn format_tz(
     tz: Tz,
     arr: &PrimitiveArray<i64>,
-    fmt: &str,
+    mut formatter: TemporalFormatter,
     conversion_f: fn(i64) -> NaiveDateTime,
 ) -> ArrayRef {
-    let datefmt_f = |ndt| tz.from_utc_datetime(&ndt).format(fmt);
+    let datefmt_f = |ndt, buf: &mut String| {
+        let dt = tz.from_utc_datetime(&ndt);
+        let datefmt = dt.format_with_items(formatter.items(&dt).iter());
+        write!(buf, "{datefmt}").unwrap();
+    };
     apply_datefmt_f(arr, conversion_f, datefmt_f)
 }
 fn format_naive(
     arr: &PrimitiveArray<i64>,
-    fmt: &str,
+    mut formatter: TemporalFormatter,
     conversion_f: fn(i64) -> NaiveDateTime,
 ) -> ArrayRef {
-    let datefmt_f = |ndt: NaiveDateTime| ndt.format(fmt);
+    let datefmt_f = |ndt: NaiveDateTime, buf: &mut String| {
+        let datefmt = ndt.format_with_items(formatter.items(&ndt).iter());
+        write!(buf, "{datefmt}").unwrap();
+    };
     apply_datefmt_f(arr, conversion_f, datefmt_f)
 }
 
@@ -84,6 +89,12 @@ impl DatetimeChunked {
     /// Convert from Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(&self, format: &str) -> PolarsResult<StringChunked> {
+        self.to_string_localized(format, Locale::default())
+    }
+
+    /// Convert from Datetime into String with the given format, with the names of months and
+    /// weekdays in the language of `locale`.
+    pub fn to_string_localized(&self, format: &str, locale: Locale) -> PolarsResult<StringChunked> {
         #[cfg(feature = "timezones")]
         use chrono::Utc;
         let conversion_f = match self.time_unit() {
@@ -115,9 +126,17 @@ impl DatetimeChunked {
         let mut ca: StringChunked = match self.time_zone() {
             #[cfg(feature = "timezones")]
             Some(time_zone) => self.apply_kernel_cast(&|arr| {
-                format_tz(time_zone.parse::<Tz>().unwrap(), arr, format, conversion_f)
+                let formatter = TemporalFormatter::new(format, locale);
+                format_tz(
+                    time_zone.parse::<Tz>().unwrap(),
+                    arr,
+                    formatter,
+                    conversion_f,
+                )
+            }),
+            _ => self.apply_kernel_cast(&|arr| {
+                format_naive(arr, TemporalFormatter::new(format, locale), conversion_f)
             }),
-            _ => self.apply_kernel_cast(&|arr| format_naive(arr, format, conversion_f)),
         };
         ca.rename(self.name());
         Ok(ca)
@@ -251,4 +270,17 @@ mod test {
             dt.cont_slice().unwrap()
         );
     }
+
+    #[test]
+    fn to_string_localized() {
+        let datetime =
+            NaiveDateTime::parse_from_str("2024-03-03 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let dt = DatetimeChunked::from_naive_datetime("name", [datetime], TimeUnit::Microseconds);
+        let out = dt
+            .to_string_localized("%A %-d %B (%a, %b) %H:%M", Locale::FrFR)
+            .unwrap();
+        assert_eq!(out.get(0), Some("dimanche 3 mars (dim., mars) 10:00"));
+        let out = dt.to_string("%A %-d %B (%a, %b)").unwrap();
+        assert_eq!(out.get(0), Some("Sunday 3 March (Sun, Mar)"));
+    }
 }
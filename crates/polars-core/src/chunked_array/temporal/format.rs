@@ -0,0 +1,291 @@
+use std::str::FromStr;
+
+use chrono::format::{Fixed, Item, StrftimeItems};
+use chrono::Datelike;
+#[cfg(any(feature = "serde-lazy", feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The language that the names of months and weekdays are formatted in.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+#[cfg_attr(
+    any(feature = "serde-lazy", feature = "serde"),
+    derive(Serialize, Deserialize)
+)]
+pub enum Locale {
+    #[default]
+    EnUS,
+    DeDE,
+    EsES,
+    FrFR,
+    ItIT,
+    NlNL,
+    PtPT,
+}
+
+struct LocaleNames {
+    months: [&'static str; 12],
+    short_months: [&'static str; 12],
+    /// Starting on Sunday.
+    weekdays: [&'static str; 7],
+    short_weekdays: [&'static str; 7],
+}
+
+static DE_DE: LocaleNames = LocaleNames {
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    short_months: [
+        "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+    ],
+    weekdays: [
+        "Sonntag",
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+    ],
+    short_weekdays: ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+};
+
+static ES_ES: LocaleNames = LocaleNames {
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    short_months: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+    ],
+    weekdays: [
+        "domingo",
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+    ],
+    short_weekdays: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+};
+
+static FR_FR: LocaleNames = LocaleNames {
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    short_months: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    weekdays: [
+        "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+    ],
+    short_weekdays: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+};
+
+static IT_IT: LocaleNames = LocaleNames {
+    months: [
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ],
+    short_months: [
+        "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+    ],
+    weekdays: [
+        "domenica",
+        "lunedì",
+        "martedì",
+        "mercoledì",
+        "giovedì",
+        "venerdì",
+        "sabato",
+    ],
+    short_weekdays: ["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+};
+
+static NL_NL: LocaleNames = LocaleNames {
+    months: [
+        "januari",
+        "februari",
+        "maart",
+        "april",
+        "mei",
+        "juni",
+        "juli",
+        "augustus",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ],
+    short_months: [
+        "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+    ],
+    weekdays: [
+        "zondag",
+        "maandag",
+        "dinsdag",
+        "woensdag",
+        "donderdag",
+        "vrijdag",
+        "zaterdag",
+    ],
+    short_weekdays: ["zo", "ma", "di", "wo", "do", "vr", "za"],
+};
+
+static PT_PT: LocaleNames = LocaleNames {
+    months: [
+        "janeiro",
+        "fevereiro",
+        "março",
+        "abril",
+        "maio",
+        "junho",
+        "julho",
+        "agosto",
+        "setembro",
+        "outubro",
+        "novembro",
+        "dezembro",
+    ],
+    short_months: [
+        "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+    ],
+    weekdays: [
+        "domingo", "segunda", "terça", "quarta", "quinta", "sexta", "sábado",
+    ],
+    short_weekdays: ["dom", "seg", "ter", "qua", "qui", "sex", "sáb"],
+};
+
+impl Locale {
+    /// The names of this locale, `None` for the English names that chrono formats itself.
+    fn names(self) -> Option<&'static LocaleNames> {
+        match self {
+            Locale::EnUS => None,
+            Locale::DeDE => Some(&DE_DE),
+            Locale::EsES => Some(&ES_ES),
+            Locale::FrFR => Some(&FR_FR),
+            Locale::ItIT => Some(&IT_IT),
+            Locale::NlNL => Some(&NL_NL),
+            Locale::PtPT => Some(&PT_PT),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        let locale = match s {
+            "en_US" => Locale::EnUS,
+            "de_DE" => Locale::DeDE,
+            "es_ES" => Locale::EsES,
+            "fr_FR" => Locale::FrFR,
+            "it_IT" => Locale::ItIT,
+            "nl_NL" => Locale::NlNL,
+            "pt_PT" => Locale::PtPT,
+            _ => polars_bail!(
+                InvalidOperation: "unsupported locale '{}', expected one of: \
+                'de_DE', 'en_US', 'es_ES', 'fr_FR', 'it_IT', 'nl_NL', 'pt_PT'", s
+            ),
+        };
+        Ok(locale)
+    }
+}
+
+/// A strftime format that is parsed once and then used to format many values.
+pub(super) struct TemporalFormatter<'a> {
+    items: Vec<Item<'a>>,
+    names: Option<&'static LocaleNames>,
+    localized: Vec<Item<'a>>,
+}
+
+impl<'a> TemporalFormatter<'a> {
+    pub(super) fn new(format: &'a str, locale: Locale) -> Self {
+        let items: Vec<_> = StrftimeItems::new(format).collect();
+        // Only formats with names need to be localized per value.
+        let names = locale.names().filter(|_| {
+            items.iter().any(|item| {
+                matches!(
+                    item,
+                    Item::Fixed(
+                        Fixed::ShortMonthName
+                            | Fixed::LongMonthName
+                            | Fixed::ShortWeekdayName
+                            | Fixed::LongWeekdayName
+                    )
+                )
+            })
+        });
+        Self {
+            items,
+            names,
+            localized: Vec::new(),
+        }
+    }
+
+    /// The items to format `date` with.
+    pub(super) fn items(&mut self, date: &impl Datelike) -> &[Item<'a>] {
+        let Some(names) = self.names else {
+            return &self.items;
+        };
+        let month0 = date.month0() as usize;
+        let weekday = date.weekday().num_days_from_sunday() as usize;
+        self.localized.clear();
+        self.localized
+            .extend(self.items.iter().map(|item| match item {
+                Item::Fixed(Fixed::ShortMonthName) => Item::Literal(names.short_months[month0]),
+                Item::Fixed(Fixed::LongMonthName) => Item::Literal(names.months[month0]),
+                Item::Fixed(Fixed::ShortWeekdayName) => {
+                    Item::Literal(names.short_weekdays[weekday])
+                },
+                Item::Fixed(Fixed::LongWeekdayName) => Item::Literal(names.weekdays[weekday]),
+                item => item.clone(),
+            }));
+        &self.localized
+    }
+}
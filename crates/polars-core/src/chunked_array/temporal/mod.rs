@@ -6,6 +6,8 @@ mod date;
 mod datetime;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+mod format;
 #[cfg(feature = "dtype-time")]
 mod time;
 #[cfg(feature = "dtype-date")]
@@ -23,6 +25,10 @@ use regex::Regex;
 pub use time::time_to_time64ns;
 
 pub use self::conversion::*;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+pub use self::format::Locale;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+use self::format::TemporalFormatter;
 #[cfg(feature = "timezones")]
 use crate::prelude::{polars_bail, PolarsResult};
 
@@ -1,6 +1,7 @@
 use std::fmt::Write;
 
 use arrow::temporal_conversions::{time64ns_to_time, NANOSECONDS};
+use chrono::format::StrftimeItems;
 use chrono::Timelike;
 
 use super::*;
@@ -22,6 +23,8 @@ impl TimeChunked {
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(&self, format: &str) -> StringChunked {
         let mut ca: StringChunked = self.apply_kernel_cast(&|arr| {
+            // Parse the format once instead of for every value.
+            let items: Vec<_> = StrftimeItems::new(format).collect();
             let mut buf = String::new();
             let mut mutarr = MutablePlString::with_capacity(arr.len());
 
@@ -30,7 +33,7 @@ impl TimeChunked {
                     None => mutarr.push_null(),
                     Some(v) => {
                         buf.clear();
-                        let timefmt = time64ns_to_time(*v).format(format);
+                        let timefmt = time64ns_to_time(*v).format_with_items(items.iter());
                         write!(buf, "{timefmt}").unwrap();
                         mutarr.push_value(&buf)
                     },
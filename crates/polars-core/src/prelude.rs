@@ -29,10 +29,12 @@ pub use crate::chunked_array::ops::rolling_window::RollingOptionsFixedWindow;
 pub use crate::chunked_array::ops::*;
 #[cfg(feature = "temporal")]
 pub use crate::chunked_array::temporal::conversion::*;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+pub use crate::chunked_array::temporal::Locale;
 pub(crate) use crate::chunked_array::ChunkLenIter;
 pub use crate::chunked_array::ChunkedArray;
 #[cfg(feature = "dtype-categorical")]
-pub use crate::datatypes::string_cache::StringCacheHolder;
+pub use crate::datatypes::string_cache::{StringCacheGuard, StringCacheHolder};
 pub use crate::datatypes::{ArrayCollectIterExt, *};
 pub use crate::error::{
     polars_bail, polars_ensure, polars_err, polars_warn, PolarsError, PolarsResult,
@@ -145,6 +145,63 @@ mod test {
         let out = serde_json::from_str::<DataFrame>(&df_str).unwrap();
         assert!(df.equals_missing(&out));
     }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-duration",
+        feature = "dtype-time",
+        feature = "dtype-categorical",
+        feature = "dtype-decimal"
+    ))]
+    fn test_serde_logical_types() {
+        let ints = Series::new("ints", &[Some(1i64), None, Some(3)]);
+        let strings = Series::new("cat", &[Some("a"), None, Some("b")]);
+        let df = DataFrame::new(vec![
+            ints.cast(&DataType::Date).unwrap().with_name("date"),
+            ints.cast(&DataType::Datetime(
+                TimeUnit::Microseconds,
+                Some("UTC".into()),
+            ))
+            .unwrap()
+            .with_name("datetime"),
+            ints.cast(&DataType::Duration(TimeUnit::Nanoseconds))
+                .unwrap()
+                .with_name("duration"),
+            ints.cast(&DataType::Time).unwrap().with_name("time"),
+            strings
+                .cast(&DataType::Categorical(None, Default::default()))
+                .unwrap(),
+            ints.cast(&DataType::Decimal(Some(10), Some(2)))
+                .unwrap()
+                .with_name("decimal"),
+            Series::full_null("null", 3, &DataType::Null),
+        ])
+        .unwrap();
+
+        let json = serde_json::to_string(&df).unwrap();
+        let out = serde_json::from_str::<DataFrame>(&json).unwrap();
+        assert_eq!(df.schema(), out.schema());
+        assert!(df.equals_missing(&out));
+
+        let bytes = bincode::serialize(&df).unwrap();
+        let out = bincode::deserialize::<DataFrame>(&bytes).unwrap();
+        assert_eq!(df.schema(), out.schema());
+        assert!(df.equals_missing(&out));
+    }
+
+    #[test]
+    fn test_serde_schema() {
+        let schema = sample_dataframe().schema();
+
+        let json = serde_json::to_string(&schema).unwrap();
+        assert_eq!(serde_json::from_str::<Schema>(&json).unwrap(), schema);
+
+        let bytes = bincode::serialize(&schema).unwrap();
+        assert_eq!(bincode::deserialize::<Schema>(&bytes).unwrap(), schema);
+    }
+
     /// test using the `DeserializedOwned` trait
     #[test]
     fn test_serde_df_owned_bincode() {
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt::Formatter;
 
 use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(feature = "dtype-array")]
@@ -70,6 +71,19 @@ impl Serialize for Series {
                 let ca = self.time().unwrap();
                 ca.serialize(serializer)
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                let ca = self.decimal().unwrap();
+                ca.serialize(serializer)
+            },
+            DataType::Null => {
+                let mut state = serializer.serialize_map(Some(4))?;
+                state.serialize_entry("name", self.name())?;
+                state.serialize_entry("datatype", self.dtype())?;
+                state.serialize_entry("bit_settings", &self.get_flags())?;
+                state.serialize_entry("values", &vec![None::<()>; self.len()])?;
+                state.end()
+            },
             dt => {
                 with_match_physical_numeric_polars_type!(dt, |$T| {
                 let ca: &ChunkedArray<$T> = self.as_ref().as_ref().as_ref();
@@ -264,9 +278,20 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Option<Cow<str>>> = map.next_value()?;
                         Ok(Series::new(&name, values).cast(&dt).unwrap())
                     },
-                    dt => {
-                        panic!("{dt:?} dtype deserialization not yet implemented")
+                    #[cfg(feature = "dtype-decimal")]
+                    DataType::Decimal(precision, scale) => {
+                        let values: Vec<Option<i128>> = map.next_value()?;
+                        Ok(Int128Chunked::from_iter_options(&name, values.into_iter())
+                            .into_decimal_unchecked(precision, scale.unwrap_or(0))
+                            .into_series())
+                    },
+                    DataType::Null => {
+                        let values: Vec<Option<()>> = map.next_value()?;
+                        Ok(Series::full_null(&name, values.len(), &DataType::Null))
                     },
+                    dt => Err(de::Error::custom(format!(
+                        "deserialization of dtype {dt:?} is not supported"
+                    ))),
                 }?;
 
                 if let Some(f) = bit_settings {
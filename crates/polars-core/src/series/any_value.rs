@@ -560,6 +560,21 @@ fn any_values_to_array(
 
     let target_dtype = DataType::Array(Box::new(inner_type.clone()), width);
 
+    // Every sub-array gets its own rev-map when casting to a categorical,
+    // the list builder merges those.
+    #[cfg(feature = "dtype-categorical")]
+    if matches!(inner_type, DataType::Categorical(_, _)) {
+        let avs = avs
+            .iter()
+            .map(|av| match av {
+                AnyValue::Array(b, _) => AnyValue::List(b.clone()),
+                av => av.clone(),
+            })
+            .collect::<Vec<_>>();
+        let out = any_values_to_list(&avs, inner_type, strict)?.cast(&target_dtype)?;
+        return Ok(out.array().unwrap().clone());
+    }
+
     // This is handled downstream. The builder will choose the first non null type.
     let mut valid = true;
     #[allow(unused_mut)]
@@ -43,6 +43,63 @@ impl SeriesWrap<CategoricalChunked> {
         let cats = apply(self.0.physical())?;
         Ok(self.finish_with_state(keep_fast_unique, cats))
     }
+
+    /// Apply a group-wise aggregation `agg` on the physical values, respecting the ordering
+    /// of the categories.
+    ///
+    /// With a lexical ordering, the physical values are first replaced by the lexical rank of
+    /// their category and mapped back afterwards.
+    #[cfg(feature = "algorithm_group_by")]
+    fn agg_ordered<F>(&self, groups: &GroupsProxy, agg: F) -> Series
+    where
+        F: Fn(&UInt32Chunked, &GroupsProxy) -> Series,
+    {
+        if !self.0.uses_lexical_ordering() {
+            let cats = agg(self.0.physical(), groups);
+            return self
+                .finish_with_state(false, cats.u32().unwrap().clone())
+                .into_series();
+        }
+
+        let rev_map = self.0.get_rev_map();
+        let categories = rev_map.get_categories();
+        // The local category indices in lexical order.
+        let mut lexical_order = (0..categories.len() as u32).collect::<Vec<_>>();
+        // SAFETY: the indices are in bounds.
+        lexical_order
+            .sort_unstable_by_key(|&idx| unsafe { categories.value_unchecked(idx as usize) });
+        let mut ranks = vec![0u32; lexical_order.len()];
+        for (rank, &idx) in lexical_order.iter().enumerate() {
+            ranks[idx as usize] = rank as u32;
+        }
+
+        // The values under nulls are not necessarily valid categories, so only map the
+        // non-null values.
+        let map_values = |ca: &UInt32Chunked, f: &dyn Fn(u32) -> u32| -> UInt32Chunked {
+            ca.apply_nonnull_values_generic(DataType::UInt32, f)
+        };
+        let cats = match &**rev_map {
+            RevMapping::Local(_, _) => {
+                let ranked = map_values(self.0.physical(), &|cat| ranks[cat as usize]);
+                let out = agg(&ranked, groups);
+                map_values(out.u32().unwrap(), &|rank| lexical_order[rank as usize])
+            },
+            RevMapping::Global(map, _, _) => {
+                let mut to_global = vec![0u32; lexical_order.len()];
+                for (&global, &local) in map.iter() {
+                    to_global[local as usize] = global;
+                }
+                let ranked = map_values(self.0.physical(), &|cat| {
+                    ranks[*map.get(&cat).unwrap() as usize]
+                });
+                let out = agg(&ranked, groups);
+                map_values(out.u32().unwrap(), &|rank| {
+                    to_global[lexical_order[rank as usize] as usize]
+                })
+            },
+        };
+        self.finish_with_state(false, cats).into_series()
+    }
 }
 
 impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
@@ -107,6 +164,16 @@ impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
         list.into_series()
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        self.agg_ordered(groups, |ca, groups| unsafe { ca.agg_min(groups) })
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        self.agg_ordered(groups, |ca, groups| unsafe { ca.agg_max(groups) })
+    }
+
     #[cfg(feature = "algorithm_group_by")]
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
         #[cfg(feature = "performant")]
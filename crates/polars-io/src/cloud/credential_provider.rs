@@ -0,0 +1,246 @@
+// The credential providers are only used by the object stores of the cloud providers.
+#![cfg_attr(
+    not(any(feature = "aws", feature = "azure", feature = "gcp")),
+    allow(dead_code)
+)]
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+#[cfg(any(feature = "aws", feature = "azure"))]
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+#[cfg(feature = "aws")]
+use object_store::aws::{AmazonS3ConfigKey, AwsCredential, AwsCredentialProvider};
+#[cfg(feature = "azure")]
+use object_store::azure::{
+    AzureAccessKey, AzureConfigKey, AzureCredential, AzureCredentialProvider,
+};
+#[cfg(feature = "gcp")]
+use object_store::gcp::{GcpCredential, GcpCredentialProvider};
+use polars_error::*;
+
+/// Credentials are requested again this long before they expire, so that they don't expire
+/// while a request is in flight.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// Credentials to connect to a cloud provider, as returned by a [`CredentialProvider`].
+#[derive(Clone, Debug, Default)]
+pub struct CloudCredentials {
+    /// The credentials as configuration keys and values, see [`CredentialProvider`].
+    pub config: Vec<(String, String)>,
+    /// The time at which the credentials expire. Credentials without an expiry time are
+    /// requested only once.
+    pub expires_at: Option<SystemTime>,
+}
+
+pub type CredentialProviderFunction = Arc<dyn Fn() -> PolarsResult<CloudCredentials> + Send + Sync>;
+
+/// A function that provides the credentials to connect to a cloud provider, e.g. short-lived
+/// credentials from AWS STS or GCP Workload Identity. The function is called again shortly
+/// before the credentials it returned expire.
+///
+/// The credentials are configuration keys and values:
+/// - AWS: `aws_access_key_id`, `aws_secret_access_key` and optionally `aws_session_token`.
+/// - Azure: `bearer_token`, or `azure_storage_account_key` for a shared access key.
+/// - GCP: `bearer_token`.
+#[derive(Clone)]
+pub struct CredentialProvider(CredentialProviderFunction);
+
+impl CredentialProvider {
+    pub fn new(function: CredentialProviderFunction) -> Self {
+        Self(function)
+    }
+
+    #[cfg(feature = "aws")]
+    pub(super) fn into_aws_provider(self) -> AwsCredentialProvider {
+        Arc::new(RefreshingProvider::new(self, parse_aws_credential))
+    }
+
+    #[cfg(feature = "azure")]
+    pub(super) fn into_azure_provider(self) -> AzureCredentialProvider {
+        Arc::new(RefreshingProvider::new(self, parse_azure_credential))
+    }
+
+    #[cfg(feature = "gcp")]
+    pub(super) fn into_gcp_provider(self) -> GcpCredentialProvider {
+        Arc::new(RefreshingProvider::new(self, parse_gcp_credential))
+    }
+
+    fn address(&self) -> usize {
+        Arc::as_ptr(&self.0) as *const () as usize
+    }
+}
+
+impl Debug for CredentialProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CredentialProvider({:#x})", self.address())
+    }
+}
+
+impl PartialEq for CredentialProvider {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CredentialProvider {}
+
+impl Hash for CredentialProvider {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address().hash(state)
+    }
+}
+
+type CachedCredential<T> = Option<(Arc<T>, Option<SystemTime>)>;
+
+/// Adapts a [`CredentialProvider`] to the credential provider of an object store, which
+/// parses the credentials with `parse` and caches them until they expire.
+struct RefreshingProvider<T> {
+    provider: CredentialProvider,
+    parse: fn(&[(String, String)]) -> PolarsResult<T>,
+    cached: Mutex<CachedCredential<T>>,
+}
+
+impl<T> RefreshingProvider<T> {
+    fn new(
+        provider: CredentialProvider,
+        parse: fn(&[(String, String)]) -> PolarsResult<T>,
+    ) -> Self {
+        Self {
+            provider,
+            parse,
+            cached: Mutex::new(None),
+        }
+    }
+
+    fn credential(&self) -> PolarsResult<Arc<T>> {
+        let mut cached = self.cached.lock().unwrap();
+        if let Some((credential, expires_at)) = cached.as_ref() {
+            if expires_at.map_or(true, |t| SystemTime::now() + EXPIRY_MARGIN < t) {
+                return Ok(credential.clone());
+            }
+        }
+        let credentials = (self.provider.0)()?;
+        let credential = Arc::new((self.parse)(&credentials.config)?);
+        *cached = Some((credential.clone(), credentials.expires_at));
+        Ok(credential)
+    }
+}
+
+impl<T> Debug for RefreshingProvider<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RefreshingProvider({:?})", self.provider)
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> object_store::CredentialProvider for RefreshingProvider<T> {
+    type Credential = T;
+
+    async fn get_credential(&self) -> object_store::Result<Arc<T>> {
+        // The provider is a blocking call, e.g. into Python, so let the runtime move its other
+        // tasks off this worker while it runs.
+        tokio::task::block_in_place(|| self.credential()).map_err(|err| {
+            object_store::Error::Generic {
+                store: "CredentialProvider",
+                source: Box::new(err),
+            }
+        })
+    }
+}
+
+#[cfg(feature = "aws")]
+fn parse_aws_credential(config: &[(String, String)]) -> PolarsResult<AwsCredential> {
+    let (mut key_id, mut secret_key, mut token) = (None, None, None);
+    for (key, value) in config {
+        match AmazonS3ConfigKey::from_str(key) {
+            Ok(AmazonS3ConfigKey::AccessKeyId) => key_id = Some(value.clone()),
+            Ok(AmazonS3ConfigKey::SecretAccessKey) => secret_key = Some(value.clone()),
+            Ok(AmazonS3ConfigKey::Token) => token = Some(value.clone()),
+            _ => polars_bail!(ComputeError: "unknown AWS credential key: {}", key),
+        }
+    }
+    let (Some(key_id), Some(secret_key)) = (key_id, secret_key) else {
+        polars_bail!(
+            ComputeError:
+            "AWS credentials must contain an `aws_access_key_id` and an `aws_secret_access_key`"
+        );
+    };
+    Ok(AwsCredential {
+        key_id,
+        secret_key,
+        token,
+    })
+}
+
+#[cfg(feature = "azure")]
+fn parse_azure_credential(config: &[(String, String)]) -> PolarsResult<AzureCredential> {
+    match config {
+        [(key, value)] => match AzureConfigKey::from_str(key) {
+            Ok(AzureConfigKey::Token) => Ok(AzureCredential::BearerToken(value.clone())),
+            Ok(AzureConfigKey::AccessKey) => AzureAccessKey::try_new(value)
+                .map(AzureCredential::AccessKey)
+                .map_err(to_compute_err),
+            _ => polars_bail!(ComputeError: "unknown Azure credential key: {}", key),
+        },
+        _ => polars_bail!(
+            ComputeError:
+            "Azure credentials must contain either a `bearer_token` or an \
+            `azure_storage_account_key`"
+        ),
+    }
+}
+
+#[cfg(feature = "gcp")]
+fn parse_gcp_credential(config: &[(String, String)]) -> PolarsResult<GcpCredential> {
+    match config {
+        [(key, bearer)] if key == "bearer_token" => Ok(GcpCredential {
+            bearer: bearer.clone(),
+        }),
+        _ => polars_bail!(ComputeError: "GCP credentials must contain a `bearer_token`"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn counting_provider(expires_in: Duration) -> (CredentialProvider, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = CredentialProvider::new(Arc::new({
+            let calls = calls.clone();
+            move || {
+                let n = calls.fetch_add(1, Ordering::Relaxed);
+                Ok(CloudCredentials {
+                    config: vec![("token".into(), n.to_string())],
+                    expires_at: Some(SystemTime::now() + expires_in),
+                })
+            }
+        }));
+        (provider, calls)
+    }
+
+    fn parse_token(config: &[(String, String)]) -> PolarsResult<String> {
+        Ok(config[0].1.clone())
+    }
+
+    #[test]
+    fn test_credentials_are_cached_until_they_expire() {
+        let (provider, calls) = counting_provider(Duration::from_secs(3600));
+        let provider = RefreshingProvider::new(provider, parse_token);
+        assert_eq!(*provider.credential().unwrap(), "0");
+        assert_eq!(*provider.credential().unwrap(), "0");
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        // Credentials that expire within the margin are requested again.
+        let (provider, calls) = counting_provider(EXPIRY_MARGIN / 2);
+        let provider = RefreshingProvider::new(provider, parse_token);
+        assert_eq!(*provider.credential().unwrap(), "0");
+        assert_eq!(*provider.credential().unwrap(), "1");
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+}
@@ -32,11 +32,17 @@ fn err_missing_feature(feature: &str, scheme: &str) -> BuildResult {
 fn url_and_creds_to_key(url: &Url, options: Option<&CloudOptions>) -> String {
     // We include credentials as they can expire, so users will send new credentials for the same url.
     let creds = serde_json::to_string(&options).unwrap_or_else(|_| "".into());
+    // Credential providers are not serialized, every provider gets its own object store.
+    let provider = options
+        .and_then(|options| options.credential_provider())
+        .map(|provider| format!("{provider:?}"))
+        .unwrap_or_default();
     format!(
-        "{}://{}<\\creds\\>{}",
+        "{}://{}<\\creds\\>{}{}",
         url.scheme(),
         &url[url::Position::BeforeHost..url::Position::AfterPort],
-        creds
+        creds,
+        provider
     )
 }
 
@@ -51,6 +57,9 @@ pub async fn build_object_store(
 ) -> BuildResult {
     let parsed = parse_url(url).map_err(to_compute_err)?;
     let cloud_location = CloudLocation::from_url(&parsed)?;
+    if let Some(store) = options.and_then(|options| options.object_store()) {
+        return Ok((cloud_location, store.clone()));
+    }
 
     let key = url_and_creds_to_key(&parsed, options);
     let mut allow_cache = true;
@@ -3,6 +3,9 @@ use std::io::Read;
 #[cfg(feature = "aws")]
 use std::path::Path;
 use std::str::FromStr;
+#[cfg(feature = "cloud")]
+use std::sync::Arc;
+use std::time::Duration;
 
 #[cfg(feature = "aws")]
 use object_store::aws::AmazonS3Builder;
@@ -34,6 +37,8 @@ use smartstring::alias::String as SmartString;
 #[cfg(feature = "cloud")]
 use url::Url;
 
+#[cfg(feature = "cloud")]
+use super::CredentialProvider;
 #[cfg(feature = "aws")]
 use crate::pl_async::with_concurrency_budget;
 #[cfg(feature = "aws")]
@@ -54,6 +59,7 @@ type Configs<T> = Vec<(T, String)>;
 
 #[derive(Clone, Debug, PartialEq, Hash, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 /// Options to connect to various cloud providers.
 pub struct CloudOptions {
     #[cfg(feature = "aws")]
@@ -62,13 +68,36 @@ pub struct CloudOptions {
     azure: Option<Configs<AzureConfigKey>>,
     #[cfg(feature = "gcp")]
     gcp: Option<Configs<GoogleConfigKey>>,
+    #[cfg(feature = "cloud")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    credential_provider: Option<CredentialProvider>,
+    #[cfg(feature = "cloud")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    object_store: Option<CustomObjectStore>,
     pub max_retries: usize,
+    /// The time after which a failing request is no longer retried.
+    pub retry_timeout: Duration,
+    /// The time to wait before the first retry, which doubles with every retry.
+    pub init_backoff: Duration,
+    /// The maximum time to wait between retries.
+    pub max_backoff: Duration,
 }
 
+/// The keys of [`CloudOptions::from_untyped_config`] that configure how failing requests are
+/// retried, rather than how to connect to the cloud provider.
+pub const RETRY_CONFIG_KEYS: [&str; 3] = ["retry_timeout", "init_backoff", "max_backoff"];
+
 impl Default for CloudOptions {
     fn default() -> Self {
         Self {
             max_retries: 2,
+            retry_timeout: Duration::from_secs(10),
+            init_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(15),
+            #[cfg(feature = "cloud")]
+            credential_provider: None,
+            #[cfg(feature = "cloud")]
+            object_store: None,
             #[cfg(feature = "aws")]
             aws: Default::default(),
             #[cfg(feature = "azure")]
@@ -79,6 +108,29 @@ impl Default for CloudOptions {
     }
 }
 
+/// An [`ObjectStore`](object_store::ObjectStore) that is read from instead of one built from
+/// the [`CloudOptions`]. Object stores are compared by address.
+#[cfg(feature = "cloud")]
+#[derive(Clone, Debug)]
+struct CustomObjectStore(Arc<dyn object_store::ObjectStore>);
+
+#[cfg(feature = "cloud")]
+impl PartialEq for CustomObjectStore {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(feature = "cloud")]
+impl Eq for CustomObjectStore {}
+
+#[cfg(feature = "cloud")]
+impl std::hash::Hash for CustomObjectStore {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.0) as *const () as usize).hash(state)
+    }
+}
+
 #[allow(dead_code)]
 /// Parse an untype configuration hashmap to a typed configuration for the given configuration key type.
 fn parsed_untyped_config<T, I: IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>>(
@@ -154,14 +206,6 @@ impl FromStr for CloudType {
         polars_bail!(ComputeError: "at least one of the cloud features must be enabled");
     }
 }
-#[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
-fn get_retry_config(max_retries: usize) -> RetryConfig {
-    RetryConfig {
-        backoff: BackoffConfig::default(),
-        max_retries,
-        retry_timeout: std::time::Duration::from_secs(10),
-    }
-}
 
 #[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
 pub(super) fn get_client_options() -> ClientOptions {
@@ -209,6 +253,45 @@ fn read_config(
 }
 
 impl CloudOptions {
+    /// Request the credentials to connect to the cloud provider from `provider`, instead of
+    /// using static credentials. This replaces credentials from the configuration or the
+    /// environment.
+    #[cfg(feature = "cloud")]
+    pub fn with_credential_provider(mut self, provider: CredentialProvider) -> Self {
+        self.credential_provider = Some(provider);
+        self
+    }
+
+    #[cfg(feature = "cloud")]
+    pub fn credential_provider(&self) -> Option<&CredentialProvider> {
+        self.credential_provider.as_ref()
+    }
+
+    /// Read from `store` instead of building an object store from these options.
+    #[cfg(feature = "cloud")]
+    pub fn with_object_store(mut self, store: Arc<dyn object_store::ObjectStore>) -> Self {
+        self.object_store = Some(CustomObjectStore(store));
+        self
+    }
+
+    #[cfg(feature = "cloud")]
+    pub fn object_store(&self) -> Option<&Arc<dyn object_store::ObjectStore>> {
+        self.object_store.as_ref().map(|store| &store.0)
+    }
+
+    #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+    fn retry_config(&self) -> RetryConfig {
+        RetryConfig {
+            backoff: BackoffConfig {
+                init_backoff: self.init_backoff,
+                max_backoff: self.max_backoff,
+                ..Default::default()
+            },
+            max_retries: self.max_retries,
+            retry_timeout: self.retry_timeout,
+        }
+    }
+
     /// Set the configuration for AWS connections. This is the preferred API from rust.
     #[cfg(feature = "aws")]
     pub fn with_aws<I: IntoIterator<Item = (AmazonS3ConfigKey, impl Into<String>)>>(
@@ -234,6 +317,9 @@ impl CloudOptions {
                 builder = builder.with_config(*key, value);
             }
         }
+        if let Some(provider) = &self.credential_provider {
+            builder = builder.with_credentials(provider.clone().into_aws_provider());
+        }
 
         read_config(
             &mut builder,
@@ -307,7 +393,7 @@ impl CloudOptions {
 
         builder
             .with_client_options(get_client_options())
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(self.retry_config())
             .build()
             .map_err(to_compute_err)
     }
@@ -337,11 +423,14 @@ impl CloudOptions {
                 builder = builder.with_config(*key, value);
             }
         }
+        if let Some(provider) = &self.credential_provider {
+            builder = builder.with_credentials(provider.clone().into_azure_provider());
+        }
 
         builder
             .with_client_options(get_client_options())
             .with_url(url)
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(self.retry_config())
             .build()
             .map_err(to_compute_err)
     }
@@ -371,20 +460,56 @@ impl CloudOptions {
                 builder = builder.with_config(*key, value);
             }
         }
+        if let Some(provider) = &self.credential_provider {
+            builder = builder.with_credentials(provider.clone().into_gcp_provider());
+        }
 
         builder
             .with_client_options(get_client_options())
             .with_url(url)
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(self.retry_config())
             .build()
             .map_err(to_compute_err)
     }
 
     /// Parse a configuration from a Hashmap. This is the interface from Python.
+    ///
+    /// Besides the configuration keys of the cloud provider, this accepts the
+    /// [`RETRY_CONFIG_KEYS`] with a number of seconds as value.
     #[allow(unused_variables)]
     pub fn from_untyped_config<I: IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>>(
         url: &str,
         config: I,
+    ) -> PolarsResult<Self> {
+        let (retry_config, config): (Vec<_>, Vec<_>) = config
+            .into_iter()
+            .map(|(key, value)| (key, value.into()))
+            .partition(|(key, _)| RETRY_CONFIG_KEYS.contains(&key.as_ref()));
+        let mut options = Self::from_untyped_provider_config(url, config)?;
+        for (key, value) in retry_config {
+            let duration = value
+                .parse::<f64>()
+                .ok()
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "invalid value '{}' for `{}`, expected a number of seconds",
+                        value, key.as_ref()
+                    )
+                })?;
+            match key.as_ref() {
+                "retry_timeout" => options.retry_timeout = duration,
+                "init_backoff" => options.init_backoff = duration,
+                _ => options.max_backoff = duration,
+            }
+        }
+        Ok(options)
+    }
+
+    #[allow(unused_variables)]
+    fn from_untyped_provider_config(
+        url: &str,
+        config: Vec<(impl AsRef<str>, String)>,
     ) -> PolarsResult<Self> {
         match CloudType::from_str(url)? {
             CloudType::Aws => {
@@ -429,7 +554,29 @@ impl CloudOptions {
 #[cfg(feature = "cloud")]
 #[cfg(test)]
 mod tests {
-    use super::parse_url;
+    use std::time::Duration;
+
+    use super::{parse_url, CloudOptions};
+
+    #[test]
+    fn test_retry_config() {
+        let options = CloudOptions::from_untyped_config(
+            "file:///data.parquet",
+            [("retry_timeout", "30"), ("init_backoff", "0.5")],
+        )
+        .unwrap();
+        assert_eq!(options.retry_timeout, Duration::from_secs(30));
+        assert_eq!(options.init_backoff, Duration::from_millis(500));
+        assert_eq!(options.max_backoff, CloudOptions::default().max_backoff);
+
+        for value in ["-1", "1s", ""] {
+            assert!(CloudOptions::from_untyped_config(
+                "file:///data.parquet",
+                [("max_backoff", value)]
+            )
+            .is_err());
+        }
+    }
 
     #[test]
     fn test_parse_url() {
@@ -0,0 +1,109 @@
+use polars_core::prelude::*;
+use polars_plan::dsl::*;
+
+use crate::dsl::list::{eval_sub_lists, validate_eval_expr};
+use crate::prelude::*;
+
+pub trait IntoArrayNameSpace {
+    fn into_array_name_space(self) -> ArrayNameSpace;
+}
+
+impl IntoArrayNameSpace for ArrayNameSpace {
+    fn into_array_name_space(self) -> ArrayNameSpace {
+        self
+    }
+}
+
+/// Whether `expr` is known to produce as many elements as it gets, so that `arr.eval` can
+/// keep the width of the arrays.
+fn keeps_width(expr: &Expr) -> bool {
+    expr.into_iter().all(|e| match e {
+        Expr::Column(_) | Expr::Alias(_, _) | Expr::BinaryExpr { .. } | Expr::Ternary { .. } => {
+            true
+        },
+        Expr::Cast { .. } | Expr::Sort { .. } => true,
+        Expr::Literal(lv) => !matches!(lv, LiteralValue::Series(_) | LiteralValue::Range { .. }),
+        Expr::Function {
+            function: FunctionExpr::Reverse | FunctionExpr::Shift,
+            ..
+        } => true,
+        #[cfg(feature = "rank")]
+        Expr::Function {
+            function: FunctionExpr::Rank { .. },
+            ..
+        } => true,
+        #[cfg(feature = "cum_agg")]
+        Expr::Function {
+            function:
+                FunctionExpr::CumCount { .. }
+                | FunctionExpr::CumSum { .. }
+                | FunctionExpr::CumProd { .. }
+                | FunctionExpr::CumMin { .. }
+                | FunctionExpr::CumMax { .. },
+            ..
+        } => true,
+        Expr::Function { options, .. } | Expr::AnonymousFunction { options, .. } => {
+            matches!(options.collect_groups, ApplyOptions::ElementWise)
+        },
+        _ => false,
+    })
+}
+
+/// Determine the output field of `arr.eval`.
+///
+/// The result is an array of the same width if the expression is known to keep the number of
+/// elements, e.g. if it is elementwise, otherwise it is a list.
+fn eval_array_field_to_dtype(f: &Field, expr: &Expr) -> PolarsResult<Field> {
+    let DataType::Array(inner, width) = f.data_type() else {
+        polars_bail!(opq = eval, f.data_type());
+    };
+    let schema = Schema::from_iter([Field::new("", inner.as_ref().clone())]);
+    let inner = expr.to_field(&schema, Context::Default)?.dtype;
+    let dtype = if keeps_width(expr) {
+        DataType::Array(Box::new(inner), *width)
+    } else {
+        DataType::List(Box::new(inner))
+    };
+    Ok(Field::new(f.name(), dtype))
+}
+
+pub trait ArrayNameSpaceExtension: IntoArrayNameSpace + Sized {
+    /// Run any [`Expr`] on the elements of these arrays.
+    ///
+    /// The result is an array of the same width if the expression is known to preserve the
+    /// number of elements, e.g. if it is elementwise or `col("").rank()`, otherwise it is a list.
+    fn eval(self, expr: Expr, parallel: bool) -> Expr {
+        let this = self.into_array_name_space();
+
+        let expr2 = expr.clone();
+        let func = move |s: Series| {
+            validate_eval_expr(&expr, "arr.eval")?;
+            let output_field = eval_array_field_to_dtype(s.field().as_ref(), &expr)?;
+            let DataType::Array(inner, _) = s.dtype() else {
+                unreachable!()
+            };
+            let lst = s.cast(&DataType::List(inner.clone()))?.list()?.clone();
+
+            match eval_sub_lists(s.name(), lst, &expr, parallel)? {
+                Some(out) if out.dtype() != output_field.data_type() => {
+                    out.cast(output_field.data_type()).map(Some)
+                },
+                out => Ok(out),
+            }
+        };
+
+        this.0
+            .map(
+                func,
+                // Errors are raised when the expression is run.
+                GetOutput::map_field(move |f| {
+                    eval_array_field_to_dtype(f, &expr2).unwrap_or_else(|_| {
+                        Field::new(f.name(), DataType::Unknown(UnknownKind::Any))
+                    })
+                }),
+            )
+            .with_fmt("arr.eval")
+    }
+}
+
+impl ArrayNameSpaceExtension for ArrayNameSpace {}
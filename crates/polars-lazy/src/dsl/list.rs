@@ -44,7 +44,7 @@ fn offsets_to_groups(offsets: &[i64]) -> Option<GroupsProxy> {
 }
 
 fn run_per_sublist(
-    s: Series,
+    name: &str,
     lst: &ListChunked,
     expr: &Expr,
     parallel: bool,
@@ -99,7 +99,7 @@ fn run_per_sublist(
         return Err(err);
     }
 
-    ca.rename(s.name());
+    ca.rename(name);
 
     if ca.dtype() != output_field.data_type() {
         ca.cast(output_field.data_type()).map(Some)
@@ -139,6 +139,69 @@ fn run_on_group_by_engine(
     Ok(Some(out.with_name(name)))
 }
 
+/// Check that `expr` only refers to the elements of the sub-lists, `name` is the namespace
+/// used in the error messages.
+pub(super) fn validate_eval_expr(expr: &Expr, name: &str) -> PolarsResult<()> {
+    for e in expr.into_iter() {
+        match e {
+            #[cfg(feature = "dtype-categorical")]
+            Expr::Cast {
+                data_type: DataType::Categorical(_, _) | DataType::Enum(_, _),
+                ..
+            } => {
+                polars_bail!(
+                    ComputeError: "casting to categorical not allowed in `{}`", name
+                )
+            },
+            Expr::Column(col_name) => {
+                polars_ensure!(
+                    col_name.is_empty(),
+                    ComputeError:
+                    "named columns are not allowed in `{}`; consider using `element` or `col(\"\")`",
+                    name
+                );
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+/// Evaluate `expr` on the elements of every sub-list of `lst`.
+pub(super) fn eval_sub_lists(
+    name: &str,
+    lst: ListChunked,
+    expr: &Expr,
+    parallel: bool,
+) -> PolarsResult<Option<Series>> {
+    // # fast returns
+    // ensure we get the new schema
+    let output_field = eval_field_to_dtype(lst.ref_field(), expr, true);
+    if lst.is_empty() {
+        return Ok(Some(Series::new_empty(name, output_field.data_type())));
+    }
+    if lst.null_count() == lst.len() {
+        return Ok(Some(
+            lst.into_series()
+                .with_name(name)
+                .cast(output_field.data_type())?,
+        ));
+    }
+
+    let fits_idx_size = lst.get_values_size() <= (IdxSize::MAX as usize);
+    // If a users passes a return type to `apply`, e.g. `return_dtype=pl.Int64`,
+    // this fails as the list builder expects `List<Int64>`, so let's skip that for now.
+    let is_user_apply = || {
+        expr.into_iter().any(|e| matches!(e, Expr::AnonymousFunction { options, .. } if options.fmt_str == MAP_LIST_NAME))
+    };
+
+    if fits_idx_size && lst.null_count() == 0 && !is_user_apply() {
+        run_on_group_by_engine(name, &lst, expr)
+    } else {
+        run_per_sublist(name, &lst, expr, parallel, output_field)
+    }
+}
+
 pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
     /// Run any [`Expr`] on these lists elements
     fn eval(self, expr: Expr, parallel: bool) -> Expr {
@@ -146,51 +209,9 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
 
         let expr2 = expr.clone();
         let func = move |s: Series| {
-            for e in expr.into_iter() {
-                match e {
-                    #[cfg(feature = "dtype-categorical")]
-                    Expr::Cast {
-                        data_type: DataType::Categorical(_, _) | DataType::Enum(_, _),
-                        ..
-                    } => {
-                        polars_bail!(
-                            ComputeError: "casting to categorical not allowed in `list.eval`"
-                        )
-                    },
-                    Expr::Column(name) => {
-                        polars_ensure!(
-                            name.is_empty(),
-                            ComputeError:
-                            "named columns are not allowed in `list.eval`; consider using `element` or `col(\"\")`"
-                        );
-                    },
-                    _ => {},
-                }
-            }
+            validate_eval_expr(&expr, "list.eval")?;
             let lst = s.list()?.clone();
-
-            // # fast returns
-            // ensure we get the new schema
-            let output_field = eval_field_to_dtype(lst.ref_field(), &expr, true);
-            if lst.is_empty() {
-                return Ok(Some(Series::new_empty(s.name(), output_field.data_type())));
-            }
-            if lst.null_count() == lst.len() {
-                return Ok(Some(s.cast(output_field.data_type())?));
-            }
-
-            let fits_idx_size = lst.get_values_size() <= (IdxSize::MAX as usize);
-            // If a users passes a return type to `apply`, e.g. `return_dtype=pl.Int64`,
-            // this fails as the list builder expects `List<Int64>`, so let's skip that for now.
-            let is_user_apply = || {
-                expr.into_iter().any(|e| matches!(e, Expr::AnonymousFunction { options, .. } if options.fmt_str == MAP_LIST_NAME))
-            };
-
-            if fits_idx_size && s.null_count() == 0 && !is_user_apply() {
-                run_on_group_by_engine(s.name(), &lst, &expr)
-            } else {
-                run_per_sublist(s, &lst, &expr, parallel, output_field)
-            }
+            eval_sub_lists(s.name(), lst, &expr, parallel)
         };
 
         this.0
@@ -200,6 +221,23 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
             )
             .with_fmt("eval")
     }
+
+    /// Keep only the elements of every sub-list for which `predicate` is true. The
+    /// elements are referred to with `col("")` in the predicate.
+    fn filter(self, predicate: Expr) -> Expr {
+        let this = self.into_list_name_space();
+
+        let expr = col("").filter(predicate);
+        let func = move |s: Series| {
+            validate_eval_expr(&expr, "list.filter")?;
+            let lst = s.list()?.clone();
+            eval_sub_lists(s.name(), lst, &expr, false)
+        };
+
+        this.0
+            .map(func, GetOutput::same_type())
+            .with_fmt("list.filter")
+    }
 }
 
 impl ListNameSpaceExtension for ListNameSpace {}
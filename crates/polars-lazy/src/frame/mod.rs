@@ -1619,8 +1619,48 @@ impl LazyFrame {
     /// inserted as columns.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest<I: IntoIterator<Item = S>, S: AsRef<str>>(self, cols: I) -> Self {
+        self.unnest_with(cols, UnnestNaming::Fields)
+    }
+
+    /// Unnest the given `Struct` columns, naming the new columns with `naming`.
+    ///
+    /// This allows unnesting structs that have fields with the same names, by prefixing
+    /// or suffixing the field names with the name of the struct column.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with<I: IntoIterator<Item = S>, S: AsRef<str>>(
+        self,
+        cols: I,
+        naming: UnnestNaming,
+    ) -> Self {
         self.map_private(DslFunction::FunctionNode(FunctionNode::Unnest {
             columns: cols.into_iter().map(|s| Arc::from(s.as_ref())).collect(),
+            naming,
+        }))
+    }
+
+    /// Upsample the [`LazyFrame`] at a regular frequency.
+    ///
+    /// Rows are inserted for every `every` interval between the first and last value of
+    /// the sorted `time_column`, with null values in the other columns. When `group_by`
+    /// is not empty, every group is upsampled separately; `maintain_order` keeps the
+    /// order of the groups.
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn upsample<I: IntoIterator<Item = S>, S: AsRef<str>>(
+        self,
+        time_column: &str,
+        every: Duration,
+        group_by: I,
+        maintain_order: bool,
+    ) -> Self {
+        self.map_private(DslFunction::FunctionNode(FunctionNode::Upsample {
+            time_column: Arc::from(time_column),
+            every,
+            group_by: group_by
+                .into_iter()
+                .map(|s| Arc::from(s.as_ref()))
+                .collect(),
+            maintain_order,
+            schema: Default::default(),
         }))
     }
 
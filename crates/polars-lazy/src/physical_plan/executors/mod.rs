@@ -17,6 +17,8 @@ mod scan;
 mod slice;
 mod sort;
 mod stack;
+#[cfg(feature = "tracing")]
+mod traced;
 mod udf;
 mod union;
 mod unique;
@@ -49,6 +51,8 @@ pub(super) use self::scan::*;
 pub(super) use self::slice::*;
 pub(super) use self::sort::*;
 pub(super) use self::stack::*;
+#[cfg(feature = "tracing")]
+pub(super) use self::traced::*;
 pub(super) use self::udf::*;
 pub(super) use self::union::*;
 pub(super) use self::unique::*;
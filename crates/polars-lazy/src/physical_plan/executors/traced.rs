@@ -0,0 +1,57 @@
+use tracing::field::Empty;
+
+use super::*;
+
+/// The nodes of a query that are executed in a `tracing` span.
+pub(crate) enum TracedNode {
+    Scan { format: &'static str },
+    Join { how: JoinType },
+    GroupBy { keys: usize },
+}
+
+impl TracedNode {
+    /// The traced node that `lp` is executed as, if it is traced at all.
+    pub(crate) fn new(lp: &IR) -> Option<Self> {
+        match lp {
+            IR::Scan { scan_type, .. } => Some(Self::Scan {
+                format: scan_type.into(),
+            }),
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } => Some(Self::Scan { format: "Python" }),
+            IR::Join { options, .. } => Some(Self::Join {
+                how: options.args.how.clone(),
+            }),
+            IR::GroupBy { keys, .. } => Some(Self::GroupBy { keys: keys.len() }),
+            _ => None,
+        }
+    }
+}
+
+/// Executes `input` within a `tracing` span, and attaches the height and estimated size of
+/// its output to the span as the `rows` and `bytes` fields.
+pub(crate) struct TracedExec {
+    pub(crate) node: TracedNode,
+    pub(crate) input: Box<dyn Executor>,
+}
+
+impl Executor for TracedExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let span = match &self.node {
+            TracedNode::Scan { format } => {
+                tracing::info_span!("scan", format, rows = Empty, bytes = Empty)
+            },
+            TracedNode::Join { how } => {
+                tracing::info_span!("join", how = %how, rows = Empty, bytes = Empty)
+            },
+            TracedNode::GroupBy { keys } => {
+                tracing::info_span!("group_by", keys, rows = Empty, bytes = Empty)
+            },
+        };
+        let out = span.in_scope(|| self.input.execute(state));
+        if let Ok(df) = &out {
+            span.record("rows", df.height());
+            span.record("bytes", df.estimated_size());
+        }
+        out
+    }
+}
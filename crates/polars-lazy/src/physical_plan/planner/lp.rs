@@ -152,6 +152,20 @@ fn create_physical_plan_impl(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     state: &ConversionState,
+) -> PolarsResult<Box<dyn Executor>> {
+    #[cfg(feature = "tracing")]
+    if let Some(node) = executors::TracedNode::new(lp_arena.get(root)) {
+        let input = create_node_executor(root, lp_arena, expr_arena, state)?;
+        return Ok(Box::new(executors::TracedExec { node, input }));
+    }
+    create_node_executor(root, lp_arena, expr_arena, state)
+}
+
+fn create_node_executor(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    state: &ConversionState,
 ) -> PolarsResult<Box<dyn Executor>> {
     use IR::*;
 
@@ -40,3 +40,55 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
     };
     supported && !args.validation.needs_checks()
 }
+
+/// The streaming join hashes the physical values of categorical keys, so it can only be used
+/// if the categoricals of both sides are known to share their encoding.
+#[cfg(feature = "dtype-categorical")]
+pub(super) fn streamable_join_keys(
+    input_left: Node,
+    input_right: Node,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    use polars_core::prelude::DataType;
+
+    let schema_left = lp_arena.get(input_left).schema(lp_arena);
+    let schema_right = lp_arena.get(input_right).schema(lp_arena);
+    let string_cache = polars_core::using_string_cache();
+    left_on.iter().zip(right_on).all(|(l, r)| {
+        let dtype_l = expr_arena
+            .get(l.node())
+            .get_type(&schema_left, Context::Default, expr_arena);
+        let dtype_r =
+            expr_arena
+                .get(r.node())
+                .get_type(&schema_right, Context::Default, expr_arena);
+        match (dtype_l, dtype_r) {
+            (Ok(DataType::Categorical(rev_map_l, _)), Ok(DataType::Categorical(rev_map_r, _))) => {
+                match (rev_map_l, rev_map_r) {
+                    (Some(l), Some(r)) => l.same_src(&r),
+                    (l, r) => {
+                        string_cache
+                            && !l.is_some_and(|l| l.is_local())
+                            && !r.is_some_and(|r| r.is_local())
+                    },
+                }
+            },
+            _ => true,
+        }
+    })
+}
+
+#[cfg(not(feature = "dtype-categorical"))]
+pub(super) fn streamable_join_keys(
+    _input_left: Node,
+    _input_right: Node,
+    _left_on: &[ExprIR],
+    _right_on: &[ExprIR],
+    _lp_arena: &Arena<IR>,
+    _expr_arena: &Arena<AExpr>,
+) -> bool {
+    true
+}
@@ -249,9 +249,20 @@ pub(crate) fn insert_streaming_nodes(
             Join {
                 input_left,
                 input_right,
+                left_on,
+                right_on,
                 options,
                 ..
-            } if streamable_join(&options.args) => {
+            } if streamable_join(&options.args)
+                && streamable_join_keys(
+                    *input_left,
+                    *input_right,
+                    left_on,
+                    right_on,
+                    lp_arena,
+                    expr_arena,
+                ) =>
+            {
                 let input_left = *input_left;
                 let input_right = *input_right;
                 state.streamable = true;
@@ -14,9 +14,9 @@ pub use polars_plan::logical_plan::{
     AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
     NULL,
 };
-pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
-#[cfg(feature = "rolling_window_by")]
+pub use polars_plan::prelude::{UnionArgs, UnnestNameMapper, UnnestNaming};
+#[cfg(any(feature = "rolling_window_by", feature = "dynamic_group_by"))]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
 pub use polars_time::{DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions};
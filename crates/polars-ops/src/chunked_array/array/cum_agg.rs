@@ -0,0 +1,39 @@
+use super::*;
+use crate::series::{cum_max, cum_min, cum_sum};
+
+type CumAggFn = fn(&Series, bool) -> PolarsResult<Series>;
+
+/// Run the cumulative aggregation `func` within every sub-array, the width is preserved.
+fn cum_agg_sub_arrays(
+    ca: &ArrayChunked,
+    reverse: bool,
+    func: CumAggFn,
+) -> PolarsResult<ArrayChunked> {
+    // Resolve the output dtype up front, this also raises on unsupported dtypes.
+    let inner_dtype = ca.inner_dtype();
+    let out_dtype = func(&Series::new_empty("", &inner_dtype), reverse)?
+        .dtype()
+        .clone();
+
+    let ca = if out_dtype != inner_dtype {
+        let s = ca.cast(&DataType::Array(Box::new(out_dtype), ca.width()))?;
+        s.array().unwrap().clone()
+    } else {
+        ca.clone()
+    };
+    // SAFETY: the sub-arrays already have the output dtype and cumulative aggregations
+    // preserve the number of elements.
+    unsafe { ca.try_apply_amortized_same_type(|s| func(s.as_ref(), reverse)) }
+}
+
+pub(super) fn array_cum_sum(ca: &ArrayChunked, reverse: bool) -> PolarsResult<ArrayChunked> {
+    cum_agg_sub_arrays(ca, reverse, cum_sum)
+}
+
+pub(super) fn array_cum_min(ca: &ArrayChunked, reverse: bool) -> PolarsResult<ArrayChunked> {
+    cum_agg_sub_arrays(ca, reverse, cum_min)
+}
+
+pub(super) fn array_cum_max(ca: &ArrayChunked, reverse: bool) -> PolarsResult<ArrayChunked> {
+    cum_agg_sub_arrays(ca, reverse, cum_max)
+}
@@ -1,3 +1,6 @@
+use polars_core::chunked_array::collect::ChunkedCollectInferIterExt;
+use polars_core::series::unstable::UnstableSeries;
+
 use super::*;
 
 pub(super) fn median_with_nulls(ca: &ArrayChunked) -> PolarsResult<Series> {
@@ -92,3 +95,67 @@ pub(super) fn var_with_nulls(ca: &ArrayChunked, ddof: u8) -> PolarsResult<Series
     out.rename(ca.name());
     Ok(out)
 }
+
+fn quantile_sub_arrays<T>(
+    ca: &ArrayChunked,
+    quantile: &Float64Chunked,
+    interpol: QuantileInterpolOptions,
+) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsNumericType,
+{
+    let quantile_of = |s: Option<UnstableSeries>, q: Option<f64>| match (s, q) {
+        (Some(s), Some(q)) => Ok(s
+            .as_ref()
+            .quantile_reduce(q, interpol)?
+            .value()
+            .extract::<T::Native>()),
+        _ => Ok(None),
+    };
+
+    match quantile.len() {
+        1 => {
+            let q = quantile.get(0);
+            ca.try_apply_amortized_generic(|s| quantile_of(s, q))
+        },
+        len if len == ca.len() => ca
+            .amortized_iter()
+            .zip(quantile)
+            .map(|(s, q)| quantile_of(s, q))
+            .try_collect_ca(ca.name()),
+        len => polars_bail!(
+            ComputeError:
+            "`arr.quantile` expression got a quantile array of length {} while the array has {} elements",
+            len, ca.len()
+        ),
+    }
+}
+
+pub(super) fn quantile_with_nulls(
+    ca: &ArrayChunked,
+    quantile: &Float64Chunked,
+    interpol: QuantileInterpolOptions,
+) -> PolarsResult<Series> {
+    let out = match ca.inner_dtype() {
+        DataType::Float32 => {
+            quantile_sub_arrays::<Float32Type>(ca, quantile, interpol)?.into_series()
+        },
+        dt if dt.is_numeric() => {
+            quantile_sub_arrays::<Float64Type>(ca, quantile, interpol)?.into_series()
+        },
+        // Compute the quantile of the physical values and cast it back to the logical dtype.
+        dt if dt.is_temporal() => {
+            let physical = dt.to_physical();
+            let ca = ca.cast(&DataType::Array(Box::new(physical.clone()), ca.width()))?;
+            quantile_sub_arrays::<Float64Type>(ca.array()?, quantile, interpol)?
+                .into_series()
+                .cast(&DataType::Int64)?
+                .cast(&physical)?
+                .cast(&dt)?
+        },
+        dt => {
+            polars_bail!(InvalidOperation: "`quantile` operation not supported for dtype `{}`", dt)
+        },
+    };
+    Ok(out.with_name(ca.name()))
+}
@@ -11,9 +11,9 @@ fn array_get_literal(ca: &ArrayChunked, idx: i64, null_on_oob: bool) -> PolarsRe
         .downcast_iter()
         .map(|arr| sub_fixed_size_list_get_literal(arr, idx, null_on_oob))
         .collect::<PolarsResult<Vec<_>>>()?;
-    Series::try_from((ca.name(), chunks))
-        .unwrap()
-        .cast(&ca.inner_dtype())
+    let s = Series::try_from((ca.name(), chunks)).unwrap();
+    // SAFETY: every value is taken from the sub-arrays, so it is valid for the inner dtype.
+    unsafe { s.cast_unchecked(&ca.inner_dtype()) }
 }
 
 /// Get the value by literal index in the array.
@@ -38,7 +38,8 @@ pub fn array_get(
             let out = binary_to_series_arr_get(ca, index, null_on_oob, |arr, idx, nob| {
                 sub_fixed_size_list_get(arr, idx, nob)
             });
-            out?.cast(&ca.inner_dtype())
+            // SAFETY: every value is taken from the sub-arrays, so it is valid for the inner dtype.
+            unsafe { out?.cast_unchecked(&ca.inner_dtype()) }
         },
         len => polars_bail!(
             ComputeError:
@@ -90,7 +90,12 @@ pub fn array_join(
                 Some(separator) => join_literal(ca, separator, ignore_nulls),
                 _ => Ok(StringChunked::full_null(ca.name(), ca.len())),
             },
-            _ => join_many(ca, separator, ignore_nulls),
+            len if len == ca.len() => join_many(ca, separator, ignore_nulls),
+            len => polars_bail!(
+                ComputeError:
+                "`arr.join` expression got a separator of length {} while the array has {} elements",
+                len, ca.len()
+            ),
         },
         dt => polars_bail!(op = "`array.join`", got = dt, expected = "String"),
     }
@@ -1,4 +1,7 @@
+use argminmax::ArgMinMax;
 use arrow::array::{Array, PrimitiveArray};
+use arrow::bitmap::Bitmap;
+use arrow::legacy::utils::CustomIterTools;
 use polars_compute::min_max::MinMaxKernel;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
@@ -84,3 +87,45 @@ pub(super) fn array_dispatch(
     });
     Series::try_from((name, chunks)).unwrap()
 }
+
+fn arg_agg<T>(
+    values: &PrimitiveArray<T>,
+    width: usize,
+    agg_type: &AggType,
+    validity: Option<&Bitmap>,
+) -> IdxArr
+where
+    T: NumericNative,
+    for<'b> &'b [T]: ArgMinMax,
+{
+    let idx: Vec<IdxSize> = values
+        .values()
+        .as_slice()
+        .chunks_exact(width)
+        .map(|sl| match agg_type {
+            AggType::Min => sl.argmin() as IdxSize,
+            AggType::Max => sl.argmax() as IdxSize,
+        })
+        .collect_trusted();
+    IdxArr::from_data_default(idx.into(), validity.cloned())
+}
+
+/// Fast path for `arr.arg_min` and `arr.arg_max` that works directly on the values
+/// buffer. Returns `None` if the inner values are not numeric or contain nulls.
+pub(super) fn array_arg_dispatch(ca: &ArrayChunked, agg_type: AggType) -> Option<IdxCa> {
+    let width = ca.width();
+    let values = ca.get_inner().to_physical_repr().into_owned();
+    if width == 0 || !values.dtype().is_numeric() || values.null_count() > 0 {
+        return None;
+    }
+
+    let chunks: Vec<IdxArr> = with_match_physical_numeric_polars_type!(values.dtype(), |$T| {
+        let values: &ChunkedArray<$T> = values.as_ref().as_ref().as_ref();
+        values
+            .downcast_iter()
+            .zip(ca.downcast_iter())
+            .map(|(values, arr)| arg_agg(values, width, &agg_type, arr.validity()))
+            .collect()
+    });
+    Some(IdxCa::from_chunk_iter(ca.name(), chunks))
+}
@@ -1,17 +1,24 @@
 #[cfg(feature = "array_any_all")]
 mod any_all;
 mod count;
+#[cfg(feature = "cum_agg")]
+mod cum_agg;
 mod dispersion;
 mod get;
 mod join;
 mod min_max;
 mod namespace;
+mod product;
+mod reshape;
+mod reverse;
 mod sum_mean;
 #[cfg(feature = "array_to_struct")]
 mod to_struct;
+mod zip_with;
 
 pub use namespace::ArrayNameSpace;
 use polars_core::prelude::*;
+pub use reshape::{flatten_array_dtype, reshape_array_dtype};
 #[cfg(feature = "array_to_struct")]
 pub use to_struct::*;
 
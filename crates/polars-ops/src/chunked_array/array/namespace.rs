@@ -1,14 +1,30 @@
+use polars_core::export::rayon::prelude::*;
+use polars_core::utils::_split_offsets;
+use polars_core::POOL;
+
 use super::min_max::AggType;
 use super::*;
 #[cfg(feature = "array_count")]
 use crate::chunked_array::array::count::array_count_matches;
 use crate::chunked_array::array::count::count_boolean_bits;
-use crate::chunked_array::array::sum_mean::sum_with_nulls;
+#[cfg(feature = "cum_agg")]
+use crate::chunked_array::array::cum_agg::{array_cum_max, array_cum_min, array_cum_sum};
+use crate::chunked_array::array::sum_mean::{mean_with_nulls, sum_with_nulls};
 #[cfg(feature = "array_any_all")]
 use crate::prelude::array::any_all::{array_all, array_any};
 use crate::prelude::array::get::array_get;
 use crate::prelude::array::join::array_join;
-use crate::prelude::array::sum_mean::sum_array_numerical;
+use crate::prelude::array::product::array_product;
+use crate::prelude::array::reshape::{array_flatten, array_reshape};
+use crate::prelude::array::reverse::array_reverse;
+use crate::prelude::array::sum_mean::{mean_array_numerical, sum_array_numerical};
+use crate::prelude::array::zip_with::array_zip_with;
+#[cfg(feature = "list_sample")]
+use crate::prelude::ListNameSpaceImpl;
+#[cfg(feature = "list_sets")]
+use crate::prelude::{list_set_operation, SetOperation};
+#[cfg(feature = "rank")]
+use crate::prelude::{RankMethod, RankOptions, SeriesRank};
 use crate::series::ArgAgg;
 
 pub fn has_inner_nulls(ca: &ArrayChunked) -> bool {
@@ -20,6 +36,11 @@ pub fn has_inner_nulls(ca: &ArrayChunked) -> bool {
     false
 }
 
+fn sort_sub_arrays(ca: &ArrayChunked, options: SortOptions) -> PolarsResult<ArrayChunked> {
+    // SAFETY: Sort only changes the order of the elements in each subarray.
+    unsafe { ca.try_apply_amortized_same_type(|s| s.as_ref().sort_with(options)) }
+}
+
 fn get_agg(ca: &ArrayChunked, agg_type: AggType) -> Series {
     let values = ca.get_inner();
     let width = ca.width();
@@ -51,11 +72,40 @@ pub trait ArrayNameSpace: AsArray {
         }
     }
 
+    fn array_mean(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+
+        if has_inner_nulls(ca) || ca.width() == 0 {
+            return mean_with_nulls(ca);
+        };
+
+        match ca.inner_dtype() {
+            dt if dt.is_numeric() => mean_array_numerical(ca, &dt),
+            _ => mean_with_nulls(ca),
+        }
+    }
+
+    fn array_product(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_product(ca)
+    }
+
     fn array_median(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
         dispersion::median_with_nulls(ca)
     }
 
+    /// Compute the quantile of every sub-array. The `quantile` is either a single value or
+    /// one value per sub-array.
+    fn array_quantile(
+        &self,
+        quantile: &Float64Chunked,
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        dispersion::quantile_with_nulls(ca, quantile, interpol)
+    }
+
     fn array_std(&self, ddof: u8) -> PolarsResult<Series> {
         let ca = self.as_array();
         dispersion::std_with_nulls(ca, ddof)
@@ -76,6 +126,25 @@ pub trait ArrayNameSpace: AsArray {
         ca.try_apply_amortized_to_list(|s| s.as_ref().unique_stable())
     }
 
+    /// Remove the null elements of every sub-array. As the number of remaining elements
+    /// differs per row, the output is a list.
+    fn array_drop_nulls(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        let list_dtype = DataType::List(Box::new(ca.inner_dtype()));
+        if !has_inner_nulls(ca) {
+            return ca.cast(&list_dtype).map(|s| s.list().unwrap().clone());
+        }
+        ca.try_apply_amortized_to_list(|s| Ok(s.as_ref().drop_nulls()))
+    }
+
+    #[cfg(feature = "mode")]
+    /// Compute the most occurring value(s) of every sub-array. As there may be ties, the
+    /// output is a list.
+    fn array_mode(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        ca.try_apply_amortized_to_list(|s| crate::chunked_array::mode::mode(s.as_ref()))
+    }
+
     fn array_n_unique(&self) -> PolarsResult<IdxCa> {
         let ca = self.as_array();
         ca.try_apply_amortized_generic(|opt_s| {
@@ -98,18 +167,91 @@ pub trait ArrayNameSpace: AsArray {
 
     fn array_sort(&self, options: SortOptions) -> PolarsResult<ArrayChunked> {
         let ca = self.as_array();
-        // SAFETY: Sort only changes the order of the elements in each subarray.
-        unsafe { ca.try_apply_amortized_same_type(|s| s.as_ref().sort_with(options)) }
+        let n_threads = POOL.current_num_threads();
+        if !options.multithreaded || n_threads == 1 || ca.len() < 2 * n_threads {
+            return sort_sub_arrays(ca, options);
+        }
+
+        // The sub-arrays are sorted independently, so we split the rows over the
+        // threads and sort the individual sub-arrays single threaded.
+        let options = SortOptions {
+            multithreaded: false,
+            ..options
+        };
+        let offsets = _split_offsets(ca.len(), n_threads);
+        let parts = POOL.install(|| {
+            offsets
+                .into_par_iter()
+                .map(|(offset, len)| sort_sub_arrays(&ca.slice(offset as i64, len), options))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut parts = parts.into_iter();
+        let mut out = parts.next().unwrap();
+        for part in parts {
+            out.append(&part)?;
+        }
+        Ok(out)
     }
 
     fn array_reverse(&self) -> ArrayChunked {
         let ca = self.as_array();
-        // SAFETY: Reverse only changes the order of the elements in each subarray
-        unsafe { ca.apply_amortized_same_type(|s| s.as_ref().reverse()) }
+        array_reverse(ca)
+    }
+
+    #[cfg(feature = "cum_agg")]
+    /// Compute the cumulative sum within every sub-array.
+    fn array_cum_sum(&self, reverse: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_cum_sum(ca, reverse)
+    }
+
+    #[cfg(feature = "cum_agg")]
+    /// Compute the cumulative min within every sub-array.
+    fn array_cum_min(&self, reverse: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_cum_min(ca, reverse)
+    }
+
+    #[cfg(feature = "cum_agg")]
+    /// Compute the cumulative max within every sub-array.
+    fn array_cum_max(&self, reverse: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_cum_max(ca, reverse)
+    }
+
+    #[cfg(feature = "rank")]
+    /// Rank the elements within every sub-array, the width is preserved.
+    fn array_rank(&self, options: RankOptions, seed: Option<u64>) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let out_dtype = match options.method {
+            RankMethod::Average => DataType::Float64,
+            _ => IDX_DTYPE,
+        };
+        let out = ca.try_apply_amortized_to_list(|s| Ok(s.as_ref().rank(options, seed)))?;
+        let out = out.cast(&DataType::Array(Box::new(out_dtype), ca.width()))?;
+        Ok(out.array().unwrap().clone())
+    }
+
+    /// Merge the two outer levels of a nested array, so `Array(Array(T, m), n)` becomes
+    /// `Array(T, n * m)`.
+    fn array_flatten(&self) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_flatten(ca)
+    }
+
+    /// Reshape the elements of every sub-array into a (nested) array of `dimensions`, one
+    /// dimension may be `-1` to infer it from the others.
+    fn array_reshape(&self, dimensions: &[i64]) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_reshape(ca, dimensions)
     }
 
     fn array_arg_min(&self) -> IdxCa {
         let ca = self.as_array();
+        if let Some(out) = min_max::array_arg_dispatch(ca, AggType::Min) {
+            return out;
+        }
         ca.apply_amortized_generic(|opt_s| {
             opt_s.and_then(|s| s.as_ref().arg_min().map(|idx| idx as IdxSize))
         })
@@ -117,6 +259,9 @@ pub trait ArrayNameSpace: AsArray {
 
     fn array_arg_max(&self) -> IdxCa {
         let ca = self.as_array();
+        if let Some(out) = min_max::array_arg_dispatch(ca, AggType::Max) {
+            return out;
+        }
         ca.apply_amortized_generic(|opt_s| {
             opt_s.and_then(|s| s.as_ref().arg_max().map(|idx| idx as IdxSize))
         })
@@ -156,7 +301,7 @@ pub trait ArrayNameSpace: AsArray {
                     )
                 }
             },
-            _ => {
+            len if len == ca.len() => {
                 // SAFETY: Shift does not change the dtype and number of elements of sub-array.
                 unsafe {
                     ca.zip_and_apply_amortized_same_type(n, |opt_s, opt_periods| {
@@ -167,9 +312,57 @@ pub trait ArrayNameSpace: AsArray {
                     })
                 }
             },
+            len => polars_bail!(
+                ComputeError:
+                "`arr.shift` expression got an `n` of length {} while the array has {} elements",
+                len, ca.len()
+            ),
         };
         Ok(out.into_series())
     }
+
+    /// Take the elements of these sub-arrays where `mask` is `true` and the elements of
+    /// `other` elsewhere.
+    fn array_zip_with(&self, mask: &Series, other: &Series) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_zip_with(ca, mask.array()?, other.array()?)
+    }
+
+    #[cfg(feature = "list_sample")]
+    /// Sample `n` elements of every sub-array, `n` is either a single value or one value per
+    /// sub-array. As `n` may differ per row, the output is a list.
+    fn array_sample_n(
+        &self,
+        n: &Series,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        let lst = ca.cast(&DataType::List(Box::new(ca.inner_dtype())))?;
+        lst.list()?.lst_sample_n(n, with_replacement, shuffle, seed)
+    }
+
+    #[cfg(feature = "list_sets")]
+    /// Compute a set operation between the sub-arrays and the elements of `other`, which
+    /// may be an array or a list column. As the number of elements in the result differs
+    /// per row, the output is a list.
+    fn array_set_operation(
+        &self,
+        other: &Series,
+        set_op: SetOperation,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        let list_dtype = DataType::List(Box::new(ca.inner_dtype()));
+        let other = match other.dtype() {
+            DataType::Array(_, _) | DataType::List(_) => other.cast(&list_dtype)?,
+            dt => polars_bail!(
+                SchemaMismatch: "invalid series dtype: expected `Array` or `List`, got `{}`", dt
+            ),
+        };
+        let lhs = ca.cast(&list_dtype)?;
+        list_set_operation(lhs.list()?, other.list()?, set_op)
+    }
 }
 
 impl ArrayNameSpace for ArrayChunked {}
@@ -0,0 +1,71 @@
+use arrow::array::{Array, PrimitiveArray};
+use arrow::legacy::utils::CustomIterTools;
+use polars_core::export::num::One;
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::*;
+
+/// The data type of the product of the elements of `dtype`, small integers are upcasted
+/// to `Int64` to reduce the chance of overflows.
+pub(super) fn product_dtype(dtype: &DataType) -> DataType {
+    use DataType::*;
+    match dtype {
+        Float32 => Float32,
+        Float64 => Float64,
+        UInt64 => UInt64,
+        _ => Int64,
+    }
+}
+
+fn product_sub_arrays<T>(ca: &ArrayChunked) -> Series
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: IntoSeries,
+{
+    let width = ca.width();
+    let chunks = ca.downcast_iter().map(|arr| {
+        let values = arr
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T::Native>>()
+            .unwrap();
+
+        let products: Vec<T::Native> = (0..arr.len())
+            .map(|i| {
+                let start = i * width;
+                if values.null_count() == 0 {
+                    let slice = &values.values()[start..start + width];
+                    slice.iter().fold(T::Native::one(), |acc, v| acc * *v)
+                } else {
+                    (start..start + width)
+                        .filter(|j| values.is_valid(*j))
+                        .fold(T::Native::one(), |acc, j| acc * values.value(j))
+                }
+            })
+            .collect_trusted();
+
+        PrimitiveArray::from_data_default(products.into(), arr.validity().cloned())
+    });
+    ChunkedArray::<T>::from_chunk_iter(ca.name(), chunks).into_series()
+}
+
+/// Compute the product of the elements in every sub-array, null elements are ignored.
+pub(super) fn array_product(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let inner_dtype = ca.inner_dtype();
+    polars_ensure!(
+        inner_dtype.is_numeric() || inner_dtype.is_bool(),
+        InvalidOperation: "`product` operation not supported for dtype `{}`", inner_dtype
+    );
+
+    let dtype = product_dtype(&inner_dtype);
+    let ca = if inner_dtype != dtype {
+        ca.cast(&DataType::Array(Box::new(dtype.clone()), ca.width()))?
+    } else {
+        ca.clone().into_series()
+    };
+    let ca = ca.array().unwrap();
+
+    Ok(with_match_physical_numeric_polars_type!(dtype, |$T| {
+        product_sub_arrays::<$T>(ca)
+    }))
+}
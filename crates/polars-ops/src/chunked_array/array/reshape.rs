@@ -0,0 +1,146 @@
+use arrow::array::FixedSizeListArray;
+use arrow::bitmap::MutableBitmap;
+use arrow::compute::utils::combine_validities_and;
+
+use super::*;
+
+/// The leaf data type of a (nested) array dtype and the number of leaf elements in every
+/// sub-array.
+fn leaf_dtype_and_width(dtype: &DataType) -> (DataType, usize) {
+    match dtype {
+        DataType::Array(inner, width) => {
+            let (leaf, inner_width) = leaf_dtype_and_width(inner);
+            (leaf, inner_width * width)
+        },
+        dt => (dt.clone(), 1),
+    }
+}
+
+/// Resolve the `dimensions` of a reshape of `n_elements`, one dimension may be `-1`, in which
+/// case it is inferred.
+fn infer_dimensions(n_elements: usize, dimensions: &[i64]) -> PolarsResult<Vec<usize>> {
+    polars_ensure!(!dimensions.is_empty(), ComputeError: "reshape `dimensions` cannot be empty");
+
+    let mut infer_idx = None;
+    let mut known = 1;
+    for (i, &dim) in dimensions.iter().enumerate() {
+        match dim {
+            -1 => {
+                polars_ensure!(
+                    infer_idx.is_none(),
+                    ComputeError: "can only infer a single dimension, got shape {:?}", dimensions
+                );
+                infer_idx = Some(i);
+            },
+            dim if dim > 0 => known *= dim as usize,
+            dim => polars_bail!(
+                ComputeError: "invalid dimension {} in shape {:?}, dimensions must be positive or -1",
+                dim, dimensions
+            ),
+        }
+    }
+
+    let mut out = dimensions
+        .iter()
+        .map(|&dim| dim.max(0) as usize)
+        .collect::<Vec<_>>();
+    if let Some(i) = infer_idx {
+        out[i] = n_elements / known;
+    }
+    polars_ensure!(
+        out.iter().product::<usize>() == n_elements && n_elements > 0,
+        ComputeError: "cannot reshape sub-arrays of {} elements into shape {:?}", n_elements, dimensions
+    );
+    Ok(out)
+}
+
+/// The data type of `arr.flatten` on `dtype`, which must be a nested array.
+pub fn flatten_array_dtype(dtype: &DataType) -> PolarsResult<DataType> {
+    match dtype {
+        DataType::Array(inner, width) => match inner.as_ref() {
+            DataType::Array(leaf, inner_width) => {
+                Ok(DataType::Array(leaf.clone(), width * inner_width))
+            },
+            _ => polars_bail!(
+                InvalidOperation: "`arr.flatten` expected a nested array, got `{}`", dtype
+            ),
+        },
+        _ => polars_bail!(opq = flatten, dtype),
+    }
+}
+
+/// The data type of `arr.reshape` with `dimensions` on `dtype`.
+pub fn reshape_array_dtype(dtype: &DataType, dimensions: &[i64]) -> PolarsResult<DataType> {
+    polars_ensure!(matches!(dtype, DataType::Array(..)), opq = reshape, dtype);
+    let (leaf, n_elements) = leaf_dtype_and_width(dtype);
+    let dimensions = infer_dimensions(n_elements, dimensions)?;
+    Ok(dimensions
+        .iter()
+        .rev()
+        .fold(leaf, |dt, &width| DataType::Array(Box::new(dt), width)))
+}
+
+/// The values of `arr` where the elements of the null sub-arrays are set to null.
+fn values_with_propagated_validity(arr: &FixedSizeListArray) -> ArrayRef {
+    let values = arr.values();
+    match arr.validity() {
+        Some(validity) if validity.unset_bits() > 0 => {
+            let mut repeated = MutableBitmap::with_capacity(values.len());
+            for valid in validity.iter() {
+                repeated.extend_constant(arr.size(), valid);
+            }
+            let validity = combine_validities_and(values.validity(), Some(&repeated.into()));
+            values.with_validity(validity)
+        },
+        _ => values.clone(),
+    }
+}
+
+fn flatten_fixed_size_list(arr: &FixedSizeListArray) -> ArrayRef {
+    let inner = arr
+        .values()
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    let values = values_with_propagated_validity(inner);
+    let data_type =
+        FixedSizeListArray::default_datatype(values.data_type().clone(), arr.size() * inner.size());
+    FixedSizeListArray::new(data_type, values, arr.validity().cloned()).boxed()
+}
+
+fn reshape_fixed_size_list(arr: &FixedSizeListArray, dimensions: &[usize]) -> ArrayRef {
+    let mut values = arr.values().clone();
+    while let Some(inner) = values.as_any().downcast_ref::<FixedSizeListArray>() {
+        values = values_with_propagated_validity(inner);
+    }
+
+    for &width in dimensions[1..].iter().rev() {
+        let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), width);
+        values = FixedSizeListArray::new(data_type, values, None).boxed();
+    }
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), dimensions[0]);
+    FixedSizeListArray::new(data_type, values, arr.validity().cloned()).boxed()
+}
+
+/// Merge the two outer levels of a nested array. Only the validities of the inner
+/// sub-arrays are rewritten, the values are not copied.
+pub(super) fn array_flatten(ca: &ArrayChunked) -> PolarsResult<ArrayChunked> {
+    let dtype = flatten_array_dtype(ca.dtype())?;
+    let chunks = ca.downcast_iter().map(flatten_fixed_size_list).collect();
+    // SAFETY: the chunks hold the physical values of `dtype`.
+    Ok(unsafe { ArrayChunked::from_chunks_and_dtype(ca.name(), chunks, dtype) })
+}
+
+/// Reshape the elements of every sub-array into a (nested) array of `dimensions`. Only the
+/// validities of the inner sub-arrays are rewritten, the values are not copied.
+pub(super) fn array_reshape(ca: &ArrayChunked, dimensions: &[i64]) -> PolarsResult<ArrayChunked> {
+    let dtype = reshape_array_dtype(ca.dtype(), dimensions)?;
+    let (_, n_elements) = leaf_dtype_and_width(ca.dtype());
+    let dimensions = infer_dimensions(n_elements, dimensions)?;
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| reshape_fixed_size_list(arr, &dimensions))
+        .collect();
+    // SAFETY: the chunks hold the physical values of `dtype`.
+    Ok(unsafe { ArrayChunked::from_chunks_and_dtype(ca.name(), chunks, dtype) })
+}
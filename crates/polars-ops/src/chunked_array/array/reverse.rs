@@ -0,0 +1,30 @@
+use arrow::array::{Array, FixedSizeListArray};
+use arrow::compute::take::take_unchecked;
+
+use super::*;
+
+fn reverse_indices(width: usize, len: usize) -> IdxArr {
+    let mut idx = Vec::with_capacity(width * len);
+    for start in (0..width * len).step_by(width.max(1)) {
+        idx.extend((start..start + width).rev().map(|i| i as IdxSize));
+    }
+    IdxArr::from_vec(idx)
+}
+
+fn sub_fixed_size_list_reverse(arr: &FixedSizeListArray) -> ArrayRef {
+    let idx = reverse_indices(arr.size(), arr.len());
+    // SAFETY: the indices we generate are in bounds of the values.
+    let values = unsafe { take_unchecked(arr.values().as_ref(), &idx) };
+    FixedSizeListArray::new(arr.data_type().clone(), values, arr.validity().cloned()).boxed()
+}
+
+/// Reverse the elements in every sub-array by gathering the values with
+/// reversed indices, the fixed width makes the offsets implicit.
+pub(super) fn array_reverse(ca: &ArrayChunked) -> ArrayChunked {
+    let chunks = ca
+        .downcast_iter()
+        .map(sub_fixed_size_list_reverse)
+        .collect();
+    // SAFETY: reversing does not change the arrow data type of the chunks.
+    unsafe { ArrayChunked::from_chunks_and_dtype(ca.name(), chunks, ca.dtype().clone()) }
+}
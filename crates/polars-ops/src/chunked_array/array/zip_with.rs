@@ -0,0 +1,62 @@
+use arrow::array::{Array, FixedSizeListArray};
+use arrow::compute::utils::combine_validities_and;
+
+use super::*;
+
+/// Select the elements of `ca` where the sub-arrays of `mask` are `true` and the elements
+/// of `other` elsewhere. A sub-array is null if it is null in any of the inputs.
+pub(super) fn array_zip_with(
+    ca: &ArrayChunked,
+    mask: &ArrayChunked,
+    other: &ArrayChunked,
+) -> PolarsResult<ArrayChunked> {
+    polars_ensure!(
+        ca.width() == mask.width() && ca.width() == other.width(),
+        SchemaMismatch: "`arr.zip_with` expected arrays of width {}, got mask of width {} and other of width {}",
+        ca.width(), mask.width(), other.width()
+    );
+    polars_ensure!(
+        ca.len() == mask.len() && ca.len() == other.len(),
+        ShapeMismatch: "`arr.zip_with` expected inputs of length {}, got mask of length {} and other of length {}",
+        ca.len(), mask.len(), other.len()
+    );
+    polars_ensure!(
+        mask.inner_dtype() == DataType::Boolean,
+        SchemaMismatch: "`arr.zip_with` expected a mask of dtype `array[bool]`, got `{}`", mask.dtype()
+    );
+
+    if ca.is_empty() {
+        return Ok(ca.clone());
+    }
+
+    // The fixed width makes the flat values of all inputs line up.
+    let ca = ca.rechunk();
+    let mask = mask.rechunk();
+    let other = other.cast(ca.dtype())?;
+    let other = other.array().unwrap().rechunk();
+
+    let values = ca
+        .get_inner()
+        .zip_with(mask.get_inner().bool().unwrap(), &other.get_inner())?;
+
+    let arr = ca.downcast_iter().next().unwrap();
+    let validity = combine_validities_and(
+        arr.validity(),
+        combine_validities_and(
+            mask.downcast_iter().next().unwrap().validity(),
+            other.downcast_iter().next().unwrap().validity(),
+        )
+        .as_ref(),
+    );
+    let values = values.to_physical_repr().rechunk();
+    let out = FixedSizeListArray::new(
+        arr.data_type().clone(),
+        values.chunks()[0].clone(),
+        validity,
+    );
+
+    // SAFETY: the values have the inner dtype of `ca`.
+    Ok(unsafe {
+        ArrayChunked::from_chunks_and_dtype(ca.name(), vec![out.boxed()], ca.dtype().clone())
+    })
+}
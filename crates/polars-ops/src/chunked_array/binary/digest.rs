@@ -0,0 +1,30 @@
+use md5::Md5;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// A hash function that binary data can be digested with.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DigestAlgorithm {
+    /// MD5, see [RFC 1321](https://www.rfc-editor.org/rfc/rfc1321).
+    Md5,
+    /// SHA-1, see [RFC 3174](https://www.rfc-editor.org/rfc/rfc3174).
+    Sha1,
+    /// SHA-256, see [RFC 6234](https://www.rfc-editor.org/rfc/rfc6234).
+    Sha256,
+    /// The CRC-32 checksum of ISO-HDLC, as used by zlib and gzip, in big endian byte order.
+    Crc32,
+}
+
+impl DigestAlgorithm {
+    pub(crate) fn digest(self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            DigestAlgorithm::Md5 => Md5::digest(bytes).to_vec(),
+            DigestAlgorithm::Sha1 => Sha1::digest(bytes).to_vec(),
+            DigestAlgorithm::Sha256 => Sha256::digest(bytes).to_vec(),
+            DigestAlgorithm::Crc32 => crc32fast::hash(bytes).to_be_bytes().to_vec(),
+        }
+    }
+}
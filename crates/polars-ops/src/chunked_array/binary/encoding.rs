@@ -0,0 +1,149 @@
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC};
+
+/// The base32 alphabet of [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648#section-6).
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Everything except the unreserved characters of
+/// [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-2.3) is percent-encoded.
+const PERCENT_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+pub(crate) fn base32_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    for chunk in bytes.chunks(5) {
+        let mut block = [0u8; 5];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let bits = block.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64);
+        // Every started group of 5 bits is encoded, the rest of the 8 characters is padding.
+        let n_chars = (chunk.len() * 8).div_ceil(5);
+        for i in 0..8 {
+            if i < n_chars {
+                let idx = (bits >> (35 - i * 5)) & 0x1f;
+                out.push(BASE32_ALPHABET[idx as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(feature = "binary_encoding")]
+fn base32_value(c: u8) -> Option<u64> {
+    match c {
+        b'A'..=b'Z' => Some((c - b'A') as u64),
+        b'2'..=b'7' => Some((c - b'2') as u64 + 26),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "binary_encoding")]
+/// Decode padded base32, returns `None` if `bytes` isn't valid base32.
+pub(crate) fn base32_decode(bytes: &[u8]) -> Option<Vec<u8>> {
+    if bytes.len() % 8 != 0 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(bytes.len() / 8 * 5);
+    let n_blocks = bytes.len() / 8;
+    for (i, block) in bytes.chunks(8).enumerate() {
+        // Only the last block may be padded.
+        let n_chars = block.iter().position(|&c| c == b'=').unwrap_or(8);
+        if n_chars < 8 && i + 1 != n_blocks {
+            return None;
+        }
+        if block[n_chars..].iter().any(|&c| c != b'=') {
+            return None;
+        }
+        let n_bytes = match n_chars {
+            8 => 5,
+            7 => 4,
+            5 => 3,
+            4 => 2,
+            2 => 1,
+            _ => return None,
+        };
+        let mut bits = 0u64;
+        for &c in &block[..n_chars] {
+            bits = (bits << 5) | base32_value(c)?;
+        }
+        bits <<= (8 - n_chars) * 5;
+        // The bits after the last encoded byte must be zero.
+        if bits & ((1 << (40 - n_bytes * 8)) - 1) != 0 {
+            return None;
+        }
+        out.extend((0..n_bytes).map(|j| (bits >> (32 - j * 8)) as u8));
+    }
+    Some(out)
+}
+
+pub(crate) fn percent_encode(bytes: &[u8]) -> String {
+    percent_encoding::percent_encode(bytes, PERCENT_ENCODE_SET).to_string()
+}
+
+#[cfg(feature = "binary_encoding")]
+/// Decode percent-encoded bytes, returns `None` if a `%` isn't followed by two hex digits.
+pub(crate) fn percent_decode(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut rest = bytes;
+    while let Some(pos) = memchr::memchr(b'%', rest) {
+        let escape = rest.get(pos + 1..pos + 3)?;
+        if !escape.iter().all(u8::is_ascii_hexdigit) {
+            return None;
+        }
+        rest = &rest[pos + 3..];
+    }
+    Some(percent_encoding::percent_decode(bytes).collect())
+}
+
+#[cfg(all(test, feature = "binary_encoding"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_base32_rfc_4648() {
+        let cases = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
+        for (decoded, encoded) in cases {
+            assert_eq!(base32_encode(decoded.as_bytes()), encoded);
+            assert_eq!(
+                base32_decode(encoded.as_bytes()).unwrap(),
+                decoded.as_bytes()
+            );
+        }
+    }
+
+    #[test]
+    fn test_base32_decode_invalid() {
+        for s in [
+            "MY",
+            "my======",
+            "MY=====A",
+            "MY======MZXW6===",
+            "MZ======",
+            "M=======",
+        ] {
+            assert!(base32_decode(s.as_bytes()).is_none(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(percent_encode("a b/c~é".as_bytes()), "a%20b%2Fc~%C3%A9");
+        assert_eq!(
+            percent_decode(b"a%20b%2fc~%C3%A9").unwrap(),
+            "a b/c~é".as_bytes()
+        );
+        for s in ["%", "%2", "%zz", "a%2g"] {
+            assert!(percent_decode(s.as_bytes()).is_none(), "{s}");
+        }
+    }
+}
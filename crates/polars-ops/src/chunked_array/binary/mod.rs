@@ -1,5 +1,11 @@
+#[cfg(feature = "hash_digest")]
+mod digest;
+#[cfg(any(feature = "binary_encoding", feature = "string_encoding"))]
+pub(crate) mod encoding;
 mod namespace;
 
+#[cfg(feature = "hash_digest")]
+pub use digest::DigestAlgorithm;
 pub use namespace::*;
 use polars_core::prelude::*;
 
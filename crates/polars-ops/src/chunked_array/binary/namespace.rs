@@ -124,6 +124,78 @@ pub trait BinaryNameSpaceImpl: AsBinary {
                 .unwrap()
         }
     }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base32_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        if strict {
+            ca.try_apply_nonnull_values_generic(|s| {
+                encoding::base32_decode(s).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError:
+                        "invalid `base32` encoding found; try setting `strict=false` to ignore"
+                    )
+                })
+            })
+        } else {
+            Ok(ca.apply(|opt_s| opt_s.and_then(|s| encoding::base32_decode(s).map(Cow::Owned))))
+        }
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base32_encode(&self) -> Series {
+        let ca = self.as_binary();
+        unsafe {
+            ca.apply_values(|s| encoding::base32_encode(s).into_bytes().into())
+                .cast_unchecked(&DataType::String)
+                .unwrap()
+        }
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn percent_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        if strict {
+            ca.try_apply_nonnull_values_generic(|s| {
+                encoding::percent_decode(s).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError:
+                        "invalid `percent` encoding found; try setting `strict=false` to ignore"
+                    )
+                })
+            })
+        } else {
+            Ok(ca.apply(|opt_s| opt_s.and_then(|s| encoding::percent_decode(s).map(Cow::Owned))))
+        }
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn percent_encode(&self) -> Series {
+        let ca = self.as_binary();
+        unsafe {
+            ca.apply_values(|s| encoding::percent_encode(s).into_bytes().into())
+                .cast_unchecked(&DataType::String)
+                .unwrap()
+        }
+    }
+
+    /// Digest every value with the hash function `algorithm`.
+    #[cfg(feature = "hash_digest")]
+    fn digest(&self, algorithm: DigestAlgorithm) -> BinaryChunked {
+        let ca = self.as_binary();
+        ca.apply_values(|s| algorithm.digest(s).into())
+    }
+
+    /// Digest every value with the hash function `algorithm`, as a lowercase hex string.
+    #[cfg(feature = "hash_digest")]
+    fn hex_digest(&self, algorithm: DigestAlgorithm) -> Series {
+        let ca = self.as_binary();
+        unsafe {
+            ca.apply_values(|s| hex::encode(algorithm.digest(s)).into_bytes().into())
+                .cast_unchecked(&DataType::String)
+                .unwrap()
+        }
+    }
 }
 
 impl BinaryNameSpaceImpl for BinaryChunked {}
@@ -6,7 +6,7 @@ use arrow::temporal_conversions::{
 };
 use chrono::NaiveDateTime;
 use chrono_tz::UTC;
-use polars_core::chunked_array::ops::arity::try_binary_elementwise;
+use polars_core::chunked_array::ops::arity::{try_binary_elementwise, try_ternary_elementwise};
 use polars_core::chunked_array::temporal::parse_time_zone;
 use polars_core::prelude::*;
 
@@ -76,6 +76,79 @@ pub fn replace_time_zone(
     Ok(out)
 }
 
+/// Replace the time zone of `datetime`, with an `ambiguous` and a `non_existent` strategy per
+/// element. Both are either of length 1 or of the length of `datetime`.
+pub fn replace_time_zone_elementwise(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    time_zone: Option<&str>,
+    ambiguous: &StringChunked,
+    non_existent: &StringChunked,
+) -> PolarsResult<DatetimeChunked> {
+    if non_existent.len() == 1 {
+        if let Some(non_existent) = non_existent.get(0) {
+            let non_existent = NonExistent::from_str(non_existent)?;
+            return replace_time_zone(datetime, time_zone, ambiguous, non_existent);
+        }
+        let out = Int64Chunked::full_null(datetime.name(), datetime.len());
+        return Ok(out.into_datetime(datetime.time_unit(), time_zone.map(|x| x.to_string())));
+    }
+    for (name, len) in [
+        ("ambiguous", ambiguous.len()),
+        ("non_existent", non_existent.len()),
+    ] {
+        polars_ensure!(
+            len == 1 || len == datetime.len(),
+            ShapeMismatch: "`replace_time_zone` expected `{}` of length 1 or {}, got {}",
+            name, datetime.len(), len
+        );
+    }
+
+    let from_tz = parse_time_zone(datetime.time_zone().as_deref().unwrap_or("UTC"))?;
+    let to_tz = parse_time_zone(time_zone.unwrap_or("UTC"))?;
+    let timestamp_to_datetime: fn(i64) -> NaiveDateTime = match datetime.time_unit() {
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+        TimeUnit::Microseconds => timestamp_us_to_datetime,
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+    };
+    let datetime_to_timestamp: fn(NaiveDateTime) -> i64 = match datetime.time_unit() {
+        TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+        TimeUnit::Microseconds => datetime_to_timestamp_us,
+        TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+    };
+
+    let ambiguous = if ambiguous.len() == 1 {
+        ambiguous.new_from_index(0, datetime.len())
+    } else {
+        ambiguous.clone()
+    };
+    let out = try_ternary_elementwise(
+        datetime,
+        &ambiguous,
+        non_existent,
+        |timestamp_opt, ambiguous_opt, non_existent_opt| match (
+            timestamp_opt,
+            ambiguous_opt,
+            non_existent_opt,
+        ) {
+            (Some(timestamp), Some(ambiguous), Some(non_existent)) => {
+                let ndt = timestamp_to_datetime(timestamp);
+                Ok::<_, PolarsError>(
+                    convert_to_naive_local(
+                        &from_tz,
+                        &to_tz,
+                        ndt,
+                        Ambiguous::from_str(ambiguous)?,
+                        NonExistent::from_str(non_existent)?,
+                    )?
+                    .map(datetime_to_timestamp),
+                )
+            },
+            _ => Ok(None),
+        },
+    )?;
+    Ok(out.into_datetime(datetime.time_unit(), time_zone.map(|x| x.to_string())))
+}
+
 /// If `ambiguous` is length-1 and not equal to "null", we can take a slightly faster path.
 pub fn impl_replace_time_zone_fast(
     datetime: &Logical<DatetimeType, Int64Type>,
@@ -0,0 +1,65 @@
+use arrow::array::{Array, ListArray};
+use arrow::offset::OffsetsBuffer;
+
+use super::*;
+
+/// Split every sublist into sublists of `size` elements, the last of which may be shorter.
+pub(super) fn list_chunk(ca: &ListChunked, size: usize) -> PolarsResult<ListChunked> {
+    polars_ensure!(size > 0, ComputeError: "`list.chunk` expected a size larger than 0");
+
+    let inner_dtype = DataType::List(Box::new(ca.inner_dtype()));
+    let dtype = DataType::List(Box::new(inner_dtype.clone()));
+    if ca.is_empty() {
+        return Ok(ListChunked::full_null_with_dtype(
+            ca.name(),
+            0,
+            &inner_dtype,
+        ));
+    }
+
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let offsets = arr.offsets().as_slice();
+    let start = offsets[0];
+    let end = *offsets.last().unwrap();
+    let values = ca
+        .get_inner()
+        .slice(start, (end - start) as usize)
+        .to_physical_repr()
+        .rechunk();
+
+    let mut inner_offsets = Vec::with_capacity(values.len() / size + ca.len() + 1);
+    inner_offsets.push(0i64);
+    let mut outer_offsets = Vec::with_capacity(ca.len() + 1);
+    outer_offsets.push(0i64);
+    for w in offsets.windows(2) {
+        // A non-empty sublist is split after every `size` elements and at its end.
+        let (lo, hi) = (w[0] - start, w[1] - start);
+        let splits = (lo..hi).step_by(size).skip(1);
+        inner_offsets.extend(splits.chain((lo < hi).then_some(hi)));
+        outer_offsets.push(inner_offsets.len() as i64 - 1);
+    }
+
+    // SAFETY: the offsets are monotonically increasing and end at the length of the values.
+    let (inner_offsets, outer_offsets) = unsafe {
+        (
+            OffsetsBuffer::new_unchecked(inner_offsets.into()),
+            OffsetsBuffer::new_unchecked(outer_offsets.into()),
+        )
+    };
+    let inner = ListArray::new(
+        arr.data_type().clone(),
+        inner_offsets,
+        values.chunks()[0].clone(),
+        None,
+    );
+    let out = ListArray::new(
+        ListArray::<i64>::default_datatype(arr.data_type().clone()),
+        outer_offsets,
+        inner.boxed(),
+        arr.validity().cloned(),
+    );
+
+    // SAFETY: the values have the inner dtype of `ca`.
+    Ok(unsafe { ListChunked::from_chunks_and_dtype(ca.name(), vec![out.boxed()], dtype) })
+}
@@ -0,0 +1,65 @@
+use arrow::array::{Array, BooleanArray, ListArray};
+use arrow::bitmap::MutableBitmap;
+use arrow::offset::OffsetsBuffer;
+
+use super::*;
+
+/// Remove the elements of every sublist that are equal to the element right before them.
+/// Null elements are considered equal to each other.
+pub(super) fn list_dedupe(ca: &ListChunked) -> PolarsResult<ListChunked> {
+    if ca.is_empty() {
+        return Ok(ca.clone());
+    }
+
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let offsets = arr.offsets().as_slice();
+    let start = offsets[0] as usize;
+    let end = *offsets.last().unwrap() as usize;
+    if start == end {
+        return Ok(ca);
+    }
+    let values = ca.get_inner().slice(start as i64, end - start);
+    let n = values.len();
+
+    // An element is kept if it differs from its predecessor or starts a new sublist.
+    let mut keep = MutableBitmap::with_capacity(n);
+    keep.push(true);
+    if n > 1 {
+        let differs = values
+            .slice(1, n - 1)
+            .not_equal_missing(&values.slice(0, n - 1))?
+            .rechunk();
+        keep.extend_from_bitmap(differs.downcast_iter().next().unwrap().values());
+    }
+    for w in offsets.windows(2) {
+        if w[0] < w[1] {
+            keep.set(w[0] as usize - start, true);
+        }
+    }
+
+    let mut new_offsets = Vec::with_capacity(offsets.len());
+    new_offsets.push(0i64);
+    let mut last = 0;
+    for w in offsets.windows(2) {
+        let (lo, hi) = (w[0] as usize - start, w[1] as usize - start);
+        last += (lo..hi).filter(|j| keep.get(*j)).count() as i64;
+        new_offsets.push(last);
+    }
+
+    let mask = BooleanChunked::with_chunk("", BooleanArray::from_data_default(keep.into(), None));
+    let values = values.filter(&mask)?.to_physical_repr().rechunk();
+    // SAFETY: the offsets are monotonically increasing and end at the length of the values.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(new_offsets.into()) };
+    let out = ListArray::new(
+        arr.data_type().clone(),
+        offsets,
+        values.chunks()[0].clone(),
+        arr.validity().cloned(),
+    );
+
+    // SAFETY: the values have the inner dtype of `ca`.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(ca.name(), vec![out.boxed()], ca.dtype().clone())
+    })
+}
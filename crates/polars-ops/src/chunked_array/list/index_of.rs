@@ -0,0 +1,56 @@
+use arrow::array::Array;
+
+use super::*;
+
+/// Find the index of the first occurrence of `value` in every sub-list, `value` is either a
+/// single value or one value per sub-list. The index is null if the value does not occur in
+/// the sub-list, a null `value` matches null elements.
+pub fn list_index_of(ca: &ListChunked, value: &Series) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        value.len() == 1 || value.len() == ca.len(),
+        ComputeError:
+        "`list.index_of` expression got a value of length {} while the list has {} elements",
+        value.len(), ca.len()
+    );
+    if ca.is_empty() {
+        return Ok(IdxCa::full_null(ca.name(), 0));
+    }
+
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let offsets = arr.offsets().as_slice();
+    let start = offsets[0] as usize;
+    let end = *offsets.last().unwrap() as usize;
+    let values = ca.get_inner().slice(start as i64, end - start);
+
+    let mask = if value.len() == 1 {
+        values.equal_missing(value)?
+    } else {
+        // Repeat the value of every row for the elements of its sub-list.
+        let idx = offsets
+            .windows(2)
+            .enumerate()
+            .flat_map(|(i, w)| std::iter::repeat(i as IdxSize).take((w[1] - w[0]) as usize))
+            .collect::<Vec<_>>();
+        let value = value.take(&IdxCa::from_vec("", idx))?;
+        values.equal_missing(&value)?
+    };
+    let mask = mask.rechunk();
+    let mask = mask.downcast_iter().next().map(|arr| arr.values());
+
+    let out: IdxCa = offsets
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| {
+            if !arr.is_valid(i) {
+                return None;
+            }
+            let mask = mask?;
+            let (lo, hi) = (w[0] as usize - start, w[1] as usize - start);
+            (lo..hi)
+                .position(|j| mask.get_bit(j))
+                .map(|idx| idx as IdxSize)
+        })
+        .collect();
+    Ok(out.with_name(ca.name()))
+}
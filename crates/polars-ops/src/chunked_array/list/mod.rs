@@ -2,22 +2,30 @@ use polars_core::prelude::*;
 
 #[cfg(feature = "list_any_all")]
 mod any_all;
+mod chunk;
 mod count;
+mod dedupe;
 mod dispersion;
 #[cfg(feature = "hash")]
 pub(crate) mod hash;
+mod index_of;
 mod min_max;
 mod namespace;
+mod scatter;
 #[cfg(feature = "list_sets")]
 mod sets;
 mod sum_mean;
 #[cfg(feature = "list_to_struct")]
 mod to_struct;
+#[cfg(feature = "dtype-struct")]
+mod value_counts;
+mod zip_with;
 
 #[cfg(feature = "list_count")]
 pub use count::*;
 #[cfg(not(feature = "list_count"))]
 use count::*;
+pub use index_of::*;
 pub use namespace::*;
 #[cfg(feature = "list_sets")]
 pub use sets::*;
@@ -1,6 +1,12 @@
 use std::fmt::Write;
 
+#[cfg(feature = "dtype-struct")]
+use arrow::array::ListArray;
 use arrow::array::ValueSize;
+#[cfg(feature = "dtype-array")]
+use arrow::bitmap::Bitmap;
+#[cfg(feature = "dtype-array")]
+use arrow::compute::utils::combine_validities_and;
 use arrow::legacy::kernels::list::{index_is_oob, sublist_get};
 use polars_core::chunked_array::builder::get_list_builder;
 #[cfg(feature = "list_gather")]
@@ -14,11 +20,19 @@ use polars_core::utils::try_get_supertype;
 use super::*;
 #[cfg(feature = "list_any_all")]
 use crate::chunked_array::list::any_all::*;
+use crate::chunked_array::list::chunk::list_chunk;
+use crate::chunked_array::list::dedupe::list_dedupe;
 use crate::chunked_array::list::min_max::{list_max_function, list_min_function};
+use crate::chunked_array::list::scatter::list_scatter;
 use crate::chunked_array::list::sum_mean::sum_with_nulls;
+#[cfg(feature = "dtype-struct")]
+use crate::chunked_array::list::value_counts::list_value_counts;
+use crate::chunked_array::list::zip_with::list_zip_with;
 #[cfg(feature = "diff")]
 use crate::prelude::diff;
 use crate::prelude::list::sum_mean::{mean_list_numerical, sum_list_numerical};
+#[cfg(feature = "top_k")]
+use crate::prelude::top_k;
 use crate::series::ArgAgg;
 
 pub(super) fn has_inner_nulls(ca: &ListChunked) -> bool {
@@ -77,25 +91,38 @@ fn cast_rhs(
 pub trait ListNameSpaceImpl: AsList {
     /// In case the inner dtype [`DataType::String`], the individual items will be joined into a
     /// single string separated by `separator`.
+    ///
+    /// Null items are written as `null_replacement` if it is set. Otherwise they are skipped
+    /// if `ignore_nulls` is set, or make the joined string null if it isn't.
     fn lst_join(
         &self,
         separator: &StringChunked,
         ignore_nulls: bool,
+        null_replacement: Option<&str>,
     ) -> PolarsResult<StringChunked> {
         let ca = self.as_list();
         match ca.inner_dtype() {
             DataType::String => match separator.len() {
                 1 => match separator.get(0) {
-                    Some(separator) => self.join_literal(separator, ignore_nulls),
+                    Some(separator) => self.join_literal(separator, ignore_nulls, null_replacement),
                     _ => Ok(StringChunked::full_null(ca.name(), ca.len())),
                 },
-                _ => self.join_many(separator, ignore_nulls),
+                len if len == ca.len() => self.join_many(separator, ignore_nulls, null_replacement),
+                len => polars_bail!(
+                    ComputeError: "`lst.join` expected a separator of length 1 or {}, got {}",
+                    ca.len(), len
+                ),
             },
             dt => polars_bail!(op = "`lst.join`", got = dt, expected = "String"),
         }
     }
 
-    fn join_literal(&self, separator: &str, ignore_nulls: bool) -> PolarsResult<StringChunked> {
+    fn join_literal(
+        &self,
+        separator: &str,
+        ignore_nulls: bool,
+        null_replacement: Option<&str>,
+    ) -> PolarsResult<StringChunked> {
         let ca = self.as_list();
         // used to amortize heap allocs
         let mut buf = String::with_capacity(128);
@@ -103,24 +130,13 @@ pub trait ListNameSpaceImpl: AsList {
 
         ca.for_each_amortized(|opt_s| {
             let opt_val = opt_s.and_then(|s| {
-                // make sure that we don't write values of previous iteration
-                buf.clear();
-                let ca = s.as_ref().str().unwrap();
-
-                if ca.null_count() != 0 && !ignore_nulls {
-                    return None;
-                }
-
-                for arr in ca.downcast_iter() {
-                    for val in arr.non_null_values_iter() {
-                        buf.write_str(val).unwrap();
-                        buf.write_str(separator).unwrap();
-                    }
-                }
-
-                // last value should not have a separator, so slice that off
-                // saturating sub because there might have been nothing written.
-                Some(&buf[..buf.len().saturating_sub(separator.len())])
+                join_sublist(
+                    &mut buf,
+                    s.as_ref().str().unwrap(),
+                    separator,
+                    ignore_nulls,
+                    null_replacement,
+                )
             });
             builder.append_option(opt_val)
         });
@@ -131,6 +147,7 @@ pub trait ListNameSpaceImpl: AsList {
         &self,
         separator: &StringChunked,
         ignore_nulls: bool,
+        null_replacement: Option<&str>,
     ) -> PolarsResult<StringChunked> {
         let ca = self.as_list();
         // used to amortize heap allocs
@@ -143,24 +160,13 @@ pub trait ListNameSpaceImpl: AsList {
                 .for_each(|(opt_s, opt_sep)| match opt_sep {
                     Some(separator) => {
                         let opt_val = opt_s.and_then(|s| {
-                            // make sure that we don't write values of previous iteration
-                            buf.clear();
-                            let ca = s.as_ref().str().unwrap();
-
-                            if ca.null_count() != 0 && !ignore_nulls {
-                                return None;
-                            }
-
-                            for arr in ca.downcast_iter() {
-                                for val in arr.non_null_values_iter() {
-                                    buf.write_str(val).unwrap();
-                                    buf.write_str(separator).unwrap();
-                                }
-                            }
-
-                            // last value should not have a separator, so slice that off
-                            // saturating sub because there might have been nothing written.
-                            Some(&buf[..buf.len().saturating_sub(separator.len())])
+                            join_sublist(
+                                &mut buf,
+                                s.as_ref().str().unwrap(),
+                                separator,
+                                ignore_nulls,
+                                null_replacement,
+                            )
                         });
                         builder.append_option(opt_val)
                     },
@@ -275,6 +281,16 @@ pub trait ListNameSpaceImpl: AsList {
         Ok(self.same_type(out))
     }
 
+    /// Remove consecutive duplicate elements of every sublist.
+    fn lst_dedupe(&self) -> PolarsResult<ListChunked> {
+        list_dedupe(self.as_list())
+    }
+
+    /// Split every sublist into sublists of `size` elements, the last of which may be shorter.
+    fn lst_chunk(&self, size: usize) -> PolarsResult<ListChunked> {
+        list_chunk(self.as_list(), size)
+    }
+
     fn lst_arg_min(&self) -> IdxCa {
         let ca = self.as_list();
         ca.apply_amortized_generic(|opt_s| {
@@ -317,6 +333,44 @@ pub trait ListNameSpaceImpl: AsList {
         Ok(self.same_type(out))
     }
 
+    /// Pad every sublist at the end with `fill_value` until it has `length` elements.
+    /// Sublists that are already at least `length` long are left untouched.
+    fn lst_pad_end(&self, length: &Series, fill_value: &Series) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        for (name, s) in [("length", length), ("fill_value", fill_value)] {
+            polars_ensure!(
+                s.len() == 1 || s.len() == ca.len(),
+                ComputeError: "`list.pad_end` expected `{}` of length 1 or {}, got {}",
+                name, ca.len(), s.len()
+            );
+        }
+        let length = length.strict_cast(&IDX_DTYPE)?;
+        let length = if length.len() == 1 {
+            length.new_from_index(0, ca.len())
+        } else {
+            length
+        };
+        let fill_value = fill_value.strict_cast(&ca.inner_dtype())?;
+
+        let mut row = 0;
+        let out = ca.try_zip_and_apply_amortized(length.idx()?, |opt_s, opt_length| {
+            let fill_idx = if fill_value.len() == 1 { 0 } else { row };
+            row += 1;
+            let (Some(s), Some(length)) = (opt_s, opt_length) else {
+                return Ok(None);
+            };
+            let s = s.as_ref();
+            // Copy the sublist out of the amortized container before appending to it.
+            let mut out = s.slice(0, s.len());
+            let n = (length as usize).saturating_sub(s.len());
+            if n > 0 {
+                out.append(&fill_value.new_from_index(fill_idx, n))?;
+            }
+            Ok(Some(out))
+        })?;
+        Ok(self.same_type(out))
+    }
+
     fn lst_slice(&self, offset: i64, length: usize) -> ListChunked {
         let ca = self.as_list();
         let out = ca.apply_amortized(|s| s.as_ref().slice(offset, length));
@@ -337,6 +391,32 @@ pub trait ListNameSpaceImpl: AsList {
         IdxCa::from_vec(ca.name(), lengths)
     }
 
+    /// Convert to an `Array` of `width`. Sublists with another length either raise an error or
+    /// become null if `null_on_mismatch` is set.
+    #[cfg(feature = "dtype-array")]
+    fn lst_to_array(&self, width: usize, null_on_mismatch: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_list();
+        let dtype = DataType::Array(Box::new(ca.inner_dtype()), width);
+
+        let ca = if null_on_mismatch && !ca.is_empty() {
+            let ca = ca.rechunk();
+            let arr = ca.downcast_iter().next().unwrap();
+            let matches: Bitmap = arr.offsets().lengths().map(|len| len == width).collect();
+            let validity = combine_validities_and(arr.validity(), Some(&matches));
+            let arr = arr.clone().with_validity(validity);
+            // SAFETY: only the validity changed.
+            unsafe {
+                ListChunked::from_chunks_and_dtype(ca.name(), vec![arr.boxed()], ca.dtype().clone())
+            }
+        } else {
+            ca.clone()
+        };
+
+        // The cast reuses the values buffer if the sublists are already laid out back to back.
+        let out = ca.cast(&dtype)?;
+        Ok(out.array()?.clone())
+    }
+
     /// Get the value by index in the sublists.
     /// So index `0` would return the first item of every sublist
     /// and index `-1` would return the last item of every sublist
@@ -361,6 +441,10 @@ pub trait ListNameSpaceImpl: AsList {
 
     #[cfg(feature = "list_gather")]
     fn lst_gather_every(&self, n: &IdxCa, offset: &IdxCa) -> PolarsResult<Series> {
+        polars_ensure!(
+            !n.iter().any(|n| n == Some(0)),
+            InvalidOperation: "gather_every(n): n should be positive"
+        );
         let list_ca = self.as_list();
         let out = match (n.len(), offset.len()) {
             (1, 1) => match (n.get(0), offset.get(0)) {
@@ -501,6 +585,38 @@ pub trait ListNameSpaceImpl: AsList {
         }
     }
 
+    /// Set the elements of every sublist at `indices` to `values`.
+    fn lst_scatter(
+        &self,
+        indices: &Series,
+        values: &Series,
+        ignore_oob: bool,
+    ) -> PolarsResult<ListChunked> {
+        list_scatter(self.as_list(), indices, values, ignore_oob)
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    /// Count the occurrences of the unique values of every sublist.
+    fn lst_value_counts(&self, sort: bool, normalize: bool) -> PolarsResult<ListChunked> {
+        list_value_counts(self.as_list(), sort, normalize)
+    }
+
+    /// Take the elements of every sublist where the sublist of `mask` is `true` and the
+    /// elements of `other` elsewhere.
+    fn lst_zip_with(
+        &self,
+        mask: &Series,
+        other: &Series,
+        null_on_mismatch: bool,
+    ) -> PolarsResult<ListChunked> {
+        list_zip_with(
+            self.as_list(),
+            mask.list()?,
+            other.list()?,
+            null_on_mismatch,
+        )
+    }
+
     #[cfg(feature = "list_drop_nulls")]
     fn lst_drop_nulls(&self) -> ListChunked {
         let list_ca = self.as_list();
@@ -519,31 +635,47 @@ pub trait ListNameSpaceImpl: AsList {
         let ca = self.as_list();
 
         let n_s = n.cast(&IDX_DTYPE)?;
+        let n_s = broadcast_to_len(n_s, ca.len());
         let n = n_s.idx()?;
 
-        let out = match n.len() {
-            1 => {
-                if let Some(n) = n.get(0) {
-                    ca.try_apply_amortized(|s| {
-                        s.as_ref()
-                            .sample_n(n as usize, with_replacement, shuffle, seed)
-                    })
-                } else {
-                    Ok(ListChunked::full_null_with_dtype(
-                        ca.name(),
-                        ca.len(),
-                        &ca.inner_dtype(),
-                    ))
-                }
-            },
-            _ => ca.try_zip_and_apply_amortized(n, |opt_s, opt_n| match (opt_s, opt_n) {
+        let mut row = 0;
+        let out = ca.try_zip_and_apply_amortized(n, |opt_s, opt_n| {
+            let seed = row_seed(seed, row);
+            row += 1;
+            match (opt_s, opt_n) {
                 (Some(s), Some(n)) => s
                     .as_ref()
                     .sample_n(n as usize, with_replacement, shuffle, seed)
                     .map(Some),
                 _ => Ok(None),
-            }),
-        };
+            }
+        });
+        out.map(|ok| self.same_type(ok))
+    }
+
+    /// Get the `k` largest elements of every sublist, or the `k` smallest if `descending` is set
+    /// in `sort_options`. This selects the elements without fully sorting the sublists.
+    #[cfg(feature = "top_k")]
+    fn lst_top_k(&self, k: &Series, sort_options: SortOptions) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        polars_ensure!(
+            k.len() == 1 || k.len() == ca.len(),
+            ComputeError: "`list.top_k` expected `k` of length 1 or {}, got {}", ca.len(), k.len()
+        );
+
+        let k_s = k.cast(&IDX_DTYPE)?;
+        let k_s = broadcast_to_len(k_s, ca.len());
+        let k = k_s.idx()?;
+
+        let out = ca.try_zip_and_apply_amortized(k, |opt_s, opt_k| match (opt_s, opt_k) {
+            (Some(s), Some(k)) => {
+                let s = s.as_ref();
+                let k = Series::new("", [std::cmp::min(k, s.len() as IdxSize)]);
+                // Slice to not return the amortized series itself for empty sublists.
+                top_k(&[s.slice(0, s.len()), k], sort_options).map(Some)
+            },
+            _ => Ok(None),
+        });
         out.map(|ok| self.same_type(ok))
     }
 
@@ -558,24 +690,14 @@ pub trait ListNameSpaceImpl: AsList {
         let ca = self.as_list();
 
         let fraction_s = fraction.cast(&DataType::Float64)?;
+        let fraction_s = broadcast_to_len(fraction_s, ca.len());
         let fraction = fraction_s.f64()?;
 
-        let out = match fraction.len() {
-            1 => {
-                if let Some(fraction) = fraction.get(0) {
-                    ca.try_apply_amortized(|s| {
-                        let n = (s.as_ref().len() as f64 * fraction) as usize;
-                        s.as_ref().sample_n(n, with_replacement, shuffle, seed)
-                    })
-                } else {
-                    Ok(ListChunked::full_null_with_dtype(
-                        ca.name(),
-                        ca.len(),
-                        &ca.inner_dtype(),
-                    ))
-                }
-            },
-            _ => ca.try_zip_and_apply_amortized(fraction, |opt_s, opt_n| match (opt_s, opt_n) {
+        let mut row = 0;
+        let out = ca.try_zip_and_apply_amortized(fraction, |opt_s, opt_fraction| {
+            let seed = row_seed(seed, row);
+            row += 1;
+            match (opt_s, opt_fraction) {
                 (Some(s), Some(fraction)) => {
                     let n = (s.as_ref().len() as f64 * fraction) as usize;
                     s.as_ref()
@@ -583,11 +705,51 @@ pub trait ListNameSpaceImpl: AsList {
                         .map(Some)
                 },
                 _ => Ok(None),
-            }),
-        };
+            }
+        });
         out.map(|ok| self.same_type(ok))
     }
 
+    /// Get the field `name` of every struct in a list of structs.
+    #[cfg(feature = "dtype-struct")]
+    fn lst_struct_field(&self, name: &str) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        let DataType::Struct(fields) = ca.inner_dtype() else {
+            polars_bail!(
+                InvalidOperation: "`list.struct_field` expected a list of structs, got `{}`", ca.dtype()
+            );
+        };
+        let dtype = fields
+            .iter()
+            .find(|fld| fld.name() == name)
+            .map(|fld| fld.data_type().clone())
+            .ok_or_else(|| polars_err!(StructFieldNotFound: "{}", name))?;
+        if ca.is_empty() {
+            return Ok(ListChunked::full_null_with_dtype(ca.name(), 0, &dtype));
+        }
+
+        // The field has a value for every struct, so the offsets of the list still apply.
+        let ca = ca.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let values = ca.get_inner().struct_()?.field_by_name(name)?;
+        let values = values.to_physical_repr().rechunk().chunks()[0].clone();
+        let out = ListArray::new(
+            ListArray::<i64>::default_datatype(values.data_type().clone()),
+            arr.offsets().clone(),
+            values,
+            arr.validity().cloned(),
+        );
+
+        // SAFETY: the values have the physical dtype of the field.
+        Ok(unsafe {
+            ListChunked::from_chunks_and_dtype(
+                ca.name(),
+                vec![out.boxed()],
+                DataType::List(Box::new(dtype)),
+            )
+        })
+    }
+
     fn lst_concat(&self, other: &[Series]) -> PolarsResult<ListChunked> {
         let ca = self.as_list();
         let other_len = other.len();
@@ -743,6 +905,66 @@ pub trait ListNameSpaceImpl: AsList {
 
 impl ListNameSpaceImpl for ListChunked {}
 
+/// Join the items of `ca` into `buf`, see [`ListNameSpaceImpl::lst_join`].
+fn join_sublist<'a>(
+    buf: &'a mut String,
+    ca: &StringChunked,
+    separator: &str,
+    ignore_nulls: bool,
+    null_replacement: Option<&str>,
+) -> Option<&'a str> {
+    // make sure that we don't write values of previous iteration
+    buf.clear();
+
+    if ca.null_count() != 0 && !ignore_nulls && null_replacement.is_none() {
+        return None;
+    }
+
+    for arr in ca.downcast_iter() {
+        match null_replacement {
+            Some(null_replacement) => {
+                for val in arr.iter() {
+                    buf.write_str(val.unwrap_or(null_replacement)).unwrap();
+                    buf.write_str(separator).unwrap();
+                }
+            },
+            None => {
+                for val in arr.non_null_values_iter() {
+                    buf.write_str(val).unwrap();
+                    buf.write_str(separator).unwrap();
+                }
+            },
+        }
+    }
+
+    // last value should not have a separator, so slice that off
+    // saturating sub because there might have been nothing written.
+    Some(&buf[..buf.len().saturating_sub(separator.len())])
+}
+
+/// Repeat a unit length `s` to `len` values.
+#[cfg(any(feature = "list_sample", feature = "top_k"))]
+fn broadcast_to_len(s: Series, len: usize) -> Series {
+    if s.len() == 1 && len != 1 {
+        s.new_from_index(0, len)
+    } else {
+        s
+    }
+}
+
+/// Derive the seed of the sublist at `row`, so that equal sublists are not sampled identically
+/// and the result of a sublist does not depend on how the other sublists are processed.
+#[cfg(feature = "list_sample")]
+fn row_seed(seed: Option<u64>, row: usize) -> Option<u64> {
+    // Mix with the splitmix64 finalizer, so that the seeds of adjacent rows are uncorrelated.
+    seed.map(|seed| {
+        let mut z = seed.wrapping_add((row as u64 + 1).wrapping_mul(0x9e3779b97f4a7c15));
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    })
+}
+
 #[cfg(feature = "list_gather")]
 fn take_series(s: &Series, idx: Series, null_on_oob: bool) -> PolarsResult<Series> {
     let len = s.len();
@@ -0,0 +1,137 @@
+use arrow::array::{Array, ListArray};
+use arrow::offset::OffsetsBuffer;
+
+use super::*;
+
+fn list_depth(dtype: &DataType) -> usize {
+    match dtype {
+        DataType::List(inner) => 1 + list_depth(inner),
+        _ => 0,
+    }
+}
+
+/// Set the elements of every sublist at `indices` to `values`.
+///
+/// `indices` is a list of indices per sublist, or a single index per sublist. Negative
+/// indices count from the end of the sublist. `values` is either a list with a value for
+/// every index, or a single value that is written at all indices of the sublist. Both are
+/// broadcast if they have a length of 1. Out-of-bounds indices raise an error, unless
+/// `ignore_oob` is set, in which case they are skipped.
+pub(super) fn list_scatter(
+    ca: &ListChunked,
+    indices: &Series,
+    values: &Series,
+    ignore_oob: bool,
+) -> PolarsResult<ListChunked> {
+    for (name, s) in [("indices", indices), ("values", values)] {
+        polars_ensure!(
+            s.len() == 1 || s.len() == ca.len(),
+            ComputeError: "`list.scatter` expected `{}` of length 1 or {}, got {}",
+            name, ca.len(), s.len()
+        );
+    }
+    let idx_dtype = indices.dtype().inner_dtype().unwrap_or(indices.dtype());
+    polars_ensure!(
+        idx_dtype.is_integer(),
+        InvalidOperation: "`list.scatter` expected integer indices, got `{}`", indices.dtype()
+    );
+    if ca.is_empty() {
+        return Ok(ca.clone());
+    }
+
+    let inner_dtype = ca.inner_dtype();
+    let indices = indices.cast(&DataType::List(Box::new(DataType::Int64)))?;
+    let indices = indices.list()?.rechunk();
+    let idx_arr = indices.downcast_iter().next().unwrap();
+    let idx_offsets = idx_arr.offsets().as_slice();
+    let idx_values = indices.get_inner();
+    let idx_values = idx_values.i64()?;
+
+    // Either one value for every index, or one value for all indices of a sublist.
+    let per_index = list_depth(values.dtype()) > list_depth(&inner_dtype);
+    let value_lists = per_index
+        .then(|| values.list().map(|ca| ca.rechunk()))
+        .transpose()?;
+    let value_arr = value_lists
+        .as_ref()
+        .map(|ca| ca.downcast_iter().next().unwrap());
+    let new_values = match &value_lists {
+        Some(ca) => ca.get_inner(),
+        None => values.clone(),
+    };
+    let new_values = new_values.strict_cast(&inner_dtype)?;
+
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let offsets = arr.offsets().as_slice();
+    let start = offsets[0] as usize;
+    let end = *offsets.last().unwrap() as usize;
+    let n_values = end - start;
+
+    // Gather from the current values followed by the new values.
+    let mut take = (0..n_values as IdxSize).map(Some).collect::<Vec<_>>();
+    for (row, w) in offsets.windows(2).enumerate() {
+        if !arr.is_valid(row) {
+            continue;
+        }
+        let (lo, len) = (w[0] as usize - start, (w[1] - w[0]) as usize);
+        let idx_row = if indices.len() == 1 { 0 } else { row };
+        if !idx_arr.is_valid(idx_row) {
+            continue;
+        }
+        let (idx_lo, idx_hi) = (
+            idx_offsets[idx_row] as usize,
+            idx_offsets[idx_row + 1] as usize,
+        );
+
+        // The position of the first new value of this sublist, `None` writes nulls.
+        let value_row = if values.len() == 1 { 0 } else { row };
+        let value_lo = match value_arr {
+            Some(value_arr) if value_arr.is_valid(value_row) => {
+                let (value_lo, value_hi) = value_arr.offsets().start_end(value_row);
+                polars_ensure!(
+                    value_hi - value_lo == idx_hi - idx_lo,
+                    ShapeMismatch: "`list.scatter` got {} values for {} indices",
+                    value_hi - value_lo, idx_hi - idx_lo
+                );
+                Some(value_lo)
+            },
+            Some(_) => None,
+            None => Some(value_row),
+        };
+
+        for (k, idx) in (idx_lo..idx_hi).enumerate() {
+            let Some(idx) = idx_values.get(idx) else {
+                continue;
+            };
+            let idx = if idx < 0 { idx + len as i64 } else { idx };
+            if idx < 0 || idx >= len as i64 {
+                polars_ensure!(ignore_oob, OutOfBounds: "scatter indices are out of bounds");
+                continue;
+            }
+            let offset = if per_index { k } else { 0 };
+            take[lo + idx as usize] =
+                value_lo.map(|value_lo| (n_values + value_lo + offset) as IdxSize);
+        }
+    }
+
+    let mut combined = ca.get_inner().slice(start as i64, n_values);
+    combined.append(&new_values)?;
+    let take = IdxCa::from_iter_options("", take.into_iter());
+    let out_values = combined.take(&take)?.to_physical_repr().rechunk();
+
+    let new_offsets = offsets.iter().map(|o| o - offsets[0]).collect::<Vec<_>>();
+    // SAFETY: the offsets are shifted by a constant, so they stay monotonically increasing.
+    let new_offsets = unsafe { OffsetsBuffer::new_unchecked(new_offsets.into()) };
+    let out = ListArray::new(
+        arr.data_type().clone(),
+        new_offsets,
+        out_values.chunks()[0].clone(),
+        arr.validity().cloned(),
+    );
+
+    // SAFETY: the values have the inner dtype of `ca`.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(ca.name(), vec![out.boxed()], ca.dtype().clone())
+    })
+}
@@ -0,0 +1,49 @@
+use super::*;
+
+fn sublist_value_counts(s: &Series, sort: bool, normalize: bool) -> PolarsResult<Series> {
+    // Sorted groups are in order of first occurrence.
+    let groups = s.group_tuples(false, true)?;
+    // SAFETY: the groups are created from `s`.
+    let mut values = unsafe { s.agg_first(&groups) };
+    values.rename("value");
+    let counts = groups.group_count();
+    let (count_name, counts) = if normalize {
+        let total = s.len() as f64;
+        let counts = counts.cast(&DataType::Float64)?.f64().unwrap() / total;
+        ("proportion", counts.into_series())
+    } else {
+        ("count", counts.into_series())
+    };
+
+    let mut df = DataFrame::new(vec![values, counts.with_name(count_name)])?;
+    if sort {
+        df = df.sort(
+            [count_name],
+            SortMultipleOptions::default()
+                .with_order_descending(true)
+                .with_maintain_order(true),
+        )?;
+    }
+    Ok(df.into_struct("").into_series())
+}
+
+/// Count the occurrences of the unique values of every sublist.
+///
+/// Every sublist becomes a list of structs with fields `value` and `count`, or `proportion`
+/// if `normalize` is set. If `sort` is set, the structs are sorted by count in descending
+/// order. Values with the same count, or all values if `sort` isn't set, are in order of
+/// first occurrence.
+pub(super) fn list_value_counts(
+    ca: &ListChunked,
+    sort: bool,
+    normalize: bool,
+) -> PolarsResult<ListChunked> {
+    // Resolve the output dtype up front, so that it is also correct if there are no sublists.
+    let empty = Series::new_empty("", &ca.inner_dtype());
+    let dtype = sublist_value_counts(&empty, sort, normalize)?
+        .dtype()
+        .clone();
+    let out = ca.try_apply_amortized(|s| sublist_value_counts(s.as_ref(), sort, normalize))?;
+    let out = out.cast(&DataType::List(Box::new(dtype)))?;
+    Ok(out.list().unwrap().clone())
+}
@@ -0,0 +1,93 @@
+use arrow::array::{Array, ListArray};
+use arrow::bitmap::MutableBitmap;
+use arrow::offset::OffsetsBuffer;
+
+use super::*;
+
+/// Select the elements of `ca` where the sublists of `mask` are `true` and the elements of
+/// `other` elsewhere. A sublist is null if it is null in any of the inputs.
+///
+/// The sublists of a row must all have the same length. A mismatch raises an error, unless
+/// `null_on_mismatch` is set, in which case the sublist is null.
+pub(super) fn list_zip_with(
+    ca: &ListChunked,
+    mask: &ListChunked,
+    other: &ListChunked,
+    null_on_mismatch: bool,
+) -> PolarsResult<ListChunked> {
+    polars_ensure!(
+        ca.len() == mask.len() && ca.len() == other.len(),
+        ShapeMismatch: "`list.zip_with` expected inputs of length {}, got mask of length {} and other of length {}",
+        ca.len(), mask.len(), other.len()
+    );
+    polars_ensure!(
+        mask.inner_dtype() == DataType::Boolean,
+        SchemaMismatch: "`list.zip_with` expected a mask of dtype `list[bool]`, got `{}`", mask.dtype()
+    );
+
+    if ca.is_empty() {
+        return Ok(ca.clone());
+    }
+
+    let ca = ca.rechunk();
+    let mask = mask.rechunk();
+    let other = other.cast(ca.dtype())?;
+    let other = other.list().unwrap().rechunk();
+    let arrs = [&ca, &mask, &other].map(|ca| ca.downcast_iter().next().unwrap());
+
+    // Gather the elements of every row from the flat values of all inputs, so that they
+    // line up even if the sublists start at different offsets.
+    let mut idx = [(); 3].map(|_| Vec::<IdxSize>::with_capacity(ca.get_inner().len()));
+    let mut offsets = Vec::with_capacity(ca.len() + 1);
+    offsets.push(0i64);
+    let mut validity = MutableBitmap::with_capacity(ca.len());
+    for row in 0..ca.len() {
+        let valid = arrs.iter().all(|arr| arr.is_valid(row));
+        let [(start, end), (mask_start, mask_end), (other_start, other_end)] =
+            arrs.map(|arr| arr.offsets().start_end(row));
+        let (len, mask_len, other_len) =
+            (end - start, mask_end - mask_start, other_end - other_start);
+        let same_len = len == mask_len && len == other_len;
+        polars_ensure!(
+            !valid || same_len || null_on_mismatch,
+            ShapeMismatch: "`list.zip_with` got sublists of different lengths in row {}: {}, {} and {}",
+            row, len, mask_len, other_len
+        );
+
+        if valid && same_len {
+            for (idx, start) in idx.iter_mut().zip([start, mask_start, other_start]) {
+                idx.extend((start..start + len).map(|i| i as IdxSize));
+            }
+            offsets.push(offsets[row] + len as i64);
+            validity.push(true);
+        } else {
+            offsets.push(offsets[row]);
+            validity.push(false);
+        }
+    }
+
+    let [idx, mask_idx, other_idx] = idx.map(|idx| IdxCa::from_vec("", idx));
+    // SAFETY: the indices are in bounds of the flat values of their sublists.
+    let values = unsafe {
+        let mask_values = mask.get_inner().take_unchecked(&mask_idx);
+        ca.get_inner().take_unchecked(&idx).zip_with(
+            mask_values.bool().unwrap(),
+            &other.get_inner().take_unchecked(&other_idx),
+        )?
+    };
+
+    let values = values.to_physical_repr().rechunk();
+    // SAFETY: the offsets are monotonically increasing and end at the length of the values.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+    let out = ListArray::new(
+        arrs[0].data_type().clone(),
+        offsets,
+        values.chunks()[0].clone(),
+        Some(validity.into()),
+    );
+
+    // SAFETY: the values have the inner dtype of `ca`.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(ca.name(), vec![out.boxed()], ca.dtype().clone())
+    })
+}
@@ -1,4 +1,6 @@
 use polars_core::prelude::StringChunked;
+#[cfg(feature = "string_to_titlecase")]
+use unicode_segmentation::UnicodeSegmentation;
 
 // Inlined from std.
 fn convert_while_ascii(b: &[u8], convert: fn(&u8) -> u8, out: &mut Vec<u8>) {
@@ -131,7 +133,60 @@ pub(super) fn to_uppercase<'a>(ca: &'a StringChunked) -> StringChunked {
     ca.apply_mut(f)
 }
 
-#[cfg(feature = "nightly")]
+fn casefold_char(c: char, out: &mut String) {
+    match c {
+        // Cherokee is the only script that folds to uppercase, as its lowercase letters
+        // were added to Unicode after its uppercase letters.
+        '\u{13A0}'..='\u{13F5}' => out.push(c),
+        '\u{13F8}'..='\u{13FD}' => out.push(char::from_u32(c as u32 - 0x8).unwrap()),
+        '\u{AB70}'..='\u{ABBF}' => out.push(char::from_u32(c as u32 - 0x97D0).unwrap()),
+        // The dotless i only folds to `i` in Turkic languages.
+        'ı' => out.push(c),
+        // Otherwise, a character folds to the lowercase of the uppercase of its lowercase,
+        // e.g. ẞ -> ß -> SS -> ss.
+        _ => {
+            for lower in c.to_lowercase() {
+                for upper in lower.to_uppercase() {
+                    out.extend(upper.to_lowercase());
+                }
+            }
+        },
+    }
+}
+
+pub(super) fn casefold<'a>(ca: &'a StringChunked) -> StringChunked {
+    // Amortize allocation.
+    let mut buf = Vec::new();
+    let f = |s: &'a str| -> &'a str {
+        // ASCII characters fold to their lowercase.
+        convert_while_ascii(s.as_bytes(), u8::to_ascii_lowercase, &mut buf);
+
+        // SAFETY: we know this is a valid char boundary since
+        // out.len() is only progressed if ascii bytes are found.
+        let rest = unsafe { s.get_unchecked(buf.len()..) };
+
+        // SAFETY: We have written only valid ASCII to our vec.
+        let mut s = unsafe { String::from_utf8_unchecked(std::mem::take(&mut buf)) };
+
+        for c in rest.chars() {
+            if c.is_ascii() {
+                s.push(c.to_ascii_lowercase());
+            } else {
+                casefold_char(c, &mut s);
+            }
+        }
+
+        // Put buf back for next iteration.
+        buf = s.into_bytes();
+
+        // SAFETY: apply_mut will copy value from buf before next iteration.
+        let slice = unsafe { std::str::from_utf8_unchecked(&buf) };
+        unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+    };
+    ca.apply_mut(f)
+}
+
+#[cfg(feature = "string_to_titlecase")]
 pub(super) fn to_titlecase<'a>(ca: &'a StringChunked) -> StringChunked {
     // Amortize allocation.
     let mut buf = Vec::new();
@@ -147,14 +202,14 @@ pub(super) fn to_titlecase<'a>(ca: &'a StringChunked) -> StringChunked {
         buf.clear();
         let mut s = unsafe { String::from_utf8_unchecked(std::mem::take(&mut buf)) };
 
-        let mut next_is_upper = true;
-        for c in lowercased.chars() {
-            if next_is_upper {
+        // Uppercase the first character of every word, with words delimited by Unicode word
+        // boundaries. Uppercasing the first character of whitespace or punctuation is a no-op.
+        for word in lowercased.split_word_bounds() {
+            let mut chars = word.chars();
+            if let Some(c) = chars.next() {
                 s.extend(c.to_uppercase());
-            } else {
-                s.push(c);
+                s.push_str(chars.as_str());
             }
-            next_is_upper = c.is_whitespace();
         }
 
         // Put buf back for next iteration.
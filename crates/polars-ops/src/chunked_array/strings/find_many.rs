@@ -42,3 +42,24 @@ pub fn replace_all(
 
     Ok(ca.apply_generic(|opt_val| opt_val.map(|val| ac.replace_all(val, replace_with.as_slice()))))
 }
+
+pub fn extract_many(
+    ca: &StringChunked,
+    patterns: &StringChunked,
+    ascii_case_insensitive: bool,
+    overlapping: bool,
+) -> PolarsResult<ListChunked> {
+    let ac = build_ac(patterns, ascii_case_insensitive)?;
+    let mut builder = ListStringChunkedBuilder::new(ca.name(), ca.len(), ca.len() * 2);
+
+    for opt_val in ca {
+        match opt_val {
+            Some(val) if overlapping => {
+                builder.append_values_iter(ac.find_overlapping_iter(val).map(|m| &val[m.span()]))
+            },
+            Some(val) => builder.append_values_iter(ac.find_iter(val).map(|m| &val[m.span()])),
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
@@ -92,12 +92,25 @@ pub trait Utf8JsonPathImpl: AsString {
     }
 
     /// Extracts a typed-JSON value for each row in the StringChunked
+    ///
+    /// If `strict` is not set, values that are not valid JSON are decoded as null, instead of
+    /// raising an error.
     fn json_decode(
         &self,
         dtype: Option<DataType>,
         infer_schema_len: Option<usize>,
+        strict: bool,
     ) -> PolarsResult<Series> {
         let ca = self.as_string();
+        let valid;
+        let ca = if strict {
+            ca
+        } else {
+            valid = unary_elementwise(ca, |opt_s: Option<&str>| {
+                opt_s.filter(|s| serde_json::from_str::<Value>(s).is_ok())
+            });
+            &valid
+        };
         let dtype = match dtype {
             Some(dt) => dt,
             None => ca.json_infer(infer_schema_len)?,
@@ -130,7 +143,7 @@ pub trait Utf8JsonPathImpl: AsString {
         infer_schema_len: Option<usize>,
     ) -> PolarsResult<Series> {
         let selected_json = self.as_string().json_path_select(json_path)?;
-        selected_json.json_decode(dtype, infer_schema_len)
+        selected_json.json_decode(dtype, infer_schema_len, true)
     }
 }
 
@@ -214,11 +227,41 @@ mod tests {
         let expected_dtype = expected_series.dtype().clone();
 
         assert!(ca
-            .json_decode(None, None)
+            .json_decode(None, None, true)
+            .unwrap()
+            .equals_missing(&expected_series));
+        assert!(ca
+            .json_decode(Some(expected_dtype), None, true)
             .unwrap()
             .equals_missing(&expected_series));
+    }
+
+    #[test]
+    fn test_json_decode_non_strict() {
+        let s = Series::new(
+            "json",
+            [
+                Some(r#"{"a": 1, "b": "hello"}"#),
+                Some(r#"{"a": 2, "b": "#),
+                None,
+                Some("not json"),
+            ],
+        );
+        let ca = s.str().unwrap();
+
+        let expected_series = StructChunked::new(
+            "",
+            &[
+                Series::new("a", &[Some(1), None, None, None]),
+                Series::new("b", &[Some("hello"), None, None, None]),
+            ],
+        )
+        .unwrap()
+        .into_series();
+
+        assert!(ca.json_decode(None, None, true).is_err());
         assert!(ca
-            .json_decode(Some(expected_dtype), None)
+            .json_decode(None, None, false)
             .unwrap()
             .equals_missing(&expected_series));
     }
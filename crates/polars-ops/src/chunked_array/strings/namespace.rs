@@ -12,6 +12,8 @@ use polars_utils::cache::FastFixedCache;
 use regex::escape;
 
 use super::*;
+#[cfg(feature = "string_encoding")]
+use crate::chunked_array::binary::encoding;
 #[cfg(feature = "binary_encoding")]
 use crate::chunked_array::binary::BinaryNameSpaceImpl;
 
@@ -61,10 +63,49 @@ pub trait StringNameSpaceImpl: AsString {
         ca.apply_values(|s| general_purpose::STANDARD.encode(s).into())
     }
 
+    #[cfg(not(feature = "binary_encoding"))]
+    fn base32_decode(&self) -> PolarsResult<StringChunked> {
+        panic!("activate 'binary_encoding' feature")
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base32_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_string();
+        ca.as_binary().base32_decode(strict)
+    }
+
+    #[must_use]
+    #[cfg(feature = "string_encoding")]
+    fn base32_encode(&self) -> StringChunked {
+        let ca = self.as_string();
+        ca.apply_values(|s| encoding::base32_encode(s.as_bytes()).into())
+    }
+
+    #[cfg(not(feature = "binary_encoding"))]
+    fn percent_decode(&self) -> PolarsResult<StringChunked> {
+        panic!("activate 'binary_encoding' feature")
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn percent_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_string();
+        ca.as_binary().percent_decode(strict)
+    }
+
+    #[must_use]
+    #[cfg(feature = "string_encoding")]
+    fn percent_encode(&self) -> StringChunked {
+        let ca = self.as_string();
+        ca.apply_values(|s| encoding::percent_encode(s.as_bytes()).into())
+    }
+
     #[cfg(feature = "string_to_integer")]
     // Parse a string number with base _radix_ into a decimal (i64)
     fn to_integer(&self, base: &UInt32Chunked, strict: bool) -> PolarsResult<Int64Chunked> {
         let ca = self.as_string();
+        if let Some(invalid) = base.iter().flatten().find(|b| !(2..=36).contains(b)) {
+            polars_bail!(ComputeError: "`str.to_integer` expected a base between 2 and 36, got {}", invalid);
+        }
         let f = |opt_s: Option<&str>, opt_base: Option<u32>| -> Option<i64> {
             match (opt_s, opt_base) {
                 (Some(s), Some(base)) => <i64 as Num>::from_str_radix(s, base).ok(),
@@ -213,6 +254,14 @@ pub trait StringNameSpaceImpl: AsString {
         }
     }
 
+    /// Convert the byte offsets in `idx` into char offsets into the string values.
+    fn byte_to_char_offsets(&self, idx: &UInt32Chunked) -> UInt32Chunked {
+        let ca = self.as_string();
+        broadcast_binary_elementwise(ca, idx, |src: Option<&str>, idx: Option<u32>| {
+            Some(src?[..idx? as usize].chars().count() as u32)
+        })
+    }
+
     /// Get the length of the string values as number of chars.
     fn str_len_chars(&self) -> UInt32Chunked {
         let ca = self.as_string();
@@ -229,22 +278,34 @@ pub trait StringNameSpaceImpl: AsString {
     ///
     /// Padding is done using the specified `fill_char`.
     /// Strings with length equal to or greater than the given length are
-    /// returned as-is.
+    /// returned as-is. The length is counted in chars, or in display width
+    /// if `display_width` is set.
     #[cfg(feature = "string_pad")]
-    fn pad_start(&self, length: usize, fill_char: char) -> StringChunked {
+    fn pad_start(
+        &self,
+        length: &UInt64Chunked,
+        fill_char: char,
+        display_width: bool,
+    ) -> PolarsResult<StringChunked> {
         let ca = self.as_string();
-        pad::pad_start(ca, length, fill_char)
+        pad::pad_start(ca, length, fill_char, display_width)
     }
 
     /// Pad the end of the string until it reaches the given length.
     ///
     /// Padding is done using the specified `fill_char`.
     /// Strings with length equal to or greater than the given length are
-    /// returned as-is.
+    /// returned as-is. The length is counted in chars, or in display width
+    /// if `display_width` is set.
     #[cfg(feature = "string_pad")]
-    fn pad_end(&self, length: usize, fill_char: char) -> StringChunked {
+    fn pad_end(
+        &self,
+        length: &UInt64Chunked,
+        fill_char: char,
+        display_width: bool,
+    ) -> PolarsResult<StringChunked> {
         let ca = self.as_string();
-        pad::pad_end(ca, length, fill_char)
+        pad::pad_end(ca, length, fill_char, display_width)
     }
 
     /// Pad the start of the string with zeros until it reaches the given length.
@@ -470,6 +531,7 @@ pub trait StringNameSpaceImpl: AsString {
     #[cfg(feature = "dtype-struct")]
     fn splitn(&self, by: &StringChunked, n: usize) -> PolarsResult<StructChunked> {
         let ca = self.as_string();
+        polars_ensure!(n > 0, ComputeError: "`str.splitn` expected `n` larger than 0");
 
         split_to_struct(ca, by, n, |s, by| s.splitn(n, by), true)
     }
@@ -570,6 +632,14 @@ pub trait StringNameSpaceImpl: AsString {
         case::to_lowercase(ca)
     }
 
+    /// Fold the case of the strings for caseless comparison, see [the Unicode standard,
+    /// section 3.13](https://www.unicode.org/versions/latest/ch03.pdf).
+    #[must_use]
+    fn casefold(&self) -> StringChunked {
+        let ca = self.as_string();
+        case::casefold(ca)
+    }
+
     /// Modify the strings to their uppercase equivalent.
     #[must_use]
     fn to_uppercase(&self) -> StringChunked {
@@ -578,8 +648,11 @@ pub trait StringNameSpaceImpl: AsString {
     }
 
     /// Modify the strings to their titlecase equivalent.
+    ///
+    /// The first character of every word is uppercased and the others are lowercased, where the
+    /// words are delimited by the Unicode word boundaries of [UAX #29](https://www.unicode.org/reports/tr29/).
     #[must_use]
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "string_to_titlecase")]
     fn to_titlecase(&self) -> StringChunked {
         let ca = self.as_string();
         case::to_titlecase(ca)
@@ -592,6 +665,13 @@ pub trait StringNameSpaceImpl: AsString {
         ca + other
     }
 
+    /// Escape all regular expression meta characters in the string values.
+    #[must_use]
+    fn str_escape_regex(&self) -> StringChunked {
+        let ca = self.as_string();
+        ca.apply_values(|s| escape(s).into())
+    }
+
     /// Reverses the string values
     #[must_use]
     #[cfg(feature = "string_reverse")]
@@ -600,13 +680,44 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Wrap the string values at word boundaries into lines of at most `width` chars.
+    ///
+    /// Words longer than `width` are broken if `break_long_words` is set, and tabs are
+    /// expanded to the next multiple of `tab_size` columns first.
+    #[cfg(feature = "string_wrap")]
+    fn str_wrap(
+        &self,
+        width: usize,
+        break_long_words: bool,
+        tab_size: usize,
+    ) -> PolarsResult<StringChunked> {
+        polars_ensure!(width > 0, ComputeError: "`str.wrap` expected a width larger than 0");
+        let ca = self.as_string();
+        Ok(wrap::wrap(ca, width, break_long_words, tab_size))
+    }
+
+    /// Normalize the string values to the given Unicode normalization form.
+    #[must_use]
+    #[cfg(feature = "string_normalize")]
+    fn str_normalize(&self, form: UnicodeForm) -> StringChunked {
+        let ca = self.as_string();
+        normalize::normalize(ca, form)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
     /// `offset` can be negative, in which case the start counts from the end of the string.
     fn str_slice(&self, offset: &Series, length: &Series) -> PolarsResult<StringChunked> {
         let ca = self.as_string();
-        let offset = offset.cast(&DataType::Int64)?;
+        for (name, s) in [("offset", offset), ("length", length)] {
+            polars_ensure!(
+                s.dtype().is_integer() || s.dtype() == &DataType::Null,
+                InvalidOperation: "`str.slice` expected an integer `{}`, got dtype `{}`",
+                name, s.dtype()
+            );
+        }
+        let offset = offset.strict_cast(&DataType::Int64)?;
         // We strict cast, otherwise negative value will be treated as a valid length.
         let length = length.strict_cast(&DataType::UInt64)?;
 
@@ -620,6 +731,10 @@ pub trait StringNameSpaceImpl: AsString {
     /// the end of the string.
     fn str_head(&self, n: &Series) -> PolarsResult<StringChunked> {
         let ca = self.as_string();
+        polars_ensure!(
+            n.dtype().is_integer() || n.dtype() == &DataType::Null,
+            InvalidOperation: "`str.head` expected an integer `n`, got dtype `{}`", n.dtype()
+        );
         let n = n.strict_cast(&DataType::Int64)?;
 
         Ok(substring::head(ca, n.i64()?))
@@ -631,6 +746,10 @@ pub trait StringNameSpaceImpl: AsString {
     /// negative, in which case the slice begins `n` characters from the start of the string.
     fn str_tail(&self, n: &Series) -> PolarsResult<StringChunked> {
         let ca = self.as_string();
+        polars_ensure!(
+            n.dtype().is_integer() || n.dtype() == &DataType::Null,
+            InvalidOperation: "`str.tail` expected an integer `n`, got dtype `{}`", n.dtype()
+        );
         let n = n.strict_cast(&DataType::Int64)?;
 
         Ok(substring::tail(ca, n.i64()?))
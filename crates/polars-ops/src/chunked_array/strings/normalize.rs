@@ -0,0 +1,53 @@
+use arrow::array::{Array, MutableBinaryViewArray, Utf8ViewArray};
+use polars_core::prelude::StringChunked;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+/// A Unicode normalization form, see [UAX #15](https://www.unicode.org/reports/tr15/).
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnicodeForm {
+    /// Canonical decomposition, followed by canonical composition.
+    NFC,
+    /// Canonical decomposition.
+    NFD,
+    /// Compatibility decomposition, followed by canonical composition.
+    NFKC,
+    /// Compatibility decomposition.
+    NFKD,
+}
+
+fn normalize_helper(s: &str, form: UnicodeForm, buf: &mut String) {
+    buf.clear();
+    match form {
+        UnicodeForm::NFC => buf.extend(s.nfc()),
+        UnicodeForm::NFD => buf.extend(s.nfd()),
+        UnicodeForm::NFKC => buf.extend(s.nfkc()),
+        UnicodeForm::NFKD => buf.extend(s.nfkd()),
+    }
+}
+
+pub(super) fn normalize(ca: &StringChunked, form: UnicodeForm) -> StringChunked {
+    // Amortize allocation.
+    let mut buf = String::new();
+    let chunks = ca.downcast_iter().map(|arr| {
+        // ASCII strings are the same in every normalization form, so ASCII chunks are reused.
+        if arr.values_iter().all(|s| s.is_ascii()) {
+            return arr.clone();
+        }
+
+        let mut out = MutableBinaryViewArray::<str>::with_capacity(arr.len());
+        for s in arr.values_iter() {
+            if s.is_ascii() {
+                out.push_value_ignore_validity(s);
+            } else {
+                normalize_helper(s, form, &mut buf);
+                out.push_value_ignore_validity(buf.as_str());
+            }
+        }
+        let out: Utf8ViewArray = out.into();
+        out.with_validity(arr.validity().cloned())
+    });
+    StringChunked::from_chunk_iter(ca.name(), chunks)
+}
@@ -1,54 +1,94 @@
 use std::fmt::Write;
 
 use polars_core::prelude::arity::broadcast_binary_elementwise;
-use polars_core::prelude::{StringChunked, UInt64Chunked};
+use polars_core::prelude::{polars_ensure, PolarsResult, StringChunked, UInt64Chunked};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-pub(super) fn pad_end<'a>(ca: &'a StringChunked, length: usize, fill_char: char) -> StringChunked {
-    // amortize allocation
-    let mut buf = String::new();
-    let f = |s: &'a str| {
-        let padding = length.saturating_sub(s.chars().count());
-        if padding == 0 {
-            s
-        } else {
-            buf.clear();
-            buf.push_str(s);
-            for _ in 0..padding {
-                buf.push(fill_char)
-            }
-            // extend lifetime
-            // lifetime is bound to 'a
-            let slice = buf.as_str();
-            unsafe { std::mem::transmute::<&str, &'a str>(slice) }
-        }
+fn pad_fn<'a>(
+    s: Option<&'a str>,
+    length: Option<u64>,
+    fill_char: char,
+    fill_width: Option<usize>,
+    at_start: bool,
+    buf: &mut String,
+) -> Option<&'a str> {
+    let (s, length) = (s?, length? as usize);
+    // Without a `fill_width` the length is counted in chars, otherwise in display width.
+    let n_fill = match fill_width {
+        None => length.saturating_sub(s.chars().count()),
+        Some(fill_width) => length.saturating_sub(s.width()) / fill_width,
     };
-    ca.apply_mut(f)
+    if n_fill == 0 {
+        return Some(s);
+    }
+
+    buf.clear();
+    if !at_start {
+        buf.push_str(s);
+    }
+    buf.extend(std::iter::repeat(fill_char).take(n_fill));
+    if at_start {
+        buf.push_str(s);
+    }
+    // extend lifetime
+    // lifetime is bound to 'a
+    let slice = buf.as_str();
+    Some(unsafe { std::mem::transmute::<&str, &'a str>(slice) })
 }
 
-pub(super) fn pad_start<'a>(
-    ca: &'a StringChunked,
-    length: usize,
+fn pad(
+    ca: &StringChunked,
+    length: &UInt64Chunked,
     fill_char: char,
-) -> StringChunked {
+    display_width: bool,
+    at_start: bool,
+) -> PolarsResult<StringChunked> {
+    let name = if at_start { "pad_start" } else { "pad_end" };
+    polars_ensure!(
+        ca.len() == length.len() || ca.len() == 1 || length.len() == 1,
+        ShapeMismatch: "`str.{}` expected `length` of length 1 or {}, got {}",
+        name, ca.len(), length.len()
+    );
+    let fill_width = if display_width {
+        let width = fill_char.width().unwrap_or(0);
+        polars_ensure!(
+            width > 0,
+            InvalidOperation: "`str.{}` expected a `fill_char` with a display width, got {:?}",
+            name, fill_char
+        );
+        Some(width)
+    } else {
+        None
+    };
+
     // amortize allocation
     let mut buf = String::new();
-    let f = |s: &'a str| {
-        let padding = length.saturating_sub(s.chars().count());
-        if padding == 0 {
-            s
-        } else {
-            buf.clear();
-            for _ in 0..padding {
-                buf.push(fill_char)
-            }
-            buf.push_str(s);
-            // extend lifetime
-            // lifetime is bound to 'a
-            let slice = buf.as_str();
-            unsafe { std::mem::transmute::<&str, &'a str>(slice) }
-        }
-    };
-    ca.apply_mut(f)
+    fn infer<F: for<'a> FnMut(Option<&'a str>, Option<u64>) -> Option<&'a str>>(f: F) -> F where {
+        f
+    }
+    Ok(broadcast_binary_elementwise(
+        ca,
+        length,
+        infer(|opt_s, opt_len| pad_fn(opt_s, opt_len, fill_char, fill_width, at_start, &mut buf)),
+    ))
+}
+
+pub(super) fn pad_start(
+    ca: &StringChunked,
+    length: &UInt64Chunked,
+    fill_char: char,
+    display_width: bool,
+) -> PolarsResult<StringChunked> {
+    pad(ca, length, fill_char, display_width, true)
+}
+
+pub(super) fn pad_end(
+    ca: &StringChunked,
+    length: &UInt64Chunked,
+    fill_char: char,
+    display_width: bool,
+) -> PolarsResult<StringChunked> {
+    pad(ca, length, fill_char, display_width, false)
 }
 
 fn zfill_fn<'a>(s: Option<&'a str>, len: Option<u64>, buf: &mut String) -> Option<&'a str> {
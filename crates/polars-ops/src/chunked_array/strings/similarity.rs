@@ -0,0 +1,146 @@
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+
+/// Reusable buffers to compare the chars of two strings.
+#[derive(Default)]
+struct CharBuffers {
+    a: Vec<char>,
+    b: Vec<char>,
+    scratch: Vec<u32>,
+}
+
+impl CharBuffers {
+    /// Compare `a` and `b` with `f`, on their bytes if both are ASCII and on their chars otherwise.
+    fn compare<R>(
+        &mut self,
+        a: &str,
+        b: &str,
+        f: impl Fn(&[char], &[char], &mut Vec<u32>) -> R,
+        f_ascii: impl Fn(&[u8], &[u8], &mut Vec<u32>) -> R,
+    ) -> R {
+        if a.is_ascii() && b.is_ascii() {
+            f_ascii(a.as_bytes(), b.as_bytes(), &mut self.scratch)
+        } else {
+            self.a.clear();
+            self.a.extend(a.chars());
+            self.b.clear();
+            self.b.extend(b.chars());
+            f(&self.a, &self.b, &mut self.scratch)
+        }
+    }
+}
+
+fn levenshtein_helper<T: PartialEq>(a: &[T], b: &[T], row: &mut Vec<u32>) -> u32 {
+    // Only keep the previous row of the edit distance matrix.
+    row.clear();
+    row.extend(0..=b.len() as u32);
+    for (i, x) in a.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i as u32 + 1;
+        for (j, y) in b.iter().enumerate() {
+            let substitution = diag + (x != y) as u32;
+            diag = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diag + 1);
+        }
+    }
+    row[b.len()]
+}
+
+fn hamming_helper<T: PartialEq>(a: &[T], b: &[T], _: &mut Vec<u32>) -> Option<u32> {
+    (a.len() == b.len()).then(|| a.iter().zip(b).filter(|(x, y)| x != y).count() as u32)
+}
+
+fn jaro_winkler_helper<T: PartialEq>(a: &[T], b: &[T], matched: &mut Vec<u32>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // Characters match if they are equal and not further apart than the window. The first
+    // `a.len()` flags mark the matched characters of `a`, the others those of `b`.
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    matched.clear();
+    matched.resize(a.len() + b.len(), 0);
+    let (a_matched, b_matched) = matched.split_at_mut(a.len());
+    let mut n_matches = 0;
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        if let Some(j) = (start..end).find(|&j| b_matched[j] == 0 && b[j] == *x) {
+            a_matched[i] = 1;
+            b_matched[j] = 1;
+            n_matches += 1;
+        }
+    }
+    if n_matches == 0 {
+        return 0.0;
+    }
+
+    // Half of the matched characters that are in a different order in `a` and `b`.
+    let a_order = a.iter().zip(a_matched.iter()).filter(|(_, &m)| m == 1);
+    let b_order = b.iter().zip(b_matched.iter()).filter(|(_, &m)| m == 1);
+    let transpositions = a_order
+        .zip(b_order)
+        .filter(|((x, _), (y, _))| x != y)
+        .count()
+        / 2;
+
+    let m = n_matches as f64;
+    let jaro = (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0;
+
+    // Boost the similarity of strings with a common prefix of up to 4 characters.
+    let prefix = a.iter().zip(b).take(4).take_while(|(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn ensure_same_len(ca: &StringChunked, other: &StringChunked, name: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+        ShapeMismatch: "`str.{}` expected `other` of length 1 or {}, got {}",
+        name, ca.len(), other.len()
+    );
+    Ok(())
+}
+
+/// The Levenshtein distance between the strings of `ca` and `other`, the minimal number of
+/// single-character insertions, deletions and substitutions to change one into the other.
+pub fn levenshtein(ca: &StringChunked, other: &StringChunked) -> PolarsResult<UInt32Chunked> {
+    ensure_same_len(ca, other, "levenshtein")?;
+    let mut buf = CharBuffers::default();
+    Ok(broadcast_binary_elementwise(
+        ca,
+        other,
+        |a: Option<&str>, b: Option<&str>| {
+            Some(buf.compare(a?, b?, levenshtein_helper, levenshtein_helper))
+        },
+    ))
+}
+
+/// The Hamming distance between the strings of `ca` and `other`, the number of positions at
+/// which their characters differ. This is null if the strings have a different number of
+/// characters.
+pub fn hamming(ca: &StringChunked, other: &StringChunked) -> PolarsResult<UInt32Chunked> {
+    ensure_same_len(ca, other, "hamming")?;
+    let mut buf = CharBuffers::default();
+    Ok(broadcast_binary_elementwise(
+        ca,
+        other,
+        |a: Option<&str>, b: Option<&str>| buf.compare(a?, b?, hamming_helper, hamming_helper),
+    ))
+}
+
+/// The Jaro-Winkler similarity between the strings of `ca` and `other`, from 0 for strings
+/// without any characters in common to 1 for equal strings.
+pub fn jaro_winkler(ca: &StringChunked, other: &StringChunked) -> PolarsResult<Float64Chunked> {
+    ensure_same_len(ca, other, "jaro_winkler")?;
+    let mut buf = CharBuffers::default();
+    Ok(broadcast_binary_elementwise(
+        ca,
+        other,
+        |a: Option<&str>, b: Option<&str>| {
+            Some(buf.compare(a?, b?, jaro_winkler_helper, jaro_winkler_helper))
+        },
+    ))
+}
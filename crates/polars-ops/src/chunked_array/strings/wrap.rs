@@ -0,0 +1,102 @@
+use polars_core::prelude::StringChunked;
+
+fn expand_tabs(line: &str, tab_size: usize, out: &mut String) {
+    let mut col = 0;
+    for c in line.chars() {
+        if c == '\t' {
+            if tab_size > 0 {
+                let n = tab_size - col % tab_size;
+                out.extend(std::iter::repeat(' ').take(n));
+                col += n;
+            }
+        } else {
+            out.push(c);
+            col += 1;
+        }
+    }
+}
+
+fn wrap_line(line: &str, width: usize, break_long_words: bool, out: &mut String) {
+    if line.chars().count() <= width {
+        out.push_str(line);
+        return;
+    }
+
+    // The length of the output line that is being filled, `None` before the first word.
+    let mut len = None;
+    let mut rest = line;
+    while !rest.is_empty() {
+        let word_start = rest.find(|c| c != ' ').unwrap_or(rest.len());
+        let (space, tail) = rest.split_at(word_start);
+        let word_end = tail.find(' ').unwrap_or(tail.len());
+        let (word, tail) = tail.split_at(word_end);
+        rest = tail;
+        if word.is_empty() {
+            // Trailing whitespace is dropped.
+            break;
+        }
+
+        let word_len = word.chars().count();
+        let space_len = space.chars().count();
+        match len {
+            // The indentation of the first line is kept.
+            None => {
+                out.push_str(space);
+                len = Some(space_len);
+            },
+            // A word that is broken anyway starts on the current line if there is space left.
+            Some(n)
+                if n + space_len + word_len <= width
+                    || (break_long_words && word_len > width && n + space_len < width) =>
+            {
+                out.push_str(space);
+                len = Some(n + space_len);
+            },
+            // The whitespace at a line break is dropped.
+            Some(_) => {
+                out.push('\n');
+                len = Some(0);
+            },
+        }
+
+        // Break a word that doesn't fit on the line into pieces that fill the lines.
+        let mut n = len.unwrap();
+        let mut word = word;
+        if break_long_words {
+            while n + word.chars().count() > width {
+                if n < width {
+                    let split = word.char_indices().nth(width - n).unwrap().0;
+                    out.push_str(&word[..split]);
+                    word = &word[split..];
+                }
+                out.push('\n');
+                n = 0;
+            }
+        }
+        out.push_str(word);
+        len = Some(n + word.chars().count());
+    }
+}
+
+pub(super) fn wrap(
+    ca: &StringChunked,
+    width: usize,
+    break_long_words: bool,
+    tab_size: usize,
+) -> StringChunked {
+    let mut expanded = String::new();
+    ca.apply_to_buffer(|s, buf| {
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                buf.push('\n');
+            }
+            if line.contains('\t') {
+                expanded.clear();
+                expand_tabs(line, tab_size, &mut expanded);
+                wrap_line(&expanded, width, break_long_words, buf);
+            } else {
+                wrap_line(line, width, break_long_words, buf);
+            }
+        }
+    })
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+/// Translate the physical values of the categorical join keys of the right side to those of
+/// the left side, so that categoricals from different sources can be joined without a global
+/// string cache, merging their categories or casting them to strings.
+///
+/// The left keys are not copied. Every category that only occurs on the right side is mapped
+/// to its own physical value that does not occur on the left side, so that distinct keys stay
+/// distinct, e.g. for join validation. The translated keys can only be used to find matches
+/// and not to materialize key values, e.g. when coalescing the keys of an outer join.
+///
+/// Returns `None` if the keys are not both categoricals.
+pub(crate) fn translate_categorical_keys(
+    left: &Series,
+    right: &Series,
+) -> Option<(Series, Series)> {
+    let (DataType::Categorical(Some(rev_map_l), _), DataType::Categorical(Some(rev_map_r), _)) =
+        (left.dtype(), right.dtype())
+    else {
+        return None;
+    };
+
+    // The physical value of every category on the left side and the first value that is not
+    // used by the left side.
+    let (lookup, mut missing): (PlHashMap<&str, u32>, u32) = match &**rev_map_l {
+        RevMapping::Local(categories, _) => (
+            categories
+                .values_iter()
+                .enumerate()
+                .map(|(idx, s)| (s, idx as u32))
+                .collect(),
+            u32::try_from(categories.len()).ok()?,
+        ),
+        RevMapping::Global(map, categories, _) => (
+            map.iter()
+                // SAFETY: the local indices are in bounds of the categories.
+                .map(|(&global, &local)| {
+                    (
+                        unsafe { categories.value_unchecked(local as usize) },
+                        global,
+                    )
+                })
+                .collect(),
+            map.keys().max().map_or(Some(0), |max| max.checked_add(1))?,
+        ),
+    };
+
+    // The translation table from the categories of the right side to physical values of the
+    // left side.
+    let table = rev_map_r
+        .get_categories()
+        .values_iter()
+        .map(|s| match lookup.get(s) {
+            Some(&v) => Some(v),
+            None => {
+                let v = missing;
+                missing = missing.checked_add(1)?;
+                Some(v)
+            },
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let physical_r = right.categorical().unwrap().physical();
+    let translated: UInt32Chunked = match &**rev_map_r {
+        RevMapping::Local(_, _) => {
+            physical_r.apply_nonnull_values_generic(DataType::UInt32, |v| table[v as usize])
+        },
+        RevMapping::Global(map, _, _) => physical_r
+            .apply_nonnull_values_generic(DataType::UInt32, |v| {
+                table[*map.get(&v).unwrap() as usize]
+            }),
+    };
+
+    let physical_l = left.categorical().unwrap().physical().clone();
+    Some((
+        physical_l.into_series(),
+        translated.with_name(right.name()).into_series(),
+    ))
+}
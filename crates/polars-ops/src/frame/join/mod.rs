@@ -2,6 +2,8 @@ mod args;
 #[cfg(feature = "asof_join")]
 mod asof;
 #[cfg(feature = "dtype-categorical")]
+mod categorical;
+#[cfg(feature = "dtype-categorical")]
 mod checks;
 mod cross_join;
 mod general;
@@ -19,6 +21,8 @@ use arrow::trusted_len::TrustedLen;
 #[cfg(feature = "asof_join")]
 pub use asof::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy};
 #[cfg(feature = "dtype-categorical")]
+use categorical::translate_categorical_keys;
+#[cfg(feature = "dtype-categorical")]
 pub(crate) use checks::*;
 pub use cross_join::CrossJoin;
 #[cfg(feature = "chunked_ids")]
@@ -181,10 +185,21 @@ pub trait DataFrameJoinOps: IntoDf {
             match _check_categorical_src(l.dtype(), r.dtype()) {
                 Ok(_) => {},
                 Err(_) => {
-                    let (ca_left, ca_right) =
-                        make_categoricals_compatible(l.categorical()?, r.categorical()?)?;
-                    *l = ca_left.into_series().with_name(l.name());
-                    *r = ca_right.into_series().with_name(r.name());
+                    // These joins only use the keys to find matches, so the keys of the right
+                    // side can be translated to those of the left side.
+                    let only_matches = match args.how {
+                        JoinType::Inner | JoinType::Left => true,
+                        #[cfg(feature = "semi_anti_join")]
+                        JoinType::Semi | JoinType::Anti => true,
+                        _ => false,
+                    };
+                    let translated = only_matches
+                        .then(|| translate_categorical_keys(l, r))
+                        .flatten();
+                    (*l, *r) = match translated {
+                        Some(keys) => keys,
+                        None => crate::series::make_compatible(l, r)?,
+                    };
                 },
             }
         }
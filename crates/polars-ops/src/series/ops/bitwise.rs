@@ -0,0 +1,96 @@
+use num_traits::{CheckedShl, CheckedShr, PrimInt};
+use polars_core::prelude::arity::broadcast_binary_elementwise_values;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_integer_polars_type;
+
+/// Shift the bits of every value of `s` to the left by the number of bits in `n`. Bits that
+/// are shifted past the width of the integer type are discarded.
+pub fn shift_left(s: &Series, n: &Series) -> PolarsResult<Series> {
+    shift(s, n, true)
+}
+
+/// Shift the bits of every value of `s` to the right by the number of bits in `n`. Signed
+/// integers are shifted arithmetically, i.e. negative values remain negative.
+pub fn shift_right(s: &Series, n: &Series) -> PolarsResult<Series> {
+    shift(s, n, false)
+}
+
+fn shift(s: &Series, n: &Series, left: bool) -> PolarsResult<Series> {
+    let name = if left { "shift_left" } else { "shift_right" };
+    polars_ensure!(
+        s.dtype().is_integer(),
+        InvalidOperation: "`{}` operation not supported for dtype `{}`", name, s.dtype()
+    );
+    polars_ensure!(
+        n.len() == 1 || s.len() == 1 || n.len() == s.len(),
+        ShapeMismatch: "cannot `{}` a column of length {} by {} values", name, s.len(), n.len()
+    );
+    let n = n.strict_cast(&DataType::UInt32)?;
+    let n = n.u32().unwrap();
+    with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_any().downcast_ref().unwrap();
+        let out: ChunkedArray<$T> = if left {
+            broadcast_binary_elementwise_values(ca, n, shift_left_value)
+        } else {
+            broadcast_binary_elementwise_values(ca, n, shift_right_value)
+        };
+        Ok(out.with_name(s.name()).into_series())
+    })
+}
+
+fn shift_left_value<T: PrimInt + CheckedShl>(v: T, n: u32) -> T {
+    v.checked_shl(n).unwrap_or(T::zero())
+}
+
+fn shift_right_value<T: PrimInt + CheckedShr>(v: T, n: u32) -> T {
+    v.checked_shr(n)
+        .unwrap_or(if v < T::zero() { !T::zero() } else { T::zero() })
+}
+
+/// A reduction over the bits of the non-null values of a column.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BitwiseReduction {
+    And,
+    Or,
+    Xor,
+}
+
+/// Reduce the non-null values of an integer or boolean `s` to a single value with `reduction`.
+/// The result is null if `s` contains no non-null values.
+pub fn bitwise_reduce(s: &Series, reduction: BitwiseReduction) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Boolean => {
+            let ca = s.bool().unwrap();
+            let values = ca
+                .downcast_iter()
+                .flat_map(|arr| arr.non_null_values_iter());
+            let out = reduce(values, reduction);
+            Ok(BooleanChunked::from_slice_options(s.name(), &[out]).into_series())
+        },
+        dt if dt.is_integer() => {
+            with_match_physical_integer_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s.as_any().downcast_ref().unwrap();
+                let values = ca.downcast_iter().flat_map(|arr| arr.non_null_values_iter());
+                let out = reduce(values, reduction);
+                Ok(ChunkedArray::<$T>::from_slice_options(s.name(), &[out]).into_series())
+            })
+        },
+        dt => polars_bail!(
+            InvalidOperation: "bitwise aggregation not supported for dtype `{}`", dt
+        ),
+    }
+}
+
+fn reduce<T, I>(mut values: I, reduction: BitwiseReduction) -> Option<T>
+where
+    T: std::ops::BitAnd<Output = T> + std::ops::BitOr<Output = T> + std::ops::BitXor<Output = T>,
+    I: Iterator<Item = T>,
+{
+    let first = values.next()?;
+    let out = match reduction {
+        BitwiseReduction::And => values.fold(first, |acc, v| acc & v),
+        BitwiseReduction::Or => values.fold(first, |acc, v| acc | v),
+        BitwiseReduction::Xor => values.fold(first, |acc, v| acc ^ v),
+    };
+    Some(out)
+}
@@ -19,11 +19,29 @@ pub enum Roll {
     Raise,
 }
 
+/// Cast Datetime values to the Date in their (local) time zone.
+fn to_date(s: &Series) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Date => Ok(s.clone()),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, None) => s.cast(&DataType::Date),
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(_)) => replace_time_zone(
+            s.datetime().unwrap(),
+            None,
+            &StringChunked::from_iter(std::iter::once("raise")),
+            NonExistent::Raise,
+        )?
+        .cast(&DataType::Date),
+        dt => polars_bail!(InvalidOperation: "expected date or datetime, got {}", dt),
+    }
+}
+
 /// Count the number of business days between `start` and `end`, excluding `end`.
 ///
 /// # Arguments
-/// - `start`: Series holding start dates.
-/// - `end`: Series holding end dates.
+/// - `start`: Series holding start dates. Datetimes are counted by their (local) date.
+/// - `end`: Series holding end dates. Datetimes are counted by their (local) date.
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
 ///   days since the UNIX epoch.
@@ -38,6 +56,7 @@ pub fn business_day_count(
     }
 
     let holidays = normalise_holidays(holidays, &week_mask);
+    let (start, end) = (to_date(start)?, to_date(end)?);
     let start_dates = start.date()?;
     let end_dates = end.date()?;
     let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
@@ -192,13 +211,12 @@ pub fn add_business_days(
 
     let holidays = normalise_holidays(holidays, &week_mask);
     let start_dates = start.date()?;
-    let n = match &n.dtype() {
-        DataType::Int64 | DataType::UInt64 | DataType::UInt32 => n.cast(&DataType::Int32)?,
-        DataType::Int32 => n.clone(),
-        _ => {
-            polars_bail!(InvalidOperation: "expected Int64, Int32, UInt64, or UInt32, got {}", n.dtype())
-        },
-    };
+    polars_ensure!(
+        n.dtype().is_integer(),
+        InvalidOperation: "expected an integer `n`, got {}", n.dtype()
+    );
+    // Strict cast, so that an `n` that overflows an Int32 raises instead of becoming null.
+    let n = n.strict_cast(&DataType::Int32)?;
     let n = n.i32()?;
     let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
 
@@ -260,6 +278,37 @@ pub fn add_business_days(
     Ok(out.into_date().into_series())
 }
 
+/// Whether the dates are business days.
+///
+/// # Arguments
+/// - `dates`: Series holding dates. Datetimes are checked by their (local) date.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+pub fn is_business_day(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let dates = to_date(dates)?;
+    let out: BooleanChunked =
+        dates
+            .date()?
+            .apply_nonnull_values_generic(DataType::Boolean, |date| {
+                // SAFETY: week_mask is length 7, get_day_of_week result is between 0 and 6
+                unsafe {
+                    *week_mask.get_unchecked(get_day_of_week(date))
+                        && holidays.binary_search(&date).is_err()
+                }
+            });
+    Ok(out.into_series())
+}
+
 /// Ported from:
 /// https://github.com/numpy/numpy/blob/e59c074842e3f73483afa5ddef031e856b9fd313/numpy/_core/src/multiarray/datetime_busday.c#L265-L353
 fn add_business_days_impl(
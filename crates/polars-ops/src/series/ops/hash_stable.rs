@@ -0,0 +1,70 @@
+use polars_core::prelude::*;
+use polars_core::with_match_physical_integer_polars_type;
+use xxhash_rust::xxh64::xxh64;
+
+/// Hash every value of `s` with XXH64 and the given `seed`.
+///
+/// Unlike [`Series::hash`], the result is guaranteed to be the same across versions and
+/// platforms, so it can be persisted, e.g. as a partitioning key. The hashed bytes are:
+/// - booleans: a single byte that is `0` or `1`.
+/// - integers, decimals and temporal types: the physical value in little endian byte order.
+///   The hash thus depends on the width of the integer type and on the time unit.
+/// - floats: the value in little endian byte order, where `-0.0` is hashed as `0.0` and all
+///   `NaN`s as the canonical `NaN`.
+/// - strings, categoricals and enums: the UTF-8 bytes of the string.
+/// - binary: the bytes themselves.
+///
+/// Null values remain null.
+pub fn hash_stable(s: &Series, seed: u64) -> PolarsResult<UInt64Chunked> {
+    let out = match s.dtype() {
+        DataType::Boolean => s
+            .bool()
+            .unwrap()
+            .apply_values_generic(|v| xxh64(&[v as u8], seed)),
+        DataType::String => s
+            .str()
+            .unwrap()
+            .apply_values_generic(|v| xxh64(v.as_bytes(), seed)),
+        DataType::Binary => s.binary().unwrap().apply_values_generic(|v| xxh64(v, seed)),
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+            return hash_stable(&s.cast(&DataType::String)?, seed)
+        },
+        DataType::Float32 => s.f32().unwrap().apply_values_generic(|v| {
+            let v = if v.is_nan() {
+                f32::NAN
+            } else if v == 0.0 {
+                0.0
+            } else {
+                v
+            };
+            xxh64(&v.to_le_bytes(), seed)
+        }),
+        DataType::Float64 => s.f64().unwrap().apply_values_generic(|v| {
+            let v = if v.is_nan() {
+                f64::NAN
+            } else if v == 0.0 {
+                0.0
+            } else {
+                v
+            };
+            xxh64(&v.to_le_bytes(), seed)
+        }),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, _) => s
+            .decimal()
+            .unwrap()
+            .apply_values_generic(|v| xxh64(&v.to_le_bytes(), seed)),
+        dt if dt.to_physical().is_integer() => {
+            let s = s.to_physical_repr();
+            with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                let ca: &ChunkedArray<$T> = s.as_any().downcast_ref().unwrap();
+                ca.apply_values_generic(|v| xxh64(&v.to_le_bytes(), seed))
+            })
+        },
+        dt => polars_bail!(
+            InvalidOperation: "`hash_stable` operation not supported for dtype `{}`", dt
+        ),
+    };
+    Ok(out.with_name(s.name()))
+}
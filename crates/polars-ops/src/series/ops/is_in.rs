@@ -205,6 +205,50 @@ fn is_in_string_list_categorical(
     Ok(ca)
 }
 
+#[cfg(all(feature = "dtype-categorical", feature = "dtype-array"))]
+fn is_in_string_array_categorical(
+    ca_in: &StringChunked,
+    other: &Series,
+    rev_map: &Arc<RevMapping>,
+) -> PolarsResult<BooleanChunked> {
+    let mut ca = if ca_in.len() == 1 && other.len() != 1 {
+        let opt_val = ca_in.get(0);
+        match opt_val.map(|val| rev_map.find(val)) {
+            None => other
+                .array()?
+                .apply_amortized_generic(|opt_s| opt_s.map(|s| s.as_ref().null_count() > 0)),
+            Some(None) => other
+                .array()?
+                .apply_amortized_generic(|opt_s| opt_s.map(|_| false)),
+            Some(Some(idx)) => other.array()?.apply_amortized_generic(|opt_s| {
+                opt_s.map(|s| {
+                    let ca = s.as_ref().categorical().unwrap();
+                    ca.physical().iter().any(|a| a == Some(idx))
+                })
+            }),
+        }
+    } else {
+        polars_ensure!(ca_in.len() == other.len(), ComputeError: "shapes don't match: expected {} elements in 'is_in' comparison, got {}", ca_in.len(), other.len());
+        ca_in
+            .iter()
+            .zip(other.array()?.amortized_iter())
+            .map(|(opt_val, series)| match (opt_val, series) {
+                (opt_val, Some(series)) => match opt_val.map(|val| rev_map.find(val)) {
+                    None => Some(series.as_ref().null_count() > 0),
+                    Some(None) => Some(false),
+                    Some(Some(idx)) => {
+                        let ca = series.as_ref().categorical().unwrap();
+                        Some(ca.physical().iter().any(|el| el == Some(idx)))
+                    },
+                },
+                _ => None,
+            })
+            .collect()
+    };
+    ca.rename(ca_in.name());
+    Ok(ca)
+}
+
 fn is_in_string(ca_in: &StringChunked, other: &Series) -> PolarsResult<BooleanChunked> {
     match other.dtype() {
         #[cfg(feature = "dtype-categorical")]
@@ -218,6 +262,17 @@ fn is_in_string(ca_in: &StringChunked, other: &Series) -> PolarsResult<BooleanCh
                 _ => unreachable!(),
             }
         },
+        #[cfg(all(feature = "dtype-categorical", feature = "dtype-array"))]
+        DataType::Array(dt, _)
+            if matches!(&**dt, DataType::Categorical(_, _) | DataType::Enum(_, _)) =>
+        {
+            match &**dt {
+                DataType::Enum(Some(rev_map), _) | DataType::Categorical(Some(rev_map), _) => {
+                    is_in_string_array_categorical(ca_in, other, rev_map)
+                },
+                _ => unreachable!(),
+            }
+        },
         DataType::List(dt) if DataType::String == **dt => is_in_binary(
             &ca_in.as_binary(),
             &other
@@ -632,6 +687,12 @@ fn is_in_cat(ca_in: &CategoricalChunked, other: &Series) -> PolarsResult<Boolean
         {
             is_in_cat_list(ca_in, other)
         },
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(dt, _)
+            if matches!(&**dt, DataType::Categorical(_, _) | DataType::Enum(_, _)) =>
+        {
+            is_in_cat_array(ca_in, other)
+        },
 
         _ => polars_bail!(opq = is_in, ca_in.dtype(), other.dtype()),
     }
@@ -668,7 +729,12 @@ fn is_in_cat_list(ca_in: &CategoricalChunked, other: &Series) -> PolarsResult<Bo
         polars_ensure!(ca_in.len() == other.len(), ComputeError: "shapes don't match: expected {} elements in 'is_in' comparison, got {}", ca_in.len(), other.len());
         let list_chunked_inner = list_chunked.get_inner();
         let inner_cat = list_chunked_inner.categorical()?;
-        // Make physicals compatible of ca_in with those of the list
+        // Make physicals compatible of ca_in with those of the list. This requires the
+        // physicals of the list to remain the same.
+        polars_ensure!(
+            !categoricals_from_different_caches(inner_cat, ca_in),
+            string_cache_mismatch
+        );
         let (_, ca_in) = make_categoricals_compatible(inner_cat, ca_in)?;
 
         // SAFETY: unstable series never lives longer than the iterator.
@@ -691,6 +757,62 @@ fn is_in_cat_list(ca_in: &CategoricalChunked, other: &Series) -> PolarsResult<Bo
     Ok(ca)
 }
 
+#[cfg(all(feature = "dtype-categorical", feature = "dtype-array"))]
+fn is_in_cat_array(ca_in: &CategoricalChunked, other: &Series) -> PolarsResult<BooleanChunked> {
+    let array_chunked = other.array()?;
+
+    let mut ca: BooleanChunked = if ca_in.len() == 1 && other.len() != 1 {
+        let (DataType::Categorical(Some(rev_map), _) | DataType::Enum(Some(rev_map), _)) =
+            array_chunked.inner_dtype()
+        else {
+            unreachable!();
+        };
+
+        let idx = ca_in.physical().get(0);
+        let new_phys = idx
+            .map(|idx| ca_in.get_rev_map().get(idx))
+            .map(|s| rev_map.find(s));
+
+        match new_phys {
+            None => array_chunked
+                .apply_amortized_generic(|opt_s| opt_s.map(|s| s.as_ref().null_count() > 0)),
+            Some(None) => array_chunked.apply_amortized_generic(|opt_s| opt_s.map(|_| false)),
+            Some(Some(idx)) => array_chunked.apply_amortized_generic(|opt_s| {
+                opt_s.map(|s| {
+                    let ca = s.as_ref().categorical().unwrap();
+                    ca.physical().iter().any(|a| a == Some(idx))
+                })
+            }),
+        }
+    } else {
+        polars_ensure!(ca_in.len() == other.len(), ComputeError: "shapes don't match: expected {} elements in 'is_in' comparison, got {}", ca_in.len(), other.len());
+        let array_chunked_inner = array_chunked.get_inner();
+        let inner_cat = array_chunked_inner.categorical()?;
+        // Make physicals compatible of ca_in with those of the array. This requires the
+        // physicals of the array to remain the same.
+        polars_ensure!(
+            !categoricals_from_different_caches(inner_cat, ca_in),
+            string_cache_mismatch
+        );
+        let (_, ca_in) = make_categoricals_compatible(inner_cat, ca_in)?;
+
+        ca_in
+            .physical()
+            .iter()
+            .zip(array_chunked.amortized_iter())
+            .map(|(value, series)| match (value, series) {
+                (val, Some(series)) => {
+                    let ca = series.as_ref().categorical().unwrap();
+                    Some(ca.physical().iter().any(|a| a == val))
+                },
+                _ => None,
+            })
+            .collect_trusted()
+    };
+    ca.rename(ca_in.name());
+    Ok(ca)
+}
+
 pub fn is_in(s: &Series, other: &Series) -> PolarsResult<BooleanChunked> {
     match s.dtype() {
         #[cfg(feature = "dtype-categorical")]
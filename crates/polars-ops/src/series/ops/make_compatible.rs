@@ -0,0 +1,66 @@
+use polars_core::prelude::*;
+
+/// Re-encode two categorical series, or two list series of categoricals, such that their
+/// physical values refer to the same categories.
+///
+/// Categoricals built under the same global string cache only get their categories merged.
+/// Otherwise, e.g. when they are built without a string cache or under different ones, the
+/// physical values are re-mapped to shared local categories without casting every value to
+/// a string. Enums can only be combined with the same enum.
+pub fn make_compatible(left: &Series, right: &Series) -> PolarsResult<(Series, Series)> {
+    let is_categorical =
+        |dt: &DataType| matches!(dt, DataType::Categorical(_, _) | DataType::Enum(_, _));
+    let (new_left, new_right) = match (left.dtype(), right.dtype()) {
+        (l, r) if is_categorical(l) && is_categorical(r) => {
+            let (l, r) = make_categoricals_compatible(left.categorical()?, right.categorical()?)?;
+            (l.into_series(), r.into_series())
+        },
+        (DataType::List(l), DataType::List(r)) if is_categorical(l) && is_categorical(r) => {
+            let (l, r) =
+                make_list_categoricals_compatible(left.list()?.clone(), right.list()?.clone())?;
+            (l.into_series(), r.into_series())
+        },
+        (l, r) => polars_bail!(
+            InvalidOperation: "`make_compatible` operation not supported for dtypes `{}` and `{}`", l, r
+        ),
+    };
+    Ok((
+        new_left.with_name(left.name()),
+        new_right.with_name(right.name()),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::{disable_string_cache, StringCacheHolder, SINGLE_LOCK};
+
+    use super::*;
+
+    #[test]
+    fn test_make_compatible_different_caches() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+        let cat_dtype = DataType::Categorical(None, Default::default());
+
+        let local = Series::new("a", ["a", "b", "a"]).cast(&cat_dtype)?;
+        let global = {
+            let _sc = StringCacheHolder::hold();
+            Series::new("b", ["c", "b"]).cast(&cat_dtype)?
+        };
+
+        let (left, right) = make_compatible(&local, &global)?;
+        assert_eq!(left.name(), "a");
+        assert_eq!(right.name(), "b");
+        let (left, right) = (left.categorical()?, right.categorical()?);
+        assert!(left.get_rev_map().is_local());
+        assert!(left.get_rev_map().same_src(right.get_rev_map()));
+        assert_eq!(
+            left.iter_str().chain(right.iter_str()).collect::<Vec<_>>(),
+            [Some("a"), Some("b"), Some("a"), Some("c"), Some("b")]
+        );
+        assert_eq!(right.physical().get(1), left.physical().get(1));
+
+        assert!(make_compatible(&local, &Series::new("c", ["a"])).is_err());
+        Ok(())
+    }
+}
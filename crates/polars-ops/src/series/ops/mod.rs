@@ -5,6 +5,8 @@ mod approx_algo;
 #[cfg(feature = "approx_unique")]
 mod approx_unique;
 mod arg_min_max;
+#[cfg(feature = "bitwise")]
+mod bitwise;
 #[cfg(feature = "business")]
 mod business;
 mod clip;
@@ -22,6 +24,8 @@ mod ewm_by;
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "hash_stable")]
+mod hash_stable;
 mod horizontal;
 mod index;
 mod int_range;
@@ -37,6 +41,8 @@ mod is_last_distinct;
 mod is_unique;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "dtype-categorical")]
+mod make_compatible;
 #[cfg(feature = "moment")]
 mod moment;
 mod negate;
@@ -69,6 +75,8 @@ pub use approx_algo::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_unique::*;
 pub use arg_min_max::ArgAgg;
+#[cfg(feature = "bitwise")]
+pub use bitwise::*;
 #[cfg(feature = "business")]
 pub use business::*;
 pub use clip::*;
@@ -86,6 +94,8 @@ pub use ewm_by::*;
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
+#[cfg(feature = "hash_stable")]
+pub use hash_stable::*;
 pub use horizontal::*;
 pub use index::*;
 pub use int_range::*;
@@ -101,6 +111,8 @@ pub use is_last_distinct::*;
 pub use is_unique::*;
 #[cfg(feature = "log")]
 pub use log::*;
+#[cfg(feature = "dtype-categorical")]
+pub use make_compatible::*;
 #[cfg(feature = "moment")]
 pub use moment::*;
 pub use negate::*;
@@ -1,4 +1,6 @@
+use arrow::types::NativeType;
 use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
 
 pub fn reinterpret(s: &Series, signed: bool) -> PolarsResult<Series> {
     Ok(match (s.dtype(), signed) {
@@ -16,3 +18,56 @@ pub fn reinterpret(s: &Series, signed: bool) -> PolarsResult<Series> {
         ),
     })
 }
+
+/// Reinterpret the bytes of every value of `ca` as a number of type `dtype`, in little or big
+/// endian byte order. Every non-null value must be exactly as wide as `dtype`.
+pub fn binary_to_numeric(
+    ca: &BinaryChunked,
+    dtype: &DataType,
+    is_little_endian: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        dtype.is_numeric(),
+        InvalidOperation: "cannot reinterpret binary as `{}`, expected a numeric type", dtype
+    );
+    with_match_physical_numeric_polars_type!(dtype, |$T| {
+        binary_to_numeric_impl::<$T>(ca, is_little_endian).map(|ca| ca.into_series())
+    })
+}
+
+fn binary_to_numeric_impl<T: PolarsNumericType>(
+    ca: &BinaryChunked,
+    is_little_endian: bool,
+) -> PolarsResult<ChunkedArray<T>> {
+    ca.try_apply_nonnull_values_generic(|bytes| {
+        let Ok(bytes) = <T::Native as NativeType>::Bytes::try_from(bytes) else {
+            polars_bail!(
+                ComputeError: "cannot reinterpret a binary value of {} bytes as `{}`, which takes {} bytes",
+                bytes.len(), T::get_dtype(), std::mem::size_of::<T::Native>()
+            );
+        };
+        Ok(if is_little_endian {
+            T::Native::from_le_bytes(bytes)
+        } else {
+            T::Native::from_be_bytes(bytes)
+        })
+    })
+}
+
+/// Reinterpret every number of `s` as its bytes, in little or big endian byte order.
+pub fn numeric_to_binary(s: &Series, is_little_endian: bool) -> PolarsResult<BinaryChunked> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "cannot reinterpret `{}` as binary, expected a numeric type", s.dtype()
+    );
+    with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        Ok(ca.apply_values_generic(|v| {
+            if is_little_endian {
+                v.to_le_bytes().as_ref().to_vec()
+            } else {
+                v.to_be_bytes().as_ref().to_vec()
+            }
+        }))
+    })
+}
@@ -10,6 +10,8 @@ use polars_core::POOL;
 use polars_expr::state::ExecutionState;
 use polars_utils::sync::SyncPtr;
 use rayon::prelude::*;
+#[cfg(feature = "tracing")]
+use tracing::field::Empty;
 
 use crate::executors::sources::DataFrameSource;
 use crate::operators::{
@@ -184,6 +186,13 @@ impl PipeLine {
         let mut sink_finished = false;
 
         for (i, mut sink) in std::mem::take(&mut self.sinks).into_iter().enumerate() {
+            #[cfg(feature = "tracing")]
+            let span = tracing::info_span!("pipeline", sink = sink.sinks[0].fmt(), rows = Empty);
+            #[cfg(feature = "tracing")]
+            let _guard = span.enter();
+            #[cfg(feature = "tracing")]
+            let mut rows = 0;
+
             for src in &mut std::mem::take(&mut self.sources) {
                 let mut next_batches = src.get_batches(ec)?;
 
@@ -191,6 +200,13 @@ impl PipeLine {
                 while let SourceResult::GotMoreData(chunks) = next_batches {
                     // Every batches iteration we check if we must continue.
                     ec.execution_state.should_stop()?;
+                    #[cfg(feature = "tracing")]
+                    {
+                        rows += chunks
+                            .iter()
+                            .map(|chunk| chunk.data.height())
+                            .sum::<usize>();
+                    }
 
                     let (sink_result, next_batches2) = par_process_chunks(
                         chunks,
@@ -220,6 +236,9 @@ impl PipeLine {
                 }
             }
 
+            #[cfg(feature = "tracing")]
+            span.record("rows", rows);
+
             // Before we reduce we also check if we should continue.
             ec.execution_state.should_stop()?;
             let allow_recursion = sink.allow_recursion();
@@ -264,7 +283,7 @@ impl PipeLine {
                     if sink.is_join_build()
                         && (!reduced_sink.is_join_build() || (sink.node() != reduced_sink.node()))
                     {
-                        let FinalizedSink::Operator = sink.finalize(ec)? else {
+                        let FinalizedSink::Operator = finalize_sink(sink.as_mut(), ec)? else {
                             unreachable!()
                         };
                     } else {
@@ -275,7 +294,7 @@ impl PipeLine {
             }
 
             if i != last_i {
-                let sink_result = reduced_sink.finalize(ec)?;
+                let sink_result = finalize_sink(reduced_sink.as_mut(), ec)?;
                 match sink_result {
                     // turn this sink an a new source
                     FinalizedSink::Finished(df) => self.set_df_as_sources(df),
@@ -304,8 +323,29 @@ impl PipeLine {
     ) -> PolarsResult<Option<FinalizedSink>> {
         let (sink_shared_count, mut reduced_sink) = self.run_pipeline_no_finalize(ec, pipelines)?;
         assert_eq!(sink_shared_count, 0);
-        Ok(reduced_sink.finalize(ec).ok())
+        Ok(finalize_sink(reduced_sink.as_mut(), ec).ok())
+    }
+}
+
+/// Finalizes `sink` within a `tracing` span.
+fn finalize_sink(sink: &mut dyn Sink, ec: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!("sink", sink = sink.fmt(), rows = Empty, bytes = Empty);
+    #[cfg(feature = "tracing")]
+    let _guard = span.enter();
+    let out = sink.finalize(ec);
+    #[cfg(feature = "tracing")]
+    if let Ok(FinalizedSink::Finished(df)) = &out {
+        record_output(&span, df);
     }
+    out
+}
+
+/// Attaches the height and estimated size of `df` to `span` as the `rows` and `bytes` fields.
+#[cfg(feature = "tracing")]
+fn record_output(span: &tracing::Span, df: &DataFrame) {
+    span.record("rows", df.height());
+    span.record("bytes", df.estimated_size());
 }
 
 /// Executes all branches and replaces operators and sinks during execution to ensure
@@ -314,18 +354,28 @@ pub fn execute_pipeline(
     state: ExecutionState,
     mut pipelines: Vec<PipeLine>,
 ) -> PolarsResult<DataFrame> {
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!(
+        "streaming",
+        pipelines = pipelines.len(),
+        rows = Empty,
+        bytes = Empty
+    );
+    #[cfg(feature = "tracing")]
+    let _guard = span.enter();
+
     let mut pipeline = pipelines.pop().unwrap();
     let ec = PExecutionContext::new(state, pipeline.verbose);
 
     let mut sink_out = pipeline.run_pipeline(&ec, &mut pipelines)?;
-    loop {
+    let out = loop {
         match &mut sink_out {
             None => {
                 let mut pipeline = pipelines.pop().unwrap();
                 sink_out = pipeline.run_pipeline(&ec, &mut pipelines)?;
             },
-            Some(FinalizedSink::Finished(df)) => return Ok(std::mem::take(df)),
-            Some(FinalizedSink::Source(src)) => return consume_source(&mut **src, &ec),
+            Some(FinalizedSink::Finished(df)) => break Ok(std::mem::take(df)),
+            Some(FinalizedSink::Source(src)) => break consume_source(&mut **src, &ec),
 
             //
             //  1/\
@@ -342,7 +392,12 @@ pub fn execute_pipeline(
                 sink_out = pipeline.run_pipeline(&ec, &mut pipelines)?;
             },
         }
+    };
+    #[cfg(feature = "tracing")]
+    if let Ok(df) = &out {
+        record_output(&span, df);
     }
+    out
 }
 
 impl Debug for PipeLine {
@@ -29,6 +29,18 @@ impl ArrayNameSpace {
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Sum))
     }
 
+    /// Compute the product of the items in every subarray.
+    pub fn product(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Product))
+    }
+
+    /// Compute the mean of the items in every subarray.
+    pub fn mean(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Mean))
+    }
+
     /// Compute the std of the items in every subarray.
     pub fn std(self, ddof: u8) -> Expr {
         self.0
@@ -47,6 +59,16 @@ impl ArrayNameSpace {
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Median))
     }
 
+    /// Compute the quantile of the items in every subarray.
+    pub fn quantile(self, quantile: Expr, interpol: QuantileInterpolOptions) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::Quantile(interpol)),
+            &[quantile],
+            false,
+            false,
+        )
+    }
+
     /// Keep only the unique values in every sub-array.
     pub fn unique(self) -> Expr {
         self.0
@@ -59,11 +81,25 @@ impl ArrayNameSpace {
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Unique(true)))
     }
 
+    /// Count the number of unique values in every sub-array.
     pub fn n_unique(self) -> Expr {
         self.0
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::NUnique))
     }
 
+    /// Drop the null values in every sub-array, the result is a list.
+    pub fn drop_nulls(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::DropNulls))
+    }
+
+    /// Compute the most occurring value(s) of every sub-array, the result is a list.
+    #[cfg(feature = "mode")]
+    pub fn mode(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Mode))
+    }
+
     /// Cast the Array column to List column with the same inner data type.
     pub fn to_list(self) -> Expr {
         self.0
@@ -84,21 +120,71 @@ impl ArrayNameSpace {
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Any))
     }
 
+    /// Sort every sub-array, the sub-arrays are sorted in parallel if
+    /// `options.multithreaded` is set.
     pub fn sort(self, options: SortOptions) -> Expr {
         self.0
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Sort(options)))
     }
 
+    /// Reverse the order of the items in every sub-array.
     pub fn reverse(self) -> Expr {
         self.0
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Reverse))
     }
 
+    /// Compute the cumulative sum within every sub-array.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_sum(self, reverse: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::CumSum { reverse }))
+    }
+
+    /// Compute the cumulative min within every sub-array.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_min(self, reverse: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::CumMin { reverse }))
+    }
+
+    /// Compute the cumulative max within every sub-array.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_max(self, reverse: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::CumMax { reverse }))
+    }
+
+    /// Rank the items within every sub-array.
+    #[cfg(feature = "rank")]
+    pub fn rank(self, options: RankOptions, seed: Option<u64>) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Rank {
+                options,
+                seed,
+            }))
+    }
+
+    /// Merge the two outer levels of a nested array.
+    pub fn flatten(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Flatten))
+    }
+
+    /// Reshape the elements of every sub-array into a (nested) array of `dimensions`.
+    pub fn reshape(self, dimensions: &[i64]) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Reshape(
+                dimensions.to_vec(),
+            )))
+    }
+
+    /// Get the index of the minimal value in every sub-array.
     pub fn arg_min(self) -> Expr {
         self.0
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::ArgMin))
     }
 
+    /// Get the index of the maximal value in every sub-array.
     pub fn arg_max(self) -> Expr {
         self.0
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::ArgMax))
@@ -114,6 +200,24 @@ impl ArrayNameSpace {
         )
     }
 
+    /// Get first item of every sub-array.
+    pub fn first(self) -> Expr {
+        self.get(lit(0i64), true)
+    }
+
+    /// Get last item of every sub-array.
+    pub fn last(self) -> Expr {
+        self.get(lit(-1i64), true)
+    }
+
+    /// Explode the sub-arrays into a Series of the inner data type.
+    ///
+    /// The fixed width of the sub-arrays makes the offsets implicit, so non-null arrays are
+    /// exploded without copying the values.
+    pub fn explode(self) -> Expr {
+        self.0.explode()
+    }
+
     /// Join all string items in a sub-array and place a separator between them.
     /// # Error
     /// Raise if inner type of array is not `DataType::String`.
@@ -158,7 +262,13 @@ impl ArrayNameSpace {
     }
 
     #[cfg(feature = "array_to_struct")]
+    /// Convert the sub-arrays to a struct with a field per element.
+    ///
+    /// The field names are generated by `name_generator`, or default to `field_{idx}`.
+    /// As the width of an array is known, the field names are already resolved in the schema.
     pub fn to_struct(self, name_generator: Option<ArrToStructNameGenerator>) -> Expr {
+        let schema_name_generator = name_generator.clone();
+
         self.0
             .map(
                 move |s| {
@@ -170,10 +280,13 @@ impl ArrayNameSpace {
                     let DataType::Array(inner, width) = dt else {
                         panic!("Only array dtype is expected for `arr.to_struct`.")
                     };
+                    let name_generator = schema_name_generator
+                        .as_deref()
+                        .unwrap_or(&arr_default_struct_name_gen);
 
                     let fields = (0..*width)
                         .map(|i| {
-                            let name = arr_default_struct_name_gen(i);
+                            let name = name_generator(i);
                             Field::from_owned(name, inner.as_ref().clone())
                         })
                         .collect();
@@ -183,6 +296,89 @@ impl ArrayNameSpace {
             .with_fmt("arr.to_struct")
     }
 
+    #[cfg(feature = "list_sets")]
+    fn set_operation(self, other: Expr, set_operation: SetOperation) -> Expr {
+        self.0
+            .map_many_private(
+                FunctionExpr::ArrayExpr(ArrayFunction::SetOperation(set_operation)),
+                &[other],
+                false,
+                false,
+            )
+            .with_function_options(|mut options| {
+                options.input_wildcard_expansion = true;
+                options
+            })
+    }
+
+    /// Return the SET UNION between the sub-arrays and `other`, the result is a list.
+    #[cfg(feature = "list_sets")]
+    pub fn set_union<E: Into<Expr>>(self, other: E) -> Expr {
+        let other = other.into();
+        self.set_operation(other, SetOperation::Union)
+    }
+
+    /// Return the SET DIFFERENCE between the sub-arrays and `other`, the result is a list.
+    #[cfg(feature = "list_sets")]
+    pub fn set_difference<E: Into<Expr>>(self, other: E) -> Expr {
+        let other = other.into();
+        self.set_operation(other, SetOperation::Difference)
+    }
+
+    /// Return the SET INTERSECTION between the sub-arrays and `other`, the result is a list.
+    #[cfg(feature = "list_sets")]
+    pub fn set_intersection<E: Into<Expr>>(self, other: E) -> Expr {
+        let other = other.into();
+        self.set_operation(other, SetOperation::Intersection)
+    }
+
+    /// Return the SET SYMMETRIC DIFFERENCE between the sub-arrays and `other`, the result is a
+    /// list.
+    #[cfg(feature = "list_sets")]
+    pub fn set_symmetric_difference<E: Into<Expr>>(self, other: E) -> Expr {
+        let other = other.into();
+        self.set_operation(other, SetOperation::SymmetricDifference)
+    }
+
+    /// Take the items of every sub-array where the sub-array of `mask` is `true` and the
+    /// items of `other` elsewhere. All arrays must have the same width.
+    pub fn zip_with(self, mask: Expr, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::ZipWith),
+            &[mask, other],
+            false,
+            false,
+        )
+    }
+
+    /// Sample `n` items of every sub-array.
+    ///
+    /// If `n` is a literal the output is an array of width `n`, otherwise it is a list.
+    #[cfg(feature = "list_sample")]
+    pub fn sample_n(
+        self,
+        n: Expr,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Expr {
+        let fixed_n = match &n {
+            Expr::Literal(lv) => lv.to_any_value().and_then(|av| av.extract::<usize>()),
+            _ => None,
+        };
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::Sample {
+                fixed_n,
+                with_replacement,
+                shuffle,
+                seed,
+            }),
+            &[n],
+            false,
+            false,
+        )
+    }
+
     /// Shift every sub-array.
     pub fn shift(self, n: Expr) -> Expr {
         self.0.map_many_private(
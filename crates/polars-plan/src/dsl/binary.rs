@@ -58,4 +58,55 @@ impl BinaryNameSpace {
         self.0
             .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
+
+    #[cfg(feature = "binary_encoding")]
+    pub fn base32_decode(self, strict: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Base32Decode(
+                strict,
+            )))
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    pub fn base32_encode(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Base32Encode))
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    pub fn percent_decode(self, strict: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::PercentDecode(
+                strict,
+            )))
+    }
+
+    /// Percent-encode all bytes except the unreserved characters of RFC 3986.
+    #[cfg(feature = "binary_encoding")]
+    pub fn percent_encode(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::PercentEncode))
+    }
+
+    /// Reinterpret the bytes of every value as a number of type `dtype`, in little or big
+    /// endian byte order. Every value must be exactly as wide as `dtype`.
+    #[cfg(feature = "reinterpret")]
+    pub fn reinterpret(self, dtype: DataType, is_little_endian: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Reinterpret {
+                dtype,
+                is_little_endian,
+            }))
+    }
+
+    /// Digest every value with the hash function `algorithm`, as a lowercase hex string if
+    /// `as_hex` is set and as binary otherwise.
+    #[cfg(feature = "hash_digest")]
+    pub fn digest(self, algorithm: DigestAlgorithm, as_hex: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Digest {
+                algorithm,
+                as_hex,
+            }))
+    }
 }
@@ -8,4 +8,34 @@ impl CategoricalNameSpace {
         self.0
             .map_private(CategoricalFunction::GetCategories.into())
     }
+
+    /// Get the number of categories stored in this data type.
+    pub fn n_categories(self) -> Expr {
+        self.0
+            .apply_private(CategoricalFunction::NCategories.into())
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Get the index of the category of every value in [`get_categories`].
+    ///
+    /// [`get_categories`]: Self::get_categories
+    pub fn codes(self) -> Expr {
+        self.0.map_private(CategoricalFunction::Codes.into())
+    }
+
+    /// Set the [`CategoricalOrdering`] used when sorting, comparing and taking the extremum of
+    /// the values, without changing the categories.
+    pub fn set_ordering(self, ordering: CategoricalOrdering) -> Expr {
+        self.0
+            .map_private(CategoricalFunction::SetOrdering(ordering).into())
+    }
+
+    /// Convert the values to a local categorical, i.e. one that does not depend on the global
+    /// string cache.
+    pub fn to_local(self) -> Expr {
+        self.0.map_private(CategoricalFunction::ToLocal.into())
+    }
 }
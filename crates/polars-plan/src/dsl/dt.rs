@@ -4,34 +4,39 @@ use super::*;
 pub struct DateLikeNameSpace(pub(crate) Expr);
 
 impl DateLikeNameSpace {
-    /// Add a given number of business days.
+    /// Add a given number of business days of `calendar`.
     #[cfg(feature = "business")]
-    pub fn add_business_days(
-        self,
-        n: Expr,
-        week_mask: [bool; 7],
-        holidays: Vec<i32>,
-        roll: Roll,
-    ) -> Expr {
+    pub fn add_business_days(self, n: Expr, calendar: HolidayCalendar, roll: Roll) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::Business(BusinessFunction::AddBusinessDay {
-                week_mask,
-                holidays,
-                roll,
-            }),
+            FunctionExpr::Business(BusinessFunction::AddBusinessDay { calendar, roll }),
             &[n],
             false,
             false,
         )
     }
 
+    /// Compute whether a Date/Datetime is a business day of `calendar`.
+    #[cfg(feature = "business")]
+    pub fn is_business_day(self, calendar: HolidayCalendar) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::IsBusinessDay {
+                calendar,
+            }))
+    }
+
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(self, format: &str) -> Expr {
+        self.to_string_localized(format, Locale::default())
+    }
+
+    /// Convert from Date/Time/Datetime into String with the given format, with the names of
+    /// months and weekdays in the language of `locale`.
+    pub fn to_string_localized(self, format: &str, locale: Locale) -> Expr {
         let format = format.to_string();
         self.0
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::ToString(
-                format,
+                format, locale,
             )))
     }
 
@@ -91,6 +96,53 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::IsLeapYear))
     }
 
+    /// Compute whether a Date/Datetime is the first day of a month.
+    pub fn is_month_start(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::IsMonthStart))
+    }
+
+    /// Compute whether a Date/Datetime is the last day of a month.
+    pub fn is_month_end(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::IsMonthEnd))
+    }
+
+    /// Compute whether a Date/Datetime is the first day of a quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    pub fn is_quarter_start(self, fiscal_year_start: u8) -> Expr {
+        self.0.map_private(FunctionExpr::TemporalExpr(
+            TemporalFunction::IsQuarterStart(fiscal_year_start),
+        ))
+    }
+
+    /// Compute whether a Date/Datetime is the last day of a quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    pub fn is_quarter_end(self, fiscal_year_start: u8) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::IsQuarterEnd(
+                fiscal_year_start,
+            )))
+    }
+
+    /// Compute whether a Date/Datetime is the first day of a year.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    pub fn is_year_start(self, fiscal_year_start: u8) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::IsYearStart(
+                fiscal_year_start,
+            )))
+    }
+
+    /// Compute whether a Date/Datetime is the last day of a year.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    pub fn is_year_end(self, fiscal_year_start: u8) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::IsYearEnd(
+                fiscal_year_start,
+            )))
+    }
+
     /// Get the iso-year of a Date/Datetime.
     /// This may not correspond with a calendar year.
     pub fn iso_year(self) -> Expr {
@@ -105,10 +157,13 @@ impl DateLikeNameSpace {
     }
 
     /// Extract quarter from underlying NaiveDateTime representation.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
     /// Quarters range from 1 to 4.
-    pub fn quarter(self) -> Expr {
+    pub fn quarter(self, fiscal_year_start: u8) -> Expr {
         self.0
-            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Quarter))
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Quarter(
+                fiscal_year_start,
+            )))
     }
 
     /// Extract the week from the underlying Date representation.
@@ -202,11 +257,33 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::TimeStamp(tu)))
     }
 
+    /// The number of `time_unit` durations that passed since `origin`, or since the UNIX
+    /// epoch if there is no origin, of a Datetime/Date.
+    ///
+    /// Whole units are rounded down, unless `fractional` is set.
+    pub fn epoch(self, time_unit: String, origin: Option<Expr>, fractional: bool) -> Expr {
+        let input: Vec<_> = origin.into_iter().collect();
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::Epoch {
+                time_unit,
+                fractional,
+            }),
+            &input,
+            false,
+            false,
+        )
+    }
+
     /// Truncate the Datetime/Date range into buckets.
-    pub fn truncate(self, every: Expr, offset: String) -> Expr {
+    ///
+    /// Ambiguous results are localized using `ambiguous` if given, or else using the DST fold
+    /// of the original datetime.
+    pub fn truncate(self, every: Expr, offset: String, ambiguous: Option<Expr>) -> Expr {
+        let mut input = vec![every];
+        input.extend(ambiguous);
         self.0.map_many_private(
             FunctionExpr::TemporalExpr(TemporalFunction::Truncate(offset)),
-            &[every],
+            &input,
             false,
             false,
         )
@@ -226,6 +303,26 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::MonthEnd))
     }
 
+    /// Roll backward to the first day of the quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    #[cfg(feature = "date_offset")]
+    pub fn quarter_start(self, fiscal_year_start: u8) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::QuarterStart(
+                fiscal_year_start,
+            )))
+    }
+
+    /// Roll forward to the last day of the quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    #[cfg(feature = "date_offset")]
+    pub fn quarter_end(self, fiscal_year_start: u8) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::QuarterEnd(
+                fiscal_year_start,
+            )))
+    }
+
     /// Get the base offset from UTC.
     #[cfg(feature = "timezones")]
     pub fn base_utc_offset(self) -> Expr {
@@ -241,11 +338,23 @@ impl DateLikeNameSpace {
     }
 
     /// Round the Datetime/Date range into buckets.
-    pub fn round<S: AsRef<str>>(self, every: Expr, offset: S) -> Expr {
+    ///
+    /// The bucket boundaries are shifted by `offset`, and weekly buckets start on `week_start`,
+    /// from 1 (Monday) to 7 (Sunday). Ambiguous results are localized using `ambiguous` if
+    /// given, or else using the DST fold of the original datetime.
+    pub fn round<S: AsRef<str>>(
+        self,
+        every: Expr,
+        offset: S,
+        week_start: u8,
+        ambiguous: Option<Expr>,
+    ) -> Expr {
         let offset = offset.as_ref().into();
+        let mut input = vec![every];
+        input.extend(ambiguous);
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::Round(offset)),
-            &[every],
+            FunctionExpr::TemporalExpr(TemporalFunction::Round(offset, week_start)),
+            &input,
             false,
             false,
         )
@@ -253,79 +362,127 @@ impl DateLikeNameSpace {
 
     /// Offset this `Date/Datetime` by a given offset [`Duration`].
     /// This will take leap years/ months into account.
+    ///
+    /// `by` holds duration strings or durations. If `end_of_month` is set, values on the
+    /// last day of their month are moved to the last day of the resulting month.
     #[cfg(feature = "date_offset")]
-    pub fn offset_by(self, by: Expr) -> Expr {
-        self.0
-            .map_many_private(FunctionExpr::DateOffset, &[by], false, false)
+    pub fn offset_by(self, by: Expr, end_of_month: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::DateOffset { end_of_month },
+            &[by],
+            false,
+            false,
+        )
     }
 
+    /// Replace the time zone of a Datetime, keeping its local time.
+    ///
+    /// `ambiguous` and `non_existent` hold the strategy for each row, or one for all rows.
     #[cfg(feature = "timezones")]
     pub fn replace_time_zone(
         self,
         time_zone: Option<TimeZone>,
         ambiguous: Expr,
-        non_existent: NonExistent,
+        non_existent: Expr,
     ) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::ReplaceTimeZone(time_zone, non_existent)),
-            &[ambiguous],
+            FunctionExpr::TemporalExpr(TemporalFunction::ReplaceTimeZone(time_zone)),
+            &[ambiguous, non_existent],
             false,
             false,
         )
     }
 
     /// Combine an existing Date/Datetime with a Time, creating a new Datetime value.
-    pub fn combine(self, time: Expr, tu: TimeUnit) -> Expr {
+    ///
+    /// The result is localized to `time_zone` if it is set, and otherwise keeps the time zone
+    /// of a Datetime.
+    pub fn combine(self, time: Expr, tu: TimeUnit, time_zone: Option<TimeZone>) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::Combine(tu)),
+            FunctionExpr::TemporalExpr(TemporalFunction::Combine(tu, time_zone)),
             &[time],
             false,
             false,
         )
     }
 
-    /// Express a Duration in terms of its total number of integer days.
-    pub fn total_days(self) -> Expr {
+    /// Express a Duration in terms of its total number of days.
+    ///
+    /// If `fractional` is set, the fractional part of the total is kept as well.
+    pub fn total_days(self, fractional: bool) -> Expr {
         self.0
-            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::TotalDays))
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::TotalDays(
+                fractional,
+            )))
     }
 
-    /// Express a Duration in terms of its total number of integer hours.
-    pub fn total_hours(self) -> Expr {
+    /// Express a Duration in terms of its total number of hours.
+    ///
+    /// If `fractional` is set, the fractional part of the total is kept as well.
+    pub fn total_hours(self, fractional: bool) -> Expr {
         self.0
-            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::TotalHours))
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::TotalHours(
+                fractional,
+            )))
     }
 
-    /// Express a Duration in terms of its total number of integer minutes.
-    pub fn total_minutes(self) -> Expr {
+    /// Express a Duration in terms of its total number of minutes.
+    ///
+    /// If `fractional` is set, the fractional part of the total is kept as well.
+    pub fn total_minutes(self, fractional: bool) -> Expr {
         self.0
-            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::TotalMinutes))
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::TotalMinutes(
+                fractional,
+            )))
     }
 
-    /// Express a Duration in terms of its total number of integer seconds.
-    pub fn total_seconds(self) -> Expr {
+    /// Express a Duration in terms of its total number of seconds.
+    ///
+    /// If `fractional` is set, the fractional part of the total is kept as well.
+    pub fn total_seconds(self, fractional: bool) -> Expr {
         self.0
-            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::TotalSeconds))
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::TotalSeconds(
+                fractional,
+            )))
     }
 
     /// Express a Duration in terms of its total number of milliseconds.
-    pub fn total_milliseconds(self) -> Expr {
+    ///
+    /// If `fractional` is set, the fractional part of the total is kept as well.
+    pub fn total_milliseconds(self, fractional: bool) -> Expr {
         self.0.map_private(FunctionExpr::TemporalExpr(
-            TemporalFunction::TotalMilliseconds,
+            TemporalFunction::TotalMilliseconds(fractional),
         ))
     }
 
     /// Express a Duration in terms of its total number of microseconds.
-    pub fn total_microseconds(self) -> Expr {
+    ///
+    /// If `fractional` is set, the fractional part of the total is kept as well.
+    pub fn total_microseconds(self, fractional: bool) -> Expr {
         self.0.map_private(FunctionExpr::TemporalExpr(
-            TemporalFunction::TotalMicroseconds,
+            TemporalFunction::TotalMicroseconds(fractional),
         ))
     }
 
     /// Express a Duration in terms of its total number of nanoseconds.
-    pub fn total_nanoseconds(self) -> Expr {
+    ///
+    /// If `fractional` is set, the fractional part of the total is kept as well.
+    pub fn total_nanoseconds(self, fractional: bool) -> Expr {
+        self.0.map_private(FunctionExpr::TemporalExpr(
+            TemporalFunction::TotalNanoseconds(fractional),
+        ))
+    }
+
+    /// Convert a Duration into a String in `format`.
+    ///
+    /// Durations are truncated to `precision`, or to their time unit if there is no precision.
+    pub fn duration_to_string(
+        self,
+        format: DurationFormat,
+        precision: Option<DurationPrecision>,
+    ) -> Expr {
         self.0.map_private(FunctionExpr::TemporalExpr(
-            TemporalFunction::TotalNanoseconds,
+            TemporalFunction::DurationToString(format, precision),
         ))
     }
 }
@@ -138,6 +138,8 @@ pub enum Expr {
     Len,
     /// Take the nth column in the `DataFrame`
     Nth(i64),
+    /// Take fields of the struct in `struct.with_fields`
+    Field(Arc<[ColumnName]>),
     // skipped fields must be last otherwise serde fails in pickle
     #[cfg_attr(feature = "serde", serde(skip))]
     RenameAlias {
@@ -177,6 +179,7 @@ impl Hash for Expr {
             Expr::Literal(lv) => std::mem::discriminant(lv).hash(state),
             Expr::Selector(s) => s.hash(state),
             Expr::Nth(v) => v.hash(state),
+            Expr::Field(names) => names.hash(state),
             Expr::Filter { input, by } => {
                 input.hash(state);
                 by.hash(state);
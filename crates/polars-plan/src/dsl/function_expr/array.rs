@@ -3,24 +3,49 @@ use polars_ops::chunked_array::array::*;
 use super::*;
 use crate::{map, map_as_slice};
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ArrayFunction {
     Min,
     Max,
     Sum,
+    Product,
+    Mean,
     ToList,
     Unique(bool),
     NUnique,
+    DropNulls,
+    #[cfg(feature = "mode")]
+    Mode,
     Std(u8),
     Var(u8),
     Median,
+    Quantile(QuantileInterpolOptions),
     #[cfg(feature = "array_any_all")]
     Any,
     #[cfg(feature = "array_any_all")]
     All,
     Sort(SortOptions),
     Reverse,
+    #[cfg(feature = "cum_agg")]
+    CumSum {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
+    CumMin {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
+    CumMax {
+        reverse: bool,
+    },
+    #[cfg(feature = "rank")]
+    Rank {
+        options: RankOptions,
+        seed: Option<u64>,
+    },
+    Flatten,
+    Reshape(Vec<i64>),
     ArgMin,
     ArgMax,
     Get(bool),
@@ -30,6 +55,17 @@ pub enum ArrayFunction {
     #[cfg(feature = "array_count")]
     CountMatches,
     Shift,
+    #[cfg(feature = "list_sets")]
+    SetOperation(SetOperation),
+    ZipWith,
+    #[cfg(feature = "list_sample")]
+    Sample {
+        /// The number of samples if it is a constant, the output is then an array of that width.
+        fixed_n: Option<usize>,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    },
 }
 
 impl ArrayFunction {
@@ -38,16 +74,45 @@ impl ArrayFunction {
         match self {
             Min | Max => mapper.map_to_list_and_array_inner_dtype(),
             Sum => mapper.nested_sum_type(),
+            Product => mapper.nested_product_type(),
+            Mean => mapper.nested_mean_type(),
             ToList => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
             Unique(_) => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
             NUnique => mapper.with_dtype(IDX_DTYPE),
+            DropNulls => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
+            #[cfg(feature = "mode")]
+            Mode => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
             Std(_) => mapper.map_to_float_dtype(),
             Var(_) => mapper.map_to_float_dtype(),
             Median => mapper.map_to_float_dtype(),
+            Quantile(_) => mapper.nested_mean_type(),
             #[cfg(feature = "array_any_all")]
             Any | All => mapper.with_dtype(DataType::Boolean),
             Sort(_) => mapper.with_same_dtype(),
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "cum_agg")]
+            CumSum { .. } => mapper.try_map_dtype(|dt| match dt {
+                DataType::Array(inner, width) => Ok(DataType::Array(
+                    Box::new(super::cum::dtypes::cum_sum(inner)),
+                    *width,
+                )),
+                _ => polars_bail!(ComputeError: "expected array dtype"),
+            }),
+            #[cfg(feature = "cum_agg")]
+            CumMin { .. } | CumMax { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "rank")]
+            Rank { options, .. } => mapper.try_map_dtype(|dt| {
+                let DataType::Array(_, width) = dt else {
+                    polars_bail!(ComputeError: "expected array dtype")
+                };
+                let inner = match options.method {
+                    RankMethod::Average => DataType::Float64,
+                    _ => IDX_DTYPE,
+                };
+                Ok(DataType::Array(Box::new(inner), *width))
+            }),
+            Flatten => mapper.try_map_dtype(flatten_array_dtype),
+            Reshape(dimensions) => mapper.try_map_dtype(|dt| reshape_array_dtype(dt, dimensions)),
             ArgMin | ArgMax => mapper.with_dtype(IDX_DTYPE),
             Get(_) => mapper.map_to_list_and_array_inner_dtype(),
             Join(_) => mapper.with_dtype(DataType::String),
@@ -56,6 +121,35 @@ impl ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
             Shift => mapper.with_same_dtype(),
+            #[cfg(feature = "list_sets")]
+            SetOperation(_) => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
+            ZipWith => {
+                let widths = mapper
+                    .args()
+                    .iter()
+                    .map(|f| match f.data_type() {
+                        DataType::Array(_, width) => Ok(*width),
+                        dt => polars_bail!(
+                            SchemaMismatch: "`arr.zip_with` expected `Array` inputs, got `{}`", dt
+                        ),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                polars_ensure!(
+                    widths.iter().all(|w| *w == widths[0]),
+                    SchemaMismatch: "`arr.zip_with` expected arrays of the same width, got widths {:?}", widths
+                );
+                mapper.with_same_dtype()
+            },
+            #[cfg(feature = "list_sample")]
+            Sample { fixed_n, .. } => mapper.try_map_dtype(|dt| match fixed_n {
+                Some(n) => {
+                    let DataType::Array(inner, _) = dt else {
+                        polars_bail!(ComputeError: "expected array dtype")
+                    };
+                    Ok(DataType::Array(inner.clone(), *n))
+                },
+                None => map_array_dtype_to_list_dtype(dt),
+            }),
         }
     }
 }
@@ -75,18 +169,34 @@ impl Display for ArrayFunction {
             Min => "min",
             Max => "max",
             Sum => "sum",
+            Product => "product",
+            Mean => "mean",
             ToList => "to_list",
             Unique(_) => "unique",
             NUnique => "n_unique",
+            DropNulls => "drop_nulls",
+            #[cfg(feature = "mode")]
+            Mode => "mode",
             Std(_) => "std",
             Var(_) => "var",
             Median => "median",
+            Quantile(_) => "quantile",
             #[cfg(feature = "array_any_all")]
             Any => "any",
             #[cfg(feature = "array_any_all")]
             All => "all",
             Sort(_) => "sort",
             Reverse => "reverse",
+            #[cfg(feature = "cum_agg")]
+            CumSum { .. } => "cum_sum",
+            #[cfg(feature = "cum_agg")]
+            CumMin { .. } => "cum_min",
+            #[cfg(feature = "cum_agg")]
+            CumMax { .. } => "cum_max",
+            #[cfg(feature = "rank")]
+            Rank { .. } => "rank",
+            Flatten => "flatten",
+            Reshape(_) => "reshape",
             ArgMin => "arg_min",
             ArgMax => "arg_max",
             Get(_) => "get",
@@ -96,6 +206,11 @@ impl Display for ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
             Shift => "shift",
+            #[cfg(feature = "list_sets")]
+            SetOperation(s) => return write!(f, "arr.{s}"),
+            ZipWith => "zip_with",
+            #[cfg(feature = "list_sample")]
+            Sample { .. } => "sample",
         };
         write!(f, "arr.{name}")
     }
@@ -108,18 +223,34 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Min => map!(min),
             Max => map!(max),
             Sum => map!(sum),
+            Product => map!(product),
+            Mean => map!(mean),
             ToList => map!(to_list),
             Unique(stable) => map!(unique, stable),
             NUnique => map!(n_unique),
+            DropNulls => map!(drop_nulls),
+            #[cfg(feature = "mode")]
+            Mode => map!(mode),
             Std(ddof) => map!(std, ddof),
             Var(ddof) => map!(var, ddof),
             Median => map!(median),
+            Quantile(interpol) => map_as_slice!(quantile, interpol),
             #[cfg(feature = "array_any_all")]
             Any => map!(any),
             #[cfg(feature = "array_any_all")]
             All => map!(all),
             Sort(options) => map!(sort, options),
             Reverse => map!(reverse),
+            #[cfg(feature = "cum_agg")]
+            CumSum { reverse } => map!(cum_sum, reverse),
+            #[cfg(feature = "cum_agg")]
+            CumMin { reverse } => map!(cum_min, reverse),
+            #[cfg(feature = "cum_agg")]
+            CumMax { reverse } => map!(cum_max, reverse),
+            #[cfg(feature = "rank")]
+            Rank { options, seed } => map!(rank, options, seed),
+            Flatten => map!(flatten),
+            Reshape(dimensions) => map!(reshape, &dimensions),
             ArgMin => map!(arg_min),
             ArgMax => map!(arg_max),
             Get(null_on_oob) => map_as_slice!(get, null_on_oob),
@@ -129,6 +260,16 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "array_count")]
             CountMatches => map_as_slice!(count_matches),
             Shift => map_as_slice!(shift),
+            #[cfg(feature = "list_sets")]
+            SetOperation(s) => map_as_slice!(set_operation, s),
+            ZipWith => map_as_slice!(zip_with),
+            #[cfg(feature = "list_sample")]
+            Sample {
+                fixed_n,
+                with_replacement,
+                shuffle,
+                seed,
+            } => map_as_slice!(sample_n, fixed_n, with_replacement, shuffle, seed),
         }
     }
 }
@@ -145,6 +286,14 @@ pub(super) fn sum(s: &Series) -> PolarsResult<Series> {
     s.array()?.array_sum()
 }
 
+pub(super) fn product(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_product()
+}
+
+pub(super) fn mean(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_mean()
+}
+
 pub(super) fn std(s: &Series, ddof: u8) -> PolarsResult<Series> {
     s.array()?.array_std(ddof)
 }
@@ -170,6 +319,15 @@ pub(super) fn n_unique(s: &Series) -> PolarsResult<Series> {
     Ok(s.array()?.array_n_unique()?.into_series())
 }
 
+pub(super) fn drop_nulls(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_drop_nulls()?.into_series())
+}
+
+#[cfg(feature = "mode")]
+pub(super) fn mode(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_mode()?.into_series())
+}
+
 pub(super) fn to_list(s: &Series) -> PolarsResult<Series> {
     let list_dtype = map_array_dtype_to_list_dtype(s.dtype())?;
     s.cast(&list_dtype)
@@ -193,6 +351,34 @@ pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     Ok(s.array()?.array_reverse().into_series())
 }
 
+#[cfg(feature = "cum_agg")]
+pub(super) fn cum_sum(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    Ok(s.array()?.array_cum_sum(reverse)?.into_series())
+}
+
+#[cfg(feature = "cum_agg")]
+pub(super) fn cum_min(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    Ok(s.array()?.array_cum_min(reverse)?.into_series())
+}
+
+#[cfg(feature = "cum_agg")]
+pub(super) fn cum_max(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    Ok(s.array()?.array_cum_max(reverse)?.into_series())
+}
+
+#[cfg(feature = "rank")]
+pub(super) fn rank(s: &Series, options: RankOptions, seed: Option<u64>) -> PolarsResult<Series> {
+    Ok(s.array()?.array_rank(options, seed)?.into_series())
+}
+
+pub(super) fn flatten(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_flatten()?.into_series())
+}
+
+pub(super) fn reshape(s: &Series, dimensions: &[i64]) -> PolarsResult<Series> {
+    Ok(s.array()?.array_reshape(dimensions)?.into_series())
+}
+
 pub(super) fn arg_min(s: &Series) -> PolarsResult<Series> {
     Ok(s.array()?.array_arg_min().into_series())
 }
@@ -208,6 +394,12 @@ pub(super) fn get(s: &[Series], null_on_oob: bool) -> PolarsResult<Series> {
     ca.array_get(index, null_on_oob)
 }
 
+pub(super) fn quantile(s: &[Series], interpol: QuantileInterpolOptions) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    let quantile = s[1].cast(&DataType::Float64)?;
+    ca.array_quantile(quantile.f64().unwrap(), interpol)
+}
+
 pub(super) fn join(s: &[Series], ignore_nulls: bool) -> PolarsResult<Series> {
     let ca = s[0].array()?;
     let separator = s[1].str()?;
@@ -243,3 +435,40 @@ pub(super) fn shift(s: &[Series]) -> PolarsResult<Series> {
 
     ca.array_shift(n)
 }
+
+#[cfg(feature = "list_sets")]
+pub(super) fn set_operation(s: &[Series], set_type: SetOperation) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    let other = &s[1];
+
+    ca.array_set_operation(other, set_type)
+        .map(|ca| ca.into_series())
+}
+
+pub(super) fn zip_with(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    let mask = &s[1];
+    let other = &s[2];
+
+    ca.array_zip_with(mask, other).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "list_sample")]
+pub(super) fn sample_n(
+    s: &[Series],
+    fixed_n: Option<usize>,
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    let n = &s[1];
+
+    let out = ca
+        .array_sample_n(n, with_replacement, shuffle, seed)?
+        .into_series();
+    match fixed_n {
+        Some(n) => out.cast(&DataType::Array(Box::new(ca.inner_dtype()), n)),
+        None => Ok(out),
+    }
+}
@@ -2,7 +2,11 @@
 use serde::{Deserialize, Serialize};
 
 use super::*;
-#[cfg(feature = "binary_encoding")]
+#[cfg(any(
+    feature = "binary_encoding",
+    feature = "hash_digest",
+    feature = "reinterpret"
+))]
 use crate::map;
 use crate::map_as_slice;
 
@@ -20,6 +24,24 @@ pub enum BinaryFunction {
     Base64Decode(bool),
     #[cfg(feature = "binary_encoding")]
     Base64Encode,
+    #[cfg(feature = "binary_encoding")]
+    Base32Decode(bool),
+    #[cfg(feature = "binary_encoding")]
+    Base32Encode,
+    #[cfg(feature = "binary_encoding")]
+    PercentDecode(bool),
+    #[cfg(feature = "binary_encoding")]
+    PercentEncode,
+    #[cfg(feature = "reinterpret")]
+    Reinterpret {
+        dtype: DataType,
+        is_little_endian: bool,
+    },
+    #[cfg(feature = "hash_digest")]
+    Digest {
+        algorithm: DigestAlgorithm,
+        as_hex: bool,
+    },
 }
 
 impl BinaryFunction {
@@ -29,9 +51,27 @@ impl BinaryFunction {
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "binary_encoding")]
-            HexDecode(_) | Base64Decode(_) => mapper.with_same_dtype(),
+            HexDecode(_) | Base64Decode(_) | Base32Decode(_) | PercentDecode(_) => {
+                mapper.with_same_dtype()
+            },
             #[cfg(feature = "binary_encoding")]
-            HexEncode | Base64Encode => mapper.with_dtype(DataType::String),
+            HexEncode | Base64Encode | Base32Encode | PercentEncode => {
+                mapper.with_dtype(DataType::String)
+            },
+            #[cfg(feature = "reinterpret")]
+            Reinterpret { dtype, .. } => {
+                polars_ensure!(
+                    dtype.is_numeric(),
+                    InvalidOperation: "cannot reinterpret binary as `{}`, expected a numeric type", dtype
+                );
+                mapper.with_dtype(dtype.clone())
+            },
+            #[cfg(feature = "hash_digest")]
+            Digest { as_hex, .. } => mapper.with_dtype(if *as_hex {
+                DataType::String
+            } else {
+                DataType::Binary
+            }),
         }
     }
 }
@@ -51,6 +91,18 @@ impl Display for BinaryFunction {
             Base64Decode(_) => "base64_decode",
             #[cfg(feature = "binary_encoding")]
             Base64Encode => "base64_encode",
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(_) => "base32_decode",
+            #[cfg(feature = "binary_encoding")]
+            Base32Encode => "base32_encode",
+            #[cfg(feature = "binary_encoding")]
+            PercentDecode(_) => "percent_decode",
+            #[cfg(feature = "binary_encoding")]
+            PercentEncode => "percent_encode",
+            #[cfg(feature = "reinterpret")]
+            Reinterpret { .. } => "reinterpret",
+            #[cfg(feature = "hash_digest")]
+            Digest { .. } => "digest",
         };
         write!(f, "bin.{s}")
     }
@@ -77,6 +129,21 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Base64Decode(strict) => map!(base64_decode, strict),
             #[cfg(feature = "binary_encoding")]
             Base64Encode => map!(base64_encode),
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(strict) => map!(base32_decode, strict),
+            #[cfg(feature = "binary_encoding")]
+            Base32Encode => map!(base32_encode),
+            #[cfg(feature = "binary_encoding")]
+            PercentDecode(strict) => map!(percent_decode, strict),
+            #[cfg(feature = "binary_encoding")]
+            PercentEncode => map!(percent_encode),
+            #[cfg(feature = "reinterpret")]
+            Reinterpret {
+                dtype,
+                is_little_endian,
+            } => map!(reinterpret, &dtype, is_little_endian),
+            #[cfg(feature = "hash_digest")]
+            Digest { algorithm, as_hex } => map!(digest, algorithm, as_hex),
         }
     }
 }
@@ -131,6 +198,56 @@ pub(super) fn base64_encode(s: &Series) -> PolarsResult<Series> {
     Ok(ca.base64_encode())
 }
 
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base32_decode(s: &Series, strict: bool) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    ca.base32_decode(strict).map(|ok| ok.into_series())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base32_encode(s: &Series) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    Ok(ca.base32_encode())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn percent_decode(s: &Series, strict: bool) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    ca.percent_decode(strict).map(|ok| ok.into_series())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn percent_encode(s: &Series) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    Ok(ca.percent_encode())
+}
+
+#[cfg(feature = "reinterpret")]
+pub(super) fn reinterpret(
+    s: &Series,
+    dtype: &DataType,
+    is_little_endian: bool,
+) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    polars_ops::series::binary_to_numeric(ca, dtype, is_little_endian)
+}
+
+#[cfg(feature = "hash_digest")]
+pub(super) fn digest(s: &Series, algorithm: DigestAlgorithm, as_hex: bool) -> PolarsResult<Series> {
+    let ca = match s.dtype() {
+        DataType::String => s.str()?.as_binary(),
+        DataType::Binary => s.binary()?.clone(),
+        dt => polars_bail!(
+            InvalidOperation: "cannot digest `{}`, expected a string or binary type", dt
+        ),
+    };
+    if as_hex {
+        Ok(ca.hex_digest(algorithm))
+    } else {
+        Ok(ca.digest(algorithm).into_series())
+    }
+}
+
 impl From<BinaryFunction> for FunctionExpr {
     fn from(b: BinaryFunction) -> Self {
         FunctionExpr::BinaryExpr(b)
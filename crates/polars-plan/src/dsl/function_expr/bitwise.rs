@@ -0,0 +1,59 @@
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+use polars_ops::prelude::BitwiseReduction;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::dsl::SpecialEq;
+use crate::prelude::SeriesUdf;
+use crate::{map, map_as_slice};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum BitwiseFunction {
+    ShiftLeft,
+    ShiftRight,
+    And,
+    Or,
+    Xor,
+}
+
+impl Display for BitwiseFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use BitwiseFunction::*;
+        let s = match self {
+            ShiftLeft => "shift_left",
+            ShiftRight => "shift_right",
+            And => "bitwise_and",
+            Or => "bitwise_or",
+            Xor => "bitwise_xor",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl From<BitwiseFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
+    fn from(func: BitwiseFunction) -> Self {
+        use BitwiseFunction::*;
+        match func {
+            ShiftLeft => map_as_slice!(shift_left),
+            ShiftRight => map_as_slice!(shift_right),
+            And => map!(bitwise_reduce, BitwiseReduction::And),
+            Or => map!(bitwise_reduce, BitwiseReduction::Or),
+            Xor => map!(bitwise_reduce, BitwiseReduction::Xor),
+        }
+    }
+}
+
+fn shift_left(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::prelude::shift_left(&s[0], &s[1])
+}
+
+fn shift_right(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::prelude::shift_right(&s[0], &s[1])
+}
+
+fn bitwise_reduce(s: &Series, reduction: BitwiseReduction) -> PolarsResult<Series> {
+    polars_ops::prelude::bitwise_reduce(s, reduction)
+}
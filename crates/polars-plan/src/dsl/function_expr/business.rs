@@ -2,6 +2,8 @@ use std::fmt::{Display, Formatter};
 
 use polars_core::prelude::*;
 use polars_ops::prelude::Roll;
+#[cfg(feature = "business")]
+use polars_time::HolidayCalendar;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -13,16 +15,14 @@ use crate::prelude::SeriesUdf;
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum BusinessFunction {
     #[cfg(feature = "business")]
-    BusinessDayCount {
-        week_mask: [bool; 7],
-        holidays: Vec<i32>,
-    },
+    BusinessDayCount { calendar: HolidayCalendar },
     #[cfg(feature = "business")]
     AddBusinessDay {
-        week_mask: [bool; 7],
-        holidays: Vec<i32>,
+        calendar: HolidayCalendar,
         roll: Roll,
     },
+    #[cfg(feature = "business")]
+    IsBusinessDay { calendar: HolidayCalendar },
 }
 
 impl Display for BusinessFunction {
@@ -33,6 +33,8 @@ impl Display for BusinessFunction {
             &BusinessDayCount { .. } => "business_day_count",
             #[cfg(feature = "business")]
             &AddBusinessDay { .. } => "add_business_days",
+            #[cfg(feature = "business")]
+            &IsBusinessDay { .. } => "is_business_day",
         };
         write!(f, "{s}")
     }
@@ -42,43 +44,46 @@ impl From<BusinessFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
         use BusinessFunction::*;
         match func {
             #[cfg(feature = "business")]
-            BusinessDayCount {
-                week_mask,
-                holidays,
-            } => {
-                map_as_slice!(business_day_count, week_mask, &holidays)
+            BusinessDayCount { calendar } => {
+                map_as_slice!(business_day_count, &calendar)
+            },
+            #[cfg(feature = "business")]
+            AddBusinessDay { calendar, roll } => {
+                map_as_slice!(add_business_days, &calendar, roll)
             },
             #[cfg(feature = "business")]
-            AddBusinessDay {
-                week_mask,
-                holidays,
-                roll,
-            } => {
-                map_as_slice!(add_business_days, week_mask, &holidays, roll)
+            IsBusinessDay { calendar } => {
+                map_as_slice!(is_business_day, &calendar)
             },
         }
     }
 }
 
 #[cfg(feature = "business")]
-pub(super) fn business_day_count(
-    s: &[Series],
-    week_mask: [bool; 7],
-    holidays: &[i32],
-) -> PolarsResult<Series> {
+pub(super) fn business_day_count(s: &[Series], calendar: &HolidayCalendar) -> PolarsResult<Series> {
     let start = &s[0];
     let end = &s[1];
-    polars_ops::prelude::business_day_count(start, end, week_mask, holidays)
+    polars_ops::prelude::business_day_count(start, end, calendar.week_mask(), calendar.holidays())
 }
 
 #[cfg(feature = "business")]
 pub(super) fn add_business_days(
     s: &[Series],
-    week_mask: [bool; 7],
-    holidays: &[i32],
+    calendar: &HolidayCalendar,
     roll: Roll,
 ) -> PolarsResult<Series> {
     let start = &s[0];
     let n = &s[1];
-    polars_ops::prelude::add_business_days(start, n, week_mask, holidays, roll)
+    polars_ops::prelude::add_business_days(
+        start,
+        n,
+        calendar.week_mask(),
+        calendar.holidays(),
+        roll,
+    )
+}
+
+#[cfg(feature = "business")]
+pub(super) fn is_business_day(s: &[Series], calendar: &HolidayCalendar) -> PolarsResult<Series> {
+    polars_ops::prelude::is_business_day(&s[0], calendar.week_mask(), calendar.holidays())
 }
@@ -5,6 +5,10 @@ use crate::map;
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum CategoricalFunction {
     GetCategories,
+    NCategories,
+    Codes,
+    SetOrdering(CategoricalOrdering),
+    ToLocal,
 }
 
 impl CategoricalFunction {
@@ -12,6 +16,24 @@ impl CategoricalFunction {
         use CategoricalFunction::*;
         match self {
             GetCategories => mapper.with_dtype(DataType::String),
+            NCategories | Codes => mapper.with_dtype(DataType::UInt32),
+            SetOrdering(ordering) => mapper.try_map_dtype(|dt| match dt {
+                DataType::Categorical(rev_map, _) => {
+                    Ok(DataType::Categorical(rev_map.clone(), *ordering))
+                },
+                DataType::Enum(rev_map, _) => Ok(DataType::Enum(rev_map.clone(), *ordering)),
+                dt => polars_bail!(
+                    InvalidOperation: "`set_ordering` operation not supported for dtype `{}`", dt
+                ),
+            }),
+            ToLocal => mapper.try_map_dtype(|dt| match dt {
+                DataType::Categorical(_, ordering) | DataType::Enum(_, ordering) => {
+                    Ok(DataType::Categorical(None, *ordering))
+                },
+                dt => polars_bail!(
+                    InvalidOperation: "`to_local` operation not supported for dtype `{}`", dt
+                ),
+            }),
         }
     }
 }
@@ -21,6 +43,10 @@ impl Display for CategoricalFunction {
         use CategoricalFunction::*;
         let s = match self {
             GetCategories => "get_categories",
+            NCategories => "n_categories",
+            Codes => "codes",
+            SetOrdering(_) => "set_ordering",
+            ToLocal => "to_local",
         };
         write!(f, "cat.{s}")
     }
@@ -31,6 +57,10 @@ impl From<CategoricalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
         use CategoricalFunction::*;
         match func {
             GetCategories => map!(get_categories),
+            NCategories => map!(n_categories),
+            Codes => map!(codes),
+            SetOrdering(ordering) => map!(set_ordering, ordering),
+            ToLocal => map!(to_local),
         }
     }
 }
@@ -48,3 +78,32 @@ fn get_categories(s: &Series) -> PolarsResult<Series> {
     let arr = rev_map.get_categories().clone().boxed();
     Series::try_from((ca.name(), arr))
 }
+
+fn n_categories(s: &Series) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    let n = ca.get_rev_map().len() as u32;
+    Ok(Series::new(ca.name(), [n]))
+}
+
+/// The index of the category of every value in the categories returned by `get_categories`.
+fn codes(s: &Series) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    let out = match &**ca.get_rev_map() {
+        // The physical values are indexes into the global string cache.
+        RevMapping::Global(map, _, _) => ca
+            .physical()
+            .apply_nonnull_values_generic(DataType::UInt32, |v| *map.get(&v).unwrap()),
+        RevMapping::Local(_, _) => ca.physical().clone(),
+    };
+    Ok(out.with_name(ca.name()).into_series())
+}
+
+fn set_ordering(s: &Series, ordering: CategoricalOrdering) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    Ok(ca.clone().set_ordering(ordering, true).into_series())
+}
+
+fn to_local(s: &Series) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    Ok(ca.to_local().into_series())
+}
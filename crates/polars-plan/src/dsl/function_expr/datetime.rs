@@ -17,8 +17,14 @@ pub enum TemporalFunction {
     Century,
     Year,
     IsLeapYear,
+    IsMonthStart,
+    IsMonthEnd,
+    IsQuarterStart(u8),
+    IsQuarterEnd(u8),
+    IsYearStart(u8),
+    IsYearEnd(u8),
     IsoYear,
-    Quarter,
+    Quarter(u8),
     Month,
     Week,
     WeekDay,
@@ -34,32 +40,41 @@ pub enum TemporalFunction {
     Millisecond,
     Microsecond,
     Nanosecond,
-    TotalDays,
-    TotalHours,
-    TotalMinutes,
-    TotalSeconds,
-    TotalMilliseconds,
-    TotalMicroseconds,
-    TotalNanoseconds,
-    ToString(String),
+    TotalDays(bool),
+    TotalHours(bool),
+    TotalMinutes(bool),
+    TotalSeconds(bool),
+    TotalMilliseconds(bool),
+    TotalMicroseconds(bool),
+    TotalNanoseconds(bool),
+    DurationToString(DurationFormat, Option<DurationPrecision>),
+    ToString(String, Locale),
     CastTimeUnit(TimeUnit),
     WithTimeUnit(TimeUnit),
     #[cfg(feature = "timezones")]
     ConvertTimeZone(TimeZone),
     TimeStamp(TimeUnit),
+    Epoch {
+        time_unit: String,
+        fractional: bool,
+    },
     Truncate(String),
     #[cfg(feature = "date_offset")]
     MonthStart,
     #[cfg(feature = "date_offset")]
     MonthEnd,
+    #[cfg(feature = "date_offset")]
+    QuarterStart(u8),
+    #[cfg(feature = "date_offset")]
+    QuarterEnd(u8),
     #[cfg(feature = "timezones")]
     BaseUtcOffset,
     #[cfg(feature = "timezones")]
     DSTOffset,
-    Round(String),
+    Round(String, u8),
     #[cfg(feature = "timezones")]
-    ReplaceTimeZone(Option<TimeZone>, NonExistent),
-    Combine(TimeUnit),
+    ReplaceTimeZone(Option<TimeZone>),
+    Combine(TimeUnit, Option<TimeZone>),
     DatetimeFunction {
         time_unit: TimeUnit,
         time_zone: Option<TimeZone>,
@@ -73,13 +88,24 @@ impl TemporalFunction {
             Millennium | Century => mapper.with_dtype(DataType::Int8),
             Year | IsoYear => mapper.with_dtype(DataType::Int32),
             OrdinalDay => mapper.with_dtype(DataType::Int16),
-            Month | Quarter | Week | WeekDay | Day | Hour | Minute | Second => {
+            Month | Quarter(_) | Week | WeekDay | Day | Hour | Minute | Second => {
                 mapper.with_dtype(DataType::Int8)
             },
             Millisecond | Microsecond | Nanosecond => mapper.with_dtype(DataType::Int32),
-            TotalDays | TotalHours | TotalMinutes | TotalSeconds | TotalMilliseconds
-            | TotalMicroseconds | TotalNanoseconds => mapper.with_dtype(DataType::Int64),
-            ToString(_) => mapper.with_dtype(DataType::String),
+            TotalDays(fractional)
+            | TotalHours(fractional)
+            | TotalMinutes(fractional)
+            | TotalSeconds(fractional)
+            | TotalMilliseconds(fractional)
+            | TotalMicroseconds(fractional)
+            | TotalNanoseconds(fractional) => {
+                if *fractional {
+                    mapper.with_dtype(DataType::Float64)
+                } else {
+                    mapper.with_dtype(DataType::Int64)
+                }
+            },
+            ToString(..) | DurationToString(..) => mapper.with_dtype(DataType::String),
             WithTimeUnit(_) => mapper.with_same_dtype(),
             CastTimeUnit(tu) => mapper.try_map_dtype(|dt| match dt {
                 DataType::Duration(_) => Ok(DataType::Duration(*tu)),
@@ -92,7 +118,15 @@ impl TemporalFunction {
                 dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
             }),
             TimeStamp(_) => mapper.with_dtype(DataType::Int64),
-            IsLeapYear => mapper.with_dtype(DataType::Boolean),
+            Epoch { fractional, .. } => {
+                if *fractional {
+                    mapper.with_dtype(DataType::Float64)
+                } else {
+                    mapper.with_dtype(DataType::Int64)
+                }
+            },
+            IsLeapYear | IsMonthStart | IsMonthEnd | IsQuarterStart(_) | IsQuarterEnd(_)
+            | IsYearStart(_) | IsYearEnd(_) => mapper.with_dtype(DataType::Boolean),
             Time => mapper.with_dtype(DataType::Time),
             Duration(tu) => mapper.with_dtype(DataType::Duration(*tu)),
             Date => mapper.with_dtype(DataType::Date),
@@ -105,13 +139,15 @@ impl TemporalFunction {
             MonthStart => mapper.with_same_dtype(),
             #[cfg(feature = "date_offset")]
             MonthEnd => mapper.with_same_dtype(),
+            #[cfg(feature = "date_offset")]
+            QuarterStart(_) | QuarterEnd(_) => mapper.with_same_dtype(),
             #[cfg(feature = "timezones")]
             BaseUtcOffset => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
             #[cfg(feature = "timezones")]
             DSTOffset => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
             Round(..) => mapper.with_same_dtype(),
             #[cfg(feature = "timezones")]
-            ReplaceTimeZone(tz, _non_existent) => mapper.map_datetime_dtype_timezone(tz.as_ref()),
+            ReplaceTimeZone(tz) => mapper.map_datetime_dtype_timezone(tz.as_ref()),
             DatetimeFunction {
                 time_unit,
                 time_zone,
@@ -119,9 +155,11 @@ impl TemporalFunction {
                 "datetime",
                 DataType::Datetime(*time_unit, time_zone.clone()),
             )),
-            Combine(tu) => mapper.try_map_dtype(|dt| match dt {
-                DataType::Datetime(_, tz) => Ok(DataType::Datetime(*tu, tz.clone())),
-                DataType::Date => Ok(DataType::Datetime(*tu, None)),
+            Combine(tu, time_zone) => mapper.try_map_dtype(|dt| match dt {
+                DataType::Datetime(_, tz) => {
+                    Ok(DataType::Datetime(*tu, time_zone.clone().or(tz.clone())))
+                },
+                DataType::Date => Ok(DataType::Datetime(*tu, time_zone.clone())),
                 dtype => {
                     polars_bail!(ComputeError: "expected Date or Datetime, got {}", dtype)
                 },
@@ -138,8 +176,14 @@ impl Display for TemporalFunction {
             Century => "century",
             Year => "year",
             IsLeapYear => "is_leap_year",
+            IsMonthStart => "is_month_start",
+            IsMonthEnd => "is_month_end",
+            IsQuarterStart(_) => "is_quarter_start",
+            IsQuarterEnd(_) => "is_quarter_end",
+            IsYearStart(_) => "is_year_start",
+            IsYearEnd(_) => "is_year_end",
             IsoYear => "iso_year",
-            Quarter => "quarter",
+            Quarter(_) => "quarter",
             Month => "month",
             Week => "week",
             WeekDay => "weekday",
@@ -155,33 +199,39 @@ impl Display for TemporalFunction {
             Millisecond => "millisecond",
             Microsecond => "microsecond",
             Nanosecond => "nanosecond",
-            TotalDays => "total_days",
-            TotalHours => "total_hours",
-            TotalMinutes => "total_minutes",
-            TotalSeconds => "total_seconds",
-            TotalMilliseconds => "total_milliseconds",
-            TotalMicroseconds => "total_microseconds",
-            TotalNanoseconds => "total_nanoseconds",
-            ToString(_) => "to_string",
+            TotalDays(_) => "total_days",
+            TotalHours(_) => "total_hours",
+            TotalMinutes(_) => "total_minutes",
+            TotalSeconds(_) => "total_seconds",
+            TotalMilliseconds(_) => "total_milliseconds",
+            TotalMicroseconds(_) => "total_microseconds",
+            TotalNanoseconds(_) => "total_nanoseconds",
+            DurationToString(..) => "duration_to_string",
+            ToString(..) => "to_string",
             #[cfg(feature = "timezones")]
             ConvertTimeZone(_) => "convert_time_zone",
             CastTimeUnit(_) => "cast_time_unit",
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
+            Epoch { time_unit, .. } => return write!(f, "dt.epoch({time_unit})"),
             Truncate(..) => "truncate",
             #[cfg(feature = "date_offset")]
             MonthStart => "month_start",
             #[cfg(feature = "date_offset")]
             MonthEnd => "month_end",
+            #[cfg(feature = "date_offset")]
+            QuarterStart(_) => "quarter_start",
+            #[cfg(feature = "date_offset")]
+            QuarterEnd(_) => "quarter_end",
             #[cfg(feature = "timezones")]
             BaseUtcOffset => "base_utc_offset",
             #[cfg(feature = "timezones")]
             DSTOffset => "dst_offset",
             Round(..) => "round",
             #[cfg(feature = "timezones")]
-            ReplaceTimeZone(_, _) => "replace_time_zone",
+            ReplaceTimeZone(_) => "replace_time_zone",
             DatetimeFunction { .. } => return write!(f, "dt.datetime"),
-            Combine(_) => "combine",
+            Combine(..) => "combine",
         };
         write!(f, "dt.{s}")
     }
@@ -199,14 +249,35 @@ pub(super) fn year(s: &Series) -> PolarsResult<Series> {
 pub(super) fn is_leap_year(s: &Series) -> PolarsResult<Series> {
     s.is_leap_year().map(|ca| ca.into_series())
 }
+pub(super) fn is_month_start(s: &Series) -> PolarsResult<Series> {
+    s.is_month_start().map(|ca| ca.into_series())
+}
+pub(super) fn is_month_end(s: &Series) -> PolarsResult<Series> {
+    s.is_month_end().map(|ca| ca.into_series())
+}
+pub(super) fn is_quarter_start(s: &Series, fiscal_year_start: u8) -> PolarsResult<Series> {
+    s.is_quarter_start(fiscal_year_start)
+        .map(|ca| ca.into_series())
+}
+pub(super) fn is_quarter_end(s: &Series, fiscal_year_start: u8) -> PolarsResult<Series> {
+    s.is_quarter_end(fiscal_year_start)
+        .map(|ca| ca.into_series())
+}
+pub(super) fn is_year_start(s: &Series, fiscal_year_start: u8) -> PolarsResult<Series> {
+    s.is_year_start(fiscal_year_start)
+        .map(|ca| ca.into_series())
+}
+pub(super) fn is_year_end(s: &Series, fiscal_year_start: u8) -> PolarsResult<Series> {
+    s.is_year_end(fiscal_year_start).map(|ca| ca.into_series())
+}
 pub(super) fn iso_year(s: &Series) -> PolarsResult<Series> {
     s.iso_year().map(|ca| ca.into_series())
 }
 pub(super) fn month(s: &Series) -> PolarsResult<Series> {
     s.month().map(|ca| ca.into_series())
 }
-pub(super) fn quarter(s: &Series) -> PolarsResult<Series> {
-    s.quarter().map(|ca| ca.into_series())
+pub(super) fn quarter(s: &Series, fiscal_year_start: u8) -> PolarsResult<Series> {
+    s.quarter(fiscal_year_start).map(|ca| ca.into_series())
 }
 pub(super) fn week(s: &Series) -> PolarsResult<Series> {
     s.week().map(|ca| ca.into_series())
@@ -302,32 +373,162 @@ pub(super) fn microsecond(s: &Series) -> PolarsResult<Series> {
 pub(super) fn nanosecond(s: &Series) -> PolarsResult<Series> {
     s.nanosecond().map(|ca| ca.into_series())
 }
-pub(super) fn total_days(s: &Series) -> PolarsResult<Series> {
-    s.duration().map(|ca| ca.days().into_series())
+/// Express a Duration as a fractional number of units of `unit_ns` nanoseconds.
+fn total_fractional(ca: &DurationChunked, unit_ns: i64) -> Series {
+    let tu_ns = match ca.time_unit() {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => NANOSECONDS / MICROSECONDS,
+        TimeUnit::Milliseconds => NANOSECONDS / MILLISECONDS,
+    };
+    // Divide or multiply by an integer factor, so that no precision is lost on the factor.
+    let out: Float64Chunked = if unit_ns % tu_ns == 0 {
+        let factor = (unit_ns / tu_ns) as f64;
+        ca.apply_nonnull_values_generic(DataType::Float64, |v| v as f64 / factor)
+    } else if tu_ns % unit_ns == 0 {
+        let factor = (tu_ns / unit_ns) as f64;
+        ca.apply_nonnull_values_generic(DataType::Float64, |v| v as f64 * factor)
+    } else {
+        let factor = tu_ns as f64 / unit_ns as f64;
+        ca.apply_nonnull_values_generic(DataType::Float64, |v| v as f64 * factor)
+    };
+    out.into_series()
+}
+pub(super) fn total_days(s: &Series, fractional: bool) -> PolarsResult<Series> {
+    let ca = s.duration()?;
+    Ok(if fractional {
+        total_fractional(ca, NANOSECONDS * SECONDS_IN_DAY)
+    } else {
+        ca.days().into_series()
+    })
+}
+pub(super) fn total_hours(s: &Series, fractional: bool) -> PolarsResult<Series> {
+    let ca = s.duration()?;
+    Ok(if fractional {
+        total_fractional(ca, NANOSECONDS * 3600)
+    } else {
+        ca.hours().into_series()
+    })
 }
-pub(super) fn total_hours(s: &Series) -> PolarsResult<Series> {
-    s.duration().map(|ca| ca.hours().into_series())
+pub(super) fn total_minutes(s: &Series, fractional: bool) -> PolarsResult<Series> {
+    let ca = s.duration()?;
+    Ok(if fractional {
+        total_fractional(ca, NANOSECONDS * 60)
+    } else {
+        ca.minutes().into_series()
+    })
 }
-pub(super) fn total_minutes(s: &Series) -> PolarsResult<Series> {
-    s.duration().map(|ca| ca.minutes().into_series())
+pub(super) fn total_seconds(s: &Series, fractional: bool) -> PolarsResult<Series> {
+    let ca = s.duration()?;
+    Ok(if fractional {
+        total_fractional(ca, NANOSECONDS)
+    } else {
+        ca.seconds().into_series()
+    })
 }
-pub(super) fn total_seconds(s: &Series) -> PolarsResult<Series> {
-    s.duration().map(|ca| ca.seconds().into_series())
+pub(super) fn total_milliseconds(s: &Series, fractional: bool) -> PolarsResult<Series> {
+    let ca = s.duration()?;
+    Ok(if fractional {
+        total_fractional(ca, NANOSECONDS / MILLISECONDS)
+    } else {
+        ca.milliseconds().into_series()
+    })
 }
-pub(super) fn total_milliseconds(s: &Series) -> PolarsResult<Series> {
-    s.duration().map(|ca| ca.milliseconds().into_series())
+pub(super) fn total_microseconds(s: &Series, fractional: bool) -> PolarsResult<Series> {
+    let ca = s.duration()?;
+    Ok(if fractional {
+        total_fractional(ca, NANOSECONDS / MICROSECONDS)
+    } else {
+        ca.microseconds().into_series()
+    })
 }
-pub(super) fn total_microseconds(s: &Series) -> PolarsResult<Series> {
-    s.duration().map(|ca| ca.microseconds().into_series())
+pub(super) fn total_nanoseconds(s: &Series, fractional: bool) -> PolarsResult<Series> {
+    let ca = s.duration()?;
+    Ok(if fractional {
+        total_fractional(ca, 1)
+    } else {
+        ca.nanoseconds().into_series()
+    })
 }
-pub(super) fn total_nanoseconds(s: &Series) -> PolarsResult<Series> {
-    s.duration().map(|ca| ca.nanoseconds().into_series())
+pub(super) fn duration_to_string(
+    s: &Series,
+    format: DurationFormat,
+    precision: Option<DurationPrecision>,
+) -> PolarsResult<Series> {
+    let ca = s.duration()?;
+    Ok(ca.duration_to_string(format, precision).into_series())
 }
 pub(super) fn timestamp(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
     s.timestamp(tu).map(|ca| ca.into_series())
 }
-pub(super) fn to_string(s: &Series, format: &str) -> PolarsResult<Series> {
-    TemporalMethods::to_string(s, format)
+
+/// The time elapsed since `origin`, or since the Unix epoch if there is no origin.
+fn elapsed_since(s: &Series, origin: Option<&Series>) -> PolarsResult<Series> {
+    let Some(origin) = origin else {
+        return match s.dtype() {
+            DataType::Date => Ok(s
+                .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
+                .cast(&DataType::Int64)?
+                .cast(&DataType::Duration(TimeUnit::Milliseconds))?),
+            DataType::Datetime(tu, _) => s.cast(&DataType::Int64)?.cast(&DataType::Duration(*tu)),
+            dt => polars_bail!(opq = epoch, got = dt, expected = "date/datetime"),
+        };
+    };
+    let (s, origin) = match (s.dtype(), origin.dtype()) {
+        (DataType::Date, DataType::Date) => (s.clone(), origin.clone()),
+        (DataType::Date, DataType::Datetime(_, None)) => (s.cast(origin.dtype())?, origin.clone()),
+        (DataType::Datetime(_, None), DataType::Date | DataType::Datetime(_, None))
+        | (DataType::Datetime(_, Some(_)), DataType::Datetime(_, Some(_))) => {
+            (s.clone(), origin.cast(s.dtype())?)
+        },
+        #[cfg(feature = "timezones")]
+        (DataType::Datetime(tu, Some(tz)), DataType::Date | DataType::Datetime(_, None)) => {
+            // A time-zone-naive origin is a local time in the time zone of `s`.
+            let origin = origin.cast(&DataType::Datetime(*tu, None))?;
+            let origin = polars_ops::chunked_array::replace_time_zone(
+                origin.datetime()?,
+                Some(tz),
+                &StringChunked::from_iter(std::iter::once("raise")),
+                NonExistent::Raise,
+            )?;
+            (s.clone(), origin.into_series())
+        },
+        (DataType::Date | DataType::Datetime(..), dt) => polars_bail!(
+            InvalidOperation: "cannot use an `origin` of type {} for values of type {}", dt, s.dtype()
+        ),
+        (dt, _) => polars_bail!(opq = epoch, got = dt, expected = "date/datetime"),
+    };
+    Ok(&s - &origin)
+}
+pub(super) fn epoch(s: &[Series], time_unit: &str, fractional: bool) -> PolarsResult<Series> {
+    let unit = Duration::try_parse(time_unit)?;
+    ensure_is_constant_duration(unit, None, "time_unit")?;
+    polars_ensure!(
+        !unit.negative() && !unit.is_zero() && !unit.parsed_int,
+        InvalidOperation: "`time_unit` must be a positive duration, got {}", time_unit
+    );
+    let unit_ns = unit.duration_ns();
+
+    let elapsed = elapsed_since(&s[0], s.get(1))?;
+    let ca = elapsed.duration()?;
+    if fractional {
+        return Ok(total_fractional(ca, unit_ns));
+    }
+    let tu_ns = match ca.time_unit() {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => NANOSECONDS / MICROSECONDS,
+        TimeUnit::Milliseconds => NANOSECONDS / MILLISECONDS,
+    } as i128;
+    // Like timestamps, whole units are rounded down.
+    let out: Int64Chunked =
+        ca.apply_values(|v| (v as i128 * tu_ns).div_euclid(unit_ns as i128) as i64);
+    Ok(out.into_series())
+}
+pub(super) fn to_string(s: &Series, format: &str, locale: Locale) -> PolarsResult<Series> {
+    match s.dtype() {
+        // Times don't have names of months and weekdays to localize.
+        DataType::Date | DataType::Datetime(_, _) => s.to_string_localized(format, locale),
+        _ => TemporalMethods::to_string(s, format),
+    }
 }
 
 #[cfg(feature = "timezones")]
@@ -375,22 +576,23 @@ pub(super) fn cast_time_unit(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
 pub(super) fn truncate(s: &[Series], offset: &str) -> PolarsResult<Series> {
     let time_series = &s[0];
     let every = s[1].str()?;
+    let ambiguous = s.get(2).map(|s| s.str()).transpose()?;
 
     let mut out = match time_series.dtype() {
         DataType::Datetime(_, tz) => match tz {
             #[cfg(feature = "timezones")]
             Some(tz) => time_series
                 .datetime()?
-                .truncate(tz.parse::<Tz>().ok().as_ref(), every, offset)?
+                .truncate(tz.parse::<Tz>().ok().as_ref(), every, offset, ambiguous)?
                 .into_series(),
             _ => time_series
                 .datetime()?
-                .truncate(None, every, offset)?
+                .truncate(None, every, offset, ambiguous)?
                 .into_series(),
         },
         DataType::Date => time_series
             .date()?
-            .truncate(None, every, offset)?
+            .truncate(None, every, offset, ambiguous)?
             .into_series(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
     };
@@ -432,6 +634,56 @@ pub(super) fn month_end(s: &Series) -> PolarsResult<Series> {
     })
 }
 
+#[cfg(feature = "date_offset")]
+pub(super) fn quarter_start(s: &Series, fiscal_year_start: u8) -> PolarsResult<Series> {
+    Ok(match s.dtype() {
+        DataType::Datetime(_, tz) => match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => s
+                .datetime()
+                .unwrap()
+                .quarter_start(fiscal_year_start, tz.parse::<Tz>().ok().as_ref())?
+                .into_series(),
+            _ => s
+                .datetime()
+                .unwrap()
+                .quarter_start(fiscal_year_start, None)?
+                .into_series(),
+        },
+        DataType::Date => s
+            .date()
+            .unwrap()
+            .quarter_start(fiscal_year_start, None)?
+            .into_series(),
+        dt => polars_bail!(opq = quarter_start, got = dt, expected = "date/datetime"),
+    })
+}
+
+#[cfg(feature = "date_offset")]
+pub(super) fn quarter_end(s: &Series, fiscal_year_start: u8) -> PolarsResult<Series> {
+    Ok(match s.dtype() {
+        DataType::Datetime(_, tz) => match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => s
+                .datetime()
+                .unwrap()
+                .quarter_end(fiscal_year_start, tz.parse::<Tz>().ok().as_ref())?
+                .into_series(),
+            _ => s
+                .datetime()
+                .unwrap()
+                .quarter_end(fiscal_year_start, None)?
+                .into_series(),
+        },
+        DataType::Date => s
+            .date()
+            .unwrap()
+            .quarter_end(fiscal_year_start, None)?
+            .into_series(),
+        dt => polars_bail!(opq = quarter_end, got = dt, expected = "date/datetime"),
+    })
+}
+
 #[cfg(feature = "timezones")]
 pub(super) fn base_utc_offset(s: &Series) -> PolarsResult<Series> {
     match s.dtype() {
@@ -465,11 +717,12 @@ pub(super) fn dst_offset(s: &Series) -> PolarsResult<Series> {
     }
 }
 
-pub(super) fn round(s: &[Series], offset: &str) -> PolarsResult<Series> {
+pub(super) fn round(s: &[Series], offset: &str, week_start: u8) -> PolarsResult<Series> {
     let offset = Duration::parse(offset);
 
     let time_series = &s[0];
     let every = s[1].str()?;
+    let ambiguous = s.get(2).map(|s| s.str()).transpose()?;
 
     Ok(match time_series.dtype() {
         DataType::Datetime(_, tz) => match tz {
@@ -477,18 +730,24 @@ pub(super) fn round(s: &[Series], offset: &str) -> PolarsResult<Series> {
             Some(tz) => time_series
                 .datetime()
                 .unwrap()
-                .round(every, offset, tz.parse::<Tz>().ok().as_ref())?
+                .round(
+                    every,
+                    offset,
+                    week_start,
+                    tz.parse::<Tz>().ok().as_ref(),
+                    ambiguous,
+                )?
                 .into_series(),
             _ => time_series
                 .datetime()
                 .unwrap()
-                .round(every, offset, None)?
+                .round(every, offset, week_start, None, ambiguous)?
                 .into_series(),
         },
         DataType::Date => time_series
             .date()
             .unwrap()
-            .round(every, offset, None)?
+            .round(every, offset, week_start, None, ambiguous)?
             .into_series(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
     })
@@ -24,6 +24,11 @@ pub(super) fn interpolate(s: &Series, method: InterpolationMethod) -> PolarsResu
     Ok(polars_ops::prelude::interpolate(s, method))
 }
 
+#[cfg(feature = "hash_stable")]
+pub(super) fn hash_stable(s: &Series, seed: u64) -> PolarsResult<Series> {
+    polars_ops::prelude::hash_stable(s, seed).map(|ca| ca.into_series())
+}
+
 pub(super) fn to_physical(s: &Series) -> PolarsResult<Series> {
     Ok(s.to_physical_repr().into_owned())
 }
@@ -35,15 +40,12 @@ pub(super) fn set_sorted_flag(s: &Series, sorted: IsSorted) -> PolarsResult<Seri
 }
 
 #[cfg(feature = "timezones")]
-pub(super) fn replace_time_zone(
-    s: &[Series],
-    time_zone: Option<&str>,
-    non_existent: NonExistent,
-) -> PolarsResult<Series> {
+pub(super) fn replace_time_zone(s: &[Series], time_zone: Option<&str>) -> PolarsResult<Series> {
     let s1 = &s[0];
     let ca = s1.datetime().unwrap();
     let s2 = &s[1].str()?;
-    Ok(polars_ops::prelude::replace_time_zone(ca, time_zone, s2, non_existent)?.into_series())
+    let s3 = &s[2].str()?;
+    Ok(polars_ops::prelude::replace_time_zone_elementwise(ca, time_zone, s2, s3)?.into_series())
 }
 
 #[cfg(feature = "dtype-struct")]
@@ -161,6 +163,11 @@ pub(super) fn reinterpret(s: &Series, signed: bool) -> PolarsResult<Series> {
     polars_ops::series::reinterpret(s, signed)
 }
 
+#[cfg(feature = "reinterpret")]
+pub(super) fn to_binary(s: &Series, is_little_endian: bool) -> PolarsResult<Series> {
+    polars_ops::series::numeric_to_binary(s, is_little_endian).map(|ca| ca.into_series())
+}
+
 pub(super) fn negate(s: &Series) -> PolarsResult<Series> {
     polars_ops::series::negate(s)
 }
@@ -4,7 +4,7 @@ use polars_ops::chunked_array::list::*;
 use super::*;
 use crate::{map, map_as_slice, wrap};
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ListFunction {
     Concat,
@@ -21,13 +21,17 @@ pub enum ListFunction {
     },
     Slice,
     Shift,
+    PadEnd,
     Get(bool),
     #[cfg(feature = "list_gather")]
     Gather(bool),
     #[cfg(feature = "list_gather")]
     GatherEvery,
+    Scatter(bool),
+    ZipWith(bool),
     #[cfg(feature = "list_count")]
     CountMatches,
+    IndexOf,
     Sum,
     Length,
     Max,
@@ -43,19 +47,60 @@ pub enum ListFunction {
         n: i64,
         null_behavior: NullBehavior,
     },
+    #[cfg(feature = "rolling_window")]
+    RollingSum {
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    },
+    #[cfg(feature = "rolling_window")]
+    RollingMean {
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    },
+    #[cfg(feature = "rolling_window")]
+    RollingMin {
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    },
+    #[cfg(feature = "rolling_window")]
+    RollingMax {
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    },
     Sort(SortOptions),
+    #[cfg(feature = "top_k")]
+    TopK(SortOptions),
     Reverse,
     Unique(bool),
     NUnique,
+    Dedupe,
+    Chunk(usize),
     #[cfg(feature = "list_sets")]
     SetOperation(SetOperation),
     #[cfg(feature = "list_any_all")]
     Any,
     #[cfg(feature = "list_any_all")]
     All,
-    Join(bool),
+    Join {
+        ignore_nulls: bool,
+        null_replacement: Option<Arc<str>>,
+    },
     #[cfg(feature = "dtype-array")]
-    ToArray(usize),
+    ToArray {
+        width: usize,
+        null_on_mismatch: bool,
+    },
+    #[cfg(feature = "dtype-struct")]
+    StructField(Arc<str>),
+    #[cfg(feature = "dtype-struct")]
+    ValueCounts {
+        sort: bool,
+        normalize: bool,
+    },
 }
 
 impl ListFunction {
@@ -71,13 +116,25 @@ impl ListFunction {
             Sample { .. } => mapper.with_same_dtype(),
             Slice => mapper.with_same_dtype(),
             Shift => mapper.with_same_dtype(),
+            PadEnd => mapper.with_same_dtype(),
             Get(_) => mapper.map_to_list_and_array_inner_dtype(),
             #[cfg(feature = "list_gather")]
             Gather(_) => mapper.with_same_dtype(),
             #[cfg(feature = "list_gather")]
             GatherEvery => mapper.with_same_dtype(),
+            Scatter(_) => mapper.with_same_dtype(),
+            ZipWith(_) => {
+                for field in mapper.args() {
+                    polars_ensure!(
+                        matches!(field.data_type(), DataType::List(_)),
+                        SchemaMismatch: "`list.zip_with` expected `List` inputs, got `{}`", field.data_type()
+                    );
+                }
+                mapper.with_same_dtype()
+            },
             #[cfg(feature = "list_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
+            IndexOf => mapper.with_dtype(IDX_DTYPE),
             Sum => mapper.nested_sum_type(),
             Min => mapper.map_to_list_and_array_inner_dtype(),
             Max => mapper.map_to_list_and_array_inner_dtype(),
@@ -89,7 +146,23 @@ impl ListFunction {
             ArgMax => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "diff")]
             Diff { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "rolling_window")]
+            RollingSum { .. } => mapper.map_dtype(|dt| match dt.inner_dtype() {
+                Some(DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16) => {
+                    DataType::List(Box::new(DataType::Int64))
+                },
+                _ => dt.clone(),
+            }),
+            #[cfg(feature = "rolling_window")]
+            RollingMin { .. } | RollingMax { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "rolling_window")]
+            RollingMean { .. } => mapper.map_dtype(|dt| match dt.inner_dtype() {
+                Some(DataType::Float32) => DataType::List(Box::new(DataType::Float32)),
+                _ => DataType::List(Box::new(DataType::Float64)),
+            }),
             Sort(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "top_k")]
+            TopK(_) => mapper.with_same_dtype(),
             Reverse => mapper.with_same_dtype(),
             Unique(_) => mapper.with_same_dtype(),
             Length => mapper.with_dtype(IDX_DTYPE),
@@ -99,10 +172,43 @@ impl ListFunction {
             Any => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "list_any_all")]
             All => mapper.with_dtype(DataType::Boolean),
-            Join(_) => mapper.with_dtype(DataType::String),
+            Join { .. } => mapper.with_dtype(DataType::String),
             #[cfg(feature = "dtype-array")]
-            ToArray(width) => mapper.try_map_dtype(|dt| map_list_dtype_to_array_dtype(dt, *width)),
+            ToArray { width, .. } => {
+                mapper.try_map_dtype(|dt| map_list_dtype_to_array_dtype(dt, *width))
+            },
             NUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "dtype-struct")]
+            StructField(name) => mapper.try_map_dtype(|dt| match dt.inner_dtype() {
+                Some(DataType::Struct(fields)) => {
+                    let fld = fields
+                        .iter()
+                        .find(|fld| fld.name() == name.as_ref())
+                        .ok_or_else(|| polars_err!(StructFieldNotFound: "{}", name.as_ref()))?;
+                    Ok(DataType::List(Box::new(fld.data_type().clone())))
+                },
+                _ => polars_bail!(
+                    InvalidOperation: "`list.struct_field` expected a list of structs, got `{}`", dt
+                ),
+            }),
+            Dedupe => mapper.with_same_dtype(),
+            Chunk(_) => mapper.map_dtype(|dt| DataType::List(Box::new(dt.clone()))),
+            #[cfg(feature = "dtype-struct")]
+            ValueCounts { normalize, .. } => mapper.map_dtype(|dt| {
+                let count = if *normalize {
+                    Field::new("proportion", DataType::Float64)
+                } else {
+                    Field::new("count", IDX_DTYPE)
+                };
+                let inner = dt
+                    .inner_dtype()
+                    .cloned()
+                    .unwrap_or(DataType::Unknown(Default::default()));
+                DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new("value", inner),
+                    count,
+                ])))
+            }),
         }
     }
 }
@@ -136,13 +242,17 @@ impl Display for ListFunction {
             },
             Slice => "slice",
             Shift => "shift",
+            PadEnd => "pad_end",
             Get(_) => "get",
             #[cfg(feature = "list_gather")]
             Gather(_) => "gather",
             #[cfg(feature = "list_gather")]
             GatherEvery => "gather_every",
+            Scatter(_) => "scatter",
+            ZipWith(_) => "zip_with",
             #[cfg(feature = "list_count")]
             CountMatches => "count_matches",
+            IndexOf => "index_of",
             Sum => "sum",
             Min => "min",
             Max => "max",
@@ -154,8 +264,24 @@ impl Display for ListFunction {
             ArgMax => "arg_max",
             #[cfg(feature = "diff")]
             Diff { .. } => "diff",
+            #[cfg(feature = "rolling_window")]
+            RollingSum { .. } => "rolling_sum",
+            #[cfg(feature = "rolling_window")]
+            RollingMean { .. } => "rolling_mean",
+            #[cfg(feature = "rolling_window")]
+            RollingMin { .. } => "rolling_min",
+            #[cfg(feature = "rolling_window")]
+            RollingMax { .. } => "rolling_max",
             Length => "length",
             Sort(_) => "sort",
+            #[cfg(feature = "top_k")]
+            TopK(SortOptions { descending, .. }) => {
+                if *descending {
+                    "bottom_k"
+                } else {
+                    "top_k"
+                }
+            },
             Reverse => "reverse",
             Unique(is_stable) => {
                 if *is_stable {
@@ -165,15 +291,21 @@ impl Display for ListFunction {
                 }
             },
             NUnique => "n_unique",
+            #[cfg(feature = "dtype-struct")]
+            StructField(_) => "struct_field",
+            Dedupe => "dedupe",
+            Chunk(_) => "chunk",
+            #[cfg(feature = "dtype-struct")]
+            ValueCounts { .. } => "value_counts",
             #[cfg(feature = "list_sets")]
             SetOperation(s) => return write!(f, "list.{s}"),
             #[cfg(feature = "list_any_all")]
             Any => "any",
             #[cfg(feature = "list_any_all")]
             All => "all",
-            Join(_) => "join",
+            Join { .. } => "join",
             #[cfg(feature = "dtype-array")]
-            ToArray(_) => "to_array",
+            ToArray { .. } => "to_array",
         };
         write!(f, "list.{name}")
     }
@@ -203,13 +335,17 @@ impl From<ListFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             },
             Slice => wrap!(slice),
             Shift => map_as_slice!(shift),
+            PadEnd => map_as_slice!(pad_end),
             Get(null_on_oob) => wrap!(get, null_on_oob),
             #[cfg(feature = "list_gather")]
             Gather(null_on_oob) => map_as_slice!(gather, null_on_oob),
             #[cfg(feature = "list_gather")]
             GatherEvery => map_as_slice!(gather_every),
+            Scatter(ignore_oob) => map_as_slice!(scatter, ignore_oob),
+            ZipWith(null_on_mismatch) => map_as_slice!(zip_with, null_on_mismatch),
             #[cfg(feature = "list_count")]
             CountMatches => map_as_slice!(count_matches),
+            IndexOf => map_as_slice!(index_of),
             Sum => map!(sum),
             Length => map!(length),
             Max => map!(max),
@@ -222,7 +358,33 @@ impl From<ListFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             ArgMax => map!(arg_max),
             #[cfg(feature = "diff")]
             Diff { n, null_behavior } => map!(diff, n, null_behavior),
+            #[cfg(feature = "rolling_window")]
+            RollingSum {
+                window_size,
+                min_periods,
+                center,
+            } => map!(rolling_sum, window_size, min_periods, center),
+            #[cfg(feature = "rolling_window")]
+            RollingMean {
+                window_size,
+                min_periods,
+                center,
+            } => map!(rolling_mean, window_size, min_periods, center),
+            #[cfg(feature = "rolling_window")]
+            RollingMin {
+                window_size,
+                min_periods,
+                center,
+            } => map!(rolling_min, window_size, min_periods, center),
+            #[cfg(feature = "rolling_window")]
+            RollingMax {
+                window_size,
+                min_periods,
+                center,
+            } => map!(rolling_max, window_size, min_periods, center),
             Sort(options) => map!(sort, options),
+            #[cfg(feature = "top_k")]
+            TopK(options) => map_as_slice!(top_k, options),
             Reverse => map!(reverse),
             Unique(is_stable) => map!(unique, is_stable),
             #[cfg(feature = "list_sets")]
@@ -231,10 +393,22 @@ impl From<ListFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Any => map!(lst_any),
             #[cfg(feature = "list_any_all")]
             All => map!(lst_all),
-            Join(ignore_nulls) => map_as_slice!(join, ignore_nulls),
+            Join {
+                ignore_nulls,
+                null_replacement,
+            } => map_as_slice!(join, ignore_nulls, null_replacement.as_deref()),
             #[cfg(feature = "dtype-array")]
-            ToArray(width) => map!(to_array, width),
+            ToArray {
+                width,
+                null_on_mismatch,
+            } => map!(to_array, width, null_on_mismatch),
             NUnique => map!(n_unique),
+            #[cfg(feature = "dtype-struct")]
+            StructField(name) => map!(struct_field, name.clone()),
+            Dedupe => map!(dedupe),
+            Chunk(size) => map!(chunk, size),
+            #[cfg(feature = "dtype-struct")]
+            ValueCounts { sort, normalize } => map!(value_counts, sort, normalize),
         }
     }
 }
@@ -302,6 +476,15 @@ pub(super) fn shift(s: &[Series]) -> PolarsResult<Series> {
     list.lst_shift(periods).map(|ok| ok.into_series())
 }
 
+pub(super) fn pad_end(s: &[Series]) -> PolarsResult<Series> {
+    let list = s[0].list()?;
+    let length = &s[1];
+    let fill_value = &s[2];
+
+    list.lst_pad_end(length, fill_value)
+        .map(|ok| ok.into_series())
+}
+
 pub(super) fn slice(args: &mut [Series]) -> PolarsResult<Option<Series>> {
     let s = &args[0];
     let list_ca = s.list()?;
@@ -510,6 +693,28 @@ pub(super) fn count_matches(args: &[Series]) -> PolarsResult<Series> {
     list_count_matches(ca, element.get(0).unwrap())
 }
 
+pub(super) fn scatter(args: &[Series], ignore_oob: bool) -> PolarsResult<Series> {
+    let ca = args[0].list()?;
+    let indices = &args[1];
+    let values = &args[2];
+    Ok(ca.lst_scatter(indices, values, ignore_oob)?.into_series())
+}
+
+pub(super) fn zip_with(args: &[Series], null_on_mismatch: bool) -> PolarsResult<Series> {
+    let ca = args[0].list()?;
+    let mask = &args[1];
+    let other = &args[2];
+    Ok(ca
+        .lst_zip_with(mask, other, null_on_mismatch)?
+        .into_series())
+}
+
+pub(super) fn index_of(args: &[Series]) -> PolarsResult<Series> {
+    let ca = args[0].list()?;
+    let value = &args[1];
+    Ok(list_index_of(ca, value)?.into_series())
+}
+
 pub(super) fn sum(s: &Series) -> PolarsResult<Series> {
     s.list()?.lst_sum()
 }
@@ -555,10 +760,107 @@ pub(super) fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsRes
     Ok(s.list()?.lst_diff(n, null_behavior)?.into_series())
 }
 
+#[cfg(feature = "rolling_window")]
+fn rolling_sub_lists(
+    s: &Series,
+    name: &str,
+    options: RollingOptionsFixedWindow,
+    func: fn(&Series, RollingOptionsFixedWindow) -> PolarsResult<Series>,
+) -> PolarsResult<Series> {
+    let inner_dtype = s.list()?.inner_dtype();
+    polars_ensure!(
+        inner_dtype.is_numeric(),
+        InvalidOperation: "`list.{}` operation not supported for dtype `{}`", name, inner_dtype
+    );
+    // The rolling kernels don't support the small integer types, so we compute them as
+    // `Int64`. Like `sum`, `rolling_sum` keeps the wider dtype, while `rolling_min` and
+    // `rolling_max` cast back.
+    let s = match inner_dtype {
+        DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16 => {
+            s.cast(&DataType::List(Box::new(DataType::Int64)))?
+        },
+        _ => s.clone(),
+    };
+    let ca = s.list()?;
+    let inner_dtype = ca.inner_dtype();
+
+    // Resolve the output dtype up front, so that it is also correct if there are no sublists.
+    let dtype = func(&Series::new_empty("", &inner_dtype), options.clone())?
+        .dtype()
+        .clone();
+    let out = ca.try_apply_amortized(|s| func(s.as_ref(), options.clone()))?;
+    out.cast(&DataType::List(Box::new(dtype)))
+}
+
+#[cfg(feature = "rolling_window")]
+fn rolling_options(
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> RollingOptionsFixedWindow {
+    RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..Default::default()
+    }
+}
+
+#[cfg(feature = "rolling_window")]
+pub(super) fn rolling_sum(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> PolarsResult<Series> {
+    let options = rolling_options(window_size, min_periods, center);
+    rolling_sub_lists(s, "rolling_sum", options, super::rolling::rolling_sum)
+}
+
+#[cfg(feature = "rolling_window")]
+pub(super) fn rolling_mean(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> PolarsResult<Series> {
+    let options = rolling_options(window_size, min_periods, center);
+    rolling_sub_lists(s, "rolling_mean", options, super::rolling::rolling_mean)
+}
+
+#[cfg(feature = "rolling_window")]
+pub(super) fn rolling_min(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> PolarsResult<Series> {
+    let options = rolling_options(window_size, min_periods, center);
+    rolling_sub_lists(s, "rolling_min", options, super::rolling::rolling_min)?.cast(s.dtype())
+}
+
+#[cfg(feature = "rolling_window")]
+pub(super) fn rolling_max(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> PolarsResult<Series> {
+    let options = rolling_options(window_size, min_periods, center);
+    rolling_sub_lists(s, "rolling_max", options, super::rolling::rolling_max)?.cast(s.dtype())
+}
+
 pub(super) fn sort(s: &Series, options: SortOptions) -> PolarsResult<Series> {
     Ok(s.list()?.lst_sort(options)?.into_series())
 }
 
+#[cfg(feature = "top_k")]
+pub(super) fn top_k(args: &[Series], options: SortOptions) -> PolarsResult<Series> {
+    let ca = args[0].list()?;
+    let k = &args[1];
+    Ok(ca.lst_top_k(k, options)?.into_series())
+}
+
 pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     Ok(s.list()?.lst_reverse().into_series())
 }
@@ -609,18 +911,42 @@ pub(super) fn lst_all(s: &Series) -> PolarsResult<Series> {
     s.list()?.lst_all()
 }
 
-pub(super) fn join(s: &[Series], ignore_nulls: bool) -> PolarsResult<Series> {
+pub(super) fn join(
+    s: &[Series],
+    ignore_nulls: bool,
+    null_replacement: Option<&str>,
+) -> PolarsResult<Series> {
     let ca = s[0].list()?;
     let separator = s[1].str()?;
-    Ok(ca.lst_join(separator, ignore_nulls)?.into_series())
+    Ok(ca
+        .lst_join(separator, ignore_nulls, null_replacement)?
+        .into_series())
 }
 
 #[cfg(feature = "dtype-array")]
-pub(super) fn to_array(s: &Series, width: usize) -> PolarsResult<Series> {
-    let array_dtype = map_list_dtype_to_array_dtype(s.dtype(), width)?;
-    s.cast(&array_dtype)
+pub(super) fn to_array(s: &Series, width: usize, null_on_mismatch: bool) -> PolarsResult<Series> {
+    let ca = s.list()?;
+    Ok(ca.lst_to_array(width, null_on_mismatch)?.into_series())
 }
 
 pub(super) fn n_unique(s: &Series) -> PolarsResult<Series> {
     Ok(s.list()?.lst_n_unique()?.into_series())
 }
+
+pub(super) fn dedupe(s: &Series) -> PolarsResult<Series> {
+    Ok(s.list()?.lst_dedupe()?.into_series())
+}
+
+pub(super) fn chunk(s: &Series, size: usize) -> PolarsResult<Series> {
+    Ok(s.list()?.lst_chunk(size)?.into_series())
+}
+
+#[cfg(feature = "dtype-struct")]
+pub(super) fn struct_field(s: &Series, name: Arc<str>) -> PolarsResult<Series> {
+    Ok(s.list()?.lst_struct_field(name.as_ref())?.into_series())
+}
+
+#[cfg(feature = "dtype-struct")]
+pub(super) fn value_counts(s: &Series, sort: bool, normalize: bool) -> PolarsResult<Series> {
+    Ok(s.list()?.lst_value_counts(sort, normalize)?.into_series())
+}
@@ -5,6 +5,8 @@ mod arg_where;
 #[cfg(feature = "dtype-array")]
 mod array;
 mod binary;
+#[cfg(feature = "bitwise")]
+mod bitwise;
 mod boolean;
 mod bounds;
 #[cfg(feature = "business")]
@@ -86,6 +88,8 @@ use schema::FieldsMapper;
 use serde::{Deserialize, Serialize};
 
 pub(crate) use self::binary::BinaryFunction;
+#[cfg(feature = "bitwise")]
+pub(super) use self::bitwise::BitwiseFunction;
 pub use self::boolean::BooleanFunction;
 #[cfg(feature = "business")]
 pub(super) use self::business::BusinessFunction;
@@ -100,6 +104,8 @@ pub(super) use self::range::RangeFunction;
 pub(super) use self::rolling::RollingFunction;
 #[cfg(feature = "rolling_window_by")]
 pub(super) use self::rolling_by::RollingFunctionBy;
+#[cfg(all(feature = "strings", feature = "timezones"))]
+pub(super) use self::strings::is_tz_aware_format;
 #[cfg(feature = "strings")]
 pub use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
@@ -127,6 +133,8 @@ pub enum FunctionExpr {
 
     // Other expressions
     Boolean(BooleanFunction),
+    #[cfg(feature = "bitwise")]
+    Bitwise(BitwiseFunction),
     #[cfg(feature = "business")]
     Business(BusinessFunction),
     #[cfg(feature = "abs")]
@@ -142,6 +150,8 @@ pub enum FunctionExpr {
     Pow(PowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "hash_stable")]
+    HashStable(u64),
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "search_sorted")]
@@ -149,7 +159,9 @@ pub enum FunctionExpr {
     #[cfg(feature = "range")]
     Range(RangeFunction),
     #[cfg(feature = "date_offset")]
-    DateOffset,
+    DateOffset {
+        end_of_month: bool,
+    },
     #[cfg(feature = "trigonometry")]
     Trigonometry(TrigonometricFunction),
     #[cfg(feature = "trigonometry")]
@@ -348,6 +360,8 @@ pub enum FunctionExpr {
     },
     #[cfg(feature = "reinterpret")]
     Reinterpret(bool),
+    #[cfg(feature = "reinterpret")]
+    ToBinary(bool),
     ExtendConstant,
 }
 
@@ -372,6 +386,8 @@ impl Hash for FunctionExpr {
 
             // Other expressions
             Boolean(f) => f.hash(state),
+            #[cfg(feature = "bitwise")]
+            Bitwise(f) => f.hash(state),
             #[cfg(feature = "business")]
             Business(f) => f.hash(state),
             Pow(f) => f.hash(state),
@@ -410,7 +426,7 @@ impl Hash for FunctionExpr {
             Negate => {},
             NullCount => {},
             #[cfg(feature = "date_offset")]
-            DateOffset => {},
+            DateOffset { end_of_month } => end_of_month.hash(state),
             #[cfg(feature = "arg_where")]
             ArgWhere => {},
             #[cfg(feature = "trigonometry")]
@@ -421,6 +437,8 @@ impl Hash for FunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "hash_stable")]
+            HashStable(seed) => seed.hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr(f) => {
@@ -566,6 +584,8 @@ impl Hash for FunctionExpr {
             GatherEvery { n, offset } => (n, offset).hash(state),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => signed.hash(state),
+            #[cfg(feature = "reinterpret")]
+            ToBinary(is_little_endian) => is_little_endian.hash(state),
             ExtendConstant => {},
             #[cfg(feature = "top_k")]
             TopKBy { sort_options } => sort_options.hash(state),
@@ -593,6 +613,8 @@ impl Display for FunctionExpr {
 
             // Other expressions
             Boolean(func) => return write!(f, "{func}"),
+            #[cfg(feature = "bitwise")]
+            Bitwise(func) => return write!(f, "{func}"),
             #[cfg(feature = "business")]
             Business(func) => return write!(f, "{func}"),
             #[cfg(feature = "abs")]
@@ -602,6 +624,8 @@ impl Display for FunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "hash_stable")]
+            HashStable(_) => "hash_stable",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "search_sorted")]
@@ -609,7 +633,7 @@ impl Display for FunctionExpr {
             #[cfg(feature = "range")]
             Range(func) => return write!(f, "{func}"),
             #[cfg(feature = "date_offset")]
-            DateOffset => "dt.offset_by",
+            DateOffset { .. } => "dt.offset_by",
             #[cfg(feature = "trigonometry")]
             Trigonometry(func) => return write!(f, "{func}"),
             #[cfg(feature = "trigonometry")]
@@ -753,6 +777,8 @@ impl Display for FunctionExpr {
             GatherEvery { .. } => "gather_every",
             #[cfg(feature = "reinterpret")]
             Reinterpret(_) => "reinterpret",
+            #[cfg(feature = "reinterpret")]
+            ToBinary(_) => "to_binary",
             ExtendConstant => "extend_constant",
         };
         write!(f, "{s}")
@@ -861,6 +887,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
 
             // Other expressions
             Boolean(func) => func.into(),
+            #[cfg(feature = "bitwise")]
+            Bitwise(func) => func.into(),
             #[cfg(feature = "business")]
             Business(func) => func.into(),
             #[cfg(feature = "abs")]
@@ -882,6 +910,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Hash(k0, k1, k2, k3) => {
                 map!(row_hash::row_hash, k0, k1, k2, k3)
             },
+            #[cfg(feature = "hash_stable")]
+            HashStable(seed) => map!(dispatch::hash_stable, seed),
             #[cfg(feature = "arg_where")]
             ArgWhere => {
                 wrap!(arg_where::arg_where)
@@ -894,8 +924,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Range(func) => func.into(),
 
             #[cfg(feature = "date_offset")]
-            DateOffset => {
-                map_as_slice!(temporal::date_offset)
+            DateOffset { end_of_month } => {
+                map_as_slice!(temporal::date_offset, end_of_month)
             },
 
             #[cfg(feature = "trigonometry")]
@@ -1134,6 +1164,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             GatherEvery { n, offset } => map!(dispatch::gather_every, n, offset),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => map!(dispatch::reinterpret, signed),
+            #[cfg(feature = "reinterpret")]
+            ToBinary(is_little_endian) => map!(dispatch::to_binary, is_little_endian),
             ExtendConstant => map_as_slice!(dispatch::extend_constant),
         }
     }
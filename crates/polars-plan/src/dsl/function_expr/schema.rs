@@ -29,10 +29,13 @@ impl FunctionExpr {
 
             // Other expressions
             Boolean(func) => func.get_field(mapper),
+            #[cfg(feature = "bitwise")]
+            Bitwise(_) => mapper.with_same_dtype(),
             #[cfg(feature = "business")]
             Business(func) => match func {
                 BusinessFunction::BusinessDayCount { .. } => mapper.with_dtype(DataType::Int32),
                 BusinessFunction::AddBusinessDay { .. } => mapper.with_same_dtype(),
+                BusinessFunction::IsBusinessDay { .. } => mapper.with_dtype(DataType::Boolean),
             },
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
@@ -45,6 +48,8 @@ impl FunctionExpr {
             Coalesce => mapper.map_to_supertype(),
             #[cfg(feature = "row_hash")]
             Hash(..) => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "hash_stable")]
+            HashStable(_) => mapper.with_dtype(DataType::UInt64),
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "search_sorted")]
@@ -314,6 +319,8 @@ impl FunctionExpr {
                 };
                 mapper.with_dtype(dt)
             },
+            #[cfg(feature = "reinterpret")]
+            ToBinary(_) => mapper.with_dtype(DataType::Binary),
             ExtendConstant => mapper.with_same_dtype(),
         }
     }
@@ -494,6 +501,39 @@ impl<'a> FieldsMapper<'a> {
         Ok(first)
     }
 
+    pub fn nested_product_type(&self) -> PolarsResult<Field> {
+        let mut first = self.fields[0].clone();
+        use DataType::*;
+        let dt = first
+            .data_type()
+            .inner_dtype()
+            .cloned()
+            .unwrap_or_else(|| Unknown(Default::default()));
+
+        match dt {
+            Float32 | Float64 | UInt64 => first.coerce(dt),
+            _ => first.coerce(Int64),
+        }
+        Ok(first)
+    }
+
+    pub fn nested_mean_type(&self) -> PolarsResult<Field> {
+        let mut first = self.fields[0].clone();
+        use DataType::*;
+        let dt = first
+            .data_type()
+            .inner_dtype()
+            .cloned()
+            .unwrap_or_else(|| Unknown(Default::default()));
+
+        match dt {
+            Float32 => first.coerce(Float32),
+            dt if dt.is_temporal() => first.coerce(dt),
+            _ => first.coerce(Float64),
+        }
+        Ok(first)
+    }
+
     pub(super) fn pow_dtype(&self) -> PolarsResult<Field> {
         let base_dtype = self.fields[0].data_type();
         let exponent_dtype = self.fields[1].data_type();
@@ -10,7 +10,13 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "timezones")]
 static TZ_AWARE_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"(%z)|(%:z)|(%::z)|(%:::z)|(%#z)|(^%\+$)").unwrap());
+    Lazy::new(|| Regex::new(r"(%z)|(%:z)|(%::z)|(%:::z)|(%#z)|(%Z)|(^%\+$)").unwrap());
+
+/// Whether `format` has a time zone offset or name, which is parsed into UTC.
+#[cfg(feature = "timezones")]
+pub(crate) fn is_tz_aware_format(format: &str) -> bool {
+    TZ_AWARE_RE.is_match(format)
+}
 
 use polars_core::utils::handle_casting_failures;
 #[cfg(feature = "dtype-struct")]
@@ -39,6 +45,8 @@ pub enum StringFunction {
     },
     CountMatches(bool),
     EndsWith,
+    #[cfg(feature = "regex")]
+    EscapeRegex,
     Explode,
     Extract(usize),
     ExtractAll,
@@ -51,16 +59,19 @@ pub enum StringFunction {
     Find {
         literal: bool,
         strict: bool,
+        char_index: bool,
     },
     #[cfg(feature = "string_to_integer")]
     ToInteger(bool),
     LenBytes,
     LenChars,
     Lowercase,
+    Casefold,
     #[cfg(feature = "extract_jsonpath")]
     JsonDecode {
         dtype: Option<DataType>,
         infer_schema_len: Option<usize>,
+        strict: bool,
     },
     #[cfg(feature = "extract_jsonpath")]
     JsonPathMatch,
@@ -71,17 +82,27 @@ pub enum StringFunction {
         n: i64,
         literal: bool,
     },
+    #[cfg(feature = "string_normalize")]
+    Normalize {
+        form: UnicodeForm,
+    },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein,
+    #[cfg(feature = "string_similarity")]
+    Hamming,
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
     #[cfg(feature = "string_pad")]
     PadStart {
-        length: usize,
         fill_char: char,
+        display_width: bool,
     },
     #[cfg(feature = "string_pad")]
     PadEnd {
-        length: usize,
         fill_char: char,
+        display_width: bool,
     },
     Slice,
     Head,
@@ -94,6 +115,14 @@ pub enum StringFunction {
     Base64Encode,
     #[cfg(feature = "binary_encoding")]
     Base64Decode(bool),
+    #[cfg(feature = "string_encoding")]
+    Base32Encode,
+    #[cfg(feature = "binary_encoding")]
+    Base32Decode(bool),
+    #[cfg(feature = "string_encoding")]
+    PercentEncode,
+    #[cfg(feature = "binary_encoding")]
+    PercentDecode(bool),
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -112,9 +141,21 @@ pub enum StringFunction {
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
-    #[cfg(feature = "nightly")]
+    #[cfg(all(feature = "timezones", feature = "dtype-duration"))]
+    ToUtcOffset {
+        format: String,
+        strict: bool,
+        cache: bool,
+    },
+    #[cfg(feature = "string_to_titlecase")]
     Titlecase,
     Uppercase,
+    #[cfg(feature = "string_wrap")]
+    Wrap {
+        width: usize,
+        break_long_words: bool,
+        tab_size: usize,
+    },
     #[cfg(feature = "string_pad")]
     ZFill,
     #[cfg(feature = "find_many")]
@@ -125,6 +166,11 @@ pub enum StringFunction {
     ReplaceMany {
         ascii_case_insensitive: bool,
     },
+    #[cfg(feature = "find_many")]
+    ExtractMany {
+        ascii_case_insensitive: bool,
+        overlapping: bool,
+    },
 }
 
 impl StringFunction {
@@ -137,6 +183,8 @@ impl StringFunction {
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
             CountMatches(_) => mapper.with_dtype(DataType::UInt32),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "regex")]
+            EscapeRegex => mapper.with_same_dtype(),
             Explode => mapper.with_same_dtype(),
             Extract(_) => mapper.with_same_dtype(),
             ExtractAll => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
@@ -154,15 +202,23 @@ impl StringFunction {
             LenChars => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "regex")]
             Replace { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "string_normalize")]
+            Normalize { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein | Hamming => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
-            #[cfg(feature = "nightly")]
+            #[cfg(feature = "string_to_titlecase")]
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => mapper.with_dtype(DataType::Decimal(None, None)),
+            #[cfg(all(feature = "timezones", feature = "dtype-duration"))]
+            ToUtcOffset { .. } => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
             #[cfg(feature = "string_encoding")]
             HexEncode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
@@ -171,10 +227,16 @@ impl StringFunction {
             Base64Encode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => mapper.with_dtype(DataType::Binary),
-            Uppercase | Lowercase | StripChars | StripCharsStart | StripCharsEnd | StripPrefix
-            | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
+            #[cfg(feature = "string_encoding")]
+            Base32Encode | PercentEncode => mapper.with_same_dtype(),
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(_) | PercentDecode(_) => mapper.with_dtype(DataType::Binary),
+            Uppercase | Lowercase | Casefold | StripChars | StripCharsStart | StripCharsEnd
+            | StripPrefix | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
             #[cfg(feature = "string_pad")]
             PadStart { .. } | PadEnd { .. } | ZFill => mapper.with_same_dtype(),
+            #[cfg(feature = "string_wrap")]
+            Wrap { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-struct")]
             SplitExact { n, .. } => mapper.with_dtype(DataType::Struct(
                 (0..n + 1)
@@ -191,6 +253,8 @@ impl StringFunction {
             ContainsMany { .. } => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "find_many")]
             ReplaceMany { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "find_many")]
+            ExtractMany { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
         }
     }
 }
@@ -203,6 +267,8 @@ impl Display for StringFunction {
             Contains { .. } => "contains",
             CountMatches(_) => "count_matches",
             EndsWith { .. } => "ends_with",
+            #[cfg(feature = "regex")]
+            EscapeRegex => "escape_regex",
             Extract(_) => "extract",
             #[cfg(feature = "concat_str")]
             ConcatHorizontal { .. } => "concat_horizontal",
@@ -224,6 +290,7 @@ impl Display for StringFunction {
             JsonPathMatch => "json_path_match",
             LenBytes => "len_bytes",
             Lowercase => "lowercase",
+            Casefold => "casefold",
             LenChars => "len_chars",
             #[cfg(feature = "string_pad")]
             PadEnd { .. } => "pad_end",
@@ -231,8 +298,16 @@ impl Display for StringFunction {
             PadStart { .. } => "pad_start",
             #[cfg(feature = "regex")]
             Replace { .. } => "replace",
+            #[cfg(feature = "string_normalize")]
+            Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            Hamming => "hamming",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -241,6 +316,14 @@ impl Display for StringFunction {
             Base64Encode => "base64_encode",
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => "base64_decode",
+            #[cfg(feature = "string_encoding")]
+            Base32Encode => "base32_encode",
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(_) => "base32_decode",
+            #[cfg(feature = "string_encoding")]
+            PercentEncode => "percent_encode",
+            #[cfg(feature = "binary_encoding")]
+            PercentDecode(_) => "percent_decode",
             Slice => "slice",
             StartsWith { .. } => "starts_with",
             StripChars => "strip_chars",
@@ -267,17 +350,23 @@ impl Display for StringFunction {
                     "split"
                 }
             },
-            #[cfg(feature = "nightly")]
+            #[cfg(feature = "string_to_titlecase")]
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => "to_decimal",
+            #[cfg(all(feature = "timezones", feature = "dtype-duration"))]
+            ToUtcOffset { .. } => "to_utc_offset",
             Uppercase => "uppercase",
+            #[cfg(feature = "string_wrap")]
+            Wrap { .. } => "wrap",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
             #[cfg(feature = "find_many")]
             ContainsMany { .. } => "contains_many",
             #[cfg(feature = "find_many")]
             ReplaceMany { .. } => "replace_many",
+            #[cfg(feature = "find_many")]
+            ExtractMany { .. } => "extract_many",
         };
         write!(f, "str.{s}")
     }
@@ -293,6 +382,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                 map_as_slice!(strings::count_matches, literal)
             },
             EndsWith { .. } => map_as_slice!(strings::ends_with),
+            #[cfg(feature = "regex")]
+            EscapeRegex => map!(strings::escape_regex),
             StartsWith { .. } => map_as_slice!(strings::starts_with),
             Extract(group_index) => map_as_slice!(strings::extract, group_index),
             ExtractAll => {
@@ -303,21 +394,37 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                 map!(strings::extract_groups, &pat, &dtype)
             },
             #[cfg(feature = "regex")]
-            Find { literal, strict } => map_as_slice!(strings::find, literal, strict),
+            Find {
+                literal,
+                strict,
+                char_index,
+            } => map_as_slice!(strings::find, literal, strict, char_index),
             LenBytes => map!(strings::len_bytes),
             LenChars => map!(strings::len_chars),
             #[cfg(feature = "string_pad")]
-            PadEnd { length, fill_char } => {
-                map!(strings::pad_end, length, fill_char)
+            PadEnd {
+                fill_char,
+                display_width,
+            } => {
+                map_as_slice!(strings::pad_end, fill_char, display_width)
             },
             #[cfg(feature = "string_pad")]
-            PadStart { length, fill_char } => {
-                map!(strings::pad_start, length, fill_char)
+            PadStart {
+                fill_char,
+                display_width,
+            } => {
+                map_as_slice!(strings::pad_start, fill_char, display_width)
             },
             #[cfg(feature = "string_pad")]
             ZFill => {
                 map_as_slice!(strings::zfill)
             },
+            #[cfg(feature = "string_wrap")]
+            Wrap {
+                width,
+                break_long_words,
+                tab_size,
+            } => map!(strings::wrap, width, break_long_words, tab_size),
             #[cfg(feature = "temporal")]
             Strptime(dtype, options) => {
                 map_as_slice!(strings::strptime, dtype.clone(), &options)
@@ -341,11 +448,20 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             } => map_as_slice!(strings::concat_hor, &delimiter, ignore_nulls),
             #[cfg(feature = "regex")]
             Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
+            #[cfg(feature = "string_normalize")]
+            Normalize { form } => map!(strings::normalize, form),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => map_as_slice!(strings::levenshtein),
+            #[cfg(feature = "string_similarity")]
+            Hamming => map_as_slice!(strings::hamming),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
-            #[cfg(feature = "nightly")]
+            Casefold => map!(casefold),
+            #[cfg(feature = "string_to_titlecase")]
             Titlecase => map!(strings::titlecase),
             StripChars => map_as_slice!(strings::strip_chars),
             StripCharsStart => map_as_slice!(strings::strip_chars_start),
@@ -365,14 +481,34 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Base64Encode => map!(strings::base64_encode),
             #[cfg(feature = "binary_encoding")]
             Base64Decode(strict) => map!(strings::base64_decode, strict),
+            #[cfg(feature = "string_encoding")]
+            Base32Encode => map!(strings::base32_encode),
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(strict) => map!(strings::base32_decode, strict),
+            #[cfg(feature = "string_encoding")]
+            PercentEncode => map!(strings::percent_encode),
+            #[cfg(feature = "binary_encoding")]
+            PercentDecode(strict) => map!(strings::percent_decode, strict),
             Explode => map!(strings::explode),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(infer_len) => map!(strings::to_decimal, infer_len),
+            #[cfg(all(feature = "timezones", feature = "dtype-duration"))]
+            ToUtcOffset {
+                format,
+                strict,
+                cache,
+            } => map!(strings::to_utc_offset, &format, strict, cache),
             #[cfg(feature = "extract_jsonpath")]
             JsonDecode {
                 dtype,
                 infer_schema_len,
-            } => map!(strings::json_decode, dtype.clone(), infer_schema_len),
+                strict,
+            } => map!(
+                strings::json_decode,
+                dtype.clone(),
+                infer_schema_len,
+                strict
+            ),
             #[cfg(feature = "extract_jsonpath")]
             JsonPathMatch => map_as_slice!(strings::json_path_match),
             #[cfg(feature = "find_many")]
@@ -387,6 +523,13 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             } => {
                 map_as_slice!(replace_many, ascii_case_insensitive)
             },
+            #[cfg(feature = "find_many")]
+            ExtractMany {
+                ascii_case_insensitive,
+                overlapping,
+            } => {
+                map_as_slice!(extract_many, ascii_case_insensitive, overlapping)
+            },
         }
     }
 }
@@ -413,6 +556,23 @@ fn replace_many(s: &[Series], ascii_case_insensitive: bool) -> PolarsResult<Seri
     .map(|out| out.into_series())
 }
 
+#[cfg(feature = "find_many")]
+fn extract_many(
+    s: &[Series],
+    ascii_case_insensitive: bool,
+    overlapping: bool,
+) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let patterns = s[1].str()?;
+    polars_ops::chunked_array::strings::extract_many(
+        ca,
+        patterns,
+        ascii_case_insensitive,
+        overlapping,
+    )
+    .map(|out| out.into_series())
+}
+
 fn uppercase(s: &Series) -> PolarsResult<Series> {
     let ca = s.str()?;
     Ok(ca.to_uppercase().into_series())
@@ -423,7 +583,12 @@ fn lowercase(s: &Series) -> PolarsResult<Series> {
     Ok(ca.to_lowercase().into_series())
 }
 
-#[cfg(feature = "nightly")]
+fn casefold(s: &Series) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.casefold().into_series())
+}
+
+#[cfg(feature = "string_to_titlecase")]
 pub(super) fn titlecase(s: &Series) -> PolarsResult<Series> {
     let ca = s.str()?;
     Ok(ca.to_titlecase().into_series())
@@ -448,11 +613,20 @@ pub(super) fn contains(s: &[Series], literal: bool, strict: bool) -> PolarsResul
 }
 
 #[cfg(feature = "regex")]
-pub(super) fn find(s: &[Series], literal: bool, strict: bool) -> PolarsResult<Series> {
+pub(super) fn find(
+    s: &[Series],
+    literal: bool,
+    strict: bool,
+    char_index: bool,
+) -> PolarsResult<Series> {
     let ca = s[0].str()?;
     let pat = s[1].str()?;
-    ca.find_chunked(pat, literal, strict)
-        .map(|ok| ok.into_series())
+    let idx = ca.find_chunked(pat, literal, strict)?;
+    if char_index {
+        Ok(ca.byte_to_char_offsets(&idx).into_series())
+    } else {
+        Ok(idx.into_series())
+    }
 }
 
 pub(super) fn ends_with(s: &[Series]) -> PolarsResult<Series> {
@@ -462,6 +636,12 @@ pub(super) fn ends_with(s: &[Series]) -> PolarsResult<Series> {
     Ok(ca.ends_with_chunked(suffix).into_series())
 }
 
+#[cfg(feature = "regex")]
+pub(super) fn escape_regex(s: &Series) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_escape_regex().into_series())
+}
+
 pub(super) fn starts_with(s: &[Series]) -> PolarsResult<Series> {
     let ca = &s[0].str()?.as_binary();
     let prefix = &s[1].str()?.as_binary();
@@ -484,15 +664,38 @@ pub(super) fn extract_groups(s: &Series, pat: &str, dtype: &DataType) -> PolarsR
 }
 
 #[cfg(feature = "string_pad")]
-pub(super) fn pad_start(s: &Series, length: usize, fill_char: char) -> PolarsResult<Series> {
-    let ca = s.str()?;
-    Ok(ca.pad_start(length, fill_char).into_series())
+pub(super) fn pad_start(
+    s: &[Series],
+    fill_char: char,
+    display_width: bool,
+) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let length_s = s[1].strict_cast(&DataType::UInt64)?;
+    let length = length_s.u64()?;
+    Ok(ca
+        .pad_start(length, fill_char, display_width)?
+        .into_series())
 }
 
 #[cfg(feature = "string_pad")]
-pub(super) fn pad_end(s: &Series, length: usize, fill_char: char) -> PolarsResult<Series> {
+pub(super) fn pad_end(s: &[Series], fill_char: char, display_width: bool) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let length_s = s[1].strict_cast(&DataType::UInt64)?;
+    let length = length_s.u64()?;
+    Ok(ca.pad_end(length, fill_char, display_width)?.into_series())
+}
+
+#[cfg(feature = "string_wrap")]
+pub(super) fn wrap(
+    s: &Series,
+    width: usize,
+    break_long_words: bool,
+    tab_size: usize,
+) -> PolarsResult<Series> {
     let ca = s.str()?;
-    Ok(ca.pad_end(length, fill_char).into_series())
+    Ok(ca
+        .str_wrap(width, break_long_words, tab_size)?
+        .into_series())
 }
 
 #[cfg(feature = "string_pad")]
@@ -504,30 +707,50 @@ pub(super) fn zfill(s: &[Series]) -> PolarsResult<Series> {
 }
 
 pub(super) fn strip_chars(s: &[Series]) -> PolarsResult<Series> {
+    polars_ensure!(
+        _ensure_lengths(s),
+        ComputeError: "all series in `str_strip_chars` should have equal or unit length",
+    );
     let ca = s[0].str()?;
     let pat_s = &s[1];
     ca.strip_chars(pat_s).map(|ok| ok.into_series())
 }
 
 pub(super) fn strip_chars_start(s: &[Series]) -> PolarsResult<Series> {
+    polars_ensure!(
+        _ensure_lengths(s),
+        ComputeError: "all series in `str_strip_chars_start` should have equal or unit length",
+    );
     let ca = s[0].str()?;
     let pat_s = &s[1];
     ca.strip_chars_start(pat_s).map(|ok| ok.into_series())
 }
 
 pub(super) fn strip_chars_end(s: &[Series]) -> PolarsResult<Series> {
+    polars_ensure!(
+        _ensure_lengths(s),
+        ComputeError: "all series in `str_strip_chars_end` should have equal or unit length",
+    );
     let ca = s[0].str()?;
     let pat_s = &s[1];
     ca.strip_chars_end(pat_s).map(|ok| ok.into_series())
 }
 
 pub(super) fn strip_prefix(s: &[Series]) -> PolarsResult<Series> {
+    polars_ensure!(
+        _ensure_lengths(s),
+        ComputeError: "all series in `str_strip_prefix` should have equal or unit length",
+    );
     let ca = s[0].str()?;
     let prefix = s[1].str()?;
     Ok(ca.strip_prefix(prefix).into_series())
 }
 
 pub(super) fn strip_suffix(s: &[Series]) -> PolarsResult<Series> {
+    polars_ensure!(
+        _ensure_lengths(s),
+        ComputeError: "all series in `str_strip_suffix` should have equal or unit length",
+    );
     let ca = s[0].str()?;
     let suffix = s[1].str()?;
     Ok(ca.strip_suffix(suffix).into_series())
@@ -653,7 +876,7 @@ fn to_datetime(
     let ambiguous = &s[1].str()?;
     let tz_aware = match &options.format {
         #[cfg(feature = "timezones")]
-        Some(format) => TZ_AWARE_RE.is_match(format),
+        Some(format) => is_tz_aware_format(format),
         _ => false,
     };
     if let (Some(tz), true) = (time_zone, tz_aware) {
@@ -695,6 +918,26 @@ fn to_datetime(
     Ok(out.into_series())
 }
 
+#[cfg(all(feature = "timezones", feature = "dtype-duration"))]
+pub(super) fn to_utc_offset(
+    s: &Series,
+    format: &str,
+    strict: bool,
+    cache: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        is_tz_aware_format(format),
+        InvalidOperation: "`to_utc_offset` needs a format with a time zone offset or name, got '{}'", format
+    );
+    let ca = s.str()?;
+    let out = ca.as_utc_offset(format, cache)?.into_series();
+
+    if strict && ca.null_count() != out.null_count() {
+        handle_casting_failures(s, &out)?;
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "dtype-time")]
 fn to_time(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
     polars_ensure!(
@@ -895,12 +1138,39 @@ pub(super) fn replace(s: &[Series], literal: bool, n: i64) -> PolarsResult<Serie
     .map(|ca| ca.into_series())
 }
 
+#[cfg(feature = "string_normalize")]
+pub(super) fn normalize(s: &Series, form: UnicodeForm) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_normalize(form).into_series())
+}
+
 #[cfg(feature = "string_reverse")]
 pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     let ca = s.str()?;
     Ok(ca.str_reverse().into_series())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    polars_ops::chunked_array::strings::levenshtein(ca, other).map(|ok| ok.into_series())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    polars_ops::chunked_array::strings::hamming(ca, other).map(|ok| ok.into_series())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    polars_ops::chunked_array::strings::jaro_winkler(ca, other).map(|ok| ok.into_series())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(s: &[Series], strict: bool) -> PolarsResult<Series> {
     let ca = s[0].str()?;
@@ -972,6 +1242,26 @@ pub(super) fn base64_decode(s: &Series, strict: bool) -> PolarsResult<Series> {
     s.str()?.base64_decode(strict).map(|ca| ca.into_series())
 }
 
+#[cfg(feature = "string_encoding")]
+pub(super) fn base32_encode(s: &Series) -> PolarsResult<Series> {
+    Ok(s.str()?.base32_encode().into_series())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base32_decode(s: &Series, strict: bool) -> PolarsResult<Series> {
+    s.str()?.base32_decode(strict).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "string_encoding")]
+pub(super) fn percent_encode(s: &Series) -> PolarsResult<Series> {
+    Ok(s.str()?.percent_encode().into_series())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn percent_decode(s: &Series, strict: bool) -> PolarsResult<Series> {
+    s.str()?.percent_decode(strict).map(|ca| ca.into_series())
+}
+
 pub(super) fn explode(s: &Series) -> PolarsResult<Series> {
     let ca = s.str()?;
     ca.explode()
@@ -988,9 +1278,10 @@ pub(super) fn json_decode(
     s: &Series,
     dtype: Option<DataType>,
     infer_schema_len: Option<usize>,
+    strict: bool,
 ) -> PolarsResult<Series> {
     let ca = s.str()?;
-    ca.json_decode(dtype, infer_schema_len)
+    ca.json_decode(dtype, infer_schema_len, strict)
 }
 
 #[cfg(feature = "extract_jsonpath")]
@@ -1,5 +1,6 @@
 use polars_core::utils::slice_offsets;
 
+use super::schema::args_to_supertype;
 use super::*;
 use crate::{map, map_as_slice};
 
@@ -9,12 +10,19 @@ pub enum StructFunction {
     FieldByIndex(i64),
     FieldByName(Arc<str>),
     RenameFields(Arc<[String]>),
+    RenameFieldsByMapping(Arc<[(String, String)]>),
     PrefixFields(Arc<str>),
     SuffixFields(Arc<str>),
     #[cfg(feature = "json")]
     JsonEncode,
     WithFields,
     MultipleFields(Arc<[ColumnName]>),
+    SelectFields(Arc<[ColumnName]>),
+    DropFields(Arc<[ColumnName]>),
+    SumFields,
+    MeanFields,
+    MinFields,
+    MaxFields,
 }
 
 impl StructFunction {
@@ -64,6 +72,19 @@ impl StructFunction {
                         .collect(),
                 ),
             }),
+            RenameFieldsByMapping(mapping) => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(fields) => {
+                    let names = fields.iter().map(|fld| fld.name().as_str());
+                    let names = renamed_fields(names, mapping)?;
+                    let fields = fields
+                        .iter()
+                        .zip(names)
+                        .map(|(fld, name)| Field::new(name, fld.data_type().clone()))
+                        .collect();
+                    Ok(DataType::Struct(fields))
+                },
+                _ => polars_bail!(op = "rename_fields", got = dt, expected = "Struct"),
+            }),
             PrefixFields(prefix) => mapper.try_map_dtype(|dt| match dt {
                 DataType::Struct(fields) => {
                     let fields = fields
@@ -97,11 +118,19 @@ impl StructFunction {
                 let struct_ = &args[0];
 
                 if let DataType::Struct(fields) = struct_.data_type() {
-                    let mut fields = fields.iter().cloned().collect::<PlIndexSet<Field>>();
+                    let mut fields = fields
+                        .iter()
+                        .map(|fld| (fld.name(), fld.data_type()))
+                        .collect::<PlIndexMap<_, _>>();
                     for arg in &args[1..] {
-                        fields.insert(arg.clone());
+                        fields.insert(arg.name(), arg.data_type());
                     }
-                    let dtype = DataType::Struct(fields.into_iter().collect());
+                    let dtype = DataType::Struct(
+                        fields
+                            .into_iter()
+                            .map(|(name, dtype)| Field::new(name, dtype.clone()))
+                            .collect(),
+                    );
                     let mut out = struct_.clone();
                     out.coerce(dtype);
                     Ok(out)
@@ -111,6 +140,31 @@ impl StructFunction {
                 }
             },
             MultipleFields(_) => panic!("should be expanded"),
+            SelectFields(names) => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(fields) => {
+                    let fields = selected_fields(fields, |fld| fld.name().as_str(), names, false)?;
+                    Ok(DataType::Struct(fields.into_iter().cloned().collect()))
+                },
+                _ => polars_bail!(op = "select_fields", got = dt, expected = "Struct"),
+            }),
+            DropFields(names) => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(fields) => {
+                    let fields = selected_fields(fields, |fld| fld.name().as_str(), names, true)?;
+                    Ok(DataType::Struct(fields.into_iter().cloned().collect()))
+                },
+                _ => polars_bail!(op = "drop_fields", got = dt, expected = "Struct"),
+            }),
+            SumFields | MeanFields | MinFields | MaxFields => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(fields) => {
+                    let fields = numeric_fields(fields, |fld| fld.data_type())?;
+                    let dtype = args_to_supertype(&fields)?;
+                    Ok(match self {
+                        MeanFields => DataType::Float64,
+                        _ => dtype,
+                    })
+                },
+                _ => polars_bail!(op = self.to_string(), got = dt, expected = "Struct"),
+            }),
         }
     }
 }
@@ -122,12 +176,19 @@ impl Display for StructFunction {
             FieldByIndex(index) => write!(f, "struct.field_by_index({index})"),
             FieldByName(name) => write!(f, "struct.field_by_name({name})"),
             RenameFields(names) => write!(f, "struct.rename_fields({:?})", names),
+            RenameFieldsByMapping(mapping) => write!(f, "struct.rename_fields({:?})", mapping),
             PrefixFields(_) => write!(f, "name.prefix_fields"),
             SuffixFields(_) => write!(f, "name.suffixFields"),
             #[cfg(feature = "json")]
             JsonEncode => write!(f, "struct.to_json"),
             WithFields => write!(f, "with_fields"),
             MultipleFields(_) => write!(f, "multiple_fields"),
+            SelectFields(names) => write!(f, "struct.select_fields({:?})", names),
+            DropFields(names) => write!(f, "struct.drop_fields({:?})", names),
+            SumFields => write!(f, "struct.sum_fields"),
+            MeanFields => write!(f, "struct.mean_fields"),
+            MinFields => write!(f, "struct.min_fields"),
+            MaxFields => write!(f, "struct.max_fields"),
         }
     }
 }
@@ -139,12 +200,19 @@ impl From<StructFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             FieldByIndex(_) => panic!("should be replaced"),
             FieldByName(name) => map!(get_by_name, name.clone()),
             RenameFields(names) => map!(rename_fields, names.clone()),
+            RenameFieldsByMapping(mapping) => map!(rename_fields_by_mapping, mapping.clone()),
             PrefixFields(prefix) => map!(prefix_fields, prefix.clone()),
             SuffixFields(suffix) => map!(suffix_fields, suffix.clone()),
             #[cfg(feature = "json")]
             JsonEncode => map!(to_json),
             WithFields => map_as_slice!(with_fields),
             MultipleFields(_) => unimplemented!(),
+            SelectFields(names) => map!(select_fields, &names, false),
+            DropFields(names) => map!(select_fields, &names, true),
+            SumFields => map!(aggregate_fields, polars_ops::prelude::sum_horizontal),
+            MeanFields => map!(aggregate_fields, polars_ops::prelude::mean_horizontal),
+            MinFields => map!(aggregate_fields, polars_ops::prelude::min_horizontal),
+            MaxFields => map!(aggregate_fields, polars_ops::prelude::max_horizontal),
         }
     }
 }
@@ -169,6 +237,39 @@ pub(super) fn rename_fields(s: &Series, names: Arc<[String]>) -> PolarsResult<Se
     StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
 }
 
+/// The new names of the fields `names` that are renamed by `mapping`.
+fn renamed_fields<'a>(
+    names: impl ExactSizeIterator<Item = &'a str> + Clone,
+    mapping: &'a [(String, String)],
+) -> PolarsResult<Vec<&'a str>> {
+    for (old, _) in mapping {
+        polars_ensure!(
+            names.clone().any(|name| name == old),
+            StructFieldNotFound: "{}", old
+        );
+    }
+    Ok(names
+        .map(|name| {
+            mapping
+                .iter()
+                .find(|(old, _)| old == name)
+                .map_or(name, |(_, new)| new.as_str())
+        })
+        .collect())
+}
+
+pub(super) fn rename_fields_by_mapping(
+    s: &Series,
+    mapping: Arc<[(String, String)]>,
+) -> PolarsResult<Series> {
+    let ca = s.struct_()?;
+    let names = renamed_fields(ca.fields().iter().map(|s| s.name()), &mapping)?
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>();
+    rename_fields(s, Arc::from(names))
+}
+
 pub(super) fn prefix_fields(s: &Series, prefix: Arc<str>) -> PolarsResult<Series> {
     let ca = s.struct_()?;
     let fields = ca
@@ -199,6 +300,82 @@ pub(super) fn suffix_fields(s: &Series, suffix: Arc<str>) -> PolarsResult<Series
     StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
 }
 
+/// The `fields` that are named in `names`, in that order, or if `drop` the other fields.
+fn selected_fields<'a, T>(
+    fields: &'a [T],
+    name: impl Fn(&T) -> &str,
+    names: &[ColumnName],
+    drop: bool,
+) -> PolarsResult<Vec<&'a T>> {
+    let find = |needle: &str| {
+        fields
+            .iter()
+            .find(|fld| name(fld) == needle)
+            .ok_or_else(|| polars_err!(StructFieldNotFound: "{}", needle))
+    };
+    if !drop {
+        polars_ensure!(
+            !names.is_empty(),
+            InvalidOperation: "cannot select no fields of a struct"
+        );
+        let mut seen = PlHashSet::with_capacity(names.len());
+        for needle in names {
+            polars_ensure!(
+                seen.insert(needle.as_ref()),
+                Duplicate: "field `{}` is selected more than once", needle
+            );
+        }
+        return names.iter().map(|needle| find(needle)).collect();
+    }
+    for needle in names {
+        find(needle)?;
+    }
+    let fields = fields
+        .iter()
+        .filter(|fld| !names.iter().any(|needle| name(fld) == needle.as_ref()))
+        .collect::<Vec<_>>();
+    polars_ensure!(
+        !fields.is_empty(),
+        InvalidOperation: "cannot drop all fields of a struct"
+    );
+    Ok(fields)
+}
+
+pub(super) fn select_fields(s: &Series, names: &[ColumnName], drop: bool) -> PolarsResult<Series> {
+    let ca = s.struct_()?;
+    let fields = selected_fields(ca.fields(), |s| s.name(), names, drop)?
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
+}
+
+/// The numeric `fields` of a struct, which are aggregated by the `struct.*_fields` functions.
+fn numeric_fields<T>(fields: &[T], dtype: impl Fn(&T) -> &DataType) -> PolarsResult<Vec<&T>> {
+    let fields = fields
+        .iter()
+        .filter(|fld| dtype(fld).is_numeric())
+        .collect::<Vec<_>>();
+    polars_ensure!(
+        !fields.is_empty(),
+        InvalidOperation: "cannot aggregate the fields of a struct without numeric fields"
+    );
+    Ok(fields)
+}
+
+pub(super) fn aggregate_fields(
+    s: &Series,
+    aggregate: fn(&[Series]) -> PolarsResult<Option<Series>>,
+) -> PolarsResult<Series> {
+    let ca = s.struct_()?;
+    let fields = numeric_fields(ca.fields(), |s| s.dtype())?
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    let out = aggregate(&fields)?.expect("at least one field");
+    Ok(out.with_name(ca.name()))
+}
+
 #[cfg(feature = "json")]
 pub(super) fn to_json(s: &Series) -> PolarsResult<Series> {
     let ca = s.struct_()?;
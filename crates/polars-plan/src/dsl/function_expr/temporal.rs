@@ -16,9 +16,17 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Century => map!(datetime::century),
             Year => map!(datetime::year),
             IsLeapYear => map!(datetime::is_leap_year),
+            IsMonthStart => map!(datetime::is_month_start),
+            IsMonthEnd => map!(datetime::is_month_end),
+            IsQuarterStart(fiscal_year_start) => {
+                map!(datetime::is_quarter_start, fiscal_year_start)
+            },
+            IsQuarterEnd(fiscal_year_start) => map!(datetime::is_quarter_end, fiscal_year_start),
+            IsYearStart(fiscal_year_start) => map!(datetime::is_year_start, fiscal_year_start),
+            IsYearEnd(fiscal_year_start) => map!(datetime::is_year_end, fiscal_year_start),
             IsoYear => map!(datetime::iso_year),
             Month => map!(datetime::month),
-            Quarter => map!(datetime::quarter),
+            Quarter(fiscal_year_start) => map!(datetime::quarter, fiscal_year_start),
             Week => map!(datetime::week),
             WeekDay => map!(datetime::weekday),
             Duration(tu) => map_as_slice!(datetime::duration, tu),
@@ -33,15 +41,22 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Millisecond => map!(datetime::millisecond),
             Microsecond => map!(datetime::microsecond),
             Nanosecond => map!(datetime::nanosecond),
-            TotalDays => map!(datetime::total_days),
-            TotalHours => map!(datetime::total_hours),
-            TotalMinutes => map!(datetime::total_minutes),
-            TotalSeconds => map!(datetime::total_seconds),
-            TotalMilliseconds => map!(datetime::total_milliseconds),
-            TotalMicroseconds => map!(datetime::total_microseconds),
-            TotalNanoseconds => map!(datetime::total_nanoseconds),
-            ToString(format) => map!(datetime::to_string, &format),
+            TotalDays(fractional) => map!(datetime::total_days, fractional),
+            TotalHours(fractional) => map!(datetime::total_hours, fractional),
+            TotalMinutes(fractional) => map!(datetime::total_minutes, fractional),
+            TotalSeconds(fractional) => map!(datetime::total_seconds, fractional),
+            TotalMilliseconds(fractional) => map!(datetime::total_milliseconds, fractional),
+            TotalMicroseconds(fractional) => map!(datetime::total_microseconds, fractional),
+            TotalNanoseconds(fractional) => map!(datetime::total_nanoseconds, fractional),
+            DurationToString(format, precision) => {
+                map!(datetime::duration_to_string, format, precision)
+            },
+            ToString(format, locale) => map!(datetime::to_string, &format, locale),
             TimeStamp(tu) => map!(datetime::timestamp, tu),
+            Epoch {
+                time_unit,
+                fractional,
+            } => map_as_slice!(datetime::epoch, &time_unit, fractional),
             #[cfg(feature = "timezones")]
             ConvertTimeZone(tz) => map!(datetime::convert_time_zone, &tz),
             WithTimeUnit(tu) => map!(datetime::with_time_unit, tu),
@@ -53,16 +68,18 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             MonthStart => map!(datetime::month_start),
             #[cfg(feature = "date_offset")]
             MonthEnd => map!(datetime::month_end),
+            #[cfg(feature = "date_offset")]
+            QuarterStart(fiscal_year_start) => map!(datetime::quarter_start, fiscal_year_start),
+            #[cfg(feature = "date_offset")]
+            QuarterEnd(fiscal_year_start) => map!(datetime::quarter_end, fiscal_year_start),
             #[cfg(feature = "timezones")]
             BaseUtcOffset => map!(datetime::base_utc_offset),
             #[cfg(feature = "timezones")]
             DSTOffset => map!(datetime::dst_offset),
-            Round(offset) => map_as_slice!(datetime::round, &offset),
+            Round(offset, week_start) => map_as_slice!(datetime::round, &offset, week_start),
             #[cfg(feature = "timezones")]
-            ReplaceTimeZone(tz, non_existent) => {
-                map_as_slice!(dispatch::replace_time_zone, tz.as_deref(), non_existent)
-            },
-            Combine(tu) => map_as_slice!(temporal::combine, tu),
+            ReplaceTimeZone(tz) => map_as_slice!(dispatch::replace_time_zone, tz.as_deref()),
+            Combine(tu, tz) => map_as_slice!(temporal::combine, tu, tz.as_deref()),
             DatetimeFunction {
                 time_unit,
                 time_zone,
@@ -186,22 +203,27 @@ pub(super) fn datetime(
 }
 
 #[cfg(feature = "date_offset")]
-fn apply_offsets_to_datetime(
+fn apply_offsets_to_datetime<T, F>(
     datetime: &Logical<DatetimeType, Int64Type>,
-    offsets: &StringChunked,
+    offsets: &ChunkedArray<T>,
+    to_duration: F,
     offset_fn: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
     time_zone: Option<&Tz>,
-) -> PolarsResult<Int64Chunked> {
+) -> PolarsResult<Int64Chunked>
+where
+    T: PolarsDataType,
+    F: for<'a> Fn(T::Physical<'a>) -> Duration,
+{
     match (datetime.len(), offsets.len()) {
         (1, _) => match datetime.0.get(0) {
             Some(dt) => offsets.try_apply_nonnull_values_generic(|offset| {
-                offset_fn(&Duration::parse(offset), dt, time_zone)
+                offset_fn(&to_duration(offset), dt, time_zone)
             }),
             _ => Ok(Int64Chunked::full_null(datetime.0.name(), offsets.len())),
         },
         (_, 1) => match offsets.get(0) {
             Some(offset) => {
-                let offset = &Duration::parse(offset);
+                let offset = &to_duration(offset);
                 datetime
                     .0
                     .try_apply_nonnull_values_generic(|v| offset_fn(offset, v, time_zone))
@@ -211,7 +233,7 @@ fn apply_offsets_to_datetime(
         _ => try_binary_elementwise(datetime, offsets, |timestamp_opt, offset_opt| {
             match (timestamp_opt, offset_opt) {
                 (Some(timestamp), Some(offset)) => {
-                    offset_fn(&Duration::parse(offset), timestamp, time_zone).map(Some)
+                    offset_fn(&to_duration(offset), timestamp, time_zone).map(Some)
                 },
                 _ => Ok(None),
             }
@@ -219,10 +241,68 @@ fn apply_offsets_to_datetime(
     }
 }
 
+/// The offsets are either strings in the duration string language, or durations.
+#[cfg(feature = "date_offset")]
+enum DateOffsets<'a> {
+    String(&'a StringChunked),
+    #[cfg(feature = "dtype-duration")]
+    Duration(Int64Chunked),
+}
+
+#[cfg(feature = "date_offset")]
+impl<'a> DateOffsets<'a> {
+    fn new(offsets: &'a Series) -> PolarsResult<Self> {
+        match offsets.dtype() {
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => {
+                let offsets = offsets.cast(&DataType::Duration(TimeUnit::Nanoseconds))?;
+                Ok(Self::Duration(offsets.duration()?.0.clone()))
+            },
+            DataType::String => Ok(Self::String(offsets.str()?)),
+            dt => polars_bail!(
+                InvalidOperation: "`offset_by` expects offsets of type String or Duration, got {}", dt
+            ),
+        }
+    }
+
+    fn apply(
+        &self,
+        datetime: &Logical<DatetimeType, Int64Type>,
+        offset_fn: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
+        time_zone: Option<&Tz>,
+    ) -> PolarsResult<Int64Chunked> {
+        match self {
+            Self::String(offsets) => {
+                apply_offsets_to_datetime(datetime, *offsets, Duration::parse, offset_fn, time_zone)
+            },
+            #[cfg(feature = "dtype-duration")]
+            Self::Duration(offsets) => apply_offsets_to_datetime(
+                datetime,
+                offsets,
+                Duration::from_nsecs,
+                offset_fn,
+                time_zone,
+            ),
+        }
+    }
+
+    /// The offset that is added to every value, if there is a single valid one.
+    fn constant(&self) -> Option<Duration> {
+        match self {
+            Self::String(offsets) if offsets.len() == 1 => offsets.get(0).map(Duration::parse),
+            #[cfg(feature = "dtype-duration")]
+            Self::Duration(offsets) if offsets.len() == 1 => {
+                offsets.get(0).map(Duration::from_nsecs)
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "date_offset")]
-pub(super) fn date_offset(s: &[Series]) -> PolarsResult<Series> {
+pub(super) fn date_offset(s: &[Series], end_of_month: bool) -> PolarsResult<Series> {
     let ts = &s[0];
-    let offsets = &s[1].str()?;
+    let offsets = DateOffsets::new(&s[1])?;
 
     let preserve_sortedness: bool;
     let out = match ts.dtype() {
@@ -231,12 +311,15 @@ pub(super) fn date_offset(s: &[Series]) -> PolarsResult<Series> {
                 .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
                 .unwrap();
             let datetime = ts.datetime().unwrap();
-            let out = apply_offsets_to_datetime(datetime, offsets, Duration::add_ms, None)?;
-            // sortedness is only guaranteed to be preserved if a constant offset is being added to every datetime
-            preserve_sortedness = match offsets.len() {
-                1 => offsets.get(0).is_some(),
-                _ => false,
+            let offset_fn = if end_of_month {
+                Duration::add_ms_end_of_month
+            } else {
+                Duration::add_ms
             };
+            let out = offsets.apply(datetime, offset_fn, None)?;
+            // sortedness is only guaranteed to be preserved if a constant offset is being added to every datetime
+            // and month ends are not moved.
+            preserve_sortedness = !end_of_month && offsets.constant().is_some();
             out.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
                 .unwrap()
                 .cast(&DataType::Date)
@@ -244,36 +327,33 @@ pub(super) fn date_offset(s: &[Series]) -> PolarsResult<Series> {
         DataType::Datetime(tu, tz) => {
             let datetime = ts.datetime().unwrap();
 
-            let offset_fn = match tu {
-                TimeUnit::Nanoseconds => Duration::add_ns,
-                TimeUnit::Microseconds => Duration::add_us,
-                TimeUnit::Milliseconds => Duration::add_ms,
+            let offset_fn = match (tu, end_of_month) {
+                (TimeUnit::Nanoseconds, false) => Duration::add_ns,
+                (TimeUnit::Microseconds, false) => Duration::add_us,
+                (TimeUnit::Milliseconds, false) => Duration::add_ms,
+                (TimeUnit::Nanoseconds, true) => Duration::add_ns_end_of_month,
+                (TimeUnit::Microseconds, true) => Duration::add_us_end_of_month,
+                (TimeUnit::Milliseconds, true) => Duration::add_ms_end_of_month,
             };
 
             let out = match tz {
                 #[cfg(feature = "timezones")]
-                Some(ref tz) => apply_offsets_to_datetime(
-                    datetime,
-                    offsets,
-                    offset_fn,
-                    tz.parse::<Tz>().ok().as_ref(),
-                )?,
-                _ => apply_offsets_to_datetime(datetime, offsets, offset_fn, None)?,
+                Some(ref tz) => {
+                    offsets.apply(datetime, offset_fn, tz.parse::<Tz>().ok().as_ref())?
+                },
+                _ => offsets.apply(datetime, offset_fn, None)?,
             };
             // Sortedness may not be preserved when crossing daylight savings time boundaries
-            // for calendar-aware durations.
+            // for calendar-aware durations, or when month ends are moved.
             // Constant durations (e.g. 2 hours) always preserve sortedness.
-            preserve_sortedness = match offsets.len() {
-                1 => match offsets.get(0) {
-                    Some(offset) => {
-                        let offset = Duration::parse(offset);
-                        tz.is_none()
+            preserve_sortedness = match offsets.constant() {
+                Some(offset) => {
+                    (!end_of_month || offset.months() == 0)
+                        && (tz.is_none()
                             || tz.as_deref() == Some("UTC")
-                            || offset.is_constant_duration(tz.as_deref())
-                    },
-                    None => false,
+                            || offset.is_constant_duration(tz.as_deref()))
                 },
-                _ => false,
+                None => false,
             };
             out.cast(&DataType::Datetime(*tu, tz.clone()))
         },
@@ -294,19 +374,21 @@ pub(super) fn date_offset(s: &[Series]) -> PolarsResult<Series> {
     }
 }
 
-pub(super) fn combine(s: &[Series], tu: TimeUnit) -> PolarsResult<Series> {
+pub(super) fn combine(s: &[Series], tu: TimeUnit, time_zone: Option<&str>) -> PolarsResult<Series> {
     let date = &s[0];
     let time = &s[1];
 
     let tz = match date.dtype() {
         DataType::Date => None,
-        DataType::Datetime(_, tz) => tz.as_ref(),
+        DataType::Datetime(_, tz) => tz.as_deref(),
         _dtype => {
             polars_bail!(ComputeError: format!("expected Date or Datetime, got {}", _dtype))
         },
     };
+    let tz = time_zone.or(tz);
 
-    let date = date.cast(&DataType::Date)?;
+    // The time replaces the time component of the local date of a time zone aware Datetime.
+    let date = datetime::date(date)?;
     let datetime = date.cast(&DataType::Datetime(tu, None)).unwrap();
 
     let duration = time.cast(&DataType::Duration(tu))?;
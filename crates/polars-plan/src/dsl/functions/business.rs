@@ -1,20 +1,13 @@
 use super::*;
 
+/// Count the business days of `calendar` between `start` and `end`, not including `end`.
 #[cfg(feature = "dtype-date")]
-pub fn business_day_count(
-    start: Expr,
-    end: Expr,
-    week_mask: [bool; 7],
-    holidays: Vec<i32>,
-) -> Expr {
+pub fn business_day_count(start: Expr, end: Expr, calendar: HolidayCalendar) -> Expr {
     let input = vec![start, end];
 
     Expr::Function {
         input,
-        function: FunctionExpr::Business(BusinessFunction::BusinessDayCount {
-            week_mask,
-            holidays,
-        }),
+        function: FunctionExpr::Business(BusinessFunction::BusinessDayCount { calendar }),
         options: FunctionOptions {
             allow_rename: true,
             ..Default::default()
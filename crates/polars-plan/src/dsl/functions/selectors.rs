@@ -46,6 +46,18 @@ pub fn cols<I: IntoVec<String>>(names: I) -> Expr {
     Expr::Columns(names)
 }
 
+/// Select fields of the struct in `struct_().with_fields`, by name or with the
+/// `"*"` wildcard.
+#[cfg(feature = "dtype-struct")]
+pub fn field<S: AsRef<str>>(names: &[S]) -> Expr {
+    Expr::Field(
+        names
+            .iter()
+            .map(|name| ColumnName::from(name.as_ref()))
+            .collect(),
+    )
+}
+
 /// Select multiple columns by dtype.
 pub fn dtype_col(dtype: &DataType) -> Expr {
     Expr::DtypeColumn(vec![dtype.clone()])
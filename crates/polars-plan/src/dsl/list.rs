@@ -127,6 +127,27 @@ impl ListNameSpace {
             .map_private(FunctionExpr::ListExpr(ListFunction::Sort(options)))
     }
 
+    /// Get the `k` largest elements of every sublist.
+    ///
+    /// This has time complexity `O(n + k log(n))` per sublist.
+    #[cfg(feature = "top_k")]
+    pub fn top_k(self, k: Expr, sort_options: SortOptions) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ListExpr(ListFunction::TopK(sort_options)),
+            &[k],
+            false,
+            false,
+        )
+    }
+
+    /// Get the `k` smallest elements of every sublist.
+    ///
+    /// This has time complexity `O(n + k log(n))` per sublist.
+    #[cfg(feature = "top_k")]
+    pub fn bottom_k(self, k: Expr, sort_options: SortOptions) -> Expr {
+        self.top_k(k, sort_options.with_order_reversed())
+    }
+
     /// Reverse every sublist
     pub fn reverse(self) -> Expr {
         self.0
@@ -150,6 +171,18 @@ impl ListNameSpace {
             .map_private(FunctionExpr::ListExpr(ListFunction::NUnique))
     }
 
+    /// Remove consecutive duplicate values in every sublist.
+    pub fn dedupe(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::Dedupe))
+    }
+
+    /// Split every sublist into sublists of `size` values, the last of which may be shorter.
+    pub fn chunk(self, size: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::Chunk(size)))
+    }
+
     /// Get items in every sublist by index.
     pub fn get(self, index: Expr, null_on_oob: bool) -> Expr {
         self.0.map_many_private(
@@ -185,6 +218,32 @@ impl ListNameSpace {
         )
     }
 
+    /// Set the elements of every sublist at `indices` to `values`.
+    ///
+    /// Out-of-bounds indices raise an error, unless `ignore_oob` is set.
+    pub fn scatter(self, indices: Expr, values: Expr, ignore_oob: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ListExpr(ListFunction::Scatter(ignore_oob)),
+            &[indices, values],
+            false,
+            false,
+        )
+    }
+
+    /// Take the elements of every sublist where the sublist of `mask` is `true` and the
+    /// elements of `other` elsewhere.
+    ///
+    /// The sublists of a row must have the same length. A mismatch raises an error, unless
+    /// `null_on_mismatch` is set, in which case the sublist is null.
+    pub fn zip_with(self, mask: Expr, other: Expr, null_on_mismatch: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ListExpr(ListFunction::ZipWith(null_on_mismatch)),
+            &[mask, other],
+            false,
+            false,
+        )
+    }
+
     /// Get first item of every sublist.
     pub fn first(self) -> Expr {
         self.get(lit(0i64), true)
@@ -196,11 +255,17 @@ impl ListNameSpace {
     }
 
     /// Join all string items in a sublist and place a separator between them.
+    ///
+    /// Null items are written as `null_replacement` if it is set. Otherwise they are skipped
+    /// if `ignore_nulls` is set, or make the joined string null if it isn't.
     /// # Error
     /// This errors if inner type of list `!= DataType::String`.
-    pub fn join(self, separator: Expr, ignore_nulls: bool) -> Expr {
+    pub fn join(self, separator: Expr, ignore_nulls: bool, null_replacement: Option<&str>) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::ListExpr(ListFunction::Join(ignore_nulls)),
+            FunctionExpr::ListExpr(ListFunction::Join {
+                ignore_nulls,
+                null_replacement: null_replacement.map(Arc::from),
+            }),
             &[separator],
             false,
             false,
@@ -229,6 +294,50 @@ impl ListNameSpace {
             }))
     }
 
+    /// Apply a rolling sum over the elements of every sublist.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_sum(self, window_size: usize, min_periods: usize, center: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::RollingSum {
+                window_size,
+                min_periods,
+                center,
+            }))
+    }
+
+    /// Apply a rolling mean over the elements of every sublist.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_mean(self, window_size: usize, min_periods: usize, center: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::RollingMean {
+                window_size,
+                min_periods,
+                center,
+            }))
+    }
+
+    /// Apply a rolling minimum over the elements of every sublist.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_min(self, window_size: usize, min_periods: usize, center: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::RollingMin {
+                window_size,
+                min_periods,
+                center,
+            }))
+    }
+
+    /// Apply a rolling maximum over the elements of every sublist.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_max(self, window_size: usize, min_periods: usize, center: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::RollingMax {
+                window_size,
+                min_periods,
+                center,
+            }))
+    }
+
     /// Shift every sublist.
     pub fn shift(self, periods: Expr) -> Expr {
         self.0.map_many_private(
@@ -249,6 +358,21 @@ impl ListNameSpace {
         )
     }
 
+    /// Pad every sublist at the end with `fill_value` until it has `length` elements.
+    pub fn pad_end(self, length: Expr, fill_value: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ListExpr(ListFunction::PadEnd),
+            &[length, fill_value],
+            false,
+            false,
+        )
+    }
+
+    /// Truncate every sublist to at most `length` elements.
+    pub fn truncate(self, length: Expr) -> Expr {
+        self.head(length)
+    }
+
     /// Get the head of every sublist
     pub fn head(self, n: Expr) -> Expr {
         self.slice(lit(0), n)
@@ -261,9 +385,46 @@ impl ListNameSpace {
 
     #[cfg(feature = "dtype-array")]
     /// Convert a List column into an Array column with the same inner data type.
+    ///
+    /// Sublists with a length other than `width` raise an error.
     pub fn to_array(self, width: usize) -> Expr {
+        self.to_array_with_options(width, false)
+    }
+
+    #[cfg(feature = "dtype-array")]
+    /// Convert a List column into an Array column with the same inner data type.
+    ///
+    /// Sublists with a length other than `width` raise an error, or become null if
+    /// `null_on_mismatch` is set.
+    pub fn to_array_with_options(self, width: usize, null_on_mismatch: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::ToArray {
+                width,
+                null_on_mismatch,
+            }))
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    /// Get the field `name` of every struct in a list of structs.
+    pub fn struct_field(self, name: &str) -> Expr {
         self.0
-            .map_private(FunctionExpr::ListExpr(ListFunction::ToArray(width)))
+            .map_private(FunctionExpr::ListExpr(ListFunction::StructField(
+                Arc::from(name),
+            )))
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    /// Count the occurrences of the unique values of every sublist.
+    ///
+    /// Every sublist becomes a list of structs with fields `value` and `count`, or
+    /// `proportion` if `normalize` is set. If `sort` is set, the structs are sorted by count
+    /// in descending order.
+    pub fn value_counts(self, sort: bool, normalize: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::ValueCounts {
+                sort,
+                normalize,
+            }))
     }
 
     #[cfg(feature = "list_to_struct")]
@@ -357,6 +518,24 @@ impl ListNameSpace {
             })
     }
 
+    /// Get the index of the first occurrence of the value produced by `value` in every
+    /// sub-list, or null if it does not occur.
+    pub fn index_of<E: Into<Expr>>(self, value: E) -> Expr {
+        let other = value.into();
+
+        self.0
+            .map_many_private(
+                FunctionExpr::ListExpr(ListFunction::IndexOf),
+                &[other],
+                false,
+                false,
+            )
+            .with_function_options(|mut options| {
+                options.input_wildcard_expansion = true;
+                options
+            })
+    }
+
     #[cfg(feature = "list_sets")]
     fn set_operation(self, other: Expr, set_operation: SetOperation) -> Expr {
         self.0
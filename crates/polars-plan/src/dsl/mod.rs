@@ -10,6 +10,8 @@ use std::any::Any;
 pub use cat::*;
 #[cfg(feature = "rolling_window_by")]
 pub(crate) use polars_time::prelude::*;
+#[cfg(feature = "business")]
+pub use polars_time::HolidayCalendar;
 
 mod arithmetic;
 mod arity;
@@ -1087,6 +1089,56 @@ impl Expr {
         binary_expr(self, Operator::LogicalAnd, expr.into())
     }
 
+    /// Shift the bits of an integer column to the left by `n` bits.
+    #[cfg(feature = "bitwise")]
+    pub fn shift_left<E: Into<Expr>>(self, n: E) -> Self {
+        self.map_many_private(
+            FunctionExpr::Bitwise(BitwiseFunction::ShiftLeft),
+            &[n.into()],
+            false,
+            false,
+        )
+    }
+
+    /// Shift the bits of an integer column to the right by `n` bits. Signed integers are
+    /// shifted arithmetically.
+    #[cfg(feature = "bitwise")]
+    pub fn shift_right<E: Into<Expr>>(self, n: E) -> Self {
+        self.map_many_private(
+            FunctionExpr::Bitwise(BitwiseFunction::ShiftRight),
+            &[n.into()],
+            false,
+            false,
+        )
+    }
+
+    /// Reduce the non-null values of an integer or boolean column/group with a bitwise "and".
+    #[cfg(feature = "bitwise")]
+    pub fn bitwise_and(self) -> Self {
+        self.bitwise_reduce(BitwiseFunction::And)
+    }
+
+    /// Reduce the non-null values of an integer or boolean column/group with a bitwise "or".
+    #[cfg(feature = "bitwise")]
+    pub fn bitwise_or(self) -> Self {
+        self.bitwise_reduce(BitwiseFunction::Or)
+    }
+
+    /// Reduce the non-null values of an integer or boolean column/group with a bitwise "xor".
+    #[cfg(feature = "bitwise")]
+    pub fn bitwise_xor(self) -> Self {
+        self.bitwise_reduce(BitwiseFunction::Xor)
+    }
+
+    #[cfg(feature = "bitwise")]
+    fn bitwise_reduce(self, function: BitwiseFunction) -> Self {
+        self.apply_private(FunctionExpr::Bitwise(function))
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
     /// Filter a single column.
     ///
     /// Should be used in aggregation context. If you want to filter on a
@@ -1780,6 +1832,13 @@ impl Expr {
         self.map_private(FunctionExpr::Hash(k0, k1, k2, k3))
     }
 
+    #[cfg(feature = "hash_stable")]
+    /// Compute the XXH64 hash of every element with the given `seed`. Unlike [`Expr::hash`],
+    /// the result is stable across versions and platforms.
+    pub fn hash_stable(self, seed: u64) -> Expr {
+        self.map_private(FunctionExpr::HashStable(seed))
+    }
+
     pub fn to_physical(self) -> Expr {
         self.map_private(FunctionExpr::ToPhysical)
     }
@@ -1793,6 +1852,13 @@ impl Expr {
         self.map_private(FunctionExpr::Reinterpret(signed))
     }
 
+    /// Reinterpret every number as its bytes, in little or big endian byte order. This is the
+    /// inverse of [`BinaryNameSpace::reinterpret`](binary::BinaryNameSpace::reinterpret).
+    #[cfg(feature = "reinterpret")]
+    pub fn to_binary(self, is_little_endian: bool) -> Expr {
+        self.map_private(FunctionExpr::ToBinary(is_little_endian))
+    }
+
     pub fn extend_constant(self, value: Expr, n: Expr) -> Expr {
         self.apply_many_private(FunctionExpr::ExtendConstant, &[value, n], false, false)
     }
@@ -72,6 +72,30 @@ impl StringNameSpace {
         )
     }
 
+    /// Uses aho-corasick to extract many patterns.
+    /// # Arguments
+    /// - `patterns`: an expression that evaluates to an String column
+    /// - `ascii_case_insensitive`: Enable ASCII-aware case insensitive matching.
+    ///  When this option is enabled, searching will be performed without respect to case for ASCII letters (a-z and A-Z) only.
+    /// - `overlapping`: Whether matches may overlap.
+    #[cfg(feature = "find_many")]
+    pub fn extract_many(
+        self,
+        patterns: Expr,
+        ascii_case_insensitive: bool,
+        overlapping: bool,
+    ) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::ExtractMany {
+                ascii_case_insensitive,
+                overlapping,
+            }),
+            &[patterns],
+            false,
+            false,
+        )
+    }
+
     /// Check if a string value ends with the `sub` string.
     pub fn ends_with(self, sub: Expr) -> Expr {
         self.0.map_many_private(
@@ -82,6 +106,14 @@ impl StringNameSpace {
         )
     }
 
+    /// Escape all regular expression meta characters, so that each string matches itself
+    /// literally when used as a pattern.
+    #[cfg(feature = "regex")]
+    pub fn escape_regex(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::EscapeRegex))
+    }
+
     /// Check if a string value starts with the `sub` string.
     pub fn starts_with(self, sub: Expr) -> Expr {
         self.0.map_many_private(
@@ -98,6 +130,17 @@ impl StringNameSpace {
             .map_private(FunctionExpr::StringExpr(StringFunction::HexEncode))
     }
 
+    /// Digest the UTF-8 bytes of every string with the hash function `algorithm`, as a
+    /// lowercase hex string if `as_hex` is set and as binary otherwise.
+    #[cfg(feature = "hash_digest")]
+    pub fn digest(self, algorithm: DigestAlgorithm, as_hex: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Digest {
+                algorithm,
+                as_hex,
+            }))
+    }
+
     #[cfg(feature = "binary_encoding")]
     pub fn hex_decode(self, strict: bool) -> Expr {
         self.0
@@ -118,6 +161,35 @@ impl StringNameSpace {
             )))
     }
 
+    #[cfg(feature = "string_encoding")]
+    pub fn base32_encode(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Base32Encode))
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    pub fn base32_decode(self, strict: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Base32Decode(
+                strict,
+            )))
+    }
+
+    /// Percent-encode all characters except the unreserved characters of RFC 3986.
+    #[cfg(feature = "string_encoding")]
+    pub fn percent_encode(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::PercentEncode))
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    pub fn percent_decode(self, strict: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::PercentDecode(
+                strict,
+            )))
+    }
+
     /// Extract a regex pattern from the a string value. If `group_index` is out of bounds, null is returned.
     pub fn extract(self, pat: Expr, group_index: usize) -> Expr {
         self.0.map_many_private(
@@ -165,22 +237,56 @@ impl StringNameSpace {
     ///
     /// Padding is done using the specified `fill_char`.
     /// Strings with length equal to or greater than the given length are
-    /// returned as-is.
+    /// returned as-is. The length is counted in chars, or in display width
+    /// if `display_width` is set.
     #[cfg(feature = "string_pad")]
-    pub fn pad_start(self, length: usize, fill_char: char) -> Expr {
-        self.0
-            .map_private(StringFunction::PadStart { length, fill_char }.into())
+    pub fn pad_start(self, length: Expr, fill_char: char, display_width: bool) -> Expr {
+        self.0.map_many_private(
+            StringFunction::PadStart {
+                fill_char,
+                display_width,
+            }
+            .into(),
+            &[length],
+            false,
+            false,
+        )
     }
 
     /// Pad the end of the string until it reaches the given length.
     ///
     /// Padding is done using the specified `fill_char`.
     /// Strings with length equal to or greater than the given length are
-    /// returned as-is.
+    /// returned as-is. The length is counted in chars, or in display width
+    /// if `display_width` is set.
     #[cfg(feature = "string_pad")]
-    pub fn pad_end(self, length: usize, fill_char: char) -> Expr {
-        self.0
-            .map_private(StringFunction::PadEnd { length, fill_char }.into())
+    pub fn pad_end(self, length: Expr, fill_char: char, display_width: bool) -> Expr {
+        self.0.map_many_private(
+            StringFunction::PadEnd {
+                fill_char,
+                display_width,
+            }
+            .into(),
+            &[length],
+            false,
+            false,
+        )
+    }
+
+    /// Wrap each string at word boundaries into lines of at most `width` characters.
+    ///
+    /// Words longer than `width` are broken if `break_long_words` is set. Tabs are
+    /// expanded to the next multiple of `tab_size` columns before wrapping.
+    #[cfg(feature = "string_wrap")]
+    pub fn wrap(self, width: usize, break_long_words: bool, tab_size: usize) -> Expr {
+        self.0.map_private(
+            StringFunction::Wrap {
+                width,
+                break_long_words,
+                tab_size,
+            }
+            .into(),
+        )
     }
 
     /// Pad the start of the string with zeros until it reaches the given length.
@@ -196,12 +302,15 @@ impl StringNameSpace {
     }
 
     /// Find the index of a literal substring within another string value.
+    ///
+    /// The index is a byte offset, or a char offset if `char_index` is set.
     #[cfg(feature = "regex")]
-    pub fn find_literal(self, pat: Expr) -> Expr {
+    pub fn find_literal(self, pat: Expr, char_index: bool) -> Expr {
         self.0.map_many_private(
             FunctionExpr::StringExpr(StringFunction::Find {
                 literal: true,
                 strict: false,
+                char_index,
             }),
             &[pat],
             false,
@@ -210,12 +319,15 @@ impl StringNameSpace {
     }
 
     /// Find the index of a substring defined by a regular expressons within another string value.
+    ///
+    /// The index is a byte offset, or a char offset if `char_index` is set.
     #[cfg(feature = "regex")]
-    pub fn find(self, pat: Expr, strict: bool) -> Expr {
+    pub fn find(self, pat: Expr, strict: bool, char_index: bool) -> Expr {
         self.0.map_many_private(
             FunctionExpr::StringExpr(StringFunction::Find {
                 literal: false,
                 strict,
+                char_index,
             }),
             &[pat],
             false,
@@ -284,9 +396,29 @@ impl StringNameSpace {
             (None, None) => TimeUnit::Microseconds,
         };
 
+        // A time zone aware format is parsed into UTC.
+        #[cfg(feature = "timezones")]
+        let time_zone = match (&options.format, time_zone) {
+            (Some(format), None) if is_tz_aware_format(format) => Some("UTC".into()),
+            (_, time_zone) => time_zone,
+        };
+
         self.strptime(DataType::Datetime(time_unit, time_zone), options, ambiguous)
     }
 
+    /// Parse the offsets from UTC of strings with a time zone offset (`%z`) or name (`%Z`),
+    /// such as the original offsets of datetimes that [`StringNameSpace::to_datetime`]
+    /// parses into UTC.
+    #[cfg(all(feature = "timezones", feature = "dtype-duration"))]
+    pub fn to_utc_offset(self, format: String, strict: bool, cache: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::ToUtcOffset {
+                format,
+                strict,
+                cache,
+            }))
+    }
+
     /// Convert a String column into a Time column.
     #[cfg(feature = "dtype-time")]
     pub fn to_time(self, options: StrptimeOptions) -> Expr {
@@ -401,6 +533,34 @@ impl StringNameSpace {
         )
     }
 
+    #[cfg(feature = "string_normalize")]
+    /// Normalize each string to the given Unicode normalization form.
+    pub fn normalize(self, form: UnicodeForm) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Normalize { form }))
+    }
+
+    /// Compute the Levenshtein distance between each string and the `other` string.
+    #[cfg(feature = "string_similarity")]
+    pub fn levenshtein(self, other: Expr) -> Expr {
+        self.0
+            .map_many_private(StringFunction::Levenshtein.into(), &[other], false, false)
+    }
+
+    /// Compute the Hamming distance between each string and the `other` string.
+    #[cfg(feature = "string_similarity")]
+    pub fn hamming(self, other: Expr) -> Expr {
+        self.0
+            .map_many_private(StringFunction::Hamming.into(), &[other], false, false)
+    }
+
+    /// Compute the Jaro-Winkler similarity between each string and the `other` string.
+    #[cfg(feature = "string_similarity")]
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0
+            .map_many_private(StringFunction::JaroWinkler.into(), &[other], false, false)
+    }
+
     #[cfg(feature = "string_reverse")]
     /// Reverse each string
     pub fn reverse(self) -> Expr {
@@ -474,8 +634,38 @@ impl StringNameSpace {
             .map_private(FunctionExpr::StringExpr(StringFunction::Uppercase))
     }
 
+    /// Fold the case of all characters, for caseless comparison.
+    ///
+    /// Unlike [`to_lowercase`](Self::to_lowercase), this maps all characters that only
+    /// differ in case to the same characters, e.g. both "ß" and "SS" fold to "ss".
+    pub fn casefold(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Casefold))
+    }
+
+    /// Check if a string value is equal to `other`, ignoring case.
+    pub fn caseless_eq(self, other: Expr) -> Expr {
+        self.casefold().eq(other.str().casefold())
+    }
+
+    /// Check if a string value contains the literal substring `pat`, ignoring case.
+    #[cfg(feature = "regex")]
+    pub fn caseless_contains(self, pat: Expr) -> Expr {
+        self.casefold().str().contains_literal(pat.str().casefold())
+    }
+
+    /// Check if a string value starts with `prefix`, ignoring case.
+    pub fn caseless_starts_with(self, prefix: Expr) -> Expr {
+        self.casefold().str().starts_with(prefix.str().casefold())
+    }
+
+    /// Check if a string value ends with `suffix`, ignoring case.
+    pub fn caseless_ends_with(self, suffix: Expr) -> Expr {
+        self.casefold().str().ends_with(suffix.str().casefold())
+    }
+
     /// Convert all characters to titlecase.
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "string_to_titlecase")]
     pub fn to_titlecase(self) -> Expr {
         self.0
             .map_private(FunctionExpr::StringExpr(StringFunction::Titlecase))
@@ -552,12 +742,23 @@ impl StringNameSpace {
             .apply_private(FunctionExpr::StringExpr(StringFunction::Explode))
     }
 
+    /// Parse the string values as JSON.
+    ///
+    /// If `dtype` is not given, it is inferred from the first `infer_schema_len` values, or from
+    /// all values if that is `None`. If `strict` is not set, values that are not valid JSON are
+    /// decoded as null, instead of raising an error.
     #[cfg(feature = "extract_jsonpath")]
-    pub fn json_decode(self, dtype: Option<DataType>, infer_schema_len: Option<usize>) -> Expr {
+    pub fn json_decode(
+        self,
+        dtype: Option<DataType>,
+        infer_schema_len: Option<usize>,
+        strict: bool,
+    ) -> Expr {
         self.0
             .map_private(FunctionExpr::StringExpr(StringFunction::JsonDecode {
                 dtype,
                 infer_schema_len,
+                strict,
             }))
     }
 
@@ -48,6 +48,85 @@ impl StructNameSpace {
             })
     }
 
+    /// Retrieve a nested field of this [`StructChunked`] by its path, e.g. `"b.c[0].d"`.
+    ///
+    /// The path consists of field names separated by `.`, each name can be followed by
+    /// list indices in square brackets, where negative indices count from the end.
+    /// List elements that are out of bounds are null. The path is resolved against the
+    /// schema of the struct, so missing fields are raised before execution.
+    pub fn field_by_path(self, path: &str) -> PolarsResult<Expr> {
+        let mut expr = self.0;
+        for segment in path.split('.') {
+            let (name, mut indices) = segment.split_at(segment.find('[').unwrap_or(segment.len()));
+            polars_ensure!(
+                !name.is_empty(),
+                ComputeError: "invalid field path '{}': empty field name", path
+            );
+            expr = expr.struct_().field_by_name(name);
+            while !indices.is_empty() {
+                let index = indices
+                    .strip_prefix('[')
+                    .and_then(|rest| rest.split_once(']'))
+                    .and_then(|(index, rest)| Some((index.trim().parse::<i64>().ok()?, rest)));
+                let Some((index, rest)) = index else {
+                    polars_bail!(
+                        ComputeError: "invalid field path '{}': expected a list index in \
+                        square brackets, got '{}'", path, indices
+                    );
+                };
+                expr = expr.list().get(lit(index), true);
+                indices = rest;
+            }
+        }
+        Ok(expr)
+    }
+
+    /// Keep only the fields `names` of the [`StructChunked`], in that order.
+    pub fn select_fields<S: AsRef<str>>(self, names: &[S]) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::SelectFields(
+                names
+                    .iter()
+                    .map(|name| ColumnName::from(name.as_ref()))
+                    .collect(),
+            )))
+    }
+
+    /// Remove the fields `names` from the [`StructChunked`].
+    pub fn drop_fields<S: AsRef<str>>(self, names: &[S]) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::DropFields(
+                names
+                    .iter()
+                    .map(|name| ColumnName::from(name.as_ref()))
+                    .collect(),
+            )))
+    }
+
+    /// Sum the numeric fields of the [`StructChunked`] per row, ignoring nulls.
+    pub fn sum_fields(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::SumFields))
+    }
+
+    /// Compute the mean of the numeric fields of the [`StructChunked`] per row, ignoring nulls.
+    pub fn mean_fields(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::MeanFields))
+    }
+
+    /// Get the minimum of the numeric fields of the [`StructChunked`] per row.
+    pub fn min_fields(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::MinFields))
+    }
+
+    /// Get the maximum of the numeric fields of the [`StructChunked`] per row.
+    pub fn max_fields(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::MaxFields))
+    }
+
     /// Rename the fields of the [`StructChunked`].
     pub fn rename_fields(self, names: Vec<String>) -> Expr {
         self.0
@@ -56,19 +135,46 @@ impl StructNameSpace {
             )))
     }
 
+    /// Rename the fields of the [`StructChunked`] from their old to their new names in
+    /// `mapping`, other fields keep their names.
+    pub fn rename_fields_by_mapping(self, mapping: Vec<(String, String)>) -> Expr {
+        self.0.map_private(FunctionExpr::StructExpr(
+            StructFunction::RenameFieldsByMapping(Arc::from(mapping)),
+        ))
+    }
+
+    /// Rename the fields of the [`StructChunked`] by applying `function` to their names.
+    pub fn rename_fields_with(self, function: FieldsNameMapper) -> Expr {
+        self.0.name().map_fields(function)
+    }
+
     #[cfg(feature = "json")]
     pub fn json_encode(self) -> Expr {
         self.0
             .map_private(FunctionExpr::StructExpr(StructFunction::JsonEncode))
     }
 
-    pub fn with_fields(self, mut fields: Vec<Expr>) -> Expr {
-        fields.insert(0, self.0);
+    /// Add or replace fields of the [`StructChunked`].
+    ///
+    /// The `field` expressions in `fields` select fields of this struct.
+    pub fn with_fields(self, fields: Vec<Expr>) -> Expr {
+        let mut input = Vec::with_capacity(fields.len() + 1);
+        input.push(self.0.clone());
+        input.extend(fields.into_iter().map(|e| {
+            e.map_expr(|e| match e {
+                Expr::Field(names) => match names.as_ref() {
+                    [name] => self.0.clone().struct_().field_by_name(name),
+                    names => self.0.clone().struct_().field_by_names(names),
+                },
+                e => e,
+            })
+        }));
         Expr::Function {
-            input: fields,
+            input,
             function: FunctionExpr::StructExpr(StructFunction::WithFields),
             options: FunctionOptions {
                 collect_groups: ApplyOptions::ElementWise,
+                input_wildcard_expansion: true,
                 ..Default::default()
             },
         }
@@ -531,6 +531,8 @@ pub fn to_alp_impl(
                 },
                 _ => {
                     let function = function.into_function_node(&input_schema)?;
+                    // Raise schema errors here, `IR::schema` assumes a valid schema.
+                    function.schema(&input_schema)?;
                     IR::MapFunction { input, function }
                 },
             }
@@ -579,7 +581,8 @@ fn expand_filter(predicate: Expr, input: Node, lp_arena: &Arena<IR>) -> PolarsRe
         | Expr::Columns(_)
         | Expr::DtypeColumn(_)
         | Expr::IndexColumn(_)
-        | Expr::Nth(_) => true,
+        | Expr::Nth(_)
+        | Expr::Field(_) => true,
         _ => false,
     }) {
         let mut rewritten = rewrite_projections(vec![predicate], &schema, &[])?;
@@ -341,6 +341,20 @@ fn replace_struct_multiple_fields_with_field(expr: Expr, column_name: &ColumnNam
     })
 }
 
+/// Like [`rewrite_special_aliases`], but names the output after the struct field `name`
+/// instead of the root column.
+#[cfg(feature = "dtype-struct")]
+fn rewrite_struct_field_aliases(expr: Expr, name: &ColumnName) -> PolarsResult<Expr> {
+    match expr {
+        Expr::KeepName(expr) => Ok(Expr::Alias(expr, name.clone())),
+        Expr::RenameAlias { expr, function } => {
+            let name = function.call(name)?;
+            Ok(Expr::Alias(expr, ColumnName::from(name)))
+        },
+        expr => rewrite_special_aliases(expr),
+    }
+}
+
 #[cfg(feature = "dtype-struct")]
 fn expand_struct_fields(
     struct_expr: &Expr,
@@ -407,10 +421,8 @@ fn expand_struct_fields(
         polars_ensure!(name.as_ref() != "*", InvalidOperation: "cannot combine wildcards and column names");
 
         if !exclude.contains(name) {
-            result.push(replace_struct_multiple_fields_with_field(
-                full_expr.clone(),
-                name,
-            ))
+            let new_expr = replace_struct_multiple_fields_with_field(full_expr.clone(), name);
+            result.push(rewrite_struct_field_aliases(new_expr, name)?)
         }
     }
     Ok(())
@@ -542,6 +554,7 @@ struct ExpansionFlags {
     has_wildcard: bool,
     has_selector: bool,
     has_exclude: bool,
+    has_field: bool,
     #[cfg(feature = "dtype-struct")]
     has_struct_field_by_index: bool,
 }
@@ -552,6 +565,7 @@ fn find_flags(expr: &Expr) -> ExpansionFlags {
     let mut has_wildcard = false;
     let mut has_selector = false;
     let mut has_exclude = false;
+    let mut has_field = false;
     #[cfg(feature = "dtype-struct")]
     let mut has_struct_field_by_index = false;
 
@@ -579,6 +593,7 @@ fn find_flags(expr: &Expr) -> ExpansionFlags {
                 multiple_columns = true;
             },
             Expr::Exclude(_, _) => has_exclude = true,
+            Expr::Field(_) => has_field = true,
             _ => {},
         }
     }
@@ -588,6 +603,7 @@ fn find_flags(expr: &Expr) -> ExpansionFlags {
         has_wildcard,
         has_selector,
         has_exclude,
+        has_field,
         #[cfg(feature = "dtype-struct")]
         has_struct_field_by_index,
     }
@@ -610,6 +626,10 @@ pub(crate) fn rewrite_projections(
         expr = expand_function_inputs(expr, schema);
 
         let mut flags = find_flags(&expr);
+        polars_ensure!(
+            !flags.has_field,
+            InvalidOperation: "`field` can only be used in `struct.with_fields`"
+        );
         if flags.has_selector {
             expr = replace_selector(expr, schema, keys)?;
             // the selector is replaced with Expr::Columns
@@ -30,6 +30,7 @@ impl fmt::Debug for Expr {
                 },
             },
             Nth(i) => write!(f, "nth({i})"),
+            Field(names) => write!(f, "field({names:?})"),
             Len => write!(f, "len()"),
             Explode(expr) => write!(f, "{expr:?}.explode()"),
             Alias(expr, name) => write!(f, "{expr:?}.alias(\"{name}\")"),
@@ -6,6 +6,7 @@ mod merge_sorted;
 mod python_udf;
 mod rename;
 mod schema;
+mod unnest;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -15,9 +16,12 @@ use std::sync::Arc;
 
 pub use dsl::*;
 use polars_core::prelude::*;
+#[cfg(feature = "dynamic_group_by")]
+use polars_time::{Duration, PolarsUpsample};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
+pub use unnest::{UnnestNameMapper, UnnestNaming};
 
 #[cfg(feature = "python")]
 use crate::dsl::python_udf::PythonFunction;
@@ -65,6 +69,7 @@ pub enum FunctionNode {
     },
     Unnest {
         columns: Arc<[Arc<str>]>,
+        naming: UnnestNaming,
     },
     Rechunk,
     // The two DataFrames are temporary concatenated
@@ -101,6 +106,15 @@ pub enum FunctionNode {
         schema: CachedSchema,
         offset: Option<IdxSize>,
     },
+    #[cfg(feature = "dynamic_group_by")]
+    Upsample {
+        time_column: Arc<str>,
+        every: Duration,
+        group_by: Arc<[Arc<str>]>,
+        maintain_order: bool,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        schema: CachedSchema,
+    },
 }
 
 impl Eq for FunctionNode {}
@@ -126,6 +140,28 @@ impl PartialEq for FunctionNode {
             (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
             (Melt { args: l, .. }, Melt { args: r, .. }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
+            #[cfg(feature = "dynamic_group_by")]
+            (
+                Upsample {
+                    time_column: time_column_l,
+                    every: every_l,
+                    group_by: group_by_l,
+                    maintain_order: maintain_order_l,
+                    ..
+                },
+                Upsample {
+                    time_column: time_column_r,
+                    every: every_r,
+                    group_by: group_by_r,
+                    maintain_order: maintain_order_r,
+                    ..
+                },
+            ) => {
+                time_column_l == time_column_r
+                    && every_l == every_r
+                    && group_by_l == group_by_r
+                    && maintain_order_l == maintain_order_r
+            },
             #[cfg(feature = "merge_sorted")]
             (MergeSorted { column: l }, MergeSorted { column: r }) => l == r,
             _ => false,
@@ -150,7 +186,10 @@ impl Hash for FunctionNode {
                 alias.hash(state);
             },
             FunctionNode::Pipeline { .. } => {},
-            FunctionNode::Unnest { columns } => columns.hash(state),
+            FunctionNode::Unnest { columns, naming } => {
+                columns.hash(state);
+                naming.hash(state);
+            },
             FunctionNode::Rechunk => {},
             #[cfg(feature = "merge_sorted")]
             FunctionNode::MergeSorted { column } => column.hash(state),
@@ -173,6 +212,19 @@ impl Hash for FunctionNode {
                 name.hash(state);
                 offset.hash(state);
             },
+            #[cfg(feature = "dynamic_group_by")]
+            FunctionNode::Upsample {
+                time_column,
+                every,
+                group_by,
+                maintain_order,
+                schema: _,
+            } => {
+                time_column.hash(state);
+                every.hash(state);
+                group_by.hash(state);
+                maintain_order.hash(state);
+            },
         }
     }
 }
@@ -191,6 +243,8 @@ impl FunctionNode {
             #[cfg(feature = "python")]
             OpaquePython { streamable, .. } => *streamable,
             RowIndex { .. } => false,
+            #[cfg(feature = "dynamic_group_by")]
+            Upsample { .. } => false,
         }
     }
 
@@ -201,6 +255,8 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             Explode { .. } | Melt { .. } => true,
+            #[cfg(feature = "dynamic_group_by")]
+            Upsample { .. } => true,
             _ => false,
         }
     }
@@ -215,6 +271,10 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             RowIndex { .. } | Count { .. } => false,
+            // The inserted rows have null values in the `group_by` columns, so even
+            // predicates on those columns give different results below this node.
+            #[cfg(feature = "dynamic_group_by")]
+            Upsample { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -234,6 +294,8 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             RowIndex { .. } => true,
+            #[cfg(feature = "dynamic_group_by")]
+            Upsample { .. } => true,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -241,10 +303,21 @@ impl FunctionNode {
     pub(crate) fn additional_projection_pd_columns(&self) -> Cow<[Arc<str>]> {
         use FunctionNode::*;
         match self {
-            Unnest { columns } => Cow::Borrowed(columns.as_ref()),
+            Unnest { columns, .. } => Cow::Borrowed(columns.as_ref()),
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column, .. } => Cow::Owned(vec![column.clone()]),
+            #[cfg(feature = "dynamic_group_by")]
+            Upsample {
+                time_column,
+                group_by,
+                ..
+            } => Cow::Owned(
+                std::iter::once(time_column)
+                    .chain(group_by.iter())
+                    .cloned()
+                    .collect(),
+            ),
             _ => Cow::Borrowed(&[]),
         }
     }
@@ -269,10 +342,13 @@ impl FunctionNode {
             },
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column } => merge_sorted(&df, column.as_ref()),
-            Unnest { columns: _columns } => {
+            Unnest {
+                columns: _columns,
+                naming: _naming,
+            } => {
                 #[cfg(feature = "dtype-struct")]
                 {
-                    df.unnest(_columns.as_ref())
+                    unnest::unnest(&df, _columns, _naming)
                 }
                 #[cfg(not(feature = "dtype-struct"))]
                 {
@@ -299,6 +375,22 @@ impl FunctionNode {
                 df.melt2(args)
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.as_ref(), *offset),
+            #[cfg(feature = "dynamic_group_by")]
+            Upsample {
+                time_column,
+                every,
+                group_by,
+                maintain_order,
+                ..
+            } => {
+                let group_by = group_by.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+                let offset = Duration::parse("0ns");
+                if *maintain_order {
+                    df.upsample_stable(group_by, time_column, *every, offset)
+                } else {
+                    df.upsample(group_by, time_column, *every, offset)
+                }
+            },
         }
     }
 }
@@ -318,7 +410,7 @@ impl Display for FunctionNode {
             OpaquePython { .. } => write!(f, "python dataframe udf"),
             Rechunk => write!(f, "RECHUNK"),
             Count { .. } => write!(f, "FAST COUNT(*)"),
-            Unnest { columns } => {
+            Unnest { columns, .. } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
                 fmt_column_delimited(f, columns, "[", "]")
@@ -342,6 +434,8 @@ impl Display for FunctionNode {
             Explode { .. } => write!(f, "EXPLODE"),
             Melt { .. } => write!(f, "MELT"),
             RowIndex { .. } => write!(f, "WITH ROW INDEX"),
+            #[cfg(feature = "dynamic_group_by")]
+            Upsample { every, .. } => write!(f, "UPSAMPLE every: {every}"),
         }
     }
 }
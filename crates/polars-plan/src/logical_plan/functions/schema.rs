@@ -15,6 +15,11 @@ impl FunctionNode {
                 let mut guard = schema.lock().unwrap();
                 *guard = None;
             },
+            #[cfg(feature = "dynamic_group_by")]
+            Upsample { schema, .. } => {
+                let mut guard = schema.lock().unwrap();
+                *guard = None;
+            },
             _ => {},
         }
     }
@@ -50,7 +55,10 @@ impl FunctionNode {
                 Ok(Cow::Owned(Arc::new(schema)))
             },
             Rechunk => Ok(Cow::Borrowed(input_schema)),
-            Unnest { columns: _columns } => {
+            Unnest {
+                columns: _columns,
+                naming: _naming,
+            } => {
                 #[cfg(feature = "dtype-struct")]
                 {
                     let mut new_schema = Schema::with_capacity(input_schema.len() * 2);
@@ -59,10 +67,12 @@ impl FunctionNode {
                             match dtype {
                                 DataType::Struct(flds) => {
                                     for fld in flds {
-                                        new_schema.with_column(
-                                            fld.name().clone(),
-                                            fld.data_type().clone(),
+                                        let name = _naming.name(name, fld.name())?;
+                                        polars_ensure!(
+                                            !new_schema.contains(&name),
+                                            Duplicate: "column with name '{}' has more than one occurrences", name
                                         );
+                                        new_schema.with_column(name, fld.data_type().clone());
                                     }
                                 },
                                 DataType::Unknown(_) => {
@@ -75,6 +85,10 @@ impl FunctionNode {
                                 },
                             }
                         } else {
+                            polars_ensure!(
+                                !new_schema.contains(name),
+                                Duplicate: "column with name '{}' has more than one occurrences", name
+                            );
                             new_schema.with_column(name.clone(), dtype.clone());
                         }
                     }
@@ -99,6 +113,12 @@ impl FunctionNode {
             },
             Explode { schema, columns } => explode_schema(schema, input_schema, columns),
             Melt { schema, args } => melt_schema(args, schema, input_schema),
+            #[cfg(feature = "dynamic_group_by")]
+            Upsample {
+                time_column,
+                schema,
+                ..
+            } => upsample_schema(schema, input_schema, time_column),
         }
     }
 }
@@ -143,6 +163,30 @@ fn explode_schema<'a>(
     Ok(Cow::Owned(schema))
 }
 
+#[cfg(feature = "dynamic_group_by")]
+fn upsample_schema<'a>(
+    cached_schema: &CachedSchema,
+    input_schema: &'a Schema,
+    time_column: &str,
+) -> PolarsResult<Cow<'a, SchemaRef>> {
+    let mut guard = cached_schema.lock().unwrap();
+    if let Some(schema) = &*guard {
+        return Ok(Cow::Owned(schema.clone()));
+    }
+    // The upsampled range is joined with the input, so the time column comes first.
+    let time_dtype = input_schema.try_get(time_column)?;
+    let mut schema = Schema::with_capacity(input_schema.len());
+    schema.with_column(time_column.into(), time_dtype.clone());
+    for (name, dtype) in input_schema.iter() {
+        if name.as_str() != time_column {
+            schema.with_column(name.clone(), dtype.clone());
+        }
+    }
+    let schema = Arc::new(schema);
+    *guard = Some(schema.clone());
+    Ok(Cow::Owned(schema))
+}
+
 fn melt_schema<'a>(
     args: &MeltArgs,
     cached_schema: &CachedSchema,
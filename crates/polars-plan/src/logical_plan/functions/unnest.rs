@@ -0,0 +1,77 @@
+use super::*;
+
+/// Maps the name of a struct column and the name of one of its fields to the name of the
+/// column that the field is unnested into.
+pub type UnnestNameMapper = Arc<dyn Fn(&str, &str) -> PolarsResult<String> + Send + Sync>;
+
+/// How the columns that the fields of a struct are unnested into are named.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnnestNaming {
+    /// Name the columns after the fields.
+    #[default]
+    Fields,
+    /// Name the columns `{column}{separator}{field}`.
+    Prefix(Arc<str>),
+    /// Name the columns `{field}{separator}{column}`.
+    Suffix(Arc<str>),
+    /// Name the columns by calling the function with the column and field name.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Function(UnnestNameMapper),
+}
+
+#[cfg(feature = "dtype-struct")]
+impl UnnestNaming {
+    /// The name of the column that `field` of the struct column `column` is unnested into.
+    pub(super) fn name(&self, column: &str, field: &str) -> PolarsResult<SmartString> {
+        Ok(match self {
+            UnnestNaming::Fields => field.into(),
+            UnnestNaming::Prefix(separator) => format!("{column}{separator}{field}").into(),
+            UnnestNaming::Suffix(separator) => format!("{field}{separator}{column}").into(),
+            UnnestNaming::Function(function) => function(column, field)?.into(),
+        })
+    }
+}
+
+impl Hash for UnnestNaming {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            UnnestNaming::Prefix(separator) | UnnestNaming::Suffix(separator) => {
+                separator.hash(state)
+            },
+            // Closures hash by address, so that plans with different mappers don't collide.
+            UnnestNaming::Function(function) => {
+                (Arc::as_ptr(function) as *const () as usize).hash(state)
+            },
+            UnnestNaming::Fields => {},
+        }
+    }
+}
+
+#[cfg(feature = "dtype-struct")]
+pub(super) fn unnest(
+    df: &DataFrame,
+    columns: &[Arc<str>],
+    naming: &UnnestNaming,
+) -> PolarsResult<DataFrame> {
+    if let UnnestNaming::Fields = naming {
+        return df.unnest(columns);
+    }
+    for name in columns {
+        df.try_get_column_index(name)?;
+    }
+    let mut new_cols = Vec::with_capacity(df.width() * 2);
+    for s in df.get_columns() {
+        if columns.iter().any(|name| name.as_ref() == s.name()) {
+            for field in s.struct_()?.fields() {
+                let mut field = field.clone();
+                field.rename(&naming.name(s.name(), field.name())?);
+                new_cols.push(field);
+            }
+        } else {
+            new_cols.push(s.clone());
+        }
+    }
+    DataFrame::new(new_cols)
+}
@@ -157,6 +157,12 @@ fn skip_pre_visit(ae: &AExpr, is_groupby: bool) -> bool {
     match ae {
         AExpr::Window { .. } => true,
         AExpr::Ternary { .. } => is_groupby,
+        // The names of the inputs are the names of the fields, so they may not be replaced.
+        #[cfg(feature = "dtype-struct")]
+        AExpr::Function {
+            function: FunctionExpr::StructExpr(StructFunction::WithFields),
+            ..
+        } => true,
         _ => false,
     }
 }
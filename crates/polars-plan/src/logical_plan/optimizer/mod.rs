@@ -55,6 +55,15 @@ pub(crate) fn init_hashmap<K, V>(max_len: Option<usize>) -> PlHashMap<K, V> {
     PlHashMap::with_capacity(std::cmp::min(max_len.unwrap_or(HASHMAP_SIZE), HASHMAP_SIZE))
 }
 
+/// Enters a `tracing` span for an optimization pass, which is exited at the end of the
+/// enclosing block.
+macro_rules! enter_pass_span {
+    ($pass:literal) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("optimize", pass = $pass).entered();
+    };
+}
+
 pub fn optimize(
     logical_plan: DslPlan,
     opt_state: OptState,
@@ -91,13 +100,16 @@ pub fn optimize(
     let opt = StackOptimizer {};
     let mut rules: Vec<Box<dyn OptimizationRule>> = Vec::with_capacity(8);
 
-    let mut lp_top = to_alp(
-        logical_plan,
-        expr_arena,
-        lp_arena,
-        simplify_expr,
-        type_coercion,
-    )?;
+    let mut lp_top = {
+        enter_pass_span!("conversion");
+        to_alp(
+            logical_plan,
+            expr_arena,
+            lp_arena,
+            simplify_expr,
+            type_coercion,
+        )?
+    };
     // During debug we check if the optimizations have not modified the final schema.
     #[cfg(debug_assertions)]
     let prev_schema = lp_arena.get(lp_top).schema(lp_arena).into_owned();
@@ -119,6 +131,7 @@ pub fn optimize(
         && members.has_duplicate_scans()
         && !members.has_cache
     {
+        enter_pass_span!("comm_subplan_elim");
         if verbose {
             eprintln!("found multiple sources; run comm_subplan_elim")
         }
@@ -137,6 +150,7 @@ pub fn optimize(
 
     // Should be run before predicate pushdown.
     if projection_pushdown {
+        enter_pass_span!("projection_pushdown");
         let mut projection_pushdown_opt = ProjectionPushDown::new();
         let alp = lp_arena.take(lp_top);
         let alp = projection_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
@@ -149,6 +163,7 @@ pub fn optimize(
     }
 
     if predicate_pushdown {
+        enter_pass_span!("predicate_pushdown");
         let predicate_pushdown_opt = PredicatePushDown::new(hive_partition_eval);
         let alp = lp_arena.take(lp_top);
         let alp = predicate_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
@@ -165,6 +180,7 @@ pub fn optimize(
     }
 
     if slice_pushdown {
+        enter_pass_span!("slice_pushdown");
         let slice_pushdown_opt = SlicePushDown::new(streaming);
         let alp = lp_arena.take(lp_top);
         let alp = slice_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
@@ -184,9 +200,13 @@ pub fn optimize(
         rules.push(Box::new(FlattenUnionRule {}));
     }
 
-    lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
+    lp_top = {
+        enter_pass_span!("optimize_loop");
+        opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?
+    };
 
     if members.has_joins_or_unions && members.has_cache && _cse_plan_changed {
+        enter_pass_span!("cache_states");
         // We only want to run this on cse inserted caches
         cache_states::set_cache_states(
             lp_top,
@@ -201,6 +221,7 @@ pub fn optimize(
     // This one should run (nearly) last as this modifies the projections
     #[cfg(feature = "cse")]
     if comm_subexpr_elim && !members.has_ext_context {
+        enter_pass_span!("comm_subexpr_elim");
         let mut optimizer = CommonSubExprOptimizer::new();
         let alp_node = IRNode::new(lp_top);
 
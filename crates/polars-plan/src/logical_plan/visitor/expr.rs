@@ -83,6 +83,7 @@ impl TreeWalker for Expr {
             KeepName(expr) => KeepName(am(expr, f)?),
             Len => Len,
             Nth(_) => self,
+            Field(_) => self,
             RenameAlias { function, expr } => RenameAlias { function, expr: am(expr, f)? },
             AnonymousFunction { input, function, output_type, options } => {
                 AnonymousFunction { input: input.into_iter().map(f).collect::<Result<_, _>>()?, function, output_type, options }
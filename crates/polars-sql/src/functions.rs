@@ -1,12 +1,8 @@
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{polars_bail, polars_err, DataType, PolarsResult};
 use polars_lazy::dsl::Expr;
-#[cfg(feature = "list_eval")]
-use polars_lazy::dsl::ListNameSpaceExtension;
 use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
 use polars_plan::logical_plan::{typed_lit, LiteralValue};
-#[cfg(feature = "list_eval")]
-use polars_plan::prelude::col;
 use polars_plan::prelude::LiteralValue::Null;
 use polars_plan::prelude::{lit, StrptimeOptions};
 use sqlparser::ast::{
@@ -292,7 +288,6 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```sql
     /// SELECT INITCAP(column_1) from df;
     /// ```
-    #[cfg(feature = "nightly")]
     InitCap,
     /// SQL 'left' function
     /// Returns the first (leftmost) `n` characters.
@@ -362,7 +357,9 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     StartsWith,
     /// SQL 'strpos' function
-    /// Returns the index of the given substring in the target string.
+    /// Returns the 1-indexed char position of the given substring in the target string, or
+    /// zero if it is not found. Like `POSITION(substring IN string)`, this counts chars, not
+    /// bytes.
     /// ```sql
     /// SELECT STRPOS(column_1,'xyz') from df;
     /// ```
@@ -729,7 +726,6 @@ impl PolarsSQLFunctions {
             "concat" => Self::Concat,
             "concat_ws" => Self::ConcatWS,
             "ends_with" => Self::EndsWith,
-            #[cfg(feature = "nightly")]
             "initcap" => Self::InitCap,
             "length" | "char_length" | "character_length" => Self::Length,
             "left" => Self::Left,
@@ -905,7 +901,6 @@ impl SQLFunctionVisitor<'_> {
                 })
             },
             EndsWith => self.visit_binary(|e, s| e.str().ends_with(s)),
-            #[cfg(feature = "nightly")]
             InitCap => self.visit_unary(|e| e.str().to_titlecase()),
             Left => self.try_visit_binary(|e, length| {
                 Ok(match length {
@@ -932,8 +927,8 @@ impl SQLFunctionVisitor<'_> {
             },
             OctetLength => self.visit_unary(|e| e.str().len_bytes()),
             StrPos => {
-                // note: 1-indexed, not 0-indexed, and returns zero if match not found
-                self.visit_binary(|expr, substring| (expr.str().find(substring, true) + typed_lit(1u32)).fill_null(typed_lit(0u32)))
+                // note: 1-indexed char position, not 0-indexed, and returns zero if match not found
+                self.visit_binary(|expr, substring| (expr.str().find(substring, true, true) + typed_lit(1u32)).fill_null(typed_lit(0u32)))
             },
             RegexpLike => match function.args.len() {
                 2 => self.visit_binary(|e, s| e.str().contains(s, true)),
@@ -1047,15 +1042,14 @@ impl SQLFunctionVisitor<'_> {
             ArrayReverse => self.visit_unary(|e| e.list().reverse()),
             ArraySum => self.visit_unary(|e| e.list().sum()),
             ArrayToString => match function.args.len() {
-                2 => self.try_visit_binary(|e, sep| { Ok(e.list().join(sep, true)) }),
-                #[cfg(feature = "list_eval")]
+                2 => self.try_visit_binary(|e, sep| { Ok(e.list().join(sep, true, None)) }),
                 3 => self.try_visit_ternary(|e, sep, null_value| {
                     match null_value {
                         Expr::Literal(LiteralValue::String(v)) => {
                             Ok(if v.is_empty() {
-                                e.list().join(sep, true)
+                                e.list().join(sep, true, None)
                             } else {
-                                e.list().eval(col("").fill_null(lit(v)), false).list().join(sep, false)
+                                e.list().join(sep, false, Some(&v))
                             })
                         },
                         _ => polars_bail!(InvalidOperation: "invalid null value for ArrayToString: {}", function.args[2]),
@@ -252,11 +252,11 @@ impl SQLExprVisitor<'_> {
             } => self.visit_like(*negated, expr, pattern, escape_char, true),
             SQLExpr::Nested(expr) => self.visit_expr(expr),
             SQLExpr::Position { expr, r#in } => Ok(
-                // note: SQL is 1-indexed, not 0-indexed
+                // note: SQL is 1-indexed, not 0-indexed, and counts chars
                 (self
                     .visit_expr(r#in)?
                     .str()
-                    .find(self.visit_expr(expr)?, true)
+                    .find(self.visit_expr(expr)?, true, true)
                     + typed_lit(1u32))
                 .fill_null(typed_lit(0u32)),
             ),
@@ -620,7 +620,7 @@ impl SQLExprVisitor<'_> {
 
         #[cfg(feature = "json")]
         if data_type == &SQLDataType::JSON {
-            return Ok(expr.str().json_decode(None, None));
+            return Ok(expr.str().json_decode(None, None, true));
         }
         let polars_type = map_sql_polars_datatype(data_type)?;
         Ok(expr.strict_cast(polars_type))
@@ -1126,7 +1126,7 @@ fn parse_extract(expr: Expr, field: &DateTimeField) -> PolarsResult<Expr> {
         DateTimeField::Decade => expr.dt().year() / typed_lit(10i32),
         DateTimeField::Isoyear => expr.dt().iso_year(),
         DateTimeField::Year => expr.dt().year(),
-        DateTimeField::Quarter => expr.dt().quarter(),
+        DateTimeField::Quarter => expr.dt().quarter(1),
         DateTimeField::Month => expr.dt().month(),
         DateTimeField::Week => expr.dt().week(),
         DateTimeField::IsoWeek => expr.dt().week(),
@@ -1155,7 +1155,7 @@ fn parse_extract(expr: Expr, field: &DateTimeField) -> PolarsResult<Expr> {
         },
         DateTimeField::Time => expr.dt().time(),
         #[cfg(feature = "timezones")]
-        DateTimeField::Timezone => expr.dt().base_utc_offset().dt().total_seconds(),
+        DateTimeField::Timezone => expr.dt().base_utc_offset().dt().total_seconds(false),
         DateTimeField::Epoch => {
             expr.clone()
                 .dt()
@@ -1,6 +1,7 @@
-use arrow::temporal_conversions::{MILLISECONDS, SECONDS_IN_DAY};
+use arrow::temporal_conversions::{date32_to_date, MILLISECONDS, SECONDS_IN_DAY};
 
 use super::*;
+use crate::windows::calendar;
 
 pub(crate) fn naive_date_to_date(nd: NaiveDate) -> i32 {
     let nt = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
@@ -12,6 +13,10 @@ pub(crate) fn naive_datetime_to_date(v: NaiveDateTime) -> i32 {
     (datetime_to_timestamp_ms(v) / (MILLISECONDS * SECONDS_IN_DAY)) as i32
 }
 
+fn apply_on_date(ca: &DateChunked, f: impl Fn(NaiveDate) -> bool) -> BooleanChunked {
+    ca.apply_nonnull_values_generic(DataType::Boolean, |d| f(date32_to_date(d)))
+}
+
 pub trait DateMethods: AsDate {
     /// Extract month from underlying NaiveDate representation.
     /// Returns the year number in the calendar date.
@@ -27,17 +32,60 @@ pub trait DateMethods: AsDate {
         ca.apply_kernel_cast::<BooleanType>(&date_to_is_leap_year)
     }
 
+    /// Returns whether the date is the first day of a month.
+    fn is_month_start(&self) -> BooleanChunked {
+        apply_on_date(self.as_date(), calendar::is_month_start)
+    }
+
+    /// Returns whether the date is the last day of a month.
+    fn is_month_end(&self) -> BooleanChunked {
+        apply_on_date(self.as_date(), calendar::is_month_end)
+    }
+
+    /// Returns whether the date is the first day of a quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_quarter_start(&self, fiscal_year_start: u8) -> BooleanChunked {
+        apply_on_date(self.as_date(), |date| {
+            calendar::is_quarter_start(date, fiscal_year_start)
+        })
+    }
+
+    /// Returns whether the date is the last day of a quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_quarter_end(&self, fiscal_year_start: u8) -> BooleanChunked {
+        apply_on_date(self.as_date(), |date| {
+            calendar::is_quarter_end(date, fiscal_year_start)
+        })
+    }
+
+    /// Returns whether the date is the first day of a year.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_year_start(&self, fiscal_year_start: u8) -> BooleanChunked {
+        apply_on_date(self.as_date(), |date| {
+            calendar::is_year_start(date, fiscal_year_start)
+        })
+    }
+
+    /// Returns whether the date is the last day of a year.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_year_end(&self, fiscal_year_start: u8) -> BooleanChunked {
+        apply_on_date(self.as_date(), |date| {
+            calendar::is_year_end(date, fiscal_year_start)
+        })
+    }
+
     /// This year number might not match the calendar year number.
     fn iso_year(&self) -> Int32Chunked {
         let ca = self.as_date();
         ca.apply_kernel_cast::<Int32Type>(&date_to_iso_year)
     }
 
-    /// Extract month from underlying NaiveDateTime representation.
+    /// Extract quarter from underlying NaiveDate representation.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
     /// Quarters range from 1 to 4.
-    fn quarter(&self) -> Int8Chunked {
+    fn quarter(&self, fiscal_year_start: u8) -> Int8Chunked {
         let months = self.month();
-        months_to_quarters(months)
+        months_to_quarters(months, fiscal_year_start)
     }
 
     /// Extract month from underlying NaiveDateTime representation.
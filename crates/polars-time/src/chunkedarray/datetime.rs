@@ -1,9 +1,18 @@
 use arrow::array::{Array, PrimitiveArray};
 use arrow::compute::cast::{cast, CastOptions};
 use arrow::compute::temporal;
+#[cfg(feature = "timezones")]
+use arrow::legacy::time_zone::Tz;
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+use chrono::Datelike;
+#[cfg(feature = "timezones")]
+use chrono::TimeZone;
 use polars_core::prelude::*;
 
 use super::*;
+use crate::windows::calendar;
 
 fn cast_and_apply<
     F: Fn(&dyn Array) -> PolarsResult<PrimitiveArray<T::Native>>,
@@ -28,6 +37,34 @@ fn cast_and_apply<
     ChunkedArray::from_chunk_iter(ca.name(), chunks)
 }
 
+/// Apply `f` to the dates of `ca` in its time zone.
+fn apply_on_local_date<U, K>(
+    ca: &DatetimeChunked,
+    dtype: DataType,
+    f: impl Fn(NaiveDate) -> K,
+) -> ChunkedArray<U>
+where
+    U: PolarsDataType,
+    U::Array: ArrayFromIterDtype<K> + ArrayFromIterDtype<Option<K>>,
+{
+    let timestamp_to_datetime = match ca.time_unit() {
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+        TimeUnit::Microseconds => timestamp_us_to_datetime,
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+    };
+    #[cfg(feature = "timezones")]
+    if let Some(tz) = ca
+        .time_zone()
+        .as_deref()
+        .and_then(|tz| tz.parse::<Tz>().ok())
+    {
+        return ca.apply_nonnull_values_generic(dtype, |t| {
+            f(tz.from_utc_datetime(&timestamp_to_datetime(t)).date_naive())
+        });
+    }
+    ca.apply_nonnull_values_generic(dtype, |t| f(timestamp_to_datetime(t).date()))
+}
+
 pub trait DatetimeMethods: AsDatetime {
     /// Extract month from underlying NaiveDateTime representation.
     /// Returns the year number in the calendar date.
@@ -38,30 +75,73 @@ pub trait DatetimeMethods: AsDatetime {
     /// Extract year from underlying NaiveDate representation.
     /// Returns whether the year is a leap year.
     fn is_leap_year(&self) -> BooleanChunked {
-        let ca = self.as_datetime();
-        let f = match ca.time_unit() {
-            TimeUnit::Nanoseconds => datetime_to_is_leap_year_ns,
-            TimeUnit::Microseconds => datetime_to_is_leap_year_us,
-            TimeUnit::Milliseconds => datetime_to_is_leap_year_ms,
-        };
-        ca.apply_kernel_cast::<BooleanType>(&f)
+        apply_on_local_date(self.as_datetime(), DataType::Boolean, |date| {
+            calendar::is_leap_year(date.year())
+        })
+    }
+
+    /// Returns whether the date is the first day of a month.
+    fn is_month_start(&self) -> BooleanChunked {
+        apply_on_local_date(
+            self.as_datetime(),
+            DataType::Boolean,
+            calendar::is_month_start,
+        )
+    }
+
+    /// Returns whether the date is the last day of a month.
+    fn is_month_end(&self) -> BooleanChunked {
+        apply_on_local_date(
+            self.as_datetime(),
+            DataType::Boolean,
+            calendar::is_month_end,
+        )
+    }
+
+    /// Returns whether the date is the first day of a quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_quarter_start(&self, fiscal_year_start: u8) -> BooleanChunked {
+        apply_on_local_date(self.as_datetime(), DataType::Boolean, |date| {
+            calendar::is_quarter_start(date, fiscal_year_start)
+        })
+    }
+
+    /// Returns whether the date is the last day of a quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_quarter_end(&self, fiscal_year_start: u8) -> BooleanChunked {
+        apply_on_local_date(self.as_datetime(), DataType::Boolean, |date| {
+            calendar::is_quarter_end(date, fiscal_year_start)
+        })
+    }
+
+    /// Returns whether the date is the first day of a year.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_year_start(&self, fiscal_year_start: u8) -> BooleanChunked {
+        apply_on_local_date(self.as_datetime(), DataType::Boolean, |date| {
+            calendar::is_year_start(date, fiscal_year_start)
+        })
+    }
+
+    /// Returns whether the date is the last day of a year.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_year_end(&self, fiscal_year_start: u8) -> BooleanChunked {
+        apply_on_local_date(self.as_datetime(), DataType::Boolean, |date| {
+            calendar::is_year_end(date, fiscal_year_start)
+        })
     }
 
     fn iso_year(&self) -> Int32Chunked {
-        let ca = self.as_datetime();
-        let f = match ca.time_unit() {
-            TimeUnit::Nanoseconds => datetime_to_iso_year_ns,
-            TimeUnit::Microseconds => datetime_to_iso_year_us,
-            TimeUnit::Milliseconds => datetime_to_iso_year_ms,
-        };
-        ca.apply_kernel_cast::<Int32Type>(&f)
+        apply_on_local_date(self.as_datetime(), DataType::Int32, |date| {
+            date.iso_week().year()
+        })
     }
 
     /// Extract quarter from underlying NaiveDateTime representation.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
     /// Quarters range from 1 to 4.
-    fn quarter(&self) -> Int8Chunked {
+    fn quarter(&self, fiscal_year_start: u8) -> Int8Chunked {
         let months = self.month();
-        months_to_quarters(months)
+        months_to_quarters(months, fiscal_year_start)
     }
 
     /// Extract month from underlying NaiveDateTime representation.
@@ -1,12 +1,152 @@
+use std::fmt::Write;
+
+use arrow::array::MutablePlString;
 use arrow::temporal_conversions::{
     MICROSECONDS, MILLISECONDS, MILLISECONDS_IN_DAY, NANOSECONDS, SECONDS_IN_DAY,
 };
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::*;
 
 const NANOSECONDS_IN_MILLISECOND: i64 = 1_000_000;
 const SECONDS_IN_HOUR: i64 = 3600;
 
+/// How to write durations as strings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DurationFormat {
+    /// The format durations are displayed in, e.g. `2d 3h 15m`.
+    Polars,
+    /// ISO 8601, e.g. `P2DT3H15M`.
+    Iso,
+}
+
+/// The smallest unit that durations are written with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DurationPrecision {
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl DurationPrecision {
+    fn nanoseconds(self) -> i128 {
+        match self {
+            DurationPrecision::Days => 86_400_000_000_000,
+            DurationPrecision::Hours => 3_600_000_000_000,
+            DurationPrecision::Minutes => 60_000_000_000,
+            DurationPrecision::Seconds => 1_000_000_000,
+            DurationPrecision::Milliseconds => 1_000_000,
+            DurationPrecision::Microseconds => 1_000,
+            DurationPrecision::Nanoseconds => 1,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            DurationPrecision::Days => "d",
+            DurationPrecision::Hours => "h",
+            DurationPrecision::Minutes => "m",
+            DurationPrecision::Seconds => "s",
+            DurationPrecision::Milliseconds => "ms",
+            DurationPrecision::Microseconds => "µs",
+            DurationPrecision::Nanoseconds => "ns",
+        }
+    }
+}
+
+impl From<TimeUnit> for DurationPrecision {
+    fn from(tu: TimeUnit) -> Self {
+        match tu {
+            TimeUnit::Milliseconds => DurationPrecision::Milliseconds,
+            TimeUnit::Microseconds => DurationPrecision::Microseconds,
+            TimeUnit::Nanoseconds => DurationPrecision::Nanoseconds,
+        }
+    }
+}
+
+const NS_SECOND: i128 = 1_000_000_000;
+const NS_UNITS: [(i128, &str, &str); 4] = [
+    (86_400 * NS_SECOND, "d", "D"),
+    (3_600 * NS_SECOND, "h", "H"),
+    (60 * NS_SECOND, "m", "M"),
+    (NS_SECOND, "s", "S"),
+];
+
+/// Write `ns` like durations are displayed, every unit with its own sign.
+fn write_polars_duration(buf: &mut String, ns: i128, precision: DurationPrecision) {
+    if ns == 0 {
+        write!(buf, "0{}", precision.name()).unwrap();
+        return;
+    }
+    let mut rest = ns;
+    for (size, name, _) in NS_UNITS {
+        let whole = rest / size;
+        rest %= size;
+        if whole != 0 {
+            write!(buf, "{whole}{name}").unwrap();
+            if rest != 0 {
+                buf.push(' ');
+            }
+        }
+    }
+    if rest % 1_000 != 0 {
+        write!(buf, "{rest}ns").unwrap();
+    } else if rest % 1_000_000 != 0 {
+        write!(buf, "{}µs", rest / 1_000).unwrap();
+    } else if rest != 0 {
+        write!(buf, "{}ms", rest / 1_000_000).unwrap();
+    }
+}
+
+/// Write `ns` as an ISO 8601 duration, with a sign for the whole duration.
+fn write_iso_duration(buf: &mut String, ns: i128, precision: DurationPrecision) {
+    if ns == 0 {
+        buf.push_str(if precision == DurationPrecision::Days {
+            "P0D"
+        } else {
+            "PT0S"
+        });
+        return;
+    }
+    if ns < 0 {
+        buf.push('-');
+    }
+    buf.push('P');
+    let mut rest = ns.abs();
+    let days = rest / NS_UNITS[0].0;
+    rest %= NS_UNITS[0].0;
+    if days != 0 {
+        write!(buf, "{days}D").unwrap();
+    }
+    if rest == 0 {
+        return;
+    }
+    buf.push('T');
+    for (size, _, designator) in &NS_UNITS[1..3] {
+        let whole = rest / size;
+        rest %= size;
+        if whole != 0 {
+            write!(buf, "{whole}{designator}").unwrap();
+        }
+    }
+    if rest != 0 {
+        let (seconds, fraction) = (rest / NS_SECOND, rest % NS_SECOND);
+        write!(buf, "{seconds}").unwrap();
+        if fraction != 0 {
+            let fraction = format!("{fraction:09}");
+            write!(buf, ".{}", fraction.trim_end_matches('0')).unwrap();
+        }
+        buf.push('S');
+    }
+}
+
 pub trait DurationMethods {
     /// Extract the hours from a `Duration`
     fn hours(&self) -> Int64Chunked;
@@ -28,6 +168,14 @@ pub trait DurationMethods {
 
     /// Extract the nanoseconds from a `Duration`
     fn nanoseconds(&self) -> Int64Chunked;
+
+    /// Convert a `Duration` into a string in `format`, truncated to `precision` or to the
+    /// time unit if there is no precision.
+    fn duration_to_string(
+        &self,
+        format: DurationFormat,
+        precision: Option<DurationPrecision>,
+    ) -> StringChunked;
 }
 
 impl DurationMethods for DurationChunked {
@@ -105,4 +253,36 @@ impl DurationMethods for DurationChunked {
             TimeUnit::Nanoseconds => self.0.clone(),
         }
     }
+
+    fn duration_to_string(
+        &self,
+        format: DurationFormat,
+        precision: Option<DurationPrecision>,
+    ) -> StringChunked {
+        let unit_ns = DurationPrecision::from(self.time_unit()).nanoseconds();
+        let precision = precision.unwrap_or(self.time_unit().into());
+        let precision_ns = precision.nanoseconds();
+        let write = match format {
+            DurationFormat::Polars => write_polars_duration,
+            DurationFormat::Iso => write_iso_duration,
+        };
+        let mut ca: StringChunked = self.apply_kernel_cast(&|arr| {
+            let mut buf = String::new();
+            let mut mutarr = MutablePlString::with_capacity(arr.len());
+            for opt in arr.into_iter() {
+                match opt {
+                    None => mutarr.push_null(),
+                    Some(v) => {
+                        buf.clear();
+                        let ns = *v as i128 * unit_ns / precision_ns * precision_ns;
+                        write(&mut buf, ns, precision);
+                        mutarr.push_value(&buf)
+                    },
+                }
+            }
+            mutarr.freeze().boxed()
+        });
+        ca.rename(self.name());
+        ca
+    }
 }
@@ -222,57 +222,3 @@ to_temporal_unit!(
     i16,
     ArrowDataType::Int16
 );
-
-#[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_iso_year_ns,
-    iso_year,
-    timestamp_ns_to_datetime_opt,
-    i64,
-    i32,
-    ArrowDataType::Int32
-);
-
-#[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_iso_year_us,
-    iso_year,
-    timestamp_us_to_datetime_opt,
-    i64,
-    i32,
-    ArrowDataType::Int32
-);
-
-#[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_iso_year_ms,
-    iso_year,
-    timestamp_ms_to_datetime_opt,
-    i64,
-    i32,
-    ArrowDataType::Int32
-);
-#[cfg(feature = "dtype-datetime")]
-to_boolean_temporal_unit!(
-    datetime_to_is_leap_year_ns,
-    year,
-    is_leap_year,
-    timestamp_ns_to_datetime_opt,
-    i64
-);
-#[cfg(feature = "dtype-datetime")]
-to_boolean_temporal_unit!(
-    datetime_to_is_leap_year_us,
-    year,
-    is_leap_year,
-    timestamp_us_to_datetime_opt,
-    i64
-);
-#[cfg(feature = "dtype-datetime")]
-to_boolean_temporal_unit!(
-    datetime_to_is_leap_year_ms,
-    year,
-    is_leap_year,
-    timestamp_ms_to_datetime_opt,
-    i64
-);
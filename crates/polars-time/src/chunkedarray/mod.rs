@@ -19,7 +19,7 @@ pub use date::DateMethods;
 #[cfg(feature = "dtype-datetime")]
 pub use datetime::DatetimeMethods;
 #[cfg(feature = "dtype-duration")]
-pub use duration::DurationMethods;
+pub use duration::{DurationFormat, DurationMethods, DurationPrecision};
 use kernels::*;
 use polars_core::prelude::*;
 #[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
@@ -30,7 +30,8 @@ pub use time::TimeMethods;
 
 // a separate function so that it is not compiled twice
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
-pub(crate) fn months_to_quarters(mut ca: Int8Chunked) -> Int8Chunked {
-    ca.apply_mut(|month| (month + 2) / 3);
+pub(crate) fn months_to_quarters(mut ca: Int8Chunked, fiscal_year_start: u8) -> Int8Chunked {
+    let fiscal_year_start = fiscal_year_start as i8;
+    ca.apply_mut(|month| (month + 12 - fiscal_year_start) % 12 / 3 + 1);
     ca
 }
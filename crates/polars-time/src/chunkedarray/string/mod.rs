@@ -1,7 +1,8 @@
 pub mod infer;
-use chrono::DateTime;
 mod patterns;
 mod strptime;
+#[cfg(feature = "timezones")]
+mod tz_aware;
 use chrono::ParseError;
 pub use patterns::Pattern;
 #[cfg(feature = "dtype-time")]
@@ -12,6 +13,8 @@ use super::*;
 #[cfg(feature = "dtype-date")]
 use crate::chunkedarray::date::naive_date_to_date;
 use crate::prelude::string::strptime::StrpTimeState;
+#[cfg(feature = "timezones")]
+use crate::prelude::string::tz_aware::TzAwareFormat;
 
 #[cfg(feature = "dtype-time")]
 fn time_pattern<F, K>(val: &str, convert: F) -> Option<&'static str>
@@ -180,6 +183,9 @@ pub trait StringMethods: AsString {
             TimeUnit::Milliseconds => datetime_to_timestamp_ms,
         };
 
+        #[cfg(feature = "timezones")]
+        let tz_aware_fmt = TzAwareFormat::new(fmt);
+
         let ca = string_ca
             .apply_generic(|opt_s| {
                 let mut s = opt_s?;
@@ -190,12 +196,19 @@ pub trait StringMethods: AsString {
                         return None;
                     }
                     let timestamp = if tz_aware {
-                        DateTime::parse_from_str(s, fmt).map(|dt| func(dt.naive_utc()))
+                        #[cfg(feature = "timezones")]
+                        {
+                            tz_aware_fmt.parse(s).map(|dt| dt.map(|(dt, _)| func(dt)))
+                        }
+                        #[cfg(not(feature = "timezones"))]
+                        {
+                            panic!("activate 'timezones' feature")
+                        }
                     } else {
-                        NaiveDateTime::parse_from_str(s, fmt).map(func)
+                        NaiveDateTime::parse_from_str(s, fmt).map(|dt| Some(func(dt)))
                     };
                     match timestamp {
-                        Ok(ts) => return Some(ts),
+                        Ok(ts) => return ts,
                         Err(e) => {
                             let e: ParseErrorByteCopy = e.into();
                             match e.0 {
@@ -203,7 +216,7 @@ pub trait StringMethods: AsString {
                                     s = &s[..s.len() - 1];
                                 },
                                 _ => {
-                                    s = &s[i..];
+                                    s = s.get(i..)?;
                                 },
                             }
                         },
@@ -295,10 +308,11 @@ pub trait StringMethods: AsString {
         if tz_aware {
             #[cfg(feature = "timezones")]
             {
+                let tz_aware_fmt = TzAwareFormat::new(&fmt);
                 let mut convert = FastCachedFunc::new(
                     |s: &str| {
-                        let dt = DateTime::parse_from_str(s, &fmt).ok()?;
-                        Some(func(dt.naive_utc()))
+                        let (dt, _) = tz_aware_fmt.parse(s).ok()??;
+                        Some(func(dt))
                     },
                     (string_ca.len() as f64).sqrt() as usize,
                 );
@@ -352,6 +366,28 @@ pub trait StringMethods: AsString {
             }
         }
     }
+
+    #[cfg(all(feature = "timezones", feature = "dtype-duration"))]
+    /// Parse the offsets from UTC of string values with a time zone offset (`%z`) or
+    /// name (`%Z`) and return them as a [`DurationChunked`].
+    fn as_utc_offset(&self, fmt: &str, use_cache: bool) -> PolarsResult<DurationChunked> {
+        let string_ca = self.as_string();
+        let fmt = strptime::compile_fmt(fmt)?;
+        let use_cache = use_cache && string_ca.len() > 50;
+
+        let tz_aware_fmt = TzAwareFormat::new(&fmt);
+        let mut convert = FastCachedFunc::new(
+            |s: &str| {
+                let (_, offset) = tz_aware_fmt.parse(s).ok()??;
+                Some(offset as i64 * 1000)
+            },
+            (string_ca.len() as f64).sqrt() as usize,
+        );
+        Ok(string_ca
+            .apply_generic(|opt_s| convert.eval(opt_s?, use_cache))
+            .with_name(string_ca.name())
+            .into_duration(TimeUnit::Milliseconds))
+    }
 }
 
 pub trait AsString {
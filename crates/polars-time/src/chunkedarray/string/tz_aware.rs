@@ -0,0 +1,131 @@
+use arrow::legacy::time_zone::Tz;
+use chrono::format::{parse, parse_and_remainder, Fixed, Item, ParseResult, Parsed, StrftimeItems};
+use chrono::{NaiveDateTime, Offset, TimeDelta, TimeZone};
+
+/// Offsets in seconds east of UTC of the time zone abbreviations of RFC 2822, and of
+/// a few other common abbreviations that only refer to a single time zone.
+const TIME_ZONE_ABBREVIATIONS: &[(&str, i32)] = &[
+    ("Z", 0),
+    ("UT", 0),
+    ("UTC", 0),
+    ("GMT", 0),
+    ("EST", -5 * 3600),
+    ("EDT", -4 * 3600),
+    ("CST", -6 * 3600),
+    ("CDT", -5 * 3600),
+    ("MST", -7 * 3600),
+    ("MDT", -6 * 3600),
+    ("PST", -8 * 3600),
+    ("PDT", -7 * 3600),
+    ("WET", 0),
+    ("WEST", 3600),
+    ("CET", 3600),
+    ("CEST", 2 * 3600),
+    ("EET", 2 * 3600),
+    ("EEST", 3 * 3600),
+    ("JST", 9 * 3600),
+    ("AWST", 8 * 3600),
+    ("ACST", 9 * 3600 + 1800),
+    ("ACDT", 10 * 3600 + 1800),
+    ("AEST", 10 * 3600),
+    ("AEDT", 11 * 3600),
+];
+
+/// The offset from UTC in seconds of the time zone `name` at the local datetime `local`.
+///
+/// `name` is either one of the [`TIME_ZONE_ABBREVIATIONS`] or an IANA time zone name.
+fn time_zone_name_offset(name: &str, local: NaiveDateTime) -> Option<i32> {
+    if let Some((_, offset)) = TIME_ZONE_ABBREVIATIONS
+        .iter()
+        .find(|(abbreviation, _)| abbreviation.eq_ignore_ascii_case(name))
+    {
+        return Some(*offset);
+    }
+    let tz: Tz = name.parse().ok()?;
+    tz.offset_from_local_datetime(&local)
+        .single()
+        .map(|offset| offset.fix().local_minus_utc())
+}
+
+/// A format with a time zone offset (`%z`) or name (`%Z`), parsed once to parse
+/// many values.
+pub(super) struct TzAwareFormat<'a> {
+    /// The items before the time zone name, or all items if there is no name.
+    items: Vec<Item<'a>>,
+    /// The items after the time zone name.
+    name_suffix: Option<Vec<Item<'a>>>,
+}
+
+impl<'a> TzAwareFormat<'a> {
+    pub(super) fn new(fmt: &'a str) -> Self {
+        let mut items: Vec<_> = StrftimeItems::new(fmt).collect();
+        let name_suffix = items
+            .iter()
+            .position(|item| matches!(item, Item::Fixed(Fixed::TimezoneName)))
+            .map(|idx| {
+                let suffix = items.split_off(idx + 1);
+                items.pop();
+                suffix
+            });
+        Self { items, name_suffix }
+    }
+
+    /// Parse `s` into a datetime in UTC and its offset from UTC in seconds.
+    ///
+    /// Returns `Ok(None)` if the time zone name is not known.
+    pub(super) fn parse(&self, s: &str) -> ParseResult<Option<(NaiveDateTime, i32)>> {
+        let mut parsed = Parsed::new();
+        let Some(suffix) = &self.name_suffix else {
+            parse(&mut parsed, s, self.items.iter())?;
+            let dt = parsed.to_datetime()?;
+            return Ok(Some((dt.naive_utc(), dt.offset().local_minus_utc())));
+        };
+        // Like chrono, take the name up to the next whitespace.
+        let rest = parse_and_remainder(&mut parsed, s, self.items.iter())?;
+        let (name, rest) = rest.split_at(rest.find(char::is_whitespace).unwrap_or(rest.len()));
+        parse(&mut parsed, rest, suffix.iter())?;
+
+        // An offset in the string takes precedence over the name.
+        if parsed.offset().is_some() {
+            let dt = parsed.to_datetime()?;
+            return Ok(Some((dt.naive_utc(), dt.offset().local_minus_utc())));
+        }
+        let local = parsed.to_naive_datetime_with_offset(0)?;
+        Ok(time_zone_name_offset(name, local)
+            .map(|offset| (local - TimeDelta::seconds(offset as i64), offset)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn test_tz_aware_format() {
+        let utc = |h, m| {
+            NaiveDate::from_ymd_opt(2024, 7, 1)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap()
+        };
+        let fmt = TzAwareFormat::new("%Y-%m-%d %H:%M %Z");
+        assert_eq!(
+            fmt.parse("2024-07-01 10:00 CEST").unwrap(),
+            Some((utc(8, 0), 7200))
+        );
+        assert_eq!(
+            fmt.parse("2024-07-01 10:00 America/New_York").unwrap(),
+            Some((utc(14, 0), -4 * 3600))
+        );
+        assert_eq!(fmt.parse("2024-07-01 10:00 XYZ").unwrap(), None);
+        assert!(fmt.parse("2024-07-01 CEST").is_err());
+
+        let fmt = TzAwareFormat::new("%Y-%m-%d %H:%M%:z");
+        assert_eq!(
+            fmt.parse("2024-07-01 10:00+05:30").unwrap(),
+            Some((utc(4, 30), 19800))
+        );
+    }
+}
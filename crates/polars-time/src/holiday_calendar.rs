@@ -0,0 +1,102 @@
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The business days of a calendar: the days of the week that are worked, apart from holidays.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HolidayCalendar {
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+}
+
+impl HolidayCalendar {
+    /// Create a calendar from the business days of the week, starting on Monday, and
+    /// holidays as the number of days since the UNIX epoch.
+    pub fn new(week_mask: [bool; 7], mut holidays: Vec<i32>) -> PolarsResult<Self> {
+        polars_ensure!(
+            week_mask.iter().any(|&x| x),
+            ComputeError: "`week_mask` must have at least one business day"
+        );
+        holidays.sort_unstable();
+        holidays.dedup();
+        Ok(Self {
+            week_mask,
+            holidays,
+        })
+    }
+
+    pub fn week_mask(&self) -> [bool; 7] {
+        self.week_mask
+    }
+
+    /// The sorted holidays, as the number of days since the UNIX epoch.
+    pub fn holidays(&self) -> &[i32] {
+        &self.holidays
+    }
+}
+
+impl Default for HolidayCalendar {
+    /// Monday to Friday without holidays.
+    fn default() -> Self {
+        Self {
+            week_mask: [true, true, true, true, true, false, false],
+            holidays: Vec::new(),
+        }
+    }
+}
+
+static HOLIDAY_CALENDARS: Lazy<RwLock<PlHashMap<String, HolidayCalendar>>> =
+    Lazy::new(Default::default);
+
+/// Register `calendar` under `name`, replacing a calendar that was registered under that name.
+pub fn register_holiday_calendar(name: &str, calendar: HolidayCalendar) {
+    let mut calendars = HOLIDAY_CALENDARS.write().unwrap();
+    calendars.insert(name.to_string(), calendar);
+}
+
+/// Remove the calendar registered under `name`, returns whether it was registered.
+pub fn unregister_holiday_calendar(name: &str) -> bool {
+    let mut calendars = HOLIDAY_CALENDARS.write().unwrap();
+    calendars.remove(name).is_some()
+}
+
+/// Get the calendar registered under `name`.
+pub fn get_holiday_calendar(name: &str) -> PolarsResult<HolidayCalendar> {
+    let calendars = HOLIDAY_CALENDARS.read().unwrap();
+    calendars
+        .get(name)
+        .cloned()
+        .ok_or_else(|| polars_err!(ComputeError: "holiday calendar '{}' is not registered", name))
+}
+
+/// The names of the registered calendars, in sorted order.
+pub fn holiday_calendar_names() -> Vec<String> {
+    let calendars = HOLIDAY_CALENDARS.read().unwrap();
+    let mut names: Vec<_> = calendars.keys().cloned().collect();
+    names.sort_unstable();
+    names
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_holiday_calendar_registry() {
+        let calendar = HolidayCalendar::new([true; 7], vec![3, 1, 3]).unwrap();
+        assert_eq!(calendar.holidays(), &[1, 3]);
+
+        register_holiday_calendar("test_registry", calendar.clone());
+        assert_eq!(get_holiday_calendar("test_registry").unwrap(), calendar);
+        assert!(holiday_calendar_names().contains(&"test_registry".to_string()));
+        assert!(unregister_holiday_calendar("test_registry"));
+        assert!(!unregister_holiday_calendar("test_registry"));
+        assert!(get_holiday_calendar("test_registry").is_err());
+
+        assert!(HolidayCalendar::new([false; 7], vec![]).is_err());
+    }
+}
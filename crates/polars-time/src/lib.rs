@@ -4,9 +4,12 @@ pub mod chunkedarray;
 mod date_range;
 mod dst_offset;
 mod group_by;
+mod holiday_calendar;
 mod month_end;
 mod month_start;
 pub mod prelude;
+mod quarter_end;
+mod quarter_start;
 mod round;
 pub mod series;
 mod truncate;
@@ -21,8 +24,11 @@ pub use date_range::*;
 pub use dst_offset::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use group_by::dynamic::*;
+pub use holiday_calendar::*;
 pub use month_end::*;
 pub use month_start::*;
+pub use quarter_end::*;
+pub use quarter_start::*;
 pub use round::*;
 pub use truncate::*;
 pub use upsample::*;
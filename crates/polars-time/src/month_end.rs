@@ -1,13 +1,12 @@
 use arrow::legacy::time_zone::Tz;
-use chrono::NaiveDateTime;
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
 use polars_core::prelude::*;
 use polars_core::utils::arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime, MILLISECONDS,
     SECONDS_IN_DAY,
 };
 
-use crate::month_start::roll_backward;
-use crate::windows::duration::Duration;
+use crate::month_start::with_local_date;
 
 // roll forward to the last day of the month
 fn roll_forward(
@@ -15,11 +14,22 @@ fn roll_forward(
     time_zone: Option<&Tz>,
     timestamp_to_datetime: fn(i64) -> NaiveDateTime,
     datetime_to_timestamp: fn(NaiveDateTime) -> i64,
-    offset_fn: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
 ) -> PolarsResult<i64> {
-    let t = roll_backward(t, time_zone, timestamp_to_datetime, datetime_to_timestamp)?;
-    let t = offset_fn(&Duration::parse("1mo"), t, time_zone)?;
-    offset_fn(&Duration::parse("-1d"), t, time_zone)
+    // The last day of the month is found directly rather than by offsetting the first day of
+    // the next month, whose time-of-day may not exist due to a DST transition.
+    with_local_date(
+        t,
+        time_zone,
+        timestamp_to_datetime,
+        datetime_to_timestamp,
+        |date| {
+            let (year, month) = match date.month() {
+                12 => (date.year() + 1, 1),
+                month => (date.year(), month + 1),
+            };
+            NaiveDate::from_ymd_opt(year, month, 1)?.pred_opt()
+        },
+    )
 }
 
 pub trait PolarsMonthEnd {
@@ -32,34 +42,24 @@ impl PolarsMonthEnd for DatetimeChunked {
     fn month_end(&self, time_zone: Option<&Tz>) -> PolarsResult<Self> {
         let timestamp_to_datetime: fn(i64) -> NaiveDateTime;
         let datetime_to_timestamp: fn(NaiveDateTime) -> i64;
-        let offset_fn: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
         match self.time_unit() {
             TimeUnit::Nanoseconds => {
                 timestamp_to_datetime = timestamp_ns_to_datetime;
                 datetime_to_timestamp = datetime_to_timestamp_ns;
-                offset_fn = Duration::add_ns;
             },
             TimeUnit::Microseconds => {
                 timestamp_to_datetime = timestamp_us_to_datetime;
                 datetime_to_timestamp = datetime_to_timestamp_us;
-                offset_fn = Duration::add_us;
             },
             TimeUnit::Milliseconds => {
                 timestamp_to_datetime = timestamp_ms_to_datetime;
                 datetime_to_timestamp = datetime_to_timestamp_ms;
-                offset_fn = Duration::add_ms;
             },
         };
         Ok(self
             .0
             .try_apply_nonnull_values_generic(|t| {
-                roll_forward(
-                    t,
-                    time_zone,
-                    timestamp_to_datetime,
-                    datetime_to_timestamp,
-                    offset_fn,
-                )
+                roll_forward(t, time_zone, timestamp_to_datetime, datetime_to_timestamp)
             })?
             .into_datetime(self.time_unit(), self.time_zone().clone()))
     }
@@ -74,7 +74,6 @@ impl PolarsMonthEnd for DateChunked {
                 None,
                 timestamp_ms_to_datetime,
                 datetime_to_timestamp_ms,
-                Duration::add_ms,
             )?;
             PolarsResult::Ok((fwd / MSECS_IN_DAY) as i32)
         })?;
@@ -1,5 +1,5 @@
 use arrow::legacy::time_zone::Tz;
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
 use polars_core::prelude::*;
 use polars_core::utils::arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime, MILLISECONDS,
@@ -9,42 +9,23 @@ use polars_core::utils::arrow::temporal_conversions::{
 #[cfg(feature = "timezones")]
 use crate::utils::{try_localize_datetime, unlocalize_datetime};
 
-// roll backward to the first day of the month
-pub(crate) fn roll_backward(
+/// Replace the (local) date of the timestamp `t` with `f(date)`, preserving the time-of-day.
+pub(crate) fn with_local_date(
     t: i64,
     tz: Option<&Tz>,
     timestamp_to_datetime: fn(i64) -> NaiveDateTime,
     datetime_to_timestamp: fn(NaiveDateTime) -> i64,
+    f: impl Fn(NaiveDate) -> Option<NaiveDate>,
 ) -> PolarsResult<i64> {
     let ts = match tz {
         #[cfg(feature = "timezones")]
         Some(tz) => unlocalize_datetime(timestamp_to_datetime(t), tz),
         _ => timestamp_to_datetime(t),
     };
-    let date = NaiveDate::from_ymd_opt(ts.year(), ts.month(), 1).ok_or_else(|| {
-        polars_err!(
-            ComputeError: format!("Could not construct date {}-{}-1", ts.year(), ts.month())
-        )
-    })?;
-    let time = NaiveTime::from_hms_nano_opt(
-        ts.hour(),
-        ts.minute(),
-        ts.second(),
-        ts.and_utc().timestamp_subsec_nanos(),
-    )
-    .ok_or_else(|| {
-        polars_err!(
-            ComputeError:
-                format!(
-                    "Could not construct time {}:{}:{}.{}",
-                    ts.hour(),
-                    ts.minute(),
-                    ts.second(),
-                    ts.and_utc().timestamp_subsec_nanos()
-                )
-        )
+    let date = f(ts.date()).ok_or_else(|| {
+        polars_err!(ComputeError: "could not construct date for month {}-{}", ts.year(), ts.month())
     })?;
-    let ndt = NaiveDateTime::new(date, time);
+    let ndt = NaiveDateTime::new(date, ts.time());
     let t = match tz {
         #[cfg(feature = "timezones")]
         Some(tz) => datetime_to_timestamp(
@@ -56,6 +37,22 @@ pub(crate) fn roll_backward(
     Ok(t)
 }
 
+// roll backward to the first day of the month
+pub(crate) fn roll_backward(
+    t: i64,
+    tz: Option<&Tz>,
+    timestamp_to_datetime: fn(i64) -> NaiveDateTime,
+    datetime_to_timestamp: fn(NaiveDateTime) -> i64,
+) -> PolarsResult<i64> {
+    with_local_date(
+        t,
+        tz,
+        timestamp_to_datetime,
+        datetime_to_timestamp,
+        |date| date.with_day(1),
+    )
+}
+
 pub trait PolarsMonthStart {
     fn month_start(&self, time_zone: Option<&Tz>) -> PolarsResult<Self>
     where
@@ -0,0 +1,71 @@
+use arrow::legacy::time_zone::Tz;
+use chrono::NaiveDateTime;
+use polars_core::prelude::*;
+use polars_core::utils::arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime, MILLISECONDS,
+    SECONDS_IN_DAY,
+};
+
+use crate::month_start::with_local_date;
+use crate::series::ensure_fiscal_year_start;
+use crate::windows::calendar;
+
+pub trait PolarsQuarterStart {
+    /// Roll backward to the first day of the quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn quarter_start(&self, fiscal_year_start: u8, time_zone: Option<&Tz>) -> PolarsResult<Self>
+    where
+        Self: Sized;
+}
+
+impl PolarsQuarterStart for DatetimeChunked {
+    fn quarter_start(&self, fiscal_year_start: u8, tz: Option<&Tz>) -> PolarsResult<Self> {
+        ensure_fiscal_year_start(fiscal_year_start)?;
+        let timestamp_to_datetime: fn(i64) -> NaiveDateTime;
+        let datetime_to_timestamp: fn(NaiveDateTime) -> i64;
+        match self.time_unit() {
+            TimeUnit::Nanoseconds => {
+                timestamp_to_datetime = timestamp_ns_to_datetime;
+                datetime_to_timestamp = datetime_to_timestamp_ns;
+            },
+            TimeUnit::Microseconds => {
+                timestamp_to_datetime = timestamp_us_to_datetime;
+                datetime_to_timestamp = datetime_to_timestamp_us;
+            },
+            TimeUnit::Milliseconds => {
+                timestamp_to_datetime = timestamp_ms_to_datetime;
+                datetime_to_timestamp = datetime_to_timestamp_ms;
+            },
+        };
+        Ok(self
+            .0
+            .try_apply_nonnull_values_generic(|t| {
+                with_local_date(
+                    t,
+                    tz,
+                    timestamp_to_datetime,
+                    datetime_to_timestamp,
+                    |date| calendar::quarter_start(date, fiscal_year_start),
+                )
+            })?
+            .into_datetime(self.time_unit(), self.time_zone().clone()))
+    }
+}
+
+impl PolarsQuarterStart for DateChunked {
+    fn quarter_start(&self, fiscal_year_start: u8, _tz: Option<&Tz>) -> PolarsResult<Self> {
+        ensure_fiscal_year_start(fiscal_year_start)?;
+        const MSECS_IN_DAY: i64 = MILLISECONDS * SECONDS_IN_DAY;
+        let ret = self.0.try_apply_nonnull_values_generic(|t| {
+            let bwd = with_local_date(
+                MSECS_IN_DAY * t as i64,
+                None,
+                timestamp_ms_to_datetime,
+                datetime_to_timestamp_ms,
+                |date| calendar::quarter_start(date, fiscal_year_start),
+            )?;
+            PolarsResult::Ok((bwd / MSECS_IN_DAY) as i32)
+        })?;
+        Ok(ret.into_date())
+    }
+}
@@ -5,20 +5,125 @@ use polars_core::prelude::*;
 use polars_utils::cache::FastFixedCache;
 
 use crate::prelude::*;
+#[cfg(feature = "timezones")]
+use crate::truncate::{apply_ambiguous, truncate_ambiguous};
 
 pub trait PolarsRound {
-    fn round(&self, every: &StringChunked, offset: Duration, tz: Option<&Tz>) -> PolarsResult<Self>
+    /// Round to the nearest boundary of the buckets of size `every`.
+    ///
+    /// The bucket boundaries are shifted by `offset`, and weekly buckets start on `week_start`,
+    /// from 1 (Monday) to 7 (Sunday).
+    ///
+    /// If `ambiguous` is given, then it determines how results which are ambiguous in the time
+    /// zone `tz` are localized, otherwise they are localized using the DST fold of the original
+    /// datetime.
+    fn round(
+        &self,
+        every: &StringChunked,
+        offset: Duration,
+        week_start: u8,
+        tz: Option<&Tz>,
+        ambiguous: Option<&StringChunked>,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
+type AddFn = fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
+
+fn ensure_week_start(week_start: u8) -> PolarsResult<()> {
+    polars_ensure!(
+        (1..=7).contains(&week_start),
+        ComputeError: "`week_start` must be between 1 (Monday) and 7 (Sunday), got {}", week_start
+    );
+    Ok(())
+}
+
+/// Round `t` with `round` to the buckets of `every` anchored on `offset` and `week_start`, by
+/// shifting `t` back by the anchor before rounding and forward again afterwards.
+fn round_anchored<F>(
+    t: i64,
+    every: &Duration,
+    offset: Duration,
+    week_start: u8,
+    tz: Option<&Tz>,
+    add: AddFn,
+    round: F,
+) -> PolarsResult<Option<i64>>
+where
+    F: FnOnce(i64) -> PolarsResult<Option<i64>>,
+{
+    let week_start = if every.weeks_only() {
+        Duration::from_days(week_start as i64 - 1)
+    } else {
+        Duration::from_days(0)
+    };
+    let anchors = [offset, week_start];
+
+    let mut t = t;
+    for anchor in anchors.iter().filter(|anchor| !anchor.is_zero()) {
+        let mut back = *anchor;
+        back.negative = !back.negative;
+        t = add(&back, t, tz)?;
+    }
+    let Some(mut t) = round(t)? else {
+        return Ok(None);
+    };
+    for anchor in anchors.iter().rev().filter(|anchor| !anchor.is_zero()) {
+        t = add(anchor, t, tz)?;
+    }
+    Ok(Some(t))
+}
+
 impl PolarsRound for DatetimeChunked {
     fn round(
         &self,
         every: &StringChunked,
         offset: Duration,
+        week_start: u8,
         tz: Option<&Tz>,
+        _ambiguous: Option<&StringChunked>,
     ) -> PolarsResult<Self> {
+        ensure_week_start(week_start)?;
+        let add = match self.time_unit() {
+            TimeUnit::Nanoseconds => Duration::add_ns,
+            TimeUnit::Microseconds => Duration::add_us,
+            TimeUnit::Milliseconds => Duration::add_ms,
+        };
+
+        // for UTC, nothing is ambiguous
+        #[cfg(feature = "timezones")]
+        if let (Some(tz), Some(ambiguous)) = (tz.filter(|tz| *tz != &chrono_tz::UTC), _ambiguous) {
+            let out = apply_ambiguous(self, every, ambiguous, |timestamp, every, ambiguous| {
+                if every.negative {
+                    polars_bail!(ComputeError: "Cannot round a Datetime to a negative duration")
+                }
+                let half_every = every.duration_ns()
+                    / match self.time_unit() {
+                        TimeUnit::Nanoseconds => 2,
+                        TimeUnit::Microseconds => 2_000,
+                        TimeUnit::Milliseconds => 2_000_000,
+                    };
+                round_anchored(timestamp, &every, offset, week_start, Some(tz), add, |t| {
+                    truncate_ambiguous(
+                        t + half_every,
+                        &every,
+                        &Duration::from_nsecs(0),
+                        tz,
+                        ambiguous,
+                        self.time_unit(),
+                    )
+                })
+            });
+            return Ok(out?.into_datetime(self.time_unit(), self.time_zone().clone()));
+        }
+
+        let func = match self.time_unit() {
+            TimeUnit::Nanoseconds => Window::round_ns,
+            TimeUnit::Microseconds => Window::round_us,
+            TimeUnit::Milliseconds => Window::round_ms,
+        };
+
         let mut duration_cache = FastFixedCache::new((every.len() as f64).sqrt() as usize);
         let out = broadcast_try_binary_elementwise(self, every, |opt_t, opt_every| {
             match (opt_t, opt_every) {
@@ -30,14 +135,10 @@ impl PolarsRound for DatetimeChunked {
                         polars_bail!(ComputeError: "Cannot round a Datetime to a negative duration")
                     }
 
-                    let w = Window::new(every, every, offset);
-
-                    let func = match self.time_unit() {
-                        TimeUnit::Nanoseconds => Window::round_ns,
-                        TimeUnit::Microseconds => Window::round_us,
-                        TimeUnit::Milliseconds => Window::round_ms,
-                    };
-                    func(&w, timestamp, tz).map(Some)
+                    let w = Window::new(every, every, Duration::from_nsecs(0));
+                    round_anchored(timestamp, &every, offset, week_start, tz, add, |t| {
+                        func(&w, t, tz).map(Some)
+                    })
                 },
                 _ => Ok(None),
             }
@@ -51,8 +152,11 @@ impl PolarsRound for DateChunked {
         &self,
         every: &StringChunked,
         offset: Duration,
+        week_start: u8,
         _tz: Option<&Tz>,
+        _ambiguous: Option<&StringChunked>,
     ) -> PolarsResult<Self> {
+        ensure_week_start(week_start)?;
         let mut duration_cache = FastFixedCache::new((every.len() as f64).sqrt() as usize);
         const MSECS_IN_DAY: i64 = MILLISECONDS * SECONDS_IN_DAY;
         let out = broadcast_try_binary_elementwise(&self.0, every, |opt_t, opt_every| {
@@ -64,10 +168,18 @@ impl PolarsRound for DateChunked {
                         polars_bail!(ComputeError: "Cannot round a Date to a negative duration")
                     }
 
-                    let w = Window::new(every, every, offset);
-                    Ok(Some(
-                        (w.round_ms(MSECS_IN_DAY * t as i64, None)? / MSECS_IN_DAY) as i32,
-                    ))
+                    let w = Window::new(every, every, Duration::from_nsecs(0));
+                    let t = MSECS_IN_DAY * t as i64;
+                    let t = round_anchored(
+                        t,
+                        &every,
+                        offset,
+                        week_start,
+                        None,
+                        Duration::add_ms,
+                        |t| w.round_ms(t, None).map(Some),
+                    )?;
+                    Ok(t.map(|t| (t / MSECS_IN_DAY) as i32))
                 },
                 _ => Ok(None),
             }
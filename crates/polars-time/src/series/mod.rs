@@ -14,6 +14,15 @@ impl AsSeries for Series {
     }
 }
 
+pub(crate) fn ensure_fiscal_year_start(fiscal_year_start: u8) -> PolarsResult<()> {
+    polars_ensure!(
+        (1..=12).contains(&fiscal_year_start),
+        InvalidOperation: "`fiscal_year_start` must be a month from 1 to 12, got {}",
+        fiscal_year_start
+    );
+    Ok(())
+}
+
 pub trait TemporalMethods: AsSeries {
     /// Extract hour from underlying NaiveDateTime representation.
     /// Returns the hour number from 0 to 23.
@@ -198,15 +207,99 @@ pub trait TemporalMethods: AsSeries {
         }
     }
 
+    /// Returns whether the date is the first day of a month.
+    fn is_month_start(&self) -> PolarsResult<BooleanChunked> {
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => s.date().map(|ca| ca.is_month_start()),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => s.datetime().map(|ca| ca.is_month_start()),
+            dt => polars_bail!(opq = is_month_start, dt),
+        }
+    }
+
+    /// Returns whether the date is the last day of a month.
+    fn is_month_end(&self) -> PolarsResult<BooleanChunked> {
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => s.date().map(|ca| ca.is_month_end()),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => s.datetime().map(|ca| ca.is_month_end()),
+            dt => polars_bail!(opq = is_month_end, dt),
+        }
+    }
+
+    /// Returns whether the date is the first day of a quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_quarter_start(&self, fiscal_year_start: u8) -> PolarsResult<BooleanChunked> {
+        ensure_fiscal_year_start(fiscal_year_start)?;
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => s.date().map(|ca| ca.is_quarter_start(fiscal_year_start)),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => s
+                .datetime()
+                .map(|ca| ca.is_quarter_start(fiscal_year_start)),
+            dt => polars_bail!(opq = is_quarter_start, dt),
+        }
+    }
+
+    /// Returns whether the date is the last day of a quarter.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_quarter_end(&self, fiscal_year_start: u8) -> PolarsResult<BooleanChunked> {
+        ensure_fiscal_year_start(fiscal_year_start)?;
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => s.date().map(|ca| ca.is_quarter_end(fiscal_year_start)),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => s.datetime().map(|ca| ca.is_quarter_end(fiscal_year_start)),
+            dt => polars_bail!(opq = is_quarter_end, dt),
+        }
+    }
+
+    /// Returns whether the date is the first day of a year.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_year_start(&self, fiscal_year_start: u8) -> PolarsResult<BooleanChunked> {
+        ensure_fiscal_year_start(fiscal_year_start)?;
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => s.date().map(|ca| ca.is_year_start(fiscal_year_start)),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => s.datetime().map(|ca| ca.is_year_start(fiscal_year_start)),
+            dt => polars_bail!(opq = is_year_start, dt),
+        }
+    }
+
+    /// Returns whether the date is the last day of a year.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
+    fn is_year_end(&self, fiscal_year_start: u8) -> PolarsResult<BooleanChunked> {
+        ensure_fiscal_year_start(fiscal_year_start)?;
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => s.date().map(|ca| ca.is_year_end(fiscal_year_start)),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => s.datetime().map(|ca| ca.is_year_end(fiscal_year_start)),
+            dt => polars_bail!(opq = is_year_end, dt),
+        }
+    }
+
     /// Extract quarter from underlying NaiveDateTime representation.
+    /// The (fiscal) year starts in month `fiscal_year_start`.
     /// Quarters range from 1 to 4.
-    fn quarter(&self) -> PolarsResult<Int8Chunked> {
+    fn quarter(&self, fiscal_year_start: u8) -> PolarsResult<Int8Chunked> {
+        ensure_fiscal_year_start(fiscal_year_start)?;
         let s = self.as_series();
         match s.dtype() {
             #[cfg(feature = "dtype-date")]
-            DataType::Date => s.date().map(|ca| ca.quarter()),
+            DataType::Date => s.date().map(|ca| ca.quarter(fiscal_year_start)),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime(_, _) => s.datetime().map(|ca| ca.quarter()),
+            DataType::Datetime(_, _) => s.datetime().map(|ca| ca.quarter(fiscal_year_start)),
             dt => polars_bail!(opq = quarter, dt),
         }
     }
@@ -243,6 +336,24 @@ pub trait TemporalMethods: AsSeries {
         }
     }
 
+    /// Convert Date/Datetime into String with the given format, with the names of months and
+    /// weekdays in the language of `locale`.
+    #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+    fn to_string_localized(&self, format: &str, locale: Locale) -> PolarsResult<Series> {
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => s
+                .date()
+                .map(|ca| ca.to_string_localized(format, locale).into_series()),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => s
+                .datetime()
+                .map(|ca| Ok(ca.to_string_localized(format, locale)?.into_series()))?,
+            dt => polars_bail!(opq = to_string, dt),
+        }
+    }
+
     /// Convert from Time into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     ///
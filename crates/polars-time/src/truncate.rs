@@ -1,21 +1,143 @@
+#[cfg(feature = "timezones")]
+use std::str::FromStr;
+
+#[cfg(feature = "timezones")]
+use arrow::legacy::kernels::{Ambiguous, NonExistent};
 use arrow::legacy::time_zone::Tz;
+#[cfg(feature = "timezones")]
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
 use arrow::temporal_conversions::{MILLISECONDS, SECONDS_IN_DAY};
+#[cfg(feature = "timezones")]
+use chrono::NaiveDateTime;
 use polars_core::prelude::arity::broadcast_try_binary_elementwise;
+#[cfg(feature = "timezones")]
+use polars_core::prelude::arity::try_ternary_elementwise;
 use polars_core::prelude::*;
 use polars_utils::cache::FastFixedCache;
 
 use crate::prelude::*;
+#[cfg(feature = "timezones")]
+use crate::utils::{try_localize_datetime, unlocalize_datetime};
 
 pub trait PolarsTruncate {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked, offset: &str) -> PolarsResult<Self>
+    /// Truncate to the start of the buckets of size `every`.
+    ///
+    /// If `ambiguous` is given, then it determines how results which are ambiguous in the time
+    /// zone `tz` are localized, otherwise they are localized using the DST fold of the original
+    /// datetime.
+    fn truncate(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        offset: &str,
+        ambiguous: Option<&StringChunked>,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
+/// Truncate `t` by `every` in the local time of `tz`, and localize the result using `ambiguous`
+/// instead of the DST fold of `t`.
+#[cfg(feature = "timezones")]
+pub(crate) fn truncate_ambiguous(
+    t: i64,
+    every: &Duration,
+    offset: &Duration,
+    tz: &Tz,
+    ambiguous: Ambiguous,
+    tu: TimeUnit,
+) -> PolarsResult<Option<i64>> {
+    type OffsetFn = fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
+    let (truncate, add): (OffsetFn, OffsetFn) = match tu {
+        TimeUnit::Nanoseconds => (Duration::truncate_ns, Duration::add_ns),
+        TimeUnit::Microseconds => (Duration::truncate_us, Duration::add_us),
+        TimeUnit::Milliseconds => (Duration::truncate_ms, Duration::add_ms),
+    };
+    let timestamp_to_datetime: fn(i64) -> NaiveDateTime = match tu {
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+        TimeUnit::Microseconds => timestamp_us_to_datetime,
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+    };
+    let datetime_to_timestamp: fn(NaiveDateTime) -> i64 = match tu {
+        TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+        TimeUnit::Microseconds => datetime_to_timestamp_us,
+        TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+    };
+    let t_local = datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
+    let result_local = timestamp_to_datetime(truncate(every, t_local, None)?);
+    match try_localize_datetime(result_local, tz, ambiguous, NonExistent::Raise)? {
+        Some(result) => add(offset, datetime_to_timestamp(result), Some(tz)).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Apply `op` to the timestamps of `ca` with the corresponding `every` and `ambiguous`, both of
+/// which may be of length 1.
+#[cfg(feature = "timezones")]
+pub(crate) fn apply_ambiguous<F>(
+    ca: &DatetimeChunked,
+    every: &StringChunked,
+    ambiguous: &StringChunked,
+    mut op: F,
+) -> PolarsResult<Int64Chunked>
+where
+    F: FnMut(i64, Duration, Ambiguous) -> PolarsResult<Option<i64>>,
+{
+    let broadcast = |other: &StringChunked, name: &str| {
+        polars_ensure!(
+            other.len() == ca.len() || other.len() == 1,
+            ShapeMismatch: "expected `{}` of length 1 or {}, got {}", name, ca.len(), other.len()
+        );
+        Ok(if other.len() == 1 && ca.len() != 1 {
+            other.new_from_index(0, ca.len())
+        } else {
+            other.clone()
+        })
+    };
+    let every = broadcast(every, "every")?;
+    let ambiguous = broadcast(ambiguous, "ambiguous")?;
+
+    // A sqrt(n) cache is not too small, not too large.
+    let mut duration_cache = FastFixedCache::new((every.len() as f64).sqrt() as usize);
+    try_ternary_elementwise(
+        ca,
+        &every,
+        &ambiguous,
+        |opt_timestamp, opt_every, opt_ambiguous| match (opt_timestamp, opt_every, opt_ambiguous) {
+            (Some(timestamp), Some(every), Some(ambiguous)) => {
+                let every =
+                    *duration_cache.get_or_insert_with(every, |every| Duration::parse(every));
+                op(timestamp, every, Ambiguous::from_str(ambiguous)?)
+            },
+            _ => Ok(None),
+        },
+    )
+}
+
 impl PolarsTruncate for DatetimeChunked {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked, offset: &str) -> PolarsResult<Self> {
+    fn truncate(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        offset: &str,
+        _ambiguous: Option<&StringChunked>,
+    ) -> PolarsResult<Self> {
         let offset = Duration::parse(offset);
 
+        // for UTC, nothing is ambiguous
+        #[cfg(feature = "timezones")]
+        if let (Some(tz), Some(ambiguous)) = (tz.filter(|tz| *tz != &chrono_tz::UTC), _ambiguous) {
+            let out = apply_ambiguous(self, every, ambiguous, |timestamp, every, ambiguous| {
+                if every.negative {
+                    polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
+                }
+                truncate_ambiguous(timestamp, &every, &offset, tz, ambiguous, self.time_unit())
+            });
+            return Ok(out?.into_datetime(self.time_unit(), self.time_zone().clone()));
+        }
+
         let func = match self.time_unit() {
             TimeUnit::Nanoseconds => Window::truncate_ns,
             TimeUnit::Microseconds => Window::truncate_us,
@@ -51,6 +173,7 @@ impl PolarsTruncate for DateChunked {
         _tz: Option<&Tz>,
         every: &StringChunked,
         offset: &str,
+        _ambiguous: Option<&StringChunked>,
     ) -> PolarsResult<Self> {
         let offset = Duration::parse(offset);
         // A sqrt(n) cache is not too small, not too large.
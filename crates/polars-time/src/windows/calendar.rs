@@ -1,3 +1,5 @@
+use chrono::{Datelike, Months, NaiveDate};
+
 pub(crate) const DAYS_PER_MONTH: [[i64; 12]; 2] = [
     //J   F   M   A   M   J   J   A   S   O   N   D
     [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31], // non-leap year
@@ -7,6 +9,50 @@ pub(crate) const DAYS_PER_MONTH: [[i64; 12]; 2] = [
 pub(crate) const fn is_leap_year(year: i32) -> bool {
     year % 400 == 0 || (year % 4 == 0 && year % 100 != 0)
 }
+
+pub(crate) fn is_month_start(date: NaiveDate) -> bool {
+    date.day() == 1
+}
+
+pub(crate) fn is_month_end(date: NaiveDate) -> bool {
+    date.succ_opt().map_or(true, |next| next.day() == 1)
+}
+
+/// The zero-based month of `date` in a fiscal year that starts in month `fiscal_year_start`.
+fn fiscal_month0(date: NaiveDate, fiscal_year_start: u8) -> u32 {
+    (date.month0() + 13 - fiscal_year_start as u32) % 12
+}
+
+pub(crate) fn is_quarter_start(date: NaiveDate, fiscal_year_start: u8) -> bool {
+    is_month_start(date) && fiscal_month0(date, fiscal_year_start) % 3 == 0
+}
+
+pub(crate) fn is_quarter_end(date: NaiveDate, fiscal_year_start: u8) -> bool {
+    is_month_end(date) && fiscal_month0(date, fiscal_year_start) % 3 == 2
+}
+
+pub(crate) fn is_year_start(date: NaiveDate, fiscal_year_start: u8) -> bool {
+    is_month_start(date) && fiscal_month0(date, fiscal_year_start) == 0
+}
+
+pub(crate) fn is_year_end(date: NaiveDate, fiscal_year_start: u8) -> bool {
+    is_month_end(date) && fiscal_month0(date, fiscal_year_start) == 11
+}
+
+/// The first day of the quarter of `date`.
+pub(crate) fn quarter_start(date: NaiveDate, fiscal_year_start: u8) -> Option<NaiveDate> {
+    let months = fiscal_month0(date, fiscal_year_start) % 3;
+    date.with_day(1)?.checked_sub_months(Months::new(months))
+}
+
+/// The last day of the quarter of `date`.
+pub(crate) fn quarter_end(date: NaiveDate, fiscal_year_start: u8) -> Option<NaiveDate> {
+    let months = 3 - fiscal_month0(date, fiscal_year_start) % 3;
+    date.with_day(1)?
+        .checked_add_months(Months::new(months))?
+        .pred_opt()
+}
+
 /// nanoseconds per unit
 pub const NS_MICROSECOND: i64 = 1_000;
 pub const NS_MILLISECOND: i64 = 1_000_000;
@@ -136,14 +136,23 @@ impl Duration {
     /// and "calendar year".
     ///
     /// # Panics
-    /// If the given str is invalid for any reason.
+    /// If the given str is invalid for any reason. See [`Duration::try_parse`] for a fallible
+    /// version.
     pub fn parse(duration: &str) -> Self {
+        Self::try_parse(duration).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Parse a string into a `Duration`, like [`Duration::parse`], but return an error if the
+    /// given str is invalid.
+    pub fn try_parse(duration: &str) -> PolarsResult<Self> {
         let num_minus_signs = duration.matches('-').count();
         if num_minus_signs > 1 {
-            panic!("a Duration string can only have a single minus sign")
+            polars_bail!(InvalidOperation: "a Duration string can only have a single minus sign")
         }
         if (num_minus_signs > 0) & !duration.starts_with('-') {
-            panic!("only a single minus sign is allowed, at the front of the string")
+            polars_bail!(
+                InvalidOperation: "only a single minus sign is allowed, at the front of the string"
+            )
         }
 
         let mut nsecs = 0;
@@ -165,9 +174,12 @@ impl Duration {
         let mut unit = String::with_capacity(2);
         while let Some((i, mut ch)) = iter.next() {
             if !ch.is_ascii_digit() {
-                let n = duration[start..i]
-                    .parse::<i64>()
-                    .expect("expected an integer in the duration string");
+                let Ok(n) = duration[start..i].parse::<i64>() else {
+                    polars_bail!(
+                        InvalidOperation: "expected an integer in the duration string, got {}",
+                        duration
+                    )
+                };
 
                 loop {
                     if ch.is_ascii_alphabetic() {
@@ -186,7 +198,7 @@ impl Duration {
                     }
                 }
                 if unit.is_empty() {
-                    panic!("expected a unit in the duration string")
+                    polars_bail!(InvalidOperation: "expected a unit in the duration string")
                 }
 
                 match &*unit {
@@ -198,29 +210,29 @@ impl Duration {
                     "h" => nsecs += n * NS_HOUR,
                     "d" => days += n,
                     "w" => weeks += n,
-                    "mo" => {
-                        months += n
-                    }
+                    "mo" => months += n,
                     "q" => months += n * 3,
                     "y" => months += n * 12,
                     // we will read indexes as nanoseconds
                     "i" => {
                         nsecs += n;
                         parsed_int = true;
-                    }
-                    unit => panic!("unit: '{unit}' not supported. Available units are: 'ns', 'us', 'ms', 's', 'm', 'h', 'd', 'w', 'q', 'mo', 'y', 'i'"),
+                    },
+                    unit => {
+                        polars_bail!(InvalidOperation: "unit: '{unit}' not supported. Available units are: 'ns', 'us', 'ms', 's', 'm', 'h', 'd', 'w', 'q', 'mo', 'y', 'i'")
+                    },
                 }
                 unit.clear();
             }
         }
-        Duration {
+        Ok(Duration {
             nsecs: nsecs.abs(),
             days: days.abs(),
             weeks: weeks.abs(),
             months: months.abs(),
             negative,
             parsed_int,
-        }
+        })
     }
 
     fn to_positive(v: i64) -> (bool, i64) {
@@ -277,7 +289,7 @@ impl Duration {
     }
 
     /// Creates a [`Duration`] that represents a fixed number of nanoseconds.
-    pub(crate) fn from_nsecs(v: i64) -> Self {
+    pub fn from_nsecs(v: i64) -> Self {
         let (negative, nsecs) = Self::to_positive(v);
         Self {
             months: 0,
@@ -409,7 +421,12 @@ impl Duration {
     }
 
     #[doc(hidden)]
-    fn add_month(ts: NaiveDateTime, n_months: i64, negative: bool) -> NaiveDateTime {
+    fn add_month(
+        ts: NaiveDateTime,
+        n_months: i64,
+        negative: bool,
+        end_of_month: bool,
+    ) -> NaiveDateTime {
         let mut months = n_months;
         if negative {
             months = -months;
@@ -420,6 +437,8 @@ impl Duration {
         let mut year = ts.year();
         let mut month = ts.month() as i32;
         let mut day = ts.day();
+        let is_end_of_month =
+            day == DAYS_PER_MONTH[is_leap_year(year) as usize][(month - 1) as usize] as u32;
         year += (months / 12) as i32;
         month += (months % 12) as i32;
 
@@ -438,7 +457,7 @@ impl Duration {
         let last_day_of_month =
             DAYS_PER_MONTH[is_leap_year(year) as usize][(month - 1) as usize] as u32;
 
-        if day > last_day_of_month {
+        if day > last_day_of_month || (end_of_month && is_end_of_month) {
             day = last_day_of_month
         }
 
@@ -797,6 +816,7 @@ impl Duration {
         &self,
         t: i64,
         tz: Option<&Tz>,
+        end_of_month: bool,
         nsecs_to_unit: F,
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
@@ -818,7 +838,7 @@ impl Duration {
                 },
                 _ => timestamp_to_datetime(t),
             };
-            let dt = Self::add_month(ts, d.months, d.negative);
+            let dt = Self::add_month(ts, d.months, d.negative, end_of_month);
             new_t = match tz {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
@@ -836,8 +856,10 @@ impl Duration {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
                 Some(tz) if tz != &chrono_tz::UTC => {
-                    new_t =
-                        datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
+                    new_t = datetime_to_timestamp(unlocalize_datetime(
+                        timestamp_to_datetime(new_t),
+                        tz,
+                    ));
                     new_t += if d.negative { -t_weeks } else { t_weeks };
                     new_t = datetime_to_timestamp(
                         try_localize_datetime(
@@ -859,8 +881,10 @@ impl Duration {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
                 Some(tz) if tz != &chrono_tz::UTC => {
-                    new_t =
-                        datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
+                    new_t = datetime_to_timestamp(unlocalize_datetime(
+                        timestamp_to_datetime(new_t),
+                        tz,
+                    ));
                     new_t += if d.negative { -t_days } else { t_days };
                     new_t = datetime_to_timestamp(
                         try_localize_datetime(
@@ -880,10 +904,21 @@ impl Duration {
     }
 
     pub fn add_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.add_ns_impl(t, tz, false)
+    }
+
+    /// Like [`Duration::add_ns`], but a `t` on the last day of its month stays on the last
+    /// day of the month when months are added.
+    pub fn add_ns_end_of_month(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.add_ns_impl(t, tz, true)
+    }
+
+    fn add_ns_impl(&self, t: i64, tz: Option<&Tz>, end_of_month: bool) -> PolarsResult<i64> {
         let d = self;
         let new_t = self.add_impl_month_week_or_day(
             t,
             tz,
+            end_of_month,
             |nsecs| nsecs,
             timestamp_ns_to_datetime,
             datetime_to_timestamp_ns,
@@ -893,10 +928,21 @@ impl Duration {
     }
 
     pub fn add_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.add_us_impl(t, tz, false)
+    }
+
+    /// Like [`Duration::add_us`], but a `t` on the last day of its month stays on the last
+    /// day of the month when months are added.
+    pub fn add_us_end_of_month(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.add_us_impl(t, tz, true)
+    }
+
+    fn add_us_impl(&self, t: i64, tz: Option<&Tz>, end_of_month: bool) -> PolarsResult<i64> {
         let d = self;
         let new_t = self.add_impl_month_week_or_day(
             t,
             tz,
+            end_of_month,
             |nsecs| nsecs / 1000,
             timestamp_us_to_datetime,
             datetime_to_timestamp_us,
@@ -906,10 +952,21 @@ impl Duration {
     }
 
     pub fn add_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.add_ms_impl(t, tz, false)
+    }
+
+    /// Like [`Duration::add_ms`], but a `t` on the last day of its month stays on the last
+    /// day of the month when months are added.
+    pub fn add_ms_end_of_month(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.add_ms_impl(t, tz, true)
+    }
+
+    fn add_ms_impl(&self, t: i64, tz: Option<&Tz>, end_of_month: bool) -> PolarsResult<i64> {
         let d = self;
         let new_t = self.add_impl_month_week_or_day(
             t,
             tz,
+            end_of_month,
             |nsecs| nsecs / 1_000_000,
             timestamp_ms_to_datetime,
             datetime_to_timestamp_ms,
@@ -1030,6 +1087,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_add_ms_end_of_month() {
+        let ms = |y, m, d| {
+            datetime_to_timestamp_ms(
+                NaiveDate::from_ymd_opt(y, m, d)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+            )
+        };
+        let one_month = Duration::parse("1mo");
+        assert_eq!(
+            one_month.add_ms(ms(2024, 2, 29), None).unwrap(),
+            ms(2024, 3, 29)
+        );
+        assert_eq!(
+            one_month
+                .add_ms_end_of_month(ms(2024, 2, 29), None)
+                .unwrap(),
+            ms(2024, 3, 31)
+        );
+        // Days other than the last day of the month are not moved.
+        assert_eq!(
+            one_month
+                .add_ms_end_of_month(ms(2024, 2, 28), None)
+                .unwrap(),
+            ms(2024, 3, 28)
+        );
+        assert_eq!(
+            Duration::parse("-1mo")
+                .add_ms_end_of_month(ms(2024, 4, 30), None)
+                .unwrap(),
+            ms(2024, 3, 31)
+        );
+    }
+
     #[test]
     fn test_display() {
         let duration = Duration::parse("1h");
@@ -186,8 +186,13 @@
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
-//! * `strings` - Extra string utilities for [`StringChunked`] //!     - `string_pad` - `zfill`, `ljust`, `rjust`
+//! * `strings` - Extra string utilities for [`StringChunked`]
+//!     - `string_normalize` - `normalize`
+//!     - `string_pad` - `zfill`, `ljust`, `rjust`
+//!     - `string_similarity` - `levenshtein`, `hamming`, `jaro_winkler`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_to_titlecase` - `to_titlecase`
+//!     - `string_wrap` - `wrap`
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -198,8 +203,12 @@
 //!                  Polars will be a bit slower with this feature activated as many data structures
 //!                  are less cache efficient.
 //!     - `cse` - Activate common subplan elimination optimization
+//!     - `tracing` - Execute scans, joins, group-bys, streaming pipelines, sinks and optimization
+//!                   passes in [tracing](https://crates.io/crates/tracing) spans, with the number
+//!                   of rows and estimated bytes they output attached as span fields.
 //! * IO related:
-//!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
+//!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization
+//!                 of `DataFrame`, `Series` and `Schema`.
 //!                 Can be used for JSON and more serde supported serialization formats.
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
@@ -241,6 +250,9 @@
 //!     - `dot_product` - Dot/inner product on [`Series`] and [`Expr`].
 //!     - `concat_str` - Concat string data in linear time.
 //!     - `reinterpret` - Utility to reinterpret bits to signed/unsigned
+//!     - `bitwise` - Bitwise shifts and bitwise aggregations of integer and boolean data.
+//!     - `hash_digest` - md5, sha1, sha256 and crc32 digests of string and binary data.
+//!     - `hash_stable` - Hashes that are stable across versions and platforms.
 //!     - `take_opt_iter` - Take from a [`Series`] with [`Iterator<Item=Option<usize>>`](std::iter::Iterator).
 //!     - `mode` - [Return the most occurring value(s)](polars_ops::chunked_array::mode)
 //!     - `cum_agg` - [`cum_sum`], [`cum_min`], [`cum_max`] aggregation.
@@ -420,7 +432,9 @@ pub use polars_core::{
     testing,
 };
 #[cfg(feature = "dtype-categorical")]
-pub use polars_core::{enable_string_cache, using_string_cache};
+pub use polars_core::{
+    disable_string_cache, enable_string_cache, using_string_cache, with_string_cache,
+};
 #[cfg(feature = "polars-io")]
 pub use polars_io as io;
 #[cfg(feature = "lazy")]
@@ -309,7 +309,7 @@ fn test_join_categorical() {
         );
     }
 
-    // Test error when joining on different string cache
+    // Test joining on categoricals from different string caches
     let (mut df_a, mut df_b) = get_dfs();
     df_a.try_apply("b", |s| {
         s.cast(&DataType::Categorical(None, Default::default()))
@@ -324,8 +324,97 @@ fn test_join_categorical() {
         s.cast(&DataType::Categorical(None, Default::default()))
     })
     .unwrap();
-    let out = df_a.join(&df_b, ["b"], ["bar"], JoinType::Left.into());
-    assert!(out.is_err());
+    let out = df_a
+        .join(&df_b, ["b"], ["bar"], JoinType::Left.into())
+        .unwrap();
+    let ham_col = out.column("ham").unwrap();
+    assert_eq!(Vec::from(ham_col.str().unwrap()), correct_ham);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "dtype-categorical")]
+fn test_join_categorical_different_sources() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock();
+    disable_string_cache();
+    let cat_dtype = DataType::Categorical(None, Default::default());
+
+    let mut df_a = df![
+        "key" => [Some("a"), Some("b"), None, Some("c"), Some("b")],
+        "val_a" => [1, 2, 3, 4, 5],
+    ]?;
+    df_a.try_apply("key", |s| s.cast(&cat_dtype))?;
+    let mut df_b = {
+        let _sc = StringCacheHolder::hold();
+        let mut df_b = df![
+            "key" => ["d", "b", "a", "e"],
+            "val_b" => [10, 20, 30, 40],
+        ]?;
+        df_b.try_apply("key", |s| s.cast(&cat_dtype))?;
+        df_b
+    };
+
+    let out = df_a.join(&df_b, ["key"], ["key"], JoinType::Inner.into())?;
+    let out = out.sort(["val_a"], Default::default())?;
+    assert_eq!(
+        Vec::from(out.column("val_a")?.i32()?),
+        [Some(1), Some(2), Some(5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("val_b")?.i32()?),
+        [Some(30), Some(20), Some(20)]
+    );
+    assert_eq!(out.column("key")?.dtype(), &cat_dtype);
+
+    let out = df_a.join(&df_b, ["key"], ["key"], JoinType::Left.into())?;
+    assert_eq!(
+        Vec::from(out.column("val_b")?.i32()?),
+        [Some(30), Some(20), None, None, Some(20)]
+    );
+
+    #[cfg(feature = "semi_anti_join")]
+    {
+        let out = df_a.join(&df_b, ["key"], ["key"], JoinType::Semi.into())?;
+        assert_eq!(
+            Vec::from(out.column("val_a")?.i32()?),
+            [Some(1), Some(2), Some(5)]
+        );
+        let out = df_a.join(&df_b, ["key"], ["key"], JoinType::Anti.into())?;
+        assert_eq!(Vec::from(out.column("val_a")?.i32()?), [Some(3), Some(4)]);
+    }
+
+    // Categories that only occur on the right side stay distinct, so validation passes.
+    let mut df_c = df!["key" => ["a", "b"]]?;
+    df_c.try_apply("key", |s| s.cast(&cat_dtype))?;
+    let mut df_d = {
+        let _sc = StringCacheHolder::hold();
+        let mut df_d = df!["key" => ["a", "d", "e"], "val_d" => [1, 2, 3]]?;
+        df_d.try_apply("key", |s| s.cast(&cat_dtype))?;
+        df_d
+    };
+    for validation in [JoinValidation::OneToOne, JoinValidation::ManyToOne] {
+        let mut args = JoinArgs::new(JoinType::Left);
+        args.validation = validation;
+        let out = df_c.join(&df_d, ["key"], ["key"], args)?;
+        assert_eq!(Vec::from(out.column("val_d")?.i32()?), [Some(1), None]);
+    }
+    df_d.try_apply("key", |s| s.cast(&DataType::String))?;
+    df_d.try_apply("key", |s| s.cast(&cat_dtype))?;
+    let mut args = JoinArgs::new(JoinType::Inner);
+    args.validation = JoinValidation::OneToOne;
+    let out = df_c.join(&df_d, ["key"], ["key"], args)?;
+    assert_eq!(Vec::from(out.column("val_d")?.i32()?), [Some(1)]);
+
+    // Join the other way around, with local categoricals on both sides.
+    df_b.try_apply("key", |s| s.cast(&DataType::String))?;
+    df_b.try_apply("key", |s| s.cast(&cat_dtype))?;
+    let out = df_b.join(&df_a, ["key"], ["key"], JoinType::Inner.into())?;
+    let out = out.sort(["val_a"], Default::default())?;
+    assert_eq!(
+        Vec::from(out.column("val_b")?.i32()?),
+        [Some(30), Some(20), Some(20)]
+    );
+    Ok(())
 }
 
 #[test]
@@ -135,12 +135,14 @@ from polars.functions import (
     duration,
     element,
     exclude,
+    field,
     first,
     fold,
     format,
     from_epoch,
     groups,
     head,
+    holiday_calendars,
     implode,
     int_range,
     int_ranges,
@@ -162,6 +164,7 @@ from polars.functions import (
     ones,
     quantile,
     reduce,
+    register_holiday_calendar,
     repeat,
     rolling_corr,
     rolling_cov,
@@ -176,6 +179,7 @@ from polars.functions import (
     time,
     time_range,
     time_ranges,
+    unregister_holiday_calendar,
     var,
     when,
     zeros,
@@ -339,10 +343,13 @@ __all__ = [
     "datetime_range",
     "datetime_ranges",
     "element",
+    "holiday_calendars",
     "ones",
+    "register_holiday_calendar",
     "repeat",
     "time_range",
     "time_ranges",
+    "unregister_holiday_calendar",
     "zeros",
     # polars.functions.aggregation
     "all",
@@ -385,6 +392,7 @@ __all__ = [
     "datetime",  # named datetime_, see import above
     "duration",
     "exclude",
+    "field",
     "first",
     "fold",
     "format",
@@ -161,6 +161,7 @@ if TYPE_CHECKING:
         SizeUnit,
         StartBy,
         UniqueKeepStrategy,
+        UnnestNaming,
         UnstackDirection,
     )
 
@@ -10450,6 +10451,8 @@ class DataFrame:
         self,
         columns: ColumnNameOrSelector | Collection[ColumnNameOrSelector],
         *more_columns: ColumnNameOrSelector,
+        naming: UnnestNaming | Callable[[str, str], str] = "field",
+        separator: str = "_",
     ) -> Self:
         """
         Decompose struct columns into separate columns for each of their fields.
@@ -10463,6 +10466,19 @@ class DataFrame:
             Name of the struct column(s) that should be unnested.
         *more_columns
             Additional columns to unnest, specified as positional arguments.
+        naming : {'field', 'prefix', 'suffix'} or function
+            How to name the new columns:
+
+            - 'field': name the columns after the fields of the struct.
+            - 'prefix': prefix the field names with the name of the struct column.
+            - 'suffix': suffix the field names with the name of the struct column.
+
+            Alternatively, a function that takes the name of the struct column and of
+            a field, and returns the name of the new column. This allows unnesting
+            structs that share field names.
+        separator
+            The separator between the names of the struct column and the field, if
+            `naming` is 'prefix' or 'suffix'.
 
         Examples
         --------
@@ -10496,8 +10512,30 @@ class DataFrame:
         │ foo    ┆ 1   ┆ a   ┆ true ┆ [1, 2]    ┆ baz   │
         │ bar    ┆ 2   ┆ b   ┆ null ┆ [3]       ┆ womp  │
         └────────┴─────┴─────┴──────┴───────────┴───────┘
+
+        Structs that share field names can be unnested by prefixing the new columns
+        with the name of the struct column:
+
+        >>> df = pl.DataFrame(
+        ...     {"a": [{"id": 1, "x": 2}], "b": [{"id": 3, "y": 4}]}
+        ... )
+        >>> df.unnest("a", "b", naming="prefix")
+        shape: (1, 4)
+        ┌──────┬─────┬──────┬─────┐
+        │ a_id ┆ a_x ┆ b_id ┆ b_y │
+        │ ---  ┆ --- ┆ ---  ┆ --- │
+        │ i64  ┆ i64 ┆ i64  ┆ i64 │
+        ╞══════╪═════╪══════╪═════╡
+        │ 1    ┆ 2   ┆ 3    ┆ 4   │
+        └──────┴─────┴──────┴─────┘
         """
         columns = _expand_selectors(self, columns, *more_columns)
+        if naming != "field":
+            return (
+                self.lazy()
+                .unnest(columns, naming=naming, separator=separator)
+                .collect(_eager=True)
+            )
         return self._from_pydf(self._df.unnest(columns))
 
     def corr(self, **kwargs: Any) -> DataFrame:
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import contextlib
+import enum
 from collections import OrderedDict
 from datetime import timezone
 from inspect import isclass
@@ -577,12 +578,34 @@ class Enum(DataType):
     Parameters
     ----------
     categories
-        The categories in the dataset. Categories must be strings.
+        The categories in the dataset. Categories must be strings. If a Python
+        :class:`enum.Enum` class is given, the values of its members are used as the
+        categories, in the order of definition.
+
+    Examples
+    --------
+    The order of the categories determines how values are compared and sorted.
+
+    >>> dtype = pl.Enum(["low", "medium", "high"])
+    >>> s = pl.Series(["medium", "high", "low"], dtype=dtype)
+    >>> s.sort().to_list()
+    ['low', 'medium', 'high']
+    >>> (s > "low").to_list()
+    [True, True, False]
+
+    Categories can also be taken from a Python enum.
+
+    >>> import enum
+    >>> class Priority(enum.Enum):
+    ...     LOW = "low"
+    ...     HIGH = "high"
+    >>> pl.Enum(Priority)
+    Enum(categories=['low', 'high'])
     """
 
     categories: Series
 
-    def __init__(self, categories: Series | Iterable[str]):
+    def __init__(self, categories: Series | Iterable[str] | type[enum.Enum]):
         # Issuing the warning on `__init__` does not trigger when the class is used
         # without being instantiated, but it's better than nothing
         from polars._utils.unstable import issue_unstable_warning
@@ -592,7 +615,9 @@ class Enum(DataType):
             " It is a work-in-progress feature and may not always work as expected."
         )
 
-        if not isinstance(categories, pl.Series):
+        if isclass(categories) and issubclass(categories, enum.Enum):
+            categories = pl.Series(values=[member.value for member in categories])
+        elif not isinstance(categories, pl.Series):
             categories = pl.Series(values=categories)
 
         if categories.is_empty():
@@ -9,7 +9,12 @@ if TYPE_CHECKING:
     from datetime import date, datetime, time
 
     from polars import Expr
-    from polars.type_aliases import IntoExpr, IntoExprColumn
+    from polars.type_aliases import (
+        IntoExpr,
+        IntoExprColumn,
+        RankMethod,
+        RollingInterpolationMethod,
+    )
 
 
 class ExprArrayNameSpace:
@@ -89,6 +94,58 @@ class ExprArrayNameSpace:
         """
         return wrap_expr(self._pyexpr.arr_sum())
 
+    def product(self) -> Expr:
+        """
+        Compute the product of the values of the sub-arrays.
+
+        Null values are ignored. Small integer types are upcasted to `Int64` to
+        prevent overflow.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[1, 2], [4, 3]]},
+        ...     schema={"a": pl.Array(pl.Int8, 2)},
+        ... )
+        >>> df.select(pl.col("a").arr.product())
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 2   │
+        │ 12  │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.arr_product())
+
+    def mean(self) -> Expr:
+        """
+        Compute the mean of the values of the sub-arrays.
+
+        Null values are ignored. Integer types are converted to `Float64`, temporal
+        types keep their data type.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[1, 2], [4, 3]]},
+        ...     schema={"a": pl.Array(pl.Int64, 2)},
+        ... )
+        >>> df.select(pl.col("a").arr.mean())
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.5 │
+        │ 3.5 │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.arr_mean())
+
     def std(self, ddof: int = 1) -> Expr:
         """
         Compute the std of the values of the sub-arrays.
@@ -158,6 +215,44 @@ class ExprArrayNameSpace:
         """
         return wrap_expr(self._pyexpr.arr_median())
 
+    def quantile(
+        self,
+        quantile: float | IntoExprColumn,
+        interpolation: RollingInterpolationMethod = "nearest",
+    ) -> Expr:
+        """
+        Compute the quantile of the values of the sub-arrays.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear'}
+            Interpolation method.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[1, 2, 3, 4], [8, 6, 4, 2]]},
+        ...     schema={"a": pl.Array(pl.Int64, 4)},
+        ... )
+        >>> df.select(
+        ...     nearest=pl.col("a").arr.quantile(0.3),
+        ...     linear=pl.col("a").arr.quantile(0.3, interpolation="linear"),
+        ... )
+        shape: (2, 2)
+        ┌─────────┬────────┐
+        │ nearest ┆ linear │
+        │ ---     ┆ ---    │
+        │ f64     ┆ f64    │
+        ╞═════════╪════════╡
+        │ 2.0     ┆ 1.9    │
+        │ 4.0     ┆ 3.8    │
+        └─────────┴────────┘
+        """
+        quantile = parse_as_expression(quantile)
+        return wrap_expr(self._pyexpr.arr_quantile(quantile, interpolation))
+
     def unique(self, *, maintain_order: bool = False) -> Expr:
         """
         Get the unique/distinct values in the array.
@@ -212,6 +307,59 @@ class ExprArrayNameSpace:
         """
         return wrap_expr(self._pyexpr.arr_n_unique())
 
+    def drop_nulls(self) -> Expr:
+        """
+        Drop all null values in every sub-array.
+
+        The original order of the remaining elements is preserved. As the number of
+        remaining elements differs per row, the result is a `List` column.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[None, 1, 2], [None, None, None], [3, 4, 5]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(drop_nulls=pl.col("a").arr.drop_nulls())
+        shape: (3, 2)
+        ┌────────────────────┬────────────┐
+        │ a                  ┆ drop_nulls │
+        │ ---                ┆ ---        │
+        │ array[i64, 3]      ┆ list[i64]  │
+        ╞════════════════════╪════════════╡
+        │ [null, 1, 2]       ┆ [1, 2]     │
+        │ [null, null, null] ┆ []         │
+        │ [3, 4, 5]          ┆ [3, 4, 5]  │
+        └────────────────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_drop_nulls())
+
+    def mode(self) -> Expr:
+        """
+        Compute the most occurring value(s) in every sub-array.
+
+        As there may be multiple most occurring values, the result is a `List`
+        column. The order of tied values is not guaranteed.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 1, 2], [2, 3, 3]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(mode=pl.col("a").arr.mode())
+        shape: (2, 2)
+        ┌───────────────┬───────────┐
+        │ a             ┆ mode      │
+        │ ---           ┆ ---       │
+        │ array[i64, 3] ┆ list[i64] │
+        ╞═══════════════╪═══════════╡
+        │ [1, 1, 2]     ┆ [1]       │
+        │ [2, 3, 3]     ┆ [3]       │
+        └───────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_mode())
+
     def to_list(self) -> Expr:
         """
         Convert an Array column into a List column with the same inner data type.
@@ -377,6 +525,227 @@ class ExprArrayNameSpace:
         """
         return wrap_expr(self._pyexpr.arr_reverse())
 
+    def cum_sum(self, *, reverse: bool = False) -> Expr:
+        """
+        Compute the cumulative sum within every sub-array.
+
+        The width of the sub-arrays is preserved.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation.
+
+        Notes
+        -----
+        Dtypes in {Int8, UInt8, Int16, UInt16} are cast to
+        Int64 before summing to prevent overflow issues.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 3, 2], [4, None, 5]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(cum_sum=pl.col("a").arr.cum_sum())
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ cum_sum       │
+        │ ---           ┆ ---           │
+        │ array[i64, 3] ┆ array[i64, 3] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 3, 2]     ┆ [1, 4, 6]     │
+        │ [4, null, 5]  ┆ [4, null, 9]  │
+        └───────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_cum_sum(reverse))
+
+    def cum_min(self, *, reverse: bool = False) -> Expr:
+        """
+        Compute the cumulative min within every sub-array.
+
+        The width of the sub-arrays is preserved.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 3, 2], [4, None, 5]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(cum_min=pl.col("a").arr.cum_min())
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ cum_min       │
+        │ ---           ┆ ---           │
+        │ array[i64, 3] ┆ array[i64, 3] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 3, 2]     ┆ [1, 1, 1]     │
+        │ [4, null, 5]  ┆ [4, null, 4]  │
+        └───────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_cum_min(reverse))
+
+    def cum_max(self, *, reverse: bool = False) -> Expr:
+        """
+        Compute the cumulative max within every sub-array.
+
+        The width of the sub-arrays is preserved.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 3, 2], [4, None, 5]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(cum_max=pl.col("a").arr.cum_max())
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ cum_max       │
+        │ ---           ┆ ---           │
+        │ array[i64, 3] ┆ array[i64, 3] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 3, 2]     ┆ [1, 3, 3]     │
+        │ [4, null, 5]  ┆ [4, null, 5]  │
+        └───────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_cum_max(reverse))
+
+    def rank(
+        self,
+        method: RankMethod = "average",
+        *,
+        descending: bool = False,
+        seed: int | None = None,
+    ) -> Expr:
+        """
+        Assign ranks to the elements within every sub-array.
+
+        The width of the sub-arrays is preserved. The ranks are of data type
+        :class:`Float64` for the 'average' method and :class:`UInt32` otherwise.
+
+        Parameters
+        ----------
+        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random'}
+            The method used to assign ranks to tied elements.
+            The following methods are available (default is 'average'):
+
+            - 'average' : The average of the ranks that would have been assigned to
+              all the tied values is assigned to each value.
+            - 'min' : The minimum of the ranks that would have been assigned to all
+              the tied values is assigned to each value. (This is also referred to
+              as "competition" ranking.)
+            - 'max' : The maximum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'dense' : Like 'min', but the rank of the next highest element is
+              assigned the rank immediately after those assigned to the tied
+              elements.
+            - 'ordinal' : All values are given a distinct rank, corresponding to
+              the order that the values occur in the sub-array.
+            - 'random' : Like 'ordinal', but the rank for ties is not dependent
+              on the order that the values occur in the sub-array.
+        descending
+            Rank in descending order.
+        seed
+            If `method="random"`, use this as seed.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[3, 1, 1], [2, 8, 2]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(
+        ...     average=pl.col("a").arr.rank(),
+        ...     dense=pl.col("a").arr.rank("dense"),
+        ... )
+        shape: (2, 3)
+        ┌───────────────┬─────────────────┬───────────────┐
+        │ a             ┆ average         ┆ dense         │
+        │ ---           ┆ ---             ┆ ---           │
+        │ array[i64, 3] ┆ array[f64, 3]   ┆ array[u32, 3] │
+        ╞═══════════════╪═════════════════╪═══════════════╡
+        │ [3, 1, 1]     ┆ [3.0, 1.5, 1.5] ┆ [2, 1, 1]     │
+        │ [2, 8, 2]     ┆ [1.5, 3.0, 1.5] ┆ [1, 2, 1]     │
+        └───────────────┴─────────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_rank(method, descending, seed))
+
+    def flatten(self) -> Expr:
+        """
+        Merge the two outer levels of a nested array column.
+
+        An `Array(Array(T, m), n)` column becomes an `Array(T, n * m)` column. The
+        values are not copied.
+
+        See Also
+        --------
+        reshape
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]},
+        ...     schema={"a": pl.Array(pl.Array(pl.Int64, 2), 2)},
+        ... )
+        >>> df.with_columns(flat=pl.col("a").arr.flatten())
+        shape: (2, 2)
+        ┌─────────────────────────┬───────────────┐
+        │ a                       ┆ flat          │
+        │ ---                     ┆ ---           │
+        │ array[array[i64, 2], 2] ┆ array[i64, 4] │
+        ╞═════════════════════════╪═══════════════╡
+        │ [[1, 2], [3, 4]]        ┆ [1, 2, … 4]   │
+        │ [[5, 6], [7, 8]]        ┆ [5, 6, … 8]   │
+        └─────────────────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_flatten())
+
+    def reshape(self, dimensions: tuple[int, ...]) -> Expr:
+        """
+        Reshape the elements of every sub-array into a (nested) array.
+
+        The elements of the (nested) sub-arrays are taken in row-major order. The
+        values are not copied.
+
+        Parameters
+        ----------
+        dimensions
+            Tuple of the widths of the dimensions of the result, the outer dimension
+            first. One dimension may be set to -1 to infer it from the others.
+
+        See Also
+        --------
+        flatten
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]]},
+        ...     schema={"a": pl.Array(pl.Int64, 6)},
+        ... )
+        >>> df.select(pl.col("a").arr.reshape((3, -1)))
+        shape: (2, 1)
+        ┌─────────────────────────────┐
+        │ a                           │
+        │ ---                         │
+        │ array[array[i64, 2], 3]     │
+        ╞═════════════════════════════╡
+        │ [[1, 2], [3, 4], [5, 6]]    │
+        │ [[7, 8], [9, 10], [11, 12]] │
+        └─────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_reshape(dimensions))
+
     def arg_min(self) -> Expr:
         """
         Retrieve the index of the minimal value in every sub-array.
@@ -779,3 +1148,271 @@ class ExprArrayNameSpace:
         """
         n = parse_as_expression(n)
         return wrap_expr(self._pyexpr.arr_shift(n))
+
+    def sample(
+        self,
+        n: int | IntoExprColumn = 1,
+        *,
+        with_replacement: bool = False,
+        shuffle: bool = False,
+        seed: int | None = None,
+    ) -> Expr:
+        """
+        Sample `n` elements from every sub-array.
+
+        If `n` is an integer, the result is an `Array` of width `n`, otherwise it is a
+        `List` column as the number of sampled elements may differ per row.
+
+        Parameters
+        ----------
+        n
+            Number of elements to return per sub-array.
+        with_replacement
+            Allow values to be sampled more than once.
+        shuffle
+            Shuffle the order of sampled data points.
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated for each sample operation.
+            Every sub-array is sampled with its own seed, derived from `seed` and the
+            row index of the sub-array.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, 5, 6]], "n": [2, 1]},
+        ...     schema={"a": pl.Array(pl.Int64, 3), "n": pl.Int64},
+        ... )
+        >>> df.with_columns(sample=pl.col("a").arr.sample(2, seed=1))
+        shape: (2, 3)
+        ┌───────────────┬─────┬───────────────┐
+        │ a             ┆ n   ┆ sample        │
+        │ ---           ┆ --- ┆ ---           │
+        │ array[i64, 3] ┆ i64 ┆ array[i64, 2] │
+        ╞═══════════════╪═════╪═══════════════╡
+        │ [1, 2, 3]     ┆ 2   ┆ [2, 1]        │
+        │ [4, 5, 6]     ┆ 1   ┆ [4, 6]        │
+        └───────────────┴─────┴───────────────┘
+        >>> df.with_columns(sample=pl.col("a").arr.sample(pl.col("n"), seed=1))
+        shape: (2, 3)
+        ┌───────────────┬─────┬───────────┐
+        │ a             ┆ n   ┆ sample    │
+        │ ---           ┆ --- ┆ ---       │
+        │ array[i64, 3] ┆ i64 ┆ list[i64] │
+        ╞═══════════════╪═════╪═══════════╡
+        │ [1, 2, 3]     ┆ 2   ┆ [2, 1]    │
+        │ [4, 5, 6]     ┆ 1   ┆ [6]       │
+        └───────────────┴─────┴───────────┘
+        """
+        n = parse_as_expression(n)
+        return wrap_expr(self._pyexpr.arr_sample_n(n, with_replacement, shuffle, seed))
+
+    def set_union(self, other: IntoExpr) -> Expr:
+        """
+        Compute the SET UNION between the elements in this array and `other`.
+
+        As the number of elements in the result differs per row, the result is a
+        `List` column.
+
+        Parameters
+        ----------
+        other
+            Right hand side of the set operation, an `Array` or `List` column.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [3, 4, 5]], "b": [[2, 3, 4], [5, 6, 7]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3), "b": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(union=pl.col("a").arr.set_union("b"))
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬─────────────┐
+        │ a             ┆ b             ┆ union       │
+        │ ---           ┆ ---           ┆ ---         │
+        │ array[i64, 3] ┆ array[i64, 3] ┆ list[i64]   │
+        ╞═══════════════╪═══════════════╪═════════════╡
+        │ [1, 2, 3]     ┆ [2, 3, 4]     ┆ [1, 2, … 4] │
+        │ [3, 4, 5]     ┆ [5, 6, 7]     ┆ [3, 4, … 7] │
+        └───────────────┴───────────────┴─────────────┘
+        """
+        other = parse_as_expression(other, str_as_lit=False)
+        return wrap_expr(self._pyexpr.arr_set_operation(other, "union"))
+
+    def set_difference(self, other: IntoExpr) -> Expr:
+        """
+        Compute the SET DIFFERENCE between the elements in this array and `other`.
+
+        As the number of elements in the result differs per row, the result is a
+        `List` column.
+
+        Parameters
+        ----------
+        other
+            Right hand side of the set operation, an `Array` or `List` column.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [3, 4, 5]], "b": [[2, 3, 4], [5, 6, 7]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3), "b": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(difference=pl.col("a").arr.set_difference("b"))
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬────────────┐
+        │ a             ┆ b             ┆ difference │
+        │ ---           ┆ ---           ┆ ---        │
+        │ array[i64, 3] ┆ array[i64, 3] ┆ list[i64]  │
+        ╞═══════════════╪═══════════════╪════════════╡
+        │ [1, 2, 3]     ┆ [2, 3, 4]     ┆ [1]        │
+        │ [3, 4, 5]     ┆ [5, 6, 7]     ┆ [3, 4]     │
+        └───────────────┴───────────────┴────────────┘
+        """
+        other = parse_as_expression(other, str_as_lit=False)
+        return wrap_expr(self._pyexpr.arr_set_operation(other, "difference"))
+
+    def set_intersection(self, other: IntoExpr) -> Expr:
+        """
+        Compute the SET INTERSECTION between the elements in this array and `other`.
+
+        As the number of elements in the result differs per row, the result is a
+        `List` column.
+
+        Parameters
+        ----------
+        other
+            Right hand side of the set operation, an `Array` or `List` column.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [3, 4, 5]], "b": [[2, 3, 4], [5, 6, 7]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3), "b": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(intersection=pl.col("a").arr.set_intersection("b"))
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬──────────────┐
+        │ a             ┆ b             ┆ intersection │
+        │ ---           ┆ ---           ┆ ---          │
+        │ array[i64, 3] ┆ array[i64, 3] ┆ list[i64]    │
+        ╞═══════════════╪═══════════════╪══════════════╡
+        │ [1, 2, 3]     ┆ [2, 3, 4]     ┆ [2, 3]       │
+        │ [3, 4, 5]     ┆ [5, 6, 7]     ┆ [5]          │
+        └───────────────┴───────────────┴──────────────┘
+        """
+        other = parse_as_expression(other, str_as_lit=False)
+        return wrap_expr(self._pyexpr.arr_set_operation(other, "intersection"))
+
+    def set_symmetric_difference(self, other: IntoExpr) -> Expr:
+        """
+        Compute the SET SYMMETRIC DIFFERENCE between this array and `other`.
+
+        As the number of elements in the result differs per row, the result is a
+        `List` column.
+
+        Parameters
+        ----------
+        other
+            Right hand side of the set operation, an `Array` or `List` column.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [3, 4, 5]], "b": [[2, 3, 4], [5, 6, 7]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3), "b": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(sdiff=pl.col("a").arr.set_symmetric_difference("b"))
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬─────────────┐
+        │ a             ┆ b             ┆ sdiff       │
+        │ ---           ┆ ---           ┆ ---         │
+        │ array[i64, 3] ┆ array[i64, 3] ┆ list[i64]   │
+        ╞═══════════════╪═══════════════╪═════════════╡
+        │ [1, 2, 3]     ┆ [2, 3, 4]     ┆ [1, 4]      │
+        │ [3, 4, 5]     ┆ [5, 6, 7]     ┆ [3, 4, … 7] │
+        └───────────────┴───────────────┴─────────────┘
+        """
+        other = parse_as_expression(other, str_as_lit=False)
+        return wrap_expr(self._pyexpr.arr_set_operation(other, "symmetric_difference"))
+
+    def zip_with(self, mask: IntoExprColumn, other: IntoExprColumn) -> Expr:
+        """
+        Take values from this array or `other` based on the given `mask`.
+
+        Where the sub-array of `mask` is true, take the value from this array, else
+        take the value from `other`. All arrays must have the same width.
+
+        Parameters
+        ----------
+        mask
+            Boolean `Array` column.
+        other
+            `Array` column of the same data type.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [[1, 2], [3, 4]],
+        ...         "mask": [[True, False], [False, True]],
+        ...         "b": [[5, 6], [7, 8]],
+        ...     },
+        ...     schema={
+        ...         "a": pl.Array(pl.Int64, 2),
+        ...         "mask": pl.Array(pl.Boolean, 2),
+        ...         "b": pl.Array(pl.Int64, 2),
+        ...     },
+        ... )
+        >>> df.select(zipped=pl.col("a").arr.zip_with(pl.col("mask"), pl.col("b")))
+        shape: (2, 1)
+        ┌───────────────┐
+        │ zipped        │
+        │ ---           │
+        │ array[i64, 2] │
+        ╞═══════════════╡
+        │ [1, 6]        │
+        │ [7, 4]        │
+        └───────────────┘
+        """
+        mask = parse_as_expression(mask)
+        other = parse_as_expression(other)
+        return wrap_expr(self._pyexpr.arr_zip_with(mask, other))
+
+    def eval(self, expr: Expr, *, parallel: bool = False) -> Expr:
+        """
+        Run any polars expression against the arrays' elements.
+
+        The result is an array of the same width if the expression is known to
+        preserve the number of elements, i.e. if it is elementwise or a sort, rank,
+        shift, reverse or cumulative operation. Otherwise, it is a list.
+
+        Parameters
+        ----------
+        expr
+            Expression to run. Note that you can select an element with `pl.first()`, or
+            `pl.col()`
+        parallel
+            Run all expression parallel. Don't activate this blindly.
+            Parallelism is worth it if there is enough work to do per thread.
+
+            This likely should not be used in the group by context, because we already
+            parallel execution per group
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 8], [5, 4], [3, 2]]}, schema={"a": pl.Array(pl.Int64, 2)}
+        ... )
+        >>> df.with_columns(rank=pl.col("a").arr.eval(pl.element().rank()))
+        shape: (3, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ rank          │
+        │ ---           ┆ ---           │
+        │ array[i64, 2] ┆ array[f64, 2] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 8]        ┆ [1.0, 2.0]    │
+        │ [5, 4]        ┆ [2.0, 1.0]    │
+        │ [3, 2]        ┆ [2.0, 1.0]    │
+        └───────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_eval(expr._pyexpr, parallel))
@@ -4,10 +4,17 @@ from typing import TYPE_CHECKING
 
 from polars._utils.parse_expr_input import parse_as_expression
 from polars._utils.wrap import wrap_expr
+from polars.datatypes import py_type_to_dtype
 
 if TYPE_CHECKING:
     from polars import Expr
-    from polars.type_aliases import IntoExpr, TransferEncoding
+    from polars.type_aliases import (
+        DigestAlgorithm,
+        Endianness,
+        IntoExpr,
+        PolarsDataType,
+        TransferEncoding,
+    )
 
 
 class ExprBinaryNameSpace:
@@ -167,8 +174,9 @@ class ExprBinaryNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base32', 'percent'}
+            The encoding to use. `'percent'` is the URL percent-encoding, which
+            encodes all characters except the unreserved characters of RFC 3986.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -204,8 +212,15 @@ class ExprBinaryNameSpace:
             return wrap_expr(self._pyexpr.bin_hex_decode(strict))
         elif encoding == "base64":
             return wrap_expr(self._pyexpr.bin_base64_decode(strict))
+        elif encoding == "base32":
+            return wrap_expr(self._pyexpr.bin_base32_decode(strict))
+        elif encoding == "percent":
+            return wrap_expr(self._pyexpr.bin_percent_decode(strict))
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'base32', 'percent'},"
+                f" got {encoding!r}"
+            )
             raise ValueError(msg)
 
     def encode(self, encoding: TransferEncoding) -> Expr:
@@ -214,8 +229,9 @@ class ExprBinaryNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base32', 'percent'}
+            The encoding to use. `'percent'` is the URL percent-encoding, which
+            encodes all characters except the unreserved characters of RFC 3986.
 
         Returns
         -------
@@ -248,6 +264,108 @@ class ExprBinaryNameSpace:
             return wrap_expr(self._pyexpr.bin_hex_encode())
         elif encoding == "base64":
             return wrap_expr(self._pyexpr.bin_base64_encode())
+        elif encoding == "base32":
+            return wrap_expr(self._pyexpr.bin_base32_encode())
+        elif encoding == "percent":
+            return wrap_expr(self._pyexpr.bin_percent_encode())
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'base32', 'percent'},"
+                f" got {encoding!r}"
+            )
             raise ValueError(msg)
+
+    def reinterpret(
+        self, *, dtype: PolarsDataType, endianness: Endianness = "little"
+    ) -> Expr:
+        r"""
+        Reinterpret the bytes of each value as a number.
+
+        Parameters
+        ----------
+        dtype
+            The numeric data type to reinterpret the bytes as. Every non-null value
+            must be exactly as long as the width of this data type, otherwise an
+            error is raised.
+        endianness : {'little', 'big'}
+            The byte order of the values.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `dtype`.
+
+        See Also
+        --------
+        Expr.to_binary : Reinterpret numbers as their bytes.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"payload": [b"\x01\x00", b"\x00\x01", None]})
+        >>> df.with_columns(
+        ...     little=pl.col("payload").bin.reinterpret(dtype=pl.UInt16),
+        ...     big=pl.col("payload").bin.reinterpret(
+        ...         dtype=pl.UInt16, endianness="big"
+        ...     ),
+        ... )
+        shape: (3, 3)
+        ┌─────────────┬────────┬──────┐
+        │ payload     ┆ little ┆ big  │
+        │ ---         ┆ ---    ┆ ---  │
+        │ binary      ┆ u16    ┆ u16  │
+        ╞═════════════╪════════╪══════╡
+        │ b"\x01\x00" ┆ 1      ┆ 256  │
+        │ b"\x00\x01" ┆ 256    ┆ 1    │
+        │ null        ┆ null   ┆ null │
+        └─────────────┴────────┴──────┘
+        """
+        if endianness not in ("little", "big"):
+            msg = f"`endianness` must be one of {{'little', 'big'}}, got {endianness!r}"
+            raise ValueError(msg)
+        dtype = py_type_to_dtype(dtype)
+        is_little_endian = endianness == "little"
+        return wrap_expr(self._pyexpr.bin_reinterpret(dtype, is_little_endian))
+
+    def digest(self, algorithm: DigestAlgorithm, *, as_hex: bool = True) -> Expr:
+        r"""
+        Hash each value with a cryptographic hash or checksum.
+
+        Parameters
+        ----------
+        algorithm : {'md5', 'sha1', 'sha256', 'crc32'}
+            The hash function to digest the values with. The CRC-32 checksum is the
+            one used by zlib and gzip, its four bytes are in big endian order.
+        as_hex
+            Return the digest as a lowercase hex string. If set to `False`, the
+            digest is returned as binary instead.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String` if `as_hex` is set, and of data
+            type :class:`Binary` otherwise.
+
+        See Also
+        --------
+        Expr.hash : Hash the values with a fast, non-cryptographic hash function.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"data": [b"abc", b"", None]})
+        >>> df.with_columns(
+        ...     md5=pl.col("data").bin.digest("md5"),
+        ...     crc32=pl.col("data").bin.digest("crc32"),
+        ...     crc32_bytes=pl.col("data").bin.digest("crc32", as_hex=False),
+        ... )
+        shape: (3, 4)
+        ┌────────┬─────────────────────────────────┬──────────┬─────────────────────┐
+        │ data   ┆ md5                             ┆ crc32    ┆ crc32_bytes         │
+        │ ---    ┆ ---                             ┆ ---      ┆ ---                 │
+        │ binary ┆ str                             ┆ str      ┆ binary              │
+        ╞════════╪═════════════════════════════════╪══════════╪═════════════════════╡
+        │ b"abc" ┆ 900150983cd24fb0d6963f7d28e17f… ┆ 352441c2 ┆ b"5$A\xc2"          │
+        │ b""    ┆ d41d8cd98f00b204e9800998ecf842… ┆ 00000000 ┆ b"\x00\x00\x00\x00" │
+        │ null   ┆ null                            ┆ null     ┆ null                │
+        └────────┴─────────────────────────────────┴──────────┴─────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_digest(algorithm, as_hex))
@@ -2,7 +2,6 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING
 
-from polars._utils.deprecation import deprecate_function
 from polars._utils.wrap import wrap_expr
 
 if TYPE_CHECKING:
@@ -18,15 +17,13 @@ class ExprCatNameSpace:
     def __init__(self, expr: Expr):
         self._pyexpr = expr._pyexpr
 
-    @deprecate_function(
-        "Set the ordering directly on the datatype `pl.Categorical('lexical')`"
-        " or `pl.Categorical('physical')` or `cast()` to the intended data type."
-        " This method will be removed in the next breaking change",
-        version="0.19.19",
-    )
     def set_ordering(self, ordering: CategoricalOrdering) -> Expr:
         """
-        Determine how this categorical series should be sorted.
+        Determine how this categorical column should be sorted.
+
+        The ordering is used when sorting, when comparing values and when taking the
+        minimum or maximum, also within a group by. The categories themselves are left
+        unchanged.
 
         Parameters
         ----------
@@ -36,6 +33,26 @@ class ExprCatNameSpace:
             - 'physical' -> Use the physical representation of the categories to
               determine the order (default).
             - 'lexical' -> Use the string values to determine the ordering.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"cats": ["z", "z", "k", "a", "b"], "vals": [3, 1, 2, 2, 3]},
+        ...     schema_overrides={"cats": pl.Categorical},
+        ... )
+        >>> df.with_columns(pl.col("cats").cat.set_ordering("lexical")).sort("cats")
+        shape: (5, 2)
+        ┌──────┬──────┐
+        │ cats ┆ vals │
+        │ ---  ┆ ---  │
+        │ cat  ┆ i64  │
+        ╞══════╪══════╡
+        │ a    ┆ 2    │
+        │ b    ┆ 3    │
+        │ k    ┆ 2    │
+        │ z    ┆ 3    │
+        │ z    ┆ 1    │
+        └──────┴──────┘
         """
         return wrap_expr(self._pyexpr.cat_set_ordering(ordering))
 
@@ -61,3 +78,86 @@ class ExprCatNameSpace:
         └──────┘
         """
         return wrap_expr(self._pyexpr.cat_get_categories())
+
+    def n_categories(self) -> Expr:
+        """
+        Get the number of categories stored in this data type.
+
+        Examples
+        --------
+        >>> df = pl.Series(
+        ...     "cats", ["foo", "bar", "foo", "foo", "ham"], dtype=pl.Categorical
+        ... ).to_frame()
+        >>> df.select(pl.col("cats").cat.n_categories())
+        shape: (1, 1)
+        ┌──────┐
+        │ cats │
+        │ ---  │
+        │ u32  │
+        ╞══════╡
+        │ 3    │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.cat_n_categories())
+
+    def codes(self) -> Expr:
+        """
+        Get the index of the category of every value in the categories.
+
+        The index refers to the position of the category in
+        :func:`get_categories`. Unlike :func:`Expr.to_physical`, this does not depend
+        on whether the categorical was created under a global string cache.
+
+        Examples
+        --------
+        >>> df = pl.Series(
+        ...     "cats", ["foo", "bar", "foo", None, "ham"], dtype=pl.Categorical
+        ... ).to_frame()
+        >>> df.with_columns(pl.col("cats").cat.codes().alias("codes"))
+        shape: (5, 2)
+        ┌──────┬───────┐
+        │ cats ┆ codes │
+        │ ---  ┆ ---   │
+        │ cat  ┆ u32   │
+        ╞══════╪═══════╡
+        │ foo  ┆ 0     │
+        │ bar  ┆ 1     │
+        │ foo  ┆ 0     │
+        │ null ┆ null  │
+        │ ham  ┆ 2     │
+        └──────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.cat_codes())
+
+    def to_local(self) -> Expr:
+        """
+        Convert a categorical column to its local representation.
+
+        This may change the underlying physical representation of the column. A local
+        categorical does not depend on the global string cache, so it can be combined
+        with categoricals from other sources, e.g. in a concatenation or a join.
+
+        See the documentation of :func:`StringCache` for more information on the
+        difference between local and global categoricals.
+
+        Examples
+        --------
+        >>> with pl.StringCache():
+        ...     _ = pl.Series("x", ["a", "b", "a"], dtype=pl.Categorical)
+        ...     df = pl.DataFrame({"y": ["c", "b", "d"]}, schema={"y": pl.Categorical})
+        >>> df.select(
+        ...     pl.col("y").to_physical().alias("global"),
+        ...     pl.col("y").cat.to_local().to_physical().alias("local"),
+        ... )
+        shape: (3, 2)
+        ┌────────┬───────┐
+        │ global ┆ local │
+        │ ---    ┆ ---   │
+        │ u32    ┆ u32   │
+        ╞════════╪═══════╡
+        │ 2      ┆ 0     │
+        │ 1      ┆ 1     │
+        │ 3      ┆ 2     │
+        └────────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.cat_to_local())
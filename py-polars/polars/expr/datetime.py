@@ -18,11 +18,14 @@ from polars._utils.parse_expr_input import parse_as_expression
 from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
 from polars.datatypes import DTYPE_TEMPORAL_UNITS, Date, Int32
+from polars.functions.business import _resolve_holiday_calendar
 
 if TYPE_CHECKING:
     from polars import Expr
     from polars.type_aliases import (
         Ambiguous,
+        DurationFormat,
+        DurationPrecision,
         EpochTimeUnit,
         IntoExpr,
         IntoExprColumn,
@@ -46,6 +49,8 @@ class ExprDateTimeNameSpace:
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        *,
+        calendar: str | None = None,
     ) -> Expr:
         """
         Offset by `n` business days.
@@ -53,8 +58,8 @@ class ExprDateTimeNameSpace:
         Parameters
         ----------
         n
-            Number of business days to offset by. Can be a single number of an
-            expression.
+            Number of business days to offset by. Can be a single integer or an
+            expression of any integer data type.
         week_mask
             Which days of the week to count. The default is Monday to Friday.
             If you wanted to count only Monday to Thursday, you would pass
@@ -78,6 +83,10 @@ class ExprDateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            Name of a holiday calendar registered with
+            :func:`polars.register_holiday_calendar` to use instead of `week_mask`
+            and `holidays`.
 
         Returns
         -------
@@ -147,16 +156,75 @@ class ExprDateTimeNameSpace:
         └────────────┴─────────────────┘
         """
         n_pyexpr = parse_as_expression(n)
-        unix_epoch = dt.date(1970, 1, 1)
+        week_mask, holidays_int = _resolve_holiday_calendar(
+            calendar, week_mask, holidays
+        )
         return wrap_expr(
             self._pyexpr.dt_add_business_days(
-                n_pyexpr,
-                week_mask,
-                [(holiday - unix_epoch).days for holiday in holidays],
-                roll,
+                n_pyexpr, week_mask, holidays_int, roll, calendar
             )
         )
 
+    def is_business_day(
+        self,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+        *,
+        calendar: str | None = None,
+    ) -> Expr:
+        """
+        Determine whether the underlying date is a business day.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week are business days. The default is Monday to Friday.
+            If you only worked Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays that are not business days.
+        calendar
+            Name of a holiday calendar registered with
+            :func:`polars.register_holiday_calendar` to use instead of `week_mask`
+            and `holidays`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2020, 1, 1), date(2020, 1, 2), date(2020, 1, 4)]}
+        ... )
+        >>> df.with_columns(
+        ...     business_day=pl.col("date").dt.is_business_day(
+        ...         holidays=[date(2020, 1, 1)]
+        ...     )
+        ... )
+        shape: (3, 2)
+        ┌────────────┬──────────────┐
+        │ date       ┆ business_day │
+        │ ---        ┆ ---          │
+        │ date       ┆ bool         │
+        ╞════════════╪══════════════╡
+        │ 2020-01-01 ┆ false        │
+        │ 2020-01-02 ┆ true         │
+        │ 2020-01-04 ┆ false        │
+        └────────────┴──────────────┘
+        """
+        week_mask, holidays_int = _resolve_holiday_calendar(
+            calendar, week_mask, holidays
+        )
+        return wrap_expr(
+            self._pyexpr.dt_is_business_day(week_mask, holidays_int, calendar)
+        )
+
     def truncate(
         self,
         every: str | timedelta | Expr,
@@ -170,10 +238,11 @@ class ExprDateTimeNameSpace:
 
         Each date/datetime is mapped to the start of its bucket using the corresponding
         local datetime. Note that weekly buckets start on Monday.
-        Ambiguous results are localised using the DST offset of the original timestamp -
-        for example, truncating `'2022-11-06 01:30:00 CST'` by `'1h'` results in
-        `'2022-11-06 01:00:00 CST'`, whereas truncating `'2022-11-06 01:30:00 CDT'` by
-        `'1h'` results in `'2022-11-06 01:00:00 CDT'`.
+        Unless `ambiguous` is given, ambiguous results are localised using the DST
+        offset of the original timestamp - for example, truncating
+        `'2022-11-06 01:30:00 CST'` by `'1h'` results in `'2022-11-06 01:00:00 CST'`,
+        whereas truncating `'2022-11-06 01:30:00 CDT'` by `'1h'` results in
+        `'2022-11-06 01:00:00 CDT'`.
 
         Parameters
         ----------
@@ -184,7 +253,9 @@ class ExprDateTimeNameSpace:
 
             .. deprecated:: 0.20.19
                 This argument is deprecated and will be removed in the next breaking
-                release. Instead, chain `dt.truncate` with `dt.offset_by`.
+                release. Instead, chain `dt.truncate` with `dt.offset_by`. Unlike
+                the `offset` of `dt.round`, this offset is added to the truncated
+                result and does not shift the bucket boundaries.
         use_earliest
             Determine how to deal with ambiguous datetimes:
 
@@ -195,14 +266,17 @@ class ExprDateTimeNameSpace:
             .. deprecated:: 0.19.0
                 This is now automatically inferred; you can safely omit this argument.
         ambiguous
-            Determine how to deal with ambiguous datetimes:
+            Determine how to deal with ambiguous results:
 
-            - `'raise'` (default): raise
+            - `None` (default): use the DST offset of the original timestamp
+            - `'raise'`: raise
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
+            - `'null'`: set to null
 
-            .. deprecated:: 0.19.3
-                This is now automatically inferred; you can safely omit this argument.
+            .. versionchanged:: 0.20.27
+                `ambiguous` is no longer deprecated and is no longer ignored: it
+                now determines how ambiguous results are localised.
 
         Notes
         -----
@@ -324,12 +398,9 @@ class ExprDateTimeNameSpace:
                 "`use_earliest` is deprecated. It is now automatically inferred; you can safely omit this argument.",
                 version="0.19.13",
             )
-        if ambiguous is not None:
-            issue_deprecation_warning(
-                "`ambiguous` is deprecated. It is now automatically inferred; you can safely omit this argument.",
-                version="0.19.13",
-            )
         every = parse_as_expression(every, str_as_lit=True)
+        if ambiguous is not None:
+            ambiguous = parse_as_expression(ambiguous, str_as_lit=True)
 
         if offset is None:
             offset = "0ns"
@@ -338,6 +409,7 @@ class ExprDateTimeNameSpace:
             self._pyexpr.dt_truncate(
                 every,
                 parse_as_duration_string(offset),
+                ambiguous,
             )
         )
 
@@ -347,6 +419,7 @@ class ExprDateTimeNameSpace:
         every: str | timedelta | IntoExprColumn,
         offset: str | timedelta | None = None,
         *,
+        week_start: int = 1,
         ambiguous: Ambiguous | Expr | None = None,
     ) -> Expr:
         """
@@ -360,30 +433,42 @@ class ExprDateTimeNameSpace:
         is mapped to the start of its bucket.
         Each date/datetime in the second half of the interval
         is mapped to the end of its bucket.
-        Ambiguous results are localised using the DST offset of the original timestamp -
-        for example, rounding `'2022-11-06 01:20:00 CST'` by `'1h'` results in
-        `'2022-11-06 01:00:00 CST'`, whereas rounding `'2022-11-06 01:20:00 CDT'` by
-        `'1h'` results in `'2022-11-06 01:00:00 CDT'`.
+        Unless `ambiguous` is given, ambiguous results are localised using the DST
+        offset of the original timestamp - for example, rounding
+        `'2022-11-06 01:20:00 CST'` by `'1h'` results in `'2022-11-06 01:00:00 CST'`,
+        whereas rounding `'2022-11-06 01:20:00 CDT'` by `'1h'` results in
+        `'2022-11-06 01:00:00 CDT'`.
 
         Parameters
         ----------
         every
             Every interval start and period length
         offset
-            Offset the window
-
-            .. deprecated:: 0.20.19
-                This argument is deprecated and will be removed in the next breaking
-                release. Instead, chain `dt.round` with `dt.offset_by`.
+            Shift the bucket boundaries by this duration. For example, rounding by
+            `'15m'` with an offset of `'5m'` rounds to `'..:05'`, `'..:20'`, `'..:35'`
+            or `'..:50'`.
+
+            .. versionchanged:: 0.20.27
+                The offset used to be added to the rounded result, which is the same
+                as chaining `dt.round` with `dt.offset_by`, and was deprecated for
+                that reason. It now shifts the bucket boundaries instead, which
+                chaining can't express, so it is no longer deprecated. The `offset`
+                of `dt.truncate` is still added to the result and remains deprecated.
+        week_start
+            The day on which weekly buckets start, from 1 (Monday) to 7 (Sunday). This
+            is only used if `every` is a number of weeks.
         ambiguous
-            Determine how to deal with ambiguous datetimes:
+            Determine how to deal with ambiguous results:
 
-            - `'raise'` (default): raise
+            - `None` (default): use the DST offset of the original timestamp
+            - `'raise'`: raise
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
+            - `'null'`: set to null
 
-            .. deprecated:: 0.19.3
-                This is now automatically inferred; you can safely omit this argument.
+            .. versionchanged:: 0.20.27
+                `ambiguous` is no longer deprecated and is no longer ignored: it
+                now determines how ambiguous results are localised.
 
         Returns
         -------
@@ -464,39 +549,57 @@ class ExprDateTimeNameSpace:
         │ 2001-01-01 00:50:00 ┆ 2001-01-01 01:00:00 │
         │ 2001-01-01 01:00:00 ┆ 2001-01-01 01:00:00 │
         └─────────────────────┴─────────────────────┘
+
+        Round to the half hours which start at ten minutes past the hour:
+
+        >>> df.with_columns(pl.col("datetime").dt.round("30m", "10m").alias("round"))
+        shape: (7, 2)
+        ┌─────────────────────┬─────────────────────┐
+        │ datetime            ┆ round               │
+        │ ---                 ┆ ---                 │
+        │ datetime[μs]        ┆ datetime[μs]        │
+        ╞═════════════════════╪═════════════════════╡
+        │ 2001-01-01 00:00:00 ┆ 2001-01-01 00:10:00 │
+        │ 2001-01-01 00:10:00 ┆ 2001-01-01 00:10:00 │
+        │ 2001-01-01 00:20:00 ┆ 2001-01-01 00:10:00 │
+        │ 2001-01-01 00:30:00 ┆ 2001-01-01 00:40:00 │
+        │ 2001-01-01 00:40:00 ┆ 2001-01-01 00:40:00 │
+        │ 2001-01-01 00:50:00 ┆ 2001-01-01 00:40:00 │
+        │ 2001-01-01 01:00:00 ┆ 2001-01-01 01:10:00 │
+        └─────────────────────┴─────────────────────┘
         """
         every = deprecate_saturating(every)
         offset = deprecate_saturating(offset)
-        if offset is not None:
-            issue_deprecation_warning(
-                "`offset` is deprecated and will be removed in the next breaking release. "
-                "Instead, chain `dt.round` with `dt.offset_by`.",
-                version="0.20.19",
-            )
         if offset is None:
             offset = "0ns"
 
-        if ambiguous is not None:
-            issue_deprecation_warning(
-                "`ambiguous` is deprecated. It is now automatically inferred; you can safely omit this argument.",
-                version="0.19.13",
-            )
         if isinstance(every, timedelta):
             every = parse_as_duration_string(every)
         every = parse_as_expression(every, str_as_lit=True)
+        if ambiguous is not None:
+            ambiguous = parse_as_expression(ambiguous, str_as_lit=True)
         return wrap_expr(
             self._pyexpr.dt_round(
                 every,
                 parse_as_duration_string(offset),
+                week_start,
+                ambiguous,
             )
         )
 
-    def combine(self, time: dt.time | Expr, time_unit: TimeUnit = "us") -> Expr:
+    def combine(
+        self,
+        time: dt.time | Expr,
+        time_unit: TimeUnit = "us",
+        time_zone: str | None = None,
+    ) -> Expr:
         """
-        Create a naive Datetime from an existing Date/Datetime expression and a Time.
+        Create a Datetime from an existing Date/Datetime expression and a Time.
 
         If the underlying expression is a Datetime then its time component is replaced,
         and if it is a Date then a new Datetime is created by combining the two values.
+        For a time zone aware Datetime, the time component in its own time zone is
+        replaced.
 
         Parameters
         ----------
@@ -504,6 +607,9 @@ class ExprDateTimeNameSpace:
             A python time literal or polars expression/column that resolves to a time.
         time_unit : {'ns', 'us', 'ms'}
             Unit of time.
+        time_zone
+            Time zone to interpret the combined date and time in. Defaults to the
+            time zone of a Datetime, and to a naive Datetime for a Date.
 
         Examples
         --------
@@ -549,9 +655,9 @@ class ExprDateTimeNameSpace:
             msg = f"expected 'time' to be a Python time or Polars expression, found {type(time).__name__!r}"
             raise TypeError(msg)
         time = parse_as_expression(time)
-        return wrap_expr(self._pyexpr.dt_combine(time, time_unit))
+        return wrap_expr(self._pyexpr.dt_combine(time, time_unit, time_zone))
 
-    def to_string(self, format: str) -> Expr:
+    def to_string(self, format: str, *, locale: str = "en_US") -> Expr:
         """
         Convert a Date/Time/Datetime column into a String column with the given format.
 
@@ -564,6 +670,10 @@ class ExprDateTimeNameSpace:
             Format to use, refer to the `chrono strftime documentation
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: `"%y-%m-%d"`.
+        locale
+            Language of the names of months and weekdays formatted by `'%B'`, `'%b'`,
+            `'%A'` and `'%a'`. One of `'de_DE'`, `'en_US'`, `'es_ES'`, `'fr_FR'`,
+            `'it_IT'`, `'nl_NL'` or `'pt_PT'`.
 
         Examples
         --------
@@ -610,10 +720,27 @@ class ExprDateTimeNameSpace:
         │ 2020-04-01 00:00:00 ┆ Wednesday ┆ April      │
         │ 2020-05-01 00:00:00 ┆ Friday    ┆ May        │
         └─────────────────────┴───────────┴────────────┘
+
+        Use `locale` to get these names in another language:
+
+        >>> df.with_columns(
+        ...     day_name=pl.col("datetime").dt.to_string("%A", locale="fr_FR"),
+        ...     month_name=pl.col("datetime").dt.to_string("%B", locale="fr_FR"),
+        ... )
+        shape: (3, 3)
+        ┌─────────────────────┬──────────┬────────────┐
+        │ datetime            ┆ day_name ┆ month_name │
+        │ ---                 ┆ ---      ┆ ---        │
+        │ datetime[μs]        ┆ str      ┆ str        │
+        ╞═════════════════════╪══════════╪════════════╡
+        │ 2020-03-01 00:00:00 ┆ dimanche ┆ mars       │
+        │ 2020-04-01 00:00:00 ┆ mercredi ┆ avril      │
+        │ 2020-05-01 00:00:00 ┆ vendredi ┆ mai        │
+        └─────────────────────┴──────────┴────────────┘
         """
-        return wrap_expr(self._pyexpr.dt_to_string(format))
+        return wrap_expr(self._pyexpr.dt_to_string(format, locale))
 
-    def strftime(self, format: str) -> Expr:
+    def strftime(self, format: str, *, locale: str = "en_US") -> Expr:
         """
         Convert a Date/Time/Datetime column into a String column with the given format.
 
@@ -628,6 +755,10 @@ class ExprDateTimeNameSpace:
             Format to use, refer to the `chrono strftime documentation
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: `"%y-%m-%d"`.
+        locale
+            Language of the names of months and weekdays formatted by `'%B'`, `'%b'`,
+            `'%A'` and `'%a'`. One of `'de_DE'`, `'en_US'`, `'es_ES'`, `'fr_FR'`,
+            `'it_IT'`, `'nl_NL'` or `'pt_PT'`.
 
         See Also
         --------
@@ -679,7 +810,41 @@ class ExprDateTimeNameSpace:
         │ 2020-05-01 00:00:00 ┆ Friday    ┆ May        │
         └─────────────────────┴───────────┴────────────┘
         """
-        return self.to_string(format)
+        return self.to_string(format, locale=locale)
+
+    def to_iso_week_string(self) -> Expr:
+        """
+        Convert a Date/Datetime column into ISO 8601 week date strings.
+
+        A week date such as `"2021-W01-1"` consists of the ISO year, the ISO week and
+        the weekday, which are also available separately as :func:`iso_year`,
+        :func:`week` and :func:`weekday`. Around New Year, the ISO year may differ
+        from the calendar year.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2020, 12, 31), date(2021, 1, 3), date(2021, 1, 4)]}
+        ... )
+        >>> df.with_columns(iso_week_date=pl.col("date").dt.to_iso_week_string())
+        shape: (3, 2)
+        ┌────────────┬───────────────┐
+        │ date       ┆ iso_week_date │
+        │ ---        ┆ ---           │
+        │ date       ┆ str           │
+        ╞════════════╪═══════════════╡
+        │ 2020-12-31 ┆ 2020-W53-4    │
+        │ 2021-01-03 ┆ 2020-W53-7    │
+        │ 2021-01-04 ┆ 2021-W01-1    │
+        └────────────┴───────────────┘
+        """
+        return self.to_string("%G-W%V-%u")
 
     def millennium(self) -> Expr:
         """
@@ -836,6 +1001,218 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_is_leap_year())
 
+    def is_month_start(self) -> Expr:
+        """
+        Determine whether the underlying date is the first day of a month.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 1, 31), date(2024, 2, 1), date(2024, 2, 29)]}
+        ... )
+        >>> df.with_columns(month_start=pl.col("date").dt.is_month_start())
+        shape: (3, 2)
+        ┌────────────┬─────────────┐
+        │ date       ┆ month_start │
+        │ ---        ┆ ---         │
+        │ date       ┆ bool        │
+        ╞════════════╪═════════════╡
+        │ 2024-01-31 ┆ false       │
+        │ 2024-02-01 ┆ true        │
+        │ 2024-02-29 ┆ false       │
+        └────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_is_month_start())
+
+    def is_month_end(self) -> Expr:
+        """
+        Determine whether the underlying date is the last day of a month.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 1, 31), date(2024, 2, 1), date(2024, 2, 29)]}
+        ... )
+        >>> df.with_columns(month_end=pl.col("date").dt.is_month_end())
+        shape: (3, 2)
+        ┌────────────┬───────────┐
+        │ date       ┆ month_end │
+        │ ---        ┆ ---       │
+        │ date       ┆ bool      │
+        ╞════════════╪═══════════╡
+        │ 2024-01-31 ┆ true      │
+        │ 2024-02-01 ┆ false     │
+        │ 2024-02-29 ┆ true      │
+        └────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_is_month_end())
+
+    def is_quarter_start(self, *, fiscal_year_start: int = 1) -> Expr:
+        """
+        Determine whether the underlying date is the first day of a quarter.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 3, 31), date(2024, 4, 1), date(2024, 5, 1)]}
+        ... )
+        >>> df.with_columns(quarter_start=pl.col("date").dt.is_quarter_start())
+        shape: (3, 2)
+        ┌────────────┬───────────────┐
+        │ date       ┆ quarter_start │
+        │ ---        ┆ ---           │
+        │ date       ┆ bool          │
+        ╞════════════╪═══════════════╡
+        │ 2024-03-31 ┆ false         │
+        │ 2024-04-01 ┆ true          │
+        │ 2024-05-01 ┆ false         │
+        └────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_is_quarter_start(fiscal_year_start))
+
+    def is_quarter_end(self, *, fiscal_year_start: int = 1) -> Expr:
+        """
+        Determine whether the underlying date is the last day of a quarter.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 3, 31), date(2024, 4, 1), date(2024, 4, 30)]}
+        ... )
+        >>> df.with_columns(quarter_end=pl.col("date").dt.is_quarter_end())
+        shape: (3, 2)
+        ┌────────────┬─────────────┐
+        │ date       ┆ quarter_end │
+        │ ---        ┆ ---         │
+        │ date       ┆ bool        │
+        ╞════════════╪═════════════╡
+        │ 2024-03-31 ┆ true        │
+        │ 2024-04-01 ┆ false       │
+        │ 2024-04-30 ┆ false       │
+        └────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_is_quarter_end(fiscal_year_start))
+
+    def is_year_start(self, *, fiscal_year_start: int = 1) -> Expr:
+        """
+        Determine whether the underlying date is the first day of a year.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2023, 12, 31), date(2024, 1, 1), date(2024, 2, 1)]}
+        ... )
+        >>> df.with_columns(year_start=pl.col("date").dt.is_year_start())
+        shape: (3, 2)
+        ┌────────────┬────────────┐
+        │ date       ┆ year_start │
+        │ ---        ┆ ---        │
+        │ date       ┆ bool       │
+        ╞════════════╪════════════╡
+        │ 2023-12-31 ┆ false      │
+        │ 2024-01-01 ┆ true       │
+        │ 2024-02-01 ┆ false      │
+        └────────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_is_year_start(fiscal_year_start))
+
+    def is_year_end(self, *, fiscal_year_start: int = 1) -> Expr:
+        """
+        Determine whether the underlying date is the last day of a year.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2023, 12, 31), date(2024, 1, 1), date(2024, 12, 30)]}
+        ... )
+        >>> df.with_columns(year_end=pl.col("date").dt.is_year_end())
+        shape: (3, 2)
+        ┌────────────┬──────────┐
+        │ date       ┆ year_end │
+        │ ---        ┆ ---      │
+        │ date       ┆ bool     │
+        ╞════════════╪══════════╡
+        │ 2023-12-31 ┆ true     │
+        │ 2024-01-01 ┆ false    │
+        │ 2024-12-30 ┆ false    │
+        └────────────┴──────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_is_year_end(fiscal_year_start))
+
     def iso_year(self) -> Expr:
         """
         Extract ISO year from underlying Date representation.
@@ -874,7 +1251,7 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_iso_year())
 
-    def quarter(self) -> Expr:
+    def quarter(self, *, fiscal_year_start: int = 1) -> Expr:
         """
         Extract quarter from underlying Date representation.
 
@@ -882,6 +1259,11 @@ class ExprDateTimeNameSpace:
 
         Returns the quarter ranging from 1 to 4.
 
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
         Returns
         -------
         Expr
@@ -904,8 +1286,24 @@ class ExprDateTimeNameSpace:
         │ 2001-06-30 ┆ 2       │
         │ 2001-12-27 ┆ 4       │
         └────────────┴─────────┘
-        """
-        return wrap_expr(self._pyexpr.dt_quarter())
+
+        Get the quarter of a fiscal year that starts in April:
+
+        >>> df.with_columns(
+        ...     pl.col("date").dt.quarter(fiscal_year_start=4).alias("fiscal_quarter")
+        ... )
+        shape: (3, 2)
+        ┌────────────┬────────────────┐
+        │ date       ┆ fiscal_quarter │
+        │ ---        ┆ ---            │
+        │ date       ┆ i8             │
+        ╞════════════╪════════════════╡
+        │ 2001-01-01 ┆ 4              │
+        │ 2001-06-30 ┆ 1              │
+        │ 2001-12-27 ┆ 3              │
+        └────────────┴────────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_quarter(fiscal_year_start))
 
     def month(self) -> Expr:
         """
@@ -1520,14 +1918,34 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_nanosecond())
 
-    def epoch(self, time_unit: EpochTimeUnit = "us") -> Expr:
+    def epoch(
+        self,
+        time_unit: EpochTimeUnit | str | timedelta = "us",
+        *,
+        origin: dt.date | dt.datetime | Expr | None = None,
+        fractional: bool = False,
+    ) -> Expr:
         """
         Get the time passed since the Unix EPOCH in the give time unit.
 
         Parameters
         ----------
-        time_unit : {'ns', 'us', 'ms', 's', 'd'}
-            Time unit.
+        time_unit : {'ns', 'us', 'ms', 's', 'd'}, str or timedelta
+            Time unit. Besides these units, any duration of a fixed length is
+            accepted, such as `"15m"` or `"1w"`. Days and weeks are taken to be 24
+            hours and 7 days long.
+        origin
+            The date or datetime to count from, instead of the Unix epoch. A
+            time-zone-naive origin of a time-zone-aware expression is taken to be
+            in its time zone.
+        fractional
+            Whether to return the fractional number of units as a `Float64`, instead
+            of the whole number of units as an integer.
+
+        Notes
+        -----
+        Whole units are rounded down, so that times before the origin are counted
+        as negative units.
 
         Examples
         --------
@@ -1551,16 +1969,42 @@ class ExprDateTimeNameSpace:
         │ 2001-01-02 ┆ 978393600000000 ┆ 978393600 │
         │ 2001-01-03 ┆ 978480000000000 ┆ 978480000 │
         └────────────┴─────────────────┴───────────┘
-        """
-        if time_unit in DTYPE_TEMPORAL_UNITS:
-            return self.timestamp(time_unit)  # type: ignore[arg-type]
-        elif time_unit == "s":
-            return wrap_expr(self._pyexpr.dt_epoch_seconds())
-        elif time_unit == "d":
-            return wrap_expr(self._pyexpr).cast(Date).cast(Int32)
-        else:
-            msg = f"`time_unit` must be one of {{'ns', 'us', 'ms', 's', 'd'}}, got {time_unit!r}"
-            raise ValueError(msg)
+
+        Count the weeks since the start of a campaign.
+
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {"time": [datetime(2024, 3, 1), datetime(2024, 3, 12, 12)]}
+        ... )
+        >>> df.with_columns(
+        ...     weeks=pl.col("time").dt.epoch("1w", origin=date(2024, 2, 26)),
+        ...     weeks_fractional=pl.col("time").dt.epoch(
+        ...         "1w", origin=date(2024, 2, 26), fractional=True
+        ...     ),
+        ... )
+        shape: (2, 3)
+        ┌─────────────────────┬───────┬──────────────────┐
+        │ time                ┆ weeks ┆ weeks_fractional │
+        │ ---                 ┆ ---   ┆ ---              │
+        │ datetime[μs]        ┆ i64   ┆ f64              │
+        ╞═════════════════════╪═══════╪══════════════════╡
+        │ 2024-03-01 00:00:00 ┆ 0     ┆ 0.571429         │
+        │ 2024-03-12 12:00:00 ┆ 2     ┆ 2.214286         │
+        └─────────────────────┴───────┴──────────────────┘
+        """
+        if origin is None and not fractional:
+            if time_unit in DTYPE_TEMPORAL_UNITS:
+                return self.timestamp(time_unit)  # type: ignore[arg-type]
+            elif time_unit == "s":
+                return wrap_expr(self._pyexpr.dt_epoch_seconds())
+            elif time_unit == "d":
+                return wrap_expr(self._pyexpr).cast(Date).cast(Int32)
+
+        time_unit = parse_as_duration_string(time_unit)
+        if time_unit.isalpha():
+            time_unit = f"1{time_unit}"
+        origin_pyexpr = None if origin is None else parse_as_expression(origin)
+        return wrap_expr(self._pyexpr.dt_epoch(time_unit, origin_pyexpr, fractional))
 
     def timestamp(self, time_unit: TimeUnit = "us") -> Expr:
         """
@@ -1740,7 +2184,7 @@ class ExprDateTimeNameSpace:
         *,
         use_earliest: bool | None = None,
         ambiguous: Ambiguous | Expr = "raise",
-        non_existent: NonExistent = "raise",
+        non_existent: NonExistent | Expr = "raise",
     ) -> Expr:
         """
         Replace time zone for an expression of type Datetime.
@@ -1768,12 +2212,17 @@ class ExprDateTimeNameSpace:
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
+
+            Pass an expression of strategies to use a different one for each row.
         non_existent
-            Determine how to deal with non-existent datetimes:
+            Determine how to deal with non-existent datetimes, which fall into the gap
+            when clocks move forward:
 
             - `'raise'` (default): raise
             - `'null'`: set to null
 
+            Pass an expression of strategies to use a different one for each row.
+
         Examples
         --------
         >>> from datetime import datetime
@@ -1839,24 +2288,61 @@ class ExprDateTimeNameSpace:
         │ 2018-10-28 02:30:00 ┆ latest    ┆ 2018-10-28 02:30:00 CET       │
         │ 2018-10-28 02:00:00 ┆ latest    ┆ 2018-10-28 02:00:00 CET       │
         └─────────────────────┴───────────┴───────────────────────────────┘
+
+        Likewise, `non_existent` can hold a strategy for each datetime:
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "ts": [datetime(2021, 3, 28, 2, 30), datetime(2021, 3, 28, 3, 30)],
+        ...         "non_existent": ["null", "raise"],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     ts_localized=pl.col("ts").dt.replace_time_zone(
+        ...         "Europe/Warsaw", non_existent=pl.col("non_existent")
+        ...     )
+        ... )
+        shape: (2, 3)
+        ┌─────────────────────┬──────────────┬─────────────────────────────┐
+        │ ts                  ┆ non_existent ┆ ts_localized                │
+        │ ---                 ┆ ---          ┆ ---                         │
+        │ datetime[μs]        ┆ str          ┆ datetime[μs, Europe/Warsaw] │
+        ╞═════════════════════╪══════════════╪═════════════════════════════╡
+        │ 2021-03-28 02:30:00 ┆ null         ┆ null                        │
+        │ 2021-03-28 03:30:00 ┆ raise        ┆ 2021-03-28 03:30:00 CEST    │
+        └─────────────────────┴──────────────┴─────────────────────────────┘
         """
         ambiguous = rename_use_earliest_to_ambiguous(use_earliest, ambiguous)
         if not isinstance(ambiguous, pl.Expr):
             ambiguous = F.lit(ambiguous)
+        if isinstance(non_existent, str) and non_existent not in ("null", "raise"):
+            msg = (
+                "`non_existent` must be one of {'null', 'raise'},"
+                f" got {non_existent}"
+            )
+            raise ValueError(msg)
+        non_existent = parse_as_expression(non_existent, str_as_lit=True)
         return wrap_expr(
             self._pyexpr.dt_replace_time_zone(
                 time_zone, ambiguous._pyexpr, non_existent
             )
         )
 
-    def total_days(self) -> Expr:
+    def total_days(self, *, fractional: bool = False) -> Expr:
         """
         Extract the total days from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Expr
-            Expression of data type :class:`Int64`.
+            Expression of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -1885,16 +2371,23 @@ class ExprDateTimeNameSpace:
         │ 2020-05-01 00:00:00 ┆ 30        │
         └─────────────────────┴───────────┘
         """
-        return wrap_expr(self._pyexpr.dt_total_days())
+        return wrap_expr(self._pyexpr.dt_total_days(fractional))
 
-    def total_hours(self) -> Expr:
+    def total_hours(self, *, fractional: bool = False) -> Expr:
         """
         Extract the total hours from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Expr
-            Expression of data type :class:`Int64`.
+            Expression of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -1923,17 +2416,41 @@ class ExprDateTimeNameSpace:
         │ 2020-01-03 00:00:00 ┆ 24         │
         │ 2020-01-04 00:00:00 ┆ 24         │
         └─────────────────────┴────────────┘
+
+        Keep the fractional part of the hours:
+
+        >>> from datetime import timedelta
+        >>> df = pl.DataFrame(
+        ...     {"duration": [timedelta(minutes=90), timedelta(minutes=-45)]}
+        ... )
+        >>> df.with_columns(hours=pl.col("duration").dt.total_hours(fractional=True))
+        shape: (2, 2)
+        ┌──────────────┬───────┐
+        │ duration     ┆ hours │
+        │ ---          ┆ ---   │
+        │ duration[μs] ┆ f64   │
+        ╞══════════════╪═══════╡
+        │ 1h 30m       ┆ 1.5   │
+        │ -45m         ┆ -0.75 │
+        └──────────────┴───────┘
         """
-        return wrap_expr(self._pyexpr.dt_total_hours())
+        return wrap_expr(self._pyexpr.dt_total_hours(fractional))
 
-    def total_minutes(self) -> Expr:
+    def total_minutes(self, *, fractional: bool = False) -> Expr:
         """
         Extract the total minutes from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Expr
-            Expression of data type :class:`Int64`.
+            Expression of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -1963,16 +2480,23 @@ class ExprDateTimeNameSpace:
         │ 2020-01-04 00:00:00 ┆ 1440         │
         └─────────────────────┴──────────────┘
         """
-        return wrap_expr(self._pyexpr.dt_total_minutes())
+        return wrap_expr(self._pyexpr.dt_total_minutes(fractional))
 
-    def total_seconds(self) -> Expr:
+    def total_seconds(self, *, fractional: bool = False) -> Expr:
         """
         Extract the total seconds from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Expr
-            Expression of data type :class:`Int64`.
+            Expression of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -2004,16 +2528,23 @@ class ExprDateTimeNameSpace:
         │ 2020-01-01 00:04:00 ┆ 60           │
         └─────────────────────┴──────────────┘
         """
-        return wrap_expr(self._pyexpr.dt_total_seconds())
+        return wrap_expr(self._pyexpr.dt_total_seconds(fractional))
 
-    def total_milliseconds(self) -> Expr:
+    def total_milliseconds(self, *, fractional: bool = False) -> Expr:
         """
         Extract the total milliseconds from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Expr
-            Expression of data type :class:`Int64`.
+            Expression of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -2046,16 +2577,23 @@ class ExprDateTimeNameSpace:
         │ 2020-01-01 00:00:01     ┆ 200               │
         └─────────────────────────┴───────────────────┘
         """
-        return wrap_expr(self._pyexpr.dt_total_milliseconds())
+        return wrap_expr(self._pyexpr.dt_total_milliseconds(fractional))
 
-    def total_microseconds(self) -> Expr:
+    def total_microseconds(self, *, fractional: bool = False) -> Expr:
         """
         Extract the total microseconds from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Expr
-            Expression of data type :class:`Int64`.
+            Expression of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -2088,16 +2626,23 @@ class ExprDateTimeNameSpace:
         │ 2020-01-01 00:00:01     ┆ 200000            │
         └─────────────────────────┴───────────────────┘
         """
-        return wrap_expr(self._pyexpr.dt_total_microseconds())
+        return wrap_expr(self._pyexpr.dt_total_microseconds(fractional))
 
-    def total_nanoseconds(self) -> Expr:
+    def total_nanoseconds(self, *, fractional: bool = False) -> Expr:
         """
         Extract the total nanoseconds from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Expr
-            Expression of data type :class:`Int64`.
+            Expression of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -2130,9 +2675,67 @@ class ExprDateTimeNameSpace:
         │ 2020-01-01 00:00:01     ┆ 200000000         │
         └─────────────────────────┴───────────────────┘
         """
-        return wrap_expr(self._pyexpr.dt_total_nanoseconds())
+        return wrap_expr(self._pyexpr.dt_total_nanoseconds(fractional))
+
+    def duration_to_string(
+        self,
+        format: DurationFormat = "polars",
+        *,
+        precision: DurationPrecision | None = None,
+    ) -> Expr:
+        """
+        Convert a Duration column into a String column.
+
+        Parameters
+        ----------
+        format : {'polars', 'iso'}
+            The format of the strings.
+
+            - `'polars'` is the format that durations are displayed in, such as
+              `"2d 3h 15m"`.
+            - `'iso'` is the ISO 8601 format, such as `"P2DT3H15M"`.
+        precision : {None, 'd', 'h', 'm', 's', 'ms', 'us', 'ns'}
+            The smallest unit to write, smaller units are truncated. By default,
+            durations are written in their full precision.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> from datetime import timedelta
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "duration": [
+        ...             timedelta(days=2, hours=3, minutes=15),
+        ...             timedelta(seconds=90, milliseconds=500),
+        ...             timedelta(0),
+        ...             None,
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     polars=pl.col("duration").dt.duration_to_string(),
+        ...     iso=pl.col("duration").dt.duration_to_string("iso"),
+        ...     minutes=pl.col("duration").dt.duration_to_string(precision="m"),
+        ... )
+        shape: (4, 4)
+        ┌──────────────┬──────────────┬───────────┬───────────┐
+        │ duration     ┆ polars       ┆ iso       ┆ minutes   │
+        │ ---          ┆ ---          ┆ ---       ┆ ---       │
+        │ duration[μs] ┆ str          ┆ str       ┆ str       │
+        ╞══════════════╪══════════════╪═══════════╪═══════════╡
+        │ 2d 3h 15m    ┆ 2d 3h 15m    ┆ P2DT3H15M ┆ 2d 3h 15m │
+        │ 1m 30s 500ms ┆ 1m 30s 500ms ┆ PT1M30.5S ┆ 1m        │
+        │ 0µs          ┆ 0µs          ┆ PT0S      ┆ 0m        │
+        │ null         ┆ null         ┆ null      ┆ null      │
+        └──────────────┴──────────────┴───────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_duration_to_string(format, precision))
 
-    def offset_by(self, by: str | Expr) -> Expr:
+    def offset_by(self, by: str | Expr, *, end_of_month: bool = False) -> Expr:
         """
         Offset this date by a relative time offset.
 
@@ -2161,6 +2764,13 @@ class ExprDateTimeNameSpace:
             not be 24 hours, due to daylight savings). Similarly for "calendar week",
             "calendar month", "calendar quarter", and "calendar year".
 
+            An expression can also give duration strings or :class:`Duration` values
+            for each row; durations are added as a fixed amount of time.
+        end_of_month
+            Keep dates on the last day of their month on the last day of the month
+            when months are added. For example, with `end_of_month=True`, 2024-02-29
+            offset by "1mo" is 2024-03-31 instead of 2024-03-29.
+
         Returns
         -------
         Expr
@@ -2213,10 +2823,28 @@ class ExprDateTimeNameSpace:
         │ 2004-01-01 00:00:00 ┆ null   ┆ null                │
         │ 2005-01-01 00:00:00 ┆ 1y     ┆ 2006-01-01 00:00:00 │
         └─────────────────────┴────────┴─────────────────────┘
+
+        Keep month ends on the end of the month:
+
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"date": [date(2024, 2, 28), date(2024, 2, 29)]})
+        >>> df.with_columns(
+        ...     next_month=pl.col("date").dt.offset_by("1mo"),
+        ...     next_month_end=pl.col("date").dt.offset_by("1mo", end_of_month=True),
+        ... )
+        shape: (2, 3)
+        ┌────────────┬────────────┬────────────────┐
+        │ date       ┆ next_month ┆ next_month_end │
+        │ ---        ┆ ---        ┆ ---            │
+        │ date       ┆ date       ┆ date           │
+        ╞════════════╪════════════╪════════════════╡
+        │ 2024-02-28 ┆ 2024-03-28 ┆ 2024-03-28     │
+        │ 2024-02-29 ┆ 2024-03-29 ┆ 2024-03-31     │
+        └────────────┴────────────┴────────────────┘
         """
         by = deprecate_saturating(by)
         by = parse_as_expression(by, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_offset_by(by))
+        return wrap_expr(self._pyexpr.dt_offset_by(by, end_of_month))
 
     def month_start(self) -> Expr:
         """
@@ -2320,6 +2948,94 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_month_end())
 
+    def quarter_start(self, *, fiscal_year_start: int = 1) -> Expr:
+        """
+        Roll backward to the first day of the quarter.
+
+        For datetimes, the time-of-day is preserved.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Date` or :class:`Datetime`.
+
+        Notes
+        -----
+        If you're coming from pandas, you can think of this as a vectorised version
+        of `pandas.tseries.offsets.QuarterBegin(startingMonth=1).rollback(datetime)`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 2, 15), date(2024, 4, 1), date(2024, 12, 31)]}
+        ... )
+        >>> df.with_columns(
+        ...     quarter_start=pl.col("date").dt.quarter_start(),
+        ...     fiscal_start=pl.col("date").dt.quarter_start(fiscal_year_start=2),
+        ... )
+        shape: (3, 3)
+        ┌────────────┬───────────────┬──────────────┐
+        │ date       ┆ quarter_start ┆ fiscal_start │
+        │ ---        ┆ ---           ┆ ---          │
+        │ date       ┆ date          ┆ date         │
+        ╞════════════╪═══════════════╪══════════════╡
+        │ 2024-02-15 ┆ 2024-01-01    ┆ 2024-02-01   │
+        │ 2024-04-01 ┆ 2024-04-01    ┆ 2024-02-01   │
+        │ 2024-12-31 ┆ 2024-10-01    ┆ 2024-11-01   │
+        └────────────┴───────────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_quarter_start(fiscal_year_start))
+
+    def quarter_end(self, *, fiscal_year_start: int = 1) -> Expr:
+        """
+        Roll forward to the last day of the quarter.
+
+        For datetimes, the time-of-day is preserved.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Date` or :class:`Datetime`.
+
+        Notes
+        -----
+        If you're coming from pandas, you can think of this as a vectorised version
+        of `pandas.tseries.offsets.QuarterEnd(startingMonth=3).rollforward(datetime)`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 2, 15), date(2024, 4, 1), date(2024, 12, 31)]}
+        ... )
+        >>> df.with_columns(
+        ...     quarter_end=pl.col("date").dt.quarter_end(),
+        ...     fiscal_end=pl.col("date").dt.quarter_end(fiscal_year_start=2),
+        ... )
+        shape: (3, 3)
+        ┌────────────┬─────────────┬────────────┐
+        │ date       ┆ quarter_end ┆ fiscal_end │
+        │ ---        ┆ ---         ┆ ---        │
+        │ date       ┆ date        ┆ date       │
+        ╞════════════╪═════════════╪════════════╡
+        │ 2024-02-15 ┆ 2024-03-31  ┆ 2024-04-30 │
+        │ 2024-04-01 ┆ 2024-06-30  ┆ 2024-04-30 │
+        │ 2024-12-31 ┆ 2024-12-31  ┆ 2025-01-31 │
+        └────────────┴─────────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_quarter_end(fiscal_year_start))
+
     def base_utc_offset(self) -> Expr:
         """
         Base offset from UTC.
@@ -85,6 +85,7 @@ if TYPE_CHECKING:
     )
     from polars.type_aliases import (
         ClosedInterval,
+        Endianness,
         FillNullStrategy,
         InterpolationMethod,
         IntoExpr,
@@ -5821,6 +5822,161 @@ class Expr:
         """
         return self.__xor__(other)
 
+    def shift_left(self, n: int | IntoExprColumn) -> Self:
+        """
+        Shift the bits of an integer column to the left by `n` bits.
+
+        Bits that are shifted past the width of the integer type are discarded.
+        Note that this is different from :func:`shift`, which shifts the values of
+        a column by a number of indices.
+
+        Parameters
+        ----------
+        n
+            Number of bits to shift by; accepts expression input. Must be
+            non-negative.
+
+        See Also
+        --------
+        shift_right
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"x": [1, 3, 16, -4], "n": [1, 2, 3, 1]},
+        ...     schema={"x": pl.Int8, "n": pl.UInt8},
+        ... )
+        >>> df.with_columns(
+        ...     pl.col("x").shift_left(2).alias("x << 2"),
+        ...     pl.col("x").shift_left(pl.col("n")).alias("x << n"),
+        ... )
+        shape: (4, 4)
+        ┌─────┬─────┬────────┬────────┐
+        │ x   ┆ n   ┆ x << 2 ┆ x << n │
+        │ --- ┆ --- ┆ ---    ┆ ---    │
+        │ i8  ┆ u8  ┆ i8     ┆ i8     │
+        ╞═════╪═════╪════════╪════════╡
+        │ 1   ┆ 1   ┆ 4      ┆ 2      │
+        │ 3   ┆ 2   ┆ 12     ┆ 12     │
+        │ 16  ┆ 3   ┆ 64     ┆ -128   │
+        │ -4  ┆ 1   ┆ -16    ┆ -8     │
+        └─────┴─────┴────────┴────────┘
+        """
+        n = parse_as_expression(n)
+        return self._from_pyexpr(self._pyexpr.shift_left(n))
+
+    def shift_right(self, n: int | IntoExprColumn) -> Self:
+        """
+        Shift the bits of an integer column to the right by `n` bits.
+
+        Signed integers are shifted arithmetically, i.e. negative values remain
+        negative. Note that this is different from :func:`shift`, which shifts the
+        values of a column by a number of indices.
+
+        Parameters
+        ----------
+        n
+            Number of bits to shift by; accepts expression input. Must be
+            non-negative.
+
+        See Also
+        --------
+        shift_left
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [1, 12, 16, -4]}, schema={"x": pl.Int8})
+        >>> df.with_columns(pl.col("x").shift_right(2).alias("x >> 2"))
+        shape: (4, 2)
+        ┌─────┬────────┐
+        │ x   ┆ x >> 2 │
+        │ --- ┆ ---    │
+        │ i8  ┆ i8     │
+        ╞═════╪════════╡
+        │ 1   ┆ 0      │
+        │ 12  ┆ 3      │
+        │ 16  ┆ 4      │
+        │ -4  ┆ -1     │
+        └─────┴────────┘
+        """
+        n = parse_as_expression(n)
+        return self._from_pyexpr(self._pyexpr.shift_right(n))
+
+    def bitwise_and(self) -> Self:
+        """
+        Reduce the values of an integer or boolean column with a bitwise "and".
+
+        Null values are ignored. If the column contains no non-null values, the
+        result is null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"group": ["a", "a", "b"], "flags": [0b110, 0b011, 0b100]}
+        ... )
+        >>> df.group_by("group", maintain_order=True).agg(pl.col("flags").bitwise_and())
+        shape: (2, 2)
+        ┌───────┬───────┐
+        │ group ┆ flags │
+        │ ---   ┆ ---   │
+        │ str   ┆ i64   │
+        ╞═══════╪═══════╡
+        │ a     ┆ 2     │
+        │ b     ┆ 4     │
+        └───────┴───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.bitwise_and())
+
+    def bitwise_or(self) -> Self:
+        """
+        Reduce the values of an integer or boolean column with a bitwise "or".
+
+        Null values are ignored. If the column contains no non-null values, the
+        result is null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"group": ["a", "a", "b"], "flags": [0b110, 0b011, 0b100]}
+        ... )
+        >>> df.group_by("group", maintain_order=True).agg(pl.col("flags").bitwise_or())
+        shape: (2, 2)
+        ┌───────┬───────┐
+        │ group ┆ flags │
+        │ ---   ┆ ---   │
+        │ str   ┆ i64   │
+        ╞═══════╪═══════╡
+        │ a     ┆ 7     │
+        │ b     ┆ 4     │
+        └───────┴───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.bitwise_or())
+
+    def bitwise_xor(self) -> Self:
+        """
+        Reduce the values of an integer or boolean column with a bitwise "xor".
+
+        Null values are ignored. If the column contains no non-null values, the
+        result is null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"group": ["a", "a", "b"], "flags": [0b110, 0b011, 0b100]}
+        ... )
+        >>> df.group_by("group", maintain_order=True).agg(pl.col("flags").bitwise_xor())
+        shape: (2, 2)
+        ┌───────┬───────┐
+        │ group ┆ flags │
+        │ ---   ┆ ---   │
+        │ str   ┆ i64   │
+        ╞═══════╪═══════╡
+        │ a     ┆ 5     │
+        │ b     ┆ 4     │
+        └───────┴───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.bitwise_xor())
+
     def is_in(self, other: Expr | Collection[Any] | Series) -> Self:
         """
         Check if elements of this expression are present in the other Series.
@@ -6043,7 +6199,7 @@ class Expr:
         -----
         This implementation of `hash` does not guarantee stable results
         across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        single version. Use :func:`hash_stable` for hashes that are persisted.
 
         Examples
         --------
@@ -6071,6 +6227,51 @@ class Expr:
         k3 = seed_3 if seed_3 is not None else seed
         return self._from_pyexpr(self._pyexpr.hash(k0, k1, k2, k3))
 
+    def hash_stable(self, seed: int = 0) -> Self:
+        """
+        Hash the elements in the selection with a hash that is stable across versions.
+
+        Unlike :func:`hash`, the result can be persisted, e.g. to partition data by a
+        hashed key. The hash value is of type `UInt64`.
+
+        Parameters
+        ----------
+        seed
+            Seed of the hash function. Defaults to 0.
+
+        Notes
+        -----
+        The hash is computed with XXH64 and is guaranteed to be the same across
+        Polars versions and platforms. The hashed bytes of a value are:
+
+        - for booleans, a single byte that is `0` or `1`.
+        - for integers, decimals and temporal types, the physical value in little
+          endian byte order. The hash thus depends on the width of the integer type
+          and on the time unit.
+        - for floats, the value in little endian byte order, where `-0.0` is hashed
+          as `0.0` and all NaN values as the same NaN.
+        - for strings, categoricals and enums, the UTF-8 encoded string.
+        - for binary data, the bytes themselves.
+
+        Null values remain null. Nested data types are not supported.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, None], "b": ["x", None, "z"]})
+        >>> df.with_columns(pl.all().hash_stable(seed=42))
+        shape: (3, 2)
+        ┌──────────────────────┬──────────────────────┐
+        │ a                    ┆ b                    │
+        │ ---                  ┆ ---                  │
+        │ u64                  ┆ u64                  │
+        ╞══════════════════════╪══════════════════════╡
+        │ 11445071438006506034 ┆ 12810693594942329153 │
+        │ 15105041264409158605 ┆ null                 │
+        │ null                 ┆ 6959616394252558107  │
+        └──────────────────────┴──────────────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.hash_stable(seed))
+
     def reinterpret(self, *, signed: bool = True) -> Self:
         """
         Reinterpret the underlying bits as a signed/unsigned integer.
@@ -6106,6 +6307,47 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.reinterpret(signed))
 
+    def to_binary(self, *, endianness: Endianness = "little") -> Self:
+        r"""
+        Reinterpret each number as its bytes.
+
+        Parameters
+        ----------
+        endianness : {'little', 'big'}
+            The byte order of the bytes.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        See Also
+        --------
+        Expr.bin.reinterpret : Reinterpret bytes as numbers.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 256]}, schema={"a": pl.UInt16})
+        >>> df.with_columns(
+        ...     little=pl.col("a").to_binary(),
+        ...     big=pl.col("a").to_binary(endianness="big"),
+        ... )
+        shape: (2, 3)
+        ┌─────┬─────────────┬─────────────┐
+        │ a   ┆ little      ┆ big         │
+        │ --- ┆ ---         ┆ ---         │
+        │ u16 ┆ binary      ┆ binary      │
+        ╞═════╪═════════════╪═════════════╡
+        │ 1   ┆ b"\x01\x00" ┆ b"\x00\x01" │
+        │ 256 ┆ b"\x00\x01" ┆ b"\x01\x00" │
+        └─────┴─────────────┴─────────────┘
+        """
+        if endianness not in ("little", "big"):
+            msg = f"`endianness` must be one of {{'little', 'big'}}, got {endianness!r}"
+            raise ValueError(msg)
+        is_little_endian = endianness == "little"
+        return self._from_pyexpr(self._pyexpr.to_binary(is_little_endian))
+
     def inspect(self, fmt: str = "{}") -> Self:
         """
         Print the value that this expression evaluates to and pass on the value.
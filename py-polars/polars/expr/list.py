@@ -163,6 +163,8 @@ class ExprListNameSpace:
         seed
             Seed for the random number generator. If set to None (default), a
             random seed is generated for each sample operation.
+            Every sublist is sampled with its own seed, derived from `seed` and the
+            row index of the sublist.
 
         Examples
         --------
@@ -175,7 +177,7 @@ class ExprListNameSpace:
         │ list[i64] ┆ i64 ┆ list[i64] │
         ╞═══════════╪═════╪═══════════╡
         │ [1, 2, 3] ┆ 2   ┆ [2, 1]    │
-        │ [4, 5]    ┆ 1   ┆ [5]       │
+        │ [4, 5]    ┆ 1   ┆ [4]       │
         └───────────┴─────┴───────────┘
         """
         if n is not None and fraction is not None:
@@ -390,6 +392,90 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_sort(descending, nulls_last))
 
+    def top_k(self, k: int | IntoExprColumn = 5) -> Expr:
+        r"""
+        Return the `k` largest elements of every sublist.
+
+        The elements are selected without fully sorting the sublists, which has time
+        complexity:
+
+        .. math:: O(n + k \log{n} - \frac{k}{2})
+
+        per sublist. The result is sorted in descending order.
+
+        Parameters
+        ----------
+        k
+            Number of elements to return per sublist. Sublists with fewer elements
+            return all of them.
+
+        See Also
+        --------
+        bottom_k
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 1, 5, 2], [4, None, 8], [6]], "k": [2, 1, 3]})
+        >>> df.with_columns(
+        ...     top=pl.col("a").list.top_k(2),
+        ...     top_per_row=pl.col("a").list.top_k(pl.col("k")),
+        ... )
+        shape: (3, 4)
+        ┌──────────────┬─────┬───────────┬─────────────┐
+        │ a            ┆ k   ┆ top       ┆ top_per_row │
+        │ ---          ┆ --- ┆ ---       ┆ ---         │
+        │ list[i64]    ┆ i64 ┆ list[i64] ┆ list[i64]   │
+        ╞══════════════╪═════╪═══════════╪═════════════╡
+        │ [3, 1, … 2]  ┆ 2   ┆ [5, 3]    ┆ [5, 3]      │
+        │ [4, null, 8] ┆ 1   ┆ [8, 4]    ┆ [8]         │
+        │ [6]          ┆ 3   ┆ [6]       ┆ [6]         │
+        └──────────────┴─────┴───────────┴─────────────┘
+        """
+        k = parse_as_expression(k)
+        return wrap_expr(self._pyexpr.list_top_k(k))
+
+    def bottom_k(self, k: int | IntoExprColumn = 5) -> Expr:
+        r"""
+        Return the `k` smallest elements of every sublist.
+
+        The elements are selected without fully sorting the sublists, which has time
+        complexity:
+
+        .. math:: O(n + k \log{n} - \frac{k}{2})
+
+        per sublist. The result is sorted in ascending order.
+
+        Parameters
+        ----------
+        k
+            Number of elements to return per sublist. Sublists with fewer elements
+            return all of them.
+
+        See Also
+        --------
+        top_k
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 1, 5, 2], [4, None, 8], [6]], "k": [2, 1, 3]})
+        >>> df.with_columns(
+        ...     bottom=pl.col("a").list.bottom_k(2),
+        ...     bottom_per_row=pl.col("a").list.bottom_k(pl.col("k")),
+        ... )
+        shape: (3, 4)
+        ┌──────────────┬─────┬───────────┬────────────────┐
+        │ a            ┆ k   ┆ bottom    ┆ bottom_per_row │
+        │ ---          ┆ --- ┆ ---       ┆ ---            │
+        │ list[i64]    ┆ i64 ┆ list[i64] ┆ list[i64]      │
+        ╞══════════════╪═════╪═══════════╪════════════════╡
+        │ [3, 1, … 2]  ┆ 2   ┆ [1, 2]    ┆ [1, 2]         │
+        │ [4, null, 8] ┆ 1   ┆ [null, 4] ┆ [null]         │
+        │ [6]          ┆ 3   ┆ [6]       ┆ [6]            │
+        └──────────────┴─────┴───────────┴────────────────┘
+        """
+        k = parse_as_expression(k)
+        return wrap_expr(self._pyexpr.list_bottom_k(k))
+
     def reverse(self) -> Expr:
         """
         Reverse the arrays in the list.
@@ -466,6 +552,64 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_n_unique())
 
+    def dedupe(self) -> Expr:
+        """
+        Remove consecutive duplicate values in every sublist.
+
+        Only values that are equal to the value right before them are removed, use
+        :func:`unique` to remove all duplicates. Null values are considered equal to
+        each other.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 1, 2, 1, 1], [None, None, 3], [4]]})
+        >>> df.with_columns(dedupe=pl.col("a").list.dedupe())
+        shape: (3, 2)
+        ┌─────────────────┬───────────┐
+        │ a               ┆ dedupe    │
+        │ ---             ┆ ---       │
+        │ list[i64]       ┆ list[i64] │
+        ╞═════════════════╪═══════════╡
+        │ [1, 1, … 1]     ┆ [1, 2, 1] │
+        │ [null, null, 3] ┆ [null, 3] │
+        │ [4]             ┆ [4]       │
+        └─────────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_dedupe())
+
+    def chunk(self, size: int) -> Expr:
+        """
+        Split every sublist into sublists of `size` values.
+
+        The last sublist of every list holds the remaining values and may be shorter.
+
+        Parameters
+        ----------
+        size
+            Number of values per sublist. Must be larger than 0.
+
+        Returns
+        -------
+        Expr
+            Expr of data type :class:`List` of the input data type.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3, 4, 5], [6, 7], []]})
+        >>> df.with_columns(chunks=pl.col("a").list.chunk(2))
+        shape: (3, 2)
+        ┌─────────────┬───────────────────────┐
+        │ a           ┆ chunks                │
+        │ ---         ┆ ---                   │
+        │ list[i64]   ┆ list[list[i64]]       │
+        ╞═════════════╪═══════════════════════╡
+        │ [1, 2, … 5] ┆ [[1, 2], [3, 4], [5]] │
+        │ [6, 7]      ┆ [[6, 7]]              │
+        │ []          ┆ []                    │
+        └─────────────┴───────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.list_chunk(size))
+
     def concat(self, other: list[Expr | str] | Expr | str | Series | list[Any]) -> Expr:
         """
         Concat the arrays in a Series dtype List in linear time.
@@ -587,6 +731,125 @@ class ExprListNameSpace:
         indices = parse_as_expression(indices)
         return wrap_expr(self._pyexpr.list_gather(indices, null_on_oob))
 
+    def scatter(
+        self,
+        indices: int | list[int] | Expr | Series,
+        values: IntoExpr | list[Any],
+        *,
+        ignore_oob: bool = False,
+    ) -> Expr:
+        """
+        Set the values of every sublist at the given indices.
+
+        Indices and values are given per sublist. A column of dtype `List` holds several
+        of them for every sublist, any other column holds one for every sublist. A
+        Python list is used for all sublists, just like values of length 1.
+
+        Parameters
+        ----------
+        indices
+            Indices to set per sublist. Negative indices count from the end of the
+            sublist.
+        values
+            Values to set. Either a value for every index, or a single value that is
+            set at all indices of the sublist.
+        ignore_oob
+            Behavior if an index is out of bounds:
+            True -> skip the index
+            False -> raise an error
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 5], [6]], "b": [10, 20, 30]})
+        >>> df.with_columns(scattered=pl.col("a").list.scatter(0, pl.col("b")))
+        shape: (3, 3)
+        ┌───────────┬─────┬────────────┐
+        │ a         ┆ b   ┆ scattered  │
+        │ ---       ┆ --- ┆ ---        │
+        │ list[i64] ┆ i64 ┆ list[i64]  │
+        ╞═══════════╪═════╪════════════╡
+        │ [1, 2, 3] ┆ 10  ┆ [10, 2, 3] │
+        │ [4, 5]    ┆ 20  ┆ [20, 5]    │
+        │ [6]       ┆ 30  ┆ [30]       │
+        └───────────┴─────┴────────────┘
+
+        Set several values at once and skip indices that are out of bounds.
+
+        >>> df.with_columns(
+        ...     scattered=pl.col("a").list.scatter([0, -1], [0, 9], ignore_oob=True)
+        ... )
+        shape: (3, 3)
+        ┌───────────┬─────┬───────────┐
+        │ a         ┆ b   ┆ scattered │
+        │ ---       ┆ --- ┆ ---       │
+        │ list[i64] ┆ i64 ┆ list[i64] │
+        ╞═══════════╪═════╪═══════════╡
+        │ [1, 2, 3] ┆ 10  ┆ [0, 2, 9] │
+        │ [4, 5]    ┆ 20  ┆ [0, 9]    │
+        │ [6]       ┆ 30  ┆ [9]       │
+        └───────────┴─────┴───────────┘
+        """
+        if isinstance(indices, list):
+            indices = pl.Series([indices])
+        if isinstance(values, list):
+            values = pl.Series([values])
+        indices = parse_as_expression(indices)
+        values = parse_as_expression(values, str_as_lit=True)
+        return wrap_expr(self._pyexpr.list_scatter(indices, values, ignore_oob))
+
+    def zip_with(
+        self,
+        mask: IntoExprColumn,
+        other: IntoExprColumn,
+        *,
+        null_on_mismatch: bool = False,
+    ) -> Expr:
+        """
+        Take values from this list or `other` based on the given `mask`.
+
+        Where the sublist of `mask` is true, take the value from this list, else take
+        the value from `other`. The sublists of every row must have the same length.
+
+        Parameters
+        ----------
+        mask
+            Boolean `List` column.
+        other
+            `List` column of the same data type.
+        null_on_mismatch
+            Behavior if the sublists of a row have different lengths:
+            True -> the sublist is null
+            False -> raise an error
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [[1, 2], [3, 4, 5], [6]],
+        ...         "mask": [[True, False], [False, True, True], [True, True]],
+        ...         "b": [[7, 8], [9, 10, 11], [12, 13]],
+        ...     }
+        ... )
+        >>> df.select(
+        ...     zipped=pl.col("a").list.zip_with(
+        ...         pl.col("mask"), pl.col("b"), null_on_mismatch=True
+        ...     )
+        ... )
+        shape: (3, 1)
+        ┌───────────┐
+        │ zipped    │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 8]    │
+        │ [9, 4, 5] │
+        │ null      │
+        └───────────┘
+        """
+        mask = parse_as_expression(mask)
+        other = parse_as_expression(other)
+        return wrap_expr(self._pyexpr.list_zip_with(mask, other, null_on_mismatch))
+
     def gather_every(
         self,
         n: int | IntoExprColumn,
@@ -707,7 +970,13 @@ class ExprListNameSpace:
         item = parse_as_expression(item, str_as_lit=True)
         return wrap_expr(self._pyexpr.list_contains(item))
 
-    def join(self, separator: IntoExprColumn, *, ignore_nulls: bool = True) -> Expr:
+    def join(
+        self,
+        separator: IntoExprColumn,
+        *,
+        ignore_nulls: bool = True,
+        null_replacement: str | None = None,
+    ) -> Expr:
         """
         Join all string items in a sublist and place a separator between them.
 
@@ -722,6 +991,9 @@ class ExprListNameSpace:
 
             If set to ``False``, null values will be propagated.
             If the sub-list contains any null values, the output is ``None``.
+        null_replacement
+            String to write in place of null values. If set, null values are
+            neither ignored nor propagated.
 
         Returns
         -------
@@ -755,9 +1027,25 @@ class ExprListNameSpace:
         │ ["a", "b", "c"] ┆ *         ┆ a*b*c │
         │ ["x", "y"]      ┆ _         ┆ x_y   │
         └─────────────────┴───────────┴───────┘
+
+        Replace null values instead of ignoring them.
+
+        >>> df = pl.DataFrame({"s": [["a", None, "c"], [None]]})
+        >>> df.with_columns(join=pl.col("s").list.join("-", null_replacement="?"))
+        shape: (2, 2)
+        ┌──────────────────┬───────┐
+        │ s                ┆ join  │
+        │ ---              ┆ ---   │
+        │ list[str]        ┆ str   │
+        ╞══════════════════╪═══════╡
+        │ ["a", null, "c"] ┆ a-?-c │
+        │ [null]           ┆ ?     │
+        └──────────────────┴───────┘
         """
         separator = parse_as_expression(separator, str_as_lit=True)
-        return wrap_expr(self._pyexpr.list_join(separator, ignore_nulls))
+        return wrap_expr(
+            self._pyexpr.list_join(separator, ignore_nulls, null_replacement)
+        )
 
     def arg_min(self) -> Expr:
         """
@@ -869,6 +1157,166 @@ class ExprListNameSpace:
         return wrap_expr(self._pyexpr.list_diff(n, null_behavior))
 
     @deprecate_renamed_parameter("periods", "n", version="0.19.11")
+    def rolling_sum(
+        self, window_size: int, *, min_periods: int | None = None, center: bool = False
+    ) -> Expr:
+        """
+        Apply a rolling sum over the values of every sublist.
+
+        A window of length `window_size` traverses every sublist separately, so the
+        result has the same length as the sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If None, it will be set equal to the window size.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 4, 2, 3], [5, 1], None]})
+        >>> df.with_columns(rolling_sum=pl.col("a").list.rolling_sum(2))
+        shape: (3, 2)
+        ┌─────────────┬────────────────┐
+        │ a           ┆ rolling_sum    │
+        │ ---         ┆ ---            │
+        │ list[i64]   ┆ list[i64]      │
+        ╞═════════════╪════════════════╡
+        │ [1, 4, … 3] ┆ [null, 5, … 5] │
+        │ [5, 1]      ┆ [null, 6]      │
+        │ null        ┆ null           │
+        └─────────────┴────────────────┘
+        """
+        if min_periods is None:
+            min_periods = window_size
+        return wrap_expr(
+            self._pyexpr.list_rolling_sum(window_size, min_periods, center)
+        )
+
+    def rolling_mean(
+        self, window_size: int, *, min_periods: int | None = None, center: bool = False
+    ) -> Expr:
+        """
+        Apply a rolling mean over the values of every sublist.
+
+        A window of length `window_size` traverses every sublist separately, so the
+        result has the same length as the sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If None, it will be set equal to the window size.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 4, 2, 3], [5, 1], None]})
+        >>> df.with_columns(rolling_mean=pl.col("a").list.rolling_mean(2))
+        shape: (3, 2)
+        ┌─────────────┬────────────────────┐
+        │ a           ┆ rolling_mean       │
+        │ ---         ┆ ---                │
+        │ list[i64]   ┆ list[f64]          │
+        ╞═════════════╪════════════════════╡
+        │ [1, 4, … 3] ┆ [null, 2.5, … 2.5] │
+        │ [5, 1]      ┆ [null, 3.0]        │
+        │ null        ┆ null               │
+        └─────────────┴────────────────────┘
+        """
+        if min_periods is None:
+            min_periods = window_size
+        return wrap_expr(
+            self._pyexpr.list_rolling_mean(window_size, min_periods, center)
+        )
+
+    def rolling_min(
+        self, window_size: int, *, min_periods: int | None = None, center: bool = False
+    ) -> Expr:
+        """
+        Apply a rolling minimum over the values of every sublist.
+
+        A window of length `window_size` traverses every sublist separately, so the
+        result has the same length as the sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If None, it will be set equal to the window size.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 4, 2, 3], [5, 1], None]})
+        >>> df.with_columns(rolling_min=pl.col("a").list.rolling_min(2))
+        shape: (3, 2)
+        ┌─────────────┬────────────────┐
+        │ a           ┆ rolling_min    │
+        │ ---         ┆ ---            │
+        │ list[i64]   ┆ list[i64]      │
+        ╞═════════════╪════════════════╡
+        │ [1, 4, … 3] ┆ [null, 1, … 2] │
+        │ [5, 1]      ┆ [null, 1]      │
+        │ null        ┆ null           │
+        └─────────────┴────────────────┘
+        """
+        if min_periods is None:
+            min_periods = window_size
+        return wrap_expr(
+            self._pyexpr.list_rolling_min(window_size, min_periods, center)
+        )
+
+    def rolling_max(
+        self, window_size: int, *, min_periods: int | None = None, center: bool = False
+    ) -> Expr:
+        """
+        Apply a rolling maximum over the values of every sublist.
+
+        A window of length `window_size` traverses every sublist separately, so the
+        result has the same length as the sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If None, it will be set equal to the window size.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 4, 2, 3], [5, 1], None]})
+        >>> df.with_columns(rolling_max=pl.col("a").list.rolling_max(2))
+        shape: (3, 2)
+        ┌─────────────┬────────────────┐
+        │ a           ┆ rolling_max    │
+        │ ---         ┆ ---            │
+        │ list[i64]   ┆ list[i64]      │
+        ╞═════════════╪════════════════╡
+        │ [1, 4, … 3] ┆ [null, 4, … 3] │
+        │ [5, 1]      ┆ [null, 5]      │
+        │ null        ┆ null           │
+        └─────────────┴────────────────┘
+        """
+        if min_periods is None:
+            min_periods = window_size
+        return wrap_expr(
+            self._pyexpr.list_rolling_max(window_size, min_periods, center)
+        )
+
     def shift(self, n: int | IntoExprColumn = 1) -> Expr:
         """
         Shift list values by the given number of indices.
@@ -999,6 +1447,79 @@ class ExprListNameSpace:
         n = parse_as_expression(n)
         return wrap_expr(self._pyexpr.list_tail(n))
 
+    def pad_end(self, length: int | str | Expr, fill_value: IntoExpr) -> Expr:
+        """
+        Pad every sublist at the end with `fill_value` until it has `length` values.
+
+        Sublists that already have at least `length` values are left untouched. Use
+        :func:`truncate` to also cut off longer sublists.
+
+        Parameters
+        ----------
+        length
+            Number of values every sublist should at least have.
+        fill_value
+            Value to pad the sublists with.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3, 4], [10, 2], []]})
+        >>> df.with_columns(padded=pl.col("a").list.pad_end(3, 0))
+        shape: (3, 2)
+        ┌─────────────┬─────────────┐
+        │ a           ┆ padded      │
+        │ ---         ┆ ---         │
+        │ list[i64]   ┆ list[i64]   │
+        ╞═════════════╪═════════════╡
+        │ [1, 2, … 4] ┆ [1, 2, … 4] │
+        │ [10, 2]     ┆ [10, 2, 0]  │
+        │ []          ┆ [0, 0, 0]   │
+        └─────────────┴─────────────┘
+
+        Normalize ragged lists to a fixed width and convert them to an Array.
+
+        >>> df.select(pl.col("a").list.pad_end(3, 0).list.truncate(3).list.to_array(3))
+        shape: (3, 1)
+        ┌───────────────┐
+        │ a             │
+        │ ---           │
+        │ array[i64, 3] │
+        ╞═══════════════╡
+        │ [1, 2, 3]     │
+        │ [10, 2, 0]    │
+        │ [0, 0, 0]     │
+        └───────────────┘
+        """
+        length = parse_as_expression(length)
+        fill_value = parse_as_expression(fill_value, str_as_lit=True)
+        return wrap_expr(self._pyexpr.list_pad_end(length, fill_value))
+
+    def truncate(self, length: int | str | Expr) -> Expr:
+        """
+        Truncate every sublist to at most `length` values.
+
+        Parameters
+        ----------
+        length
+            Maximum number of values of every sublist.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3, 4], [10, 2], []]})
+        >>> df.with_columns(truncated=pl.col("a").list.truncate(3))
+        shape: (3, 2)
+        ┌─────────────┬───────────┐
+        │ a           ┆ truncated │
+        │ ---         ┆ ---       │
+        │ list[i64]   ┆ list[i64] │
+        ╞═════════════╪═══════════╡
+        │ [1, 2, … 4] ┆ [1, 2, 3] │
+        │ [10, 2]     ┆ [10, 2]   │
+        │ []          ┆ []        │
+        └─────────────┴───────────┘
+        """
+        return self.head(length)
+
     def explode(self) -> Expr:
         """
         Returns a column with a separate row for every list element.
@@ -1061,7 +1582,43 @@ class ExprListNameSpace:
         element = parse_as_expression(element, str_as_lit=True)
         return wrap_expr(self._pyexpr.list_count_matches(element))
 
-    def to_array(self, width: int) -> Expr:
+    def index_of(self, value: IntoExpr) -> Expr:
+        """
+        Get the index of the first occurrence of a value in every sub-list.
+
+        The index is null if the value does not occur in the sub-list.
+
+        Parameters
+        ----------
+        value
+            The value to search for. Either a single value that is searched for in
+            every sub-list, or an expression that produces one value per sub-list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [3, 1], [4], None], "b": [3, 4, 4, 1]}
+        ... )
+        >>> df.with_columns(
+        ...     idx_of_three=pl.col("a").list.index_of(3),
+        ...     idx_of_b=pl.col("a").list.index_of(pl.col("b")),
+        ... )
+        shape: (4, 4)
+        ┌───────────┬─────┬──────────────┬──────────┐
+        │ a         ┆ b   ┆ idx_of_three ┆ idx_of_b │
+        │ ---       ┆ --- ┆ ---          ┆ ---      │
+        │ list[i64] ┆ i64 ┆ u32          ┆ u32      │
+        ╞═══════════╪═════╪══════════════╪══════════╡
+        │ [1, 2, 3] ┆ 3   ┆ 2            ┆ 2        │
+        │ [3, 1]    ┆ 4   ┆ 0            ┆ null     │
+        │ [4]       ┆ 4   ┆ null         ┆ 0        │
+        │ null      ┆ 1   ┆ null         ┆ null     │
+        └───────────┴─────┴──────────────┴──────────┘
+        """
+        value = parse_as_expression(value, str_as_lit=True)
+        return wrap_expr(self._pyexpr.list_index_of(value))
+
+    def to_array(self, width: int, *, null_on_mismatch: bool = False) -> Expr:
         """
         Convert a List column into an Array column with the same inner data type.
 
@@ -1069,6 +1626,9 @@ class ExprListNameSpace:
         ----------
         width
             Width of the resulting Array column.
+        null_on_mismatch
+            Set sublists with a length other than `width` to null instead of raising
+            an error.
 
         Returns
         -------
@@ -1091,8 +1651,23 @@ class ExprListNameSpace:
         │ [1, 2]   ┆ [1, 2]       │
         │ [3, 4]   ┆ [3, 4]       │
         └──────────┴──────────────┘
-        """
-        return wrap_expr(self._pyexpr.list_to_array(width))
+
+        Sublists of another length can be set to null instead of raising an error.
+
+        >>> df = pl.DataFrame({"a": [[1, 2], [3], None]})
+        >>> df.with_columns(array=pl.col("a").list.to_array(2, null_on_mismatch=True))
+        shape: (3, 2)
+        ┌───────────┬───────────────┐
+        │ a         ┆ array         │
+        │ ---       ┆ ---           │
+        │ list[i64] ┆ array[i64, 2] │
+        ╞═══════════╪═══════════════╡
+        │ [1, 2]    ┆ [1, 2]        │
+        │ [3]       ┆ null          │
+        │ null      ┆ null          │
+        └───────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.list_to_array(width, null_on_mismatch))
 
     def to_struct(
         self,
@@ -1191,6 +1766,100 @@ class ExprListNameSpace:
             pyexpr = self._pyexpr.list_to_struct(n_field_strategy, fields, upper_bound)
             return wrap_expr(pyexpr)
 
+    def struct_field(self, name: str) -> Expr:
+        """
+        Get the field `name` of every struct in a list of structs.
+
+        Parameters
+        ----------
+        name
+            Name of the struct field to retrieve.
+
+        Returns
+        -------
+        Expr
+            Expr of data type :class:`List` with the inner data type of the field.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "events": [
+        ...             [{"ts": 1, "kind": "a"}, {"ts": 5, "kind": "b"}],
+        ...             [{"ts": 3, "kind": "a"}],
+        ...             None,
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(ts=pl.col("events").list.struct_field("ts"))
+        shape: (3, 2)
+        ┌────────────────────┬───────────┐
+        │ events             ┆ ts        │
+        │ ---                ┆ ---       │
+        │ list[struct[2]]    ┆ list[i64] │
+        ╞════════════════════╪═══════════╡
+        │ [{1,"a"}, {5,"b"}] ┆ [1, 5]    │
+        │ [{3,"a"}]          ┆ [3]       │
+        │ null               ┆ null      │
+        └────────────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_struct_field(name))
+
+    def value_counts(self, *, sort: bool = False, normalize: bool = False) -> Expr:
+        """
+        Count the occurrences of the unique values of every sublist.
+
+        Parameters
+        ----------
+        sort
+            Sort the output of every sublist by count in descending order.
+            If set to `False` (default), the values are in order of first occurrence.
+        normalize
+            Return the proportion of every value in the sublist instead of the count.
+            The field is named `proportion` instead of `count`.
+
+        Returns
+        -------
+        Expr
+            Expr of data type :class:`List` of :class:`Struct` with the fields `value`
+            and `count` (or `proportion`).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"tokens": [["a", "b", "a"], ["c"], [], None]})
+        >>> df.with_columns(counts=pl.col("tokens").list.value_counts(sort=True))
+        shape: (4, 2)
+        ┌─────────────────┬────────────────────┐
+        │ tokens          ┆ counts             │
+        │ ---             ┆ ---                │
+        │ list[str]       ┆ list[struct[2]]    │
+        ╞═════════════════╪════════════════════╡
+        │ ["a", "b", "a"] ┆ [{"a",2}, {"b",1}] │
+        │ ["c"]           ┆ [{"c",1}]          │
+        │ []              ┆ []                 │
+        │ null            ┆ null               │
+        └─────────────────┴────────────────────┘
+
+        Return the proportions instead of the counts.
+
+        >>> df.select(pl.col("tokens").list.value_counts(normalize=True)).explode(
+        ...     "tokens"
+        ... ).unnest("tokens")
+        shape: (5, 2)
+        ┌───────┬────────────┐
+        │ value ┆ proportion │
+        │ ---   ┆ ---        │
+        │ str   ┆ f64        │
+        ╞═══════╪════════════╡
+        │ a     ┆ 0.666667   │
+        │ b     ┆ 0.333333   │
+        │ c     ┆ 1.0        │
+        │ null  ┆ null       │
+        │ null  ┆ null       │
+        └───────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.list_value_counts(sort, normalize))
+
     def eval(self, expr: Expr, *, parallel: bool = False) -> Expr:
         """
         Run any polars expression against the lists' elements.
@@ -1226,6 +1895,37 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_eval(expr._pyexpr, parallel))
 
+    def filter(self, predicate: Expr) -> Expr:
+        """
+        Filter elements in each of the sublists by a boolean expression.
+
+        Parameters
+        ----------
+        predicate
+            Boolean expression that is evaluated against the elements of the
+            sublists. Refer to the elements with `pl.element()`.
+
+        See Also
+        --------
+        eval
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 8, 3], [4, 5, 2], []]})
+        >>> df.with_columns(filtered=pl.col("a").list.filter(pl.element() > 2))
+        shape: (3, 2)
+        ┌───────────┬───────────┐
+        │ a         ┆ filtered  │
+        │ ---       ┆ ---       │
+        │ list[i64] ┆ list[i64] │
+        ╞═══════════╪═══════════╡
+        │ [1, 8, 3] ┆ [8, 3]    │
+        │ [4, 5, 2] ┆ [4, 5]    │
+        │ []        ┆ []        │
+        └───────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_filter(predicate._pyexpr))
+
     def set_union(self, other: IntoExpr) -> Expr:
         """
         Compute the SET UNION between the elements in this list and the elements of `other`.
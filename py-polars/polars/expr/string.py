@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import warnings
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Mapping
 
 import polars._reexport as pl
 from polars import functions as F
@@ -12,22 +12,34 @@ from polars._utils.deprecation import (
     rename_use_earliest_to_ambiguous,
 )
 from polars._utils.parse_expr_input import parse_as_expression
-from polars._utils.various import find_stacklevel
+from polars._utils.various import find_stacklevel, no_default
 from polars._utils.wrap import wrap_expr
-from polars.datatypes import Date, Datetime, Int32, Time, py_type_to_dtype
+from polars.datatypes import (
+    Date,
+    Datetime,
+    Int32,
+    String,
+    Struct,
+    Time,
+    py_type_to_dtype,
+)
 from polars.datatypes.constants import N_INFER_DEFAULT
 from polars.exceptions import ChronoFormatWarning
 
 if TYPE_CHECKING:
     from polars import Expr
+    from polars._utils.various import NoDefault
     from polars.type_aliases import (
         Ambiguous,
+        DigestAlgorithm,
         IntoExpr,
         IntoExprColumn,
         PolarsDataType,
         PolarsTemporalType,
+        SchemaDict,
         TimeUnit,
         TransferEncoding,
+        UnicodeForm,
     )
 
 
@@ -141,6 +153,16 @@ class ExprStringNameSpace:
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
 
+        Notes
+        -----
+        Formats with a time zone offset (`%z`) or name (`%Z`) are parsed into UTC, so
+        values with different offsets can be parsed together. Time zone names are
+        either IANA time zone names, such as `"Europe/Amsterdam"`, or the
+        abbreviations `UT`, `UTC`, `GMT`, `Z`, `EST`, `EDT`, `CST`, `CDT`, `MST`,
+        `MDT`, `PST`, `PDT`, `WET`, `WEST`, `CET`, `CEST`, `EET`, `EEST`, `JST`,
+        `AWST`, `ACST`, `ACDT`, `AEST` and `AEDT`. Use :func:`to_utc_offset` to keep
+        the original offsets.
+
         Examples
         --------
         >>> s = pl.Series(["2020-01-01 01:00Z", "2020-01-01 02:00Z"])
@@ -151,6 +173,23 @@ class ExprStringNameSpace:
                 2020-01-01 01:00:00 UTC
                 2020-01-01 02:00:00 UTC
         ]
+
+        Parse values with different time zones, and keep their original offsets:
+
+        >>> df = pl.DataFrame({"s": ["2020-01-01 01:00 CET", "2020-01-01 02:00 UTC"]})
+        >>> df.select(
+        ...     datetime=pl.col("s").str.to_datetime("%Y-%m-%d %H:%M %Z"),
+        ...     offset=pl.col("s").str.to_utc_offset("%Y-%m-%d %H:%M %Z"),
+        ... )
+        shape: (2, 2)
+        ┌─────────────────────────┬──────────────┐
+        │ datetime                ┆ offset       │
+        │ ---                     ┆ ---          │
+        │ datetime[μs, UTC]       ┆ duration[ms] │
+        ╞═════════════════════════╪══════════════╡
+        │ 2020-01-01 00:00:00 UTC ┆ 1h           │
+        │ 2020-01-01 02:00:00 UTC ┆ 0ms          │
+        └─────────────────────────┴──────────────┘
         """
         _validate_format_argument(format)
         ambiguous = rename_use_earliest_to_ambiguous(use_earliest, ambiguous)
@@ -205,6 +244,62 @@ class ExprStringNameSpace:
         _validate_format_argument(format)
         return wrap_expr(self._pyexpr.str_to_time(format, strict, cache))
 
+    def to_utc_offset(
+        self,
+        format: str,
+        *,
+        strict: bool = True,
+        cache: bool = True,
+    ) -> Expr:
+        """
+        Parse the offsets from UTC of a String column with a time zone.
+
+        Parameters
+        ----------
+        format
+            Format of the strings, with a time zone offset (`%z`) or name (`%Z`).
+            Refer to the `chrono crate documentation
+            <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
+            for the full specification. Example: `"%Y-%m-%d %H:%M:%S%z"`.
+        strict
+            Raise an error if any conversion fails.
+        cache
+            Use a cache of unique, converted offsets to apply the conversion.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Duration` with time unit `"ms"`.
+
+        See Also
+        --------
+        to_datetime : Parse the strings into datetimes in UTC.
+
+        Notes
+        -----
+        The offset of an IANA time zone name, such as `"Europe/Amsterdam"`, is its
+        offset at the parsed datetime, including daylight saving time.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     [
+        ...         "2020-01-01 01:00 Europe/Amsterdam",
+        ...         "2020-07-01 01:00 Europe/Amsterdam",
+        ...         "2020-07-01 01:00 EST",
+        ...     ]
+        ... )
+        >>> s.str.to_utc_offset("%Y-%m-%d %H:%M %Z")
+        shape: (3,)
+        Series: '' [duration[ms]]
+        [
+                1h
+                2h
+                -5h
+        ]
+        """
+        return wrap_expr(self._pyexpr.str_to_utc_offset(format, strict, cache))
+
     def strptime(
         self,
         dtype: PolarsTemporalType,
@@ -549,10 +644,174 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_to_lowercase())
 
+    def casefold(self) -> Expr:
+        """
+        Fold the case of the string values, for caseless comparison.
+
+        Case folding is a more aggressive form of lowercasing that maps all
+        characters that only differ in case to the same characters. For example,
+        "ß" and "SS" both fold to "ss", whereas their lowercase variants differ.
+
+        Notes
+        -----
+        This is the full case folding of the Unicode standard, without the
+        Turkic-specific mappings, and is equivalent to Python's `str.casefold`.
+        Strings are not normalized; use :meth:`normalize` first to also compare
+        strings that differ in their Unicode normalization form.
+
+        See Also
+        --------
+        caseless_eq : Check if string values are equal, ignoring case.
+        to_lowercase : Transform to lowercase variant.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"foo": ["Straße", "STRASSE", "ΣΊΣΥΦΟΣ"]})
+        >>> df.with_columns(
+        ...     foo_lower=pl.col("foo").str.to_lowercase(),
+        ...     foo_folded=pl.col("foo").str.casefold(),
+        ... )
+        shape: (3, 3)
+        ┌─────────┬───────────┬────────────┐
+        │ foo     ┆ foo_lower ┆ foo_folded │
+        │ ---     ┆ ---       ┆ ---        │
+        │ str     ┆ str       ┆ str        │
+        ╞═════════╪═══════════╪════════════╡
+        │ Straße  ┆ straße    ┆ strasse    │
+        │ STRASSE ┆ strasse   ┆ strasse    │
+        │ ΣΊΣΥΦΟΣ ┆ σίσυφος   ┆ σίσυφοσ    │
+        └─────────┴───────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_casefold())
+
+    def caseless_eq(self, other: str | Expr) -> Expr:
+        """
+        Check if string values are equal to `other`, ignoring case.
+
+        Both sides are compared after :meth:`casefold`.
+
+        Parameters
+        ----------
+        other
+            String or expression to compare with.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["Straße", "Hello", None], "b": ["STRASSE", "hi", "x"]}
+        ... )
+        >>> df.with_columns(eq=pl.col("a").str.caseless_eq(pl.col("b")))
+        shape: (3, 3)
+        ┌────────┬─────────┬───────┐
+        │ a      ┆ b       ┆ eq    │
+        │ ---    ┆ ---     ┆ ---   │
+        │ str    ┆ str     ┆ bool  │
+        ╞════════╪═════════╪═══════╡
+        │ Straße ┆ STRASSE ┆ true  │
+        │ Hello  ┆ hi      ┆ false │
+        │ null   ┆ x       ┆ null  │
+        └────────┴─────────┴───────┘
+        """
+        other = parse_as_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_caseless_eq(other))
+
+    def caseless_contains(self, pattern: str | Expr) -> Expr:
+        """
+        Check if string values contain the literal substring `pattern`, ignoring case.
+
+        Both sides are compared after :meth:`casefold`.
+
+        Parameters
+        ----------
+        pattern
+            Literal substring, or an expression of substrings.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"street": ["Hauptstraße", "HAUPTSTRASSE 1", "Ringweg"]})
+        >>> df.filter(pl.col("street").str.caseless_contains("strasse"))
+        shape: (2, 1)
+        ┌────────────────┐
+        │ street         │
+        │ ---            │
+        │ str            │
+        ╞════════════════╡
+        │ Hauptstraße    │
+        │ HAUPTSTRASSE 1 │
+        └────────────────┘
+        """
+        pattern = parse_as_expression(pattern, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_caseless_contains(pattern))
+
+    def caseless_starts_with(self, prefix: str | Expr) -> Expr:
+        """
+        Check if string values start with `prefix`, ignoring case.
+
+        Both sides are compared after :meth:`casefold`.
+
+        Parameters
+        ----------
+        prefix
+            Prefix substring, or an expression of prefixes.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"fruits": ["Apple", "mango", None]})
+        >>> df.with_columns(has_prefix=pl.col("fruits").str.caseless_starts_with("APP"))
+        shape: (3, 2)
+        ┌────────┬────────────┐
+        │ fruits ┆ has_prefix │
+        │ ---    ┆ ---        │
+        │ str    ┆ bool       │
+        ╞════════╪════════════╡
+        │ Apple  ┆ true       │
+        │ mango  ┆ false      │
+        │ null   ┆ null       │
+        └────────┴────────────┘
+        """
+        prefix = parse_as_expression(prefix, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_caseless_starts_with(prefix))
+
+    def caseless_ends_with(self, suffix: str | Expr) -> Expr:
+        """
+        Check if string values end with `suffix`, ignoring case.
+
+        Both sides are compared after :meth:`casefold`.
+
+        Parameters
+        ----------
+        suffix
+            Suffix substring, or an expression of suffixes.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"fruits": ["apple", "MANGO", None]})
+        >>> df.with_columns(has_suffix=pl.col("fruits").str.caseless_ends_with("go"))
+        shape: (3, 2)
+        ┌────────┬────────────┐
+        │ fruits ┆ has_suffix │
+        │ ---    ┆ ---        │
+        │ str    ┆ bool       │
+        ╞════════╪════════════╡
+        │ apple  ┆ false      │
+        │ MANGO  ┆ true       │
+        │ null   ┆ null       │
+        └────────┴────────────┘
+        """
+        suffix = parse_as_expression(suffix, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_caseless_ends_with(suffix))
+
     def to_titlecase(self) -> Expr:
         """
         Transform to titlecase variant.
 
+        Notes
+        -----
+        The first character of every word is uppercased and the other characters
+        are lowercased. Words are delimited by Unicode word boundaries, so
+        punctuation such as hyphens also starts a new word, but apostrophes within
+        a word do not.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -858,7 +1117,13 @@ class ExprStringNameSpace:
         suffix = parse_as_expression(suffix, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_strip_suffix(suffix))
 
-    def pad_start(self, length: int, fill_char: str = " ") -> Expr:
+    def pad_start(
+        self,
+        length: int | IntoExprColumn,
+        fill_char: str = " ",
+        *,
+        display_width: bool = False,
+    ) -> Expr:
         """
         Pad the start of the string until it reaches the given length.
 
@@ -866,9 +1131,15 @@ class ExprStringNameSpace:
         ----------
         length
             Pad the string until it reaches this length. Strings with length equal to
-            or greater than this value are returned as-is.
+            or greater than this value are returned as-is. Can be an expression to
+            pad every string to a different length.
         fill_char
             The character to pad the string with.
+        display_width
+            Count the length in terminal display width rather than in characters,
+            so that strings with wide characters such as CJK line up. The string is
+            never padded beyond `length`, so if `fill_char` is wide the result may
+            be one column short.
 
         See Also
         --------
@@ -891,9 +1162,16 @@ class ExprStringNameSpace:
         │ null         ┆ null         │
         └──────────────┴──────────────┘
         """
-        return wrap_expr(self._pyexpr.str_pad_start(length, fill_char))
+        length = parse_as_expression(length)
+        return wrap_expr(self._pyexpr.str_pad_start(length, fill_char, display_width))
 
-    def pad_end(self, length: int, fill_char: str = " ") -> Expr:
+    def pad_end(
+        self,
+        length: int | IntoExprColumn,
+        fill_char: str = " ",
+        *,
+        display_width: bool = False,
+    ) -> Expr:
         """
         Pad the end of the string until it reaches the given length.
 
@@ -901,9 +1179,15 @@ class ExprStringNameSpace:
         ----------
         length
             Pad the string until it reaches this length. Strings with length equal to
-            or greater than this value are returned as-is.
+            or greater than this value are returned as-is. Can be an expression to
+            pad every string to a different length.
         fill_char
             The character to pad the string with.
+        display_width
+            Count the length in terminal display width rather than in characters,
+            so that strings with wide characters such as CJK line up. The string is
+            never padded beyond `length`, so if `fill_char` is wide the result may
+            be one column short.
 
         See Also
         --------
@@ -925,7 +1209,8 @@ class ExprStringNameSpace:
         │ null         ┆ null         │
         └──────────────┴──────────────┘
         """
-        return wrap_expr(self._pyexpr.str_pad_end(length, fill_char))
+        length = parse_as_expression(length)
+        return wrap_expr(self._pyexpr.str_pad_end(length, fill_char, display_width))
 
     @deprecate_renamed_parameter("alignment", "length", version="0.19.12")
     def zfill(self, length: int | IntoExprColumn) -> Expr:
@@ -969,6 +1254,45 @@ class ExprStringNameSpace:
         length = parse_as_expression(length)
         return wrap_expr(self._pyexpr.str_zfill(length))
 
+    def wrap(
+        self, width: int, *, break_long_words: bool = True, tab_size: int = 8
+    ) -> Expr:
+        r"""
+        Wrap the string values into lines of at most `width` characters.
+
+        Parameters
+        ----------
+        width
+            Maximum number of characters per line.
+        break_long_words
+            Break words that are longer than `width` across lines. If set to `False`,
+            such words are put on a line of their own.
+        tab_size
+            Expand tabs to the next multiple of this number of columns before
+            wrapping. If set to 0, tabs are removed.
+
+        Notes
+        -----
+        Words are separated by spaces. Existing line breaks are kept, and the
+        whitespace at a new line break is dropped. Strings that fit within `width`
+        are returned as-is.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"text": ["The quick brown fox jumps over the lazy dog", "short", None]}
+        ... )
+        >>> df.select(pl.col("text").str.wrap(10)).to_series().to_list()
+        ['The quick\nbrown fox\njumps over\nthe lazy\ndog', 'short', None]
+
+        >>> df = pl.DataFrame({"text": ["see https://example.com/a/long/path"]})
+        >>> df.select(
+        ...     pl.col("text").str.wrap(10, break_long_words=False)
+        ... ).to_series().to_list()
+        ['see\nhttps://example.com/a/long/path']
+        """
+        return wrap_expr(self._pyexpr.str_wrap(width, break_long_words, tab_size))
+
     def contains(
         self, pattern: str | Expr, *, literal: bool = False, strict: bool = True
     ) -> Expr:
@@ -1040,7 +1364,12 @@ class ExprStringNameSpace:
         return wrap_expr(self._pyexpr.str_contains(pattern, literal, strict))
 
     def find(
-        self, pattern: str | Expr, *, literal: bool = False, strict: bool = True
+        self,
+        pattern: str | Expr,
+        *,
+        literal: bool = False,
+        strict: bool = True,
+        char_index: bool = False,
     ) -> Expr:
         """
         Return the index position of the first substring matching a pattern.
@@ -1057,6 +1386,9 @@ class ExprStringNameSpace:
         strict
             Raise an error if the underlying pattern is not a valid regex,
             otherwise mask out with a null value.
+        char_index
+            Return the index as a number of characters instead of bytes. This
+            differs from the byte index if the string contains non-ASCII characters.
 
         Notes
         -----
@@ -1128,9 +1460,26 @@ class ExprStringNameSpace:
         │ null       ┆ [aeiuo]   ┆ null     │
         │ Crustaceon ┆ (?i)A[BC] ┆ 5        │
         └────────────┴───────────┴──────────┘
+
+        Non-ASCII characters take up more than one byte. Return the index as a
+        number of characters instead:
+
+        >>> pl.DataFrame({"s": ["añb", "ññb"]}).with_columns(
+        ...     byte_idx=pl.col("s").str.find("b"),
+        ...     char_idx=pl.col("s").str.find("b", char_index=True),
+        ... )
+        shape: (2, 3)
+        ┌─────┬──────────┬──────────┐
+        │ s   ┆ byte_idx ┆ char_idx │
+        │ --- ┆ ---      ┆ ---      │
+        │ str ┆ u32      ┆ u32      │
+        ╞═════╪══════════╪══════════╡
+        │ añb ┆ 3        ┆ 2        │
+        │ ññb ┆ 4        ┆ 2        │
+        └─────┴──────────┴──────────┘
         """
         pattern = parse_as_expression(pattern, str_as_lit=True)
-        return wrap_expr(self._pyexpr.str_find(pattern, literal, strict))
+        return wrap_expr(self._pyexpr.str_find(pattern, literal, strict, char_index))
 
     def ends_with(self, suffix: str | Expr) -> Expr:
         """
@@ -1195,6 +1544,60 @@ class ExprStringNameSpace:
         suffix = parse_as_expression(suffix, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_ends_with(suffix))
 
+    def escape_regex(self) -> Expr:
+        r"""
+        Escape all regular expression meta characters in the string values.
+
+        The escaped strings match themselves literally when used as a regex pattern,
+        so that values from the data can be interpolated into dynamic patterns.
+
+        See Also
+        --------
+        contains : Check if string contains a substring that matches a regex.
+        replace : Replace the first matching regex/literal substring.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["abc", "1+1=2", "$5.00", None]})
+        >>> df.with_columns(escaped=pl.col("text").str.escape_regex())
+        shape: (4, 2)
+        ┌───────┬─────────┐
+        │ text  ┆ escaped │
+        │ ---   ┆ ---     │
+        │ str   ┆ str     │
+        ╞═══════╪═════════╡
+        │ abc   ┆ abc     │
+        │ 1+1=2 ┆ 1\+1=2  │
+        │ $5.00 ┆ \$5\.00 │
+        │ null  ┆ null    │
+        └───────┴─────────┘
+
+        Build a regex pattern for every row from the data.
+
+        >>> df = pl.DataFrame(
+        ...     {"text": ["1+1=2", "11=2", "costs $5"], "value": ["1+1", "1+1", "$5"]}
+        ... )
+        >>> df.with_columns(
+        ...     starts_with=pl.col("text").str.contains(
+        ...         pl.lit("^") + pl.col("value").str.escape_regex()
+        ...     ),
+        ...     ends_with=pl.col("text").str.contains(
+        ...         pl.col("value").str.escape_regex() + pl.lit("$")
+        ...     ),
+        ... )
+        shape: (3, 4)
+        ┌──────────┬───────┬─────────────┬───────────┐
+        │ text     ┆ value ┆ starts_with ┆ ends_with │
+        │ ---      ┆ ---   ┆ ---         ┆ ---       │
+        │ str      ┆ str   ┆ bool        ┆ bool      │
+        ╞══════════╪═══════╪═════════════╪═══════════╡
+        │ 1+1=2    ┆ 1+1   ┆ true        ┆ false     │
+        │ 11=2     ┆ 1+1   ┆ false       ┆ false     │
+        │ costs $5 ┆ $5    ┆ false       ┆ true      │
+        └──────────┴───────┴─────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.str_escape_regex())
+
     def starts_with(self, prefix: str | Expr) -> Expr:
         """
         Check if string values start with a substring.
@@ -1260,22 +1663,30 @@ class ExprStringNameSpace:
 
     def json_decode(
         self,
-        dtype: PolarsDataType | None = None,
+        dtype: PolarsDataType | SchemaDict | None = None,
         infer_schema_length: int | None = N_INFER_DEFAULT,
+        *,
+        strict: bool = True,
     ) -> Expr:
         """
         Parse string values as JSON.
 
-        Throws an error if invalid JSON strings are encountered.
+        Throws an error if invalid JSON strings are encountered, unless `strict` is
+        set to False.
 
         Parameters
         ----------
         dtype
-            The dtype to cast the extracted value to. If None, the dtype will be
-            inferred from the JSON value.
+            The dtype to cast the extracted value to. A mapping of field names to
+            dtypes is decoded as a :class:`Struct` with those fields. If None, the
+            dtype will be inferred from the JSON value.
         infer_schema_length
             The maximum number of rows to scan for schema inference.
             If set to `None`, the full data may be scanned *(this is slow)*.
+        strict
+            Raise an error if a string is not valid JSON. If set to False, such
+            strings are decoded as null instead, and are ignored when inferring the
+            dtype.
 
         See Also
         --------
@@ -1299,10 +1710,31 @@ class ExprStringNameSpace:
         │ null                ┆ {null,null} │
         │ {"a":2, "b": false} ┆ {2,false}   │
         └─────────────────────┴─────────────┘
-        """
-        if dtype is not None:
+
+        Decode malformed JSON strings as null:
+
+        >>> df = pl.DataFrame({"json": ['{"a": 1}', '{"a": ', '{"a": 3}']})
+        >>> df.with_columns(
+        ...     decoded=pl.col("json").str.json_decode({"a": pl.Int8}, strict=False)
+        ... )
+        shape: (3, 2)
+        ┌──────────┬───────────┐
+        │ json     ┆ decoded   │
+        │ ---      ┆ ---       │
+        │ str      ┆ struct[1] │
+        ╞══════════╪═══════════╡
+        │ {"a": 1} ┆ {1}       │
+        │ {"a":    ┆ {null}    │
+        │ {"a": 3} ┆ {3}       │
+        └──────────┴───────────┘
+        """
+        if isinstance(dtype, Mapping):
+            dtype = Struct(dtype)
+        elif dtype is not None:
             dtype = py_type_to_dtype(dtype)
-        return wrap_expr(self._pyexpr.str_json_decode(dtype, infer_schema_length))
+        return wrap_expr(
+            self._pyexpr.str_json_decode(dtype, infer_schema_length, strict)
+        )
 
     def json_path_match(self, json_path: IntoExprColumn) -> Expr:
         """
@@ -1354,8 +1786,9 @@ class ExprStringNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base32', 'percent'}
+            The encoding to use. `'percent'` is the URL percent-encoding, which
+            encodes all characters except the unreserved characters of RFC 3986.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -1379,13 +1812,36 @@ class ExprStringNameSpace:
         │ ffff00 ┆ b"\xff\xff\x00" │
         │ 0000ff ┆ b"\x00\x00\xff" │
         └────────┴─────────────────┘
+
+        Decode percent-encoded URL components and cast the result back to strings.
+
+        >>> df = pl.DataFrame({"key": ["a%20b%2Fc.csv", "caf%C3%A9", "100%"]})
+        >>> decoded = pl.col("key").str.decode("percent", strict=False)
+        >>> df.with_columns(decoded=decoded.cast(pl.String))
+        shape: (3, 2)
+        ┌───────────────┬───────────┐
+        │ key           ┆ decoded   │
+        │ ---           ┆ ---       │
+        │ str           ┆ str       │
+        ╞═══════════════╪═══════════╡
+        │ a%20b%2Fc.csv ┆ a b/c.csv │
+        │ caf%C3%A9     ┆ café      │
+        │ 100%          ┆ null      │
+        └───────────────┴───────────┘
         """
         if encoding == "hex":
             return wrap_expr(self._pyexpr.str_hex_decode(strict))
         elif encoding == "base64":
             return wrap_expr(self._pyexpr.str_base64_decode(strict))
+        elif encoding == "base32":
+            return wrap_expr(self._pyexpr.str_base32_decode(strict))
+        elif encoding == "percent":
+            return wrap_expr(self._pyexpr.str_percent_decode(strict))
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'base32', 'percent'},"
+                f" got {encoding!r}"
+            )
             raise ValueError(msg)
 
     def encode(self, encoding: TransferEncoding) -> Expr:
@@ -1394,8 +1850,9 @@ class ExprStringNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base32', 'percent'}
+            The encoding to use. `'percent'` is the URL percent-encoding, which
+            encodes all characters except the unreserved characters of RFC 3986.
 
         Returns
         -------
@@ -1421,8 +1878,15 @@ class ExprStringNameSpace:
             return wrap_expr(self._pyexpr.str_hex_encode())
         elif encoding == "base64":
             return wrap_expr(self._pyexpr.str_base64_encode())
+        elif encoding == "base32":
+            return wrap_expr(self._pyexpr.str_base32_encode())
+        elif encoding == "percent":
+            return wrap_expr(self._pyexpr.str_percent_encode())
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'base32', 'percent'},"
+                f" got {encoding!r}"
+            )
             raise ValueError(msg)
 
     def extract(self, pattern: IntoExprColumn, group_index: int = 1) -> Expr:
@@ -2143,6 +2607,211 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_reverse())
 
+    def normalize(self, form: UnicodeForm = "NFC") -> Expr:
+        """
+        Normalize the string values to a Unicode normalization form.
+
+        Parameters
+        ----------
+        form : {'NFC', 'NFD', 'NFKC', 'NFKD'}
+            Unicode normalization form to convert to. The canonical forms (NFC and
+            NFD) only unify different representations of the same character, the
+            compatibility forms (NFKC and NFKD) also unify characters that have the
+            same meaning, such as ligatures. See the `Unicode standard
+            <https://www.unicode.org/reports/tr15/>`_ for more information.
+
+        Notes
+        -----
+        ASCII strings are the same in every normalization form and are returned as-is.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["01²", "ﬁle", "cafe\u0301"]})
+        >>> df.with_columns(
+        ...     nfc=pl.col("text").str.normalize("NFC"),
+        ...     nfkc=pl.col("text").str.normalize("NFKC"),
+        ...     nfc_len=pl.col("text").str.normalize("NFC").str.len_chars(),
+        ...     nfd_len=pl.col("text").str.normalize("NFD").str.len_chars(),
+        ... )
+        shape: (3, 5)
+        ┌──────┬──────┬──────┬─────────┬─────────┐
+        │ text ┆ nfc  ┆ nfkc ┆ nfc_len ┆ nfd_len │
+        │ ---  ┆ ---  ┆ ---  ┆ ---     ┆ ---     │
+        │ str  ┆ str  ┆ str  ┆ u32     ┆ u32     │
+        ╞══════╪══════╪══════╪═════════╪═════════╡
+        │ 01²  ┆ 01²  ┆ 012  ┆ 3       ┆ 3       │
+        │ ﬁle  ┆ ﬁle  ┆ file ┆ 3       ┆ 3       │
+        │ café ┆ café ┆ café ┆ 4       ┆ 5       │
+        └──────┴──────┴──────┴─────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_normalize(form))
+
+    def digest(self, algorithm: DigestAlgorithm, *, as_hex: bool = True) -> Expr:
+        """
+        Hash the UTF-8 bytes of each string with a cryptographic hash or checksum.
+
+        Parameters
+        ----------
+        algorithm : {'md5', 'sha1', 'sha256', 'crc32'}
+            The hash function to digest the values with. The CRC-32 checksum is the
+            one used by zlib and gzip, its four bytes are in big endian order.
+        as_hex
+            Return the digest as a lowercase hex string. If set to `False`, the
+            digest is returned as binary instead.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String` if `as_hex` is set, and of data
+            type :class:`Binary` otherwise.
+
+        See Also
+        --------
+        Expr.hash : Hash the values with a fast, non-cryptographic hash function.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["polars", "", None]})
+        >>> df.with_columns(sha256=pl.col("text").str.digest("sha256"))
+        shape: (3, 2)
+        ┌────────┬─────────────────────────────────┐
+        │ text   ┆ sha256                          │
+        │ ---    ┆ ---                             │
+        │ str    ┆ str                             │
+        ╞════════╪═════════════════════════════════╡
+        │ polars ┆ e37e7e864c58e1e96380229f66040d… │
+        │        ┆ e3b0c44298fc1c149afbf4c8996fb9… │
+        │ null   ┆ null                            │
+        └────────┴─────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_digest(algorithm, as_hex))
+
+    def levenshtein(self, other: str | Expr) -> Expr:
+        """
+        Compute the Levenshtein distance to another string.
+
+        The Levenshtein distance is the minimal number of single-character
+        insertions, deletions and substitutions to change one string into the other.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings to compare with. Strings are parsed as
+            literals, not as column names.
+
+        See Also
+        --------
+        hamming
+        jaro_winkler
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "polars", "pandas", None],
+        ...         "b": ["sitting", "polar", "pandas", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     dist=pl.col("a").str.levenshtein(pl.col("b")),
+        ...     dist_lit=pl.col("a").str.levenshtein("polars"),
+        ... )
+        shape: (4, 4)
+        ┌────────┬─────────┬──────┬──────────┐
+        │ a      ┆ b       ┆ dist ┆ dist_lit │
+        │ ---    ┆ ---     ┆ ---  ┆ ---      │
+        │ str    ┆ str     ┆ u32  ┆ u32      │
+        ╞════════╪═════════╪══════╪══════════╡
+        │ kitten ┆ sitting ┆ 3    ┆ 6        │
+        │ polars ┆ polar   ┆ 1    ┆ 0        │
+        │ pandas ┆ pandas  ┆ 0    ┆ 4        │
+        │ null   ┆ x       ┆ null ┆ null     │
+        └────────┴─────────┴──────┴──────────┘
+        """
+        other = parse_as_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein(other))
+
+    def hamming(self, other: str | Expr) -> Expr:
+        """
+        Compute the Hamming distance to another string.
+
+        The Hamming distance is the number of positions at which the characters of
+        two strings differ. It is null if the strings have a different number of
+        characters.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings to compare with. Strings are parsed as
+            literals, not as column names.
+
+        See Also
+        --------
+        levenshtein
+        jaro_winkler
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["karolin", "1011101", "abc"], "b": ["kathrin", "1001001", "ab"]}
+        ... )
+        >>> df.with_columns(dist=pl.col("a").str.hamming(pl.col("b")))
+        shape: (3, 3)
+        ┌─────────┬─────────┬──────┐
+        │ a       ┆ b       ┆ dist │
+        │ ---     ┆ ---     ┆ ---  │
+        │ str     ┆ str     ┆ u32  │
+        ╞═════════╪═════════╪══════╡
+        │ karolin ┆ kathrin ┆ 3    │
+        │ 1011101 ┆ 1001001 ┆ 2    │
+        │ abc     ┆ ab      ┆ null │
+        └─────────┴─────────┴──────┘
+        """
+        other = parse_as_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming(other))
+
+    def jaro_winkler(self, other: str | Expr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The Jaro-Winkler similarity ranges from 0 for strings without any characters
+        in common to 1 for equal strings. Strings that share a prefix of up to four
+        characters are considered more similar.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings to compare with. Strings are parsed as
+            literals, not as column names.
+
+        See Also
+        --------
+        levenshtein
+        hamming
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["martha", "dwayne", "dixon"],
+        ...         "b": ["marhta", "duane", "dicksonx"],
+        ...     }
+        ... )
+        >>> df.with_columns(sim=pl.col("a").str.jaro_winkler(pl.col("b")))
+        shape: (3, 3)
+        ┌────────┬──────────┬──────────┐
+        │ a      ┆ b        ┆ sim      │
+        │ ---    ┆ ---      ┆ ---      │
+        │ str    ┆ str      ┆ f64      │
+        ╞════════╪══════════╪══════════╡
+        │ martha ┆ marhta   ┆ 0.961111 │
+        │ dwayne ┆ duane    ┆ 0.84     │
+        │ dixon  ┆ dicksonx ┆ 0.813333 │
+        └────────┴──────────┴──────────┘
+        """
+        other = parse_as_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaro_winkler(other))
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Expr:
@@ -2152,10 +2821,11 @@ class ExprStringNameSpace:
         Parameters
         ----------
         offset
-            Start index. Negative indexing is supported.
+            Start index (integer or expression). Negative indexing is supported.
+            A null offset results in a null value.
         length
-            Length of the slice. If set to `None` (default), the slice is taken to the
-            end of the string.
+            Length of the slice (integer or expression). If set to `None` (default),
+            the slice is taken to the end of the string, as it is for null lengths.
 
         Returns
         -------
@@ -2201,6 +2871,27 @@ class ExprStringNameSpace:
         │ papaya      ┆ ya    │
         │ dragonfruit ┆ onf   │
         └─────────────┴───────┘
+
+        Taking the offset and length from other columns
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "s": ["pear", "papaya", "dragonfruit"],
+        ...         "i": [1, -4, 6],
+        ...         "n": [2, 3, None],
+        ...     }
+        ... )
+        >>> df.with_columns(pl.col("s").str.slice("i", "n").alias("slice"))
+        shape: (3, 4)
+        ┌─────────────┬─────┬──────┬───────┐
+        │ s           ┆ i   ┆ n    ┆ slice │
+        │ ---         ┆ --- ┆ ---  ┆ ---   │
+        │ str         ┆ i64 ┆ i64  ┆ str   │
+        ╞═════════════╪═════╪══════╪═══════╡
+        │ pear        ┆ 1   ┆ 2    ┆ ea    │
+        │ papaya      ┆ -4  ┆ 3    ┆ pay   │
+        │ dragonfruit ┆ 6   ┆ null ┆ fruit │
+        └─────────────┴─────┴──────┴───────┘
         """
         offset = parse_as_expression(offset)
         length = parse_as_expression(length)
@@ -2390,8 +3081,8 @@ class ExprStringNameSpace:
         Parameters
         ----------
         base
-            Positive integer or expression which is the base of the string
-            we are parsing.
+            Integer between 2 and 36 or expression which is the base of the
+            string we are parsing.
             Default: 10.
         strict
             Bool, Default=True will raise any ParseError or overflow as ComputeError.
@@ -2621,7 +3312,8 @@ class ExprStringNameSpace:
         Parameters
         ----------
         patterns
-            String patterns to search.
+            String patterns to search. All patterns are searched for in every
+            string, also if they are given as a column. Null patterns are ignored.
         ascii_case_insensitive
             Enable ASCII-aware case insensitive matching.
             When this option is enabled, searching will be performed without respect
@@ -2660,8 +3352,8 @@ class ExprStringNameSpace:
 
     def replace_many(
         self,
-        patterns: IntoExpr,
-        replace_with: IntoExpr,
+        patterns: IntoExpr | Mapping[str, str],
+        replace_with: IntoExpr | NoDefault = no_default,
         *,
         ascii_case_insensitive: bool = False,
     ) -> Expr:
@@ -2673,6 +3365,8 @@ class ExprStringNameSpace:
         ----------
         patterns
             String patterns to search and replace.
+            Also accepts a mapping of patterns to their replacement as syntactic sugar
+            for `replace_many(pl.Series(mapping.keys()), pl.Series(mapping.values()))`.
         replace_with
             Strings to replace where a pattern was a match.
             This can be broadcasted. So it supports many:one and many:many.
@@ -2681,6 +3375,11 @@ class ExprStringNameSpace:
             When this option is enabled, searching will be performed without respect
             to case for ASCII letters (a-z and A-Z) only.
 
+        Notes
+        -----
+        All patterns are replaced in a single pass over every string, so a
+        replacement is never matched by another pattern.
+
         Examples
         --------
         >>> _ = pl.Config.set_fmt_str_lengths(100)
@@ -2729,7 +3428,29 @@ class ExprStringNameSpace:
         │ Tell me what you want, what you really really want ┆ Tell you what me want, what me really really want │
         │ Can you feel the love tonight                      ┆ Can me feel the love tonight                      │
         └────────────────────────────────────────────────────┴───────────────────────────────────────────────────┘
+
+        Pass a mapping of patterns to their replacement.
+
+        >>> df = pl.DataFrame({"text": ["a cat and a dog", "no pets"]})
+        >>> df.with_columns(
+        ...     swapped=pl.col("text").str.replace_many({"cat": "dog", "dog": "cat"})
+        ... )
+        shape: (2, 2)
+        ┌─────────────────┬─────────────────┐
+        │ text            ┆ swapped         │
+        │ ---             ┆ ---             │
+        │ str             ┆ str             │
+        ╞═════════════════╪═════════════════╡
+        │ a cat and a dog ┆ a dog and a cat │
+        │ no pets         ┆ no pets         │
+        └─────────────────┴─────────────────┘
         """  # noqa: W505
+        if replace_with is no_default:
+            if not isinstance(patterns, Mapping):
+                msg = "`replace_with` must be given if `patterns` is not a mapping"
+                raise TypeError(msg)
+            replace_with = pl.Series(patterns.values(), dtype=String)
+            patterns = pl.Series(patterns.keys(), dtype=String)
         patterns = parse_as_expression(patterns, str_as_lit=False, list_as_lit=False)
         replace_with = parse_as_expression(
             replace_with, str_as_lit=True, list_as_lit=False
@@ -2741,6 +3462,61 @@ class ExprStringNameSpace:
         )
 
 
+    def extract_many(
+        self,
+        patterns: IntoExpr,
+        *,
+        ascii_case_insensitive: bool = False,
+        overlapping: bool = False,
+    ) -> Expr:
+        """
+        Use the aho-corasick algorithm to extract many matches.
+
+        Parameters
+        ----------
+        patterns
+            String patterns to search.
+        ascii_case_insensitive
+            Enable ASCII-aware case insensitive matching.
+            When this option is enabled, searching will be performed without respect
+            to case for ASCII letters (a-z and A-Z) only.
+        overlapping
+            Whether matches may overlap.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List` of :class:`String` with the matches
+            in order of occurrence.
+
+        Examples
+        --------
+        >>> _ = pl.Config.set_fmt_str_lengths(100)
+        >>> df = pl.DataFrame({"values": ["discontent", "reconstruct", None]})
+        >>> patterns = ["winter", "disco", "onte", "discontent", "struct"]
+        >>> df.with_columns(
+        ...     matches=pl.col("values").str.extract_many(patterns),
+        ...     matches_overlapping=pl.col("values").str.extract_many(
+        ...         patterns, overlapping=True
+        ...     ),
+        ... )
+        shape: (3, 3)
+        ┌─────────────┬────────────┬─────────────────────────────────┐
+        │ values      ┆ matches    ┆ matches_overlapping             │
+        │ ---         ┆ ---        ┆ ---                             │
+        │ str         ┆ list[str]  ┆ list[str]                       │
+        ╞═════════════╪════════════╪═════════════════════════════════╡
+        │ discontent  ┆ ["disco"]  ┆ ["disco", "onte", "discontent"] │
+        │ reconstruct ┆ ["struct"] ┆ ["struct"]                      │
+        │ null        ┆ null       ┆ null                            │
+        └─────────────┴────────────┴─────────────────────────────────┘
+        """
+        patterns = parse_as_expression(patterns, str_as_lit=False, list_as_lit=False)
+        return wrap_expr(
+            self._pyexpr.str_extract_many(patterns, ascii_case_insensitive, overlapping)
+        )
+
+
 def _validate_format_argument(format: str | None) -> None:
     if format is not None and ".%f" in format:
         message = (
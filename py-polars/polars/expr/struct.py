@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import os
-from typing import TYPE_CHECKING, Iterable, Sequence
+from typing import TYPE_CHECKING, Callable, Iterable, Mapping, Sequence
 
 from polars._utils.parse_expr_input import parse_as_list_of_expressions
 from polars._utils.wrap import wrap_expr
@@ -35,7 +35,9 @@ class ExprStructNameSpace:
         Parameters
         ----------
         name
-            Name of the struct field to retrieve.
+            Name of the struct field to retrieve. Accepts `"*"` to retrieve all fields
+            and regular expressions starting with `^` and ending with `$` to retrieve
+            the matching fields, like `pl.col` does for columns.
         *more_names
             Additional struct field names.
 
@@ -126,6 +128,19 @@ class ExprStructNameSpace:
         │ 2   ┆ cd  │
         └─────┴─────┘
 
+        The expanded fields can be renamed with the `name` namespace:
+
+        >>> df.select(pl.col("struct_col").struct.field("*").name.prefix("struct_"))
+        shape: (2, 4)
+        ┌────────────┬────────────┬────────────┬────────────┐
+        │ struct_aaa ┆ struct_bbb ┆ struct_ccc ┆ struct_ddd │
+        │ ---        ┆ ---        ┆ ---        ┆ ---        │
+        │ i64        ┆ str        ┆ bool       ┆ list[i64]  │
+        ╞════════════╪════════════╪════════════╪════════════╡
+        │ 1          ┆ ab         ┆ true       ┆ [1, 2]     │
+        │ 2          ┆ cd         ┆ null       ┆ [3]        │
+        └────────────┴────────────┴────────────┴────────────┘
+
         """
         if more_names:
             name = [*([name] if isinstance(name, str) else name), *more_names]
@@ -134,14 +149,238 @@ class ExprStructNameSpace:
 
         return wrap_expr(self._pyexpr.struct_field_by_name(name))
 
-    def rename_fields(self, names: Sequence[str]) -> Expr:
+    def field_by_path(self, path: str) -> Expr:
+        """
+        Retrieve a nested field of this `Struct` by its path.
+
+        Parameters
+        ----------
+        path
+            Field names separated by `.`, where each name can be followed by list
+            indices in square brackets, for example `"b.c[0].d"`. Negative indices
+            count from the end of the list.
+
+        Notes
+        -----
+        List indices that are out of bounds give null. Fields that don't exist are
+        raised when the schema is resolved, before the query is executed.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [
+        ...             {"b": {"c": [{"d": 1}, {"d": 2}]}},
+        ...             {"b": {"c": [{"d": 3}]}},
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(
+        ...     pl.col("a").struct.field_by_path("b.c[0].d").alias("first"),
+        ...     pl.col("a").struct.field_by_path("b.c[1].d").alias("second"),
+        ... )
+        shape: (2, 2)
+        ┌───────┬────────┐
+        │ first ┆ second │
+        │ ---   ┆ ---    │
+        │ i64   ┆ i64    │
+        ╞═══════╪════════╡
+        │ 1     ┆ 2      │
+        │ 3     ┆ null   │
+        └───────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.struct_field_by_path(path))
+
+    def select_fields(self, names: str | Sequence[str], *more_names: str) -> Expr:
+        """
+        Keep only the given fields of the struct, in the given order.
+
+        Parameters
+        ----------
+        names
+            Name(s) of the struct fields to keep.
+        *more_names
+            Additional struct field names.
+
+        See Also
+        --------
+        drop_fields
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"s": [{"a": 1, "b": "x", "c": True}]})
+        >>> df.select(pl.col("s").struct.select_fields("c", "a")).unnest("s")
+        shape: (1, 2)
+        ┌──────┬─────┐
+        │ c    ┆ a   │
+        │ ---  ┆ --- │
+        │ bool ┆ i64 │
+        ╞══════╪═════╡
+        │ true ┆ 1   │
+        └──────┴─────┘
+        """
+        names = [names] if isinstance(names, str) else list(names)
+        names.extend(more_names)
+        return wrap_expr(self._pyexpr.struct_select_fields(names))
+
+    def drop_fields(self, names: str | Sequence[str], *more_names: str) -> Expr:
+        """
+        Remove the given fields from the struct.
+
+        Parameters
+        ----------
+        names
+            Name(s) of the struct fields to remove.
+        *more_names
+            Additional struct field names.
+
+        See Also
+        --------
+        select_fields
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"s": [{"a": 1, "b": "x", "c": True}]})
+        >>> df.select(pl.col("s").struct.drop_fields("b")).unnest("s")
+        shape: (1, 2)
+        ┌─────┬──────┐
+        │ a   ┆ c    │
+        │ --- ┆ ---  │
+        │ i64 ┆ bool │
+        ╞═════╪══════╡
+        │ 1   ┆ true │
+        └─────┴──────┘
+        """
+        names = [names] if isinstance(names, str) else list(names)
+        names.extend(more_names)
+        return wrap_expr(self._pyexpr.struct_drop_fields(names))
+
+    def sum_fields(self) -> Expr:
+        """
+        Sum the numeric fields of the struct per row.
+
+        Fields that are not numeric are skipped. Null values are ignored, so a row
+        without any non-null values sums to zero.
+
+        See Also
+        --------
+        polars.sum_horizontal
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"s": [{"a": 1, "b": 2.5, "c": "x"}, {"a": None, "b": 4.0, "c": "y"}]}
+        ... )
+        >>> df.select(pl.col("s").struct.sum_fields())
+        shape: (2, 1)
+        ┌─────┐
+        │ s   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 3.5 │
+        │ 4.0 │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.struct_sum_fields())
+
+    def mean_fields(self) -> Expr:
+        """
+        Compute the mean of the numeric fields of the struct per row.
+
+        Fields that are not numeric are skipped. Null values are ignored.
+
+        See Also
+        --------
+        polars.mean_horizontal
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"s": [{"a": 1, "b": 4, "c": "x"}, {"a": 6, "b": None, "c": "y"}]}
+        ... )
+        >>> df.select(pl.col("s").struct.mean_fields())
+        shape: (2, 1)
+        ┌─────┐
+        │ s   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 2.5 │
+        │ 6.0 │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.struct_mean_fields())
+
+    def min_fields(self) -> Expr:
+        """
+        Get the minimum of the numeric fields of the struct per row.
+
+        Fields that are not numeric are skipped. Null values are ignored.
+
+        See Also
+        --------
+        polars.min_horizontal
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"s": [{"a": 1, "b": 4, "c": "x"}, {"a": 6, "b": None, "c": "y"}]}
+        ... )
+        >>> df.select(pl.col("s").struct.min_fields())
+        shape: (2, 1)
+        ┌─────┐
+        │ s   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 6   │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.struct_min_fields())
+
+    def max_fields(self) -> Expr:
+        """
+        Get the maximum of the numeric fields of the struct per row.
+
+        Fields that are not numeric are skipped. Null values are ignored.
+
+        See Also
+        --------
+        polars.max_horizontal
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"s": [{"a": 1, "b": 4, "c": "x"}, {"a": 6, "b": None, "c": "y"}]}
+        ... )
+        >>> df.select(pl.col("s").struct.max_fields())
+        shape: (2, 1)
+        ┌─────┐
+        │ s   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 4   │
+        │ 6   │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.struct_max_fields())
+
+    def rename_fields(
+        self, names: Sequence[str] | Mapping[str, str] | Callable[[str], str]
+    ) -> Expr:
         """
         Rename the fields of the struct.
 
         Parameters
         ----------
         names
-            New names, given in the same order as the struct's fields.
+            New names, given in the same order as the struct's fields. Alternatively,
+            a mapping from old to new names, which leaves the fields that are not in
+            the mapping as they are, or a function that takes an old name and returns
+            the new name.
 
         Examples
         --------
@@ -195,13 +434,49 @@ class ExprStructNameSpace:
 
         >>> df.select(pl.col("struct_col").struct.field("aaa"))  # doctest: +SKIP
         StructFieldNotFoundError: aaa
+
+        Rename some of the fields by name, or all of them with a function:
+
+        >>> df.select(
+        ...     pl.col("struct_col").struct.rename_fields({"www": "w", "yyy": "y"})
+        ... ).unnest("struct_col")
+        shape: (2, 4)
+        ┌─────┬─────┬──────┬───────────┐
+        │ w   ┆ xxx ┆ y    ┆ zzz       │
+        │ --- ┆ --- ┆ ---  ┆ ---       │
+        │ i64 ┆ str ┆ bool ┆ list[i64] │
+        ╞═════╪═════╪══════╪═══════════╡
+        │ 1   ┆ ab  ┆ true ┆ [1, 2]    │
+        │ 2   ┆ cd  ┆ null ┆ [3]       │
+        └─────┴─────┴──────┴───────────┘
+        >>> df.select(
+        ...     pl.col("struct_col").struct.rename_fields(lambda name: name.upper())
+        ... ).unnest("struct_col")
+        shape: (2, 4)
+        ┌─────┬─────┬──────┬───────────┐
+        │ WWW ┆ XXX ┆ YYY  ┆ ZZZ       │
+        │ --- ┆ --- ┆ ---  ┆ ---       │
+        │ i64 ┆ str ┆ bool ┆ list[i64] │
+        ╞═════╪═════╪══════╪═══════════╡
+        │ 1   ┆ ab  ┆ true ┆ [1, 2]    │
+        │ 2   ┆ cd  ┆ null ┆ [3]       │
+        └─────┴─────┴──────┴───────────┘
         """
+        if isinstance(names, Mapping):
+            return wrap_expr(
+                self._pyexpr.struct_rename_fields_by_mapping(list(names.items()))
+            )
+        elif callable(names):
+            return wrap_expr(self._pyexpr.name_map_fields(names))
         return wrap_expr(self._pyexpr.struct_rename_fields(names))
 
     def json_encode(self) -> Expr:
         """
         Convert this struct to a string column with json values.
 
+        Nested lists and structs are encoded as json arrays and objects, temporal
+        values are encoded as strings. This is the inverse of `str.json_decode`.
+
         Examples
         --------
         >>> pl.DataFrame(
@@ -227,7 +502,9 @@ class ExprStructNameSpace:
         """
         Add or overwrite fields of this struct.
 
-        This is similar to `with_columns` on `DataFrame`.
+        This is similar to `with_columns` on `DataFrame`. The expressions are
+        evaluated in the context of the fields of this struct, use :func:`field` to
+        select them.
 
         Examples
         --------
@@ -250,8 +527,8 @@ class ExprStructNameSpace:
         └───────────┴──────────┘
         >>> df = df.with_columns(
         ...     pl.col("coords").struct.with_fields(
-        ...         pl.col("coords").struct.field("x").sqrt(),
-        ...         y_mul=pl.col("coords").struct.field("y") * pl.col("multiply"),
+        ...         pl.field("x").sqrt(),
+        ...         y_mul=pl.field("y") * pl.col("multiply"),
         ...     ),
         ... )
         >>> df
@@ -25,7 +25,12 @@ from polars.functions.as_datatype import (
 from polars.functions.as_datatype import date_ as date
 from polars.functions.as_datatype import datetime_ as datetime
 from polars.functions.as_datatype import time_ as time
-from polars.functions.business import business_day_count
+from polars.functions.business import (
+    business_day_count,
+    holiday_calendars,
+    register_holiday_calendar,
+    unregister_holiday_calendar,
+)
 from polars.functions.col import col
 from polars.functions.eager import align_frames, concat
 from polars.functions.lazy import (
@@ -48,6 +53,7 @@ from polars.functions.lazy import (
     cumreduce,
     element,
     exclude,
+    field,
     first,
     fold,
     from_epoch,
@@ -145,12 +151,14 @@ __all__ = [
     "datetime",  # named datetime_, see import above
     "duration",
     "exclude",
+    "field",
     "first",
     "fold",
     "format",
     "from_epoch",
     "groups",
     "head",
+    "holiday_calendars",
     "implode",
     "int_range",
     "int_ranges",
@@ -166,6 +174,7 @@ __all__ = [
     "nth",
     "quantile",
     "reduce",
+    "register_holiday_calendar",
     "rolling_corr",
     "rolling_cov",
     "select",
@@ -174,6 +183,7 @@ __all__ = [
     "struct",
     "tail",
     "time",
+    "unregister_holiday_calendar",
     "var",
     # polars.functions.len
     "len",
@@ -14,12 +14,125 @@ if TYPE_CHECKING:
     from polars import Expr
     from polars.type_aliases import IntoExprColumn
 
+_DEFAULT_WEEK_MASK = (True, True, True, True, True, False, False)
+
+
+def register_holiday_calendar(
+    name: str,
+    *,
+    week_mask: Iterable[bool] = _DEFAULT_WEEK_MASK,
+    holidays: Iterable[date] = (),
+) -> None:
+    """
+    Register a holiday calendar under `name`.
+
+    Business day functions and expressions can refer to a registered calendar with
+    their `calendar` parameter, instead of being passed the same `week_mask` and
+    `holidays` every time. Registering a calendar under an existing name replaces
+    that calendar.
+
+    The calendar is looked up when the expression is created, so replacing or
+    unregistering it does not affect existing expressions. Calendars are not
+    supported by :meth:`DataFrame.group_by_dynamic` and :meth:`DataFrame.rolling`,
+    whose windows are always measured in calendar days.
+
+    Parameters
+    ----------
+    name
+        Name to register the calendar under.
+    week_mask
+        Which days of the week are business days. The default is Monday to Friday.
+    holidays
+        Holidays that are not business days.
+
+    See Also
+    --------
+    unregister_holiday_calendar
+    holiday_calendars
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> pl.register_holiday_calendar(
+    ...     "exchange", holidays=[date(2020, 1, 1), date(2020, 12, 25)]
+    ... )
+    >>> df = pl.DataFrame({"date": [date(2020, 1, 1), date(2020, 1, 2)]})
+    >>> df.with_columns(
+    ...     business_day=pl.col("date").dt.is_business_day(calendar="exchange"),
+    ...     next_business_day=pl.col("date").dt.add_business_days(
+    ...         1, calendar="exchange", roll="forward"
+    ...     ),
+    ... )
+    shape: (2, 3)
+    ┌────────────┬──────────────┬───────────────────┐
+    │ date       ┆ business_day ┆ next_business_day │
+    │ ---        ┆ ---          ┆ ---               │
+    │ date       ┆ bool         ┆ date              │
+    ╞════════════╪══════════════╪═══════════════════╡
+    │ 2020-01-01 ┆ false        ┆ 2020-01-03        │
+    │ 2020-01-02 ┆ true         ┆ 2020-01-03        │
+    └────────────┴──────────────┴───────────────────┘
+    >>> pl.unregister_holiday_calendar("exchange")
+    True
+    """
+    unix_epoch = date(1970, 1, 1)
+    plr.register_holiday_calendar(
+        name, tuple(week_mask), [(holiday - unix_epoch).days for holiday in holidays]
+    )
+
+
+def unregister_holiday_calendar(name: str) -> bool:
+    """
+    Remove the holiday calendar registered under `name`.
+
+    Parameters
+    ----------
+    name
+        Name of the calendar.
+
+    Returns
+    -------
+    bool
+        Whether a calendar was registered under `name`.
+
+    See Also
+    --------
+    register_holiday_calendar
+    """
+    return plr.unregister_holiday_calendar(name)
+
+
+def holiday_calendars() -> list[str]:
+    """
+    Get the names of the registered holiday calendars, in sorted order.
+
+    See Also
+    --------
+    register_holiday_calendar
+    """
+    return plr.holiday_calendar_names()
+
+
+def _resolve_holiday_calendar(
+    calendar: str | None, week_mask: Iterable[bool], holidays: Iterable[date]
+) -> tuple[Iterable[bool], list[int]]:
+    """Get the week mask and the holidays as days since the UNIX epoch."""
+    week_mask = tuple(week_mask)
+    unix_epoch = date(1970, 1, 1)
+    holidays_int = [(holiday - unix_epoch).days for holiday in holidays]
+    if calendar is not None and (week_mask != _DEFAULT_WEEK_MASK or holidays_int):
+        msg = "`calendar` cannot be combined with `week_mask` or `holidays`"
+        raise ValueError(msg)
+    return week_mask, holidays_int
+
 
 def business_day_count(
     start: date | IntoExprColumn,
     end: date | IntoExprColumn,
     week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
     holidays: Iterable[date] = (),
+    *,
+    calendar: str | None = None,
 ) -> Expr:
     """
     Count the number of business days between `start` and `end` (not including `end`).
@@ -27,9 +140,9 @@ def business_day_count(
     Parameters
     ----------
     start
-        Start dates.
+        Start dates. Datetimes are counted by their date in their time zone.
     end
-        End dates.
+        End dates. Datetimes are counted by their date in their time zone.
     week_mask
         Which days of the week to count. The default is Monday to Friday.
         If you wanted to count only Monday to Thursday, you would pass
@@ -47,6 +160,10 @@ def business_day_count(
             my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
         and pass `holidays=my_holidays` when you call `business_day_count`.
+    calendar
+        Name of a holiday calendar registered with
+        :func:`register_holiday_calendar` to use instead of `week_mask` and
+        `holidays`.
 
     Returns
     -------
@@ -112,12 +229,9 @@ def business_day_count(
     """
     start_pyexpr = parse_as_expression(start)
     end_pyexpr = parse_as_expression(end)
-    unix_epoch = date(1970, 1, 1)
+    week_mask, holidays_int = _resolve_holiday_calendar(calendar, week_mask, holidays)
     return wrap_expr(
         plr.business_day_count(
-            start_pyexpr,
-            end_pyexpr,
-            week_mask,
-            [(holiday - unix_epoch).days for holiday in holidays],
+            start_pyexpr, end_pyexpr, week_mask, holidays_int, calendar
         )
     )
@@ -88,6 +88,45 @@ def element() -> Expr:
     return F.col("")
 
 
+def field(name: str | list[str], *more_names: str) -> Expr:
+    """
+    Select fields of the struct in a `struct.with_fields` expression.
+
+    Parameters
+    ----------
+    name
+        The name of a field, or a list of names. The name `"*"` selects all fields.
+    *more_names
+        Additional names of fields, specified as positional arguments.
+
+    See Also
+    --------
+    Expr.struct.with_fields
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"coords": [{"x": 1, "y": 4}, {"x": 4, "y": 9}]})
+    >>> df.select(
+    ...     pl.col("coords").struct.with_fields(
+    ...         pl.field("x") * 10,
+    ...         distance=(pl.field("x") ** 2 + pl.field("y") ** 2).sqrt(),
+    ...     )
+    ... ).unnest("coords")
+    shape: (2, 3)
+    ┌─────┬─────┬──────────┐
+    │ x   ┆ y   ┆ distance │
+    │ --- ┆ --- ┆ ---      │
+    │ i64 ┆ i64 ┆ f64      │
+    ╞═════╪═════╪══════════╡
+    │ 10  ┆ 4   ┆ 4.123106 │
+    │ 40  ┆ 9   ┆ 9.848858 │
+    └─────┴─────┴──────────┘
+    """
+    if isinstance(name, str):
+        name = [name]
+    return wrap_expr(plr.field([*name, *more_names]))
+
+
 @deprecate_parameter_as_positional("column", version="0.20.4")
 def count(*columns: str) -> Expr:
     """
@@ -26,7 +26,11 @@ with contextlib.suppress(ImportError):
 
 if TYPE_CHECKING:
     from polars import DataFrame, DataType, LazyFrame
-    from polars.type_aliases import ParallelStrategy, SchemaDict
+    from polars.type_aliases import (
+        CredentialProviderFunction,
+        ParallelStrategy,
+        SchemaDict,
+    )
 
 
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -300,6 +304,7 @@ def scan_parquet(
     low_memory: bool = False,
     cache: bool = True,
     storage_options: dict[str, Any] | None = None,
+    credential_provider: CredentialProviderFunction | None = None,
     retries: int = 0,
 ) -> LazyFrame:
     """
@@ -355,8 +360,36 @@ def scan_parquet(
         * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
         * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
 
+        Besides these, the keys `retry_timeout`, `init_backoff` and `max_backoff`
+        set the time in seconds after which failing requests are no longer retried,
+        the time to wait before the first retry and the maximum time to wait between
+        retries, e.g. `{"retry_timeout": "30"}`.
+
         If `storage_options` is not provided, Polars will try to infer the information
         from environment variables.
+    credential_provider
+        Function that returns the credentials to connect to the cloud provider, for
+        credentials that expire, such as the temporary credentials of AWS STS or the
+        access tokens of GCP Workload Identity. The function returns a dictionary
+        of credentials and the Unix timestamp in seconds at which they expire, or
+        `None` if they don't expire. It is called again shortly before the
+        credentials expire. The credentials replace those in `storage_options`.
+        The function is called from the threads that read the files, so it should
+        not depend on running on the main thread.
+
+        * aws: `aws_access_key_id`, `aws_secret_access_key` and optionally
+          `aws_session_token`.
+        * gcp: `bearer_token`.
+        * azure: `bearer_token`, or `azure_storage_account_key`.
+
+        Only `scan_parquet` reads from cloud storage natively. In this version,
+        `scan_ipc` reads remote files through `fsspec` and `scan_csv` and
+        `scan_ndjson` don't support cloud storage, so they don't accept a
+        credential provider.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     retries
         Number of retries if accessing a cloud instance fails.
 
@@ -381,10 +414,33 @@ def scan_parquet(
     ...     "aws_region": "us-east-1",
     ... }
     >>> pl.scan_parquet(source, storage_options=storage_options)  # doctest: +SKIP
+
+    Scan a file on AWS S3 with temporary credentials that are refreshed.
+
+    >>> import boto3  # doctest: +SKIP
+    >>> def credential_provider():
+    ...     creds = boto3.client("sts").assume_role(
+    ...         RoleArn="<role>", RoleSessionName="polars"
+    ...     )["Credentials"]
+    ...     credentials = {
+    ...         "aws_access_key_id": creds["AccessKeyId"],
+    ...         "aws_secret_access_key": creds["SecretAccessKey"],
+    ...         "aws_session_token": creds["SessionToken"],
+    ...     }
+    ...     return credentials, int(creds["Expiration"].timestamp())
+    >>> pl.scan_parquet(
+    ...     source, credential_provider=credential_provider
+    ... )  # doctest: +SKIP
     """
     if hive_schema is not None:
         msg = "The `hive_schema` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
+    if credential_provider is not None:
+        msg = (
+            "The `credential_provider` parameter of `scan_parquet` is considered"
+            " unstable."
+        )
+        issue_unstable_warning(msg)
 
     if isinstance(source, (str, Path)):
         source = normalize_filepath(source)
@@ -400,6 +456,7 @@ def scan_parquet(
         row_index_name=row_index_name,
         row_index_offset=row_index_offset,
         storage_options=storage_options,
+        credential_provider=credential_provider,
         low_memory=low_memory,
         use_statistics=use_statistics,
         hive_partitioning=hive_partitioning,
@@ -419,6 +476,7 @@ def _scan_parquet_impl(
     row_index_name: str | None = None,
     row_index_offset: int = 0,
     storage_options: dict[str, object] | None = None,
+    credential_provider: CredentialProviderFunction | None = None,
     low_memory: bool = False,
     use_statistics: bool = True,
     hive_partitioning: bool = True,
@@ -448,6 +506,7 @@ def _scan_parquet_impl(
         parse_row_index_args(row_index_name, row_index_offset),
         low_memory,
         cloud_options=storage_options,
+        credential_provider=credential_provider,
         use_statistics=use_statistics,
         hive_partitioning=hive_partitioning,
         hive_schema=hive_schema,
@@ -112,6 +112,7 @@ if TYPE_CHECKING:
         SchemaDict,
         StartBy,
         UniqueKeepStrategy,
+        UnnestNaming,
     )
 
     if sys.version_info >= (3, 10):
@@ -3621,6 +3622,116 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return LazyGroupBy(lgb)
 
+    def upsample(
+        self,
+        time_column: str,
+        *,
+        every: str | timedelta,
+        group_by: str | Sequence[str] | None = None,
+        maintain_order: bool = False,
+    ) -> Self:
+        """
+        Upsample a LazyFrame at a regular frequency.
+
+        The `every` argument is created with the following string language:
+
+        - 1ns   (1 nanosecond)
+        - 1us   (1 microsecond)
+        - 1ms   (1 millisecond)
+        - 1s    (1 second)
+        - 1m    (1 minute)
+        - 1h    (1 hour)
+        - 1d    (1 calendar day)
+        - 1w    (1 calendar week)
+        - 1mo   (1 calendar month)
+        - 1q    (1 calendar quarter)
+        - 1y    (1 calendar year)
+        - 1i    (1 index count)
+
+        Or combine them:
+
+        - "3d12h4m25s" # 3 days, 12 hours, 4 minutes, and 25 seconds
+
+
+        By "calendar day", we mean the corresponding time on the next day (which may
+        not be 24 hours, due to daylight savings). Similarly for "calendar week",
+        "calendar month", "calendar quarter", and "calendar year".
+
+        Parameters
+        ----------
+        time_column
+            Time column will be used to determine a date_range.
+            Note that this column has to be sorted for the output to make sense.
+        every
+            Interval will start 'every' duration.
+        group_by
+            First group by these columns and then upsample for every group.
+        maintain_order
+            Keep the ordering predictable. This is slower.
+
+        Returns
+        -------
+        LazyFrame
+            Result will be sorted by `time_column` (but note that if `group_by` columns
+            are passed, it will only be sorted within each group). The `time_column`
+            is the first column of the result.
+
+        See Also
+        --------
+        DataFrame.upsample
+
+        Notes
+        -----
+        The inserted rows have null values in all columns other than `time_column`,
+        including the `group_by` columns. Filters after `upsample` are therefore not
+        pushed down past it.
+
+        Examples
+        --------
+        Upsample a LazyFrame by a certain interval, and fill the inserted rows.
+
+        >>> from datetime import datetime
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "time": [
+        ...             datetime(2021, 2, 1),
+        ...             datetime(2021, 4, 1),
+        ...             datetime(2021, 5, 1),
+        ...             datetime(2021, 6, 1),
+        ...         ],
+        ...         "groups": ["A", "B", "A", "B"],
+        ...         "values": [0, 1, 2, 3],
+        ...     }
+        ... ).set_sorted("time")
+        >>> lf.upsample(
+        ...     time_column="time", every="1mo", group_by="groups", maintain_order=True
+        ... ).select(pl.all().forward_fill()).collect()
+        shape: (7, 3)
+        ┌─────────────────────┬────────┬────────┐
+        │ time                ┆ groups ┆ values │
+        │ ---                 ┆ ---    ┆ ---    │
+        │ datetime[μs]        ┆ str    ┆ i64    │
+        ╞═════════════════════╪════════╪════════╡
+        │ 2021-02-01 00:00:00 ┆ A      ┆ 0      │
+        │ 2021-03-01 00:00:00 ┆ A      ┆ 0      │
+        │ 2021-04-01 00:00:00 ┆ A      ┆ 0      │
+        │ 2021-05-01 00:00:00 ┆ A      ┆ 2      │
+        │ 2021-04-01 00:00:00 ┆ B      ┆ 1      │
+        │ 2021-05-01 00:00:00 ┆ B      ┆ 1      │
+        │ 2021-06-01 00:00:00 ┆ B      ┆ 3      │
+        └─────────────────────┴────────┴────────┘
+        """
+        if group_by is None:
+            group_by = []
+        if isinstance(group_by, str):
+            group_by = [group_by]
+
+        every = parse_as_duration_string(every)
+
+        return self._from_pyldf(
+            self._ldf.upsample(time_column, every, group_by, maintain_order)
+        )
+
     def join_asof(
         self,
         other: LazyFrame,
@@ -5769,6 +5880,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         columns: ColumnNameOrSelector | Collection[ColumnNameOrSelector],
         *more_columns: ColumnNameOrSelector,
+        naming: UnnestNaming | Callable[[str, str], str] = "field",
+        separator: str = "_",
     ) -> Self:
         """
         Decompose struct columns into separate columns for each of their fields.
@@ -5782,6 +5895,19 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Name of the struct column(s) that should be unnested.
         *more_columns
             Additional columns to unnest, specified as positional arguments.
+        naming : {'field', 'prefix', 'suffix'} or function
+            How to name the new columns:
+
+            - 'field': name the columns after the fields of the struct.
+            - 'prefix': prefix the field names with the name of the struct column.
+            - 'suffix': suffix the field names with the name of the struct column.
+
+            Alternatively, a function that takes the name of the struct column and of
+            a field, and returns the name of the new column. This allows unnesting
+            structs that share field names.
+        separator
+            The separator between the names of the struct column and the field, if
+            `naming` is 'prefix' or 'suffix'.
 
         Examples
         --------
@@ -5815,9 +5941,29 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ foo    ┆ 1   ┆ a   ┆ true ┆ [1, 2]    ┆ baz   │
         │ bar    ┆ 2   ┆ b   ┆ null ┆ [3]       ┆ womp  │
         └────────┴─────┴─────┴──────┴───────────┴───────┘
+
+        Structs that share field names can be unnested by prefixing the new columns
+        with the name of the struct column:
+
+        >>> df = pl.LazyFrame(
+        ...     {"a": [{"id": 1, "x": 2}], "b": [{"id": 3, "y": 4}]}
+        ... )
+        >>> df.unnest("a", "b", naming="prefix").collect()
+        shape: (1, 4)
+        ┌──────┬─────┬──────┬─────┐
+        │ a_id ┆ a_x ┆ b_id ┆ b_y │
+        │ ---  ┆ --- ┆ ---  ┆ --- │
+        │ i64  ┆ i64 ┆ i64  ┆ i64 │
+        ╞══════╪═════╪══════╪═════╡
+        │ 1    ┆ 2   ┆ 3    ┆ 4   │
+        └──────┴─────┴──────┴─────┘
         """
         columns = _expand_selectors(self, columns, *more_columns)
-        return self._from_pyldf(self._ldf.unnest(columns))
+        if callable(naming):
+            return self._from_pyldf(
+                self._ldf.unnest(columns, "field", separator, naming)
+            )
+        return self._from_pyldf(self._ldf.unnest(columns, naming, separator, None))
 
     def merge_sorted(self, other: LazyFrame, key: str) -> Self:
         """
@@ -9,9 +9,14 @@ from polars.series.utils import expr_dispatch
 if TYPE_CHECKING:
     from datetime import date, datetime, time
 
-    from polars import Series
+    from polars import Expr, Series
     from polars.polars import PySeries
-    from polars.type_aliases import IntoExpr, IntoExprColumn
+    from polars.type_aliases import (
+        IntoExpr,
+        IntoExprColumn,
+        RankMethod,
+        RollingInterpolationMethod,
+    )
 
 
 @expr_dispatch
@@ -71,6 +76,73 @@ class ArrayNameSpace:
         ]
         """
 
+    def product(self) -> Series:
+        """
+        Compute the product of the values of the sub-arrays.
+
+        Null values are ignored. Small integer types are upcasted to `Int64` to
+        prevent overflow.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2], [4, 3]], dtype=pl.Array(pl.Int8, 2))
+        >>> s.arr.product()
+        shape: (2,)
+        Series: '' [i64]
+        [
+            2
+            12
+        ]
+        """
+
+    def mean(self) -> Series:
+        """
+        Compute the mean of the values of the sub-arrays.
+
+        Null values are ignored. Integer types are converted to `Float64`, temporal
+        types keep their data type.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2], [4, 3]], dtype=pl.Array(pl.Int64, 2))
+        >>> s.arr.mean()
+        shape: (2,)
+        Series: '' [f64]
+        [
+            1.5
+            3.5
+        ]
+        """
+
+    def quantile(
+        self,
+        quantile: float | IntoExprColumn,
+        interpolation: RollingInterpolationMethod = "nearest",
+    ) -> Series:
+        """
+        Compute the quantile of the values of the sub-arrays.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear'}
+            Interpolation method.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2, 3, 4], [8, 6, 4, 2]], dtype=pl.Array(pl.Int64, 4)
+        ... )
+        >>> s.arr.quantile(0.3, interpolation="linear")
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            1.9
+            3.8
+        ]
+        """
+
     def std(self, ddof: int = 1) -> Series:
         """
         Compute the std of the values of the sub-arrays.
@@ -161,6 +233,47 @@ class ArrayNameSpace:
         ]
         """
 
+    def drop_nulls(self) -> Series:
+        """
+        Drop all null values in every sub-array.
+
+        The original order of the remaining elements is preserved. As the number of
+        remaining elements differs per row, the result is a `List` column.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     [[None, 1, 2], [None, None, None], [3, 4, 5]], dtype=pl.Array(pl.Int64, 3)
+        ... )
+        >>> s.arr.drop_nulls()
+        shape: (3,)
+        Series: '' [list[i64]]
+        [
+            [1, 2]
+            []
+            [3, 4, 5]
+        ]
+        """
+
+    def mode(self) -> Series:
+        """
+        Compute the most occurring value(s) in every sub-array.
+
+        As there may be multiple most occurring values, the result is a `List`
+        column. The order of tied values is not guaranteed.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 1, 2], [2, 3, 3]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.mode()
+        shape: (2,)
+        Series: '' [list[i64]]
+        [
+            [1]
+            [3]
+        ]
+        """
+
     def to_list(self) -> Series:
         """
         Convert an Array column into a List column with the same inner data type.
@@ -292,6 +405,188 @@ class ArrayNameSpace:
 
         """
 
+    def cum_sum(self, *, reverse: bool = False) -> Series:
+        """
+        Compute the cumulative sum within every sub-array.
+
+        The width of the sub-arrays is preserved.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation.
+
+        Notes
+        -----
+        Dtypes in {Int8, UInt8, Int16, UInt16} are cast to
+        Int64 before summing to prevent overflow issues.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 3, 2], [4, None, 5]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.cum_sum()
+        shape: (2,)
+        Series: 'a' [array[i64, 3]]
+        [
+            [1, 4, 6]
+            [4, null, 9]
+        ]
+        """
+
+    def cum_min(self, *, reverse: bool = False) -> Series:
+        """
+        Compute the cumulative min within every sub-array.
+
+        The width of the sub-arrays is preserved.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 3, 2], [4, None, 5]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.cum_min()
+        shape: (2,)
+        Series: 'a' [array[i64, 3]]
+        [
+            [1, 1, 1]
+            [4, null, 4]
+        ]
+        """
+
+    def cum_max(self, *, reverse: bool = False) -> Series:
+        """
+        Compute the cumulative max within every sub-array.
+
+        The width of the sub-arrays is preserved.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 3, 2], [4, None, 5]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.cum_max()
+        shape: (2,)
+        Series: 'a' [array[i64, 3]]
+        [
+            [1, 3, 3]
+            [4, null, 5]
+        ]
+        """
+
+    def rank(
+        self,
+        method: RankMethod = "average",
+        *,
+        descending: bool = False,
+        seed: int | None = None,
+    ) -> Series:
+        """
+        Assign ranks to the elements within every sub-array.
+
+        The width of the sub-arrays is preserved. The ranks are of data type
+        :class:`Float64` for the 'average' method and :class:`UInt32` otherwise.
+
+        Parameters
+        ----------
+        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random'}
+            The method used to assign ranks to tied elements.
+            The following methods are available (default is 'average'):
+
+            - 'average' : The average of the ranks that would have been assigned to
+              all the tied values is assigned to each value.
+            - 'min' : The minimum of the ranks that would have been assigned to all
+              the tied values is assigned to each value. (This is also referred to
+              as "competition" ranking.)
+            - 'max' : The maximum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'dense' : Like 'min', but the rank of the next highest element is
+              assigned the rank immediately after those assigned to the tied
+              elements.
+            - 'ordinal' : All values are given a distinct rank, corresponding to
+              the order that the values occur in the sub-array.
+            - 'random' : Like 'ordinal', but the rank for ties is not dependent
+              on the order that the values occur in the sub-array.
+        descending
+            Rank in descending order.
+        seed
+            If `method="random"`, use this as seed.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 1, 1], [2, 8, 2]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.rank("min")
+        shape: (2,)
+        Series: 'a' [array[u32, 3]]
+        [
+            [3, 1, 1]
+            [1, 3, 1]
+        ]
+        """
+
+    def flatten(self) -> Series:
+        """
+        Merge the two outer levels of a nested array column.
+
+        An `Array(Array(T, m), n)` column becomes an `Array(T, n * m)` column. The
+        values are not copied.
+
+        See Also
+        --------
+        reshape
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[[1, 2], [3, 4]], [[5, 6], [7, 8]]],
+        ...     dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+        ... )
+        >>> s.arr.flatten()
+        shape: (2,)
+        Series: 'a' [array[i64, 4]]
+        [
+            [1, 2, … 4]
+            [5, 6, … 8]
+        ]
+        """
+
+    def reshape(self, dimensions: tuple[int, ...]) -> Series:
+        """
+        Reshape the elements of every sub-array into a (nested) array.
+
+        The elements of the (nested) sub-arrays are taken in row-major order. The
+        values are not copied.
+
+        Parameters
+        ----------
+        dimensions
+            Tuple of the widths of the dimensions of the result, the outer dimension
+            first. One dimension may be set to -1 to infer it from the others.
+
+        See Also
+        --------
+        flatten
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2, 3, 4], [5, 6, 7, 8]], dtype=pl.Array(pl.Int64, 4)
+        ... )
+        >>> s.arr.reshape((2, 2))
+        shape: (2,)
+        Series: 'a' [array[array[i64, 2], 2]]
+        [
+            [[1, 2], [3, 4]]
+            [[5, 6], [7, 8]]
+        ]
+        """
+
     def arg_min(self) -> Series:
         """
         Retrieve the index of the minimal value in every sub-array.
@@ -621,3 +916,206 @@ class ArrayNameSpace:
             [6, null, null]
         ]
         """
+
+    def sample(
+        self,
+        n: int | IntoExprColumn = 1,
+        *,
+        with_replacement: bool = False,
+        shuffle: bool = False,
+        seed: int | None = None,
+    ) -> Series:
+        """
+        Sample `n` elements from every sub-array.
+
+        If `n` is an integer, the result is an `Array` of width `n`, otherwise it is a
+        `List` column as the number of sampled elements may differ per row.
+
+        Parameters
+        ----------
+        n
+            Number of elements to return per sub-array.
+        with_replacement
+            Allow values to be sampled more than once.
+        shuffle
+            Shuffle the order of sampled data points.
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated for each sample operation.
+            Every sub-array is sampled with its own seed, derived from `seed` and the
+            row index of the sub-array.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2, 3], [4, 5, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.sample(2, seed=1)
+        shape: (2,)
+        Series: '' [array[i64, 2]]
+        [
+            [2, 1]
+            [4, 6]
+        ]
+        """
+
+    def set_union(self, other: Series) -> Series:
+        """
+        Compute the SET UNION between the elements in this array and `other`.
+
+        As the number of elements in the result differs per row, the result is a
+        `List` column.
+
+        Parameters
+        ----------
+        other
+            Right hand side of the set operation, an `Array` or `List` Series.
+
+        Examples
+        --------
+        >>> a = pl.Series([[1, 2, 3], [3, 4, 5]], dtype=pl.Array(pl.Int64, 3))
+        >>> b = pl.Series([[2, 3, 4], [5, 6, 7]], dtype=pl.Array(pl.Int64, 3))
+        >>> a.arr.set_union(b)
+        shape: (2,)
+        Series: '' [list[i64]]
+        [
+            [1, 2, … 4]
+            [3, 4, … 7]
+        ]
+        """
+
+    def set_difference(self, other: Series) -> Series:
+        """
+        Compute the SET DIFFERENCE between the elements in this array and `other`.
+
+        As the number of elements in the result differs per row, the result is a
+        `List` column.
+
+        Parameters
+        ----------
+        other
+            Right hand side of the set operation, an `Array` or `List` Series.
+
+        Examples
+        --------
+        >>> a = pl.Series([[1, 2, 3], [3, 4, 5]], dtype=pl.Array(pl.Int64, 3))
+        >>> b = pl.Series([[2, 3, 4], [5, 6, 7]], dtype=pl.Array(pl.Int64, 3))
+        >>> a.arr.set_difference(b)
+        shape: (2,)
+        Series: '' [list[i64]]
+        [
+            [1]
+            [3, 4]
+        ]
+        """
+
+    def set_intersection(self, other: Series) -> Series:
+        """
+        Compute the SET INTERSECTION between the elements in this array and `other`.
+
+        As the number of elements in the result differs per row, the result is a
+        `List` column.
+
+        Parameters
+        ----------
+        other
+            Right hand side of the set operation, an `Array` or `List` Series.
+
+        Examples
+        --------
+        >>> a = pl.Series([[1, 2, 3], [3, 4, 5]], dtype=pl.Array(pl.Int64, 3))
+        >>> b = pl.Series([[2, 3, 4], [5, 6, 7]], dtype=pl.Array(pl.Int64, 3))
+        >>> a.arr.set_intersection(b)
+        shape: (2,)
+        Series: '' [list[i64]]
+        [
+            [2, 3]
+            [5]
+        ]
+        """
+
+    def set_symmetric_difference(self, other: Series) -> Series:
+        """
+        Compute the SET SYMMETRIC DIFFERENCE between this array and `other`.
+
+        As the number of elements in the result differs per row, the result is a
+        `List` column.
+
+        Parameters
+        ----------
+        other
+            Right hand side of the set operation, an `Array` or `List` Series.
+
+        Examples
+        --------
+        >>> a = pl.Series([[1, 2, 3], [3, 4, 5]], dtype=pl.Array(pl.Int64, 3))
+        >>> b = pl.Series([[2, 3, 4], [5, 6, 7]], dtype=pl.Array(pl.Int64, 3))
+        >>> a.arr.set_symmetric_difference(b)
+        shape: (2,)
+        Series: '' [list[i64]]
+        [
+            [1, 4]
+            [3, 4, … 7]
+        ]
+        """
+
+    def zip_with(self, mask: Series, other: Series) -> Series:
+        """
+        Take values from this array or `other` based on the given `mask`.
+
+        Where the sub-array of `mask` is true, take the value from this array, else
+        take the value from `other`. All arrays must have the same width.
+
+        Parameters
+        ----------
+        mask
+            Boolean `Array` Series.
+        other
+            `Array` Series of the same data type.
+
+        Examples
+        --------
+        >>> a = pl.Series([[1, 2], [3, 4]], dtype=pl.Array(pl.Int64, 2))
+        >>> mask = pl.Series(
+        ...     [[True, False], [False, True]], dtype=pl.Array(pl.Boolean, 2)
+        ... )
+        >>> b = pl.Series([[5, 6], [7, 8]], dtype=pl.Array(pl.Int64, 2))
+        >>> a.arr.zip_with(mask, b)
+        shape: (2,)
+        Series: '' [array[i64, 2]]
+        [
+            [1, 6]
+            [7, 4]
+        ]
+        """
+
+    def eval(self, expr: Expr, *, parallel: bool = False) -> Series:
+        """
+        Run any polars expression against the arrays' elements.
+
+        The result is an array of the same width if the expression is known to
+        preserve the number of elements, i.e. if it is elementwise or a sort, rank,
+        shift, reverse or cumulative operation. Otherwise, it is a list.
+
+        Parameters
+        ----------
+        expr
+            Expression to run. Note that you can select an element with `pl.first()`, or
+            `pl.col()`
+        parallel
+            Run all expression parallel. Don't activate this blindly.
+            Parallelism is worth it if there is enough work to do per thread.
+
+            This likely should not be used in the group by context, because we already
+            parallel execution per group
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 8], [5, 4], [3, 2]], dtype=pl.Array(pl.Int64, 2))
+        >>> s.arr.eval(pl.element().rank())
+        shape: (3,)
+        Series: 'a' [array[f64, 2]]
+        [
+            [1.0, 2.0]
+            [2.0, 1.0]
+            [2.0, 1.0]
+        ]
+        """
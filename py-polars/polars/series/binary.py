@@ -7,7 +7,13 @@ from polars.series.utils import expr_dispatch
 if TYPE_CHECKING:
     from polars import Series
     from polars.polars import PySeries
-    from polars.type_aliases import IntoExpr, TransferEncoding
+    from polars.type_aliases import (
+        DigestAlgorithm,
+        Endianness,
+        IntoExpr,
+        PolarsDataType,
+        TransferEncoding,
+    )
 
 
 @expr_dispatch
@@ -96,8 +102,9 @@ class BinaryNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base32', 'percent'}
+            The encoding to use. `'percent'` is the URL percent-encoding, which
+            encodes all characters except the unreserved characters of RFC 3986.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -152,8 +159,9 @@ class BinaryNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base32', 'percent'}
+            The encoding to use. `'percent'` is the URL percent-encoding, which
+            encodes all characters except the unreserved characters of RFC 3986.
 
         Returns
         -------
@@ -185,3 +193,74 @@ class BinaryNameSpace:
             "AAD/"
         ]
         """
+
+    def reinterpret(
+        self, *, dtype: PolarsDataType, endianness: Endianness = "little"
+    ) -> Series:
+        r"""
+        Reinterpret the bytes of each value as a number.
+
+        Parameters
+        ----------
+        dtype
+            The numeric data type to reinterpret the bytes as. Every non-null value
+            must be exactly as long as the width of this data type, otherwise an
+            error is raised.
+        endianness : {'little', 'big'}
+            The byte order of the values.
+
+        Returns
+        -------
+        Series
+            Series of data type `dtype`.
+
+        See Also
+        --------
+        Series.to_binary : Reinterpret numbers as their bytes.
+
+        Examples
+        --------
+        >>> s = pl.Series("payload", [b"\x00\x00\x80\x3f", b"\x00\x00\x00\xc0"])
+        >>> s.bin.reinterpret(dtype=pl.Float32)
+        shape: (2,)
+        Series: 'payload' [f32]
+        [
+                1.0
+                -2.0
+        ]
+        """
+
+    def digest(self, algorithm: DigestAlgorithm, *, as_hex: bool = True) -> Series:
+        """
+        Hash each value with a cryptographic hash or checksum.
+
+        Parameters
+        ----------
+        algorithm : {'md5', 'sha1', 'sha256', 'crc32'}
+            The hash function to digest the values with. The CRC-32 checksum is the
+            one used by zlib and gzip, its four bytes are in big endian order.
+        as_hex
+            Return the digest as a lowercase hex string. If set to `False`, the
+            digest is returned as binary instead.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String` if `as_hex` is set, and of data type
+            :class:`Binary` otherwise.
+
+        See Also
+        --------
+        Series.hash : Hash the values with a fast, non-cryptographic hash function.
+
+        Examples
+        --------
+        >>> s = pl.Series("data", [b"abc", None])
+        >>> s.bin.digest("sha1")
+        shape: (2,)
+        Series: 'data' [str]
+        [
+                "a9993e364706816aba3e25717850c2…
+                null
+        ]
+        """
@@ -2,7 +2,6 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING
 
-from polars._utils.deprecation import deprecate_function
 from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_s
 from polars.series.utils import expr_dispatch
@@ -22,20 +21,12 @@ class CatNameSpace:
     def __init__(self, series: Series):
         self._s: PySeries = series._s
 
-    @deprecate_function(
-        "Set the ordering directly on the datatype `pl.Categorical('lexical')`"
-        " or `pl.Categorical('physical')` or `cast()` to the intended data type."
-        " This method will be removed in the next breaking change",
-        version="0.19.19",
-    )
     def set_ordering(self, ordering: CategoricalOrdering) -> Series:
         """
         Determine how this categorical series should be sorted.
 
-        .. deprecated:: 0.19.19
-            Set the ordering directly on the datatype `pl.Categorical('lexical')`
-            or `pl.Categorical('physical')` or `cast()` to the intended data type.
-            This method will be removed in the next breaking change
+        The ordering is used when sorting, when comparing values and when taking the
+        minimum or maximum. The categories themselves are left unchanged.
 
         Parameters
         ----------
@@ -45,6 +36,18 @@ class CatNameSpace:
             - 'physical' -> Use the physical representation of the categories to
               determine the order (default).
             - 'lexical' -> Use the string values to determine the ordering.
+
+        Examples
+        --------
+        >>> s = pl.Series(["b", "c", "a"], dtype=pl.Categorical)
+        >>> s.cat.set_ordering("lexical").sort()
+        shape: (3,)
+        Series: '' [cat]
+        [
+                "a"
+                "b"
+                "c"
+        ]
         """
 
     def get_categories(self) -> Series:
@@ -64,6 +67,51 @@ class CatNameSpace:
         ]
         """
 
+    def n_categories(self) -> int:
+        """
+        Get the number of categories stored in this data type.
+
+        Examples
+        --------
+        >>> s = pl.Series(["foo", "bar", "foo", "foo", "ham"], dtype=pl.Categorical)
+        >>> s.cat.n_categories()
+        3
+        """
+        return self.get_categories().len()
+
+    def codes(self) -> Series:
+        """
+        Get the index of the category of every value in the categories.
+
+        The index refers to the position of the category in
+        :func:`get_categories`. Unlike :func:`Series.to_physical`, this does not
+        depend on whether the categorical was created under a global string cache.
+
+        Examples
+        --------
+        >>> with pl.StringCache():
+        ...     _ = pl.Series(["a", "b"], dtype=pl.Categorical)
+        ...     s = pl.Series(["c", "b", None, "c"], dtype=pl.Categorical)
+        >>> s.to_physical()
+        shape: (4,)
+        Series: '' [u32]
+        [
+                2
+                1
+                null
+                2
+        ]
+        >>> s.cat.codes()
+        shape: (4,)
+        Series: '' [u32]
+        [
+                0
+                1
+                null
+                0
+        ]
+        """
+
     def is_local(self) -> bool:
         """
         Return whether or not the column is a local categorical.
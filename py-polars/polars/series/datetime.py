@@ -16,6 +16,8 @@ if TYPE_CHECKING:
     from polars.polars import PySeries
     from polars.type_aliases import (
         Ambiguous,
+        DurationFormat,
+        DurationPrecision,
         EpochTimeUnit,
         IntoExpr,
         IntoExprColumn,
@@ -45,6 +47,8 @@ class DateTimeNameSpace:
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        *,
+        calendar: str | None = None,
     ) -> Expr:
         """
         Offset by `n` business days.
@@ -52,8 +56,8 @@ class DateTimeNameSpace:
         Parameters
         ----------
         n
-            Number of business days to offset by. Can be a single number of an
-            expression.
+            Number of business days to offset by. Can be a single integer or an
+            expression of any integer data type.
         week_mask
             Which days of the week to count. The default is Monday to Friday.
             If you wanted to count only Monday to Thursday, you would pass
@@ -77,6 +81,10 @@ class DateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            Name of a holiday calendar registered with
+            :func:`polars.register_holiday_calendar` to use instead of `week_mask`
+            and `holidays`.
 
         Returns
         -------
@@ -130,6 +138,53 @@ class DateTimeNameSpace:
         ]
         """
 
+    def is_business_day(
+        self,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+        *,
+        calendar: str | None = None,
+    ) -> Series:
+        """
+        Determine whether the underlying date is a business day.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week are business days. The default is Monday to Friday.
+            If you only worked Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays that are not business days.
+        calendar
+            Name of a holiday calendar registered with
+            :func:`polars.register_holiday_calendar` to use instead of `week_mask`
+            and `holidays`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2020, 1, 1), date(2020, 1, 2), date(2020, 1, 4)]
+        ... )
+        >>> s.dt.is_business_day(holidays=[date(2020, 1, 1)])
+        shape: (3,)
+        Series: 'date' [bool]
+        [
+                false
+                true
+                false
+        ]
+        """
+
     def min(self) -> dt.date | dt.datetime | dt.timedelta | None:
         """
         Return minimum as Python datetime.
@@ -212,7 +267,7 @@ class DateTimeNameSpace:
                 return to_py_datetime(int(out), s.dtype.time_unit)  # type: ignore[arg-type, attr-defined]
         return None
 
-    def to_string(self, format: str) -> Series:
+    def to_string(self, format: str, *, locale: str = "en_US") -> Series:
         """
         Convert a Date/Time/Datetime column into a String column with the given format.
 
@@ -225,6 +280,10 @@ class DateTimeNameSpace:
             Format to use, refer to the `chrono strftime documentation
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: `"%y-%m-%d"`.
+        locale
+            Language of the names of months and weekdays formatted by `'%B'`, `'%b'`,
+            `'%A'` and `'%a'`. One of `'de_DE'`, `'en_US'`, `'es_ES'`, `'fr_FR'`,
+            `'it_IT'`, `'nl_NL'` or `'pt_PT'`.
 
         Examples
         --------
@@ -264,7 +323,7 @@ class DateTimeNameSpace:
         ]
         """
 
-    def strftime(self, format: str) -> Series:
+    def strftime(self, format: str, *, locale: str = "en_US") -> Series:
         """
         Convert a Date/Time/Datetime column into a String column with the given format.
 
@@ -279,6 +338,10 @@ class DateTimeNameSpace:
             Format to use, refer to the `chrono strftime documentation
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: `"%y-%m-%d"`.
+        locale
+            Language of the names of months and weekdays formatted by `'%B'`, `'%b'`,
+            `'%A'` and `'%a'`. One of `'de_DE'`, `'en_US'`, `'es_ES'`, `'fr_FR'`,
+            `'it_IT'`, `'nl_NL'` or `'pt_PT'`.
 
         See Also
         --------
@@ -321,7 +384,37 @@ class DateTimeNameSpace:
                 "May"
         ]
         """
-        return self.to_string(format)
+        return self.to_string(format, locale=locale)
+
+    def to_iso_week_string(self) -> Series:
+        """
+        Convert a Date/Datetime column into ISO 8601 week date strings.
+
+        A week date such as `"2021-W01-1"` consists of the ISO year, the ISO week and
+        the weekday, which are also available separately as :func:`iso_year`,
+        :func:`week` and :func:`weekday`. Around New Year, the ISO year may differ
+        from the calendar year.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2020, 12, 31), date(2021, 1, 3), date(2021, 1, 4)]
+        ... )
+        >>> s.dt.to_iso_week_string()
+        shape: (3,)
+        Series: 'date' [str]
+        [
+                "2020-W53-4"
+                "2020-W53-7"
+                "2021-W01-1"
+        ]
+        """
 
     def millennium(self) -> Expr:
         """
@@ -452,6 +545,194 @@ class DateTimeNameSpace:
         ]
         """
 
+    def is_month_start(self) -> Series:
+        """
+        Determine whether the underlying date is the first day of a month.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2024, 1, 31), date(2024, 2, 1), date(2024, 2, 29)]
+        ... )
+        >>> s.dt.is_month_start()
+        shape: (3,)
+        Series: 'date' [bool]
+        [
+                false
+                true
+                false
+        ]
+        """
+
+    def is_month_end(self) -> Series:
+        """
+        Determine whether the underlying date is the last day of a month.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2024, 1, 31), date(2024, 2, 1), date(2024, 2, 29)]
+        ... )
+        >>> s.dt.is_month_end()
+        shape: (3,)
+        Series: 'date' [bool]
+        [
+                true
+                false
+                true
+        ]
+        """
+
+    def is_quarter_start(self, *, fiscal_year_start: int = 1) -> Series:
+        """
+        Determine whether the underlying date is the first day of a quarter.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2024, 3, 31), date(2024, 4, 1), date(2024, 5, 1)]
+        ... )
+        >>> s.dt.is_quarter_start()
+        shape: (3,)
+        Series: 'date' [bool]
+        [
+                false
+                true
+                false
+        ]
+        """
+
+    def is_quarter_end(self, *, fiscal_year_start: int = 1) -> Series:
+        """
+        Determine whether the underlying date is the last day of a quarter.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2024, 3, 31), date(2024, 4, 1), date(2024, 4, 30)]
+        ... )
+        >>> s.dt.is_quarter_end()
+        shape: (3,)
+        Series: 'date' [bool]
+        [
+                true
+                false
+                false
+        ]
+        """
+
+    def is_year_start(self, *, fiscal_year_start: int = 1) -> Series:
+        """
+        Determine whether the underlying date is the first day of a year.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2023, 12, 31), date(2024, 1, 1), date(2024, 2, 1)]
+        ... )
+        >>> s.dt.is_year_start()
+        shape: (3,)
+        Series: 'date' [bool]
+        [
+                false
+                true
+                false
+        ]
+        """
+
+    def is_year_end(self, *, fiscal_year_start: int = 1) -> Series:
+        """
+        Determine whether the underlying date is the last day of a year.
+
+        Applies to Date and Datetime columns. Datetimes are checked by their date in
+        their own time zone.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2023, 12, 31), date(2024, 1, 1), date(2024, 12, 30)]
+        ... )
+        >>> s.dt.is_year_end()
+        shape: (3,)
+        Series: 'date' [bool]
+        [
+                true
+                false
+                false
+        ]
+        """
+
     def iso_year(self) -> Series:
         """
         Extract ISO year from underlying Date representation.
@@ -478,7 +759,7 @@ class DateTimeNameSpace:
         ]
         """
 
-    def quarter(self) -> Series:
+    def quarter(self, *, fiscal_year_start: int = 1) -> Series:
         """
         Extract quarter from underlying Date representation.
 
@@ -486,6 +767,11 @@ class DateTimeNameSpace:
 
         Returns the quarter ranging from 1 to 4.
 
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
         Returns
         -------
         Series
@@ -1067,14 +1353,34 @@ class DateTimeNameSpace:
         ]
         """
 
-    def epoch(self, time_unit: EpochTimeUnit = "us") -> Series:
+    def epoch(
+        self,
+        time_unit: EpochTimeUnit | str | dt.timedelta = "us",
+        *,
+        origin: dt.date | dt.datetime | Expr | None = None,
+        fractional: bool = False,
+    ) -> Series:
         """
         Get the time passed since the Unix EPOCH in the give time unit.
 
         Parameters
         ----------
-        time_unit : {'us', 'ns', 'ms', 's', 'd'}
-            Unit of time.
+        time_unit : {'us', 'ns', 'ms', 's', 'd'}, str or timedelta
+            Unit of time. Besides these units, any duration of a fixed length is
+            accepted, such as `"15m"` or `"1w"`. Days and weeks are taken to be 24
+            hours and 7 days long.
+        origin
+            The date or datetime to count from, instead of the Unix epoch. A
+            time-zone-naive origin of a time-zone-aware Series is taken to be in its
+            time zone.
+        fractional
+            Whether to return the fractional number of units as a `Float64`, instead
+            of the whole number of units as an integer.
+
+        Notes
+        -----
+        Whole units are rounded down, so that times before the origin are counted
+        as negative units.
 
         Examples
         --------
@@ -1108,6 +1414,14 @@ class DateTimeNameSpace:
                 978393600
                 978480000
         ]
+        >>> date.dt.epoch("12h", origin=datetime(2001, 1, 2))
+        shape: (3,)
+        Series: 'datetime' [i64]
+        [
+                -2
+                0
+                2
+        ]
         """
 
     def with_time_unit(self, time_unit: TimeUnit) -> Series:
@@ -1231,7 +1545,7 @@ class DateTimeNameSpace:
         *,
         use_earliest: bool | None = None,
         ambiguous: Ambiguous | Series = "raise",
-        non_existent: NonExistent = "raise",
+        non_existent: NonExistent | Series = "raise",
     ) -> Series:
         """
         Replace time zone for a Series of type Datetime.
@@ -1259,12 +1573,17 @@ class DateTimeNameSpace:
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
+
+            Pass a Series of strategies to use a different one for each element.
         non_existent
-            Determine how to deal with non-existent datetimes:
+            Determine how to deal with non-existent datetimes, which fall into the gap
+            when clocks move forward:
 
             - `'raise'` (default): raise
             - `'null'`: set to null
 
+            Pass a Series of strategies to use a different one for each element.
+
         Examples
         --------
         >>> from datetime import datetime
@@ -1334,14 +1653,21 @@ class DateTimeNameSpace:
         └─────────────────────┴───────────┴───────────────────────────────┘
         """
 
-    def total_days(self) -> Series:
+    def total_days(self, *, fractional: bool = False) -> Series:
         """
         Extract the total days from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Series
-            Series of data type :class:`Int64`.
+            Series of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -1367,14 +1693,21 @@ class DateTimeNameSpace:
         ]
         """
 
-    def total_hours(self) -> Series:
+    def total_hours(self, *, fractional: bool = False) -> Series:
         """
         Extract the total hours from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Series
-            Series of data type :class:`Int64`.
+            Series of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -1400,16 +1733,35 @@ class DateTimeNameSpace:
                 24
                 24
         ]
+
+        Keep the fractional part of the hours:
+
+        >>> from datetime import timedelta
+        >>> s = pl.Series("duration", [timedelta(minutes=90), timedelta(minutes=-45)])
+        >>> s.dt.total_hours(fractional=True)
+        shape: (2,)
+        Series: 'duration' [f64]
+        [
+                1.5
+                -0.75
+        ]
         """
 
-    def total_minutes(self) -> Series:
+    def total_minutes(self, *, fractional: bool = False) -> Series:
         """
         Extract the total minutes from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Series
-            Series of data type :class:`Int64`.
+            Series of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -1437,14 +1789,21 @@ class DateTimeNameSpace:
         ]
         """
 
-    def total_seconds(self) -> Series:
+    def total_seconds(self, *, fractional: bool = False) -> Series:
         """
         Extract the total seconds from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Series
-            Series of data type :class:`Int64`.
+            Series of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -1474,14 +1833,21 @@ class DateTimeNameSpace:
         ]
         """
 
-    def total_milliseconds(self) -> Series:
+    def total_milliseconds(self, *, fractional: bool = False) -> Series:
         """
         Extract the total milliseconds from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Series
-            Series of data type :class:`Int64`.
+            Series of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -1510,14 +1876,21 @@ class DateTimeNameSpace:
         ]
         """
 
-    def total_microseconds(self) -> Series:
+    def total_microseconds(self, *, fractional: bool = False) -> Series:
         """
         Extract the total microseconds from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Series
-            Series of data type :class:`Int64`.
+            Series of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -1546,14 +1919,21 @@ class DateTimeNameSpace:
         ]
         """
 
-    def total_nanoseconds(self) -> Series:
+    def total_nanoseconds(self, *, fractional: bool = False) -> Series:
         """
         Extract the total nanoseconds from a Duration type.
 
+        Parameters
+        ----------
+        fractional
+            Whether to keep the fractional part of the total, in which case the result
+            is a :class:`Float64` instead of a truncated :class:`Int64`.
+
         Returns
         -------
         Series
-            Series of data type :class:`Int64`.
+            Series of data type :class:`Int64`, or :class:`Float64` if
+            `fractional` is set.
 
         Examples
         --------
@@ -1582,7 +1962,46 @@ class DateTimeNameSpace:
         ]
         """
 
-    def offset_by(self, by: str | Expr) -> Series:
+    def duration_to_string(
+        self,
+        format: DurationFormat = "polars",
+        *,
+        precision: DurationPrecision | None = None,
+    ) -> Series:
+        """
+        Convert a Duration Series into a String Series.
+
+        Parameters
+        ----------
+        format : {'polars', 'iso'}
+            The format of the strings.
+
+            - `'polars'` is the format that durations are displayed in, such as
+              `"2d 3h 15m"`.
+            - `'iso'` is the ISO 8601 format, such as `"P2DT3H15M"`.
+        precision : {None, 'd', 'h', 'm', 's', 'ms', 'us', 'ns'}
+            The smallest unit to write, smaller units are truncated. By default,
+            durations are written in their full precision.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> from datetime import timedelta
+        >>> s = pl.Series([timedelta(days=2, hours=3, minutes=15, seconds=10), None])
+        >>> s.dt.duration_to_string("iso", precision="m")
+        shape: (2,)
+        Series: '' [str]
+        [
+                "P2DT3H15M"
+                null
+        ]
+        """
+
+    def offset_by(self, by: str | Expr, *, end_of_month: bool = False) -> Series:
         """
         Offset this date by a relative time offset.
 
@@ -1612,6 +2031,13 @@ class DateTimeNameSpace:
             "calendar week", "calendar month", "calendar quarter", and
             "calendar year".
 
+            An expression can also give duration strings or :class:`Duration` values
+            for each row; durations are added as a fixed amount of time.
+        end_of_month
+            Keep dates on the last day of their month on the last day of the month
+            when months are added. For example, with `end_of_month=True`, 2024-02-29
+            offset by "1mo" is 2024-03-31 instead of 2024-03-29.
+
         Returns
         -------
         Series
@@ -1671,10 +2097,11 @@ class DateTimeNameSpace:
 
         Each date/datetime is mapped to the start of its bucket using the corresponding
         local datetime. Note that weekly buckets start on Monday.
-        Ambiguous results are localised using the DST offset of the original timestamp -
-        for example, truncating `'2022-11-06 01:30:00 CST'` by `'1h'` results in
-        `'2022-11-06 01:00:00 CST'`, whereas truncating `'2022-11-06 01:30:00 CDT'` by
-        `'1h'` results in `'2022-11-06 01:00:00 CDT'`.
+        Unless `ambiguous` is given, ambiguous results are localised using the DST
+        offset of the original timestamp - for example, truncating
+        `'2022-11-06 01:30:00 CST'` by `'1h'` results in `'2022-11-06 01:00:00 CST'`,
+        whereas truncating `'2022-11-06 01:30:00 CDT'` by `'1h'` results in
+        `'2022-11-06 01:00:00 CDT'`.
 
         Parameters
         ----------
@@ -1685,7 +2112,9 @@ class DateTimeNameSpace:
 
             .. deprecated:: 0.20.19
                 This argument is deprecated and will be removed in the next breaking
-                release. Instead, chain `dt.truncate` with `dt.offset_by`.
+                release. Instead, chain `dt.truncate` with `dt.offset_by`. Unlike
+                the `offset` of `dt.round`, this offset is added to the truncated
+                result and does not shift the bucket boundaries.
         use_earliest
             Determine how to deal with ambiguous datetimes:
 
@@ -1696,14 +2125,17 @@ class DateTimeNameSpace:
             .. deprecated:: 0.19.0
                 This is now automatically inferred; you can safely omit this argument.
         ambiguous
-            Determine how to deal with ambiguous datetimes:
+            Determine how to deal with ambiguous results:
 
-            - `'raise'` (default): raise
+            - `None` (default): use the DST offset of the original timestamp
+            - `'raise'`: raise
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
+            - `'null'`: set to null
 
-            .. deprecated:: 0.19.3
-                This is now automatically inferred; you can safely omit this argument.
+            .. versionchanged:: 0.20.27
+                `ambiguous` is no longer deprecated and is no longer ignored: it
+                now determines how ambiguous results are localised.
 
         Notes
         -----
@@ -1809,6 +2241,7 @@ class DateTimeNameSpace:
         every: str | dt.timedelta | IntoExprColumn,
         offset: str | dt.timedelta | None = None,
         *,
+        week_start: int = 1,
         ambiguous: Ambiguous | Series | None = None,
     ) -> Series:
         """
@@ -1822,30 +2255,42 @@ class DateTimeNameSpace:
         its bucket.
         Each date/datetime in the second half of the interval is mapped to the end of
         its bucket.
-        Ambiguous results are localized using the DST offset of the original timestamp -
-        for example, rounding `'2022-11-06 01:20:00 CST'` by `'1h'` results in
-        `'2022-11-06 01:00:00 CST'`, whereas rounding `'2022-11-06 01:20:00 CDT'` by
-        `'1h'` results in `'2022-11-06 01:00:00 CDT'`.
+        Unless `ambiguous` is given, ambiguous results are localized using the DST
+        offset of the original timestamp - for example, rounding
+        `'2022-11-06 01:20:00 CST'` by `'1h'` results in `'2022-11-06 01:00:00 CST'`,
+        whereas rounding `'2022-11-06 01:20:00 CDT'` by `'1h'` results in
+        `'2022-11-06 01:00:00 CDT'`.
 
         Parameters
         ----------
         every
             Every interval start and period length
         offset
-            Offset the window
-
-            .. deprecated:: 0.20.19
-                This argument is deprecated and will be removed in the next breaking
-                release. Instead, chain `dt.round` with `dt.offset_by`.
+            Shift the bucket boundaries by this duration. For example, rounding by
+            `'15m'` with an offset of `'5m'` rounds to `'..:05'`, `'..:20'`, `'..:35'`
+            or `'..:50'`.
+
+            .. versionchanged:: 0.20.27
+                The offset used to be added to the rounded result, which is the same
+                as chaining `dt.round` with `dt.offset_by`, and was deprecated for
+                that reason. It now shifts the bucket boundaries instead, which
+                chaining can't express, so it is no longer deprecated. The `offset`
+                of `dt.truncate` is still added to the result and remains deprecated.
+        week_start
+            The day on which weekly buckets start, from 1 (Monday) to 7 (Sunday). This
+            is only used if `every` is a number of weeks.
         ambiguous
-            Determine how to deal with ambiguous datetimes:
+            Determine how to deal with ambiguous results:
 
-            - `'raise'` (default): raise
+            - `None` (default): use the DST offset of the original timestamp
+            - `'raise'`: raise
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
+            - `'null'`: set to null
 
-            .. deprecated:: 0.19.3
-                This is now automatically inferred; you can safely omit this argument.
+            .. versionchanged:: 0.20.27
+                `ambiguous` is no longer deprecated and is no longer ignored: it
+                now determines how ambiguous results are localised.
 
         Returns
         -------
@@ -1935,12 +2380,19 @@ class DateTimeNameSpace:
         ]
         """
 
-    def combine(self, time: dt.time | Series, time_unit: TimeUnit = "us") -> Expr:
+    def combine(
+        self,
+        time: dt.time | Series,
+        time_unit: TimeUnit = "us",
+        time_zone: str | None = None,
+    ) -> Expr:
         """
-        Create a naive Datetime from an existing Date/Datetime expression and a Time.
+        Create a Datetime from an existing Date/Datetime expression and a Time.
 
         If the underlying expression is a Datetime then its time component is replaced,
         and if it is a Date then a new Datetime is created by combining the two values.
+        For a time zone aware Datetime, the time component in its own time zone is
+        replaced.
 
         Parameters
         ----------
@@ -1948,6 +2400,9 @@ class DateTimeNameSpace:
             A python time literal or Series of the same length as this Series.
         time_unit : {'ns', 'us', 'ms'}
             Unit of time.
+        time_zone
+            Time zone to interpret the combined date and time in. Defaults to the
+            time zone of a Datetime, and to a naive Datetime for a Date.
 
         Examples
         --------
@@ -1963,6 +2418,16 @@ class DateTimeNameSpace:
             2022-12-31 01:02:03.456
             2023-07-05 01:02:03.456
         ]
+
+        Interpret the combined values in a time zone:
+
+        >>> s.dt.combine(time(1, 2, 3), time_zone="Europe/Amsterdam")
+        shape: (2,)
+        Series: 'dtm' [datetime[μs, Europe/Amsterdam]]
+        [
+                2022-12-31 01:02:03 CET
+                2023-07-05 01:02:03 CEST
+        ]
         """
 
     def month_start(self) -> Series:
@@ -2027,6 +2492,78 @@ class DateTimeNameSpace:
         ]
         """
 
+    def quarter_start(self, *, fiscal_year_start: int = 1) -> Series:
+        """
+        Roll backward to the first day of the quarter.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Date` or :class:`Datetime`.
+
+        Notes
+        -----
+        If you're coming from pandas, you can think of this as a vectorised version
+        of `pandas.tseries.offsets.QuarterBegin(startingMonth=1).rollback(datetime)`.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> s = pl.datetime_range(
+        ...     datetime(2000, 1, 2, 2), datetime(2000, 4, 2, 2), "1mo", eager=True
+        ... ).alias("datetime")
+        >>> s.dt.quarter_start()
+        shape: (4,)
+        Series: 'datetime' [datetime[μs]]
+        [
+                2000-01-01 02:00:00
+                2000-01-01 02:00:00
+                2000-01-01 02:00:00
+                2000-04-01 02:00:00
+        ]
+        """
+
+    def quarter_end(self, *, fiscal_year_start: int = 1) -> Series:
+        """
+        Roll forward to the last day of the quarter.
+
+        Parameters
+        ----------
+        fiscal_year_start
+            The month, from 1 to 12, in which the (fiscal) year starts.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Date` or :class:`Datetime`.
+
+        Notes
+        -----
+        If you're coming from pandas, you can think of this as a vectorised version
+        of `pandas.tseries.offsets.QuarterEnd(startingMonth=3).rollforward(datetime)`.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> s = pl.datetime_range(
+        ...     datetime(2000, 1, 2, 2), datetime(2000, 4, 2, 2), "1mo", eager=True
+        ... ).alias("datetime")
+        >>> s.dt.quarter_end(fiscal_year_start=2)
+        shape: (4,)
+        Series: 'datetime' [datetime[μs]]
+        [
+                2000-01-31 02:00:00
+                2000-04-30 02:00:00
+                2000-04-30 02:00:00
+                2000-04-30 02:00:00
+        ]
+        """
+
     def base_utc_offset(self) -> Series:
         """
         Base offset from UTC.
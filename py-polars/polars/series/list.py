@@ -156,6 +156,8 @@ class ListNameSpace:
         seed
             Seed for the random number generator. If set to None (default), a
             random seed is generated for each sample operation.
+            Every sublist is sampled with its own seed, derived from `seed` and the
+            row index of the sublist.
 
         Examples
         --------
@@ -165,7 +167,7 @@ class ListNameSpace:
         Series: 'values' [list[i64]]
         [
             [2, 1]
-            [5]
+            [4]
         ]
         """
 
@@ -319,6 +321,74 @@ class ListNameSpace:
         ]
         """
 
+    def top_k(self, k: int | IntoExprColumn = 5) -> Series:
+        r"""
+        Return the `k` largest elements of every sublist.
+
+        The elements are selected without fully sorting the sublists, which has time
+        complexity:
+
+        .. math:: O(n + k \log{n} - \frac{k}{2})
+
+        per sublist. The result is sorted in descending order.
+
+        Parameters
+        ----------
+        k
+            Number of elements to return per sublist. Sublists with fewer elements
+            return all of them.
+
+        See Also
+        --------
+        bottom_k
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 1, 5, 2], [4, None, 8], [6]])
+        >>> s.list.top_k(2)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [5, 3]
+            [8, 4]
+            [6]
+        ]
+        """
+
+    def bottom_k(self, k: int | IntoExprColumn = 5) -> Series:
+        r"""
+        Return the `k` smallest elements of every sublist.
+
+        The elements are selected without fully sorting the sublists, which has time
+        complexity:
+
+        .. math:: O(n + k \log{n} - \frac{k}{2})
+
+        per sublist. The result is sorted in ascending order.
+
+        Parameters
+        ----------
+        k
+            Number of elements to return per sublist. Sublists with fewer elements
+            return all of them.
+
+        See Also
+        --------
+        top_k
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 1, 5, 2], [4, None, 8], [6]])
+        >>> s.list.bottom_k(2)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 2]
+            [null, 4]
+            [6]
+        ]
+        """
+
     def reverse(self) -> Series:
         """
         Reverse the arrays in the list.
@@ -372,6 +442,56 @@ class ListNameSpace:
         ]
         """
 
+    def dedupe(self) -> Series:
+        """
+        Remove consecutive duplicate values in every sublist.
+
+        Only values that are equal to the value right before them are removed, use
+        :func:`unique` to remove all duplicates. Null values are considered equal to
+        each other.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 1, 2, 1, 1], [None, None, 3], [4]])
+        >>> s.list.dedupe()
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 2, 1]
+            [null, 3]
+            [4]
+        ]
+        """
+
+    def chunk(self, size: int) -> Series:
+        """
+        Split every sublist into sublists of `size` values.
+
+        The last sublist of every list holds the remaining values and may be shorter.
+
+        Parameters
+        ----------
+        size
+            Number of values per sublist. Must be larger than 0.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List` of the input data type.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3, 4, 5], [6, 7], []])
+        >>> s.list.chunk(2)
+        shape: (3,)
+        Series: 'a' [list[list[i64]]]
+        [
+            [[1, 2], [3, 4], [5]]
+            [[6, 7]]
+            []
+        ]
+        """
+
     def concat(self, other: list[Series] | Series | list[Any]) -> Series:
         """
         Concat the arrays in a Series dtype List in linear time.
@@ -464,6 +584,80 @@ class ListNameSpace:
         ]
         """
 
+    def scatter(
+        self,
+        indices: int | list[int] | Expr | Series,
+        values: IntoExpr | list[Any],
+        *,
+        ignore_oob: bool = False,
+    ) -> Series:
+        """
+        Set the values of every sublist at the given indices.
+
+        Indices and values are given per sublist. A column of dtype `List` holds several
+        of them for every sublist, any other column holds one for every sublist. A
+        Python list is used for all sublists, just like values of length 1.
+
+        Parameters
+        ----------
+        indices
+            Indices to set per sublist. Negative indices count from the end of the
+            sublist.
+        values
+            Values to set. Either a value for every index, or a single value that is
+            set at all indices of the sublist.
+        ignore_oob
+            Behavior if an index is out of bounds:
+            True -> skip the index
+            False -> raise an error
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 5], [6]])
+        >>> s.list.scatter([0, -1], [0, 9], ignore_oob=True)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [0, 2, 9]
+            [0, 9]
+            [9]
+        ]
+        """
+
+    def zip_with(
+        self, mask: Series, other: Series, *, null_on_mismatch: bool = False
+    ) -> Series:
+        """
+        Take values from this list or `other` based on the given `mask`.
+
+        Where the sublist of `mask` is true, take the value from this list, else take
+        the value from `other`. The sublists of every row must have the same length.
+
+        Parameters
+        ----------
+        mask
+            Boolean `List` Series.
+        other
+            `List` Series of the same data type.
+        null_on_mismatch
+            Behavior if the sublists of a row have different lengths:
+            True -> the sublist is null
+            False -> raise an error
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [3, 4, 5]])
+        >>> mask = pl.Series([[True, False], [False, True, True]])
+        >>> other = pl.Series([[6, 7], [8, 9, 10]])
+        >>> s.list.zip_with(mask, other)
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 7]
+            [8, 4, 5]
+        ]
+        """
+
     def gather_every(
         self, n: int | IntoExprColumn, offset: int | IntoExprColumn = 0
     ) -> Series:
@@ -493,7 +687,13 @@ class ListNameSpace:
     def __getitem__(self, item: int) -> Series:
         return self.get(item)
 
-    def join(self, separator: IntoExprColumn, *, ignore_nulls: bool = True) -> Series:
+    def join(
+        self,
+        separator: IntoExprColumn,
+        *,
+        ignore_nulls: bool = True,
+        null_replacement: str | None = None,
+    ) -> Series:
         """
         Join all string items in a sublist and place a separator between them.
 
@@ -508,6 +708,9 @@ class ListNameSpace:
 
             If set to ``False``, null values will be propagated.
             If the sub-list contains any null values, the output is ``None``.
+        null_replacement
+            String to write in place of null values. If set, null values are
+            neither ignored nor propagated.
 
         Returns
         -------
@@ -673,6 +876,134 @@ class ListNameSpace:
         """
 
     @deprecate_renamed_parameter("periods", "n", version="0.19.11")
+    def rolling_sum(
+        self, window_size: int, *, min_periods: int | None = None, center: bool = False
+    ) -> Series:
+        """
+        Apply a rolling sum over the values of every sublist.
+
+        A window of length `window_size` traverses every sublist separately, so the
+        result has the same length as the sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If None, it will be set equal to the window size.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 4, 2, 3], [5, 1], None])
+        >>> s.list.rolling_sum(2)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [null, 5, … 5]
+            [null, 6]
+            null
+        ]
+        """
+
+    def rolling_mean(
+        self, window_size: int, *, min_periods: int | None = None, center: bool = False
+    ) -> Series:
+        """
+        Apply a rolling mean over the values of every sublist.
+
+        A window of length `window_size` traverses every sublist separately, so the
+        result has the same length as the sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If None, it will be set equal to the window size.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 4, 2, 3], [5, 1], None])
+        >>> s.list.rolling_mean(2)
+        shape: (3,)
+        Series: 'a' [list[f64]]
+        [
+            [null, 2.5, … 2.5]
+            [null, 3.0]
+            null
+        ]
+        """
+
+    def rolling_min(
+        self, window_size: int, *, min_periods: int | None = None, center: bool = False
+    ) -> Series:
+        """
+        Apply a rolling minimum over the values of every sublist.
+
+        A window of length `window_size` traverses every sublist separately, so the
+        result has the same length as the sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If None, it will be set equal to the window size.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 4, 2, 3], [5, 1], None])
+        >>> s.list.rolling_min(2)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [null, 1, … 2]
+            [null, 1]
+            null
+        ]
+        """
+
+    def rolling_max(
+        self, window_size: int, *, min_periods: int | None = None, center: bool = False
+    ) -> Series:
+        """
+        Apply a rolling maximum over the values of every sublist.
+
+        A window of length `window_size` traverses every sublist separately, so the
+        result has the same length as the sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If None, it will be set equal to the window size.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 4, 2, 3], [5, 1], None])
+        >>> s.list.rolling_max(2)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [null, 4, … 3]
+            [null, 5]
+            null
+        ]
+        """
+
     def shift(self, n: int | IntoExprColumn = 1) -> Series:
         """
         Shift list values by the given number of indices.
@@ -778,6 +1109,55 @@ class ListNameSpace:
         ]
         """
 
+    def pad_end(self, length: int | str | Expr, fill_value: IntoExpr) -> Series:
+        """
+        Pad every sublist at the end with `fill_value` until it has `length` values.
+
+        Sublists that already have at least `length` values are left untouched. Use
+        :func:`truncate` to also cut off longer sublists.
+
+        Parameters
+        ----------
+        length
+            Number of values every sublist should at least have.
+        fill_value
+            Value to pad the sublists with.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3, 4], [10, 2], []])
+        >>> s.list.pad_end(3, 0)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 2, … 4]
+            [10, 2, 0]
+            [0, 0, 0]
+        ]
+        """
+
+    def truncate(self, length: int | str | Expr) -> Series:
+        """
+        Truncate every sublist to at most `length` values.
+
+        Parameters
+        ----------
+        length
+            Maximum number of values of every sublist.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3, 4], [10, 2], []])
+        >>> s.list.truncate(3)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 2, 3]
+            [10, 2]
+            []
+        ]
+        """
+
     def explode(self) -> Series:
         """
         Returns a column with a separate row for every list element.
@@ -831,7 +1211,33 @@ class ListNameSpace:
         ]
         """
 
-    def to_array(self, width: int) -> Series:
+    def index_of(self, value: IntoExpr) -> Series:
+        """
+        Get the index of the first occurrence of a value in every sub-list.
+
+        The index is null if the value does not occur in the sub-list.
+
+        Parameters
+        ----------
+        value
+            The value to search for. Either a single value that is searched for in
+            every sub-list, or an expression that produces one value per sub-list.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [3, 1], [4], None])
+        >>> s.list.index_of(3)
+        shape: (4,)
+        Series: 'a' [u32]
+        [
+            2
+            0
+            null
+            null
+        ]
+        """
+
+    def to_array(self, width: int, *, null_on_mismatch: bool = False) -> Series:
         """
         Convert a List column into an Array column with the same inner data type.
 
@@ -839,6 +1245,9 @@ class ListNameSpace:
         ----------
         width
             Width of the resulting Array column.
+        null_on_mismatch
+            Set sublists with a length other than `width` to null instead of raising
+            an error.
 
         Returns
         -------
@@ -929,6 +1338,73 @@ class ListNameSpace:
             .to_series()
         )
 
+    def struct_field(self, name: str) -> Series:
+        """
+        Get the field `name` of every struct in a list of structs.
+
+        Parameters
+        ----------
+        name
+            Name of the struct field to retrieve.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List` with the inner data type of the field.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "events",
+        ...     [
+        ...         [{"ts": 1, "kind": "a"}, {"ts": 5, "kind": "b"}],
+        ...         [{"ts": 3, "kind": "a"}],
+        ...         None,
+        ...     ],
+        ... )
+        >>> s.list.struct_field("ts")
+        shape: (3,)
+        Series: 'events' [list[i64]]
+        [
+            [1, 5]
+            [3]
+            null
+        ]
+        """
+
+    def value_counts(self, *, sort: bool = False, normalize: bool = False) -> Series:
+        """
+        Count the occurrences of the unique values of every sublist.
+
+        Parameters
+        ----------
+        sort
+            Sort the output of every sublist by count in descending order.
+            If set to `False` (default), the values are in order of first occurrence.
+        normalize
+            Return the proportion of every value in the sublist instead of the count.
+            The field is named `proportion` instead of `count`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List` of :class:`Struct` with the fields `value`
+            and `count` (or `proportion`).
+
+        Examples
+        --------
+        >>> s = pl.Series("tokens", [["a", "b", "a"], ["c"], [], None])
+        >>> s.list.value_counts(sort=True)
+        shape: (4,)
+        Series: 'tokens' [list[struct[2]]]
+        [
+            [{"a",2}, {"b",1}]
+            [{"c",1}]
+            []
+            null
+        ]
+        """
+
     def eval(self, expr: Expr, *, parallel: bool = False) -> Series:
         """
         Run any polars expression against the lists' elements.
@@ -958,6 +1434,33 @@ class ListNameSpace:
         ]
         """
 
+    def filter(self, predicate: Expr) -> Series:
+        """
+        Filter elements in each of the sublists by a boolean expression.
+
+        Parameters
+        ----------
+        predicate
+            Boolean expression that is evaluated against the elements of the
+            sublists. Refer to the elements with `pl.element()`.
+
+        See Also
+        --------
+        eval
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 8, 3], [4, 5, 2], []])
+        >>> s.list.filter(pl.element() > 2)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [8, 3]
+            [4, 5]
+            []
+        ]
+        """
+
     def set_union(self, other: Series) -> Series:
         """
         Compute the SET UNION between the elements in this list and the elements of `other`.
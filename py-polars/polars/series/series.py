@@ -133,6 +133,7 @@ if TYPE_CHECKING:
         BufferInfo,
         ClosedInterval,
         ComparisonOperator,
+        Endianness,
         FillNullStrategy,
         InterpolationMethod,
         IntoExpr,
@@ -2058,6 +2059,51 @@ class Series:
         """
         return self.to_frame().select_seq(F.col(self.name).nan_min()).item()
 
+    def bitwise_and(self) -> int | bool | None:
+        """
+        Reduce the values of an integer or boolean Series with a bitwise "and".
+
+        Null values are ignored. If the Series contains no non-null values, the
+        result is `None`.
+
+        Examples
+        --------
+        >>> s = pl.Series("flags", [0b110, 0b011, None])
+        >>> s.bitwise_and()
+        2
+        """
+        return self.to_frame().select_seq(F.col(self.name).bitwise_and()).item()
+
+    def bitwise_or(self) -> int | bool | None:
+        """
+        Reduce the values of an integer or boolean Series with a bitwise "or".
+
+        Null values are ignored. If the Series contains no non-null values, the
+        result is `None`.
+
+        Examples
+        --------
+        >>> s = pl.Series("flags", [0b110, 0b011, None])
+        >>> s.bitwise_or()
+        7
+        """
+        return self.to_frame().select_seq(F.col(self.name).bitwise_or()).item()
+
+    def bitwise_xor(self) -> int | bool | None:
+        """
+        Reduce the values of an integer or boolean Series with a bitwise "xor".
+
+        Null values are ignored. If the Series contains no non-null values, the
+        result is `None`.
+
+        Examples
+        --------
+        >>> s = pl.Series("flags", [0b110, 0b011, None])
+        >>> s.bitwise_xor()
+        5
+        """
+        return self.to_frame().select_seq(F.col(self.name).bitwise_xor()).item()
+
     def std(self, ddof: int = 1) -> float | timedelta | None:
         """
         Get the standard deviation of this Series.
@@ -5546,6 +5592,62 @@ class Series:
         ]
         """
 
+    def shift_left(self, n: int | IntoExprColumn) -> Series:
+        """
+        Shift the bits of an integer Series to the left by `n` bits.
+
+        Bits that are shifted past the width of the integer type are discarded.
+        Note that this is different from :func:`shift`, which shifts the values of
+        a Series by a number of indices.
+
+        Parameters
+        ----------
+        n
+            Number of bits to shift by; accepts expression input. Must be
+            non-negative.
+
+        Examples
+        --------
+        >>> s = pl.Series("x", [1, 3, 16, -4], dtype=pl.Int8)
+        >>> s.shift_left(2)
+        shape: (4,)
+        Series: 'x' [i8]
+        [
+                4
+                12
+                64
+                -16
+        ]
+        """
+
+    def shift_right(self, n: int | IntoExprColumn) -> Series:
+        """
+        Shift the bits of an integer Series to the right by `n` bits.
+
+        Signed integers are shifted arithmetically, i.e. negative values remain
+        negative. Note that this is different from :func:`shift`, which shifts the
+        values of a Series by a number of indices.
+
+        Parameters
+        ----------
+        n
+            Number of bits to shift by; accepts expression input. Must be
+            non-negative.
+
+        Examples
+        --------
+        >>> s = pl.Series("x", [1, 12, 16, -4], dtype=pl.Int8)
+        >>> s.shift_right(2)
+        shape: (4,)
+        Series: 'x' [i8]
+        [
+                0
+                3
+                4
+                -1
+        ]
+        """
+
     def zip_with(self, mask: Series, other: Series) -> Self:
         """
         Take values from self or other based on the given mask.
@@ -6351,7 +6453,7 @@ class Series:
         -----
         This implementation of `hash` does not guarantee stable results
         across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        single version. Use :func:`hash_stable` for hashes that are persisted.
 
         Examples
         --------
@@ -6366,6 +6468,47 @@ class Series:
         ]
         """
 
+    def hash_stable(self, seed: int = 0) -> Series:
+        """
+        Hash the Series with a hash that is stable across versions.
+
+        Unlike :func:`hash`, the result can be persisted, e.g. to partition data by a
+        hashed key. The hash value is of type `UInt64`.
+
+        Parameters
+        ----------
+        seed
+            Seed of the hash function. Defaults to 0.
+
+        Notes
+        -----
+        The hash is computed with XXH64 and is guaranteed to be the same across
+        Polars versions and platforms. The hashed bytes of a value are:
+
+        - for booleans, a single byte that is `0` or `1`.
+        - for integers, decimals and temporal types, the physical value in little
+          endian byte order. The hash thus depends on the width of the integer type
+          and on the time unit.
+        - for floats, the value in little endian byte order, where `-0.0` is hashed
+          as `0.0` and all NaN values as the same NaN.
+        - for strings, categoricals and enums, the UTF-8 encoded string.
+        - for binary data, the bytes themselves.
+
+        Null values remain null. Nested data types are not supported.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["x", "y", None])
+        >>> s.hash_stable(seed=42)
+        shape: (3,)
+        Series: 'a' [u64]
+        [
+                12810693594942329153
+                15545397689228866651
+                null
+        ]
+        """
+
     def reinterpret(self, *, signed: bool = True) -> Series:
         """
         Reinterpret the underlying bits as a signed/unsigned integer.
@@ -6399,6 +6542,36 @@ class Series:
         ]
         """
 
+    def to_binary(self, *, endianness: Endianness = "little") -> Series:
+        r"""
+        Reinterpret each number as its bytes.
+
+        Parameters
+        ----------
+        endianness : {'little', 'big'}
+            The byte order of the bytes.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        See Also
+        --------
+        Series.bin.reinterpret : Reinterpret bytes as numbers.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 256], dtype=pl.Int32)
+        >>> s.to_binary(endianness="big")
+        shape: (2,)
+        Series: 'a' [binary]
+        [
+                b"\x00\x00\x00\x01"
+                b"\x00\x00\x01\x00"
+        ]
+        """
+
     def interpolate(self, method: InterpolationMethod = "linear") -> Series:
         """
         Fill null values using interpolation.
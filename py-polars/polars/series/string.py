@@ -1,25 +1,30 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Mapping
 
 from polars._utils.deprecation import (
     deprecate_renamed_function,
     deprecate_renamed_parameter,
 )
+from polars._utils.various import no_default
 from polars.datatypes.constants import N_INFER_DEFAULT
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
     from polars import Expr, Series
+    from polars._utils.various import NoDefault
     from polars.polars import PySeries
     from polars.type_aliases import (
         Ambiguous,
+        DigestAlgorithm,
         IntoExpr,
         IntoExprColumn,
         PolarsDataType,
         PolarsTemporalType,
+        SchemaDict,
         TimeUnit,
         TransferEncoding,
+        UnicodeForm,
     )
 
 
@@ -189,6 +194,49 @@ class StringNameSpace:
         ]
         """
 
+    def to_utc_offset(
+        self,
+        format: str,
+        *,
+        strict: bool = True,
+        cache: bool = True,
+    ) -> Series:
+        """
+        Parse the offsets from UTC of a String column with a time zone.
+
+        Parameters
+        ----------
+        format
+            Format of the strings, with a time zone offset (`%z`) or name (`%Z`).
+            Refer to the `chrono crate documentation
+            <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
+            for the full specification. Example: `"%Y-%m-%d %H:%M:%S%z"`.
+        strict
+            Raise an error if any conversion fails.
+        cache
+            Use a cache of unique, converted offsets to apply the conversion.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Duration` with time unit `"ms"`.
+
+        See Also
+        --------
+        to_datetime : Parse the strings into datetimes in UTC.
+
+        Examples
+        --------
+        >>> s = pl.Series(["2020-01-01 01:00+01:00", "2020-01-01 02:00-05:00"])
+        >>> s.str.to_utc_offset("%Y-%m-%d %H:%M%:z")
+        shape: (2,)
+        Series: '' [duration[ms]]
+        [
+                1h
+                -5h
+        ]
+        """
+
     def strptime(
         self,
         dtype: PolarsTemporalType,
@@ -497,7 +545,12 @@ class StringNameSpace:
         """
 
     def find(
-        self, pattern: str | Expr, *, literal: bool = False, strict: bool = True
+        self,
+        pattern: str | Expr,
+        *,
+        literal: bool = False,
+        strict: bool = True,
+        char_index: bool = False,
     ) -> Expr:
         """
         Return the index of the first substring in Series strings matching a pattern.
@@ -514,6 +567,9 @@ class StringNameSpace:
         strict
             Raise an error if the underlying pattern is not a valid regex,
             otherwise mask out with a null value.
+        char_index
+            Return the index as a number of characters instead of bytes. This
+            differs from the byte index if the string contains non-ASCII characters.
 
         Notes
         -----
@@ -580,6 +636,15 @@ class StringNameSpace:
             null
             5
         ]
+
+        Non-ASCII characters take up more than one byte. Return the index as a
+        number of characters instead:
+
+        >>> s = pl.Series(["añb", "ññb"])
+        >>> s.str.find("b").to_list()
+        [3, 4]
+        >>> s.str.find("b", char_index=True).to_list()
+        [2, 2]
         """
 
     def ends_with(self, suffix: str | Expr) -> Series:
@@ -609,6 +674,31 @@ class StringNameSpace:
         ]
         """
 
+    def escape_regex(self) -> Series:
+        r"""
+        Escape all regular expression meta characters in the string values.
+
+        The escaped strings match themselves literally when used as a regex pattern,
+        so that values from the data can be interpolated into dynamic patterns.
+
+        See Also
+        --------
+        contains : Check if string contains a substring that matches a regex.
+
+        Examples
+        --------
+        >>> s = pl.Series(["abc", "1+1=2", "$5.00", None])
+        >>> s.str.escape_regex()
+        shape: (4,)
+        Series: '' [str]
+        [
+            "abc"
+            "1\+1=2"
+            "\$5\.00"
+            null
+        ]
+        """
+
     def starts_with(self, prefix: str | Expr) -> Series:
         """
         Check if string values start with a substring.
@@ -642,8 +732,9 @@ class StringNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base32', 'percent'}
+            The encoding to use. `'percent'` is the URL percent-encoding, which
+            encodes all characters except the unreserved characters of RFC 3986.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -672,8 +763,9 @@ class StringNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base32', 'percent'}
+            The encoding to use. `'percent'` is the URL percent-encoding, which
+            encodes all characters except the unreserved characters of RFC 3986.
 
         Returns
         -------
@@ -695,22 +787,30 @@ class StringNameSpace:
 
     def json_decode(
         self,
-        dtype: PolarsDataType | None = None,
+        dtype: PolarsDataType | SchemaDict | None = None,
         infer_schema_length: int | None = N_INFER_DEFAULT,
+        *,
+        strict: bool = True,
     ) -> Series:
         """
         Parse string values as JSON.
 
-        Throws an error if invalid JSON strings are encountered.
+        Throws an error if invalid JSON strings are encountered, unless `strict` is
+        set to False.
 
         Parameters
         ----------
         dtype
-            The dtype to cast the extracted value to. If None, the dtype will be
-            inferred from the JSON value.
+            The dtype to cast the extracted value to. A mapping of field names to
+            dtypes is decoded as a :class:`Struct` with those fields. If None, the
+            dtype will be inferred from the JSON value.
         infer_schema_length
             The maximum number of rows to scan for schema inference.
             If set to `None`, the full data may be scanned *(this is slow)*.
+        strict
+            Raise an error if a string is not valid JSON. If set to False, such
+            strings are decoded as null instead, and are ignored when inferring the
+            dtype.
 
         See Also
         --------
@@ -728,6 +828,18 @@ class StringNameSpace:
                 {null,null}
                 {2,false}
         ]
+
+        Decode malformed JSON strings as null:
+
+        >>> s = pl.Series("json", ['{"a": 1}', '{"a": ', '{"a": 3}'])
+        >>> s.str.json_decode({"a": pl.Int8}, strict=False)
+        shape: (3,)
+        Series: 'json' [struct[1]]
+        [
+            {1}
+            {null}
+            {3}
+        ]
         """
 
     def json_path_match(self, json_path: IntoExprColumn) -> Series:
@@ -1439,7 +1551,13 @@ class StringNameSpace:
         ]
         """
 
-    def pad_start(self, length: int, fill_char: str = " ") -> Series:
+    def pad_start(
+        self,
+        length: int | IntoExprColumn,
+        fill_char: str = " ",
+        *,
+        display_width: bool = False,
+    ) -> Series:
         """
         Pad the start of the string until it reaches the given length.
 
@@ -1447,9 +1565,15 @@ class StringNameSpace:
         ----------
         length
             Pad the string until it reaches this length. Strings with length equal to
-            or greater than this value are returned as-is.
+            or greater than this value are returned as-is. Can be an expression to
+            pad every string to a different length.
         fill_char
             The character to pad the string with.
+        display_width
+            Count the length in terminal display width rather than in characters,
+            so that strings with wide characters such as CJK line up. The string is
+            never padded beyond `length`, so if `fill_char` is wide the result may
+            be one column short.
 
         See Also
         --------
@@ -1470,7 +1594,13 @@ class StringNameSpace:
         ]
         """
 
-    def pad_end(self, length: int, fill_char: str = " ") -> Series:
+    def pad_end(
+        self,
+        length: int | IntoExprColumn,
+        fill_char: str = " ",
+        *,
+        display_width: bool = False,
+    ) -> Series:
         """
         Pad the end of the string until it reaches the given length.
 
@@ -1478,9 +1608,15 @@ class StringNameSpace:
         ----------
         length
             Pad the string until it reaches this length. Strings with length equal to
-            or greater than this value are returned as-is.
+            or greater than this value are returned as-is. Can be an expression to
+            pad every string to a different length.
         fill_char
             The character to pad the string with.
+        display_width
+            Count the length in terminal display width rather than in characters,
+            so that strings with wide characters such as CJK line up. The string is
+            never padded beyond `length`, so if `fill_char` is wide the result may
+            be one column short.
 
         See Also
         --------
@@ -1537,6 +1673,36 @@ class StringNameSpace:
         ]
         """
 
+    def wrap(
+        self, width: int, *, break_long_words: bool = True, tab_size: int = 8
+    ) -> Series:
+        r"""
+        Wrap the string values into lines of at most `width` characters.
+
+        Parameters
+        ----------
+        width
+            Maximum number of characters per line.
+        break_long_words
+            Break words that are longer than `width` across lines. If set to `False`,
+            such words are put on a line of their own.
+        tab_size
+            Expand tabs to the next multiple of this number of columns before
+            wrapping. If set to 0, tabs are removed.
+
+        Notes
+        -----
+        Words are separated by spaces. Existing line breaks are kept, and the
+        whitespace at a new line break is dropped. Strings that fit within `width`
+        are returned as-is.
+
+        Examples
+        --------
+        >>> s = pl.Series(["The quick brown fox jumps over the lazy dog", "short"])
+        >>> s.str.wrap(10).to_list()
+        ['The quick\nbrown fox\njumps over\nthe lazy\ndog', 'short']
+        """
+
     def to_lowercase(self) -> Series:
         """
         Modify the strings to their lowercase equivalent.
@@ -1553,6 +1719,130 @@ class StringNameSpace:
         ]
         """
 
+    def casefold(self) -> Series:
+        """
+        Fold the case of the string values, for caseless comparison.
+
+        Case folding is a more aggressive form of lowercasing that maps all
+        characters that only differ in case to the same characters. For example,
+        "ß" and "SS" both fold to "ss", whereas their lowercase variants differ.
+
+        Notes
+        -----
+        This is the full case folding of the Unicode standard, without the
+        Turkic-specific mappings, and is equivalent to Python's `str.casefold`.
+        Strings are not normalized; use :meth:`normalize` first to also compare
+        strings that differ in their Unicode normalization form.
+
+        Examples
+        --------
+        >>> s = pl.Series("foo", ["Straße", "STRASSE", "ΣΊΣΥΦΟΣ"])
+        >>> s.str.casefold()
+        shape: (3,)
+        Series: 'foo' [str]
+        [
+            "strasse"
+            "strasse"
+            "σίσυφοσ"
+        ]
+        """
+
+    def caseless_eq(self, other: str | Expr) -> Series:
+        """
+        Check if string values are equal to `other`, ignoring case.
+
+        Both sides are compared after :meth:`casefold`.
+
+        Parameters
+        ----------
+        other
+            String or expression to compare with.
+
+        Examples
+        --------
+        >>> s = pl.Series(["Straße", "Hello", None])
+        >>> s.str.caseless_eq("STRASSE")
+        shape: (3,)
+        Series: '' [bool]
+        [
+            true
+            false
+            null
+        ]
+        """
+
+    def caseless_contains(self, pattern: str | Expr) -> Series:
+        """
+        Check if string values contain the literal substring `pattern`, ignoring case.
+
+        Both sides are compared after :meth:`casefold`.
+
+        Parameters
+        ----------
+        pattern
+            Literal substring, or an expression of substrings.
+
+        Examples
+        --------
+        >>> s = pl.Series(["Hauptstraße", "HAUPTSTRASSE 1", "Ringweg"])
+        >>> s.str.caseless_contains("strasse")
+        shape: (3,)
+        Series: '' [bool]
+        [
+            true
+            true
+            false
+        ]
+        """
+
+    def caseless_starts_with(self, prefix: str | Expr) -> Series:
+        """
+        Check if string values start with `prefix`, ignoring case.
+
+        Both sides are compared after :meth:`casefold`.
+
+        Parameters
+        ----------
+        prefix
+            Prefix substring, or an expression of prefixes.
+
+        Examples
+        --------
+        >>> s = pl.Series("fruits", ["Apple", "mango", None])
+        >>> s.str.caseless_starts_with("APP")
+        shape: (3,)
+        Series: 'fruits' [bool]
+        [
+            true
+            false
+            null
+        ]
+        """
+
+    def caseless_ends_with(self, suffix: str | Expr) -> Series:
+        """
+        Check if string values end with `suffix`, ignoring case.
+
+        Both sides are compared after :meth:`casefold`.
+
+        Parameters
+        ----------
+        suffix
+            Suffix substring, or an expression of suffixes.
+
+        Examples
+        --------
+        >>> s = pl.Series("fruits", ["apple", "MANGO", None])
+        >>> s.str.caseless_ends_with("go")
+        shape: (3,)
+        Series: 'fruits' [bool]
+        [
+            false
+            true
+            null
+        ]
+        """
+
     def to_uppercase(self) -> Series:
         """
         Modify the strings to their uppercase equivalent.
@@ -1573,6 +1863,13 @@ class StringNameSpace:
         """
         Modify the strings to their titlecase equivalent.
 
+        Notes
+        -----
+        The first character of every word is uppercased and the other characters
+        are lowercased. Words are delimited by Unicode word boundaries, so
+        punctuation such as hyphens also starts a new word, but apostrophes within
+        a word do not.
+
         Examples
         --------
         >>> s = pl.Series("sing", ["welcome to my world", "THERE'S NO TURNING BACK"])
@@ -1602,6 +1899,169 @@ class StringNameSpace:
         ]
         """
 
+    def normalize(self, form: UnicodeForm = "NFC") -> Series:
+        """
+        Normalize the string values to a Unicode normalization form.
+
+        Parameters
+        ----------
+        form : {'NFC', 'NFD', 'NFKC', 'NFKD'}
+            Unicode normalization form to convert to. The canonical forms (NFC and
+            NFD) only unify different representations of the same character, the
+            compatibility forms (NFKC and NFKD) also unify characters that have the
+            same meaning, such as ligatures. See the `Unicode standard
+            <https://www.unicode.org/reports/tr15/>`_ for more information.
+
+        Notes
+        -----
+        ASCII strings are the same in every normalization form and are returned as-is.
+
+        Examples
+        --------
+        >>> s = pl.Series(["01²", "ﬁle", "cafe\u0301"])
+        >>> s.str.normalize("NFKC")
+        shape: (3,)
+        Series: '' [str]
+        [
+            "012"
+            "file"
+            "café"
+        ]
+        >>> s.str.normalize("NFD").str.len_chars().to_list()
+        [3, 3, 5]
+        """
+
+    def digest(self, algorithm: DigestAlgorithm, *, as_hex: bool = True) -> Series:
+        """
+        Hash the UTF-8 bytes of each string with a cryptographic hash or checksum.
+
+        Parameters
+        ----------
+        algorithm : {'md5', 'sha1', 'sha256', 'crc32'}
+            The hash function to digest the values with. The CRC-32 checksum is the
+            one used by zlib and gzip, its four bytes are in big endian order.
+        as_hex
+            Return the digest as a lowercase hex string. If set to `False`, the
+            digest is returned as binary instead.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String` if `as_hex` is set, and of data type
+            :class:`Binary` otherwise.
+
+        See Also
+        --------
+        Series.hash : Hash the values with a fast, non-cryptographic hash function.
+
+        Examples
+        --------
+        >>> s = pl.Series("text", ["polars", None])
+        >>> s.str.digest("md5")
+        shape: (2,)
+        Series: 'text' [str]
+        [
+                "33f83688ff9c10a6eb6a9b34d8fac7…
+                null
+        ]
+        """
+
+    def levenshtein(self, other: str | Expr) -> Series:
+        """
+        Compute the Levenshtein distance to another string.
+
+        The Levenshtein distance is the minimal number of single-character
+        insertions, deletions and substitutions to change one string into the other.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings to compare with. Strings are parsed as
+            literals, not as column names.
+
+        See Also
+        --------
+        hamming
+        jaro_winkler
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "polars", "pandas", None])
+        >>> s.str.levenshtein("sitting")
+        shape: (4,)
+        Series: '' [u32]
+        [
+            3
+            7
+            7
+            null
+        ]
+        """
+
+    def hamming(self, other: str | Expr) -> Series:
+        """
+        Compute the Hamming distance to another string.
+
+        The Hamming distance is the number of positions at which the characters of
+        two strings differ. It is null if the strings have a different number of
+        characters.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings to compare with. Strings are parsed as
+            literals, not as column names.
+
+        See Also
+        --------
+        levenshtein
+        jaro_winkler
+
+        Examples
+        --------
+        >>> s = pl.Series(["karolin", "kerstin", "karl"])
+        >>> s.str.hamming("kathrin")
+        shape: (3,)
+        Series: '' [u32]
+        [
+            3
+            4
+            null
+        ]
+        """
+
+    def jaro_winkler(self, other: str | Expr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The Jaro-Winkler similarity ranges from 0 for strings without any characters
+        in common to 1 for equal strings. Strings that share a prefix of up to four
+        characters are considered more similar.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings to compare with. Strings are parsed as
+            literals, not as column names.
+
+        See Also
+        --------
+        levenshtein
+        hamming
+
+        Examples
+        --------
+        >>> s = pl.Series(["martha", "marhta", "xyz"])
+        >>> s.str.jaro_winkler("martha")
+        shape: (3,)
+        Series: '' [f64]
+        [
+            1.0
+            0.961111
+            0.0
+        ]
+        """
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Series:
@@ -1611,10 +2071,11 @@ class StringNameSpace:
         Parameters
         ----------
         offset
-            Start index. Negative indexing is supported.
+            Start index (integer or expression). Negative indexing is supported.
+            A null offset results in a null value.
         length
-            Length of the slice. If set to `None` (default), the slice is taken to the
-            end of the string.
+            Length of the slice (integer or expression). If set to `None` (default),
+            the slice is taken to the end of the string, as it is for null lengths.
 
         Returns
         -------
@@ -1808,8 +2269,8 @@ class StringNameSpace:
         Parameters
         ----------
         base
-            Positive integer or expression which is the base of the string
-            we are parsing.
+            Integer between 2 and 36 or expression which is the base of the
+            string we are parsing.
             Default: 10.
         strict
             Bool, Default=True will raise any ParseError or overflow as ComputeError.
@@ -2019,7 +2480,8 @@ class StringNameSpace:
         Parameters
         ----------
         patterns
-            String patterns to search.
+            String patterns to search. All patterns are searched for in every
+            string, also if they are given as a column. Null patterns are ignored.
         ascii_case_insensitive
             Enable ASCII-aware case insensitive matching.
             When this option is enabled, searching will be performed without respect
@@ -2048,8 +2510,8 @@ class StringNameSpace:
 
     def replace_many(
         self,
-        patterns: Series | list[str],
-        replace_with: Series | list[str] | str,
+        patterns: Series | list[str] | Mapping[str, str],
+        replace_with: Series | list[str] | str | NoDefault = no_default,
         *,
         ascii_case_insensitive: bool = False,
     ) -> Series:
@@ -2060,6 +2522,8 @@ class StringNameSpace:
         ----------
         patterns
             String patterns to search and replace.
+            Also accepts a mapping of patterns to their replacement as syntactic sugar
+            for `replace_many(pl.Series(mapping.keys()), pl.Series(mapping.values()))`.
         replace_with
             Strings to replace where a pattern was a match.
             This can be broadcasted. So it supports many:one and many:many.
@@ -2068,6 +2532,11 @@ class StringNameSpace:
             When this option is enabled, searching will be performed without respect
             to case for ASCII letters (a-z and A-Z) only.
 
+        Notes
+        -----
+        All patterns are replaced in a single pass over every string, so a
+        replacement is never matched by another pattern.
+
         Examples
         --------
         >>> _ = pl.Config.set_fmt_str_lengths(100)
@@ -2087,4 +2556,56 @@ class StringNameSpace:
             "Tell you what me want, what me really really want"
             "Can me feel the love tonight"
         ]
+
+        Pass a mapping of patterns to their replacement.
+
+        >>> s = pl.Series("text", ["a cat and a dog", "no pets"])
+        >>> s.str.replace_many({"cat": "dog", "dog": "cat"})
+        shape: (2,)
+        Series: 'text' [str]
+        [
+            "a dog and a cat"
+            "no pets"
+        ]
+        """
+
+    def extract_many(
+        self,
+        patterns: Series | list[str],
+        *,
+        ascii_case_insensitive: bool = False,
+        overlapping: bool = False,
+    ) -> Series:
+        """
+        Use the aho-corasick algorithm to extract many matches.
+
+        Parameters
+        ----------
+        patterns
+            String patterns to search.
+        ascii_case_insensitive
+            Enable ASCII-aware case insensitive matching.
+            When this option is enabled, searching will be performed without respect
+            to case for ASCII letters (a-z and A-Z) only.
+        overlapping
+            Whether matches may overlap.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List` of :class:`String` with the matches in
+            order of occurrence.
+
+        Examples
+        --------
+        >>> s = pl.Series("values", ["discontent", "reconstruct", None])
+        >>> patterns = ["winter", "disco", "onte", "discontent", "struct"]
+        >>> s.str.extract_many(patterns, overlapping=True)
+        shape: (3,)
+        Series: 'values' [list[str]]
+        [
+            ["disco", "onte", "discontent"]
+            ["struct"]
+            null
+        ]
         """
@@ -1,15 +1,16 @@
 from __future__ import annotations
 
 from collections import OrderedDict
-from typing import TYPE_CHECKING, Sequence
+from typing import TYPE_CHECKING, Callable, Mapping, Sequence
 
 from polars._utils.various import BUILDING_SPHINX_DOCS, sphinx_accessor
-from polars._utils.wrap import wrap_df
+from polars._utils.wrap import wrap_df, wrap_s
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
     from polars import DataFrame, DataType, Series
     from polars.polars import PySeries
+    from polars.type_aliases import UnnestNaming
 elif BUILDING_SPHINX_DOCS:
     property = sphinx_accessor
 
@@ -71,14 +72,174 @@ class StructNameSpace:
         ]
         """
 
-    def rename_fields(self, names: Sequence[str]) -> Series:
+    def field_by_path(self, path: str) -> Series:
+        """
+        Retrieve a nested field of this `Struct` by its path.
+
+        Parameters
+        ----------
+        path
+            Field names separated by `.`, where each name can be followed by list
+            indices in square brackets, for example `"b.c[0].d"`. Negative indices
+            count from the end of the list.
+
+        Examples
+        --------
+        >>> s = pl.Series([{"b": [{"c": 1}, {"c": 2}]}, {"b": [{"c": 3}]}])
+        >>> s.struct.field_by_path("b[-1].c")
+        shape: (2,)
+        Series: 'c' [i64]
+        [
+                2
+                3
+        ]
+        """
+
+    def select_fields(self, names: str | Sequence[str], *more_names: str) -> Series:
+        """
+        Keep only the given fields of the struct, in the given order.
+
+        Parameters
+        ----------
+        names
+            Name(s) of the struct fields to keep.
+        *more_names
+            Additional struct field names.
+
+        Examples
+        --------
+        >>> s = pl.Series([{"a": 1, "b": "x", "c": True}])
+        >>> s.struct.select_fields("c", "a").struct.fields
+        ['c', 'a']
+        """
+
+    def drop_fields(self, names: str | Sequence[str], *more_names: str) -> Series:
+        """
+        Remove the given fields from the struct.
+
+        Parameters
+        ----------
+        names
+            Name(s) of the struct fields to remove.
+        *more_names
+            Additional struct field names.
+
+        Examples
+        --------
+        >>> s = pl.Series([{"a": 1, "b": "x", "c": True}])
+        >>> s.struct.drop_fields("b").struct.fields
+        ['a', 'c']
+        """
+
+    def sum_fields(self) -> Series:
+        """
+        Sum the numeric fields of the struct per row.
+
+        Fields that are not numeric are skipped. Null values are ignored, so a row
+        without any non-null values sums to zero.
+
+        See Also
+        --------
+        polars.sum_horizontal
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "s", [{"a": 1, "b": 4, "c": "x"}, {"a": 6, "b": None, "c": "y"}]
+        ... )
+        >>> s.struct.sum_fields()
+        shape: (2,)
+        Series: 's' [i64]
+        [
+                5
+                6
+        ]
+        """
+
+    def mean_fields(self) -> Series:
+        """
+        Compute the mean of the numeric fields of the struct per row.
+
+        Fields that are not numeric are skipped. Null values are ignored.
+
+        See Also
+        --------
+        polars.mean_horizontal
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "s", [{"a": 1, "b": 4, "c": "x"}, {"a": 6, "b": None, "c": "y"}]
+        ... )
+        >>> s.struct.mean_fields()
+        shape: (2,)
+        Series: 's' [f64]
+        [
+                2.5
+                6.0
+        ]
+        """
+
+    def min_fields(self) -> Series:
+        """
+        Get the minimum of the numeric fields of the struct per row.
+
+        Fields that are not numeric are skipped. Null values are ignored.
+
+        See Also
+        --------
+        polars.min_horizontal
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "s", [{"a": 1, "b": 4, "c": "x"}, {"a": 6, "b": None, "c": "y"}]
+        ... )
+        >>> s.struct.min_fields()
+        shape: (2,)
+        Series: 's' [i64]
+        [
+                1
+                6
+        ]
+        """
+
+    def max_fields(self) -> Series:
+        """
+        Get the maximum of the numeric fields of the struct per row.
+
+        Fields that are not numeric are skipped. Null values are ignored.
+
+        See Also
+        --------
+        polars.max_horizontal
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "s", [{"a": 1, "b": 4, "c": "x"}, {"a": 6, "b": None, "c": "y"}]
+        ... )
+        >>> s.struct.max_fields()
+        shape: (2,)
+        Series: 's' [i64]
+        [
+                4
+                6
+        ]
+        """
+
+    def rename_fields(
+        self, names: Sequence[str] | Mapping[str, str] | Callable[[str], str]
+    ) -> Series:
         """
         Rename the fields of the struct.
 
         Parameters
         ----------
         names
-            New names in the order of the struct's fields.
+            New names in the order of the struct's fields. Alternatively, a mapping
+            from old to new names, which leaves the fields that are not in the mapping
+            as they are, or a function that takes an old name and returns the new name.
 
         Examples
         --------
@@ -88,6 +249,10 @@ class StructNameSpace:
         >>> s = s.struct.rename_fields(["c", "d"])
         >>> s.struct.fields
         ['c', 'd']
+        >>> s.struct.rename_fields({"d": "e"}).struct.fields
+        ['c', 'e']
+        >>> s.struct.rename_fields(lambda name: f"{name}_1").struct.fields
+        ['c_1', 'd_1']
         """
 
     @property
@@ -105,10 +270,23 @@ class StructNameSpace:
             return OrderedDict()
         return OrderedDict(self._s.dtype().to_schema())
 
-    def unnest(self) -> DataFrame:
+    def unnest(
+        self,
+        *,
+        naming: UnnestNaming | Callable[[str, str], str] = "field",
+        separator: str = "_",
+    ) -> DataFrame:
         """
         Convert this struct Series to a DataFrame with a separate column for each field.
 
+        Parameters
+        ----------
+        naming : {'field', 'prefix', 'suffix'} or function
+            How to name the columns, see :meth:`DataFrame.unnest`.
+        separator
+            The separator between the names of the Series and the field, if `naming`
+            is 'prefix' or 'suffix'.
+
         Examples
         --------
         >>> s = pl.Series([{"a": 1, "b": 2}, {"a": 3, "b": 4}])
@@ -123,12 +301,21 @@ class StructNameSpace:
         │ 3   ┆ 4   │
         └─────┴─────┘
         """
+        if naming != "field":
+            return (
+                wrap_s(self._s)
+                .to_frame()
+                .unnest(self._s.name(), naming=naming, separator=separator)
+            )
         return wrap_df(self._s.struct_unnest())
 
     def json_encode(self) -> Series:
         """
         Convert this struct to a string column with json values.
 
+        Nested lists and structs are encoded as json arrays and objects, temporal
+        values are encoded as strings. This is the inverse of `str.json_decode`.
+
         Examples
         --------
         >>> s = pl.Series("a", [{"a": [1, 2], "b": [45]}, {"a": [9, 1, 3], "b": None}])
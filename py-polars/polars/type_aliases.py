@@ -5,6 +5,7 @@ from decimal import Decimal
 from typing import (
     TYPE_CHECKING,
     Any,
+    Callable,
     Collection,
     Iterable,
     List,
@@ -131,6 +132,8 @@ StartBy: TypeAlias = Literal[
     "sunday",
 ]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
+UnicodeForm: TypeAlias = Literal["NFC", "NFD", "NFKC", "NFKD"]
+DigestAlgorithm: TypeAlias = Literal["md5", "sha1", "sha256", "crc32"]
 UniqueKeepStrategy: TypeAlias = Literal["first", "last", "any", "none"]
 UnstackDirection: TypeAlias = Literal["vertical", "horizontal"]
 MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
@@ -162,12 +165,16 @@ CorrelationMethod: TypeAlias = Literal["pearson", "spearman"]
 DbReadEngine: TypeAlias = Literal["adbc", "connectorx"]
 DbWriteEngine: TypeAlias = Literal["sqlalchemy", "adbc"]
 DbWriteMode: TypeAlias = Literal["replace", "append", "fail"]
+DurationFormat: TypeAlias = Literal["polars", "iso"]
+DurationPrecision: TypeAlias = Literal["d", "h", "m", "s", "ms", "us", "ns"]
+Endianness: TypeAlias = Literal["big", "little"]
 EpochTimeUnit = Literal["ns", "us", "ms", "s", "d"]
 JaxExportType: TypeAlias = Literal["array", "dict"]
 Orientation: TypeAlias = Literal["col", "row"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
 TorchExportType: TypeAlias = Literal["tensor", "dataset", "dict"]
-TransferEncoding: TypeAlias = Literal["hex", "base64"]
+TransferEncoding: TypeAlias = Literal["hex", "base64", "base32", "percent"]
+UnnestNaming: TypeAlias = Literal["field", "prefix", "suffix"]
 WindowMappingStrategy: TypeAlias = Literal["group_to_rows", "join", "explode"]
 
 # type signature for allowed frame init
@@ -219,6 +226,11 @@ ExcelSpreadsheetEngine: TypeAlias = Literal[
     "xlsx2csv", "openpyxl", "calamine", "pyxlsb"
 ]
 
+# function that returns cloud credentials and the Unix timestamp at which they expire
+CredentialProviderFunction: TypeAlias = Callable[
+    [], Tuple[Mapping[str, str], Union[int, None]]
+]
+
 
 class SeriesBuffers(TypedDict):
     """Underlying buffers of a Series."""
@@ -12,6 +12,8 @@ use polars::frame::NullStrategy;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
+#[cfg(feature = "cloud")]
+use polars::io::cloud::{CloudCredentials, CredentialProvider};
 use polars::series::ops::NullBehavior;
 use polars_core::utils::arrow::array::Array;
 use polars_core::utils::arrow::types::NativeType;
@@ -770,21 +772,6 @@ impl<'py> FromPyObject<'py> for Wrap<ListToStructWidthStrategy> {
     }
 }
 
-impl<'py> FromPyObject<'py> for Wrap<NonExistent> {
-    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
-        let parsed = match &*ob.extract::<PyBackedStr>()? {
-            "null" => NonExistent::Null,
-            "raise" => NonExistent::Raise,
-            v => {
-                return Err(PyValueError::new_err(format!(
-                    "`non_existent` must be one of {{'null', 'raise'}}, got {v}",
-                )))
-            },
-        };
-        Ok(Wrap(parsed))
-    }
-}
-
 impl<'py> FromPyObject<'py> for Wrap<NullBehavior> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -967,6 +954,88 @@ impl<'py> FromPyObject<'py> for Wrap<SearchSortedSide> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<DigestAlgorithm> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "md5" => DigestAlgorithm::Md5,
+            "sha1" => DigestAlgorithm::Sha1,
+            "sha256" => DigestAlgorithm::Sha256,
+            "crc32" => DigestAlgorithm::Crc32,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`algorithm` must be one of {{'md5', 'sha1', 'sha256', 'crc32'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<UnicodeForm> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "NFC" => UnicodeForm::NFC,
+            "NFD" => UnicodeForm::NFD,
+            "NFKC" => UnicodeForm::NFKC,
+            "NFKD" => UnicodeForm::NFKD,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`form` must be one of {{'NFC', 'NFD', 'NFKC', 'NFKD'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<Locale> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let v = ob.extract::<PyBackedStr>()?;
+        let parsed = v.parse().map_err(|_| {
+            PyValueError::new_err(format!(
+                "`locale` must be one of {{'de_DE', 'en_US', 'es_ES', 'fr_FR', 'it_IT', 'nl_NL', 'pt_PT'}}, got {}",
+                &*v
+            ))
+        })?;
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<DurationFormat> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "polars" => DurationFormat::Polars,
+            "iso" => DurationFormat::Iso,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`format` must be one of {{'polars', 'iso'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<DurationPrecision> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "d" => DurationPrecision::Days,
+            "h" => DurationPrecision::Hours,
+            "m" => DurationPrecision::Minutes,
+            "s" => DurationPrecision::Seconds,
+            "ms" => DurationPrecision::Milliseconds,
+            "us" => DurationPrecision::Microseconds,
+            "ns" => DurationPrecision::Nanoseconds,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`precision` must be one of {{'d', 'h', 'm', 's', 'ms', 'us', 'ns'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<ClosedInterval> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1041,6 +1110,26 @@ pub(crate) fn parse_cloud_options(uri: &str, kv: Vec<(String, String)>) -> PyRes
     Ok(out)
 }
 
+/// Wrap a Python function that returns a dictionary of credentials and the Unix timestamp
+/// at which they expire, or `None` if they don't.
+#[cfg(feature = "cloud")]
+pub(crate) fn parse_credential_provider(provider: PyObject) -> CredentialProvider {
+    CredentialProvider::new(Arc::new(move || {
+        Python::with_gil(|py| {
+            let out = provider.bind(py).call0()?;
+            let (config, expires_at): (Bound<PyDict>, Option<u64>) = out.extract()?;
+            let config = config
+                .iter()
+                .map(|(key, value)| Ok((key.extract()?, value.extract()?)))
+                .collect::<PyResult<_>>()?;
+            let expires_at =
+                expires_at.map(|secs| std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs));
+            Ok(CloudCredentials { config, expires_at })
+        })
+        .map_err(|err: PyErr| polars_err!(ComputeError: "credential provider failed: {}", err))
+    }))
+}
+
 #[cfg(feature = "list_sets")]
 impl<'py> FromPyObject<'py> for Wrap<SetOperation> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -6,6 +6,7 @@ use pyo3::prelude::*;
 use pyo3::pymethods;
 use smartstring::alias::String as SmartString;
 
+use crate::conversion::Wrap;
 use crate::expr::PyExpr;
 
 #[pymethods]
@@ -22,6 +23,14 @@ impl PyExpr {
         self.inner.clone().arr().sum().into()
     }
 
+    fn arr_product(&self) -> Self {
+        self.inner.clone().arr().product().into()
+    }
+
+    fn arr_mean(&self) -> Self {
+        self.inner.clone().arr().mean().into()
+    }
+
     fn arr_std(&self, ddof: u8) -> Self {
         self.inner.clone().arr().std(ddof).into()
     }
@@ -34,6 +43,14 @@ impl PyExpr {
         self.inner.clone().arr().median().into()
     }
 
+    fn arr_quantile(&self, quantile: PyExpr, interpolation: Wrap<QuantileInterpolOptions>) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .quantile(quantile.inner, interpolation.0)
+            .into()
+    }
+
     fn arr_unique(&self, maintain_order: bool) -> Self {
         if maintain_order {
             self.inner.clone().arr().unique_stable().into()
@@ -46,6 +63,14 @@ impl PyExpr {
         self.inner.clone().arr().n_unique().into()
     }
 
+    fn arr_drop_nulls(&self) -> Self {
+        self.inner.clone().arr().drop_nulls().into()
+    }
+
+    fn arr_mode(&self) -> Self {
+        self.inner.clone().arr().mode().into()
+    }
+
     fn arr_to_list(&self) -> Self {
         self.inner.clone().arr().to_list().into()
     }
@@ -74,6 +99,34 @@ impl PyExpr {
         self.inner.clone().arr().reverse().into()
     }
 
+    fn arr_cum_sum(&self, reverse: bool) -> Self {
+        self.inner.clone().arr().cum_sum(reverse).into()
+    }
+
+    fn arr_cum_min(&self, reverse: bool) -> Self {
+        self.inner.clone().arr().cum_min(reverse).into()
+    }
+
+    fn arr_cum_max(&self, reverse: bool) -> Self {
+        self.inner.clone().arr().cum_max(reverse).into()
+    }
+
+    fn arr_rank(&self, method: Wrap<RankMethod>, descending: bool, seed: Option<u64>) -> Self {
+        let options = RankOptions {
+            method: method.0,
+            descending,
+        };
+        self.inner.clone().arr().rank(options, seed).into()
+    }
+
+    fn arr_flatten(&self) -> Self {
+        self.inner.clone().arr().flatten().into()
+    }
+
+    fn arr_reshape(&self, dimensions: Vec<i64>) -> Self {
+        self.inner.clone().arr().reshape(&dimensions).into()
+    }
+
     fn arr_arg_min(&self) -> Self {
         self.inner.clone().arr().arg_min().into()
     }
@@ -126,4 +179,43 @@ impl PyExpr {
     fn arr_shift(&self, n: PyExpr) -> Self {
         self.inner.clone().arr().shift(n.inner).into()
     }
+
+    #[cfg(feature = "list_sets")]
+    fn arr_set_operation(&self, other: PyExpr, operation: Wrap<SetOperation>) -> Self {
+        let e = self.inner.clone().arr();
+        match operation.0 {
+            SetOperation::Intersection => e.set_intersection(other.inner),
+            SetOperation::Difference => e.set_difference(other.inner),
+            SetOperation::Union => e.set_union(other.inner),
+            SetOperation::SymmetricDifference => e.set_symmetric_difference(other.inner),
+        }
+        .into()
+    }
+
+    fn arr_zip_with(&self, mask: PyExpr, other: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .zip_with(mask.inner, other.inner)
+            .into()
+    }
+
+    #[cfg(feature = "list_sample")]
+    fn arr_sample_n(
+        &self,
+        n: PyExpr,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .sample_n(n.inner, with_replacement, shuffle, seed)
+            .into()
+    }
+
+    fn arr_eval(&self, expr: PyExpr, parallel: bool) -> Self {
+        self.inner.clone().arr().eval(expr.inner, parallel).into()
+    }
 }
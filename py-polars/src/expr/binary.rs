@@ -1,5 +1,7 @@
+use polars::prelude::*;
 use pyo3::prelude::*;
 
+use crate::conversion::Wrap;
 use crate::PyExpr;
 
 #[pymethods]
@@ -20,6 +22,22 @@ impl PyExpr {
         self.inner.clone().binary().starts_with(sub.inner).into()
     }
 
+    fn bin_reinterpret(&self, dtype: Wrap<DataType>, is_little_endian: bool) -> Self {
+        self.inner
+            .clone()
+            .binary()
+            .reinterpret(dtype.0, is_little_endian)
+            .into()
+    }
+
+    fn bin_digest(&self, algorithm: Wrap<DigestAlgorithm>, as_hex: bool) -> Self {
+        self.inner
+            .clone()
+            .binary()
+            .digest(algorithm.0, as_hex)
+            .into()
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn bin_hex_decode(&self, strict: bool) -> Self {
         self.inner.clone().binary().hex_decode(strict).into()
@@ -39,4 +57,24 @@ impl PyExpr {
     fn bin_base64_encode(&self) -> Self {
         self.inner.clone().binary().base64_encode().into()
     }
+
+    #[cfg(feature = "binary_encoding")]
+    fn bin_base32_decode(&self, strict: bool) -> Self {
+        self.inner.clone().binary().base32_decode(strict).into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn bin_base32_encode(&self) -> Self {
+        self.inner.clone().binary().base32_encode().into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn bin_percent_decode(&self, strict: bool) -> Self {
+        self.inner.clone().binary().percent_decode(strict).into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn bin_percent_encode(&self) -> Self {
+        self.inner.clone().binary().percent_encode().into()
+    }
 }
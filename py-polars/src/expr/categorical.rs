@@ -7,13 +7,22 @@ use crate::PyExpr;
 #[pymethods]
 impl PyExpr {
     fn cat_set_ordering(&self, ordering: Wrap<CategoricalOrdering>) -> Self {
-        self.inner
-            .clone()
-            .cast(DataType::Categorical(None, ordering.0))
-            .into()
+        self.inner.clone().cat().set_ordering(ordering.0).into()
     }
 
     fn cat_get_categories(&self) -> Self {
         self.inner.clone().cat().get_categories().into()
     }
+
+    fn cat_n_categories(&self) -> Self {
+        self.inner.clone().cat().n_categories().into()
+    }
+
+    fn cat_codes(&self) -> Self {
+        self.inner.clone().cat().codes().into()
+    }
+
+    fn cat_to_local(&self) -> Self {
+        self.inner.clone().cat().to_local().into()
+    }
 }
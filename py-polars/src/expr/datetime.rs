@@ -2,6 +2,7 @@ use polars::prelude::*;
 use pyo3::prelude::*;
 
 use crate::conversion::Wrap;
+use crate::functions::holiday_calendar;
 use crate::PyExpr;
 
 #[pymethods]
@@ -12,20 +13,41 @@ impl PyExpr {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
         roll: Wrap<Roll>,
-    ) -> Self {
-        self.inner
+        calendar: Option<&str>,
+    ) -> PyResult<Self> {
+        let calendar = holiday_calendar(week_mask, holidays, calendar)?;
+        Ok(self
+            .inner
             .clone()
             .dt()
-            .add_business_days(n.inner, week_mask, holidays, roll.0)
-            .into()
+            .add_business_days(n.inner, calendar, roll.0)
+            .into())
     }
 
-    fn dt_to_string(&self, format: &str) -> Self {
-        self.inner.clone().dt().to_string(format).into()
+    fn dt_is_business_day(
+        &self,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        calendar: Option<&str>,
+    ) -> PyResult<Self> {
+        let calendar = holiday_calendar(week_mask, holidays, calendar)?;
+        Ok(self.inner.clone().dt().is_business_day(calendar).into())
     }
 
-    fn dt_offset_by(&self, by: PyExpr) -> Self {
-        self.inner.clone().dt().offset_by(by.inner).into()
+    fn dt_to_string(&self, format: &str, locale: Wrap<Locale>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .to_string_localized(format, locale.0)
+            .into()
+    }
+
+    fn dt_offset_by(&self, by: PyExpr, end_of_month: bool) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .offset_by(by.inner, end_of_month)
+            .into()
     }
 
     fn dt_epoch_seconds(&self) -> Self {
@@ -41,6 +63,15 @@ impl PyExpr {
             .into()
     }
 
+    #[pyo3(signature = (time_unit, origin, fractional))]
+    fn dt_epoch(&self, time_unit: String, origin: Option<PyExpr>, fractional: bool) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .epoch(time_unit, origin.map(|e| e.inner), fractional)
+            .into()
+    }
+
     fn dt_with_time_unit(&self, time_unit: Wrap<TimeUnit>) -> Self {
         self.inner.clone().dt().with_time_unit(time_unit.0).into()
     }
@@ -60,17 +91,22 @@ impl PyExpr {
         &self,
         time_zone: Option<String>,
         ambiguous: Self,
-        non_existent: Wrap<NonExistent>,
+        non_existent: Self,
     ) -> Self {
         self.inner
             .clone()
             .dt()
-            .replace_time_zone(time_zone, ambiguous.inner, non_existent.0)
+            .replace_time_zone(time_zone, ambiguous.inner, non_existent.inner)
             .into()
     }
 
-    fn dt_truncate(&self, every: Self, offset: String) -> Self {
-        self.inner.clone().dt().truncate(every.inner, offset).into()
+    #[pyo3(signature = (every, offset, ambiguous))]
+    fn dt_truncate(&self, every: Self, offset: String, ambiguous: Option<Self>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .truncate(every.inner, offset, ambiguous.map(|e| e.inner))
+            .into()
     }
 
     fn dt_month_start(&self) -> Self {
@@ -81,6 +117,22 @@ impl PyExpr {
         self.inner.clone().dt().month_end().into()
     }
 
+    fn dt_quarter_start(&self, fiscal_year_start: u8) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .quarter_start(fiscal_year_start)
+            .into()
+    }
+
+    fn dt_quarter_end(&self, fiscal_year_start: u8) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .quarter_end(fiscal_year_start)
+            .into()
+    }
+
     #[cfg(feature = "timezones")]
     fn dt_base_utc_offset(&self) -> Self {
         self.inner.clone().dt().base_utc_offset().into()
@@ -90,15 +142,26 @@ impl PyExpr {
         self.inner.clone().dt().dst_offset().into()
     }
 
-    fn dt_round(&self, every: Self, offset: &str) -> Self {
-        self.inner.clone().dt().round(every.inner, offset).into()
+    #[pyo3(signature = (every, offset, week_start, ambiguous))]
+    fn dt_round(&self, every: Self, offset: &str, week_start: u8, ambiguous: Option<Self>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .round(every.inner, offset, week_start, ambiguous.map(|e| e.inner))
+            .into()
     }
 
-    fn dt_combine(&self, time: Self, time_unit: Wrap<TimeUnit>) -> Self {
+    #[pyo3(signature = (time, time_unit, time_zone))]
+    fn dt_combine(
+        &self,
+        time: Self,
+        time_unit: Wrap<TimeUnit>,
+        time_zone: Option<TimeZone>,
+    ) -> Self {
         self.inner
             .clone()
             .dt()
-            .combine(time.inner, time_unit.0)
+            .combine(time.inner, time_unit.0, time_zone)
             .into()
     }
     fn dt_millennium(&self) -> Self {
@@ -113,11 +176,45 @@ impl PyExpr {
     fn dt_is_leap_year(&self) -> Self {
         self.inner.clone().dt().is_leap_year().into()
     }
+    fn dt_is_month_start(&self) -> Self {
+        self.inner.clone().dt().is_month_start().into()
+    }
+    fn dt_is_month_end(&self) -> Self {
+        self.inner.clone().dt().is_month_end().into()
+    }
+    fn dt_is_quarter_start(&self, fiscal_year_start: u8) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .is_quarter_start(fiscal_year_start)
+            .into()
+    }
+    fn dt_is_quarter_end(&self, fiscal_year_start: u8) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .is_quarter_end(fiscal_year_start)
+            .into()
+    }
+    fn dt_is_year_start(&self, fiscal_year_start: u8) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .is_year_start(fiscal_year_start)
+            .into()
+    }
+    fn dt_is_year_end(&self, fiscal_year_start: u8) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .is_year_end(fiscal_year_start)
+            .into()
+    }
     fn dt_iso_year(&self) -> Self {
         self.inner.clone().dt().iso_year().into()
     }
-    fn dt_quarter(&self) -> Self {
-        self.inner.clone().dt().quarter().into()
+    fn dt_quarter(&self, fiscal_year_start: u8) -> Self {
+        self.inner.clone().dt().quarter(fiscal_year_start).into()
     }
     fn dt_month(&self) -> Self {
         self.inner.clone().dt().month().into()
@@ -164,25 +261,44 @@ impl PyExpr {
     fn dt_timestamp(&self, time_unit: Wrap<TimeUnit>) -> Self {
         self.inner.clone().dt().timestamp(time_unit.0).into()
     }
-    fn dt_total_days(&self) -> Self {
-        self.inner.clone().dt().total_days().into()
+    fn dt_total_days(&self, fractional: bool) -> Self {
+        self.inner.clone().dt().total_days(fractional).into()
     }
-    fn dt_total_hours(&self) -> Self {
-        self.inner.clone().dt().total_hours().into()
+    fn dt_total_hours(&self, fractional: bool) -> Self {
+        self.inner.clone().dt().total_hours(fractional).into()
     }
-    fn dt_total_minutes(&self) -> Self {
-        self.inner.clone().dt().total_minutes().into()
+    fn dt_total_minutes(&self, fractional: bool) -> Self {
+        self.inner.clone().dt().total_minutes(fractional).into()
     }
-    fn dt_total_seconds(&self) -> Self {
-        self.inner.clone().dt().total_seconds().into()
+    fn dt_total_seconds(&self, fractional: bool) -> Self {
+        self.inner.clone().dt().total_seconds(fractional).into()
+    }
+    fn dt_total_milliseconds(&self, fractional: bool) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .total_milliseconds(fractional)
+            .into()
     }
-    fn dt_total_milliseconds(&self) -> Self {
-        self.inner.clone().dt().total_milliseconds().into()
+    fn dt_total_microseconds(&self, fractional: bool) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .total_microseconds(fractional)
+            .into()
     }
-    fn dt_total_microseconds(&self) -> Self {
-        self.inner.clone().dt().total_microseconds().into()
+    fn dt_total_nanoseconds(&self, fractional: bool) -> Self {
+        self.inner.clone().dt().total_nanoseconds(fractional).into()
     }
-    fn dt_total_nanoseconds(&self) -> Self {
-        self.inner.clone().dt().total_nanoseconds().into()
+    fn dt_duration_to_string(
+        &self,
+        format: Wrap<DurationFormat>,
+        precision: Option<Wrap<DurationPrecision>>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .duration_to_string(format.0, precision.map(|p| p.0))
+            .into()
     }
 }
@@ -696,6 +696,26 @@ impl PyExpr {
         self.inner.clone().xor(expr.inner).into()
     }
 
+    fn shift_left(&self, n: Self) -> Self {
+        self.inner.clone().shift_left(n.inner).into()
+    }
+
+    fn shift_right(&self, n: Self) -> Self {
+        self.inner.clone().shift_right(n.inner).into()
+    }
+
+    fn bitwise_and(&self) -> Self {
+        self.inner.clone().bitwise_and().into()
+    }
+
+    fn bitwise_or(&self) -> Self {
+        self.inner.clone().bitwise_or().into()
+    }
+
+    fn bitwise_xor(&self) -> Self {
+        self.inner.clone().bitwise_xor().into()
+    }
+
     #[cfg(feature = "is_in")]
     fn is_in(&self, expr: Self) -> Self {
         self.inner.clone().is_in(expr.inner).into()
@@ -767,6 +787,10 @@ impl PyExpr {
     fn reinterpret(&self, signed: bool) -> Self {
         self.inner.clone().reinterpret(signed).into()
     }
+
+    fn to_binary(&self, is_little_endian: bool) -> Self {
+        self.inner.clone().to_binary(is_little_endian).into()
+    }
     fn mode(&self) -> Self {
         self.inner.clone().mode().into()
     }
@@ -932,6 +956,9 @@ impl PyExpr {
     fn hash(&self, seed: u64, seed_1: u64, seed_2: u64, seed_3: u64) -> Self {
         self.inner.clone().hash(seed, seed_1, seed_2, seed_3).into()
     }
+    fn hash_stable(&self, seed: u64) -> Self {
+        self.inner.clone().hash_stable(seed).into()
+    }
     fn set_sorted_flag(&self, descending: bool) -> Self {
         let is_sorted = if descending {
             IsSorted::Descending
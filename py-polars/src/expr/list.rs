@@ -38,6 +38,10 @@ impl PyExpr {
         self.inner.clone().list().count_matches(expr.inner).into()
     }
 
+    fn list_index_of(&self, value: PyExpr) -> Self {
+        self.inner.clone().list().index_of(value.inner).into()
+    }
+
     fn list_diff(&self, n: i64, null_behavior: Wrap<NullBehavior>) -> PyResult<Self> {
         Ok(self.inner.clone().list().diff(n, null_behavior.0).into())
     }
@@ -46,6 +50,10 @@ impl PyExpr {
         self.inner.clone().list().eval(expr.inner, parallel).into()
     }
 
+    fn list_filter(&self, predicate: PyExpr) -> Self {
+        self.inner.clone().list().filter(predicate.inner).into()
+    }
+
     fn list_get(&self, index: PyExpr, null_on_oob: bool) -> Self {
         self.inner
             .clone()
@@ -54,11 +62,16 @@ impl PyExpr {
             .into()
     }
 
-    fn list_join(&self, separator: PyExpr, ignore_nulls: bool) -> Self {
+    fn list_join(
+        &self,
+        separator: PyExpr,
+        ignore_nulls: bool,
+        null_replacement: Option<&str>,
+    ) -> Self {
         self.inner
             .clone()
             .list()
-            .join(separator.inner, ignore_nulls)
+            .join(separator.inner, ignore_nulls, null_replacement)
             .into()
     }
 
@@ -110,14 +123,72 @@ impl PyExpr {
         self.inner.clone().list().min().into()
     }
 
+    #[cfg(feature = "top_k")]
+    fn list_top_k(&self, k: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .top_k(k.inner, SortOptions::default().with_multithreaded(false))
+            .into()
+    }
+
+    #[cfg(feature = "top_k")]
+    fn list_bottom_k(&self, k: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .bottom_k(k.inner, SortOptions::default().with_multithreaded(false))
+            .into()
+    }
+
     fn list_reverse(&self) -> Self {
         self.inner.clone().list().reverse().into()
     }
 
+    fn list_rolling_sum(&self, window_size: usize, min_periods: usize, center: bool) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling_sum(window_size, min_periods, center)
+            .into()
+    }
+
+    fn list_rolling_mean(&self, window_size: usize, min_periods: usize, center: bool) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling_mean(window_size, min_periods, center)
+            .into()
+    }
+
+    fn list_rolling_min(&self, window_size: usize, min_periods: usize, center: bool) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling_min(window_size, min_periods, center)
+            .into()
+    }
+
+    fn list_rolling_max(&self, window_size: usize, min_periods: usize, center: bool) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling_max(window_size, min_periods, center)
+            .into()
+    }
+
     fn list_shift(&self, periods: PyExpr) -> Self {
         self.inner.clone().list().shift(periods.inner).into()
     }
 
+    fn list_pad_end(&self, length: PyExpr, fill_value: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .pad_end(length.inner, fill_value.inner)
+            .into()
+    }
+
     fn list_slice(&self, offset: PyExpr, length: Option<PyExpr>) -> Self {
         let length = match length {
             Some(i) => i.inner,
@@ -182,6 +253,22 @@ impl PyExpr {
     }
 
     #[cfg(feature = "list_gather")]
+    fn list_scatter(&self, indices: PyExpr, values: PyExpr, ignore_oob: bool) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .scatter(indices.inner, values.inner, ignore_oob)
+            .into()
+    }
+
+    fn list_zip_with(&self, mask: PyExpr, other: PyExpr, null_on_mismatch: bool) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .zip_with(mask.inner, other.inner, null_on_mismatch)
+            .into()
+    }
+
     fn list_gather(&self, index: PyExpr, null_on_oob: bool) -> Self {
         self.inner
             .clone()
@@ -199,8 +286,28 @@ impl PyExpr {
             .into()
     }
 
-    fn list_to_array(&self, width: usize) -> Self {
-        self.inner.clone().list().to_array(width).into()
+    fn list_to_array(&self, width: usize, null_on_mismatch: bool) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .to_array_with_options(width, null_on_mismatch)
+            .into()
+    }
+
+    fn list_struct_field(&self, name: &str) -> Self {
+        self.inner.clone().list().struct_field(name).into()
+    }
+
+    fn list_chunk(&self, size: usize) -> Self {
+        self.inner.clone().list().chunk(size).into()
+    }
+
+    fn list_value_counts(&self, sort: bool, normalize: bool) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .value_counts(sort, normalize)
+            .into()
     }
 
     #[pyo3(signature = (width_strat, name_gen, upper_bound))]
@@ -232,6 +339,10 @@ impl PyExpr {
         self.inner.clone().list().n_unique().into()
     }
 
+    fn list_dedupe(&self) -> Self {
+        self.inner.clone().list().dedupe().into()
+    }
+
     fn list_unique(&self, maintain_order: bool) -> Self {
         let e = self.inner.clone();
 
@@ -122,7 +122,34 @@ impl PyExpr {
         self.inner.clone().str().to_lowercase().into()
     }
 
-    #[cfg(feature = "nightly")]
+    fn str_casefold(&self) -> Self {
+        self.inner.clone().str().casefold().into()
+    }
+
+    fn str_caseless_eq(&self, other: Self) -> Self {
+        self.inner.clone().str().caseless_eq(other.inner).into()
+    }
+
+    fn str_caseless_contains(&self, pat: Self) -> Self {
+        self.inner.clone().str().caseless_contains(pat.inner).into()
+    }
+
+    fn str_caseless_starts_with(&self, prefix: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .caseless_starts_with(prefix.inner)
+            .into()
+    }
+
+    fn str_caseless_ends_with(&self, suffix: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .caseless_ends_with(suffix.inner)
+            .into()
+    }
+
     fn str_to_titlecase(&self) -> Self {
         self.inner.clone().str().to_titlecase().into()
     }
@@ -153,16 +180,57 @@ impl PyExpr {
             .into()
     }
 
+    fn str_normalize(&self, form: Wrap<UnicodeForm>) -> Self {
+        self.inner.clone().str().normalize(form.0).into()
+    }
+
+    fn str_digest(&self, algorithm: Wrap<DigestAlgorithm>, as_hex: bool) -> Self {
+        self.inner.clone().str().digest(algorithm.0, as_hex).into()
+    }
+
+    #[cfg(feature = "regex")]
+    fn str_escape_regex(&self) -> Self {
+        self.inner.clone().str().escape_regex().into()
+    }
+
     fn str_reverse(&self) -> Self {
         self.inner.clone().str().reverse().into()
     }
 
-    fn str_pad_start(&self, length: usize, fill_char: char) -> Self {
-        self.inner.clone().str().pad_start(length, fill_char).into()
+    fn str_levenshtein(&self, other: Self) -> Self {
+        self.inner.clone().str().levenshtein(other.inner).into()
+    }
+
+    fn str_hamming(&self, other: Self) -> Self {
+        self.inner.clone().str().hamming(other.inner).into()
+    }
+
+    fn str_jaro_winkler(&self, other: Self) -> Self {
+        self.inner.clone().str().jaro_winkler(other.inner).into()
+    }
+
+    fn str_pad_start(&self, length: Self, fill_char: char, display_width: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .pad_start(length.inner, fill_char, display_width)
+            .into()
+    }
+
+    fn str_pad_end(&self, length: Self, fill_char: char, display_width: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .pad_end(length.inner, fill_char, display_width)
+            .into()
     }
 
-    fn str_pad_end(&self, length: usize, fill_char: char) -> Self {
-        self.inner.clone().str().pad_end(length, fill_char).into()
+    fn str_wrap(&self, width: usize, break_long_words: bool, tab_size: usize) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .wrap(width, break_long_words, tab_size)
+            .into()
     }
 
     fn str_zfill(&self, length: Self) -> Self {
@@ -178,12 +246,22 @@ impl PyExpr {
         }
     }
 
-    #[pyo3(signature = (pat, literal, strict))]
+    #[pyo3(signature = (pat, literal, strict, char_index))]
     #[cfg(feature = "regex")]
-    fn str_find(&self, pat: Self, literal: Option<bool>, strict: bool) -> Self {
+    fn str_find(&self, pat: Self, literal: Option<bool>, strict: bool, char_index: bool) -> Self {
         match literal {
-            Some(true) => self.inner.clone().str().find_literal(pat.inner).into(),
-            _ => self.inner.clone().str().find(pat.inner, strict).into(),
+            Some(true) => self
+                .inner
+                .clone()
+                .str()
+                .find_literal(pat.inner, char_index)
+                .into(),
+            _ => self
+                .inner
+                .clone()
+                .str()
+                .find(pat.inner, strict, char_index)
+                .into(),
         }
     }
 
@@ -213,6 +291,24 @@ impl PyExpr {
         self.inner.clone().str().base64_decode(strict).into()
     }
 
+    fn str_base32_encode(&self) -> Self {
+        self.inner.clone().str().base32_encode().into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn str_base32_decode(&self, strict: bool) -> Self {
+        self.inner.clone().str().base32_decode(strict).into()
+    }
+
+    fn str_percent_encode(&self) -> Self {
+        self.inner.clone().str().percent_encode().into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn str_percent_decode(&self, strict: bool) -> Self {
+        self.inner.clone().str().percent_decode(strict).into()
+    }
+
     fn str_to_integer(&self, base: Self, strict: bool) -> Self {
         self.inner
             .clone()
@@ -222,17 +318,19 @@ impl PyExpr {
             .into()
     }
 
+    #[pyo3(signature = (dtype, infer_schema_len, strict))]
     #[cfg(feature = "extract_jsonpath")]
     fn str_json_decode(
         &self,
         dtype: Option<Wrap<DataType>>,
         infer_schema_len: Option<usize>,
+        strict: bool,
     ) -> Self {
         let dtype = dtype.map(|wrap| wrap.0);
         self.inner
             .clone()
             .str()
-            .json_decode(dtype, infer_schema_len)
+            .json_decode(dtype, infer_schema_len, strict)
             .into()
     }
 
@@ -296,6 +394,14 @@ impl PyExpr {
         self.inner.clone().str().splitn(by.inner, n).into()
     }
 
+    fn str_to_utc_offset(&self, format: String, strict: bool, cache: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .to_utc_offset(format, strict, cache)
+            .into()
+    }
+
     fn str_to_decimal(&self, infer_len: usize) -> Self {
         self.inner.clone().str().to_decimal(infer_len).into()
     }
@@ -321,4 +427,18 @@ impl PyExpr {
             .replace_many(patterns.inner, replace_with.inner, ascii_case_insensitive)
             .into()
     }
+
+    #[cfg(feature = "find_many")]
+    fn str_extract_many(
+        &self,
+        patterns: PyExpr,
+        ascii_case_insensitive: bool,
+        overlapping: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .extract_many(patterns.inner, ascii_case_insensitive, overlapping)
+            .into()
+    }
 }
@@ -1,5 +1,6 @@
 use pyo3::prelude::*;
 
+use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 use crate::PyExpr;
 
@@ -17,10 +18,52 @@ impl PyExpr {
         self.inner.clone().struct_().field_by_names(&names).into()
     }
 
+    fn struct_field_by_path(&self, path: &str) -> PyResult<Self> {
+        Ok(self
+            .inner
+            .clone()
+            .struct_()
+            .field_by_path(path)
+            .map_err(PyPolarsErr::from)?
+            .into())
+    }
+
+    fn struct_select_fields(&self, names: Vec<String>) -> Self {
+        self.inner.clone().struct_().select_fields(&names).into()
+    }
+
+    fn struct_drop_fields(&self, names: Vec<String>) -> Self {
+        self.inner.clone().struct_().drop_fields(&names).into()
+    }
+
+    fn struct_sum_fields(&self) -> Self {
+        self.inner.clone().struct_().sum_fields().into()
+    }
+
+    fn struct_mean_fields(&self) -> Self {
+        self.inner.clone().struct_().mean_fields().into()
+    }
+
+    fn struct_min_fields(&self) -> Self {
+        self.inner.clone().struct_().min_fields().into()
+    }
+
+    fn struct_max_fields(&self) -> Self {
+        self.inner.clone().struct_().max_fields().into()
+    }
+
     fn struct_rename_fields(&self, names: Vec<String>) -> Self {
         self.inner.clone().struct_().rename_fields(names).into()
     }
 
+    fn struct_rename_fields_by_mapping(&self, mapping: Vec<(String, String)>) -> Self {
+        self.inner
+            .clone()
+            .struct_()
+            .rename_fields_by_mapping(mapping)
+            .into()
+    }
+
     fn struct_json_encode(&self) -> Self {
         self.inner.clone().struct_().json_encode().into()
     }
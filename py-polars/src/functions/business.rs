@@ -1,6 +1,8 @@
 use polars::lazy::dsl;
+use polars::time::HolidayCalendar;
 use pyo3::prelude::*;
 
+use crate::error::PyPolarsErr;
 use crate::PyExpr;
 
 #[pyfunction]
@@ -9,8 +11,45 @@ pub fn business_day_count(
     end: PyExpr,
     week_mask: [bool; 7],
     holidays: Vec<i32>,
-) -> PyExpr {
+    calendar: Option<&str>,
+) -> PyResult<PyExpr> {
     let start = start.inner;
     let end = end.inner;
-    dsl::business_day_count(start, end, week_mask, holidays).into()
+    let calendar = holiday_calendar(week_mask, holidays, calendar)?;
+    Ok(dsl::business_day_count(start, end, calendar).into())
+}
+
+/// Look up the registered calendar `name`, or build one from the week mask
+/// and holidays if no name is given.
+pub(crate) fn holiday_calendar(
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+    name: Option<&str>,
+) -> PyResult<HolidayCalendar> {
+    let calendar = match name {
+        Some(name) => polars::time::get_holiday_calendar(name),
+        None => HolidayCalendar::new(week_mask, holidays),
+    };
+    Ok(calendar.map_err(PyPolarsErr::from)?)
+}
+
+#[pyfunction]
+pub fn register_holiday_calendar(
+    name: &str,
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+) -> PyResult<()> {
+    let calendar = HolidayCalendar::new(week_mask, holidays).map_err(PyPolarsErr::from)?;
+    polars::time::register_holiday_calendar(name, calendar);
+    Ok(())
+}
+
+#[pyfunction]
+pub fn unregister_holiday_calendar(name: &str) -> bool {
+    polars::time::unregister_holiday_calendar(name)
+}
+
+#[pyfunction]
+pub fn holiday_calendar_names() -> Vec<String> {
+    polars::time::holiday_calendar_names()
 }
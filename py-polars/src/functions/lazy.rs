@@ -378,6 +378,11 @@ pub fn duration(
     dsl::duration(args).into()
 }
 
+#[pyfunction]
+pub fn field(names: Vec<String>) -> PyExpr {
+    dsl::field(&names).into()
+}
+
 #[pyfunction]
 pub fn first() -> PyExpr {
     dsl::first().into()
@@ -257,7 +257,8 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (path, paths, n_rows, cache, parallel, rechunk, row_index,
-        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, retries, glob)
+        low_memory, cloud_options, credential_provider, use_statistics, hive_partitioning,
+        hive_schema, retries, glob)
     )]
     fn new_from_parquet(
         path: Option<PathBuf>,
@@ -269,6 +270,7 @@ impl PyLazyFrame {
         row_index: Option<(String, IdxSize)>,
         low_memory: bool,
         cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
         use_statistics: bool,
         hive_partitioning: bool,
         hive_schema: Option<Wrap<Schema>>,
@@ -299,6 +301,11 @@ impl PyLazyFrame {
                         options
                     });
         }
+        if let Some(provider) = credential_provider {
+            let options = cloud_options.unwrap_or_default();
+            cloud_options =
+                Some(options.with_credential_provider(parse_credential_provider(provider)));
+        }
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: Arc::from(name.as_str()),
             offset,
@@ -1193,8 +1200,55 @@ impl PyLazyFrame {
         Ok(schema_dict.to_object(py))
     }
 
-    fn unnest(&self, columns: Vec<String>) -> Self {
-        self.ldf.clone().unnest(columns).into()
+    fn unnest(
+        &self,
+        columns: Vec<String>,
+        naming: &str,
+        separator: &str,
+        name_mapper: Option<PyObject>,
+    ) -> PyResult<Self> {
+        let naming = match (naming, name_mapper) {
+            (_, Some(name_mapper)) => {
+                UnnestNaming::Function(Arc::new(move |column: &str, field: &str| {
+                    Python::with_gil(|py| {
+                        name_mapper
+                            .call1(py, (column, field))
+                            .and_then(|out| out.extract::<String>(py))
+                            .map_err(|e| {
+                                polars_err!(
+                                    ComputeError: "Python function in 'unnest' produced an error: {e}."
+                                )
+                            })
+                    })
+                }))
+            },
+            ("field", None) => UnnestNaming::Fields,
+            ("prefix", None) => UnnestNaming::Prefix(Arc::from(separator)),
+            ("suffix", None) => UnnestNaming::Suffix(Arc::from(separator)),
+            (v, None) => {
+                return Err(PyValueError::new_err(format!(
+                    "`naming` must be one of {{'field', 'prefix', 'suffix'}}, got {v}",
+                )))
+            },
+        };
+        Ok(self.ldf.clone().unnest_with(columns, naming).into())
+    }
+
+    fn upsample(
+        &self,
+        time_column: &str,
+        every: &str,
+        group_by: Vec<String>,
+        maintain_order: bool,
+    ) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.upsample(
+            time_column,
+            Duration::parse(every),
+            group_by,
+            maintain_order,
+        )
+        .into()
     }
 
     fn width(&self) -> PyResult<usize> {
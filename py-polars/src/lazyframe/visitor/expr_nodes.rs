@@ -66,6 +66,7 @@ pub enum PyStringFunction {
     Contains,
     CountMatches,
     EndsWith,
+    EscapeRegex,
     Explode,
     Extract,
     ExtractAll,
@@ -75,10 +76,15 @@ pub enum PyStringFunction {
     LenBytes,
     LenChars,
     Lowercase,
+    Casefold,
     JsonDecode,
     JsonPathMatch,
     Replace,
+    Normalize,
     Reverse,
+    Levenshtein,
+    Hamming,
+    JaroWinkler,
     PadStart,
     PadEnd,
     Slice,
@@ -88,6 +94,10 @@ pub enum PyStringFunction {
     HexDecode,
     Base64Encode,
     Base64Decode,
+    Base32Encode,
+    Base32Decode,
+    PercentEncode,
+    PercentDecode,
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -99,11 +109,14 @@ pub enum PyStringFunction {
     Strptime,
     Split,
     ToDecimal,
+    ToUtcOffset,
     Titlecase,
     Uppercase,
+    Wrap,
     ZFill,
     ContainsMany,
     ReplaceMany,
+    ExtractMany,
 }
 
 #[pymethods]
@@ -646,6 +659,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::EndsWith => {
                         (PyStringFunction::EndsWith.into_py(py),).to_object(py)
                     },
+                    StringFunction::EscapeRegex => {
+                        (PyStringFunction::EscapeRegex.into_py(py),).to_object(py)
+                    },
                     StringFunction::Explode => {
                         (PyStringFunction::Explode.into_py(py),).to_object(py)
                     },
@@ -661,9 +677,17 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         pat,
                     )
                         .to_object(py),
-                    StringFunction::Find { literal, strict } => {
-                        (PyStringFunction::Find.into_py(py), literal, strict).to_object(py)
-                    },
+                    StringFunction::Find {
+                        literal,
+                        strict,
+                        char_index,
+                    } => (
+                        PyStringFunction::Find.into_py(py),
+                        literal,
+                        strict,
+                        char_index,
+                    )
+                        .to_object(py),
                     StringFunction::ToInteger(_) => {
                         (PyStringFunction::ToInteger.into_py(py),).to_object(py)
                     },
@@ -676,25 +700,58 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::Lowercase => {
                         (PyStringFunction::Lowercase.into_py(py),).to_object(py)
                     },
+                    StringFunction::Casefold => {
+                        (PyStringFunction::Casefold.into_py(py),).to_object(py)
+                    },
                     StringFunction::JsonDecode {
                         dtype: _,
                         infer_schema_len,
-                    } => (PyStringFunction::JsonDecode.into_py(py), infer_schema_len).to_object(py),
+                        strict,
+                    } => (
+                        PyStringFunction::JsonDecode.into_py(py),
+                        infer_schema_len,
+                        strict,
+                    )
+                        .to_object(py),
                     StringFunction::JsonPathMatch => {
                         (PyStringFunction::JsonPathMatch.into_py(py),).to_object(py)
                     },
                     StringFunction::Replace { n, literal } => {
                         (PyStringFunction::Replace.into_py(py), n, literal).to_object(py)
                     },
+                    StringFunction::Normalize { form } => {
+                        (PyStringFunction::Normalize.into_py(py), format!("{form:?}")).to_object(py)
+                    },
                     StringFunction::Reverse => {
                         (PyStringFunction::Reverse.into_py(py),).to_object(py)
                     },
-                    StringFunction::PadStart { length, fill_char } => {
-                        (PyStringFunction::PadStart.into_py(py), length, fill_char).to_object(py)
+                    StringFunction::Levenshtein => {
+                        (PyStringFunction::Levenshtein.into_py(py),).to_object(py)
+                    },
+                    StringFunction::Hamming => {
+                        (PyStringFunction::Hamming.into_py(py),).to_object(py)
                     },
-                    StringFunction::PadEnd { length, fill_char } => {
-                        (PyStringFunction::PadEnd.into_py(py), length, fill_char).to_object(py)
+                    StringFunction::JaroWinkler => {
+                        (PyStringFunction::JaroWinkler.into_py(py),).to_object(py)
                     },
+                    StringFunction::PadStart {
+                        fill_char,
+                        display_width,
+                    } => (
+                        PyStringFunction::PadStart.into_py(py),
+                        fill_char,
+                        display_width,
+                    )
+                        .to_object(py),
+                    StringFunction::PadEnd {
+                        fill_char,
+                        display_width,
+                    } => (
+                        PyStringFunction::PadEnd.into_py(py),
+                        fill_char,
+                        display_width,
+                    )
+                        .to_object(py),
                     StringFunction::Slice => (PyStringFunction::Slice.into_py(py),).to_object(py),
                     StringFunction::Head => (PyStringFunction::Head.into_py(py),).to_object(py),
                     StringFunction::Tail => (PyStringFunction::Tail.into_py(py),).to_object(py),
@@ -710,6 +767,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::Base64Decode(_) => {
                         (PyStringFunction::Base64Decode.into_py(py),).to_object(py)
                     },
+                    StringFunction::Base32Encode => {
+                        (PyStringFunction::Base32Encode.into_py(py),).to_object(py)
+                    },
+                    StringFunction::Base32Decode(_) => {
+                        (PyStringFunction::Base32Decode.into_py(py),).to_object(py)
+                    },
+                    StringFunction::PercentEncode => {
+                        (PyStringFunction::PercentEncode.into_py(py),).to_object(py)
+                    },
+                    StringFunction::PercentDecode(_) => {
+                        (PyStringFunction::PercentDecode.into_py(py),).to_object(py)
+                    },
                     StringFunction::StartsWith => {
                         (PyStringFunction::StartsWith.into_py(py),).to_object(py)
                     },
@@ -743,12 +812,34 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::ToDecimal(_) => {
                         (PyStringFunction::ToDecimal.into_py(py),).to_object(py)
                     },
+                    StringFunction::ToUtcOffset {
+                        format,
+                        strict,
+                        cache,
+                    } => (
+                        PyStringFunction::ToUtcOffset.into_py(py),
+                        format,
+                        strict,
+                        cache,
+                    )
+                        .to_object(py),
                     StringFunction::Titlecase => {
                         (PyStringFunction::Titlecase.into_py(py),).to_object(py)
                     },
                     StringFunction::Uppercase => {
                         (PyStringFunction::Uppercase.into_py(py),).to_object(py)
                     },
+                    StringFunction::Wrap {
+                        width,
+                        break_long_words,
+                        tab_size,
+                    } => (
+                        PyStringFunction::Wrap.into_py(py),
+                        width,
+                        break_long_words,
+                        tab_size,
+                    )
+                        .to_object(py),
                     StringFunction::ZFill => (PyStringFunction::ZFill.into_py(py),).to_object(py),
                     StringFunction::ContainsMany {
                         ascii_case_insensitive,
@@ -764,6 +855,15 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         ascii_case_insensitive,
                     )
                         .to_object(py),
+                    StringFunction::ExtractMany {
+                        ascii_case_insensitive,
+                        overlapping,
+                    } => (
+                        PyStringFunction::ExtractMany.into_py(py),
+                        ascii_case_insensitive,
+                        overlapping,
+                    )
+                        .to_object(py),
                 },
                 FunctionExpr::StructExpr(_) => {
                     return Err(PyNotImplementedError::new_err("struct expr"))
@@ -787,12 +887,15 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Hash(_, _, _, _) => {
                     return Err(PyNotImplementedError::new_err("hash"))
                 },
+                FunctionExpr::HashStable(_) => {
+                    return Err(PyNotImplementedError::new_err("hash_stable"))
+                },
                 FunctionExpr::ArgWhere => ("argwhere",).to_object(py),
                 FunctionExpr::SearchSorted(_) => {
                     return Err(PyNotImplementedError::new_err("search sorted"))
                 },
                 FunctionExpr::Range(_) => return Err(PyNotImplementedError::new_err("range")),
-                FunctionExpr::DateOffset => {
+                FunctionExpr::DateOffset { .. } => {
                     return Err(PyNotImplementedError::new_err("date offset"))
                 },
                 FunctionExpr::Trigonometry(trigfun) => match trigfun {
@@ -1011,9 +1114,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Reinterpret(_) => {
                     return Err(PyNotImplementedError::new_err("reinterpret"))
                 },
+                FunctionExpr::ToBinary(_) => {
+                    return Err(PyNotImplementedError::new_err("to binary"))
+                },
                 FunctionExpr::ExtendConstant => {
                     return Err(PyNotImplementedError::new_err("extend constant"))
                 },
+                FunctionExpr::Bitwise(_) => return Err(PyNotImplementedError::new_err("bitwise")),
                 FunctionExpr::Business(_) => {
                     return Err(PyNotImplementedError::new_err("business"))
                 },
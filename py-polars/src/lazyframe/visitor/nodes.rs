@@ -1,7 +1,7 @@
 use polars_core::prelude::{IdxSize, UniqueKeepStrategy};
 use polars_ops::prelude::JoinType;
 use polars_plan::logical_plan::IR;
-use polars_plan::prelude::{FileCount, FileScan, FileScanOptions, FunctionNode};
+use polars_plan::prelude::{FileCount, FileScan, FileScanOptions, FunctionNode, UnnestNaming};
 use pyo3::exceptions::PyNotImplementedError;
 use pyo3::prelude::*;
 
@@ -487,11 +487,17 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     schema: _,
                     original: _,
                 } => return Err(PyNotImplementedError::new_err("pipeline mapfunction")),
-                FunctionNode::Unnest { columns } => (
+                FunctionNode::Unnest {
+                    columns,
+                    naming: UnnestNaming::Fields,
+                } => (
                     "unnest",
                     columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
                 )
                     .to_object(py),
+                FunctionNode::Unnest { .. } => {
+                    return Err(PyNotImplementedError::new_err("unnest with naming"))
+                },
                 FunctionNode::Rechunk => ("rechunk",).to_object(py),
                 FunctionNode::MergeSorted { column } => {
                     ("merge_sorted", column.to_string()).to_object(py)
@@ -533,6 +539,20 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     schema: _,
                     offset,
                 } => ("row_index", name.to_string(), offset.unwrap_or(0)).to_object(py),
+                FunctionNode::Upsample {
+                    time_column,
+                    every,
+                    group_by,
+                    maintain_order,
+                    schema: _,
+                } => (
+                    "upsample",
+                    time_column.to_string(),
+                    every.to_string(),
+                    group_by.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+                    *maintain_order,
+                )
+                    .to_object(py),
                 FunctionNode::Count {
                     paths: _,
                     scan_type: _,
@@ -167,6 +167,12 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - business
     m.add_wrapped(wrap_pyfunction!(functions::business_day_count))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::register_holiday_calendar))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::unregister_holiday_calendar))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::holiday_calendar_names))
+        .unwrap();
 
     // Functions - aggregation
     m.add_wrapped(wrap_pyfunction!(functions::all_horizontal))
@@ -228,6 +234,7 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::duration))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::field)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::first)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::fold)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::last)).unwrap();
@@ -1463,6 +1463,40 @@ def test_reproducible_hash_with_seeds() -> None:
         assert_series_equal(expected, result, check_names=False, check_exact=True)
 
 
+def test_hash_stable() -> None:
+    # Reference values of XXH64, these must never change.
+    s = pl.Series("s", ["", "abc", None])
+    expected = pl.Series("s", [0xEF46DB3751D8E999, 0x44BC2CF5AD770999, None], pl.UInt64)
+    assert_series_equal(s.hash_stable(), expected)
+    assert_series_equal(pl.select(pl.lit(s).hash_stable()).to_series(), expected)
+
+    s = pl.Series("s", [1, 2**40, None])
+    expected = pl.Series("s", [4957705028731227720, 4045505703158817335, None])
+    assert_series_equal(s.hash_stable(seed=7), expected.cast(pl.UInt64))
+
+
+def test_hash_stable_dtypes() -> None:
+    def hashes(s: pl.Series) -> list[int | None]:
+        return s.hash_stable().to_list()
+
+    s = pl.Series(["a", "b", None])
+    assert hashes(s.cast(pl.Categorical)) == hashes(s)
+    assert hashes(s.cast(pl.Enum(["b", "a"]))) == hashes(s)
+    assert hashes(s.cast(pl.Binary)) == hashes(s)
+
+    floats = hashes(pl.Series([0.0, -0.0, float("nan"), -float("nan")]))
+    assert floats[0] == floats[1]
+    assert floats[2] == floats[3]
+
+    assert hashes(pl.Series([date(2024, 1, 1)])) == hashes(
+        pl.Series([19723], dtype=pl.Int32)
+    )
+    assert hashes(pl.Series([True, False])) == hashes(pl.Series([1, 0], dtype=pl.UInt8))
+
+    with pytest.raises(pl.InvalidOperationError, match="hash_stable"):
+        pl.Series([[1]]).hash_stable()
+
+
 @pytest.mark.slow()
 @pytest.mark.parametrize(
     "e",
@@ -3,6 +3,7 @@ from __future__ import annotations
 import contextlib
 import io
 import operator
+import warnings
 from typing import TYPE_CHECKING, Any, Callable, Literal
 
 import pytest
@@ -509,7 +510,6 @@ def test_categorical_sort_order_by_parameter(
 
 
 @StringCache()
-@pytest.mark.filterwarnings("ignore:`set_ordering` is deprecated:DeprecationWarning")
 def test_categorical_sort_order(monkeypatch: Any) -> None:
     # create the categorical ordering first
     pl.Series(["foo", "bar", "baz"], dtype=pl.Categorical)
@@ -535,6 +535,53 @@ def test_categorical_sort_order(monkeypatch: Any) -> None:
     )["x"].to_list() == ["bar", "baz", "foo"]
 
 
+@pytest.mark.parametrize("use_string_cache", [False, True])
+def test_categorical_set_ordering(use_string_cache: bool) -> None:
+    with pl.StringCache() if use_string_cache else contextlib.nullcontext():
+        pl.Series(["z", "c", "b", "a"], dtype=pl.Categorical)
+        df = pl.DataFrame(
+            {"g": [1, 1, 2, 2, 3], "x": ["b", "a", "z", "c", None]},
+            schema_overrides={"x": pl.Categorical},
+        )
+        lf = df.lazy().with_columns(pl.col("x").cat.set_ordering("lexical"))
+        assert lf.schema["x"] == pl.Categorical("lexical")
+
+        result = lf.collect()
+        assert result["x"].cat.get_categories().to_list() == ["b", "a", "z", "c"]
+        assert result.sort("x")["x"].to_list() == [None, "a", "b", "c", "z"]
+        assert result.filter(pl.col("x") < "c")["x"].to_list() == ["b", "a"]
+        assert result["x"].min() == "a"
+        assert result["x"].max() == "z"
+
+        out = lf.group_by("g", maintain_order=True).agg(
+            min=pl.col("x").min(), max=pl.col("x").max()
+        )
+        expected = pl.DataFrame(
+            {"g": [1, 2, 3], "min": ["a", "c", None], "max": ["b", "z", None]},
+            schema_overrides={
+                "min": pl.Categorical("lexical"),
+                "max": pl.Categorical("lexical"),
+            },
+        )
+        assert_frame_equal(out.collect(), expected, categorical_as_str=True)
+
+        keys = lf.group_by("x").agg(pl.len()).sort("x").collect()
+        assert keys["x"].to_list() == [None, "a", "b", "c", "z"]
+
+        # Switching back to the physical ordering uses the order in which the categories
+        # were first seen.
+        physical = result.with_columns(pl.col("x").cat.set_ordering("physical"))
+        order = ["z", "c", "b", "a"] if use_string_cache else ["b", "a", "z", "c"]
+        assert physical.sort("x")["x"].to_list() == [None, *order]
+        out = physical.group_by("g", maintain_order=True).agg(pl.col("x").min())
+        assert out["x"].to_list() == ["b", "z", None]
+
+
+def test_categorical_set_ordering_invalid_dtype() -> None:
+    with pytest.raises(pl.InvalidOperationError, match="set_ordering"):
+        pl.select(pl.lit("a").cat.set_ordering("lexical"))
+
+
 def test_err_on_categorical_asof_join_by_arg() -> None:
     df1 = pl.DataFrame(
         [
@@ -835,3 +882,130 @@ def test_cat_append_lexical_sorted_flag() -> None:
     df2 = pl.concat([part.sort("y") for part in df.partition_by("x")])
 
     assert not (df2["y"].is_sorted())
+
+
+def test_cat_to_local_expr() -> None:
+    with pl.StringCache():
+        pl.Series(["x", "y"], dtype=pl.Categorical)
+        df = pl.DataFrame({"a": ["c", "b", None, "c"]}, schema={"a": pl.Categorical})
+
+    lf = df.lazy().select(pl.col("a").cat.to_local())
+    assert lf.schema == {"a": pl.Categorical}
+    result = lf.collect()
+    assert result["a"].cat.is_local()
+    assert result["a"].to_list() == ["c", "b", None, "c"]
+    assert result["a"].to_physical().to_list() == [0, 1, None, 0]
+
+    with pytest.raises(pl.InvalidOperationError, match="to_local"):
+        pl.select(pl.lit("a").cat.to_local())
+
+
+@pytest.mark.filterwarnings("ignore::polars.exceptions.CategoricalRemappingWarning")
+def test_combine_categoricals_from_different_caches() -> None:
+    dtype = pl.Categorical
+    df_local = pl.DataFrame(
+        {"x": ["a", "b"], "l": [["a"], ["b", "c"]], "i": [1, 2]},
+        schema={"x": dtype, "l": pl.List(dtype), "i": pl.Int64},
+    )
+    with pl.StringCache():
+        pl.Series(["q", "r"], dtype=dtype)
+        df_global = pl.DataFrame(
+            {"x": ["b", "z"], "l": [["z"], None], "i": [3, 4]},
+            schema={"x": dtype, "l": pl.List(dtype), "i": pl.Int64},
+        )
+    with pl.StringCache():
+        df_other_global = pl.DataFrame(
+            {"x": ["z", "a"], "l": [["a"], []], "i": [5, 6]},
+            schema={"x": dtype, "l": pl.List(dtype), "i": pl.Int64},
+        )
+
+    result = pl.concat([df_local, df_global, df_other_global])
+    assert result["x"].to_list() == ["a", "b", "b", "z", "z", "a"]
+    assert result["l"].to_list() == [["a"], ["b", "c"], ["z"], None, ["a"], []]
+    assert result["x"].cat.is_local()
+
+    result = pl.concat([df_global.lazy(), df_local.lazy()]).collect()
+    assert result["x"].to_list() == ["b", "z", "a", "b"]
+    assert result["l"].to_list() == [["z"], None, ["a"], ["b", "c"]]
+
+    result = df_global.join(df_other_global, on="x", how="left")
+    assert result["i_right"].to_list() == [None, 5]
+    result = df_local.join(df_global, on="x")
+    assert result.select("x", "i", "i_right").rows() == [("b", 2, 3)]
+
+
+@pytest.mark.parametrize("use_string_cache", [False, True])
+def test_join_categoricals_from_different_sources(use_string_cache: bool) -> None:
+    df_left = pl.DataFrame(
+        {"k": ["a", "b", None, "c", "b"], "i": [1, 1, 1, 2, 1], "x": [1, 2, 3, 4, 5]},
+        schema_overrides={"k": pl.Categorical},
+    )
+    with pl.StringCache() if use_string_cache else contextlib.nullcontext():
+        df_right = pl.DataFrame(
+            {"k": ["d", "b", "a", "e"], "i": [1, 1, 2, 1], "y": [10, 20, 30, 40]},
+            schema_overrides={"k": pl.Categorical},
+        )
+
+    # Joins that only need to find matches translate the keys without re-encoding
+    # the categoricals.
+    with warnings.catch_warnings():
+        warnings.simplefilter("error", CategoricalRemappingWarning)
+        inner = df_left.join(df_right, on="k").sort("x")
+        left = df_left.join(df_right, on="k", how="left")
+        semi = df_left.join(df_right, on="k", how="semi")
+        anti = df_left.join(df_right, on="k", how="anti")
+        multiple = df_left.join(df_right, on=["k", "i"])
+
+    assert inner.rows() == [("a", 1, 1, 2, 30), ("b", 1, 2, 1, 20), ("b", 1, 5, 1, 20)]
+    assert inner.schema["k"] == pl.Categorical
+    assert left["y"].to_list() == [30, 20, None, None, 20]
+    assert semi["x"].to_list() == [1, 2, 5]
+    assert anti["x"].to_list() == [3, 4]
+    assert multiple.select("x", "y").sort("x").rows() == [(2, 20), (5, 20)]
+
+    with warnings.catch_warnings():
+        warnings.simplefilter("ignore", CategoricalRemappingWarning)
+        outer = df_left.join(df_right, on="k", how="outer_coalesce")
+    assert outer["k"].cast(pl.String).sort(nulls_last=True).to_list() == [
+        "a", "b", "b", "c", "d", "e", None
+    ]  # fmt: skip
+
+
+@pytest.mark.parametrize("use_string_cache", [False, True])
+def test_join_categoricals_from_different_sources_validate(
+    use_string_cache: bool,
+) -> None:
+    df_left = pl.DataFrame({"k": ["a", "b"]}, schema={"k": pl.Categorical})
+    with pl.StringCache() if use_string_cache else contextlib.nullcontext():
+        df_right = pl.DataFrame(
+            {"k": ["a", "d", "e"], "y": [1, 2, 3]},
+            schema_overrides={"k": pl.Categorical},
+        )
+
+    # The categories that only occur on the right side are no duplicate keys.
+    out = df_left.join(df_right, on="k", how="left", validate="1:1")
+    assert out["y"].to_list() == [1, None]
+    out = df_left.join(df_right, on="k", how="left", validate="m:1")
+    assert out["y"].to_list() == [1, None]
+
+    df_right = df_right.vstack(df_right.head(1))
+    with pytest.raises(pl.ComputeError, match="1:1 validation"):
+        df_left.join(df_right, on="k", how="left", validate="1:1")
+
+
+@pytest.mark.parametrize("how", ["inner", "left"])
+def test_join_categoricals_from_different_sources_streaming(
+    how: Literal["inner", "left"],
+) -> None:
+    df_left = pl.DataFrame(
+        {"k": ["a", "b", "c"], "x": [1, 2, 3]},
+        schema_overrides={"k": pl.Categorical},
+    )
+    df_right = pl.DataFrame(
+        {"k": ["c", "b", "d"], "y": [30, 20, 40]},
+        schema_overrides={"k": pl.Categorical},
+    )
+    q = df_left.lazy().join(df_right.lazy(), on="k", how=how)
+    result = q.collect(streaming=True).sort("x")
+    assert_frame_equal(result, q.collect().sort("x"))
+    assert result.filter(pl.col("x") > 1)["y"].to_list() == [20, 30]
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import enum
 import operator
 import re
 from datetime import date
@@ -28,6 +29,21 @@ def test_enum_creation() -> None:
     assert e.categories.to_list() == ["a", "b", "c", "d", "e"]
 
 
+def test_enum_from_python_enum() -> None:
+    class Color(enum.Enum):
+        RED = "red"
+        GREEN = "green"
+        BLUE = "blue"
+
+    assert pl.Enum(Color) == pl.Enum(["red", "green", "blue"])
+
+    class Number(enum.Enum):
+        ONE = 1
+
+    with pytest.raises(TypeError, match="Enum categories must be strings"):
+        pl.Enum(Number)
+
+
 @pytest.mark.parametrize("categories", [[], pl.Series("foo", dtype=pl.Int16), None])
 def test_enum_init_empty(categories: pl.Series | list[str] | None) -> None:
     dtype = pl.Enum(categories)  # type: ignore[arg-type]
@@ -529,3 +545,53 @@ def test_integer_cast_to_enum_15738(dt: pl.DataType) -> None:
     assert s.to_list() == ["a", "b", "c"]
     expected_s = pl.Series(["a", "b", "c"], dtype=pl.Enum(["a", "b", "c"]))
     assert_series_equal(s, expected_s)
+
+
+def test_enum_min_max() -> None:
+    dtype = pl.Enum(["low", "medium", "high"])
+    df = pl.DataFrame({"a": pl.Series(["medium", None, "low"], dtype=dtype)})
+
+    lf = df.lazy().select(
+        pl.col("a").min().alias("min"),
+        pl.col("a").max().alias("max"),
+    )
+    expected = pl.DataFrame(
+        {"min": ["low"], "max": ["medium"]}, schema={"min": dtype, "max": dtype}
+    )
+    assert lf.schema == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+
+    assert df["a"].min() == "low"
+    assert df["a"].max() == "medium"
+
+
+def test_compare_different_enums_raise() -> None:
+    s = pl.Series(["a", "b"], dtype=pl.Enum(["a", "b"]))
+    other = pl.Series(["a", "b"], dtype=pl.Enum(["a", "b", "c"]))
+    for op in [operator.eq, operator.ne, pl.Series.eq_missing]:
+        with pytest.raises(
+            pl.ComputeError, match="can only compare categoricals of the same type"
+        ):
+            op(s, other)
+
+    assert_series_equal(s == other.cast(s.dtype), pl.Series([True, True]))
+
+
+def test_enum_join() -> None:
+    dtype = pl.Enum(["a", "b", "c"])
+    left = pl.DataFrame({"key": pl.Series(["a", "b"], dtype=dtype), "x": [1, 2]})
+    right = pl.DataFrame({"key": pl.Series(["c", "b"], dtype=dtype), "y": [3, 4]})
+
+    result = left.join(right, on="key", how="outer_coalesce").sort("key")
+    expected = pl.DataFrame(
+        {
+            "key": pl.Series(["a", "b", "c"], dtype=dtype),
+            "x": [1, 2, None],
+            "y": [None, 4, 3],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    other = right.with_columns(pl.col("key").cast(pl.Enum(["b", "c"])))
+    with pytest.raises(pl.ComputeError, match="datatypes of join keys don't match"):
+        left.join(other, on="key")
@@ -160,6 +160,30 @@ def test_struct_unnest_multiple() -> None:
     assert_frame_equal(result, df)
 
 
+def test_struct_unnest_naming() -> None:
+    df = pl.DataFrame({"a": [{"id": 1, "x": 2}], "b": [{"id": 3, "y": 4}]})
+
+    with pytest.raises(pl.DuplicateError, match="id"):
+        df.lazy().unnest("a", "b").schema
+
+    lf = df.lazy().unnest("a", "b", naming="prefix")
+    expected = pl.DataFrame({"a_id": [1], "a_x": [2], "b_id": [3], "b_y": [4]})
+    assert lf.schema == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+
+    result = df.unnest("a", naming="suffix", separator=".")
+    assert result.columns == ["id.a", "x.a", "b"]
+
+    result = df.unnest("a", "b", naming=lambda column, field: f"{field}[{column}]")
+    assert result.columns == ["id[a]", "x[a]", "id[b]", "y[b]"]
+
+    result = df["b"].struct.unnest(naming="prefix", separator="")
+    assert_frame_equal(result, pl.DataFrame({"bid": [3], "by": [4]}))
+
+    with pytest.raises(ValueError, match="`naming` must be one of"):
+        df.unnest("a", naming="x")  # type: ignore[arg-type]
+
+
 def test_struct_function_expansion() -> None:
     df = pl.DataFrame(
         {"a": [1, 2, 3, 4], "b": ["one", "two", "three", "four"], "c": [9, 8, 7, 6]}
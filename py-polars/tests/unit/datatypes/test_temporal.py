@@ -16,6 +16,7 @@ from polars.exceptions import (
     ComputeError,
     InvalidOperationError,
     PolarsInefficientMapWarning,
+    ShapeError,
     TimeZoneAwareConstructorWarning,
 )
 from polars.testing import (
@@ -638,19 +639,11 @@ def test_offset_deprecated() -> None:
     with pytest.deprecated_call():
         df.select(pl.col("time").dt.truncate(every="1mo", offset="1d"))
 
-    # round
-    with pytest.deprecated_call():
-        df.select(pl.col("time").dt.round(every="1mo", offset="1d"))
-
     ser = df.to_series(0)
     # truncate
     with pytest.deprecated_call():
         ser.dt.truncate(every="1mo", offset="1d")
 
-    # round
-    with pytest.deprecated_call():
-        ser.dt.round(every="1mo", offset="1d")
-
 
 @pytest.mark.parametrize("time_zone", [None, "US/Central"])
 @pytest.mark.parametrize(
@@ -837,6 +830,67 @@ def test_upsample_index_invalid(
             )
 
 
+@pytest.mark.parametrize("maintain_order", [True, False])
+def test_upsample_lazy(maintain_order: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "groups": ["a", "b", "a", "b"],
+            "time": [
+                datetime(2021, 2, 1),
+                datetime(2021, 4, 1),
+                datetime(2021, 5, 1),
+                datetime(2021, 6, 1),
+            ],
+            "values": [0, 1, 2, 3],
+        }
+    ).set_sorted("time")
+    lf = df.lazy().upsample(
+        time_column="time",
+        every="1mo",
+        group_by="groups",
+        maintain_order=maintain_order,
+    )
+    expected = df.upsample(
+        time_column="time",
+        every="1mo",
+        group_by="groups",
+        maintain_order=maintain_order,
+    )
+    assert lf.schema == expected.schema
+    assert lf.columns == ["time", "groups", "values"]
+    result = lf.collect()
+    if not maintain_order:
+        result = result.sort("groups", "time")
+        expected = expected.sort("groups", "time")
+    assert_frame_equal(result, expected)
+
+
+def test_upsample_lazy_pushdown() -> None:
+    lf = pl.LazyFrame(
+        {
+            "time": [date(2024, 1, 1), date(2024, 1, 3)],
+            "values": [1, 2],
+            "other": ["x", "y"],
+        }
+    ).set_sorted("time")
+    q = lf.upsample("time", every="1d").select(pl.col("values").forward_fill())
+    assert "PROJECT 2/3 COLUMNS" in q.explain()
+    assert_frame_equal(q.collect(), pl.DataFrame({"values": [1, 1, 2]}))
+
+    # The inserted rows only have a value in the time column, so filters are
+    # not pushed past the upsample.
+    q = lf.upsample("time", every="1d").filter(pl.col("values").is_null())
+    plan = q.explain()
+    assert plan.index("FILTER") < plan.index("UPSAMPLE")
+    assert_frame_equal(
+        q.collect(),
+        pl.DataFrame(
+            {"time": [date(2024, 1, 2)], "values": [None], "other": [None]},
+            schema=lf.schema,
+        ),
+    )
+
+
 def test_microseconds_accuracy() -> None:
     timestamps = [
         datetime(2600, 1, 1, 0, 0, 0, 123456),
@@ -1435,6 +1489,26 @@ def test_iso_year() -> None:
     assert pl.Series([date(2022, 1, 1)]).dt.iso_year()[0] == 2021
 
 
+def test_iso_year_time_zone() -> None:
+    # 2021-01-03 23:30 UTC is already Monday of the first ISO week in Tokyo.
+    s = (
+        pl.Series([datetime(2021, 1, 3, 23, 30)])
+        .dt.replace_time_zone("UTC")
+        .dt.convert_time_zone("Asia/Tokyo")
+    )
+    assert s.dt.iso_year().item() == 2021
+    assert s.dt.week().item() == 1
+    assert s.dt.weekday().item() == 1
+    assert s.dt.to_iso_week_string().item() == "2021-W01-1"
+
+
+def test_to_iso_week_string() -> None:
+    s = pl.Series([date(2020, 12, 31), date(2024, 12, 30), None])
+    expected = pl.Series(["2020-W53-4", "2025-W01-1", None])
+    assert_series_equal(s.dt.to_iso_week_string(), expected)
+    assert_series_equal(s.cast(pl.Datetime("ms")).dt.to_iso_week_string(), expected)
+
+
 def test_replace_time_zone() -> None:
     ny = ZoneInfo("America/New_York")
     assert pl.DataFrame({"a": [datetime(2022, 9, 25, 14)]}).with_columns(
@@ -1469,6 +1543,63 @@ def test_invalid_non_existent() -> None:
         )
 
 
+def test_replace_time_zone_non_existent_expression() -> None:
+    df = pl.DataFrame(
+        {
+            "ts": [
+                datetime(2021, 3, 28, 2, 30),
+                datetime(2021, 3, 28, 2, 30),
+                datetime(2021, 10, 31, 2, 30),
+                datetime(2021, 10, 31, 2, 30),
+                None,
+            ],
+            "ambiguous": ["raise", "raise", "earliest", "latest", "raise"],
+            "non_existent": ["null", None, "raise", "raise", "null"],
+        }
+    )
+    result = df.select(
+        pl.col("ts").dt.replace_time_zone(
+            "Europe/Warsaw",
+            ambiguous=pl.col("ambiguous"),
+            non_existent=pl.col("non_existent"),
+        )
+    )["ts"]
+    expected = pl.Series(
+        "ts",
+        [
+            None,
+            None,
+            datetime(2021, 10, 31, 0, 30),
+            datetime(2021, 10, 31, 1, 30),
+            None,
+        ],
+        dtype=pl.Datetime("us", "UTC"),
+    ).dt.convert_time_zone("Europe/Warsaw")
+    assert_series_equal(result, expected)
+
+    s = pl.Series([datetime(2021, 3, 28, 2, 30), datetime(2021, 3, 28, 3, 30)])
+    result = s.dt.replace_time_zone(
+        "Europe/Warsaw", non_existent=pl.Series(["null", "raise"])
+    )
+    assert result.dt.replace_time_zone(None).to_list() == [
+        None,
+        datetime(2021, 3, 28, 3, 30),
+    ]
+
+    with pytest.raises(ComputeError, match="is non-existent in time zone"):
+        s.dt.replace_time_zone(
+            "Europe/Warsaw", non_existent=pl.Series(["raise", "null"])
+        )
+    with pytest.raises(InvalidOperationError, match="Invalid argument cabbage"):
+        s.dt.replace_time_zone(
+            "Europe/Warsaw", non_existent=pl.Series(["cabbage", "null"])
+        )
+    with pytest.raises(ShapeError, match="expected `non_existent` of length 1 or 2"):
+        s.dt.replace_time_zone(
+            "Europe/Warsaw", non_existent=pl.Series(["null", "null", "null"])
+        )
+
+
 @pytest.mark.parametrize(
     ("to_tz", "tzinfo"),
     [
@@ -1874,10 +2005,9 @@ def test_ambiguous_expressions() -> None:
             "Europe/London", ambiguous=pl.col("ambiguous")
         )
     )
-    with pytest.deprecated_call():
-        result = df.select(
-            pl.col("ts").dt.truncate("1h", ambiguous=pl.col("ambiguous"))
-        )["ts"]
+    result = df.select(pl.col("ts").dt.truncate("1h", ambiguous=pl.col("ambiguous")))[
+        "ts"
+    ]
     expected = pl.Series("ts", [1603584000000000, 1603587600000000]).cast(
         pl.Datetime("us", "Europe/London")
     )
@@ -2082,6 +2212,43 @@ def test_tz_aware_to_string() -> None:
     assert result.to_dict(as_series=False) == expected
 
 
+@pytest.mark.parametrize(
+    ("locale", "expected"),
+    [
+        ("en_US", ["Sunday 3 March (Sun, Mar)", "Monday 12 August (Mon, Aug)", None]),
+        ("de_DE", ["Sonntag 3 März (So, Mär)", "Montag 12 August (Mo, Aug)", None]),
+        (
+            "fr_FR",
+            ["dimanche 3 mars (dim., mars)", "lundi 12 août (lun., août)", None],
+        ),
+    ],
+)
+@pytest.mark.parametrize("time_zone", [None, "Asia/Kathmandu"])
+def test_to_string_locale(
+    locale: str, expected: list[str | None], time_zone: str | None
+) -> None:
+    s = pl.Series([date(2024, 3, 3), date(2024, 8, 12), None])
+    format = "%A %-d %B (%a, %b)"
+    result = s.dt.to_string(format, locale=locale)
+    assert_series_equal(result, pl.Series(expected))
+    result = (
+        s.cast(pl.Datetime)
+        .dt.replace_time_zone(time_zone)
+        .dt.strftime(format, locale=locale)
+    )
+    assert_series_equal(result, pl.Series(expected))
+
+
+def test_to_string_locale_time() -> None:
+    s = pl.Series([time(10, 30)])
+    assert s.dt.to_string("%H:%M", locale="nl_NL").item() == "10:30"
+
+
+def test_to_string_locale_invalid() -> None:
+    with pytest.raises(ValueError, match="`locale` must be one of"):
+        pl.Series([date(2024, 3, 3)]).dt.to_string("%B", locale="xx_XX")
+
+
 @pytest.mark.parametrize(
     ("time_zone", "directive", "expected"),
     [
@@ -2103,10 +2270,87 @@ def test_local_time_zone_name() -> None:
         pl.Datetime, "%Y-%m-%d %H:%M%Z"
     )
     result = ser[0]
-    expected = datetime(2020, 1, 1, 3)
+    expected = datetime(2019, 12, 31, 17, 30, tzinfo=timezone.utc)
     assert result == expected
 
 
+def test_strptime_time_zone_names() -> None:
+    s = pl.Series(
+        [
+            "2024-07-01 10:00 CEST",
+            "2024-07-01 10:00 America/New_York",
+            "2024-01-01 10:00 Europe/Amsterdam",
+            "2024-07-01 10:00 utc",
+            None,
+        ]
+    )
+    result = s.str.to_datetime("%Y-%m-%d %H:%M %Z", time_unit="ms")
+    expected = pl.Series(
+        [
+            datetime(2024, 7, 1, 8),
+            datetime(2024, 7, 1, 14),
+            datetime(2024, 1, 1, 9),
+            datetime(2024, 7, 1, 10),
+            None,
+        ],
+        dtype=pl.Datetime("ms"),
+    ).dt.replace_time_zone("UTC")
+    assert_series_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="conversion .* failed"):
+        pl.Series(["2024-07-01 10:00 XYZ"]).str.to_datetime("%Y-%m-%d %H:%M %Z")
+
+
+def test_strptime_time_zone_names_not_exact() -> None:
+    s = pl.Series(["on 2024-07-01 10:00 CEST", "2024-07-01 10:00 America/New_York!"])
+    result = s.str.to_datetime("%Y-%m-%d %H:%M %Z", exact=False, strict=False)
+    assert result.to_list() == [datetime(2024, 7, 1, 8, tzinfo=timezone.utc), None]
+
+
+def test_strptime_mixed_offsets_lazy_schema() -> None:
+    lf = pl.LazyFrame({"s": ["2024-01-01 10:00+01:00", "2024-01-01 10:00-05:00"]})
+    q = lf.select(pl.col("s").str.to_datetime("%Y-%m-%d %H:%M%:z"))
+    assert q.schema == {"s": pl.Datetime("us", "UTC")}
+    assert q.collect().to_series().to_list() == [
+        datetime(2024, 1, 1, 9, tzinfo=timezone.utc),
+        datetime(2024, 1, 1, 15, tzinfo=timezone.utc),
+    ]
+
+
+def test_to_utc_offset() -> None:
+    df = pl.DataFrame(
+        {
+            "s": [
+                "2024-01-01 10:00 +0100",
+                "2024-07-01 10:00 Europe/Amsterdam",
+                "2024-07-01 10:00 +0530 IST",
+                None,
+            ]
+        }
+    )
+    result = df.select(
+        pl.col("s").str.to_utc_offset("%Y-%m-%d %H:%M %z", strict=False).alias("z"),
+        pl.col("s").str.to_utc_offset("%Y-%m-%d %H:%M %Z", strict=False).alias("Z"),
+    )
+    expected = pl.DataFrame(
+        {
+            "z": [timedelta(hours=1), None, None, None],
+            "Z": [None, timedelta(hours=2), None, None],
+        },
+        schema={"z": pl.Duration("ms"), "Z": pl.Duration("ms")},
+    )
+    assert_frame_equal(result, expected)
+
+    # An offset takes precedence over the name.
+    result = pl.Series(["2024-07-01 10:00 +0530 IST"]).str.to_utc_offset(
+        "%Y-%m-%d %H:%M %z %Z"
+    )
+    assert result.item() == timedelta(hours=5, minutes=30)
+
+    with pytest.raises(InvalidOperationError, match="time zone offset or name"):
+        pl.Series(["2024-01-01"]).str.to_utc_offset("%Y-%m-%d")
+
+
 def test_tz_aware_filter_lit() -> None:
     start = datetime(1970, 1, 1)
     stop = datetime(1970, 1, 1, 7)
@@ -2441,6 +2685,62 @@ def test_truncate_non_existent_14957() -> None:
         ).dt.truncate("46m")
 
 
+def test_truncate_ambiguous_strategy() -> None:
+    ser = pl.Series(
+        "datetime", [datetime(2020, 10, 25, 1, 30), datetime(2020, 10, 25, 1, 45)]
+    ).dt.replace_time_zone("Europe/London", ambiguous="earliest")
+    # the DST fold of the original datetime is used by default
+    result = ser.dt.truncate("1h")
+    assert result.dt.dst_offset().to_list() == [timedelta(hours=1)] * 2
+
+    result = ser.dt.truncate("1h", ambiguous="latest")
+    expected = pl.Series(
+        "datetime", [datetime(2020, 10, 25, 1), datetime(2020, 10, 25, 1)]
+    ).dt.replace_time_zone("Europe/London", ambiguous="latest")
+    assert_series_equal(result, expected)
+
+    result = ser.dt.truncate("1h", ambiguous=pl.Series(["null", "earliest"]))
+    expected = pl.Series(
+        "datetime", [None, datetime(2020, 10, 25, 1)]
+    ).dt.replace_time_zone("Europe/London", ambiguous="earliest")
+    assert_series_equal(result, expected)
+
+    # results which aren't ambiguous are unaffected
+    result = ser.dt.truncate("1d", ambiguous="raise")
+    expected = pl.Series(
+        "datetime", [datetime(2020, 10, 25), datetime(2020, 10, 25)]
+    ).dt.replace_time_zone("Europe/London")
+    assert_series_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="is ambiguous"):
+        ser.dt.truncate("1h", ambiguous="raise")
+    with pytest.raises(InvalidOperationError, match="Invalid argument foo"):
+        ser.dt.truncate("1h", ambiguous="foo")
+    with pytest.raises(ShapeError, match="expected `ambiguous` of length 1"):
+        ser.dt.truncate("1h", ambiguous=pl.Series(["earliest"] * 3))
+
+
+def test_round_ambiguous_strategy() -> None:
+    df = pl.DataFrame(
+        {
+            "datetime": [datetime(2020, 10, 25, 1, 40), datetime(2020, 10, 25, 0, 50)],
+            "ambiguous": ["earliest", "null"],
+        }
+    ).with_columns(
+        pl.col("datetime").dt.replace_time_zone("Europe/London", ambiguous="earliest")
+    )
+    result = df.select(pl.col("datetime").dt.round("1h", ambiguous=pl.col("ambiguous")))
+    expected = pl.DataFrame(
+        {"datetime": [datetime(2020, 10, 25, 1), None]}
+    ).with_columns(
+        pl.col("datetime").dt.replace_time_zone("Europe/London", ambiguous="earliest")
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="is ambiguous"):
+        df.select(pl.col("datetime").dt.round("1h", ambiguous="raise"))
+
+
 def test_round_ambiguous() -> None:
     t = (
         pl.datetime_range(
@@ -2493,8 +2793,7 @@ def test_round_ambiguous() -> None:
         }
     )
 
-    with pytest.deprecated_call():
-        df = df.select(pl.col("date").dt.round("30m", ambiguous=pl.col("ambiguous")))
+    df = df.select(pl.col("date").dt.round("30m", ambiguous=pl.col("ambiguous")))
     assert df.to_dict(as_series=False) == {
         "date": [
             datetime(2020, 10, 25, 0, 30, tzinfo=ZoneInfo("Europe/London")),
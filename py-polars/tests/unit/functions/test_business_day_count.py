@@ -129,6 +129,67 @@ def test_business_day_count_w_holidays() -> None:
     assert_series_equal(result, expected)
 
 
+@pytest.mark.parametrize("time_zone", [None, "Asia/Kathmandu"])
+def test_business_day_count_datetime(time_zone: str | None) -> None:
+    df = pl.DataFrame(
+        {
+            "start": [dt.datetime(2020, 1, 1, 23), dt.datetime(2020, 1, 2)],
+            "end": [dt.datetime(2020, 1, 2, 1), dt.datetime(2020, 1, 10, 12)],
+        }
+    ).with_columns(pl.all().dt.replace_time_zone(time_zone))
+    result = df.select(n=pl.business_day_count("start", "end"))["n"]
+    assert_series_equal(result, pl.Series("n", [1, 6], pl.Int32))
+
+    result = df.select(n=pl.business_day_count("start", dt.date(2020, 1, 10)))["n"]
+    assert_series_equal(result, pl.Series("n", [7, 6], pl.Int32))
+
+
+def test_business_day_count_invalid_dtype() -> None:
+    df = pl.DataFrame({"start": [dt.timedelta(1)], "end": [date(2020, 1, 1)]})
+    with pytest.raises(pl.InvalidOperationError, match="expected date or datetime"):
+        df.select(pl.business_day_count("start", "end"))
+
+
+def test_business_day_count_calendar() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [date(2020, 1, 1), date(2020, 1, 2)],
+            "end": [date(2020, 1, 2), date(2020, 1, 10)],
+        }
+    )
+    week_mask = (True, True, True, True, True, True, False)
+    holidays = [date(2020, 1, 1), date(2020, 1, 6)]
+    pl.register_holiday_calendar(
+        "test_calendar", week_mask=week_mask, holidays=holidays
+    )
+    try:
+        assert "test_calendar" in pl.holiday_calendars()
+        result = df.select(
+            pl.business_day_count("start", "end", calendar="test_calendar")
+        )
+        expected = df.select(pl.business_day_count("start", "end", week_mask, holidays))
+        assert_series_equal(result.to_series(), pl.Series("start", [0, 6], pl.Int32))
+        assert_series_equal(result.to_series(), expected.to_series())
+    finally:
+        assert pl.unregister_holiday_calendar("test_calendar")
+    assert "test_calendar" not in pl.holiday_calendars()
+    assert not pl.unregister_holiday_calendar("test_calendar")
+
+
+def test_business_day_count_calendar_invalid() -> None:
+    with pytest.raises(pl.ComputeError, match="'unknown' is not registered"):
+        pl.business_day_count("start", "end", calendar="unknown")
+    with pytest.raises(ValueError, match="cannot be combined with `week_mask`"):
+        pl.business_day_count(
+            "start", "end", holidays=[date(2020, 1, 1)], calendar="unknown"
+        )
+    with pytest.raises(
+        pl.ComputeError, match="`week_mask` must have at least one business day"
+    ):
+        pl.register_holiday_calendar("test_calendar", week_mask=[False] * 7)
+    assert "test_calendar" not in pl.holiday_calendars()
+
+
 @given(
     start=st.dates(min_value=dt.date(1969, 1, 1), max_value=dt.date(1970, 12, 31)),
     end=st.dates(min_value=dt.date(1969, 1, 1), max_value=dt.date(1970, 12, 31)),
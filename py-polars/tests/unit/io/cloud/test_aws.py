@@ -95,3 +95,53 @@ def test_lazy_count_s3(s3: str) -> None:
     assert "FAST COUNT(*)" in lf.explain()
     expected = pl.DataFrame({"len": [54]}, schema={"len": pl.UInt32})
     assert_frame_equal(lf.collect(), expected)
+
+
+def test_scan_s3_credential_provider(s3: str) -> None:
+    calls = 0
+
+    def credential_provider() -> tuple[dict[str, str], int | None]:
+        nonlocal calls
+        calls += 1
+        credentials = {
+            "aws_access_key_id": "accesskey",
+            "aws_secret_access_key": "secretkey",
+        }
+        return credentials, None
+
+    lf = pl.scan_parquet(
+        "s3://bucket/foods*.parquet",
+        storage_options={"endpoint_url": s3},
+        credential_provider=credential_provider,
+    )
+    assert lf.collect().shape == (54, 4)
+    assert calls == 1
+
+
+def test_scan_s3_credential_provider_error(s3: str) -> None:
+    def credential_provider() -> tuple[dict[str, str], int | None]:
+        msg = "credentials have expired"
+        raise PermissionError(msg)
+
+    lf = pl.scan_parquet(
+        "s3://bucket/foods1.parquet",
+        storage_options={"endpoint_url": s3},
+        credential_provider=credential_provider,
+    )
+    with pytest.raises(pl.ComputeError, match="credentials have expired"):
+        lf.collect()
+
+
+def test_scan_s3_retry_config(s3: str) -> None:
+    storage_options = {
+        "endpoint_url": s3,
+        "retry_timeout": "30",
+        "init_backoff": "0.1",
+        "max_backoff": "1",
+    }
+    lf = pl.scan_parquet("s3://bucket/foods1.parquet", storage_options=storage_options)
+    assert lf.collect().shape == (27, 4)
+
+    storage_options["retry_timeout"] = "30s"
+    with pytest.raises(pl.ComputeError, match="expected a number of seconds"):
+        pl.scan_parquet("s3://bucket/foods1.parquet", storage_options=storage_options)
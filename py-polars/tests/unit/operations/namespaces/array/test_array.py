@@ -63,6 +63,72 @@ def test_arr_sum(
     assert s.arr.sum().to_list() == expected_sum
 
 
+@pytest.mark.parametrize(
+    ("data", "expected_product", "dtype", "expected_dtype"),
+    [
+        ([[1, 2], [4, 3]], [2, 12], pl.Int64, pl.Int64),
+        ([[100, 100], [None, 3], [None, None]], [10_000, 3, 1], pl.Int8, pl.Int64),
+        ([[1.5, 2.0], [4.0, None]], [3.0, 4.0], pl.Float32, pl.Float32),
+        ([[2, 3], [4, 5]], [6, 20], pl.UInt64, pl.UInt64),
+        ([[True, False], [True, True]], [0, 1], pl.Boolean, pl.Int64),
+    ],
+)
+def test_arr_product(
+    data: list[list[Any]],
+    expected_product: list[Any],
+    dtype: pl.DataType,
+    expected_dtype: pl.DataType,
+) -> None:
+    s = pl.Series("a", data, dtype=pl.Array(dtype, 2))
+    out = s.arr.product()
+    assert out.dtype == expected_dtype
+    assert out.to_list() == expected_product
+
+    lf = s.to_frame().lazy().select(pl.col("a").arr.product())
+    assert lf.schema == {"a": expected_dtype}
+
+
+def test_arr_product_null_sub_arrays() -> None:
+    s = pl.Series("a", [[1, 2], None, [3, 4]], dtype=pl.Array(pl.Int32, 2))
+    assert s.arr.product().to_list() == [2, None, 12]
+
+
+@pytest.mark.parametrize(
+    ("data", "expected_mean", "dtype", "expected_dtype"),
+    [
+        ([[1, 2], [4, 3]], [1.5, 3.5], pl.Int8, pl.Float64),
+        ([[1, None], [None, 3], [None, None]], [1.0, 3.0, None], pl.Int64, pl.Float64),
+        ([[1.0, 2.0], [4.0, 3.0]], [1.5, 3.5], pl.Float32, pl.Float32),
+        ([[True, False], [True, True]], [0.5, 1.0], pl.Boolean, pl.Float64),
+    ],
+)
+def test_arr_mean(
+    data: list[list[Any]],
+    expected_mean: list[Any],
+    dtype: pl.DataType,
+    expected_dtype: pl.DataType,
+) -> None:
+    s = pl.Series("a", data, dtype=pl.Array(dtype, 2))
+    out = s.arr.mean()
+    assert out.dtype == expected_dtype
+    assert out.to_list() == expected_mean
+
+    lf = s.to_frame().lazy().select(pl.col("a").arr.mean())
+    assert lf.schema == {"a": expected_dtype}
+
+
+def test_arr_mean_duration() -> None:
+    td = datetime.timedelta
+    s = pl.Series(
+        "a",
+        [[td(hours=1), td(hours=2)], [td(hours=3), None]],
+        dtype=pl.Array(pl.Duration("ms"), 2),
+    )
+    out = s.arr.mean()
+    expected = pl.Series("a", [td(hours=1, minutes=30), td(hours=3)], pl.Duration("ms"))
+    assert_series_equal(out, expected)
+
+
 def test_arr_unique() -> None:
     df = pl.DataFrame(
         {"a": pl.Series("a", [[1, 1], [4, 3]], dtype=pl.Array(pl.Int64, 2))}
@@ -115,6 +181,19 @@ def test_array_sort() -> None:
     )
 
 
+def test_array_sort_many_rows() -> None:
+    values = [[(i * 7) % 5, None, (i * 3) % 4] for i in range(1_000)]
+    s = pl.Series(values, dtype=pl.Array(pl.Int64, 3))
+
+    out = s.arr.sort(descending=True, nulls_last=True)
+    expected = (
+        s.arr.to_list()
+        .list.sort(descending=True, nulls_last=True)
+        .cast(pl.Array(pl.Int64, 3))
+    )
+    assert_series_equal(out, expected)
+
+
 def test_array_reverse() -> None:
     s = pl.Series([[2, None, 1], [1, None, 2]], dtype=pl.Array(pl.UInt32, 3))
 
@@ -122,6 +201,22 @@ def test_array_reverse() -> None:
     expected = pl.Series([[1, None, 2], [2, None, 1]], dtype=pl.Array(pl.UInt32, 3))
     assert_series_equal(s, expected)
 
+    # null sub-arrays and logical inner types
+    s = pl.Series(
+        [[datetime.date(2020, 1, 1), datetime.date(2020, 1, 2)], None],
+        dtype=pl.Array(pl.Date, 2),
+    )
+    expected = pl.Series(
+        [[datetime.date(2020, 1, 2), datetime.date(2020, 1, 1)], None],
+        dtype=pl.Array(pl.Date, 2),
+    )
+    assert_series_equal(s.arr.reverse(), expected)
+
+    # sliced input
+    s = pl.Series([[1, 2], [3, 4], [5, 6]], dtype=pl.Array(pl.Int64, 2))[1:]
+    expected = pl.Series([[4, 3], [6, 5]], dtype=pl.Array(pl.Int64, 2))
+    assert_series_equal(s.arr.reverse(), expected)
+
 
 def test_array_arg_min_max() -> None:
     s = pl.Series("a", [[1, 2, 4], [3, 2, 1]], dtype=pl.Array(pl.UInt32, 3))
@@ -130,6 +225,14 @@ def test_array_arg_min_max() -> None:
     expected = pl.Series("a", [2, 0], dtype=pl.UInt32)
     assert_series_equal(s.arr.arg_max(), expected)
 
+    # null sub-arrays and nulls inside the sub-arrays
+    s = pl.Series(
+        "a", [[1.0, -2.0, 4.0], None, [3.0, None, 1.0]], dtype=pl.Array(pl.Float64, 3)
+    )
+    assert_series_equal(s.arr.arg_min(), pl.Series("a", [1, None, 2], dtype=pl.UInt32))
+    assert_series_equal(s.arr.arg_max(), pl.Series("a", [2, None, 0], dtype=pl.UInt32))
+    assert_series_equal(s[:2].arr.arg_max(), pl.Series("a", [2, None], dtype=pl.UInt32))
+
 
 def test_array_get() -> None:
     s = pl.Series(
@@ -230,6 +333,25 @@ def test_array_get_null_on_oob() -> None:
     assert_series_equal(out, expected)
 
 
+def test_array_get_null_sub_arrays() -> None:
+    s = pl.Series(
+        "a",
+        [[1, 2], None, [5, 6]],
+        dtype=pl.Array(pl.Int64, 2),
+    )
+    expected = pl.Series("a", [2, None, 6], dtype=pl.Int64)
+
+    # Null sub-arrays must produce null, independent of the underlying values.
+    masked = s.to_frame().select(
+        pl.when(pl.Series([True, False, True])).then(pl.col("a"))
+    )["a"]
+    for series in (s, masked):
+        assert_series_equal(series.arr.get(-1, null_on_oob=False), expected)
+        assert_series_equal(
+            series.arr.get(pl.Series([1, 0, -1]), null_on_oob=False), expected
+        )
+
+
 def test_arr_first_last() -> None:
     s = pl.Series(
         "a",
@@ -322,6 +444,12 @@ def test_array_join() -> None:
     assert out.to_dict(as_series=False) == {"a": [None, None, None, "c@d@e@f"]}
 
 
+def test_array_join_invalid_separator_length() -> None:
+    s = pl.Series([["a", "b"], ["c", "d"], ["e", "f"]], dtype=pl.Array(pl.String, 2))
+    with pytest.raises(pl.ComputeError, match="separator of length 2"):
+        s.arr.join(pl.Series(["-", "+"]))
+
+
 def test_array_explode() -> None:
     df = pl.DataFrame(
         {
@@ -375,6 +503,19 @@ def test_array_explode() -> None:
     )
     assert_series_equal(out_s, expected_s)
 
+    # multiple chunks and a sliced chunk in the no-null fast path
+    s = pl.concat(
+        [
+            pl.Series([[1, 2], [3, 4], [5, 6]], dtype=pl.Array(pl.Int64, 2))[1:],
+            pl.Series([[7, 8]], dtype=pl.Array(pl.Int64, 2)),
+        ],
+        rechunk=False,
+    )
+    assert s.n_chunks() == 2
+    df = pl.DataFrame({"a": s, "b": [1, 2, 3]}).explode("a")
+    expected_df = pl.DataFrame({"a": [3, 4, 5, 6, 7, 8], "b": [1, 1, 2, 2, 3, 3]})
+    assert_frame_equal(df, expected_df)
+
 
 @pytest.mark.parametrize(
     ("arr", "data", "expected", "dtype"),
@@ -416,6 +557,10 @@ def test_array_to_struct() -> None:
         "a"
     ).sum().collect().columns == ["field_0", "field_1", "field_2"]
 
+    lf = df.lazy().select(pl.col("a").arr.to_struct(fields=lambda idx: f"c{idx}"))
+    assert lf.schema["a"] == pl.Struct({"c0": pl.Int8, "c1": pl.Int8, "c2": pl.Int8})
+    assert lf.unnest("a").sum().collect().columns == ["c0", "c1", "c2"]
+
 
 def test_array_shift() -> None:
     df = pl.DataFrame(
@@ -436,6 +581,12 @@ def test_array_shift() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_array_shift_invalid_n_length() -> None:
+    s = pl.Series([[1, 2], [3, 4], [5, 6]], dtype=pl.Array(pl.Int64, 2))
+    with pytest.raises(pl.ComputeError, match="`n` of length 2"):
+        s.arr.shift(pl.Series([1, -1]))
+
+
 def test_array_n_unique() -> None:
     df = pl.DataFrame(
         {
@@ -449,3 +600,366 @@ def test_array_n_unique() -> None:
         {"n_unique": [2, 1, 1, None]}, schema={"n_unique": pl.UInt32}
     )
     assert_frame_equal(out, expected)
+
+
+def test_array_set_operations() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 3], [None, 3, 3], [5, 6, 7], None],
+            "b": [[2, 3, 4], [3, 4, None], [6, 8, 9], [1, 2, 3]],
+        },
+        schema={"a": pl.Array(pl.Int64, 3), "b": pl.Array(pl.Int64, 3)},
+    )
+
+    out = df.lazy().select(
+        union=pl.col("a").arr.set_union("b"),
+        difference=pl.col("a").arr.set_difference("b"),
+        intersection=pl.col("a").arr.set_intersection("b"),
+        sdiff=pl.col("a").arr.set_symmetric_difference("b"),
+    )
+    assert out.schema == {
+        "union": pl.List(pl.Int64),
+        "difference": pl.List(pl.Int64),
+        "intersection": pl.List(pl.Int64),
+        "sdiff": pl.List(pl.Int64),
+    }
+    assert out.collect().to_dict(as_series=False) == {
+        "union": [[1, 2, 3, 4], [None, 3, 4], [5, 6, 7, 8, 9], None],
+        "difference": [[1], [], [5, 7], None],
+        "intersection": [[2, 3], [None, 3], [6], None],
+        "sdiff": [[1, 4], [4], [5, 7, 8, 9], None],
+    }
+
+
+def test_array_set_operations_list_other() -> None:
+    s = pl.Series("a", [[1, 2], [3, 4]], dtype=pl.Array(pl.Int32, 2))
+
+    out = s.arr.set_union(pl.Series([[2, 5], [6]]))
+    assert_series_equal(out, pl.Series("a", [[1, 2, 5], [3, 4, 6]], pl.List(pl.Int32)))
+
+    out = s.to_frame().select(pl.col("a").arr.set_intersection(pl.lit([1, 4])))
+    assert out.to_series().to_list() == [[1], [4]]
+
+
+def test_array_drop_nulls() -> None:
+    df = pl.DataFrame(
+        {"a": [[None, 1, 2], [None, None, None], [3, 4, 5], None]},
+        schema={"a": pl.Array(pl.Int64, 3)},
+    )
+
+    out = df.lazy().select(pl.col("a").arr.drop_nulls())
+    assert out.schema == {"a": pl.List(pl.Int64)}
+    expected = pl.DataFrame({"a": [[1, 2], [], [3, 4, 5], None]})
+    assert_frame_equal(out.collect(), expected)
+
+    # no nulls in the sub-arrays
+    s = pl.Series([["a", "b"], None], dtype=pl.Array(pl.String, 2))
+    out = s.arr.drop_nulls()
+    assert_series_equal(out, pl.Series([["a", "b"], None], dtype=pl.List(pl.String)))
+
+
+def test_array_zip_with() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 3], [4, 5, 6], None, [7, 8, 9]],
+            "mask": [[True, False, None], [False, True, True], [True] * 3, None],
+            "b": [[-1, -2, -3], [-4, -5, -6], [-7, -8, -9], [-7, -8, -9]],
+        },
+        schema={
+            "a": pl.Array(pl.Int64, 3),
+            "mask": pl.Array(pl.Boolean, 3),
+            "b": pl.Array(pl.Int64, 3),
+        },
+    )
+
+    out = df.lazy().select(pl.col("a").arr.zip_with(pl.col("mask"), pl.col("b")))
+    assert out.schema == {"a": pl.Array(pl.Int64, 3)}
+    expected = pl.Series("a", [[1, -2, -3], [-4, 5, 6], None, None])
+    assert_series_equal(
+        out.collect().to_series(), expected.cast(pl.Array(pl.Int64, 3))
+    )
+
+
+def test_array_zip_with_width_mismatch() -> None:
+    lf = pl.LazyFrame(
+        {"a": [[1, 2]], "mask": [[True, False, True]], "b": [[3, 4]]},
+        schema={
+            "a": pl.Array(pl.Int64, 2),
+            "mask": pl.Array(pl.Boolean, 3),
+            "b": pl.Array(pl.Int64, 2),
+        },
+    )
+    with pytest.raises(pl.SchemaError, match="same width"):
+        _ = lf.select(pl.col("a").arr.zip_with(pl.col("mask"), pl.col("b"))).schema
+
+
+@pytest.mark.parametrize(
+    ("interpolation", "expected"),
+    [
+        ("nearest", [2.0, 4.0, None, 6.0]),
+        ("lower", [1.0, 2.0, None, 6.0]),
+        ("higher", [2.0, 4.0, None, 6.0]),
+        ("midpoint", [1.5, 3.0, None, 6.0]),
+        ("linear", [1.9, 3.8, None, 6.0]),
+    ],
+)
+def test_array_quantile(interpolation: Any, expected: list[float | None]) -> None:
+    s = pl.Series(
+        "a",
+        [[1, 2, 3, 4], [8, 6, 4, 2], None, [6, None, None, None]],
+        dtype=pl.Array(pl.Int64, 4),
+    )
+    out = s.arr.quantile(0.3, interpolation=interpolation)
+    assert_series_equal(out, pl.Series("a", expected, dtype=pl.Float64))
+
+
+def test_array_quantile_expr() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+            "q": [0.0, 0.5, 1.0],
+        },
+        schema={"a": pl.Array(pl.Float32, 3), "q": pl.Float64},
+    )
+    out = df.lazy().select(
+        pl.col("a").arr.quantile(pl.col("q")).alias("per_row"),
+        pl.col("a").arr.quantile(pl.lit(0.5)).alias("literal"),
+    )
+    assert out.schema == {"per_row": pl.Float32, "literal": pl.Float32}
+    expected = pl.DataFrame(
+        {"per_row": [1.0, 5.0, 9.0], "literal": [2.0, 5.0, 8.0]},
+        schema={"per_row": pl.Float32, "literal": pl.Float32},
+    )
+    assert_frame_equal(out.collect(), expected)
+
+    s = pl.Series(
+        [[datetime.timedelta(1), datetime.timedelta(3)]],
+        dtype=pl.Array(pl.Duration("us"), 2),
+    )
+    assert s.arr.quantile(1.0).to_list() == [datetime.timedelta(3)]
+
+    df_temporal = pl.DataFrame(
+        {
+            "date": [[datetime.date(2024, 1, d) for d in (1, 3, 5)]],
+            "datetime": [[datetime.datetime(2024, 1, 1, h) for h in (1, 3, 5)]],
+        },
+        schema={
+            "date": pl.Array(pl.Date, 3),
+            "datetime": pl.Array(pl.Datetime("ms"), 3),
+        },
+    )
+    lf = df_temporal.lazy().select(pl.all().arr.quantile(0.5))
+    assert lf.schema == {"date": pl.Date, "datetime": pl.Datetime("ms")}
+    assert lf.collect().rows() == [
+        (datetime.date(2024, 1, 3), datetime.datetime(2024, 1, 1, 3))
+    ]
+
+    with pytest.raises(ComputeError, match="should be between 0.0 and 1.0"):
+        df.select(pl.col("a").arr.quantile(1.5))
+
+
+def test_array_cum_agg() -> None:
+    s = pl.Series("a", [[1, 3, 2], None, [4, None, 5]], dtype=pl.Array(pl.Int64, 3))
+
+    assert_series_equal(
+        s.arr.cum_sum(),
+        pl.Series("a", [[1, 4, 6], None, [4, None, 9]], dtype=pl.Array(pl.Int64, 3)),
+    )
+    assert_series_equal(
+        s.arr.cum_sum(reverse=True),
+        pl.Series("a", [[6, 5, 2], None, [9, None, 5]], dtype=pl.Array(pl.Int64, 3)),
+    )
+    assert_series_equal(
+        s.arr.cum_min(),
+        pl.Series("a", [[1, 1, 1], None, [4, None, 4]], dtype=pl.Array(pl.Int64, 3)),
+    )
+    assert_series_equal(
+        s.arr.cum_max(reverse=True),
+        pl.Series("a", [[3, 3, 2], None, [5, None, 5]], dtype=pl.Array(pl.Int64, 3)),
+    )
+
+
+def test_array_cum_sum_dtype() -> None:
+    df = pl.DataFrame(
+        {"a": [[1, 2], [3, 4]], "b": [[True, True], [False, True]]},
+        schema={"a": pl.Array(pl.Int8, 2), "b": pl.Array(pl.Boolean, 2)},
+    )
+    q = df.lazy().select(pl.all().arr.cum_sum())
+    expected = pl.DataFrame(
+        {"a": [[1, 3], [3, 7]], "b": [[1, 2], [0, 1]]},
+        schema={"a": pl.Array(pl.Int64, 2), "b": pl.Array(pl.UInt32, 2)},
+    )
+    assert q.schema == expected.schema
+    assert_frame_equal(q.collect(), expected)
+
+    s = pl.Series(
+        [[datetime.date(2024, 1, 2), datetime.date(2024, 1, 1)]],
+        dtype=pl.Array(pl.Date, 2),
+    )
+    out = s.arr.cum_min()
+    assert out.dtype == pl.Array(pl.Date, 2)
+    assert out.to_list() == [[datetime.date(2024, 1, 2), datetime.date(2024, 1, 1)]]
+
+
+def test_array_rank() -> None:
+    s = pl.Series(
+        "a", [[3, 6, 1, 1], None, [2, None, 2, 8]], dtype=pl.Array(pl.Int64, 4)
+    )
+
+    out = s.arr.rank()
+    assert out.dtype == pl.Array(pl.Float64, 4)
+    assert out.to_list() == [[3.0, 4.0, 1.5, 1.5], None, [1.5, None, 1.5, 3.0]]
+
+    out = s.arr.rank("dense", descending=True)
+    assert out.dtype == pl.Array(pl.UInt32, 4)
+    assert out.to_list() == [[2, 1, 3, 3], None, [2, None, 2, 1]]
+
+    assert s.arr.rank("ordinal").to_list()[0] == [3, 4, 1, 2]
+    assert s.arr.rank("min").to_list()[0] == [3, 4, 1, 1]
+    assert s.arr.rank("max").to_list()[0] == [3, 4, 2, 2]
+
+    df = pl.DataFrame({"a": s})
+    q = df.lazy().select(
+        pl.col("a").arr.rank().alias("average"),
+        pl.col("a").arr.rank("ordinal").alias("ordinal"),
+    )
+    schema = q.schema
+    assert schema == {
+        "average": pl.Array(pl.Float64, 4),
+        "ordinal": pl.Array(pl.UInt32, 4),
+    }
+
+
+def test_array_flatten() -> None:
+    s = pl.Series(
+        "a",
+        [[[1, 2], [3, None]], None, [None, [7, 8]]],
+        dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+    )
+    out = s.arr.flatten()
+    expected = pl.Series(
+        "a", [[1, 2, 3, None], None, [None, None, 7, 8]], dtype=pl.Array(pl.Int64, 4)
+    )
+    assert_series_equal(out, expected)
+    assert_series_equal(s.slice(1).arr.flatten(), expected.slice(1))
+
+    q = pl.LazyFrame({"a": s}).select(pl.col("a").arr.flatten())
+    assert q.schema == {"a": pl.Array(pl.Int64, 4)}
+
+    with pytest.raises(pl.InvalidOperationError, match="nested array"):
+        expected.arr.flatten()
+
+
+def test_array_reshape() -> None:
+    s = pl.Series(
+        "a",
+        [[1, 2, 3, 4, 5, 6], None, [7, None, 9, 10, 11, 12]],
+        dtype=pl.Array(pl.Int64, 6),
+    )
+
+    out = s.arr.reshape((2, 3))
+    assert out.dtype == pl.Array(pl.Array(pl.Int64, 3), 2)
+    assert out.to_list() == [
+        [[1, 2, 3], [4, 5, 6]],
+        None,
+        [[7, None, 9], [10, 11, 12]],
+    ]
+
+    out = s.arr.reshape((-1, 2, 1))
+    assert out.dtype == pl.Array(pl.Array(pl.Array(pl.Int64, 1), 2), 3)
+    assert out.to_list()[0] == [[[1], [2]], [[3], [4]], [[5], [6]]]
+
+    # Reshaping back to a single dimension flattens all levels.
+    assert_series_equal(out.arr.reshape((-1,)), s)
+
+    q = pl.LazyFrame({"a": s}).select(pl.col("a").arr.reshape((3, -1)))
+    assert q.schema == {"a": pl.Array(pl.Array(pl.Int64, 2), 3)}
+
+    with pytest.raises(ComputeError, match="cannot reshape"):
+        s.arr.reshape((4, -1))
+    with pytest.raises(ComputeError, match="single dimension"):
+        s.arr.reshape((-1, -1))
+
+
+def test_array_mode() -> None:
+    s = pl.Series(
+        "a",
+        [[1, 1, 2], [3, 3, 3], None, [1.5, None, None]],
+        dtype=pl.Array(pl.Float64, 3),
+    )
+    out = s.arr.mode()
+    assert out.dtype == pl.List(pl.Float64)
+    assert out.to_list() == [[1.0], [3.0], None, [None]]
+
+    s = pl.Series("a", [["x", "y", "y"], ["x", "z", "y"]], dtype=pl.Array(pl.String, 3))
+    out = s.arr.mode()
+    assert out.dtype == pl.List(pl.String)
+    assert out[0].to_list() == ["y"]
+    assert sorted(out[1].to_list()) == ["x", "y", "z"]
+
+    df = pl.DataFrame({"a": s})
+    assert df.lazy().select(pl.col("a").arr.mode()).schema["a"] == pl.List(pl.String)
+
+
+def test_array_sample() -> None:
+    df = pl.DataFrame(
+        {"a": [[1, 2, 3], [4, 5, 6], None], "n": [1, 3, 2]},
+        schema={"a": pl.Array(pl.Int64, 3), "n": pl.Int64},
+    )
+
+    out = df.select(pl.col("a").arr.sample(2, seed=1))
+    assert out.schema["a"] == pl.Array(pl.Int64, 2)
+    assert out["a"].null_count() == 1
+    for sampled, original in zip(out["a"].to_list()[:2], [[1, 2, 3], [4, 5, 6]]):
+        assert len(set(sampled)) == 2
+        assert set(sampled) <= set(original)
+
+    out = df.select(pl.col("a").arr.sample(pl.col("n"), seed=1))
+    assert out.schema["a"] == pl.List(pl.Int64)
+    assert out["a"].to_list()[2] is None
+    assert out["a"].list.len().to_list()[:2] == [1, 3]
+
+    out = df.select(pl.col("a").arr.sample(5, with_replacement=True, seed=1))
+    assert out.schema["a"] == pl.Array(pl.Int64, 5)
+
+    expected = df.select(pl.col("a").arr.sample(2, seed=1)).to_series()
+    assert_series_equal(df["a"].arr.sample(2, seed=1), expected)
+
+    with pytest.raises(pl.ShapeError):
+        df.select(pl.col("a").arr.sample(4))
+
+
+def test_array_eval() -> None:
+    df = pl.DataFrame(
+        {"a": [[1, 8, 3], [5, 4, 6], None]}, schema={"a": pl.Array(pl.Int64, 3)}
+    )
+
+    out = df.lazy().select(rank=pl.col("a").arr.eval(pl.element().rank()))
+    assert out.schema == {"rank": pl.Array(pl.Float64, 3)}
+    expected = pl.DataFrame(
+        {"rank": [[1.0, 3.0, 2.0], [2.0, 1.0, 3.0], None]},
+        schema={"rank": pl.Array(pl.Float64, 3)},
+    )
+    assert_frame_equal(out.collect(), expected)
+
+    # the width is not preserved, so the result is a list
+    expr = pl.col("a").arr.eval(pl.element().filter(pl.element() > 3))
+    out = df.lazy().select(expr)
+    assert out.schema == {"a": pl.List(pl.Int64)}
+    assert out.collect().to_series().to_list() == [[8], [5, 4, 6], None]
+
+    # the width is not known to be preserved, even if it is for all-null sub-arrays
+    expr = pl.col("a").arr.eval(pl.element().filter(pl.element().is_null()))
+    out = df.lazy().select(expr)
+    assert out.schema == {"a": pl.List(pl.Int64)}
+    assert out.collect().to_series().to_list() == [[], [], None]
+
+    out = df.lazy().select(pl.col("a").arr.eval(pl.element() * 2))
+    assert out.schema == {"a": pl.Array(pl.Int64, 3)}
+    assert out.collect().to_series().to_list() == [[2, 16, 6], [10, 8, 12], None]
+
+
+def test_array_eval_named_column() -> None:
+    s = pl.Series([[1, 2]], dtype=pl.Array(pl.Int64, 2))
+    with pytest.raises(pl.ComputeError, match="named columns are not allowed"):
+        s.arr.eval(pl.col("a") + 1)
@@ -70,3 +70,39 @@ def test_array_contains_invalid_datatype() -> None:
     df = pl.DataFrame({"a": [[1, 2], [3, 4]]}, schema={"a": pl.List(pl.Int8)})
     with pytest.raises(pl.SchemaError, match="invalid series dtype: expected `Array`"):
         df.select(pl.col("a").arr.contains(2))
+
+
+@pytest.mark.parametrize("dtype", [pl.Categorical, pl.Enum(["a", "b", "c", "d"])])
+def test_array_contains_cat(dtype: pl.DataType) -> None:
+    df = pl.DataFrame(
+        [
+            (["a", "b"], "c"),
+            (["a", "b"], "a"),
+            (["a", None], None),
+            (["a", "c"], None),
+            (["a", "d"], "d"),
+        ],
+        schema={"arr": pl.Array(dtype, 2), "x": dtype},
+    )
+    out = df.select(pl.col("arr").arr.contains(pl.col("x"))).to_series()
+    expected = pl.Series("arr", [False, True, True, False, True])
+    assert_series_equal(out, expected)
+
+
+def test_array_contains_cat_from_str() -> None:
+    df = pl.DataFrame(
+        [
+            (["a", "b"], "c"),
+            (["a", "b"], "a"),
+            (["a", None], None),
+            (["a", "c"], None),
+        ],
+        schema={"arr": pl.Array(pl.Categorical, 2), "x": pl.String},
+    )
+    out = df.select(pl.col("arr").arr.contains(pl.col("x"))).to_series()
+    expected = pl.Series("arr", [False, True, True, False])
+    assert_series_equal(out, expected)
+
+    out = df.select(pl.col("arr").arr.contains("b")).to_series()
+    expected = pl.Series("arr", [True, True, False, False])
+    assert_series_equal(out, expected)
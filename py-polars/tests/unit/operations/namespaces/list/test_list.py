@@ -220,6 +220,32 @@ def test_list_join() -> None:
     assert out.to_dict(as_series=False) == {"a": [None, None, None, "c@d", ""]}
 
 
+def test_list_join_null_replacement() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [["a", None, "b"], None, [None], ["c", "d"], []],
+            "separator": ["-", "&", " ", "@", "/"],
+        }
+    )
+    for ignore_nulls in (True, False):
+        out = df.select(
+            pl.col("a").list.join(
+                "-", ignore_nulls=ignore_nulls, null_replacement="<NA>"
+            )
+        )
+        assert out.to_dict(as_series=False) == {
+            "a": ["a-<NA>-b", None, "<NA>", "c-d", ""]
+        }
+    out = df.select(pl.col("a").list.join(pl.col("separator"), null_replacement=""))
+    assert out.to_dict(as_series=False) == {"a": ["a--b", None, "", "c@d", ""]}
+
+
+def test_list_join_separator_length() -> None:
+    s = pl.Series("a", [["a", "b"], ["c"], ["d"]])
+    with pytest.raises(pl.ComputeError, match="separator of length 1 or 3, got 2"):
+        s.list.join(pl.Series(["-", "+"]))
+
+
 def test_list_arr_empty() -> None:
     df = pl.DataFrame({"cars": [[1, 2, 3], [2, 3], [4], []]})
 
@@ -295,12 +321,12 @@ def test_list_drop_nulls() -> None:
 def test_list_sample() -> None:
     s = pl.Series("values", [[1, 2, 3, None], [None, None], [1, 2], None])
 
-    expected_sample_n = pl.Series("values", [[3, 1], [None], [2], None])
+    expected_sample_n = pl.Series("values", [[1, 2], [None], [2], None])
     assert_series_equal(
         s.list.sample(n=pl.Series([2, 1, 1, 1]), seed=1), expected_sample_n
     )
 
-    expected_sample_frac = pl.Series("values", [[3, 1], [None], [1, 2], None])
+    expected_sample_frac = pl.Series("values", [[1, 2], [None], [1, 2], None])
     assert_series_equal(
         s.list.sample(fraction=pl.Series([0.5, 0.5, 1.0, 0.3]), seed=1),
         expected_sample_frac,
@@ -318,11 +344,26 @@ def test_list_sample() -> None:
         sample_frac=pl.col("values").list.sample(fraction=pl.col("frac"), seed=1),
     )
     expected_df = pl.DataFrame(
-        {"sample_n": [[3, 1], [None], [3, 4]], "sample_frac": [[3, 1], [None], [3, 4]]}
+        {"sample_n": [[1, 2], [None], [3, 4]], "sample_frac": [[1, 2], [None], [3, 4]]}
     )
     assert_frame_equal(df, expected_df)
 
 
+def test_list_sample_seed_per_row() -> None:
+    s = pl.Series("a", [list(range(20))] * 3)
+
+    # Equal sublists are sampled with different seeds.
+    out = s.list.sample(fraction=1.0, shuffle=True, seed=0)
+    assert out[0].to_list() != out[1].to_list()
+    assert all(sorted(row) == list(range(20)) for row in out.to_list())
+
+    # The sample of a row only depends on the seed and its row index.
+    assert_series_equal(s.list.sample(fraction=1.0, shuffle=True, seed=0), out)
+    df = pl.DataFrame({"a": s, "n": [20, 20, 20]})
+    result = df.select(pl.col("a").list.sample(pl.col("n"), shuffle=True, seed=0))
+    assert_series_equal(result.to_series(), out)
+
+
 def test_list_diff() -> None:
     s = pl.Series("a", [[1, 2], [10, 2, 1]])
     expected = pl.Series("a", [[None, 1], [None, -8, -1]])
@@ -697,6 +738,381 @@ def test_list_count_matches_boolean_nulls_9141() -> None:
     assert a.select(pl.col("a").list.count_matches(True))["a"].to_list() == [1]
 
 
+def test_list_index_of() -> None:
+    s = pl.Series("a", [[1, 2, 3, 2], [3, 1], [], None, [None, 4]])
+    assert s.list.index_of(2).to_list() == [1, None, None, None, None]
+    assert s.list.index_of(None).to_list() == [None, None, None, None, 0]
+
+    df = pl.DataFrame({"a": s, "b": [3, 1, 1, 1, 4]})
+    out = df.select(pl.col("a").list.index_of(pl.col("b")))
+    assert out.schema == {"a": pl.UInt32}
+    assert out["a"].to_list() == [2, 1, None, None, 1]
+
+
+def test_list_index_of_strings() -> None:
+    s = pl.Series("a", [["x", "y"], ["y"], ["z", None]])
+    assert s.list.index_of("y").to_list() == [1, 0, None]
+
+    s = s.cast(pl.List(pl.Categorical))
+    assert s.list.index_of("z").to_list() == [None, None, 0]
+
+
+def test_list_index_of_wrong_length() -> None:
+    df = pl.DataFrame({"a": [[1], [2], [3]]})
+    with pytest.raises(pl.ComputeError, match="length 2"):
+        df.select(pl.col("a").list.index_of(pl.Series([1, 2])))
+
+
+def test_list_pad_end() -> None:
+    s = pl.Series("a", [[1, 2, 3, 4], [10, 2], [], None])
+    assert s.list.pad_end(3, 0).to_list() == [[1, 2, 3, 4], [10, 2, 0], [0, 0, 0], None]
+    out = s.list.pad_end(2, None)
+    assert out.to_list() == [[1, 2, 3, 4], [10, 2], [None, None], None]
+
+    df = pl.DataFrame({"a": s, "length": [1, 3, 2, None], "fill": [9, 8, 7, 6]})
+    out = df.select(pl.col("a").list.pad_end(pl.col("length"), pl.col("fill")))
+    assert out.schema == {"a": pl.List(pl.Int64)}
+    assert out["a"].to_list() == [[1, 2, 3, 4], [10, 2, 8], [7, 7], None]
+
+
+def test_list_pad_end_strings() -> None:
+    s = pl.Series("a", [["x"], ["x", "y", "z"]])
+    assert s.list.pad_end(2, "").to_list() == [["x", ""], ["x", "y", "z"]]
+
+
+def test_list_pad_end_truncate_to_array() -> None:
+    s = pl.Series("a", [[1, 2, 3, 4], [10, 2], []])
+    assert s.list.truncate(2).to_list() == [[1, 2], [10, 2], []]
+
+    out = s.list.pad_end(3, -1).list.truncate(3).list.to_array(3)
+    assert out.dtype == pl.Array(pl.Int64, 3)
+    assert out.to_list() == [[1, 2, 3], [10, 2, -1], [-1, -1, -1]]
+
+
+def test_list_pad_end_invalid() -> None:
+    s = pl.Series("a", [[1], [2], [3]])
+    with pytest.raises(pl.ComputeError, match="expected `length` of length 1 or 3"):
+        s.list.pad_end(pl.Series([1, 2]), 0)
+    with pytest.raises(pl.ComputeError, match="conversion"):
+        s.list.pad_end(-1, 0)
+
+
+def test_list_rolling() -> None:
+    s = pl.Series("a", [[1, 4, 2, 3], [5, None, 1], [], None])
+
+    assert s.list.rolling_sum(2).to_list() == [
+        [None, 5, 6, 5],
+        [None, None, None],
+        [],
+        None,
+    ]
+    assert s.list.rolling_min(2, min_periods=1).to_list() == [
+        [1, 1, 2, 2],
+        [5, 5, 1],
+        [],
+        None,
+    ]
+    assert s.list.rolling_max(3, center=True).to_list() == [
+        [None, 4, 4, None],
+        [None, None, None],
+        [],
+        None,
+    ]
+
+    out = s.list.rolling_mean(2, min_periods=1)
+    assert out.dtype == pl.List(pl.Float64)
+    assert out.to_list() == [[1.0, 2.5, 3.0, 2.5], [5.0, 5.0, 1.0], [], None]
+
+
+def test_list_rolling_dtypes() -> None:
+    df = pl.DataFrame({"a": [[1.0, 2.0]]}, schema={"a": pl.List(pl.Float32)})
+    out = df.select(
+        pl.col("a").list.rolling_sum(2).alias("sum"),
+        pl.col("a").list.rolling_mean(2).alias("mean"),
+    )
+    assert out.schema == {"sum": pl.List(pl.Float32), "mean": pl.List(pl.Float32)}
+
+    s = pl.Series("a", [None], dtype=pl.List(pl.Int32))
+    assert s.list.rolling_mean(2).dtype == pl.List(pl.Float64)
+    assert s.list.rolling_sum(2).dtype == pl.List(pl.Int32)
+
+    with pytest.raises(pl.InvalidOperationError, match="list.rolling_sum"):
+        pl.Series([["a"]]).list.rolling_sum(2)
+
+
+@pytest.mark.parametrize("dtype", [pl.Int8, pl.UInt8, pl.Int16, pl.UInt16])
+def test_list_rolling_small_ints(dtype: pl.PolarsDataType) -> None:
+    df = pl.DataFrame({"a": [[100, 100, 1], None]}, schema={"a": pl.List(dtype)})
+    out = df.lazy().select(
+        pl.col("a").list.rolling_sum(2).alias("sum"),
+        pl.col("a").list.rolling_mean(2).alias("mean"),
+        pl.col("a").list.rolling_min(2).alias("min"),
+        pl.col("a").list.rolling_max(2).alias("max"),
+    )
+    assert out.schema == {
+        "sum": pl.List(pl.Int64),
+        "mean": pl.List(pl.Float64),
+        "min": pl.List(dtype),
+        "max": pl.List(dtype),
+    }
+    assert out.collect().to_dict(as_series=False) == {
+        "sum": [[None, 200, 101], None],
+        "mean": [[None, 100.0, 50.5], None],
+        "min": [[None, 100, 1], None],
+        "max": [[None, 100, 100], None],
+    }
+
+
+def test_list_dedupe() -> None:
+    s = pl.Series(
+        "a", [[1, 1, 2, 1, 1], [None, None, 3, 3, None], [], None, [4], [5, 5]]
+    )
+    assert s.list.dedupe().to_list() == [[1, 2, 1], [None, 3, None], [], None, [4], [5]]
+
+    # Equal values at the end of one sublist and the start of the next are both kept.
+    s = pl.Series("a", [[1, 2], [2, 2, 3], [3]])
+    assert s.list.dedupe().to_list() == [[1, 2], [2, 3], [3]]
+
+    # Sliced input does not start at the first value.
+    assert s.slice(1).list.dedupe().to_list() == [[2, 3], [3]]
+
+
+def test_list_dedupe_dtypes() -> None:
+    s = pl.Series("a", [["x", "x", "y"], ["y", "y"]], dtype=pl.List(pl.Categorical))
+    out = s.list.dedupe()
+    assert out.dtype == pl.List(pl.Categorical)
+    assert out.to_list() == [["x", "y"], ["y"]]
+
+    df = pl.DataFrame({"a": [[[1], [1], [2]], []]})
+    out = df.select(pl.col("a").list.dedupe())
+    assert out.schema == {"a": pl.List(pl.List(pl.Int64))}
+    assert out["a"].to_list() == [[[1], [2]], []]
+
+
+def test_list_struct_field() -> None:
+    df = pl.DataFrame(
+        {
+            "events": [
+                [{"ts": 1, "kind": "a"}, {"ts": None, "kind": "b"}],
+                [],
+                None,
+                [{"ts": 3, "kind": "c"}],
+            ]
+        }
+    )
+    out = df.select(
+        pl.col("events").list.struct_field("ts"),
+        pl.col("events").list.struct_field("kind").alias("kind"),
+    )
+    assert out.schema == {"events": pl.List(pl.Int64), "kind": pl.List(pl.String)}
+    assert out.to_dict(as_series=False) == {
+        "events": [[1, None], [], None, [3]],
+        "kind": [["a", "b"], [], None, ["c"]],
+    }
+
+    # Sliced input keeps the offsets aligned with the field values.
+    s = df.to_series().slice(3)
+    assert s.list.struct_field("ts").to_list() == [[3]]
+
+
+def test_list_struct_field_lazy_schema() -> None:
+    lf = pl.LazyFrame({"a": [[{"x": 1.0}]]})
+    out = lf.select(pl.col("a").list.struct_field("x"))
+    assert out.schema == {"a": pl.List(pl.Float64)}
+    assert out.collect()["a"].to_list() == [[1.0]]
+
+
+def test_list_struct_field_invalid() -> None:
+    s = pl.Series("a", [[{"x": 1}]])
+    with pytest.raises(pl.StructFieldNotFoundError):
+        s.list.struct_field("y")
+    with pytest.raises(pl.InvalidOperationError, match="list of structs"):
+        pl.Series([[1]]).list.struct_field("x")
+
+
+def test_list_scatter() -> None:
+    df = pl.DataFrame(
+        {"a": [[1, 2, 3], [4, 5], [6], None, []], "b": [10, 20, 30, 40, 50]}
+    )
+
+    out = df.select(pl.col("a").list.scatter(-1, pl.col("b"), ignore_oob=True))
+    assert out["a"].to_list() == [[1, 2, 10], [4, 20], [30], None, []]
+
+    out = df.select(pl.col("a").list.scatter([0, 1], [None, 0], ignore_oob=True))
+    assert out["a"].to_list() == [[None, 0, 3], [None, 0], [None], None, []]
+
+    indices = pl.Series([[2, 0], [1], [], [0], None])
+    values = pl.Series([[7, 8], [None], [], [1], None])
+    out = df.select(pl.col("a").list.scatter(indices, values))
+    assert out.schema == {"a": pl.List(pl.Int64)}
+    assert out["a"].to_list() == [[8, 2, 7], [4, None], [6], None, []]
+
+
+def test_list_scatter_strings() -> None:
+    s = pl.Series("a", [["x", "y"], ["z"]])
+    assert s.list.scatter(0, "w").to_list() == [["w", "y"], ["w"]]
+
+
+def test_list_scatter_invalid() -> None:
+    s = pl.Series("a", [[1, 2], [3]])
+    with pytest.raises(pl.OutOfBoundsError, match="scatter indices are out of bounds"):
+        s.list.scatter(1, 0)
+    with pytest.raises(pl.ShapeError, match="got 1 values for 2 indices"):
+        s.list.scatter([0, 1], [0])
+    with pytest.raises(pl.InvalidOperationError, match="integer indices"):
+        s.list.scatter(pl.Series(["0"]), 0)
+
+
+def test_list_zip_with() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 3], [4, 5], None, [6]],
+            "mask": [[True, False, None], [False, True], [True], [True]],
+            "b": [[7, 8, 9], [10, 11], [12], None],
+        }
+    )
+    out = df.select(pl.col("a").list.zip_with(pl.col("mask"), pl.col("b")))
+    expected = pl.DataFrame({"a": [[1, 8, 9], [10, 5], None, None]})
+    assert_frame_equal(out, expected)
+
+    # The sublists don't need to start at the same offset.
+    s = pl.Series("a", [[0], [1, 2], [3]]).slice(1)
+    mask = pl.Series([[False, True], [True]])
+    other = pl.Series([["x", "y"], ["z"]])
+    assert s.cast(pl.List(pl.String)).list.zip_with(mask, other).to_list() == [
+        ["x", "2"],
+        ["3"],
+    ]
+
+
+def test_list_zip_with_mismatch() -> None:
+    s = pl.Series("a", [[1, 2], [3]])
+    mask = pl.Series([[True, False], [True, False]])
+    other = pl.Series([[4, 5], [6, 7]])
+    with pytest.raises(pl.ShapeError, match="different lengths in row 1: 1, 2 and 2"):
+        s.list.zip_with(mask, other)
+
+    out = s.list.zip_with(mask, other, null_on_mismatch=True)
+    assert out.to_list() == [[1, 5], None]
+
+    with pytest.raises(pl.SchemaError, match=r"mask of dtype `list\[bool\]`"):
+        s.list.zip_with(other, other)
+
+
+def test_list_value_counts() -> None:
+    s = pl.Series("a", [[3, 1, 3, None, 1, 3], [2], [], None])
+    out = s.list.value_counts()
+    assert out.dtype == pl.List(
+        pl.Struct({"value": pl.Int64, "count": pl.get_index_type()})
+    )
+    assert out.to_list() == [
+        [
+            {"value": 3, "count": 3},
+            {"value": 1, "count": 2},
+            {"value": None, "count": 1},
+        ],
+        [{"value": 2, "count": 1}],
+        [],
+        None,
+    ]
+
+    out = s.list.value_counts(sort=True, normalize=True)
+    assert out.dtype == pl.List(
+        pl.Struct({"value": pl.Int64, "proportion": pl.Float64})
+    )
+    assert out.to_list()[:2] == [
+        [
+            {"value": 3, "proportion": 0.5},
+            {"value": 1, "proportion": 2 / 6},
+            {"value": None, "proportion": 1 / 6},
+        ],
+        [{"value": 2, "proportion": 1.0}],
+    ]
+
+
+def test_list_value_counts_sort() -> None:
+    df = pl.DataFrame({"a": [["x", "y", "y", "z", "z"]]})
+    out = df.select(pl.col("a").list.value_counts(sort=True).list.struct_field("value"))
+    # Values with the same count keep the order of first occurrence.
+    assert out.to_series().to_list() == [["y", "z", "x"]]
+
+    lf = df.lazy().select(pl.col("a").list.value_counts())
+    assert lf.schema == {
+        "a": pl.List(pl.Struct({"value": pl.String, "count": pl.get_index_type()}))
+    }
+    assert lf.collect().schema == lf.schema
+
+
+def test_list_chunk() -> None:
+    s = pl.Series("a", [[1, 2, 3, 4, 5], [6, 7], None, [], [8, None, 9]])
+    out = s.list.chunk(2)
+    assert out.dtype == pl.List(pl.List(pl.Int64))
+    assert out.to_list() == [
+        [[1, 2], [3, 4], [5]],
+        [[6, 7]],
+        None,
+        [],
+        [[8, None], [9]],
+    ]
+    assert s.list.chunk(10).to_list() == [
+        [[1, 2, 3, 4, 5]],
+        [[6, 7]],
+        None,
+        [],
+        [[8, None, 9]],
+    ]
+
+    # Sliced input and logical types.
+    s = pl.Series("a", [[date(2024, 1, d) for d in range(1, 4)]] * 3).slice(1, 2)
+    out = s.list.chunk(2)
+    assert out.dtype == pl.List(pl.List(pl.Date))
+    assert out.to_list() == [
+        [[date(2024, 1, 1), date(2024, 1, 2)], [date(2024, 1, 3)]]
+    ] * 2
+
+    assert pl.Series("a", [], pl.List(pl.String)).list.chunk(2).dtype == pl.List(
+        pl.List(pl.String)
+    )
+    with pytest.raises(pl.ComputeError, match="size larger than 0"):
+        s.list.chunk(0)
+
+
+def test_list_top_k_bottom_k() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[3, 1, 5, 2, 5], [4, None, 8], [], None, [6]],
+            "k": [3, 1, 2, 2, None],
+        }
+    )
+    out = df.select(
+        top=pl.col("a").list.top_k(2),
+        bottom=pl.col("a").list.bottom_k(2),
+        top_per_row=pl.col("a").list.top_k(pl.col("k")),
+        bottom_per_row=pl.col("a").list.bottom_k(pl.col("k")),
+    )
+    expected = pl.DataFrame(
+        {
+            "top": [[5, 5], [8, 4], [], None, [6]],
+            "bottom": [[1, 2], [None, 4], [], None, [6]],
+            "top_per_row": [[5, 5, 3], [8], [], None, None],
+            "bottom_per_row": [[1, 2, 3], [None], [], None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_list_top_k_dtypes() -> None:
+    s = pl.Series("a", [["b", "a", "c"], ["x"]])
+    assert s.list.top_k(2).to_list() == [["c", "b"], ["x"]]
+    s = pl.Series("a", [[date(2024, 1, 3), date(2024, 1, 1), date(2024, 1, 2)]])
+    out = s.list.bottom_k(2)
+    assert out.dtype == pl.List(pl.Date)
+    assert out.to_list() == [[date(2024, 1, 1), date(2024, 1, 2)]]
+
+    with pytest.raises(pl.ComputeError, match="`k` of length 1 or 2, got 3"):
+        pl.Series("a", [[1], [2]]).list.top_k(pl.Series([1, 2, 3]))
+
+
 def test_list_gather_oob_10079() -> None:
     df = pl.DataFrame(
         {
@@ -770,6 +1186,32 @@ def test_list_to_array_wrong_lengths() -> None:
         s.list.to_array(3)
 
 
+def test_list_to_array_null_on_mismatch() -> None:
+    s = pl.Series("a", [[1, 2], [3], None, [4, 5, 6], [7, 8]])
+
+    result = s.list.to_array(2, null_on_mismatch=True)
+    expected = pl.Series("a", [[1, 2], None, None, None, [7, 8]], pl.Array(pl.Int64, 2))
+    assert_series_equal(result, expected)
+
+    with pytest.raises(
+        pl.ComputeError, match="not all elements have the specified width"
+    ):
+        s.list.to_array(2)
+
+
+def test_list_to_array_sliced() -> None:
+    s = pl.Series("a", [[0], [1, 2], [3, 4]]).slice(1)
+    expected = pl.Series("a", [[1, 2], [3, 4]], pl.Array(pl.Int64, 2))
+    assert_series_equal(s.list.to_array(2), expected)
+
+    # The length of the last sublist is validated as well.
+    s = pl.Series("a", [[1, 2], [3, 4, 5]])
+    with pytest.raises(
+        pl.ComputeError, match="not all elements have the specified width"
+    ):
+        s.list.to_array(2)
+
+
 def test_list_to_array_wrong_dtype() -> None:
     s = pl.Series([1.0, 2.0])
     with pytest.raises(pl.ComputeError, match="expected List dtype"):
@@ -869,6 +1311,39 @@ def test_list_gather_every() -> None:
 
     assert_frame_equal(out, expected)
 
+    with pytest.raises(pl.InvalidOperationError, match="n should be positive"):
+        df.select(pl.col("lst").list.gather_every(0))
+    with pytest.raises(pl.InvalidOperationError, match="n should be positive"):
+        df.select(pl.col("lst").list.gather_every(pl.col("n") - 1))
+
+
+def test_list_filter() -> None:
+    df = pl.DataFrame({"a": [[1, 8, 3], None, [4, None, 2], [], [9, 10]]})
+    out = df.select(pl.col("a").list.filter(pl.element() > 2))
+    expected = pl.DataFrame({"a": [[8, 3], None, [4], [], [9, 10]]})
+    assert_frame_equal(out, expected)
+
+    # Combined predicates and the group-by engine (no null sub-lists).
+    out = pl.Series("a", [[1, 2, 3, 4], [5, 6]]).list.filter(
+        (pl.element() % 2 == 0) & (pl.element() < 6)
+    )
+    assert out.to_list() == [[2, 4], []]
+
+    s = pl.Series("a", [["x", "yy"], ["zzz"]])
+    out = s.list.filter(pl.element().str.len_chars() > 1)
+    assert out.to_list() == [["yy"], ["zzz"]]
+
+    q = pl.LazyFrame({"a": [[1.0, 2.0]]}).select(pl.col("a").list.filter(pl.lit(True)))
+    assert q.schema == {"a": pl.List(pl.Float64)}
+
+
+def test_list_filter_named_column_raises() -> None:
+    df = pl.DataFrame({"a": [[1, 2]], "b": [1]})
+    with pytest.raises(
+        pl.ComputeError, match="named columns are not allowed in `list.filter`"
+    ):
+        df.select(pl.col("a").list.filter(pl.element() > pl.col("b")))
+
 
 def test_list_n_unique() -> None:
     df = pl.DataFrame(
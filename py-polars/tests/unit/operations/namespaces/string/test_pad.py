@@ -135,3 +135,66 @@ def test_str_zfill_unicode_not_respected() -> None:
 
     expected = pl.LazyFrame({"a": ["0Café", "000345", "東京", None]})
     assert_frame_equal(result, expected)
+
+
+def test_str_pad_expr() -> None:
+    df = pl.DataFrame({"a": ["a", "bc", "def", None], "n": [3, None, 1, 2]})
+
+    result = df.select(
+        start=pl.col("a").str.pad_start("n", "-"),
+        end=pl.col("a").str.pad_end(pl.col("n") + 1, "-"),
+        lit=pl.lit("x").str.pad_start("n"),
+    )
+
+    expected = pl.DataFrame(
+        {
+            "start": ["--a", None, "def", None],
+            "end": ["a---", None, "def", None],
+            "lit": ["  x", None, "x", " x"],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_str_pad_display_width() -> None:
+    s = pl.Series(["東京", "abc", "é", None])
+
+    assert s.str.pad_start(6, display_width=True).to_list() == [
+        "  東京",
+        "   abc",
+        "     é",
+        None,
+    ]
+    assert s.str.pad_end(6, "*", display_width=True).to_list() == [
+        "東京**",
+        "abc***",
+        "é*****",
+        None,
+    ]
+    # a wide fill char never pads beyond the length
+    assert s.str.pad_end(6, "日", display_width=True).to_list() == [
+        "東京日",
+        "abc日",
+        "é日日",
+        None,
+    ]
+
+
+def test_str_pad_display_width_zero_width_fill_char() -> None:
+    s = pl.Series(["a"])
+    with pytest.raises(pl.InvalidOperationError, match="display width"):
+        s.str.pad_start(3, "\u200b", display_width=True)
+
+
+def test_str_pad_length_mismatch() -> None:
+    s = pl.Series(["a", "漢", None])
+    with pytest.raises(pl.ShapeError, match="pad_start"):
+        s.str.pad_start(pl.Series([1, 2]), "*")
+    with pytest.raises(pl.ShapeError, match="pad_end"):
+        s.str.pad_end(pl.Series([1, 2]), "*")
+
+    # a single string is broadcast to the lengths
+    assert pl.Series(["a"]).str.pad_end(pl.Series([1, 3]), "*").to_list() == [
+        "a",
+        "a**",
+    ]
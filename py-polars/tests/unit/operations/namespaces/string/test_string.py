@@ -1,11 +1,18 @@
 from __future__ import annotations
 
+import textwrap
+import unicodedata
+from typing import TYPE_CHECKING
+
 import pytest
 
 import polars as pl
 import polars.selectors as cs
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars.type_aliases import TransferEncoding, UnicodeForm
+
 
 def test_str_slice() -> None:
     df = pl.DataFrame({"a": ["foobar", "barfoo"]})
@@ -107,6 +114,14 @@ def test_str_head_expr() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_str_head_non_integer() -> None:
+    s = pl.Series(["abcd", "xyz"])
+    with pytest.raises(pl.InvalidOperationError, match="expected an integer `n`"):
+        s.str.head(pl.Series([1.5, 2.0]))
+    assert s.str.head(pl.Series([1, 2], dtype=pl.UInt8)).to_list() == ["a", "xy"]
+    assert s.str.head(pl.lit(None)).to_list() == [None, None]
+
+
 @pytest.mark.parametrize(
     ("input", "n", "output"),
     [
@@ -168,6 +183,27 @@ def test_str_tail_expr() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_str_tail_non_integer() -> None:
+    s = pl.Series(["abcd", "xyz"])
+    with pytest.raises(pl.InvalidOperationError, match="expected an integer `n`"):
+        s.str.tail(pl.Series([1.5, 2.0]))
+    assert s.str.tail(pl.Series([1, 2], dtype=pl.UInt8)).to_list() == ["d", "yz"]
+
+
+def test_str_slice_expr_non_integer() -> None:
+    s = pl.Series(["abcd", "xyz"])
+    with pytest.raises(pl.InvalidOperationError, match="expected an integer `offset`"):
+        s.str.slice(pl.Series([1.5, 0.0]))
+    with pytest.raises(pl.InvalidOperationError, match="expected an integer `length`"):
+        s.str.slice(0, pl.Series(["1", "2"]))
+
+    offset = pl.Series([-1, 1], dtype=pl.Int8)
+    length = pl.Series([1, 5], dtype=pl.UInt8)
+    out = s.str.slice(offset, length)
+    assert out.to_list() == ["d", "yz"]
+    assert s.str.slice(pl.lit(None)).to_list() == [None, None]
+
+
 def test_str_slice_multibyte() -> None:
     ref = "你好世界"
     s = pl.Series([ref])
@@ -235,9 +271,11 @@ def test_str_encode() -> None:
     s = pl.Series(["foo", "bar", None])
     hex_encoded = pl.Series(["666f6f", "626172", None])
     base64_encoded = pl.Series(["Zm9v", "YmFy", None])
+    base32_encoded = pl.Series(["MZXW6===", "MJQXE===", None])
 
     assert_series_equal(s.str.encode("hex"), hex_encoded)
     assert_series_equal(s.str.encode("base64"), base64_encoded)
+    assert_series_equal(s.str.encode("base32"), base32_encoded)
     with pytest.raises(ValueError):
         s.str.encode("utf8")  # type: ignore[arg-type]
 
@@ -249,6 +287,19 @@ def test_str_decode() -> None:
 
     assert_series_equal(hex_encoded.str.decode("hex"), expected)
     assert_series_equal(base64_encoded.str.decode("base64"), expected)
+    base32_encoded = pl.Series(["MZXW6===", "MJQXE===", None])
+    assert_series_equal(base32_encoded.str.decode("base32"), expected)
+
+
+def test_str_percent_encoding() -> None:
+    s = pl.Series(["a b/c?d=é", "A-Z_a.z~09", "", None])
+    encoded = pl.Series(["a%20b%2Fc%3Fd%3D%C3%A9", "A-Z_a.z~09", "", None])
+    assert_series_equal(s.str.encode("percent"), encoded)
+    assert_series_equal(encoded.str.decode("percent").cast(pl.String), s)
+
+    # Lowercase hex digits are decoded too.
+    decoded = pl.Series(["%2f%c3%a9"]).str.decode("percent")
+    assert decoded.to_list() == ["/é".encode()]
 
 
 def test_str_decode_exception() -> None:
@@ -257,10 +308,29 @@ def test_str_decode_exception() -> None:
         s.str.decode(encoding="hex")
     with pytest.raises(pl.ComputeError):
         s.str.decode(encoding="base64")
+    with pytest.raises(pl.ComputeError):
+        s.str.decode(encoding="base32")
     with pytest.raises(ValueError):
         s.str.decode("utf8")  # type: ignore[arg-type]
 
 
+@pytest.mark.parametrize(
+    ("encoding", "values"),
+    [
+        ("base32", ["MZXW6===", "mzxw6===", "MZXW6", "MZXW6=== "]),
+        ("percent", ["a%20b", "100%", "%2", "%zz"]),
+    ],
+)
+def test_str_decode_strict(encoding: TransferEncoding, values: list[str]) -> None:
+    s = pl.Series(values)
+    with pytest.raises(pl.ComputeError, match=f"invalid `{encoding}` encoding found"):
+        s.str.decode(encoding)
+
+    out = s.str.decode(encoding, strict=False)
+    assert out.null_count() == 3
+    assert out[0] is not None
+
+
 @pytest.mark.parametrize("strict", [True, False])
 def test_str_find(strict: bool) -> None:
     df = pl.DataFrame(
@@ -345,6 +415,31 @@ def test_str_find_escaped_chars() -> None:
     )
 
 
+@pytest.mark.parametrize("literal", [True, False])
+def test_str_find_char_index(literal: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "txt": ["straße", "ñandú", "abc", None, "ééé"],
+            "pat": ["e", "d", "c", "a", None],
+        }
+    )
+    res = df.select(
+        byte_idx=pl.col("txt").str.find(pl.col("pat"), literal=literal),
+        char_idx=pl.col("txt").str.find(
+            pl.col("pat"), literal=literal, char_index=True
+        ),
+        char_idx_lit=pl.col("txt").str.find("ú", literal=literal, char_index=True),
+    )
+    assert res.to_dict(as_series=False) == {
+        "byte_idx": [6, 4, 2, None, None],
+        "char_idx": [5, 3, 2, None, None],
+        "char_idx_lit": [None, 4, None, None, None],
+    }
+
+    s = pl.Series(["añb", "ññb"])
+    assert s.str.find("b", literal=literal, char_index=True).to_list() == [2, 2]
+
+
 def test_hex_decode_return_dtype() -> None:
     data = {"a": ["68656c6c6f", "776f726c64"]}
     expr = pl.col("a").str.decode("hex")
@@ -399,6 +494,61 @@ def test_str_to_lowercase() -> None:
     assert_series_equal(s.str.to_lowercase(), expected)
 
 
+def test_str_casefold() -> None:
+    s = pl.Series(["Straße", "ẞ", "İstanbul", "ΣΊΣΥΦΟΣ", "ﬁne", "ꭰ", "ı", None])
+    expected = pl.Series(
+        ["strasse", "ss", "i\u0307stanbul", "σίσυφοσ", "fine", "Ꭰ", "ı", None]
+    )
+    assert_series_equal(s.str.casefold(), expected)
+
+
+def test_str_casefold_matches_python() -> None:
+    # Case folding is stable for assigned characters, whatever the Unicode version.
+    chars = [
+        c
+        for c in map(chr, range(0x110000))
+        if unicodedata.category(c) not in ("Cn", "Cs")
+    ]
+    # Mix ASCII and non-ASCII characters to cover the ASCII fast path.
+    values = [f"Ab{c}Cd" for c in chars]
+    s = pl.Series(values)
+    assert s.str.casefold().to_list() == [v.casefold() for v in values]
+
+
+def test_str_caseless_comparison() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["Straße", "HELLO", "ΣΊΣΥΦΟΣ", "mango", None],
+            "b": ["STRASSE", "hello", "σίσυφος", "apple", "x"],
+        }
+    )
+    out = df.select(
+        eq=pl.col("a").str.caseless_eq(pl.col("b")),
+        eq_lit=pl.col("a").str.caseless_eq("strasse"),
+        contains=pl.col("a").str.caseless_contains("SS"),
+        contains_expr=pl.col("a").str.caseless_contains(pl.col("b")),
+        starts_with=pl.col("a").str.caseless_starts_with("str"),
+        ends_with=pl.col("a").str.caseless_ends_with(pl.col("b").str.slice(-2)),
+    )
+    expected = pl.DataFrame(
+        {
+            "eq": [True, True, True, False, None],
+            "eq_lit": [True, False, False, False, None],
+            "contains": [True, False, False, False, None],
+            "contains_expr": [True, True, True, False, None],
+            "starts_with": [True, False, False, False, None],
+            "ends_with": [True, True, True, False, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    s = pl.Series(["Straße", "Apple"])
+    assert s.str.caseless_eq("STRASSE").to_list() == [True, False]
+    assert s.str.caseless_contains("PP").to_list() == [False, True]
+    assert s.str.caseless_starts_with("STR").to_list() == [True, False]
+    assert s.str.caseless_ends_with("SSE").to_list() == [True, False]
+
+
 def test_str_to_uppercase() -> None:
     s = pl.Series(["Hello", "WORLD"])
     expected = pl.Series(["HELLO", "WORLD"])
@@ -471,6 +621,13 @@ def test_str_to_integer_base_literal() -> None:
         )
 
 
+@pytest.mark.parametrize("base", [0, 1, 37, pl.Series([2, 40])])
+def test_str_to_integer_invalid_base(base: int | pl.Series) -> None:
+    s = pl.Series(["10", "zz"])
+    with pytest.raises(pl.ComputeError, match="expected a base between 2 and 36"):
+        s.str.to_integer(base=base, strict=False)
+
+
 def test_str_parse_int_deprecated() -> None:
     s = pl.Series(["110", "101", "010"])
     with pytest.deprecated_call(match="It has been renamed to `to_integer`"):
@@ -614,6 +771,22 @@ def test_str_strip_prefix_suffix_expr() -> None:
     }
 
 
+@pytest.mark.parametrize(
+    "method",
+    [
+        "strip_chars",
+        "strip_chars_start",
+        "strip_chars_end",
+        "strip_prefix",
+        "strip_suffix",
+    ],
+)
+def test_str_strip_expr_length_mismatch(method: str) -> None:
+    s = pl.Series(["xax", "yby", "zcz"])
+    with pytest.raises(pl.ComputeError, match="should have equal or unit length"):
+        getattr(s.str, method)(pl.Series(["x", "y"]))
+
+
 def test_str_strip_suffix() -> None:
     s = pl.Series(["foo:bar", "foo:barbar", "foo:foo", "bar", "", None])
     expected = pl.Series(["foo:", "foo:bar", "foo:foo", "", "", None])
@@ -739,6 +912,33 @@ def test_json_decode_primitive_to_list_11053() -> None:
     assert_frame_equal(output, expected)
 
 
+def test_json_decode_schema_mapping() -> None:
+    s = pl.Series(['{"a": 1, "b": "x"}', '{"a": 2}'])
+    out = s.str.json_decode({"a": pl.Int8, "b": pl.String})
+    assert out.dtype == pl.Struct({"a": pl.Int8, "b": pl.String})
+    assert out.to_list() == [{"a": 1, "b": "x"}, {"a": 2, "b": None}]
+
+
+def test_json_decode_non_strict() -> None:
+    s = pl.Series(["[1, 2]", "[3", None, "nonsense", "[4]", ""])
+    with pytest.raises(pl.ComputeError):
+        s.str.json_decode()
+
+    out = s.str.json_decode(strict=False)
+    assert out.dtype == pl.List(pl.Int64)
+    assert out.to_list() == [[1, 2], None, None, None, [4], None]
+
+    # malformed values are also ignored when inferring the dtype
+    out = s.str.json_decode(infer_schema_length=2, strict=False)
+    assert out.dtype == pl.List(pl.Int64)
+
+    out = pl.select(
+        pl.lit(s).str.json_decode(pl.List(pl.Int8), strict=False)
+    ).to_series()
+    assert out.dtype == pl.List(pl.Int8)
+    assert out.to_list() == [[1, 2], None, None, None, [4], None]
+
+
 def test_jsonpath_single() -> None:
     s = pl.Series(['{"a":"1"}', None, '{"a":2}', '{"a":2.1}', '{"a":true}'])
     expected = pl.Series(["1", None, "2", "2.1", "true"])
@@ -1158,6 +1358,82 @@ def test_extract_groups() -> None:
     ).to_dict(as_series=False) == {"literal": [{"foo": "foo", "bar": None}]}
 
 
+def test_extract_groups_null_and_no_match() -> None:
+    s = pl.concat(
+        [pl.Series("s", ["a1", None, "zz"]), pl.Series("s", ["b2"])], rechunk=False
+    )
+    out = s.str.extract_groups(r"(?<letter>[a-z])(?<digit>\d)")
+    assert out.dtype == pl.Struct({"letter": pl.String, "digit": pl.String})
+    assert out.to_list() == [
+        {"letter": "a", "digit": "1"},
+        {"letter": None, "digit": None},
+        {"letter": None, "digit": None},
+        {"letter": "b", "digit": "2"},
+    ]
+
+
+def test_str_escape_regex() -> None:
+    s = pl.Series(["abc", "1+1=2", r"(a|b)*[c]?\d{2}^$.", "", None])
+    escaped = s.str.escape_regex()
+    assert escaped.to_list() == [
+        "abc",
+        r"1\+1=2",
+        r"\(a\|b\)\*\[c\]\?\\d\{2\}\^\$\.",
+        "",
+        None,
+    ]
+
+    # every escaped string only matches itself
+    df = pl.DataFrame(
+        {"text": ["a.c", "abc", "1+1", "11"], "pat": ["a.c", "a.c", "1+1", "1+1"]}
+    )
+    out = df.select(pl.col("text").str.contains(pl.col("pat").str.escape_regex()))
+    assert out.to_series().to_list() == [True, False, True, False]
+
+
+def test_str_wrap() -> None:
+    s = pl.Series(
+        [
+            "The quick brown fox jumps over the lazy dog",
+            "short",
+            None,
+            "  indented text that wraps",
+            "line one here\nline two here",
+            "ab cdefghijklmnopqrstuvw",
+        ]
+    )
+    assert s.str.wrap(10).to_list() == [
+        "The quick\nbrown fox\njumps over\nthe lazy\ndog",
+        "short",
+        None,
+        "  indented\ntext that\nwraps",
+        "line one\nhere\nline two\nhere",
+        "ab cdefghi\njklmnopqrs\ntuvw",
+    ]
+    assert s.str.wrap(10, break_long_words=False).to_list()[-1] == (
+        "ab\ncdefghijklmnopqrstuvw"
+    )
+
+
+def test_str_wrap_tabs() -> None:
+    s = pl.Series(["a\tb\tc d e f g"])
+    assert s.str.wrap(10).to_list() == ["a       b\nc d e f g"]
+    assert s.str.wrap(10, tab_size=4).to_list() == ["a   b   c\nd e f g"]
+    assert s.str.wrap(10, tab_size=0).to_list() == ["abc d e f\ng"]
+
+
+def test_str_wrap_matches_textwrap() -> None:
+    text = "Lorem ipsum dolor sit amet, consecteturadipiscingelit, sed do eiusmod"
+    for width in [1, 5, 12, 30]:
+        result = pl.Series([text]).str.wrap(width).item()
+        assert result == textwrap.fill(text, width)
+
+
+def test_str_wrap_invalid_width() -> None:
+    with pytest.raises(pl.ComputeError, match="width larger than 0"):
+        pl.Series(["a"]).str.wrap(0)
+
+
 def test_starts_ends_with() -> None:
     df = pl.DataFrame(
         {
@@ -1441,6 +1717,12 @@ def test_splitn() -> None:
     assert_frame_equal(df["x"].str.splitn("", 2).to_frame().unnest("x"), expected)
 
 
+def test_splitn_zero() -> None:
+    s = pl.Series(["a_b", None])
+    with pytest.raises(pl.ComputeError, match="expected `n` larger than 0"):
+        s.str.splitn("_", 0)
+
+
 def test_splitn_expr() -> None:
     df = pl.DataFrame(
         {
@@ -1483,6 +1765,27 @@ def test_titlecase() -> None:
     }
 
 
+def test_titlecase_word_boundaries() -> None:
+    s = pl.Series(
+        [
+            "jean-luc picard",
+            "they're (not) here",
+            "1st place, 2ND PLACE",
+            "ὈΔΥΣΣΕΎΣ",
+            "",
+            None,
+        ]
+    )
+    assert s.str.to_titlecase().to_list() == [
+        "Jean-Luc Picard",
+        "They're (Not) Here",
+        "1st Place, 2nd Place",
+        "Ὀδυσσεύς",
+        "",
+        None,
+    ]
+
+
 def test_string_replace_with_nulls_10124() -> None:
     df = pl.DataFrame({"col1": ["S", "S", "S", None, "S", "S", "S", "S"]})
 
@@ -1515,6 +1818,59 @@ def test_string_extract_groups_lazy_schema_10305() -> None:
     assert df.schema == {"candidate": pl.String, "ref": pl.String}
 
 
+def test_str_levenshtein() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["kitten", "flaw", "", "straße", "abc", None],
+            "b": ["sitting", "lawn", "abc", "strasse", None, "abc"],
+        }
+    )
+    res = df.select(
+        dist=pl.col("a").str.levenshtein(pl.col("b")),
+        dist_rev=pl.col("b").str.levenshtein(pl.col("a")),
+        dist_lit=pl.col("a").str.levenshtein("abc"),
+    )
+    assert res.to_dict(as_series=False) == {
+        "dist": [3, 2, 3, 2, None, None],
+        "dist_rev": [3, 2, 3, 2, None, None],
+        "dist_lit": [6, 4, 3, 5, 0, None],
+    }
+    assert res.schema["dist"] == pl.UInt32
+
+
+def test_str_hamming() -> None:
+    s = pl.Series(["karolin", "kärolin", "abc", "", None])
+    assert s.str.hamming("kathrin").to_list() == [3, 4, None, None, None]
+    assert s.str.hamming(s).to_list() == [0, 0, 0, 0, None]
+
+
+def test_str_jaro_winkler() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["martha", "dwayne", "dixon", "abc", "", "", "ñandú", None],
+            "b": ["marhta", "duane", "dicksonx", "xyz", "", "a", "ñandu", "a"],
+        }
+    )
+    res = df.select(sim=pl.col("a").str.jaro_winkler(pl.col("b")))
+    expected = [0.961111, 0.84, 0.813333, 0.0, 1.0, 0.0, 0.92, None]
+    assert_series_equal(
+        res.to_series(), pl.Series("sim", expected), check_exact=False, atol=1e-6
+    )
+    assert_series_equal(
+        df.select(pl.col("b").str.jaro_winkler(pl.col("a"))).to_series(),
+        pl.Series("b", expected),
+        check_exact=False,
+        atol=1e-6,
+    )
+
+
+@pytest.mark.parametrize("method", ["levenshtein", "hamming", "jaro_winkler"])
+def test_str_similarity_length_mismatch(method: str) -> None:
+    s = pl.Series(["a", "b", "c"])
+    with pytest.raises(pl.ShapeError):
+        getattr(s.str, method)(pl.Series(["a", "b"]))
+
+
 def test_string_reverse() -> None:
     df = pl.DataFrame(
         {
@@ -1535,6 +1891,30 @@ def test_string_reverse() -> None:
     assert_frame_equal(result, expected)
 
 
+@pytest.mark.parametrize(
+    ("form", "expected"),
+    [
+        ("NFC", ["\u00e9", "\u00e9x", "\ufb01", "\u2460", "abc", None]),
+        ("NFD", ["e\u0301", "e\u0301x", "\ufb01", "\u2460", "abc", None]),
+        ("NFKC", ["\u00e9", "\u00e9x", "fi", "1", "abc", None]),
+        ("NFKD", ["e\u0301", "e\u0301x", "fi", "1", "abc", None]),
+    ],
+)
+def test_string_normalize(form: UnicodeForm, expected: list[str | None]) -> None:
+    s = pl.Series(["\u00e9", "e\u0301x", "\ufb01", "\u2460", "abc", None])
+    assert s.str.normalize(form).to_list() == expected
+    assert pl.select(pl.lit(s).str.normalize(form)).to_series().to_list() == expected
+
+    # a chunk of only ASCII strings is returned as-is
+    s = pl.concat([pl.Series(["abc", None]), s], rechunk=False)
+    assert s.str.normalize(form).to_list() == ["abc", None, *expected]
+
+
+def test_string_normalize_invalid_form() -> None:
+    with pytest.raises(ValueError, match="`form` must be one of"):
+        pl.Series(["a"]).str.normalize("NFX")  # type: ignore[arg-type]
+
+
 @pytest.mark.parametrize(
     ("data", "expected_dat"),
     [
@@ -1552,3 +1932,92 @@ def test_replace_lit_n_char_13385(
     res = s.str.replace("a", "b", literal=True)
     expected_s = pl.Series(expected_dat, dtype=pl.String)
     assert_series_equal(res, expected_s)
+
+
+def test_contains_any() -> None:
+    s = pl.Series(["Hello World", "foo bar", None, "BAR", ""])
+    assert s.str.contains_any(["world", "bar"]).to_list() == [
+        False,
+        True,
+        None,
+        False,
+        False,
+    ]
+    assert s.str.contains_any(
+        ["world", "bar"], ascii_case_insensitive=True
+    ).to_list() == [True, True, None, True, False]
+
+    # null patterns are ignored, and without patterns nothing matches
+    assert s.str.contains_any(pl.Series(["o W", None])).to_list() == [
+        True,
+        False,
+        None,
+        False,
+        False,
+    ]
+    assert s.str.contains_any(pl.Series([], dtype=pl.String)).to_list() == [
+        False,
+        False,
+        None,
+        False,
+        False,
+    ]
+
+
+def test_contains_any_column() -> None:
+    # all patterns of the column are searched for in every string
+    df = pl.DataFrame({"s": ["ab", "cd", "ef"], "pat": ["d", "x", "a"]})
+    out = df.lazy().select(pl.col("s").str.contains_any(pl.col("pat"))).collect()
+    assert out.to_series().to_list() == [True, True, False]
+
+
+def test_replace_many() -> None:
+    df = pl.DataFrame({"text": ["a cat and a dog", "A Cat", "", None]})
+
+    # All patterns are replaced in a single pass, so "dog" is not replaced again.
+    out = df.select(pl.col("text").str.replace_many(["cat", "dog"], ["dog", "cat"]))
+    assert out.to_series().to_list() == ["a dog and a cat", "A Cat", "", None]
+
+    out = df.select(
+        pl.col("text").str.replace_many(
+            ["cat", "dog"], "pet", ascii_case_insensitive=True
+        )
+    )
+    assert out.to_series().to_list() == ["a pet and a pet", "A pet", "", None]
+
+    out = df.select(pl.col("text").str.replace_many({"cat": "dog", "dog": "cat"}))
+    assert out.to_series().to_list() == ["a dog and a cat", "A Cat", "", None]
+
+
+def test_replace_many_invalid() -> None:
+    s = pl.Series("text", ["a cat"])
+    with pytest.raises(TypeError, match="`replace_with` must be given"):
+        s.str.replace_many(["cat"])
+    with pytest.raises(pl.InvalidOperationError, match="same amount of patterns"):
+        s.str.replace_many(["cat", "dog", "fish"], ["dog", "cat"])
+
+
+def test_extract_many() -> None:
+    df = pl.DataFrame({"values": ["discontent", "Disco Inferno", "", None]})
+    patterns = ["winter", "disco", "onte", "discontent"]
+
+    out = df.select(pl.col("values").str.extract_many(patterns))
+    assert out.to_series().to_list() == [["disco"], [], [], None]
+
+    out = df.select(
+        pl.col("values").str.extract_many(
+            patterns, ascii_case_insensitive=True, overlapping=True
+        )
+    )
+    assert out.schema == {"values": pl.List(pl.String)}
+    assert out.to_series().to_list() == [
+        ["disco", "onte", "discontent"],
+        ["Disco"],
+        [],
+        None,
+    ]
+
+    # Patterns can also be a column.
+    df = pl.DataFrame({"values": ["ab", "ba", "abab"], "patterns": ["a", "b", None]})
+    out = df.select(pl.col("values").str.extract_many(pl.col("patterns")))
+    assert out.to_series().to_list() == [["a", "b"], ["b", "a"], ["a", "b", "a", "b"]]
@@ -198,7 +198,7 @@ def test_add_business_days_invalid() -> None:
     df = pl.DataFrame({"start": [date(2020, 1, 1)]})
     with pytest.raises(
         pl.InvalidOperationError,
-        match="expected Int64, Int32, UInt64, or UInt32, got f64",
+        match="expected an integer `n`, got f64",
     ):
         df.select(
             result=pl.col("start").dt.add_business_days(1.5, week_mask=[True] * 7)
@@ -210,6 +210,19 @@ def test_add_business_days_invalid() -> None:
         df.select(result=pl.col("start").dt.add_business_days(1, roll="cabbage"))  # type: ignore[arg-type]
 
 
+@pytest.mark.parametrize("dtype", [pl.Int8, pl.Int16, pl.UInt8, pl.UInt16, pl.UInt64])
+def test_add_business_days_integer_dtypes(dtype: pl.PolarsDataType) -> None:
+    df = pl.DataFrame({"start": [date(2020, 1, 3)], "n": pl.Series([1], dtype=dtype)})
+    result = df.select(result=pl.col("start").dt.add_business_days("n"))["result"]
+    assert result.to_list() == [date(2020, 1, 6)]
+
+
+def test_add_business_days_n_out_of_bounds() -> None:
+    df = pl.DataFrame({"start": [date(2020, 1, 3)], "n": [2**40]})
+    with pytest.raises(pl.ComputeError, match="conversion from `i64` to `i32` failed"):
+        df.select(result=pl.col("start").dt.add_business_days("n"))
+
+
 def test_add_business_days_w_nulls() -> None:
     df = pl.DataFrame(
         {
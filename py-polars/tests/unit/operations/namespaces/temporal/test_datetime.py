@@ -304,6 +304,26 @@ def test_month_end_date(dt: date, expected: date) -> None:
     assert result == expected
 
 
+@pytest.mark.parametrize(
+    "dt", [datetime(2023, 9, 15, 0, 30), datetime(2023, 9, 30, 0, 30)]
+)
+def test_month_end_dst_in_next_month(dt: datetime) -> None:
+    # 2023-10-01 00:30 doesn't exist in Asuncion, which mustn't affect September.
+    ser = pl.Series([dt]).dt.replace_time_zone("America/Asuncion")
+    result = ser.dt.month_end().item()
+    assert result == datetime(2023, 9, 30, 0, 30, tzinfo=ZoneInfo("America/Asuncion"))
+
+
+def test_month_start_end_non_existent() -> None:
+    ser = pl.Series([datetime(2023, 10, 15, 0, 30)])
+    ser = ser.dt.replace_time_zone("America/Asuncion")
+    with pytest.raises(ComputeError, match="is non-existent in time zone"):
+        ser.dt.month_start()
+    assert ser.dt.month_end().item() == datetime(
+        2023, 10, 31, 0, 30, tzinfo=ZoneInfo("America/Asuncion")
+    )
+
+
 def test_month_start_end_invalid() -> None:
     ser = pl.Series([time(1, 2, 3)])
     with pytest.raises(
@@ -318,6 +338,63 @@ def test_month_start_end_invalid() -> None:
         ser.dt.month_end()
 
 
+@pytest.mark.parametrize(
+    ("dt", "fiscal_year_start", "expected_start", "expected_end"),
+    [
+        (date(2022, 3, 15), 1, date(2022, 1, 1), date(2022, 3, 31)),
+        (date(2022, 4, 1), 1, date(2022, 4, 1), date(2022, 6, 30)),
+        (date(2022, 12, 31), 1, date(2022, 10, 1), date(2022, 12, 31)),
+        (date(2022, 3, 15), 4, date(2022, 1, 1), date(2022, 3, 31)),
+        (date(2022, 1, 15), 2, date(2021, 11, 1), date(2022, 1, 31)),
+        (date(2022, 11, 30), 12, date(2022, 9, 1), date(2022, 11, 30)),
+    ],
+)
+def test_quarter_start_end_date(
+    dt: date, fiscal_year_start: int, expected_start: date, expected_end: date
+) -> None:
+    ser = pl.Series([dt, None])
+    result = ser.dt.quarter_start(fiscal_year_start=fiscal_year_start)
+    assert result.to_list() == [expected_start, None]
+    result = ser.dt.quarter_end(fiscal_year_start=fiscal_year_start)
+    assert result.to_list() == [expected_end, None]
+
+
+@pytest.mark.parametrize(
+    ("tzinfo", "time_zone"),
+    [
+        (None, None),
+        (ZoneInfo("Asia/Kathmandu"), "Asia/Kathmandu"),
+    ],
+)
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+def test_quarter_start_end_datetime(
+    time_unit: TimeUnit, tzinfo: ZoneInfo | None, time_zone: str | None
+) -> None:
+    ser = (
+        pl.Series([datetime(2022, 5, 15, 3, 2, 1, 123000)])
+        .dt.replace_time_zone(time_zone)
+        .dt.cast_time_unit(time_unit)
+    )
+    result = ser.dt.quarter_start().item()
+    assert result == datetime(2022, 4, 1, 3, 2, 1, 123000, tzinfo=tzinfo)
+    result = ser.dt.quarter_end(fiscal_year_start=7).item()
+    assert result == datetime(2022, 6, 30, 3, 2, 1, 123000, tzinfo=tzinfo)
+
+
+def test_quarter_start_end_invalid() -> None:
+    ser = pl.Series([time(1, 2, 3)])
+    with pytest.raises(
+        InvalidOperationError,
+        match=r"`quarter_start` operation not supported for dtype `time` \(expected: date/datetime\)",
+    ):
+        ser.dt.quarter_start()
+    with pytest.raises(
+        InvalidOperationError,
+        match="`fiscal_year_start` must be a month from 1 to 12, got 13",
+    ):
+        pl.Series([date(2022, 1, 1)]).dt.quarter_end(fiscal_year_start=13)
+
+
 @pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
 def test_base_utc_offset(time_unit: TimeUnit) -> None:
     ser = pl.datetime_range(
@@ -450,6 +527,106 @@ def test_duration_extract_times(
     assert_series_equal(getattr(duration.dt, unit_attr)(), expected)
 
 
+@pytest.mark.parametrize(
+    ("unit_attr", "expected"),
+    [
+        ("total_days", [1.5, -0.25, None]),
+        ("total_hours", [36.0, -6.0, None]),
+        ("total_minutes", [2160.0, -360.0, None]),
+        ("total_seconds", [129600.0, -21600.0, None]),
+        ("total_milliseconds", [129600e3, -21600e3, None]),
+        ("total_microseconds", [129600e6, -21600e6, None]),
+        ("total_nanoseconds", [129600e9, -21600e9, None]),
+    ],
+)
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+def test_duration_extract_times_fractional(
+    unit_attr: str, expected: list[float | None], time_unit: TimeUnit
+) -> None:
+    duration = pl.Series(
+        [timedelta(hours=36), timedelta(hours=-6), None],
+        dtype=pl.Duration(time_unit),
+    )
+    result = getattr(duration.dt, unit_attr)(fractional=True)
+    assert_series_equal(result, pl.Series(expected, dtype=pl.Float64))
+
+    result = pl.select(getattr(pl.lit(duration).dt, unit_attr)(fractional=True))
+    assert result.dtypes == [pl.Float64]
+
+    # Without `fractional`, the totals are truncated towards zero.
+    result = (duration - timedelta(microseconds=1)).dt.total_days()
+    assert_series_equal(result, pl.Series([1, 0, None], dtype=pl.Int64))
+
+
+@pytest.mark.parametrize(
+    ("format", "expected"),
+    [
+        ("polars", ["2d 3h 15m", "-1m -30s -500ms", None, None]),
+        ("iso", ["P2DT3H15M", "-PT1M30.5S", "PT0S", None]),
+    ],
+)
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+def test_duration_to_string(
+    format: str, expected: list[str | None], time_unit: TimeUnit
+) -> None:
+    duration = pl.Series(
+        [
+            timedelta(days=2, hours=3, minutes=15),
+            -timedelta(seconds=90, milliseconds=500),
+            timedelta(0),
+            None,
+        ],
+        dtype=pl.Duration(time_unit),
+    )
+    result = duration.dt.duration_to_string(format)  # type: ignore[arg-type]
+    if format == "polars":
+        # Zero is written in the time unit.
+        expected = [*expected[:2], f"0{time_unit}".replace("us", "µs"), None]
+    assert_series_equal(result, pl.Series(expected, dtype=pl.String))
+
+    expr = pl.lit(duration).dt.duration_to_string(format)  # type: ignore[arg-type]
+    assert pl.select(expr).dtypes == [pl.String]
+
+
+def test_duration_to_string_precision() -> None:
+    duration = pl.Series(
+        [timedelta(days=1, hours=2, minutes=3, seconds=4, microseconds=5), None],
+        dtype=pl.Duration("ns"),
+    )
+    assert duration.dt.duration_to_string(precision="d").to_list() == ["1d", None]
+    assert duration.dt.duration_to_string(precision="m").to_list() == [
+        "1d 2h 3m",
+        None,
+    ]
+    assert duration.dt.duration_to_string("iso", precision="d").to_list() == [
+        "P1D",
+        None,
+    ]
+    assert duration.dt.duration_to_string("iso", precision="ms").to_list() == [
+        "P1DT2H3M4S",
+        None,
+    ]
+    assert duration.dt.duration_to_string("iso", precision="us").to_list() == [
+        "P1DT2H3M4.000005S",
+        None,
+    ]
+
+    # Durations that are smaller than the precision are written as zero.
+    duration = pl.Series([timedelta(minutes=5)])
+    assert duration.dt.duration_to_string(precision="h").to_list() == ["0h"]
+    assert duration.dt.duration_to_string("iso", precision="d").to_list() == ["P0D"]
+
+
+def test_duration_to_string_invalid() -> None:
+    duration = pl.Series([timedelta(days=1)])
+    with pytest.raises(ValueError, match="`format` must be one of"):
+        duration.dt.duration_to_string("unknown")  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="`precision` must be one of"):
+        duration.dt.duration_to_string(precision="y")  # type: ignore[arg-type]
+    with pytest.raises(pl.SchemaError):
+        pl.Series([1]).dt.duration_to_string()
+
+
 @pytest.mark.parametrize(
     ("unit_attr", "expected"),
     [
@@ -646,6 +823,62 @@ def test_round_negative() -> None:
         pl.Series([datetime(1895, 5, 7)]).dt.round("-1m")
 
 
+def test_round_offset() -> None:
+    s = pl.Series(
+        [
+            datetime(2024, 1, 1, 10, 12),
+            datetime(2024, 1, 1, 10, 13),
+            datetime(2024, 1, 1, 10, 57),
+        ]
+    )
+    expected = pl.Series(
+        [
+            datetime(2024, 1, 1, 10, 5),
+            datetime(2024, 1, 1, 10, 20),
+            datetime(2024, 1, 1, 10, 50),
+        ]
+    )
+    assert_series_equal(s.dt.round("15m", "5m"), expected)
+    assert_series_equal(s.dt.round("15m", "-10m"), expected)
+    assert_series_equal(s.dt.round("15m", timedelta(minutes=5)), expected)
+
+
+@pytest.mark.parametrize("time_zone", [None, "Europe/Amsterdam"])
+def test_round_week_start(time_zone: str | None) -> None:
+    # 2024-04-28 and 2024-05-05 are Sundays
+    s = pl.Series(
+        [
+            datetime(2024, 4, 28),
+            datetime(2024, 5, 1),
+            datetime(2024, 5, 2),
+        ]
+    ).dt.replace_time_zone(time_zone)
+    result = s.dt.round("1w", week_start=7)
+    expected = pl.Series(
+        [datetime(2024, 4, 28), datetime(2024, 4, 28), datetime(2024, 5, 5)]
+    ).dt.replace_time_zone(time_zone)
+    assert_series_equal(result, expected)
+    result = s.dt.date().dt.round("1w", week_start=7)
+    assert_series_equal(result, expected.dt.date())
+
+    # weeks start on Monday by default
+    result = s.dt.round("1w")
+    expected = pl.Series(
+        [datetime(2024, 4, 29), datetime(2024, 4, 29), datetime(2024, 4, 29)]
+    ).dt.replace_time_zone(time_zone)
+    assert_series_equal(result, expected)
+
+    # the week start is only used for weekly buckets
+    assert_series_equal(s.dt.round("1d", week_start=7), s)
+
+
+def test_round_week_start_invalid() -> None:
+    s = pl.Series([date(2024, 5, 1)])
+    for week_start in [0, 8]:
+        with pytest.raises(ComputeError, match="`week_start` must be between 1"):
+            s.dt.round("1w", week_start=week_start)
+
+
 @pytest.mark.parametrize(
     ("time_unit", "date_in_that_unit"),
     [
@@ -677,6 +910,64 @@ def test_epoch_matches_timestamp() -> None:
     )
 
 
+def test_epoch_origin() -> None:
+    dates = pl.Series([date(2024, 1, 1), date(2023, 12, 31), None])
+    origin = date(2024, 1, 1)
+    assert dates.dt.epoch("d", origin=origin).to_list() == [0, -1, None]
+    assert dates.dt.epoch("1h", origin=datetime(2024, 1, 1, 1)).to_list() == [
+        -1,
+        -25,
+        None,
+    ]
+
+    times = pl.Series([datetime(2024, 3, 1), datetime(2024, 3, 12, 12)])
+    origin = date(2024, 2, 26)
+    assert times.dt.epoch("1w", origin=origin).to_list() == [0, 2]
+    assert times.dt.epoch("15m", origin=datetime(2024, 3, 1)).to_list() == [0, 1104]
+    assert times.dt.epoch(timedelta(days=2), origin=origin).to_list() == [2, 7]
+
+    result = pl.select(
+        pl.lit(times).dt.epoch("1h", origin=pl.lit(datetime(2024, 3, 1)))
+    ).to_series()
+    assert_series_equal(result, pl.Series([0, 276]), check_names=False)
+
+
+def test_epoch_fractional() -> None:
+    times = pl.Series([datetime(1969, 12, 31, 23, 45), datetime(1970, 1, 1, 1, 30)])
+    assert times.dt.epoch("h").to_list() == [-1, 1]
+    assert times.dt.epoch("h", fractional=True).to_list() == [-0.25, 1.5]
+    assert times.dt.epoch("2h", fractional=True).to_list() == [-0.125, 0.75]
+    assert times.dt.epoch("1500us", origin=datetime(1970, 1, 1)).to_list() == [
+        -600000,
+        3600000,
+    ]
+    assert pl.select(pl.lit(times).dt.epoch("h", fractional=True)).dtypes == [
+        pl.Float64
+    ]
+
+
+def test_epoch_origin_time_zone() -> None:
+    times = pl.Series([datetime(2024, 3, 31, 12)]).dt.replace_time_zone(
+        "Europe/Amsterdam"
+    )
+    # A naive origin is a local time, and a time-zone-aware origin is an instant.
+    assert times.dt.epoch("h", origin=date(2024, 3, 31)).to_list() == [11]
+    origin = datetime(2024, 3, 31, tzinfo=ZoneInfo("UTC"))
+    assert times.dt.epoch("h", origin=origin).to_list() == [10]
+
+    with pytest.raises(InvalidOperationError, match="cannot use an `origin`"):
+        pl.Series([datetime(2024, 1, 1)]).dt.epoch("h", origin=origin)
+
+
+@pytest.mark.parametrize("time_unit", ["1mo", "-1h", "2i", "foo", "h2", "1h-"])
+def test_epoch_invalid_time_unit(time_unit: str) -> None:
+    s = pl.Series([datetime(2024, 1, 1)])
+    with pytest.raises(InvalidOperationError):
+        s.dt.epoch(time_unit, fractional=True)
+    with pytest.raises(InvalidOperationError):
+        s.dt.epoch(time_unit)
+
+
 @pytest.mark.parametrize(
     ("tzinfo", "time_zone"),
     [(None, None), (ZoneInfo("Asia/Kathmandu"), "Asia/Kathmandu")],
@@ -769,6 +1060,37 @@ def test_combine_lazy_schema_date(time_unit: TimeUnit) -> None:
     assert result == expected
 
 
+def test_combine_time_zone() -> None:
+    df = pl.DataFrame(
+        {
+            "dt": [date(2022, 10, 10), date(2022, 7, 5)],
+            "tm": [time(1, 2, 3, 456789), time(7, 8, 9, 101)],
+        }
+    )
+    expr = pl.col("dt").dt.combine(pl.col("tm"), "ns", time_zone="Asia/Kathmandu")
+    result = df.select(expr)
+    expected = pl.DataFrame(
+        {
+            "dt": [
+                datetime(2022, 10, 10, 1, 2, 3, 456789),
+                datetime(2022, 7, 5, 7, 8, 9, 101),
+            ]
+        },
+        schema={"dt": pl.Datetime("ns")},
+    ).with_columns(pl.col("dt").dt.replace_time_zone("Asia/Kathmandu"))
+    assert_frame_equal(result, expected)
+    assert df.lazy().select(expr).schema == expected.schema
+
+
+def test_combine_time_zone_aware_local_date() -> None:
+    # 23:00 in New York is already the next day in UTC.
+    s = pl.Series([datetime(2022, 1, 1, 23)]).dt.replace_time_zone("America/New_York")
+    result = s.dt.combine(time(1, 2, 3)).item()
+    assert result == datetime(2022, 1, 1, 1, 2, 3, tzinfo=ZoneInfo("America/New_York"))
+    result = s.dt.combine(time(1, 2, 3), time_zone="Asia/Tokyo").item()
+    assert result == datetime(2022, 1, 1, 1, 2, 3, tzinfo=ZoneInfo("Asia/Tokyo"))
+
+
 def test_is_leap_year() -> None:
     assert pl.datetime_range(
         datetime(1990, 1, 1), datetime(2004, 1, 1), "1y", eager=True
@@ -791,12 +1113,137 @@ def test_is_leap_year() -> None:
     ]
 
 
+def test_is_leap_year_time_zone() -> None:
+    s = pl.Series([datetime(2023, 12, 31, 15, 30)]).dt.replace_time_zone("UTC")
+    result = s.dt.convert_time_zone("Asia/Tokyo").dt.is_leap_year()
+    assert result.to_list() == [True]
+
+
+@pytest.mark.parametrize(
+    ("predicate", "expected"),
+    [
+        ("is_month_start", [False, True, False, True, False, True, False]),
+        ("is_month_end", [True, False, True, False, True, False, True]),
+        ("is_quarter_start", [False, False, False, True, False, True, False]),
+        ("is_quarter_end", [False, False, True, False, True, False, False]),
+        ("is_year_start", [False, False, False, False, False, True, False]),
+        ("is_year_end", [False, False, False, False, True, False, False]),
+    ],
+)
+@pytest.mark.parametrize("time_zone", [None, "Asia/Kathmandu"])
+def test_calendar_predicates(
+    predicate: str, expected: list[bool], time_zone: str | None
+) -> None:
+    dates = [
+        date(2023, 1, 31),
+        date(2023, 2, 1),
+        date(2023, 3, 31),
+        date(2023, 4, 1),
+        date(2023, 12, 31),
+        date(2024, 1, 1),
+        date(2024, 2, 29),
+    ]
+    s = pl.Series(dates + [None])
+    assert getattr(s.dt, predicate)().to_list() == [*expected, None]
+
+    # Datetimes are checked by their local date, not by their UTC date.
+    s_dt = (s.cast(pl.Datetime) + timedelta(minutes=30)).dt.replace_time_zone(time_zone)
+    result = s_dt.to_frame("a").select(getattr(pl.col("a").dt, predicate)())["a"]
+    assert result.to_list() == [*expected, None]
+
+
+@pytest.mark.parametrize(
+    ("predicate", "expected"),
+    [
+        ("is_quarter_start", [False, True, True, False, True, False]),
+        ("is_quarter_end", [True, False, False, True, False, True]),
+        ("is_year_start", [False, True, False, False, False, False]),
+        ("is_year_end", [True, False, False, False, False, True]),
+    ],
+)
+def test_calendar_predicates_fiscal_year(predicate: str, expected: list[bool]) -> None:
+    dates = [
+        date(2023, 3, 31),
+        date(2023, 4, 1),
+        date(2023, 7, 1),
+        date(2023, 9, 30),
+        date(2024, 1, 1),
+        date(2024, 3, 31),
+    ]
+    s = pl.Series(dates)
+    result = getattr(s.dt, predicate)(fiscal_year_start=4)
+    assert result.to_list() == expected
+
+
+def test_is_business_day() -> None:
+    s = pl.Series(
+        [date(2023, 12, 29), date(2023, 12, 30), date(2024, 1, 1), date(2024, 1, 2)]
+    ).extend_constant(None, 1)
+    assert s.dt.is_business_day().to_list() == [True, False, True, True, None]
+
+    result = s.dt.is_business_day(
+        week_mask=(True, True, True, True, False, True, False),
+        holidays=[date(2024, 1, 1), date(2024, 1, 6)],
+    )
+    assert result.to_list() == [False, True, False, True, None]
+
+    # Datetimes are checked by their local date.
+    s_tz = pl.Series([datetime(2024, 1, 2, 0, 30)]).dt.replace_time_zone("Asia/Tokyo")
+    result = s_tz.dt.is_business_day(holidays=[date(2024, 1, 1)])
+    assert result.to_list() == [True]
+
+
+def test_is_business_day_week_mask_invalid() -> None:
+    s = pl.Series([date(2024, 1, 1)])
+    with pytest.raises(
+        pl.ComputeError, match="`week_mask` must have at least one business day"
+    ):
+        s.dt.is_business_day(week_mask=[False] * 7)
+
+
 def test_quarter() -> None:
     assert pl.datetime_range(
         datetime(2022, 1, 1), datetime(2022, 12, 1), "1mo", eager=True
     ).dt.quarter().to_list() == [1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4]
 
 
+@pytest.mark.parametrize(
+    ("fiscal_year_start", "expected"),
+    [
+        (4, [4, 4, 4, 1, 1, 1, 2, 2, 2, 3, 3, 3]),
+        (7, [3, 3, 3, 4, 4, 4, 1, 1, 1, 2, 2, 2]),
+        (10, [2, 2, 2, 3, 3, 3, 4, 4, 4, 1, 1, 1]),
+        (12, [1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4, 1]),
+    ],
+)
+def test_quarter_fiscal_year(fiscal_year_start: int, expected: list[int]) -> None:
+    s = pl.date_range(date(2022, 1, 1), date(2022, 12, 1), "1mo", eager=True)
+    assert s.dt.quarter(fiscal_year_start=fiscal_year_start).to_list() == expected
+
+    lf = s.to_frame("date").lazy()
+    result = lf.select(pl.col("date").dt.quarter(fiscal_year_start=fiscal_year_start))
+    assert result.collect()["date"].to_list() == expected
+
+    with pytest.raises(InvalidOperationError, match="`fiscal_year_start`"):
+        s.dt.quarter(fiscal_year_start=0)
+
+
+def test_business_day_calendar() -> None:
+    s = pl.Series([date(2020, 1, 1), date(2020, 1, 3)])
+    pl.register_holiday_calendar(
+        "test_calendar",
+        week_mask=(True, True, True, True, False, False, True),
+        holidays=[date(2020, 1, 1)],
+    )
+    try:
+        result = s.dt.is_business_day(calendar="test_calendar")
+        assert_series_equal(result, pl.Series([False, False]))
+        result = s.dt.add_business_days(1, calendar="test_calendar", roll="forward")
+        assert_series_equal(result, pl.Series([date(2020, 1, 5), date(2020, 1, 6)]))
+    finally:
+        pl.unregister_holiday_calendar("test_calendar")
+
+
 def test_date_offset() -> None:
     df = pl.DataFrame(
         {
@@ -1000,6 +1447,92 @@ def test_offset_by_saturating_8217_8474(
     assert result == expected
 
 
+@pytest.mark.parametrize(
+    ("duration", "input_date", "expected"),
+    [
+        ("1mo", date(2024, 2, 29), date(2024, 3, 31)),
+        ("1mo", date(2024, 2, 28), date(2024, 3, 28)),
+        ("1mo", date(2024, 1, 31), date(2024, 2, 29)),
+        ("-1mo", date(2024, 4, 30), date(2024, 3, 31)),
+        ("1q", date(2023, 9, 30), date(2023, 12, 31)),
+        ("1y", date(2023, 2, 28), date(2024, 2, 29)),
+        ("1d", date(2024, 2, 29), date(2024, 3, 1)),
+    ],
+)
+def test_offset_by_end_of_month(
+    duration: str, input_date: date, expected: date
+) -> None:
+    result = pl.Series([input_date]).dt.offset_by(duration, end_of_month=True).item()
+    assert result == expected
+
+
+def test_offset_by_end_of_month_expression() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [
+                datetime(2024, 1, 31, 12),
+                datetime(2024, 6, 30),
+                datetime(2024, 4, 15),
+            ],
+            "b": ["1mo", "3mo", "1mo2d"],
+        }
+    )
+    result = df.select(
+        c=pl.col("a").dt.offset_by(pl.col("b"), end_of_month=True),
+        d=pl.col("a")
+        .dt.replace_time_zone("Europe/London")
+        .dt.offset_by(pl.col("b"), end_of_month=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "c": [
+                datetime(2024, 2, 29, 12),
+                datetime(2024, 9, 30),
+                datetime(2024, 5, 17),
+            ],
+            "d": [
+                datetime(2024, 2, 29, 12),
+                datetime(2024, 9, 30),
+                datetime(2024, 5, 17),
+            ],
+        }
+    ).with_columns(pl.col("d").dt.replace_time_zone("Europe/London"))
+    assert_frame_equal(result, expected)
+
+
+def test_offset_by_duration() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [datetime(2024, 3, 30, 12), datetime(2024, 3, 31), None],
+            "b": [timedelta(days=1), timedelta(hours=-6), timedelta(minutes=1)],
+        }
+    )
+    result = df.select(
+        c=pl.col("a").dt.offset_by(pl.col("b")),
+        d=pl.col("a").dt.date().dt.offset_by(pl.col("b")),
+        # Durations are a fixed amount of time, also across DST changes.
+        e=pl.col("a")
+        .dt.replace_time_zone("Europe/London")
+        .dt.offset_by(pl.col("b"))
+        .dt.replace_time_zone(None),
+        f=pl.col("a").dt.offset_by(pl.duration(days=2)),
+    )
+    expected = pl.DataFrame(
+        {
+            "c": [datetime(2024, 3, 31, 12), datetime(2024, 3, 30, 18), None],
+            "d": [date(2024, 3, 31), date(2024, 3, 30), None],
+            "e": [datetime(2024, 3, 31, 13), datetime(2024, 3, 30, 18), None],
+            "f": [datetime(2024, 4, 1, 12), datetime(2024, 4, 2), None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_offset_by_invalid_dtype() -> None:
+    with pytest.raises(InvalidOperationError, match="String or Duration"):
+        pl.Series([date(2024, 1, 1)]).dt.offset_by(pl.lit(1))
+
+
 def test_year_empty_df() -> None:
     df = pl.DataFrame(pl.Series(name="date", dtype=pl.Date))
     assert df.select(pl.col("date").dt.year()).dtypes == [pl.Int32]
@@ -1,8 +1,14 @@
+from __future__ import annotations
+
+import hashlib
+import zlib
+from typing import Any
+
 import pytest
 
 import polars as pl
-from polars.testing import assert_frame_equal
-from polars.type_aliases import TransferEncoding
+from polars.testing import assert_frame_equal, assert_series_equal
+from polars.type_aliases import DigestAlgorithm, Endianness, TransferEncoding
 
 
 def test_binary_conversions() -> None:
@@ -120,11 +126,40 @@ def test_hex_decode() -> None:
     assert [b"asd", b"qwe"] == df["data"].bin.decode("hex").to_list()
 
 
+def test_base32_encode() -> None:
+    df = pl.DataFrame({"data": [b"asd", b"qwe\xff"]})
+
+    assert ["MFZWI===", "OF3WL7Y="] == df["data"].bin.encode("base32").to_list()
+
+
+def test_base32_decode() -> None:
+    df = pl.DataFrame({"data": [b"MFZWI===", b"OF3WL7Y="]})
+
+    assert [b"asd", b"qwe\xff"] == df["data"].bin.decode("base32").to_list()
+
+
+def test_percent_encode() -> None:
+    df = pl.DataFrame({"data": [b"a/s d", b"\xff~"]})
+
+    assert ["a%2Fs%20d", "%FF~"] == df["data"].bin.encode("percent").to_list()
+
+
+def test_percent_decode() -> None:
+    df = pl.DataFrame({"data": [b"a%2Fs%20d", b"%FF~", b"%G0"]})
+
+    decoded = df["data"].bin.decode("percent", strict=False)
+    assert [b"a/s d", b"\xff~", None] == decoded.to_list()
+    with pytest.raises(pl.ComputeError, match="invalid `percent` encoding found"):
+        df["data"].bin.decode("percent")
+
+
 @pytest.mark.parametrize(
     "encoding",
     [
         "hex",
         "base64",
+        "base32",
+        "percent",
     ],
 )
 def test_compare_encode_between_lazy_and_eager_6814(encoding: TransferEncoding) -> None:
@@ -141,6 +176,7 @@ def test_compare_encode_between_lazy_and_eager_6814(encoding: TransferEncoding)
     [
         "hex",
         "base64",
+        "percent",
     ],
 )
 def test_compare_decode_between_lazy_and_eager_6814(encoding: TransferEncoding) -> None:
@@ -150,3 +186,85 @@ def test_compare_decode_between_lazy_and_eager_6814(encoding: TransferEncoding)
     dtype = result_eager["x"].dtype
     result_lazy = df.lazy().select(expr).select(pl.col(dtype)).collect()
     assert_frame_equal(result_eager, result_lazy)
+
+
+@pytest.mark.parametrize(
+    ("dtype", "values"),
+    [
+        (pl.Int8, [-1, 0, 100]),
+        (pl.UInt16, [1, 256, 65535]),
+        (pl.Int32, [-(2**31), 0, 2**31 - 1]),
+        (pl.UInt64, [0, 1, 2**64 - 1]),
+        (pl.Float32, [-1.5, 0.0, 3.25]),
+        (pl.Float64, [-1e300, 0.0, 1e-300]),
+    ],
+)
+@pytest.mark.parametrize("endianness", ["little", "big"])
+def test_reinterpret_round_trip(
+    dtype: pl.PolarsDataType, values: list[Any], endianness: Endianness
+) -> None:
+    s = pl.Series("a", [*values, None], dtype=dtype)
+    binary = s.to_binary(endianness=endianness)
+    assert binary.dtype == pl.Binary
+    assert binary.null_count() == 1
+    result = binary.bin.reinterpret(dtype=dtype, endianness=endianness)
+    assert_series_equal(result, s)
+
+
+def test_reinterpret_endianness() -> None:
+    lf = pl.LazyFrame({"x": [b"\x00\x00\x01\x02"]})
+    result = lf.select(
+        little=pl.col("x").bin.reinterpret(dtype=pl.UInt32),
+        big=pl.col("x").bin.reinterpret(dtype=pl.UInt32, endianness="big"),
+    )
+    expected = pl.DataFrame(
+        {"little": [0x02010000], "big": [0x00000102]},
+        schema={"little": pl.UInt32, "big": pl.UInt32},
+    )
+    assert result.schema == expected.schema
+    assert_frame_equal(result.collect(), expected)
+
+
+def test_reinterpret_invalid() -> None:
+    s = pl.Series([b"\x00\x01", b"\x00"])
+    with pytest.raises(pl.ComputeError, match="1 bytes as `i16`"):
+        s.bin.reinterpret(dtype=pl.Int16)
+    with pytest.raises(pl.InvalidOperationError, match="expected a numeric type"):
+        s.bin.reinterpret(dtype=pl.String)
+    with pytest.raises(pl.InvalidOperationError, match="expected a numeric type"):
+        pl.Series(["a"]).to_binary()
+    with pytest.raises(ValueError, match="`endianness` must be one of"):
+        s.bin.reinterpret(dtype=pl.Int16, endianness="middle")  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize(
+    ("algorithm", "digest"),
+    [
+        ("md5", lambda b: hashlib.md5(b).digest()),
+        ("sha1", lambda b: hashlib.sha1(b).digest()),
+        ("sha256", lambda b: hashlib.sha256(b).digest()),
+        ("crc32", lambda b: zlib.crc32(b).to_bytes(4, "big")),
+    ],
+)
+def test_digest(algorithm: DigestAlgorithm, digest: Any) -> None:
+    values = [b"", b"polars", "caf\u00e9".encode(), None]
+    expected = [None if v is None else digest(v) for v in values]
+
+    strings = [None if v is None else v.decode() for v in values]
+    lf = pl.LazyFrame({"bin": values, "str": strings})
+    result = lf.select(
+        pl.col("bin").bin.digest(algorithm, as_hex=False).alias("bin"),
+        pl.col("str").str.digest(algorithm).alias("str_hex"),
+    )
+    assert result.schema == {"bin": pl.Binary, "str_hex": pl.String}
+    assert result.collect().to_dict(as_series=False) == {
+        "bin": expected,
+        "str_hex": [None if d is None else d.hex() for d in expected],
+    }
+
+
+def test_digest_invalid() -> None:
+    with pytest.raises(pl.InvalidOperationError, match="cannot digest `i64`"):
+        pl.Series([1]).to_frame().select(pl.first().bin.digest("md5"))
+    with pytest.raises(ValueError, match="`algorithm` must be one of"):
+        pl.Series([b"a"]).bin.digest("sha512")  # type: ignore[arg-type]
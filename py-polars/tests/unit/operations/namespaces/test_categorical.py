@@ -1,5 +1,7 @@
+import pytest
+
 import polars as pl
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_categorical_lexical_sort() -> None:
@@ -83,6 +85,41 @@ def test_categorical_get_categories() -> None:
     ).cat.get_categories().to_list() == ["foo", "bar", "ham"]
 
 
+def test_categorical_n_categories_codes() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2],
+            "cats": pl.Series(["foo", "bar", None], dtype=pl.Categorical),
+        }
+    )
+    lf = df.lazy().select(
+        pl.col("cats").cat.n_categories().alias("n"),
+        pl.col("cats").cat.codes().alias("codes").implode(),
+    )
+    assert lf.schema == {"n": pl.UInt32, "codes": pl.List(pl.UInt32)}
+    assert lf.collect().rows() == [(2, [0, 1, None])]
+    assert df["cats"].cat.n_categories() == 2
+
+    s = pl.Series(["b", "a", "b"], dtype=pl.Enum(["a", "b", "c"]))
+    assert s.cat.n_categories() == 3
+    assert_series_equal(s.cat.codes(), pl.Series([1, 0, 1], dtype=pl.UInt32))
+
+    with pytest.raises(pl.SchemaError):
+        pl.Series([1]).cat.codes()
+
+
+def test_categorical_codes_global() -> None:
+    with pl.StringCache():
+        _ = pl.Series(["a", "b"], dtype=pl.Categorical)
+        s = pl.Series(["c", "b", None, "c"], dtype=pl.Categorical)
+
+    categories = s.cat.get_categories()
+    codes = s.cat.codes()
+    assert s.to_physical().to_list() == [2, 1, None, 2]
+    assert codes.to_list() == [0, 1, None, 0]
+    assert categories.gather(codes).to_list() == s.cast(pl.String).to_list()
+
+
 def test_cat_to_local() -> None:
     with pl.StringCache():
         s1 = pl.Series(["a", "b", "a"], dtype=pl.Categorical)
@@ -3,8 +3,10 @@ from __future__ import annotations
 import datetime
 from collections import OrderedDict
 
+import pytest
+
 import polars as pl
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_struct_various() -> None:
@@ -30,12 +32,138 @@ def test_struct_various() -> None:
         assert s.struct._ipython_key_completions_() == s.struct.fields
 
 
+def test_struct_field_wildcard_regex() -> None:
+    df = pl.DataFrame({"s": [{"metric_a": 1, "metric_b": 2.0, "other": "x"}]})
+
+    lf = df.lazy().select(pl.col("s").struct.field("^metric_.*$") * 2)
+    expected = pl.DataFrame({"metric_a": [2], "metric_b": [4.0]})
+    assert lf.schema == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+
+    result = df.select(pl.col("s").struct.field("*").name.suffix("_x"))
+    assert result.columns == ["metric_a_x", "metric_b_x", "other_x"]
+    result = df.select(pl.col("s").struct.field("^metric_.*$").name.keep())
+    assert result.columns == ["metric_a", "metric_b"]
+
+
+def test_field_by_path() -> None:
+    df = pl.DataFrame(
+        {"a": [{"b": {"c": [{"d": 1}, {"d": 2}]}}, {"b": {"c": [{"d": 3}]}}]}
+    )
+    lf = df.lazy().select(
+        pl.col("a").struct.field_by_path("b.c[0].d").alias("first"),
+        pl.col("a").struct.field_by_path("b.c[-1].d").alias("last"),
+        pl.col("a").struct.field_by_path("b.c[1]"),
+    )
+    expected = pl.DataFrame(
+        {"first": [1, 3], "last": [2, 3], "c": [{"d": 2}, None]},
+    )
+    assert lf.schema == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+
+
+@pytest.mark.parametrize("path", ["b.x", "b.c[0].e"])
+def test_field_by_path_schema_error(path: str) -> None:
+    lf = pl.LazyFrame({"a": [{"b": {"c": [{"d": 1}]}}]})
+    with pytest.raises(pl.StructFieldNotFoundError):
+        lf.select(pl.col("a").struct.field_by_path(path)).schema
+
+
+def test_field_by_path_index_non_list() -> None:
+    lf = pl.LazyFrame({"a": [{"b": {"c": [{"d": 1}]}}]})
+    with pytest.raises(pl.SchemaError, match="expected `List`"):
+        lf.select(pl.col("a").struct.field_by_path("b[0]")).collect()
+
+
+@pytest.mark.parametrize("path", ["b..c", "b.c[x]", "b.c[0"])
+def test_field_by_path_invalid(path: str) -> None:
+    with pytest.raises(pl.ComputeError, match="invalid field path"):
+        pl.col("a").struct.field_by_path(path)
+
+
+def test_select_drop_fields() -> None:
+    df = pl.DataFrame({"s": [{"a": 1, "b": "x", "c": True}, None]})
+
+    lf = df.lazy().select(pl.col("s").struct.select_fields(["c", "a"]))
+    expected_dtype = pl.Struct({"c": pl.Boolean, "a": pl.Int64})
+    assert lf.schema == {"s": expected_dtype}
+    assert lf.collect().schema == {"s": expected_dtype}
+    assert lf.collect()["s"].to_list()[0] == {"c": True, "a": 1}
+
+    lf = df.lazy().select(pl.col("s").struct.drop_fields("a", "c"))
+    assert lf.schema == {"s": pl.Struct({"b": pl.String})}
+    assert lf.collect()["s"].to_list()[0] == {"b": "x"}
+
+    with pytest.raises(pl.StructFieldNotFoundError, match="d"):
+        df.lazy().select(pl.col("s").struct.select_fields("d").struct.field("a")).schema
+    with pytest.raises(pl.StructFieldNotFoundError, match="a"):
+        df.lazy().select(pl.col("s").struct.drop_fields("a").struct.field("a")).schema
+    with pytest.raises(pl.InvalidOperationError, match="cannot drop all fields"):
+        df.select(pl.col("s").struct.drop_fields("a", "b", "c"))
+    with pytest.raises(pl.InvalidOperationError, match="cannot select no fields"):
+        df.lazy().select(pl.col("s").struct.select_fields([])).schema
+    with pytest.raises(pl.InvalidOperationError, match="cannot select no fields"):
+        df.with_columns(pl.col("s").struct.select_fields([]))
+    with pytest.raises(pl.DuplicateError, match="more than once"):
+        df.lazy().select(pl.col("s").struct.select_fields(["a", "a"])).schema
+    with pytest.raises(pl.DuplicateError, match="more than once"):
+        df.select(pl.col("s").struct.select_fields(["a", "a"]))
+
+
+def test_aggregate_fields() -> None:
+    df = pl.DataFrame(
+        {"s": [{"a": 1, "b": 2.5, "c": "x"}, {"a": None, "b": 4.0, "c": "y"}]}
+    )
+    lf = df.lazy().select(
+        pl.col("s").struct.sum_fields().alias("sum"),
+        pl.col("s").struct.mean_fields().alias("mean"),
+        pl.col("s").struct.min_fields().alias("min"),
+        pl.col("s").struct.max_fields().alias("max"),
+    )
+    expected = pl.DataFrame(
+        {
+            "sum": [3.5, 4.0],
+            "mean": [1.75, 4.0],
+            "min": [1.0, 4.0],
+            "max": [2.5, 4.0],
+        }
+    )
+    assert lf.schema == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+
+    s = pl.Series("s", [{"a": 1, "b": 2}], pl.Struct({"a": pl.Int8, "b": pl.Int16}))
+    assert_series_equal(s.struct.sum_fields(), pl.Series("s", [3], pl.Int16))
+
+    with pytest.raises(pl.InvalidOperationError, match="without numeric fields"):
+        pl.DataFrame({"s": [{"a": "x"}]}).select(pl.col("s").struct.sum_fields())
+
+
 def test_rename_fields() -> None:
     df = pl.DataFrame({"int": [1, 2], "str": ["a", "b"], "bool": [True, None]})
     s = df.to_struct("my_struct").struct.rename_fields(["a", "b"])
     assert s.struct.fields == ["a", "b"]
 
 
+def test_rename_fields_mapping() -> None:
+    df = pl.DataFrame({"x": {"a": 1, "b": "c", "c": True}})
+    lf = df.lazy().select(pl.col("x").struct.rename_fields({"c": "a", "a": "c"}))
+    expected_schema = {"x": pl.Struct({"c": pl.Int64, "b": pl.String, "a": pl.Boolean})}
+    assert lf.schema == expected_schema
+    assert lf.collect().schema == expected_schema
+    assert lf.collect().unnest("x").rows() == [(1, "c", True)]
+
+    with pytest.raises(pl.StructFieldNotFoundError, match="d"):
+        df.select(pl.col("x").struct.rename_fields({"d": "e"}))
+
+
+def test_rename_fields_function() -> None:
+    s = pl.Series("x", [{"a": 1, "b": 2}])
+    assert s.struct.rename_fields(lambda name: f"{name}_1").struct.fields == [
+        "a_1",
+        "b_1",
+    ]
+
+
 def test_struct_json_encode() -> None:
     assert pl.DataFrame(
         {"a": [{"a": [1, 2], "b": [45]}, {"a": [9, 1, 3], "b": None}]}
@@ -64,6 +192,17 @@ def test_struct_json_encode_logical_type() -> None:
     }
 
 
+def test_struct_json_encode_time() -> None:
+    s = pl.Series("a", [{"t": datetime.time(10, 30, 1)}, {"t": None}])
+    assert s.struct.json_encode().to_list() == ['{"t":"10:30:01"}', '{"t":null}']
+
+
+def test_struct_json_encode_round_trip() -> None:
+    dtype = pl.Struct({"a": pl.Struct({"b": pl.List(pl.Int64)}), "c": pl.String})
+    s = pl.Series("x", [{"a": {"b": [1, 2]}, "c": "d"}, {"a": None, "c": None}], dtype)
+    assert_series_equal(s.struct.json_encode().str.json_decode(dtype), s)
+
+
 def test_map_fields() -> None:
     df = pl.DataFrame({"x": {"a": 1, "b": 2}})
     assert df.schema == OrderedDict([("x", pl.Struct({"a": pl.Int64, "b": pl.Int64}))])
@@ -83,3 +222,49 @@ def test_prefix_suffix_fields() -> None:
     assert suffix_df.schema == OrderedDict(
         [("x", pl.Struct({"a_f": pl.Int64, "b_f": pl.Int64}))]
     )
+
+
+def test_with_fields() -> None:
+    df = pl.DataFrame({"coords": [{"x": 1, "y": 4}, {"x": 4, "y": 9}], "m": [10, 2]})
+    q = df.lazy().select(
+        pl.col("coords").struct.with_fields(
+            pl.field("x").cast(pl.Float64) * 10,
+            pl.field("y").alias("y_copy"),
+            y_mul=pl.field("y") * pl.col("m"),
+        )
+    )
+    expected_dtype = pl.Struct(
+        {"x": pl.Float64, "y": pl.Int64, "y_copy": pl.Int64, "y_mul": pl.Int64}
+    )
+    assert q.schema == {"coords": expected_dtype}
+
+    result = q.collect()
+    assert result.schema == {"coords": expected_dtype}
+    assert result["coords"].to_list() == [
+        {"x": 10.0, "y": 4, "y_copy": 4, "y_mul": 40},
+        {"x": 40.0, "y": 9, "y_copy": 9, "y_mul": 18},
+    ]
+
+
+def test_with_fields_multiple() -> None:
+    df = pl.DataFrame({"s": [{"a": 1, "b": 2}]})
+    result = df.select(pl.col("s").struct.with_fields(pl.field("*") + 1))
+    assert result["s"].to_list() == [{"a": 2, "b": 3}]
+    result = df.select(pl.col("s").struct.with_fields(pl.field("a", "b").cast(pl.Int8)))
+    assert result.schema == {"s": pl.Struct({"a": pl.Int8, "b": pl.Int8})}
+
+
+def test_with_fields_nested() -> None:
+    df = pl.DataFrame({"s": [{"a": 1, "inner": {"b": 2}}]})
+    result = df.select(
+        pl.col("s").struct.with_fields(
+            pl.field("inner").struct.with_fields(c=pl.field("b") * 3)
+        )
+    )
+    assert result["s"].to_list() == [{"a": 1, "inner": {"b": 2, "c": 6}}]
+
+
+def test_field_outside_with_fields() -> None:
+    df = pl.DataFrame({"s": [{"a": 1}]})
+    with pytest.raises(pl.InvalidOperationError, match="struct.with_fields"):
+        df.select(pl.field("a"))
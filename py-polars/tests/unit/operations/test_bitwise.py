@@ -0,0 +1,97 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.mark.parametrize("dtype", [pl.Int8, pl.Int64, pl.UInt8, pl.UInt64])
+def test_shift_left_right(dtype: pl.PolarsDataType) -> None:
+    s = pl.Series("x", [1, 5, None, 32], dtype=dtype)
+    assert_series_equal(s.shift_left(1), pl.Series("x", [2, 10, None, 64], dtype=dtype))
+    assert_series_equal(s.shift_right(2), pl.Series("x", [0, 1, None, 8], dtype=dtype))
+
+    # Shifting by at least the width of the integer type shifts out all bits.
+    assert s.shift_left(64).to_list() == [0, 0, None, 0]
+    assert s.shift_right(64).to_list() == [0, 0, None, 0]
+
+
+def test_shift_signed() -> None:
+    s = pl.Series("x", [-8, 127, None], dtype=pl.Int8)
+    assert s.shift_right(1).to_list() == [-4, 63, None]
+    assert s.shift_right(8).to_list() == [-1, 0, None]
+    assert s.shift_left(1).to_list() == [-16, -2, None]
+
+
+def test_shift_by_column() -> None:
+    df = pl.DataFrame({"x": [1, 1, 1, 1], "n": [0, 1, 2, None]})
+    result = df.select(
+        left=pl.col("x").shift_left(pl.col("n")),
+        right=pl.lit(16).shift_right(pl.col("n")),
+    )
+    expected = pl.DataFrame(
+        {"left": [1, 2, 4, None], "right": [16, 8, 4, None]},
+        schema={"left": pl.Int64, "right": pl.Int32},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_shift_invalid() -> None:
+    with pytest.raises(pl.InvalidOperationError, match="shift_left"):
+        pl.Series([1.0]).shift_left(1)
+    with pytest.raises(pl.ComputeError, match="conversion"):
+        pl.Series([1]).shift_right(-1)
+    with pytest.raises(pl.ShapeError):
+        pl.select(pl.Series([1, 2, 3]).shift_left(pl.Series([1, 2])))
+
+
+def test_bitwise_reductions() -> None:
+    s = pl.Series("x", [0b1100, None, 0b1010], dtype=pl.UInt8)
+    assert s.bitwise_and() == 0b1000
+    assert s.bitwise_or() == 0b1110
+    assert s.bitwise_xor() == 0b0110
+
+    s = pl.Series("x", [-1, -2], dtype=pl.Int16)
+    assert s.bitwise_and() == -2
+    assert s.bitwise_or() == -1
+    assert s.bitwise_xor() == 1
+
+    s = pl.Series("x", [True, None, False, True])
+    assert s.bitwise_and() is False
+    assert s.bitwise_or() is True
+    assert s.bitwise_xor() is False
+
+    for s in (pl.Series("x", [], dtype=pl.Int32), pl.Series("x", [None], pl.Boolean)):
+        assert s.bitwise_and() is None
+        assert s.bitwise_or() is None
+        assert s.bitwise_xor() is None
+
+    with pytest.raises(pl.InvalidOperationError, match="bitwise"):
+        pl.Series(["a"]).bitwise_and()
+
+
+def test_bitwise_group_by() -> None:
+    df = pl.DataFrame(
+        {"g": [1, 1, 2, 2, 3], "x": [0b0110, 0b0011, 0b1000, None, None]},
+        schema_overrides={"x": pl.Int32},
+    )
+    result = df.group_by("g", maintain_order=True).agg(
+        pl.col("x").bitwise_and().alias("and"),
+        pl.col("x").bitwise_or().alias("or"),
+        pl.col("x").bitwise_xor().alias("xor"),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "and": [0b0010, 0b1000, None],
+            "or": [0b0111, 0b1000, None],
+            "xor": [0b0101, 0b1000, None],
+        },
+        schema_overrides={"and": pl.Int32, "or": pl.Int32, "xor": pl.Int32},
+    )
+    assert_frame_equal(result, expected)
+
+    lf = df.lazy().select(pl.col("x").bitwise_or())
+    assert lf.schema == {"x": pl.Int32}
+    assert lf.collect().to_dict(as_series=False) == {"x": [0b1111]}
@@ -298,6 +298,15 @@ def test_string_position() -> None:
             ),
         )
 
+    # positions count chars, not bytes
+    df = pl.DataFrame({"txt": ["ñandú", "straße"]})
+    res = df.sql("SELECT POSITION('d' IN txt) AS d, STRPOS(txt, 'e') AS e FROM self")
+    assert res.to_dict(as_series=False) == {"d": [4, 0], "e": [0, 6]}
+
+    df = pl.DataFrame({"txt": ["日本語のテキスト", "テキスト", "text"]})
+    res = df.sql("SELECT POSITION('テキ' IN txt) AS p, STRPOS(txt, 'キ') AS s FROM self")
+    assert res.to_dict(as_series=False) == {"p": [5, 1, 0], "s": [6, 2, 0]}
+
 
 def test_string_replace() -> None:
     df = pl.DataFrame({"words": ["Yemeni coffee is the best coffee", "", None]})